@@ -0,0 +1,107 @@
+//! Cost of preparing the star map's draw list
+//!
+//! Compares re-culling a 50,000-star catalog on every render with reusing
+//! the memoized list when the view has not changed, as when a quiz answer
+//! or toast re-renders the map, and scanning the whole catalog with
+//! walking only the on-screen tiles. Run with `cargo bench --bench draw_list`;
+//! under `cargo test` it runs a single quick pass as a smoke test.
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use stargazer_poc::data::star_names::Lang;
+use stargazer_poc::data::tile_view::TileView;
+use stargazer_poc::data::{CelestialCoord, Star, StarCatalog, StarId, TileSystem};
+use stargazer_poc::utils::sky_svg::{draw_list_for, prepare_draw_list, DrawList, DrawOptions};
+use stargazer_poc::utils::{LabelDensity, Viewport};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const STAR_COUNT: u32 = 50_000;
+
+/// A uniform random sky, one named star in fifty
+fn synthetic_catalog(seed: u64) -> StarCatalog {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut catalog = StarCatalog::new();
+    for i in 0..STAR_COUNT {
+        catalog.add_star(Star {
+            id: StarId(i),
+            name: (i % 50 == 0).then(|| format!("Star {}", i)),
+            coord: CelestialCoord::new(rng.gen_range(0.0..24.0), rng.gen_range(-90.0..90.0)),
+            magnitude: rng.gen_range(-1.0..6.5),
+            constellation: None,
+        });
+    }
+    catalog.rebuild_indices();
+    catalog
+}
+
+/// Renders in which the view stays put, then the time for all of them
+fn time_renders(renders: u32, mut render: impl FnMut() -> usize) -> Duration {
+    let started = Instant::now();
+    for _ in 0..renders {
+        black_box(render());
+    }
+    started.elapsed()
+}
+
+fn main() {
+    let benching = std::env::args().any(|arg| arg == "--bench");
+    let renders = if benching { 200 } else { 2 };
+
+    let catalog = synthetic_catalog(7);
+    let stars: Vec<Star> = catalog.all_stars().cloned().collect();
+    let tiles = TileView::new(&catalog, &TileSystem::from_stars(&stars));
+    let options = DrawOptions {
+        magnitude_limit: 6.5,
+        star_scale: 1.0,
+        label_density: LabelDensity::default(),
+        name_lang: Lang::default(),
+    };
+
+    for zoom in [1.0, 4.0] {
+        let viewport = Viewport {
+            zoom,
+            ..Viewport::default()
+        };
+
+        let uncached = time_renders(renders, || {
+            prepare_draw_list(&catalog, &viewport, &options).stars.len()
+        });
+
+        // What the map's use_memo does: recompute only when the key changes
+        let mut cache: Option<((Viewport, DrawOptions), DrawList)> = None;
+        let cached = time_renders(renders, || {
+            let key = (viewport, options);
+            if cache.as_ref().is_none_or(|(k, _)| *k != key) {
+                cache = Some((key, prepare_draw_list(&catalog, &viewport, &options)));
+            }
+            cache.as_ref().map_or(0, |(_, list)| list.stars.len())
+        });
+
+        let (ra_min, ra_max) = viewport.ra_range();
+        let (dec_min, dec_max) = viewport.dec_range();
+        let tiled = time_renders(renders, || {
+            let visible = tiles.stars_in_view(
+                &catalog,
+                ra_min,
+                ra_max,
+                dec_min,
+                dec_max,
+                options.magnitude_limit,
+            );
+            draw_list_for(&visible, &viewport, &options).stars.len()
+        });
+
+        let visible = prepare_draw_list(&catalog, &viewport, &options).stars.len();
+        println!(
+            "zoom {:>3}: {:>6} visible stars, {:>4} renders: recompute {:>10.3?} ({:.3?}/render), tile walk {:>10.3?}, memoized {:>10.3?}",
+            zoom,
+            visible,
+            renders,
+            uncached,
+            uncached / renders,
+            tiled,
+            cached,
+        );
+    }
+}
@@ -0,0 +1,163 @@
+//! Captures build information for `stargazer_poc::build_info`
+//!
+//! Sets `STARGAZER_GIT_SHA`, `STARGAZER_GIT_DIRTY`, `STARGAZER_BUILD_TIME`
+//! and `STARGAZER_BUILD_PROFILE` for the crate. The build time honors
+//! `SOURCE_DATE_EPOCH` so reproducible builds stay reproducible.
+//!
+//! With the `embedded-catalog` feature it also converts the catalog JSON
+//! to the binary layout in `OUT_DIR/catalog.bin`, for
+//! `stargazer_poc::data::embedded` to include.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[allow(dead_code)]
+#[path = "src/utils/dates.rs"]
+mod dates;
+
+#[path = "src/data/binary.rs"]
+mod binary;
+
+/// Catalog embedded by default, relative to the crate root
+const EMBEDDED_CATALOG: &str = "data/stars.json";
+
+/// Overrides [`EMBEDDED_CATALOG`]
+const EMBEDDED_CATALOG_VAR: &str = "STARGAZER_CATALOG";
+
+/// A star as written in the catalog JSON
+#[derive(serde::Deserialize)]
+struct JsonStar {
+    id: u32,
+    name: Option<String>,
+    coord: JsonCoord,
+    magnitude: f64,
+    constellation: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct JsonCoord {
+    ra: f64,
+    dec: f64,
+}
+
+/// Short SHA of the checked-out commit, or `unknown` outside a git checkout
+fn git_sha() -> String {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|sha| sha.trim().to_string())
+        .filter(|sha| !sha.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Whether tracked files differ from the checked-out commit; `false`
+/// outside a git checkout
+fn git_dirty() -> bool {
+    Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .is_some_and(|output| !output.stdout.is_empty())
+}
+
+/// Build time as seconds since the Unix epoch
+fn build_seconds() -> i64 {
+    if let Some(epoch) = env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|s| s.parse().ok())
+    {
+        return epoch;
+    }
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// RFC 3339 UTC timestamp, e.g. `2025-12-30T18:55:00Z`
+fn timestamp(seconds: i64) -> String {
+    let day = dates::day_from_unix_seconds(seconds);
+    let of_day = seconds - day * 86_400;
+    format!(
+        "{}T{:02}:{:02}:{:02}Z",
+        dates::format_date(day),
+        of_day / 3600,
+        of_day % 3600 / 60,
+        of_day % 60
+    )
+}
+
+/// Write the catalog JSON as `OUT_DIR/catalog.bin`
+fn embed_catalog() {
+    println!("cargo:rerun-if-env-changed={}", EMBEDDED_CATALOG_VAR);
+    let source = env::var(EMBEDDED_CATALOG_VAR).unwrap_or_else(|_| EMBEDDED_CATALOG.to_string());
+    let path = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join(source);
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let json = fs::read(&path).unwrap_or_else(|e| {
+        panic!(
+            "embedded-catalog: cannot read {} ({}); export one with \
+             `stargazer-cli generate --format json` or set {}",
+            path.display(),
+            e,
+            EMBEDDED_CATALOG_VAR
+        )
+    });
+    let stars: Vec<JsonStar> = serde_json::from_slice(&json)
+        .unwrap_or_else(|e| panic!("embedded-catalog: {}: {}", path.display(), e));
+
+    let mut out = Vec::with_capacity(8 + stars.len() * 40);
+    let written = binary::write_header(&mut out, stars.len()).and_then(|_| {
+        stars.iter().try_for_each(|star| {
+            binary::write_star(
+                &mut out,
+                star.id,
+                star.coord.ra,
+                star.coord.dec,
+                star.magnitude,
+                star.name.as_deref(),
+                star.constellation.as_deref(),
+            )
+        })
+    });
+    if let Err(e) = written {
+        panic!("embedded-catalog: {}: {}", path.display(), e);
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("catalog.bin"), out).unwrap();
+    println!(
+        "cargo:rustc-env=STARGAZER_EMBEDDED_SOURCE={}",
+        path.display()
+    );
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs/heads");
+    // Edits and staging change the dirty flag
+    println!("cargo:rerun-if-changed=.git/index");
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+
+    let profile = env::var("PROFILE").unwrap_or_else(|_| "unknown".to_string());
+    println!("cargo:rustc-env=STARGAZER_GIT_SHA={}", git_sha());
+    println!("cargo:rustc-env=STARGAZER_GIT_DIRTY={}", git_dirty());
+    println!(
+        "cargo:rustc-env=STARGAZER_BUILD_TIME={}",
+        timestamp(build_seconds())
+    );
+    println!("cargo:rustc-env=STARGAZER_BUILD_PROFILE={}", profile);
+
+    if env::var_os("CARGO_FEATURE_EMBEDDED_CATALOG").is_some() {
+        embed_catalog();
+    }
+}
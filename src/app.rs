@@ -2,193 +2,1056 @@
 //!
 //! The root component that assembles all UI pieces and manages global state.
 
-use crate::components::{Controls, QuizDropdown, ScoreDisplay, StarMap, SummaryPopup};
-use crate::data::{generate_placeholder_catalog, TileSystem, ZoomLevel};
-use crate::game::{game_reducer, GameAction, GameState, QuizConfig, QuizGenerator};
+pub mod audio;
+pub mod catalog_status;
+pub mod crash;
+pub mod i18n;
+pub mod slices;
+pub mod url_state;
+pub mod views;
+
+use crate::build_info;
+use crate::components::keyboard::{use_keyboard_shortcuts, ShortcutContext};
+use crate::components::DebugOverlay;
+use crate::data::dso;
+use crate::data::embedded::embedded_catalog;
+use crate::data::{generate_placeholder_catalog, Star, StarCatalog, StarId};
+use crate::game::achievements::newly_unlocked;
+use crate::game::events::{self, HostQuizConfig, ANSWERED, QUIZ_STARTED, SESSION_ENDED};
+use crate::game::progress::{Progress, PROGRESS_FILENAME};
+use crate::game::review::{missed_stars, ReviewDeck};
+use crate::game::{
+    deep_sky_choices, game_reducer, intern, Achievements, AnswerMode, DailyChallenge, GameAction,
+    GameMode, GameState, NoTelemetry, Preferences, QuestionKind, QuizConfig, QuizGenerator,
+    QuizQuestion, StarStats, Telemetry, ViewMode,
+};
+use crate::js_api::{dispatch_quiz_event, host_quiz_config, host_telemetry};
+use crate::utils::dates::day_from_unix_millis;
+use crate::utils::download::{download_svg_as_png, download_text, read_text, JSON_MIME, SVG_MIME};
+use crate::utils::perf;
+use crate::utils::sky_svg::{render_to_svg_string, SvgExportOptions};
+use crate::utils::{layout_for_width, ExportFormat, LocalSky, Observer, SkyIndex};
+use catalog_status::{CatalogEvent, CatalogSource, CatalogStatus};
 use gloo::events::EventListener;
+use gloo::timers::callback::Timeout;
+use i18n::{t, tf, TextKey};
 use rand::SeedableRng;
+use slices::Slices;
 use std::rc::Rc;
-use wasm_bindgen::JsCast;
+use views::{
+    ChallengeView, ControlsView, CountdownView, HelpView, LegendView, MapView, ProgressMapView,
+    QuizView, RunView, ScoreView, SettingsView, StarInfoView, StarPanelView, SummaryView,
+    ToastView, TourView, TrophiesView,
+};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
 use yew::prelude::*;
 
 #[cfg(not(target_arch = "wasm32"))]
-use crate::data::{load_stars_from_json, StarCatalog};
+use crate::data::load_stars_from_json;
 
-/// The main application component
-#[function_component(App)]
-pub fn app() -> Html {
-    // Initialize star catalog - try to load from JSON, fallback to placeholder
-    let catalog = use_memo((), |_| {
-        #[cfg(target_arch = "wasm32")]
-        {
-            // In WASM, use placeholder for now
-            // TODO: Implement async loading from HTTP
-            return generate_placeholder_catalog();
+/// Fraction of the viewport a quiz target must fall inside to avoid re-centering
+const QUIZ_CENTRAL_FRACTION: f64 = 0.6;
+
+/// How long the "ping" highlight plays on a newly quizzed star
+const QUIZ_PING_MS: u32 = 1200;
+
+/// Quiet period before the URL hash follows state changes
+const URL_SYNC_DELAY_MS: u32 = 400;
+
+/// How long an answer stays up before a timed run moves on
+const AUTO_ADVANCE_MS: u32 = 700;
+
+/// The catalog on screen and the sky index built from it
+#[derive(Clone)]
+struct LoadedCatalog {
+    catalog: Rc<StarCatalog>,
+    index: Rc<SkyIndex>,
+}
+
+impl LoadedCatalog {
+    fn new(catalog: StarCatalog) -> Self {
+        Self {
+            index: Rc::new(SkyIndex::new(&catalog)),
+            catalog: Rc::new(catalog),
         }
+    }
+}
 
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            // Try loading from JSON in development/testing
-            if let Ok(stars) = load_stars_from_json() {
-                let mut catalog = StarCatalog::new();
-                for star in stars {
-                    catalog.add_star(star);
-                }
-                catalog.rebuild_indices();
-                return catalog;
-            }
+/// What the app's long-lived callbacks read from the latest render
+///
+/// The callbacks are created once so the views they are passed to see
+/// equal props from one render to the next.
+#[derive(Clone)]
+struct Current {
+    state: UseReducerHandle<GameState>,
+    catalog: Rc<StarCatalog>,
+    index: Rc<SkyIndex>,
+    telemetry: Option<Rc<dyn Telemetry>>,
+}
+
+/// Props for [`App`]
+#[derive(Properties, Default)]
+pub struct AppProps {
+    /// Where usage reports go; without it they go to the callback the
+    /// host page registered with `set_telemetry_callback`, if any
+    #[prop_or_default]
+    pub telemetry: Option<Rc<dyn Telemetry>>,
+}
+
+impl PartialEq for AppProps {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.telemetry, &other.telemetry) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (a, b) => a.is_none() && b.is_none(),
         }
+    }
+}
 
-        #[allow(unreachable_code)]
+/// How questions are asked: the player's settings, without "none of
+/// above" when answers are typed
+fn question_config(state: &GameState) -> QuizConfig {
+    let mut config = state.quiz_config.clone();
+    if state.preferences.answer_mode == AnswerMode::Typed {
+        config.include_none_option = false;
+    }
+    config
+}
+
+/// Whether a quiz may ask about `star`: the host allows it, it is within
+/// the player's difficulty, and in local sky mode it is above the horizon
+fn may_ask(
+    state: &GameState,
+    host_config: &HostQuizConfig,
+    sky: Option<&LocalSky>,
+    star: &Star,
+) -> bool {
+    host_config.allows(star)
+        && state.difficulty.is_none_or(|d| d.includes(star.magnitude))
+        && sky.is_none_or(|sky| sky.is_visible(&star.coord))
+}
+
+/// A question about a random named star the player may be asked about,
+/// for runs that move from question to question by themselves; its
+/// distractors come from around it, as for a clicked star
+fn random_question<R: rand::Rng>(
+    state: &GameState,
+    catalog: &StarCatalog,
+    index: &SkyIndex,
+    host_config: &HostQuizConfig,
+    rng: &mut R,
+) -> Option<QuizQuestion> {
+    let (min, max) = state
+        .difficulty
+        .or(host_config.difficulty)
+        .map_or((f64::NEG_INFINITY, f64::INFINITY), |d| d.magnitude_range());
+    let sky = state.local_sky(js_sys::Date::now() / 1000.0);
+    QuizGenerator::with_index(catalog, question_config(state), index, state.viewport.zoom)
+        .generate_matching(
+            min,
+            max,
+            |star| may_ask(state, host_config, sky.as_ref(), star),
+            rng,
+        )
+}
+
+/// A question naming a star missed earlier, for a review of mistakes:
+/// always by name, and never "none of the above"
+fn mistake_question<R: rand::Rng>(
+    state: &GameState,
+    catalog: &StarCatalog,
+    star: StarId,
+    rng: &mut R,
+) -> Option<QuizQuestion> {
+    let config = QuizConfig {
+        question_kind: QuestionKind::Name,
+        include_none_option: false,
+        ..question_config(state)
+    };
+    QuizGenerator::new(catalog, config).generate_for_star(catalog.get(star)?, rng)
+}
+
+/// The app's own telemetry, else the host page's, else none
+fn active_telemetry(own: &Option<Rc<dyn Telemetry>>) -> Rc<dyn Telemetry> {
+    own.clone()
+        .or_else(host_telemetry)
+        .unwrap_or_else(|| Rc::new(NoTelemetry))
+}
+
+/// Load the full catalog, reporting its progress and outcome to
+/// `status`, and any failure to `telemetry`
+///
+/// In the browser the catalog is fetched from [`catalog_status::CATALOG_URL`];
+/// native builds read it from disk.
+fn load_catalog(
+    loaded: UseStateHandle<LoadedCatalog>,
+    status: UseReducerHandle<CatalogStatus>,
+    telemetry: Rc<dyn Telemetry>,
+) {
+    #[cfg(target_arch = "wasm32")]
+    let on_progress = {
+        let status = status.clone();
+        move |progress| status.dispatch(CatalogEvent::Progress(progress))
+    };
+    let finish = move |url: &str, result: Result<StarCatalog, String>| match result {
+        Ok(catalog) => {
+            let stars = catalog.count();
+            loaded.set(LoadedCatalog::new(catalog));
+            status.dispatch(CatalogEvent::Loaded {
+                url: url.to_string(),
+                stars,
+            });
+        }
+        Err(error) => {
+            log::warn!("Could not load the star catalog: {}", error);
+            telemetry.error("catalog", &error);
+            status.dispatch(CatalogEvent::LoadFailed(error));
+        }
+    };
+
+    #[cfg(target_arch = "wasm32")]
+    wasm_bindgen_futures::spawn_local(async move {
+        let url = catalog_status::CATALOG_URL;
+        finish(url, catalog_status::fetch_catalog(url, on_progress).await);
+    });
+
+    #[cfg(not(target_arch = "wasm32"))]
+    finish(
+        "data/stars.json",
+        load_stars_from_json().and_then(catalog_status::catalog_from_stars),
+    );
+}
+
+/// Ask the browser where the user is, dispatching the answer as
+/// [`GameAction::SetObserver`]
+///
+/// If the page has no geolocation or the user declines, nothing is
+/// dispatched and the settings' location form stays up.
+fn request_location(dispatch: Callback<GameAction>) {
+    let Some(geolocation) = web_sys::window().and_then(|w| w.navigator().geolocation().ok()) else {
+        return;
+    };
+    // web-sys only types the position behind its unstable APIs, so its
+    // fields are read by name
+    let found = Closure::once_into_js(move |position: JsValue| {
+        let coords = js_sys::Reflect::get(&position, &JsValue::from_str("coords"))
+            .unwrap_or(JsValue::UNDEFINED);
+        let degrees = |name: &str| {
+            js_sys::Reflect::get(&coords, &JsValue::from_str(name))
+                .ok()
+                .and_then(|value| value.as_f64())
+        };
+        if let Some(observer) = degrees("latitude")
+            .zip(degrees("longitude"))
+            .and_then(|(latitude, longitude)| Observer::new(latitude, longitude))
         {
-            generate_placeholder_catalog()
+            dispatch.emit(GameAction::SetObserver(observer));
         }
     });
+    let failed = Closure::once_into_js(|error: JsValue| {
+        log::info!("Location unavailable: {:?}", error);
+    });
+    if let Err(e) = geolocation.get_current_position_with_error_callback(
+        found.unchecked_ref(),
+        Some(failed.unchecked_ref()),
+    ) {
+        log::info!("Location unavailable: {:?}", e);
+    }
+}
+
+/// Dispatch a quiz lifecycle event on the element the app is mounted on
+fn emit_quiz_event<T: serde::Serialize>(name: &str, detail: &T) {
+    if let Some(body) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.body())
+    {
+        dispatch_quiz_event(&body, name, detail);
+    }
+}
+
+/// The main application component
+#[function_component(App)]
+pub fn app(props: &AppProps) -> Html {
+    // Star catalog: one built into the binary if there is one, otherwise
+    // the placeholder until the full catalog loads, and whenever loading
+    // it fails
+    let embedded = use_memo((), |_| embedded_catalog().map(LoadedCatalog::new));
+    let loaded = {
+        let embedded = embedded.clone();
+        use_state(move || {
+            (*embedded)
+                .clone()
+                .unwrap_or_else(|| LoadedCatalog::new(generate_placeholder_catalog()))
+        })
+    };
+    let catalog_status = use_reducer(|| match &*embedded {
+        Some(loaded) => CatalogStatus::Ready {
+            source: CatalogSource::Embedded {
+                stars: loaded.catalog.count(),
+            },
+        },
+        None => CatalogStatus::loading(),
+    });
+    let catalog = loaded.catalog.clone();
+    let sky_index = loaded.index.clone();
+
+    // Load the catalog on startup and on each retry
+    {
+        let loaded = loaded.clone();
+        let status = catalog_status.clone();
+        let telemetry = props.telemetry.clone();
+        use_effect_with(catalog_status.is_loading(), move |loading| {
+            if *loading {
+                load_catalog(loaded, status, active_telemetry(&telemetry));
+            }
+        });
+    }
+    let on_catalog_event = {
+        let status = catalog_status.clone();
+        Callback::from(move |event: CatalogEvent| status.dispatch(event))
+    };
 
-    // Build tile system from catalog
-    let tile_system = use_memo(catalog.clone(), |cat| {
-        let stars: Vec<_> = cat.all_stars().cloned().collect();
-        TileSystem::from_stars(&stars)
+    // Game state with reducer, seeded with saved preferences,
+    // statistics and achievements and any settings shared in the URL hash
+    let state = use_reducer(|| {
+        let initial = Rc::new(GameState {
+            stats: Rc::new(StarStats::load()),
+            achievements: Achievements::load(),
+            ..GameState::with_preferences(Preferences::load())
+        });
+        let restored = url_state::decode(&url_state::current_hash())
+            .into_iter()
+            .fold(initial, game_reducer);
+        (*restored).clone()
     });
 
-    // Game state with reducer
-    let state = use_reducer(GameState::default);
+    // Persist preferences, statistics and achievements whenever they change
+    use_effect_with(state.preferences.clone(), |prefs| {
+        prefs.save();
+    });
+    use_effect_with(state.stats.clone(), |stats| {
+        stats.save();
+    });
+    use_effect_with(state.achievements.clone(), |achievements| {
+        achievements.save();
+    });
+
+    // Interface text follows the chosen language, as does the page's
+    // `lang` attribute
+    let locale = state.preferences.locale;
+    i18n::set_locale(locale);
+    use_effect_with(locale, |locale| {
+        if let Some(root) = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.document_element())
+        {
+            let _ = root.set_attribute("lang", locale.code());
+        }
+    });
 
     // Create a clone of state for use in callbacks
     let state_clone = state.clone();
+    let current = use_mut_ref(|| Current {
+        state: state.clone(),
+        catalog: catalog.clone(),
+        index: sky_index.clone(),
+        telemetry: props.telemetry.clone(),
+    });
+    *current.borrow_mut() = Current {
+        state: state.clone(),
+        catalog: catalog.clone(),
+        index: sky_index.clone(),
+        telemetry: props.telemetry.clone(),
+    };
 
-    // Create action dispatcher
+    // Create action dispatcher; actions are logged for the debug overlay
     let dispatch = {
-        let state = state_clone.clone();
-        Callback::from(move |action: GameAction| {
-            state.dispatch(action);
+        let dispatcher = state.dispatcher();
+        use_callback((), move |action: GameAction, _| {
+            perf::record_action(format!("{:?}", action));
+            dispatcher.dispatch(action);
+        })
+    };
+
+    // Keyboard focus returns to the map when popups close
+    let map_ref = use_node_ref();
+
+    // Question randomness, repeatable when the URL carries a seed
+    let quiz_rng = {
+        let seed = state.seed;
+        use_mut_ref(move || match seed {
+            Some(seed) => rand::rngs::SmallRng::seed_from_u64(seed),
+            None => rand::rngs::SmallRng::from_entropy(),
         })
     };
 
     // Handle star selection to start quiz
     let on_action = {
         let dispatch = dispatch.clone();
-        let catalog = catalog.clone();
-        let tile_system = tile_system.clone();
-        let state_for_quiz = state_clone.clone();
+        let current = current.clone();
+        let quiz_rng = quiz_rng.clone();
+
+        use_callback((), move |action: GameAction, _| {
+            let Current {
+                state: state_for_quiz,
+                catalog,
+                index,
+                telemetry,
+            } = current.borrow().clone();
+            let host_config = host_quiz_config();
+            let telemetry = active_telemetry(&telemetry);
 
-        Callback::from(move |action: GameAction| {
             // Special handling for star selection
             if let GameAction::SelectStar(star_id) = &action {
-                // If clicking a named star, start a quiz
-                if let Some(star) = catalog.get(*star_id) {
-                    if star.has_name() {
-                        let mut rng = rand::rngs::SmallRng::from_entropy();
-                        let config = QuizConfig::default();
-
-                        // Calculate zoom level based on viewport zoom
-                        let current_zoom = state_for_quiz.viewport.zoom;
-                        let zoom_level = ZoomLevel((current_zoom.log2().floor() as u8).clamp(0, 5));
-
-                        // Use tile-aware quiz generator
-                        let generator =
-                            QuizGenerator::with_tiles(&catalog, config, &tile_system, zoom_level);
-
-                        if let Some(question) = generator.generate_for_star(star, &mut rng) {
-                            dispatch.emit(GameAction::StartQuiz {
-                                target_star_id: question.target_star,
-                                correct_name: question.correct_answer,
-                                choices: question.choices,
-                            });
+                // If clicking a named star the host allows, start a quiz,
+                // except in practice; in local sky mode only stars above
+                // the horizon count
+                if let Some(star) = catalog
+                    .get(*star_id)
+                    .filter(|_| state_for_quiz.mode.asks_questions())
+                {
+                    let sky = state_for_quiz.local_sky(js_sys::Date::now() / 1000.0);
+                    let allowed = may_ask(&state_for_quiz, &host_config, sky.as_ref(), star);
+                    if star.has_name() && allowed {
+                        // Distractors come from around the star at the
+                        // current zoom, where the index has tiles
+                        let generator = QuizGenerator::with_index(
+                            &catalog,
+                            question_config(&state_for_quiz),
+                            &index,
+                            state_for_quiz.viewport.zoom,
+                        );
+
+                        let question =
+                            generator.generate_for_star(star, &mut *quiz_rng.borrow_mut());
+                        if let Some(question) = question {
+                            emit_quiz_event(
+                                QUIZ_STARTED,
+                                &events::quiz_started(question.target_star, question.choices.len()),
+                            );
+                            dispatch.emit(GameAction::start(question));
+
+                            // Bring targets near the edge of the view to the center
+                            let viewport = state_for_quiz.viewport;
+                            if !viewport.is_in_central_region(&star.coord, QUIZ_CENTRAL_FRACTION) {
+                                let mut centered = viewport;
+                                centered.center_ra = star.coord.ra;
+                                centered.center_dec = star.coord.dec;
+                                let screen = centered.project(&star.coord).point;
+                                dispatch.emit(GameAction::SetCenter(star.coord.ra, star.coord.dec));
+                                dispatch.emit(GameAction::SetDropdownPosition(screen.x, screen.y));
+                            }
+                        }
+                    }
+                }
+            }
+
+            // A click on a Messier object asks about it, among the objects
+            // near it on the sky, unless it is below the local horizon or
+            // this is practice
+            if let GameAction::SelectDeepSky(messier) = action {
+                let visible = |object: &dso::DeepSkyObject| {
+                    state_for_quiz
+                        .local_sky(js_sys::Date::now() / 1000.0)
+                        .is_none_or(|sky| sky.is_visible(&object.coord))
+                };
+                let object = dso::messier(messier)
+                    .filter(|object| visible(object) && state_for_quiz.mode.asks_questions());
+                if let Some(object) = object {
+                    let choices = deep_sky_choices(
+                        object,
+                        state_for_quiz.quiz_config.num_choices,
+                        &mut *quiz_rng.borrow_mut(),
+                    );
+                    dispatch.emit(GameAction::StartDeepSkyQuiz {
+                        messier,
+                        correct_name: intern(&object.designation()),
+                        choices,
+                    });
+                }
+            }
+
+            // Asked for by the location form's button
+            if matches!(action, GameAction::LocateObserver) {
+                request_location(dispatch.clone());
+            }
+
+            // The daily challenge asks today's questions in turn, each about
+            // a star brought to the center of the map
+            let challenge_question = match &action {
+                GameAction::PlayDailyChallenge => {
+                    let today = day_from_unix_millis(js_sys::Date::now());
+                    let challenge = DailyChallenge::new(&catalog, today);
+                    let first = challenge.next_question().cloned();
+                    dispatch.emit(GameAction::StartChallenge(challenge));
+                    first
+                }
+                GameAction::NextQuestion => state_for_quiz
+                    .challenge
+                    .as_ref()
+                    .and_then(DailyChallenge::next_question)
+                    .cloned(),
+                _ => None,
+            };
+
+            // Timed runs ask about one random star after another, and a
+            // review of mistakes about each missed star in turn
+            let mistake = match &action {
+                GameAction::ReviewMistakes => {
+                    let stars = missed_stars(&catalog, &state_for_quiz.guess_history);
+                    let first = stars.first().copied();
+                    if first.is_some() {
+                        dispatch.emit(GameAction::StartMistakes(stars));
+                    }
+                    first
+                }
+                GameAction::NextQuestion => match &state_for_quiz.mode {
+                    GameMode::Mistakes(run) => run.next_star(),
+                    _ => None,
+                },
+                _ => None,
+            };
+            let random = match &action {
+                GameAction::StartTimeAttack(_) | GameAction::StartRound => true,
+                GameAction::NextQuestion => {
+                    mistake.is_none() && state_for_quiz.mode.advances_by_itself()
+                }
+                _ => false,
+            };
+            let run_question = match mistake {
+                Some(star) => {
+                    mistake_question(&state_for_quiz, &catalog, star, &mut *quiz_rng.borrow_mut())
+                }
+                None => random
+                    .then(|| {
+                        random_question(
+                            &state_for_quiz,
+                            &catalog,
+                            &index,
+                            &host_config,
+                            &mut *quiz_rng.borrow_mut(),
+                        )
+                    })
+                    .flatten(),
+            };
+
+            // With no star left to ask about, say so, and don't start a
+            // run that would have no question
+            if random && run_question.is_none() {
+                dispatch.emit(GameAction::ShowToast(t(TextKey::NoStarsToAsk).to_string()));
+                if matches!(
+                    action,
+                    GameAction::StartTimeAttack(_) | GameAction::StartRound
+                ) {
+                    return;
+                }
+            }
+
+            // Report answers, and end the session on the summary or after
+            // the host's question count
+            let mut session_end = None;
+            let mut open_summary = false;
+            let mut unlocked = Vec::new();
+            match &action {
+                GameAction::ShowSummary if !state_for_quiz.ui.summary_shown => {
+                    session_end = Some(events::session_ended(
+                        &state_for_quiz.guess_history,
+                        &state_for_quiz.score,
+                    ));
+                }
+                _ => {
+                    if let Some(quiz) = &state_for_quiz.quiz {
+                        let elapsed = quiz.elapsed_ms(js_sys::Date::now());
+                        if let Some(detail) = events::answered(quiz, &action, elapsed) {
+                            emit_quiz_event(ANSWERED, &detail);
+                            telemetry.question_answered(
+                                detail.correct,
+                                state_for_quiz.difficulty.or(host_config.difficulty),
+                                detail.elapsed_ms,
+                            );
+                            if state_for_quiz.preferences.sound_enabled {
+                                let streak = state_for_quiz.score.streak + 1;
+                                audio::play(audio::cue_for_answer(detail.correct, streak));
+                            }
+                            let next =
+                                game_reducer(Rc::new((*state_for_quiz).clone()), action.clone());
+                            unlocked = newly_unlocked(&next, &catalog);
+                            if host_config.session_complete(state_for_quiz.guess_history.len() + 1)
+                            {
+                                session_end =
+                                    Some(events::session_ended(&next.guess_history, &next.score));
+                                open_summary = true;
+                            }
                         }
                     }
                 }
             }
 
             dispatch.emit(action);
+            if !unlocked.is_empty() {
+                let names: Vec<_> = unlocked
+                    .iter()
+                    .map(|&achievement| t(TextKey::for_achievement(achievement)))
+                    .collect();
+                dispatch.emit(GameAction::ShowToast(tf(
+                    TextKey::AchievementUnlocked,
+                    &[&names.join(", ")],
+                )));
+                dispatch.emit(GameAction::UnlockAchievements(unlocked));
+            }
+            if let Some(question) = &run_question {
+                dispatch.emit(GameAction::SelectStar(question.target_star));
+                dispatch.emit(GameAction::start(question.clone()));
+            }
+            if let Some(question) = challenge_question.or(run_question) {
+                emit_quiz_event(
+                    QUIZ_STARTED,
+                    &events::quiz_started(question.target_star, question.choices.len()),
+                );
+                if let Some(star) = catalog.get(question.target_star) {
+                    let viewport = state_for_quiz.viewport;
+                    dispatch.emit(GameAction::SetCenter(star.coord.ra, star.coord.dec));
+                    dispatch.emit(GameAction::SetDropdownPosition(
+                        viewport.width / 2.0,
+                        viewport.height / 2.0,
+                    ));
+                }
+            }
+            if open_summary {
+                dispatch.emit(GameAction::ShowSummary);
+            }
+            if let Some(detail) = session_end {
+                emit_quiz_event(SESSION_ENDED, &detail);
+                telemetry.session_ended(&detail);
+            }
+        })
+    };
+
+    // Export the current map view as an image
+    let on_export = {
+        let current = current.clone();
+
+        use_callback((), move |format: ExportFormat, _| {
+            let Current {
+                state: state_for_export,
+                catalog,
+                telemetry,
+                ..
+            } = current.borrow().clone();
+            let viewport = state_for_export.viewport;
+            let options = SvgExportOptions {
+                magnitude_limit: state_for_export.magnitude_limit,
+                show_grid: state_for_export.show_grid,
+                show_constellations: state_for_export.show_constellations,
+                star_scale: state_for_export.preferences.star_scale,
+                label_density: state_for_export.shown_label_density(),
+                name_lang: state_for_export.preferences.star_name_lang,
+            };
+            let svg = render_to_svg_string(&catalog, &viewport, &options);
+
+            let result = match format {
+                ExportFormat::Svg => download_text(format.filename(), SVG_MIME, &svg),
+                ExportFormat::Png => download_svg_as_png(
+                    format.filename(),
+                    &svg,
+                    viewport.width as u32,
+                    viewport.height as u32,
+                ),
+            };
+            if let Err(e) = result {
+                log::warn!("Map export failed: {:?}", e);
+                active_telemetry(&telemetry).error("export", &format!("{:?}", e));
+            }
         })
     };
 
-    // Build the quiz dropdown if active
-    let quiz_panel = if let (Some(quiz), Some(pos)) =
-        (state_clone.quiz.clone(), state_clone.ui.dropdown_position)
+    // Save everything learned to a file, and replace it from one
+    let on_export_progress = {
+        let current = current.clone();
+        use_callback((), move |_: (), _| {
+            let Current {
+                state, telemetry, ..
+            } = current.borrow().clone();
+            let progress = Progress {
+                stats: (*state.stats).clone(),
+                deck: ReviewDeck::load(),
+                achievements: state.achievements.clone(),
+                ..Progress::default()
+            };
+            if let Err(e) = download_text(PROGRESS_FILENAME, JSON_MIME, &progress.to_json()) {
+                log::warn!("Progress export failed: {:?}", e);
+                active_telemetry(&telemetry).error("export", &format!("{:?}", e));
+            }
+        })
+    };
+    let on_import_progress = {
+        let dispatch = dispatch.clone();
+        use_callback((), move |file: web_sys::File, _| {
+            let dispatch = dispatch.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let progress = read_text(&file)
+                    .await
+                    .map_err(|e| format!("{:?}", e))
+                    .and_then(|json| Progress::from_json(&json));
+                match progress {
+                    Ok(progress) => {
+                        progress.deck.save();
+                        dispatch.emit(GameAction::ShowToast(tf(
+                            TextKey::ProgressImported,
+                            &[&progress.stats.len(), &progress.achievements.len()],
+                        )));
+                        dispatch.emit(GameAction::ImportProgress(
+                            Rc::new(progress.stats),
+                            progress.achievements,
+                        ));
+                    }
+                    Err(e) => {
+                        log::warn!("Progress import failed: {}", e);
+                        dispatch.emit(GameAction::ShowToast(tf(
+                            TextKey::ProgressImportFailed,
+                            &[&e],
+                        )));
+                    }
+                }
+            });
+        })
+    };
+
+    // Track the window width to switch between wide and compact layouts
+    {
+        let dispatch = dispatch.clone();
+        use_effect_with((), move |_| {
+            let window = web_sys::window().expect("no window");
+            let update = {
+                let window = window.clone();
+                move || {
+                    if let Some(width) = window.inner_width().ok().and_then(|w| w.as_f64()) {
+                        let compact = layout_for_width(width).is_compact();
+                        dispatch.emit(GameAction::SetCompactLayout(compact));
+                    }
+                }
+            };
+            update();
+            let listener = EventListener::new(&window, "resize", move |_| update());
+            move || drop(listener)
+        });
+    }
+
+    // While sound is on, any click or key press may start audio, as
+    // browsers require a gesture before a page plays sound
+    {
+        let sound_enabled = state_clone.preferences.sound_enabled;
+        use_effect_with(sound_enabled, move |_| {
+            let listeners = sound_enabled.then(|| {
+                let window = web_sys::window().expect("no window");
+                [
+                    EventListener::new(&window, "pointerdown", |_| audio::unlock()),
+                    EventListener::new(&window, "keydown", |_| audio::unlock()),
+                ]
+            });
+            move || drop(listeners)
+        });
+    }
+
+    // Clear the quiz "ping" highlight shortly after it starts
+    {
+        let dispatch = dispatch.clone();
+        let quiz_ping = state_clone.ui.quiz_ping;
+        use_effect_with(quiz_ping, move |_| {
+            let timeout = quiz_ping.then(|| {
+                Timeout::new(QUIZ_PING_MS, move || {
+                    dispatch.emit(GameAction::ClearQuizPing);
+                })
+            });
+            move || drop(timeout)
+        });
+    }
+
+    // Timed runs move on to the next question a moment after each answer
+    {
+        let on_action = on_action.clone();
+        let advance = state_clone.mode.advances_by_itself()
+            && state_clone.quiz.as_ref().is_some_and(|quiz| quiz.answered);
+        use_effect_with(advance, move |&advance| {
+            let timeout = advance.then(|| {
+                Timeout::new(AUTO_ADVANCE_MS, move || {
+                    on_action.emit(GameAction::NextQuestion);
+                })
+            });
+            move || drop(timeout)
+        });
+    }
+
+    // Keep the URL hash in step with the shareable settings
+    {
+        let hash = url_state::encode(&state_clone);
+        use_effect_with(hash, move |hash| {
+            let hash = hash.clone();
+            let timeout = Timeout::new(URL_SYNC_DELAY_MS, move || url_state::replace_hash(&hash));
+            move || drop(timeout)
+        });
+    }
+
+    // Follow a link opened in this tab, whether pasted in or reached with
+    // the back button; the app's own hash updates do not fire this
+    {
+        let dispatch = dispatch.clone();
+        use_effect_with((), move |_| {
+            let window = web_sys::window().expect("no window");
+            let listener = EventListener::new(&window, "hashchange", move |_| {
+                for action in url_state::decode(&url_state::current_hash()) {
+                    dispatch.emit(action);
+                }
+            });
+            move || drop(listener)
+        });
+    }
+
+    // Local sky mode needs the observer's location; ask the browser once
+    // per switch into the mode, unless it is already known
+    {
+        let dispatch = dispatch.clone();
+        let wants_location = state_clone.view_mode == ViewMode::LocalSky
+            && state_clone.preferences.observer.is_none();
+        use_effect_with(state_clone.view_mode, move |_| {
+            if wants_location {
+                request_location(dispatch);
+            }
+        });
+    }
+
+    // A daily-challenge link starts today's challenge, once the catalog
+    // has settled
     {
+        let on_action = on_action.clone();
+        let daily_challenge = state_clone.daily_challenge;
+        let opened = use_mut_ref(|| false);
+        use_effect_with(catalog_status.is_loading(), move |loading| {
+            if daily_challenge && !*loading && !opened.replace(true) {
+                on_action.emit(GameAction::PlayDailyChallenge);
+            }
+        });
+    }
+
+    // Ctrl+Shift+D toggles the debug overlay
+    let debug_open = use_state(|| false);
+    {
+        let debug_open = debug_open.clone();
+        use_effect_with(*debug_open, move |open| {
+            let open = *open;
+            let window = web_sys::window().expect("no window");
+            let listener = EventListener::new(&window, "keydown", move |event| {
+                let Some(event) = event.dyn_ref::<web_sys::KeyboardEvent>() else {
+                    return;
+                };
+                if event.ctrl_key() && event.shift_key() && event.key().eq_ignore_ascii_case("d") {
+                    event.prevent_default();
+                    debug_open.set(!open);
+                }
+            });
+            move || drop(listener)
+        });
+    }
+
+    // A crash report saved by an earlier session, offered until dismissed
+    let crash_report = use_state(crash::stored_report);
+    let report_copied = use_state(|| false);
+
+    // Arrow keys, +/-, answer numbers, Escape and ? for help
+    use_keyboard_shortcuts(
+        ShortcutContext {
+            quiz: state_clone.quiz.clone(),
+            typed_answers: state_clone.preferences.answer_mode == AnswerMode::Typed,
+            has_selection: state_clone.selected_star.is_some(),
+            settings_open: state_clone.ui.settings_open,
+            trophies_open: state_clone.ui.trophies_open,
+            progress_map_open: state_clone.ui.progress_map_open,
+            summary_shown: state_clone.ui.summary_shown,
+            challenge_results: state_clone
+                .challenge
+                .as_ref()
+                .is_some_and(|c| c.is_complete() && state_clone.quiz.is_none()),
+            run_results: state_clone.mode.is_over() && state_clone.quiz.is_none(),
+            help_shown: state_clone.ui.help_shown,
+        },
+        on_action.clone(),
+    );
+
+    let compact = state_clone.ui.compact_layout;
+    let drawer_toggle = if compact {
         html! {
-            <QuizDropdown
-                quiz={quiz.clone()}
-                position={pos}
-                on_action={on_action.clone()}
-            />
+            <button
+                class="drawer-toggle"
+                onclick={dispatch.reform(|_| GameAction::ToggleDrawer)}
+                title={t(TextKey::ControlsTitle)}
+            >
+                { "☰" }
+            </button>
         }
     } else {
         Html::default()
     };
+    let sidebar_class = classes!(
+        "sidebar",
+        compact.then_some("drawer"),
+        (compact && state_clone.ui.drawer_open).then_some("open"),
+    );
 
-    // Build summary popup if active
-    let summary_panel = if state_clone.ui.summary_shown {
+    let catalog_loading = match catalog_status.progress() {
+        Some(progress) => {
+            let percent = progress.percent();
+            html! {
+                <div class="catalog-loading" role="status">
+                    <div class="spinner"></div>
+                    <span>
+                        { match percent {
+                            Some(percent) => tf(TextKey::LoadingCatalogPercent, &[&percent]),
+                            None => t(TextKey::LoadingCatalog).to_string(),
+                        } }
+                    </span>
+                    if let Some(percent) = percent {
+                        <progress class="catalog-progress" max="100" value={percent.to_string()} />
+                    }
+                </div>
+            }
+        }
+        None => Html::default(),
+    };
+    let catalog_error = match catalog_status.error() {
+        Some(error) => html! {
+            <div class="catalog-error" role="alert">
+                <span class="catalog-error-message">
+                    { tf(TextKey::CatalogError, &[&error]) }
+                </span>
+                <button
+                    class="catalog-retry"
+                    onclick={on_catalog_event.reform(|_| CatalogEvent::Retry)}
+                >
+                    { t(TextKey::Retry) }
+                </button>
+                <button
+                    class="catalog-dismiss"
+                    onclick={on_catalog_event.reform(|_| CatalogEvent::DismissError)}
+                    aria-label={t(TextKey::Dismiss)}
+                    title={t(TextKey::Dismiss)}
+                >
+                    { "×" }
+                </button>
+            </div>
+        },
+        None => Html::default(),
+    };
+    let crash_banner = match &*crash_report {
+        Some(report) => {
+            let on_copy = {
+                let report = report.clone();
+                let report_copied = report_copied.clone();
+                Callback::from(move |_| {
+                    crash::copy_to_clipboard(&report);
+                    report_copied.set(true);
+                })
+            };
+            let on_dismiss = {
+                let crash_report = crash_report.clone();
+                Callback::from(move |_| {
+                    crash::clear_stored_report();
+                    crash_report.set(None);
+                })
+            };
+            html! {
+                <div class="crash-banner" role="status">
+                    <span class="crash-banner-message">{ t(TextKey::CrashBanner) }</span>
+                    <button class="crash-banner-copy" onclick={on_copy}>
+                        { t(if *report_copied { TextKey::Copied } else { TextKey::CopyErrorDetails }) }
+                    </button>
+                    <button
+                        class="crash-banner-dismiss"
+                        onclick={on_dismiss}
+                        aria-label={t(TextKey::Dismiss)}
+                        title={t(TextKey::Dismiss)}
+                    >
+                        { "×" }
+                    </button>
+                </div>
+            }
+        }
+        None => Html::default(),
+    };
+    let demo_badge = if catalog_status.uses_placeholder() {
         html! {
-            <SummaryPopup
-                guesses={state_clone.guess_history.clone()}
-                score={state_clone.score.clone()}
-                on_action={on_action.clone()}
-            />
+            <span class="demo-badge" title={t(TextKey::DemoDataTitle)}>
+                { t(TextKey::DemoData) }
+            </span>
         }
     } else {
         Html::default()
     };
 
-    // ESC key listener to dismiss summary popup
-    {
-        let dispatch = dispatch.clone();
-        let summary_shown = state_clone.ui.summary_shown;
-        use_effect_with(summary_shown, move |_| {
-            let listener = if summary_shown {
-                let window = web_sys::window().expect("no window");
-                Some(EventListener::new(&window, "keydown", move |event| {
-                    let event = event.dyn_ref::<web_sys::KeyboardEvent>().unwrap();
-                    if event.key() == "Escape" {
-                        dispatch.emit(GameAction::HideSummary);
-                    }
-                }))
-            } else {
-                None
-            };
-            move || drop(listener)
-        });
-    }
-
-    html! {
-        <div class="app-container">
-            <a href="https://github.com/wrightmikea/stargazer-poc" class="github-fork-ribbon" target="_blank" rel="noopener noreferrer" title="Fork me on GitHub">
-                <span>{ "Fork me on GitHub" }</span>
+    let page = html! {
+        <div class={classes!("app-container", compact.then_some("compact"))}>
+            <a href="https://github.com/wrightmikea/stargazer-poc" class="github-fork-ribbon" target="_blank" rel="noopener noreferrer" title={t(TextKey::ForkMe)}>
+                <span>{ t(TextKey::ForkMe) }</span>
             </a>
             <header class="app-header">
                 <div class="header-left">
-                    <h1 class="app-title">{ "✦ Stargazer" }</h1>
-                    <p class="app-subtitle">{ "Test your knowledge of night sky" }</p>
+                    <h1 class="app-title">{ "✦ Stargazer" }{ demo_badge }</h1>
+                    <p class="app-subtitle">{ t(TextKey::Subtitle) }</p>
                 </div>
-                <ScoreDisplay score={state_clone.score.clone()} />
+                <ScoreView compact={compact} />
+                { drawer_toggle }
             </header>
 
+            { crash_banner }
+            { catalog_error }
+
             <main class="app-main">
                 <div class="star-map-wrapper">
-                    <div class="star-map-container">
-                        <StarMap
+                    <div class="star-map-container" ref={map_ref.clone()} tabindex="-1">
+                        <MapView
                             catalog={catalog.clone()}
-                            viewport={state_clone.viewport}
-                            magnitude_limit={state_clone.magnitude_limit}
-                            show_grid={state_clone.show_grid}
-                            selected_star={state_clone.selected_star}
+                            index={sky_index.clone()}
                             on_action={on_action.clone()}
                         />
                     </div>
-                    { quiz_panel }
+                    <LegendView />
+                    <CountdownView on_action={on_action.clone()} />
+                    <QuizView
+                        on_action={on_action.clone()}
+                        compact={compact}
+                        return_focus={map_ref.clone()}
+                    />
+                    <StarInfoView catalog={catalog.clone()} on_action={on_action.clone()} />
+                    <TourView catalog={catalog.clone()} on_action={on_action.clone()} />
+                    <StarPanelView catalog={catalog.clone()} />
+                    { catalog_loading }
+                    if *debug_open {
+                        <DebugOverlay
+                            viewport={state_clone.viewport}
+                            catalog_source={catalog_status.source_label()}
+                        />
+                    }
                 </div>
 
-                <aside class="sidebar">
-                    <Controls
-                        zoom={state_clone.viewport.zoom}
-                        magnitude_limit={state_clone.magnitude_limit}
-                        show_grid={state_clone.show_grid}
+                <aside class={sidebar_class}>
+                    <ControlsView
                         on_action={on_action.clone()}
+                        on_export={on_export}
+                        on_export_progress={on_export_progress}
+                        on_import_progress={on_import_progress}
                     />
-                    { summary_panel }
+                    <SummaryView catalog={catalog.clone()} on_action={on_action.clone()} />
                 </aside>
+                <SettingsView on_action={on_action.clone()} />
+                <TrophiesView on_action={on_action.clone()} />
+                <ProgressMapView
+                    catalog={catalog.clone()}
+                    index={sky_index.clone()}
+                    on_action={on_action.clone()}
+                />
+                <ChallengeView on_action={on_action.clone()} />
+                <RunView on_action={on_action.clone()} />
+                <ToastView on_action={dispatch.clone()} />
+                <HelpView on_action={on_action.clone()} />
             </main>
 
             <footer class="app-footer">
@@ -198,12 +1061,15 @@ pub fn app() -> Html {
                         <span class="separator">{ "•" }</span>
                         <span class="license">{ "MIT License" }</span>
                         <span class="separator">{ "•" }</span>
-                        <span class="build-info">{ format!("Build: 2025-12-30T10:55-08:00 • SHA: 7e39ace") }</span>
+                        <span class="build-info">{ build_info().footer_text() }</span>
                     </p>
                 </div>
             </footer>
         </div>
-    }
+    };
+
+    // Each part of the page follows only its slice of the state
+    Slices::of(&state_clone).provide(page)
 }
 
 // Required for use_reducer
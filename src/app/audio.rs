@@ -0,0 +1,303 @@
+//! Sound cues for answers
+//!
+//! Tones are synthesized with the Web Audio API, so no audio files ship
+//! with the app. Browsers only let a page start audio in response to a
+//! user gesture, so the `AudioContext` is created (or resumed) by
+//! [`unlock`], which the app calls from gesture handlers while sound is
+//! enabled; until then [`play`] does nothing. Pages without Web Audio
+//! never get a context and stay silent.
+//!
+//! What each cue sounds like is plain data in [`Tone`], so it can be
+//! checked without a browser.
+
+use std::cell::RefCell;
+use wasm_bindgen::JsValue;
+use web_sys::{AudioContext, AudioContextState, OscillatorType};
+
+/// A correct answer that brings the streak to a multiple of this is a milestone
+pub const MILESTONE_STREAK: u32 = 5;
+
+/// Gain that stands for silence; exponential ramps cannot reach zero
+const SILENT_GAIN: f32 = 0.0001;
+
+/// Something worth a sound
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cue {
+    Correct,
+    Incorrect,
+    /// A streak milestone
+    Milestone,
+}
+
+/// Oscillator shape
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Waveform {
+    Sine,
+    Triangle,
+}
+
+/// One pitch within a tone, timed from the start of the tone
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Note {
+    pub frequency_hz: f32,
+    pub start_s: f64,
+    pub duration_s: f64,
+}
+
+/// How loudness rises and falls over each note
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Envelope {
+    /// Time to reach full volume
+    pub attack_s: f64,
+    /// Full volume, where 1.0 is the output's maximum
+    pub peak_gain: f32,
+}
+
+/// Everything needed to play a cue
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tone {
+    pub waveform: Waveform,
+    pub notes: &'static [Note],
+    pub envelope: Envelope,
+}
+
+const SOFT: Envelope = Envelope {
+    attack_s: 0.01,
+    peak_gain: 0.2,
+};
+
+/// A rising major third
+const CORRECT: Tone = Tone {
+    waveform: Waveform::Sine,
+    notes: &[
+        Note {
+            frequency_hz: 659.25,
+            start_s: 0.0,
+            duration_s: 0.12,
+        },
+        Note {
+            frequency_hz: 830.61,
+            start_s: 0.1,
+            duration_s: 0.18,
+        },
+    ],
+    envelope: SOFT,
+};
+
+/// A falling minor second, lower and duller
+const INCORRECT: Tone = Tone {
+    waveform: Waveform::Triangle,
+    notes: &[
+        Note {
+            frequency_hz: 220.0,
+            start_s: 0.0,
+            duration_s: 0.15,
+        },
+        Note {
+            frequency_hz: 207.65,
+            start_s: 0.14,
+            duration_s: 0.25,
+        },
+    ],
+    envelope: SOFT,
+};
+
+/// A rising major arpeggio
+const MILESTONE: Tone = Tone {
+    waveform: Waveform::Sine,
+    notes: &[
+        Note {
+            frequency_hz: 523.25,
+            start_s: 0.0,
+            duration_s: 0.1,
+        },
+        Note {
+            frequency_hz: 659.25,
+            start_s: 0.09,
+            duration_s: 0.1,
+        },
+        Note {
+            frequency_hz: 783.99,
+            start_s: 0.18,
+            duration_s: 0.1,
+        },
+        Note {
+            frequency_hz: 1046.5,
+            start_s: 0.27,
+            duration_s: 0.3,
+        },
+    ],
+    envelope: SOFT,
+};
+
+impl Cue {
+    pub fn tone(&self) -> Tone {
+        match self {
+            Cue::Correct => CORRECT,
+            Cue::Incorrect => INCORRECT,
+            Cue::Milestone => MILESTONE,
+        }
+    }
+}
+
+impl Tone {
+    /// Seconds from the start of the first note to the end of the last
+    pub fn duration_s(&self) -> f64 {
+        self.notes
+            .iter()
+            .map(|n| n.start_s + n.duration_s)
+            .fold(0.0, f64::max)
+    }
+}
+
+impl Envelope {
+    /// Gain targets for `note` played from `start`: silent, full after
+    /// the attack, then decaying to silence by the note's end
+    pub fn gain_points(&self, note: &Note, start: f64) -> [(f64, f32); 3] {
+        let begin = start + note.start_s;
+        [
+            (begin, SILENT_GAIN),
+            (begin + self.attack_s, self.peak_gain),
+            (begin + note.duration_s, SILENT_GAIN),
+        ]
+    }
+}
+
+/// The cue for an answer, given the streak after it
+pub fn cue_for_answer(correct: bool, streak: u32) -> Cue {
+    match (correct, streak) {
+        (false, _) => Cue::Incorrect,
+        (true, streak) if streak > 0 && streak % MILESTONE_STREAK == 0 => Cue::Milestone,
+        (true, _) => Cue::Correct,
+    }
+}
+
+thread_local! {
+    static CONTEXT: RefCell<Option<AudioContext>> = const { RefCell::new(None) };
+}
+
+/// Whether the page has Web Audio at all
+fn audio_supported() -> bool {
+    web_sys::window().is_some_and(|w| {
+        js_sys::Reflect::has(&w, &JsValue::from_str("AudioContext")).unwrap_or(false)
+    })
+}
+
+/// Create the audio context, or resume it if the browser suspended it
+///
+/// Call only from a user gesture (a click or key press) so the browser
+/// allows the audio to start.
+pub fn unlock() {
+    if !audio_supported() {
+        return;
+    }
+    CONTEXT.with(|context| {
+        let mut context = context.borrow_mut();
+        if context.is_none() {
+            match AudioContext::new() {
+                Ok(created) => *context = Some(created),
+                Err(e) => {
+                    log::warn!("Sound is unavailable: {:?}", e);
+                    return;
+                }
+            }
+        }
+        if let Some(context) = context.as_ref() {
+            if context.state() == AudioContextState::Suspended {
+                let _ = context.resume();
+            }
+        }
+    });
+}
+
+/// Play `cue`, if [`unlock`] has started audio
+pub fn play(cue: Cue) {
+    CONTEXT.with(|context| {
+        if let Some(context) = context.borrow().as_ref() {
+            if context.state() == AudioContextState::Running {
+                if let Err(e) = schedule(context, &cue.tone()) {
+                    log::warn!("Could not play a sound: {:?}", e);
+                }
+            }
+        }
+    });
+}
+
+/// Queue each note of `tone` on `context`, starting now
+fn schedule(context: &AudioContext, tone: &Tone) -> Result<(), JsValue> {
+    let start = context.current_time();
+    for note in tone.notes {
+        let oscillator = context.create_oscillator()?;
+        oscillator.set_type(match tone.waveform {
+            Waveform::Sine => OscillatorType::Sine,
+            Waveform::Triangle => OscillatorType::Triangle,
+        });
+        oscillator
+            .frequency()
+            .set_value_at_time(note.frequency_hz, start + note.start_s)?;
+
+        let gain = context.create_gain()?;
+        let [silent, peak, end] = tone.envelope.gain_points(note, start);
+        gain.gain().set_value_at_time(silent.1, silent.0)?;
+        gain.gain().linear_ramp_to_value_at_time(peak.1, peak.0)?;
+        gain.gain()
+            .exponential_ramp_to_value_at_time(end.1, end.0)?;
+
+        oscillator.connect_with_audio_node(&gain)?;
+        gain.connect_with_audio_node(&context.destination())?;
+        oscillator.start_with_when(silent.0)?;
+        oscillator.stop_with_when(end.0)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CUES: [Cue; 3] = [Cue::Correct, Cue::Incorrect, Cue::Milestone];
+
+    #[test]
+    fn test_tones_are_short_and_quiet() {
+        for cue in CUES {
+            let tone = cue.tone();
+            assert!(!tone.notes.is_empty(), "{:?}", cue);
+            assert!(tone.duration_s() < 1.0, "{:?}", cue);
+            assert!(tone.envelope.peak_gain > 0.0 && tone.envelope.peak_gain <= 0.3);
+            for note in tone.notes {
+                assert!((20.0..4000.0).contains(&note.frequency_hz), "{:?}", cue);
+                assert!(tone.envelope.attack_s < note.duration_s, "{:?}", cue);
+            }
+        }
+    }
+
+    #[test]
+    fn test_correct_rises_and_incorrect_falls() {
+        let pitches =
+            |cue: Cue| -> Vec<f32> { cue.tone().notes.iter().map(|n| n.frequency_hz).collect() };
+        assert!(pitches(Cue::Correct).windows(2).all(|w| w[0] < w[1]));
+        assert!(pitches(Cue::Milestone).windows(2).all(|w| w[0] < w[1]));
+        assert!(pitches(Cue::Incorrect).windows(2).all(|w| w[0] > w[1]));
+        assert!(pitches(Cue::Incorrect)[0] < pitches(Cue::Correct)[0]);
+        assert!(Cue::Milestone.tone().duration_s() > Cue::Correct.tone().duration_s());
+    }
+
+    #[test]
+    fn test_gain_points_follow_the_note() {
+        let note = CORRECT.notes[1];
+        let points = SOFT.gain_points(&note, 2.0);
+        assert_eq!(points[0], (2.1, SILENT_GAIN));
+        assert_eq!(points[1], (2.1 + SOFT.attack_s, SOFT.peak_gain));
+        assert!((points[2].0 - 2.28).abs() < 1e-9);
+        assert!(points.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_streak_milestones() {
+        assert_eq!(cue_for_answer(false, 0), Cue::Incorrect);
+        assert_eq!(cue_for_answer(true, 1), Cue::Correct);
+        assert_eq!(cue_for_answer(true, MILESTONE_STREAK), Cue::Milestone);
+        assert_eq!(cue_for_answer(true, MILESTONE_STREAK + 1), Cue::Correct);
+        assert_eq!(cue_for_answer(true, 2 * MILESTONE_STREAK), Cue::Milestone);
+    }
+}
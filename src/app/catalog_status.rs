@@ -0,0 +1,382 @@
+//! Catalog loading status
+//!
+//! The app starts on the placeholder catalog and fetches the real one in
+//! the background with [`fetch_catalog`], which reports how much has
+//! arrived as it streams in. [`CatalogStatus`] tracks that fetch as a
+//! small state machine driven by [`CatalogEvent`]s; the fetch itself is
+//! wired up in `App`.
+
+use crate::data::formats::{decode_stars, CatalogFormat};
+use crate::data::{Star, StarCatalog};
+use std::fmt;
+use std::rc::Rc;
+use yew::Reducible;
+
+/// Where the catalog is served from, relative to the page
+pub const CATALOG_URL: &str = "stars.json";
+
+/// Bytes between progress reports when the catalog's size is unknown
+const UNSIZED_REPORT_BYTES: usize = 256 * 1024;
+
+/// How much of the catalog has arrived
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadProgress {
+    pub received: usize,
+    /// The catalog's size in bytes, when the server says
+    pub total: Option<usize>,
+}
+
+impl LoadProgress {
+    /// Whole percent received, when the size is known
+    pub fn percent(&self) -> Option<u32> {
+        let total = self.total.filter(|&total| total > 0)?;
+        Some((self.received.min(total) * 100 / total) as u32)
+    }
+
+    /// Whether this is worth showing after `last`: a new whole percent,
+    /// or another [`UNSIZED_REPORT_BYTES`] when the size is unknown
+    pub fn advances(&self, last: &LoadProgress) -> bool {
+        match self.percent() {
+            Some(percent) => last.percent() != Some(percent),
+            None => self.received / UNSIZED_REPORT_BYTES != last.received / UNSIZED_REPORT_BYTES,
+        }
+    }
+}
+
+/// Where the catalog in use came from
+#[derive(Debug, Clone, PartialEq)]
+pub enum CatalogSource {
+    /// Built into the binary with the `embedded-catalog` feature
+    Embedded { stars: usize },
+    /// Fetched from `url`
+    Remote { url: String, stars: usize },
+    /// The built-in demo catalog
+    Placeholder,
+}
+
+impl fmt::Display for CatalogSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CatalogSource::Embedded { stars } => write!(f, "built-in ({} stars)", stars),
+            CatalogSource::Remote { url, stars } => write!(f, "{} ({} stars)", url, stars),
+            CatalogSource::Placeholder => write!(f, "demo data"),
+        }
+    }
+}
+
+/// Progress of loading the catalog
+#[derive(Debug, Clone, PartialEq)]
+pub enum CatalogStatus {
+    Loading {
+        progress: LoadProgress,
+    },
+    Ready {
+        source: CatalogSource,
+    },
+    /// The fetch failed; the placeholder is in use until a retry succeeds
+    Failed {
+        error: String,
+    },
+}
+
+/// Something that happened to the catalog fetch
+#[derive(Debug, Clone, PartialEq)]
+pub enum CatalogEvent {
+    Loaded {
+        url: String,
+        stars: usize,
+    },
+    LoadFailed(String),
+    /// More of the catalog arrived
+    Progress(LoadProgress),
+    /// Fetch again after a failure, or to replace the placeholder
+    Retry,
+    /// Hide the error and carry on with the placeholder
+    DismissError,
+}
+
+impl CatalogStatus {
+    /// A fetch that has not received anything yet
+    pub fn loading() -> CatalogStatus {
+        CatalogStatus::Loading {
+            progress: LoadProgress::default(),
+        }
+    }
+
+    /// The status after `event`; events that do not apply are ignored
+    pub fn next(&self, event: CatalogEvent) -> CatalogStatus {
+        match (self, event) {
+            (CatalogStatus::Loading { .. }, CatalogEvent::Loaded { url, stars }) => {
+                CatalogStatus::Ready {
+                    source: CatalogSource::Remote { url, stars },
+                }
+            }
+            (CatalogStatus::Loading { .. }, CatalogEvent::LoadFailed(error)) => {
+                CatalogStatus::Failed { error }
+            }
+            (CatalogStatus::Loading { .. }, CatalogEvent::Progress(progress)) => {
+                CatalogStatus::Loading { progress }
+            }
+            (
+                CatalogStatus::Failed { .. }
+                | CatalogStatus::Ready {
+                    source: CatalogSource::Placeholder,
+                },
+                CatalogEvent::Retry,
+            ) => CatalogStatus::loading(),
+            (CatalogStatus::Failed { .. }, CatalogEvent::DismissError) => CatalogStatus::Ready {
+                source: CatalogSource::Placeholder,
+            },
+            (status, _) => status.clone(),
+        }
+    }
+
+    pub fn is_loading(&self) -> bool {
+        matches!(self, CatalogStatus::Loading { .. })
+    }
+
+    /// How far the fetch has got, while loading
+    pub fn progress(&self) -> Option<LoadProgress> {
+        match self {
+            CatalogStatus::Loading { progress } => Some(*progress),
+            _ => None,
+        }
+    }
+
+    /// The error to show, if the last fetch failed
+    pub fn error(&self) -> Option<&str> {
+        match self {
+            CatalogStatus::Failed { error } => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Whether the placeholder catalog is on screen
+    pub fn uses_placeholder(&self) -> bool {
+        !matches!(
+            self,
+            CatalogStatus::Ready {
+                source: CatalogSource::Remote { .. } | CatalogSource::Embedded { .. }
+            }
+        )
+    }
+
+    /// Short description of the catalog in use
+    pub fn source_label(&self) -> String {
+        match self {
+            CatalogStatus::Ready { source } => source.to_string(),
+            _ => CatalogSource::Placeholder.to_string(),
+        }
+    }
+}
+
+impl Reducible for CatalogStatus {
+    type Action = CatalogEvent;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        Rc::new(self.next(action))
+    }
+}
+
+/// Build a catalog from a JSON star list
+pub fn catalog_from_json(bytes: &[u8]) -> Result<StarCatalog, String> {
+    catalog_from_stars(decode_stars(bytes, CatalogFormat::Json)?)
+}
+
+/// Build a catalog from loaded stars, refusing an empty list
+pub fn catalog_from_stars(stars: Vec<Star>) -> Result<StarCatalog, String> {
+    if stars.is_empty() {
+        return Err("the catalog has no stars".to_string());
+    }
+    let mut catalog = StarCatalog::new();
+    for star in stars {
+        catalog.add_star(star);
+    }
+    catalog.rebuild_indices();
+    Ok(catalog)
+}
+
+/// Fetch and parse the catalog at `url`, calling `on_progress` as the
+/// download advances
+///
+/// Browsers without streaming response bodies get the whole catalog at
+/// once, with no progress reports.
+pub async fn fetch_catalog(
+    url: &str,
+    on_progress: impl Fn(LoadProgress),
+) -> Result<StarCatalog, String> {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+
+    let describe = |e: wasm_bindgen::JsValue| {
+        e.as_string()
+            .unwrap_or_else(|| format!("could not load {}", url))
+    };
+    let window = web_sys::window().ok_or("no window")?;
+    let response: web_sys::Response = JsFuture::from(window.fetch_with_str(url))
+        .await
+        .map_err(describe)?
+        .dyn_into()
+        .map_err(describe)?;
+    if !response.ok() {
+        return Err(format!(
+            "{} returned {} {}",
+            url,
+            response.status(),
+            response.status_text()
+        ));
+    }
+
+    let total = response
+        .headers()
+        .get("content-length")
+        .ok()
+        .flatten()
+        .and_then(|length| length.parse().ok());
+    let bytes = match response.body() {
+        Some(body) => {
+            // Without options, getReader() gives a default reader
+            let reader: web_sys::ReadableStreamDefaultReader = body.get_reader().unchecked_into();
+            let mut bytes = Vec::with_capacity(total.unwrap_or_default());
+            let mut last = LoadProgress::default();
+            loop {
+                let chunk = JsFuture::from(reader.read()).await.map_err(describe)?;
+                let field = |name: &str| {
+                    js_sys::Reflect::get(&chunk, &wasm_bindgen::JsValue::from_str(name))
+                        .map_err(describe)
+                };
+                if field("done")?.is_truthy() {
+                    break;
+                }
+                bytes.extend(js_sys::Uint8Array::new(&field("value")?).to_vec());
+                let progress = LoadProgress {
+                    received: bytes.len(),
+                    total,
+                };
+                if progress.advances(&last) {
+                    on_progress(progress);
+                    last = progress;
+                }
+            }
+            bytes
+        }
+        None => {
+            let buffer = JsFuture::from(response.array_buffer().map_err(describe)?)
+                .await
+                .map_err(describe)?;
+            js_sys::Uint8Array::new(&buffer).to_vec()
+        }
+    };
+    catalog_from_json(&bytes).map_err(|e| format!("{}: {}", url, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loaded() -> CatalogEvent {
+        CatalogEvent::Loaded {
+            url: CATALOG_URL.to_string(),
+            stars: 9000,
+        }
+    }
+
+    #[test]
+    fn test_load_succeeds() {
+        let status = CatalogStatus::loading();
+        assert!(status.is_loading() && status.uses_placeholder());
+        assert_eq!(status.progress(), Some(LoadProgress::default()));
+
+        let halfway = LoadProgress {
+            received: 512,
+            total: Some(1024),
+        };
+        let status = status.next(CatalogEvent::Progress(halfway));
+        assert_eq!(status.progress(), Some(halfway));
+
+        let status = status.next(loaded());
+        assert!(!status.uses_placeholder());
+        assert_eq!(status.source_label(), "stars.json (9000 stars)");
+    }
+
+    #[test]
+    fn test_failure_retry_and_dismiss() {
+        let failed = CatalogStatus::loading().next(CatalogEvent::LoadFailed("404".into()));
+        assert_eq!(failed.error(), Some("404"));
+        assert!(failed.uses_placeholder());
+
+        // Retrying goes back to loading, and can then succeed
+        let retried = failed.next(CatalogEvent::Retry);
+        assert!(retried.is_loading());
+        assert!(!retried.next(loaded()).uses_placeholder());
+
+        // Dismissing keeps the placeholder, which can still be retried
+        let dismissed = failed.next(CatalogEvent::DismissError);
+        assert_eq!(
+            dismissed,
+            CatalogStatus::Ready {
+                source: CatalogSource::Placeholder
+            }
+        );
+        assert_eq!(dismissed.source_label(), "demo data");
+        assert!(dismissed.next(CatalogEvent::Retry).is_loading());
+    }
+
+    #[test]
+    fn test_embedded_catalog_is_final() {
+        let embedded = CatalogStatus::Ready {
+            source: CatalogSource::Embedded { stars: 9000 },
+        };
+        assert!(!embedded.uses_placeholder());
+        assert_eq!(embedded.source_label(), "built-in (9000 stars)");
+        assert_eq!(embedded.next(CatalogEvent::Retry), embedded);
+    }
+
+    #[test]
+    fn test_stray_events_are_ignored() {
+        let ready = CatalogStatus::loading().next(loaded());
+        assert_eq!(ready.next(CatalogEvent::Retry), ready);
+        assert_eq!(ready.next(CatalogEvent::LoadFailed("late".into())), ready);
+        assert_eq!(
+            ready.next(CatalogEvent::Progress(LoadProgress::default())),
+            ready
+        );
+        assert_eq!(
+            CatalogStatus::loading().next(CatalogEvent::DismissError),
+            CatalogStatus::loading()
+        );
+    }
+
+    #[test]
+    fn test_progress_reports() {
+        let at = |received, total| LoadProgress { received, total };
+        assert_eq!(at(0, Some(1000)).percent(), Some(0));
+        assert_eq!(at(999, Some(1000)).percent(), Some(99));
+        assert_eq!(at(1000, Some(1000)).percent(), Some(100));
+        // A compressed transfer can deliver more than its stated length
+        assert_eq!(at(4000, Some(1000)).percent(), Some(100));
+        assert_eq!(at(10, Some(0)).percent(), None);
+        assert_eq!(at(10, None).percent(), None);
+
+        // Each whole percent is reported once
+        assert!(at(10, Some(1000)).advances(&at(0, Some(1000))));
+        assert!(!at(19, Some(1000)).advances(&at(10, Some(1000))));
+        assert!(at(20, Some(1000)).advances(&at(19, Some(1000))));
+
+        // Without a size, every so many bytes
+        let step = UNSIZED_REPORT_BYTES;
+        assert!(!at(step - 1, None).advances(&at(0, None)));
+        assert!(at(step, None).advances(&at(step - 1, None)));
+    }
+
+    #[test]
+    fn test_catalog_from_json() {
+        let catalog = catalog_from_json(
+            br#"[{"id": 1, "name": "Vega", "coord": {"ra": 18.6, "dec": 38.8}, "magnitude": 0.03, "constellation": "Lyr"}]"#,
+        )
+        .unwrap();
+        assert_eq!(catalog.count(), 1);
+        assert!(catalog_from_json(b"[]").is_err());
+        assert!(catalog_from_json(b"not json").is_err());
+    }
+}
@@ -0,0 +1,180 @@
+//! Recovery from panics
+//!
+//! A panic leaves the wasm module unusable, so instead of a frozen page
+//! the panic hook installed by [`install_panic_hook`] logs the panic,
+//! saves a crash report to localStorage and replaces the page with an
+//! error card. The card is built with plain DOM calls and its buttons run
+//! JavaScript only: neither Yew nor any Rust callback can be trusted once
+//! the module has panicked.
+//!
+//! On the next start the app finds the saved report with
+//! [`stored_report`] and offers to copy it until dismissed.
+
+use crate::app::i18n::{t, TextKey};
+use crate::utils::perf;
+use crate::{build_info, BuildInfo};
+use std::panic::PanicHookInfo;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{HtmlElement, Storage};
+
+/// localStorage key for the last crash report
+pub const CRASH_REPORT_KEY: &str = "stargazer.crash_report";
+
+/// The text of a crash report
+///
+/// `recent_actions` are the last dispatched actions, newest first.
+pub fn crash_report(message: &str, recent_actions: &[String], build: &BuildInfo) -> String {
+    let mut report = format!(
+        "Stargazer {}\n\n{}\n\n",
+        build.footer_text(),
+        message.trim()
+    );
+    if recent_actions.is_empty() {
+        report.push_str("Recent actions: none\n");
+    } else {
+        report.push_str("Recent actions, newest first:\n");
+        for action in recent_actions {
+            report.push_str("- ");
+            report.push_str(action);
+            report.push('\n');
+        }
+    }
+    report
+}
+
+/// Replace the default panic handling with crash recovery
+pub fn install_panic_hook() {
+    std::panic::set_hook(Box::new(on_panic));
+}
+
+fn on_panic(info: &PanicHookInfo) {
+    console_error_panic_hook::hook(info);
+    let report = crash_report(
+        &info.to_string(),
+        &perf::try_recent_actions(),
+        &build_info(),
+    );
+    if let Some(storage) = storage() {
+        let _ = storage.set_item(CRASH_REPORT_KEY, &report);
+    }
+    if let Err(e) = show_error_card(&report) {
+        log::error!("Could not show the error card: {:?}", e);
+    }
+}
+
+fn storage() -> Option<Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// The report saved by the last crash, if it has not been dismissed
+pub fn stored_report() -> Option<String> {
+    storage()?.get_item(CRASH_REPORT_KEY).ok()?
+}
+
+/// Forget the saved report
+pub fn clear_stored_report() {
+    if let Some(storage) = storage() {
+        let _ = storage.remove_item(CRASH_REPORT_KEY);
+    }
+}
+
+/// Copy `text` to the clipboard
+pub fn copy_to_clipboard(text: &str) {
+    if let Some(window) = web_sys::window() {
+        let _ = window.navigator().clipboard().write_text(text);
+    }
+}
+
+/// Add a `tag` element with `class` and `text` to the end of `parent`
+fn append(
+    parent: &HtmlElement,
+    tag: &str,
+    class: &str,
+    text: &str,
+) -> Result<HtmlElement, JsValue> {
+    let document = parent
+        .owner_document()
+        .ok_or_else(|| JsValue::from_str("no document"))?;
+    let element: HtmlElement = document.create_element(tag)?.dyn_into()?;
+    if !class.is_empty() {
+        element.set_class_name(class);
+    }
+    element.set_text_content(Some(text));
+    parent.append_child(&element)?;
+    Ok(element)
+}
+
+/// Replace the page's content with an error card for `report`
+fn show_error_card(report: &str) -> Result<(), JsValue> {
+    let body = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.body())
+        .ok_or_else(|| JsValue::from_str("no page body"))?;
+
+    body.set_text_content(None);
+    let screen = append(&body, "div", "crash-screen", "")?;
+    let card = append(&screen, "div", "crash-card", "")?;
+    card.set_attribute("role", "alert")?;
+    append(&card, "h2", "", t(TextKey::CrashTitle))?;
+    append(&card, "p", "", t(TextKey::CrashMessage))?;
+
+    let actions = append(&card, "div", "crash-actions", "")?;
+    let reload = append(&actions, "button", "crash-reload", t(TextKey::Reload))?;
+    reload.set_onclick(Some(&js_sys::Function::new_no_args("location.reload()")));
+    let copy = append(
+        &actions,
+        "button",
+        "crash-copy",
+        t(TextKey::CopyErrorDetails),
+    )?;
+    copy.set_attribute("data-report", report)?;
+    copy.set_onclick(Some(&js_sys::Function::new_with_args(
+        "event",
+        "navigator.clipboard && navigator.clipboard.writeText(event.currentTarget.dataset.report)",
+    )));
+
+    let details = append(&card, "details", "crash-details", "")?;
+    append(&details, "summary", "", t(TextKey::ErrorDetails))?;
+    append(&details, "pre", "", report)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build() -> BuildInfo {
+        BuildInfo {
+            version: "0.1.0",
+            git_sha: "7e39ace",
+            git_dirty: false,
+            build_time: "2025-12-30T18:55:00Z",
+            profile: "release",
+            target_arch: "wasm32",
+            features: vec!["web"],
+        }
+    }
+
+    #[test]
+    fn test_report_lists_message_and_actions() {
+        let actions = vec!["ZoomIn".to_string(), "SetZoom(2.0)".to_string()];
+        let report = crash_report(
+            "panicked at src/data/tiles.rs:10:5:\nindex out of bounds\n",
+            &actions,
+            &build(),
+        );
+        assert_eq!(
+            report,
+            "Stargazer v0.1.0 • Build: 2025-12-30T18:55:00Z • SHA: 7e39ace\n\n\
+             panicked at src/data/tiles.rs:10:5:\nindex out of bounds\n\n\
+             Recent actions, newest first:\n- ZoomIn\n- SetZoom(2.0)\n"
+        );
+    }
+
+    #[test]
+    fn test_report_without_actions() {
+        let report = crash_report("boom", &[], &build());
+        assert!(report.contains("\n\nboom\n\n"));
+        assert!(report.ends_with("Recent actions: none\n"));
+    }
+}
@@ -0,0 +1,1680 @@
+//! Translated interface text
+//!
+//! Components look up their text with [`t`], or [`tf`] for text with
+//! values filled in, in the language set by [`set_locale`]. `App` sets it
+//! from the saved preference on each render and provides the locale as a
+//! context; components call [`use_locale`] so they re-render when it
+//! changes.
+
+use std::cell::Cell;
+use std::fmt::{Display, Write};
+use yew::prelude::*;
+
+use crate::data::dso::DsoKind;
+use crate::data::star_names::Lang;
+use crate::game::{Achievement, AnswerMode, Difficulty, QuestionKind, TourId};
+use crate::utils::horizon::Cardinal;
+use crate::utils::LabelDensity;
+pub use crate::utils::Locale;
+
+/// A piece of interface text
+///
+/// Texts containing `{}` take values through [`tf`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextKey {
+    // Header and page
+    Subtitle,
+    ForkMe,
+    ControlsTitle,
+    DemoData,
+    DemoDataTitle,
+    LoadingCatalog,
+    /// Takes the percent loaded
+    LoadingCatalogPercent,
+    /// Takes the load error
+    CatalogError,
+    Retry,
+    Dismiss,
+
+    // Crash recovery
+    CrashTitle,
+    CrashMessage,
+    Reload,
+    CopyErrorDetails,
+    ErrorDetails,
+    CrashBanner,
+    Copied,
+
+    // Quiz
+    QuizTitle,
+    QuizTitleDeepSky,
+    QuizTitleAsterism,
+    QuizTitleConstellation,
+    QuizTitleBrighter,
+    CloseQuiz,
+    NoneOfAbove,
+    Correct,
+    Incorrect,
+    /// Takes the correct name
+    AnswerWas,
+    AnnounceCorrect,
+    /// Takes the correct name
+    AnnounceIncorrect,
+
+    // Score
+    Score,
+    Accuracy,
+    Streak,
+    Best,
+
+    // Summary
+    SessionSummary,
+    CloseSummary,
+    TotalQuestions,
+    CorrectCount,
+    IncorrectCount,
+    AccuracyPercent,
+    CurrentStreak,
+    BestStreak,
+    GuessHistory,
+    NoGuesses,
+
+    // Statistics dashboard
+    /// Takes the number of answers averaged
+    AccuracyTrend,
+    MostMissed,
+    /// Takes the wrong answers and all answers
+    MissedCount,
+    ConstellationAccuracy,
+    NoStatsYet,
+
+    // Daily challenge
+    DailyChallenge,
+    DailyChallengeTitle,
+    /// Takes the question number and the number of questions
+    QuestionProgress,
+    NextQuestion,
+    SeeResults,
+    /// Takes the right answers and the number of questions
+    ChallengeScore,
+    CopyResults,
+    CloseResults,
+
+    // Game modes
+    Survival,
+    SurvivalTitle,
+    Lives,
+    OutOfLives,
+    /// Takes the number of questions survived
+    SurvivedScore,
+    PlayAgain,
+    CloseRunResults,
+    TimeAttack,
+    TimeAttackTitle,
+    TimeLeft,
+    TimeUp,
+    /// Takes the right answers and the questions answered
+    TimeAttackScore,
+    Round,
+    RoundTitle,
+    RoundComplete,
+    Grade,
+    Practice,
+    PracticeTitle,
+    /// Takes a constellation's name
+    InConstellation,
+    Tours,
+    // Tour names, and the notes at each stop
+    BrightestStarsTour,
+    WinterHexagonTour,
+    BrightestSiriusNote,
+    BrightestCanopusNote,
+    BrightestRigilKentaurusNote,
+    BrightestArcturusNote,
+    BrightestVegaNote,
+    BrightestCapellaNote,
+    BrightestRigelNote,
+    BrightestProcyonNote,
+    BrightestAchernarNote,
+    BrightestBetelgeuseNote,
+    BrightestHadarNote,
+    BrightestAltairNote,
+    BrightestAcruxNote,
+    BrightestAldebaranNote,
+    BrightestAntaresNote,
+    BrightestSpicaNote,
+    BrightestPolluxNote,
+    BrightestFomalhautNote,
+    BrightestDenebNote,
+    BrightestMimosaNote,
+    HexagonSiriusNote,
+    HexagonRigelNote,
+    HexagonAldebaranNote,
+    HexagonCapellaNote,
+    HexagonPolluxNote,
+    HexagonProcyonNote,
+    HexagonBetelgeuseNote,
+    /// Takes the stop's number and the tour's length
+    TourProgress,
+    EndTour,
+    PreviousStop,
+    NextStop,
+    AboutStar,
+    /// Takes an MK spectral type
+    SpectralType,
+    /// Takes a distance in light-years
+    DistanceLy,
+    /// Takes a multiple of the Sun's luminosity
+    Luminosity,
+    Points,
+    Hint,
+    HintTitle,
+    ReviewMistakes,
+    ReviewMistakesTitle,
+    MistakesReviewed,
+    /// Takes the stars missed and the answers it took to name them
+    MistakesScore,
+
+    // Achievements
+    Trophies,
+    TrophiesTitle,
+    CloseTrophies,
+    ProgressMap,
+    ProgressMapTitle,
+    CloseProgressMap,
+    ProgressMapHint,
+    RegionMastery,
+    Untouched,
+    Shaky,
+    Learned,
+    /// Takes the achievement names
+    AchievementUnlocked,
+    /// Takes the date
+    EarnedOn,
+    NotYetEarned,
+    FirstCorrect,
+    FirstCorrectGoal,
+    StreakAchievement,
+    /// Takes the streak length
+    StreakGoal,
+    OrionMastered,
+    OrionMasteredGoal,
+    HundredQuestions,
+    /// Takes the number of questions
+    HundredQuestionsGoal,
+    ResetAndStartOver,
+    Close,
+
+    // Controls
+    Zoom,
+    ZoomIn,
+    ZoomOut,
+    ResetView,
+    StarBrightness,
+    /// Takes the magnitude limit
+    MagnitudeHint,
+    /// Takes the estimated star count
+    StarCount,
+    Bright,
+    Faint,
+    StarSize,
+    Display,
+    Grid,
+    Figures,
+    FiguresTitle,
+    DeepSky,
+    DeepSkyTitle,
+    Asterisms,
+    AsterismsTitle,
+    StarInfo,
+    StarInfoTitle,
+    Sound,
+    SoundTitle,
+    Labels,
+    LabelsOff,
+    LabelsSparse,
+    LabelsNormal,
+    LabelsDense,
+    Language,
+    ExportImage,
+    Progress,
+    ExportProgress,
+    ExportProgressTitle,
+    ImportProgress,
+    ImportProgressTitle,
+    ProgressImported,
+    ProgressImportFailed,
+    NoStarsToAsk,
+    ExportSvgTitle,
+    ExportPngTitle,
+    Done,
+    HelpPanZoom,
+    HelpClickBefore,
+    HelpBrightStar,
+    HelpClickAfter,
+
+    // Local sky
+    Sky,
+    WholeSky,
+    Tonight,
+    TonightTitle,
+    Latitude,
+    Longitude,
+    SetLocation,
+    LocationHint,
+    LocationInvalid,
+    UseMyLocation,
+    UseMyLocationTitle,
+    HideBelowHorizon,
+    HideBelowHorizonTitle,
+    ObservationTime,
+    TimeOfDay,
+    Play,
+    Pause,
+    TimeLapseTitle,
+    Now,
+    NowTitle,
+    North,
+    East,
+    South,
+    West,
+    /// Takes the star's name
+    BelowHorizon,
+
+    // Map projection
+    Projection,
+    Flat,
+    FlatTitle,
+    Stereographic,
+    StereographicTitle,
+
+    // Map renderer
+    Renderer,
+    SvgRendererTitle,
+    Canvas,
+    CanvasRendererTitle,
+
+    // Keyboard shortcuts help
+    KeyboardShortcuts,
+    CloseHelp,
+    ShortcutPan,
+    ShortcutZoom,
+    ShortcutAnswer,
+    ShortcutClose,
+    ShortcutHelp,
+
+    // Quiz settings
+    QuizSettings,
+    QuizSettingsTitle,
+    CloseSettings,
+    Choices,
+    WrongChoices,
+    NearbyStars,
+    LookAlikeNames,
+    ChoiceNames,
+    ProperNames,
+    ProperNamesTitle,
+    BayerNames,
+    BayerNamesTitle,
+    AskFor,
+    AskName,
+    AskNameTitle,
+    AskAsterism,
+    AskAsterismTitle,
+    AskConstellation,
+    AskConstellationTitle,
+    AskBrighter,
+    AskBrighterTitle,
+    LabelNames,
+    IauNames,
+    IauNamesTitle,
+    ArabicNames,
+    ArabicNamesTitle,
+    ChineseNames,
+    ChineseNamesTitle,
+    /// Takes the chance as a whole percentage
+    NoneOfAboveChance,
+    Difficulty,
+    AllStars,
+    Easy,
+    Medium,
+    Hard,
+    Answers,
+    PickAnswer,
+    TypeAnswer,
+
+    // Typed answers
+    StarName,
+    CheckAnswer,
+    /// Takes the name as it is spelled
+    CloseEnough,
+
+    // Legend and star info
+    Legend,
+    MapLegend,
+    NamedStar,
+    UnnamedStar,
+    StarColors,
+    /// Takes the magnitude
+    Magnitude,
+    RaDecGrid,
+    CelestialEquator,
+    /// Takes right ascension in hours and declination in degrees
+    StarPosition,
+    /// Takes the neighbor's name and its distance in degrees
+    NearNeighbor,
+
+    // Deep-sky objects
+    Galaxy,
+    GlobularCluster,
+    OpenCluster,
+    Nebula,
+    PlanetaryNebula,
+    SupernovaRemnant,
+    Asterism,
+}
+
+impl TextKey {
+    /// Every key, for checking the translations
+    pub const ALL: [TextKey; 286] = [
+        TextKey::Subtitle,
+        TextKey::ForkMe,
+        TextKey::ControlsTitle,
+        TextKey::DemoData,
+        TextKey::DemoDataTitle,
+        TextKey::LoadingCatalog,
+        TextKey::LoadingCatalogPercent,
+        TextKey::CatalogError,
+        TextKey::Retry,
+        TextKey::Dismiss,
+        TextKey::CrashTitle,
+        TextKey::CrashMessage,
+        TextKey::Reload,
+        TextKey::CopyErrorDetails,
+        TextKey::ErrorDetails,
+        TextKey::CrashBanner,
+        TextKey::Copied,
+        TextKey::QuizTitle,
+        TextKey::QuizTitleDeepSky,
+        TextKey::QuizTitleAsterism,
+        TextKey::QuizTitleConstellation,
+        TextKey::QuizTitleBrighter,
+        TextKey::CloseQuiz,
+        TextKey::NoneOfAbove,
+        TextKey::Correct,
+        TextKey::Incorrect,
+        TextKey::AnswerWas,
+        TextKey::AnnounceCorrect,
+        TextKey::AnnounceIncorrect,
+        TextKey::Score,
+        TextKey::Accuracy,
+        TextKey::Streak,
+        TextKey::Best,
+        TextKey::SessionSummary,
+        TextKey::CloseSummary,
+        TextKey::TotalQuestions,
+        TextKey::CorrectCount,
+        TextKey::IncorrectCount,
+        TextKey::AccuracyPercent,
+        TextKey::CurrentStreak,
+        TextKey::BestStreak,
+        TextKey::GuessHistory,
+        TextKey::NoGuesses,
+        TextKey::AccuracyTrend,
+        TextKey::MostMissed,
+        TextKey::MissedCount,
+        TextKey::ConstellationAccuracy,
+        TextKey::NoStatsYet,
+        TextKey::DailyChallenge,
+        TextKey::DailyChallengeTitle,
+        TextKey::QuestionProgress,
+        TextKey::NextQuestion,
+        TextKey::SeeResults,
+        TextKey::ChallengeScore,
+        TextKey::CopyResults,
+        TextKey::CloseResults,
+        TextKey::Survival,
+        TextKey::SurvivalTitle,
+        TextKey::Lives,
+        TextKey::OutOfLives,
+        TextKey::SurvivedScore,
+        TextKey::PlayAgain,
+        TextKey::CloseRunResults,
+        TextKey::TimeAttack,
+        TextKey::TimeAttackTitle,
+        TextKey::TimeLeft,
+        TextKey::TimeUp,
+        TextKey::TimeAttackScore,
+        TextKey::Round,
+        TextKey::RoundTitle,
+        TextKey::RoundComplete,
+        TextKey::Grade,
+        TextKey::Practice,
+        TextKey::PracticeTitle,
+        TextKey::InConstellation,
+        TextKey::Tours,
+        TextKey::BrightestStarsTour,
+        TextKey::WinterHexagonTour,
+        TextKey::BrightestSiriusNote,
+        TextKey::BrightestCanopusNote,
+        TextKey::BrightestRigilKentaurusNote,
+        TextKey::BrightestArcturusNote,
+        TextKey::BrightestVegaNote,
+        TextKey::BrightestCapellaNote,
+        TextKey::BrightestRigelNote,
+        TextKey::BrightestProcyonNote,
+        TextKey::BrightestAchernarNote,
+        TextKey::BrightestBetelgeuseNote,
+        TextKey::BrightestHadarNote,
+        TextKey::BrightestAltairNote,
+        TextKey::BrightestAcruxNote,
+        TextKey::BrightestAldebaranNote,
+        TextKey::BrightestAntaresNote,
+        TextKey::BrightestSpicaNote,
+        TextKey::BrightestPolluxNote,
+        TextKey::BrightestFomalhautNote,
+        TextKey::BrightestDenebNote,
+        TextKey::BrightestMimosaNote,
+        TextKey::HexagonSiriusNote,
+        TextKey::HexagonRigelNote,
+        TextKey::HexagonAldebaranNote,
+        TextKey::HexagonCapellaNote,
+        TextKey::HexagonPolluxNote,
+        TextKey::HexagonProcyonNote,
+        TextKey::HexagonBetelgeuseNote,
+        TextKey::TourProgress,
+        TextKey::EndTour,
+        TextKey::PreviousStop,
+        TextKey::NextStop,
+        TextKey::AboutStar,
+        TextKey::SpectralType,
+        TextKey::DistanceLy,
+        TextKey::Luminosity,
+        TextKey::Points,
+        TextKey::Hint,
+        TextKey::HintTitle,
+        TextKey::ReviewMistakes,
+        TextKey::ReviewMistakesTitle,
+        TextKey::MistakesReviewed,
+        TextKey::MistakesScore,
+        TextKey::Trophies,
+        TextKey::TrophiesTitle,
+        TextKey::CloseTrophies,
+        TextKey::ProgressMap,
+        TextKey::ProgressMapTitle,
+        TextKey::CloseProgressMap,
+        TextKey::ProgressMapHint,
+        TextKey::RegionMastery,
+        TextKey::Untouched,
+        TextKey::Shaky,
+        TextKey::Learned,
+        TextKey::AchievementUnlocked,
+        TextKey::EarnedOn,
+        TextKey::NotYetEarned,
+        TextKey::FirstCorrect,
+        TextKey::FirstCorrectGoal,
+        TextKey::StreakAchievement,
+        TextKey::StreakGoal,
+        TextKey::OrionMastered,
+        TextKey::OrionMasteredGoal,
+        TextKey::HundredQuestions,
+        TextKey::HundredQuestionsGoal,
+        TextKey::ResetAndStartOver,
+        TextKey::Close,
+        TextKey::Zoom,
+        TextKey::ZoomIn,
+        TextKey::ZoomOut,
+        TextKey::ResetView,
+        TextKey::StarBrightness,
+        TextKey::MagnitudeHint,
+        TextKey::StarCount,
+        TextKey::Bright,
+        TextKey::Faint,
+        TextKey::StarSize,
+        TextKey::Display,
+        TextKey::Grid,
+        TextKey::Figures,
+        TextKey::FiguresTitle,
+        TextKey::DeepSky,
+        TextKey::DeepSkyTitle,
+        TextKey::Asterisms,
+        TextKey::AsterismsTitle,
+        TextKey::StarInfo,
+        TextKey::StarInfoTitle,
+        TextKey::Sound,
+        TextKey::SoundTitle,
+        TextKey::Labels,
+        TextKey::LabelsOff,
+        TextKey::LabelsSparse,
+        TextKey::LabelsNormal,
+        TextKey::LabelsDense,
+        TextKey::Language,
+        TextKey::ExportImage,
+        TextKey::Progress,
+        TextKey::ExportProgress,
+        TextKey::ExportProgressTitle,
+        TextKey::ImportProgress,
+        TextKey::ImportProgressTitle,
+        TextKey::ProgressImported,
+        TextKey::ProgressImportFailed,
+        TextKey::NoStarsToAsk,
+        TextKey::ExportSvgTitle,
+        TextKey::ExportPngTitle,
+        TextKey::Done,
+        TextKey::HelpPanZoom,
+        TextKey::HelpClickBefore,
+        TextKey::HelpBrightStar,
+        TextKey::HelpClickAfter,
+        TextKey::Sky,
+        TextKey::WholeSky,
+        TextKey::Tonight,
+        TextKey::TonightTitle,
+        TextKey::Latitude,
+        TextKey::Longitude,
+        TextKey::SetLocation,
+        TextKey::LocationHint,
+        TextKey::LocationInvalid,
+        TextKey::UseMyLocation,
+        TextKey::UseMyLocationTitle,
+        TextKey::HideBelowHorizon,
+        TextKey::HideBelowHorizonTitle,
+        TextKey::ObservationTime,
+        TextKey::TimeOfDay,
+        TextKey::Play,
+        TextKey::Pause,
+        TextKey::TimeLapseTitle,
+        TextKey::Now,
+        TextKey::NowTitle,
+        TextKey::North,
+        TextKey::East,
+        TextKey::South,
+        TextKey::West,
+        TextKey::BelowHorizon,
+        TextKey::Projection,
+        TextKey::Flat,
+        TextKey::FlatTitle,
+        TextKey::Stereographic,
+        TextKey::StereographicTitle,
+        TextKey::Renderer,
+        TextKey::SvgRendererTitle,
+        TextKey::Canvas,
+        TextKey::CanvasRendererTitle,
+        TextKey::KeyboardShortcuts,
+        TextKey::CloseHelp,
+        TextKey::ShortcutPan,
+        TextKey::ShortcutZoom,
+        TextKey::ShortcutAnswer,
+        TextKey::ShortcutClose,
+        TextKey::ShortcutHelp,
+        TextKey::QuizSettings,
+        TextKey::QuizSettingsTitle,
+        TextKey::CloseSettings,
+        TextKey::Choices,
+        TextKey::WrongChoices,
+        TextKey::NearbyStars,
+        TextKey::LookAlikeNames,
+        TextKey::ChoiceNames,
+        TextKey::ProperNames,
+        TextKey::ProperNamesTitle,
+        TextKey::BayerNames,
+        TextKey::BayerNamesTitle,
+        TextKey::AskFor,
+        TextKey::AskName,
+        TextKey::AskNameTitle,
+        TextKey::AskAsterism,
+        TextKey::AskAsterismTitle,
+        TextKey::AskConstellation,
+        TextKey::AskConstellationTitle,
+        TextKey::AskBrighter,
+        TextKey::AskBrighterTitle,
+        TextKey::LabelNames,
+        TextKey::IauNames,
+        TextKey::IauNamesTitle,
+        TextKey::ArabicNames,
+        TextKey::ArabicNamesTitle,
+        TextKey::ChineseNames,
+        TextKey::ChineseNamesTitle,
+        TextKey::NoneOfAboveChance,
+        TextKey::Difficulty,
+        TextKey::AllStars,
+        TextKey::Easy,
+        TextKey::Medium,
+        TextKey::Hard,
+        TextKey::Answers,
+        TextKey::PickAnswer,
+        TextKey::TypeAnswer,
+        TextKey::StarName,
+        TextKey::CheckAnswer,
+        TextKey::CloseEnough,
+        TextKey::Legend,
+        TextKey::MapLegend,
+        TextKey::NamedStar,
+        TextKey::UnnamedStar,
+        TextKey::StarColors,
+        TextKey::Magnitude,
+        TextKey::RaDecGrid,
+        TextKey::CelestialEquator,
+        TextKey::StarPosition,
+        TextKey::NearNeighbor,
+        TextKey::Galaxy,
+        TextKey::GlobularCluster,
+        TextKey::OpenCluster,
+        TextKey::Nebula,
+        TextKey::PlanetaryNebula,
+        TextKey::SupernovaRemnant,
+        TextKey::Asterism,
+    ];
+
+    /// The name of a kind of deep-sky object
+    pub fn for_dso_kind(kind: DsoKind) -> Self {
+        match kind {
+            DsoKind::Galaxy => TextKey::Galaxy,
+            DsoKind::GlobularCluster => TextKey::GlobularCluster,
+            DsoKind::OpenCluster => TextKey::OpenCluster,
+            DsoKind::Nebula => TextKey::Nebula,
+            DsoKind::PlanetaryNebula => TextKey::PlanetaryNebula,
+            DsoKind::SupernovaRemnant => TextKey::SupernovaRemnant,
+            DsoKind::Asterism => TextKey::Asterism,
+        }
+    }
+
+    /// The name and title of a question kind's button
+    pub fn for_question_kind(kind: QuestionKind) -> (Self, Self) {
+        match kind {
+            QuestionKind::Name => (TextKey::AskName, TextKey::AskNameTitle),
+            QuestionKind::Asterism => (TextKey::AskAsterism, TextKey::AskAsterismTitle),
+            QuestionKind::Constellation => {
+                (TextKey::AskConstellation, TextKey::AskConstellationTitle)
+            }
+            QuestionKind::Brighter => (TextKey::AskBrighter, TextKey::AskBrighterTitle),
+        }
+    }
+
+    /// The name and title of a star naming tradition's button
+    pub fn for_name_lang(lang: Lang) -> (Self, Self) {
+        match lang {
+            Lang::Iau => (TextKey::IauNames, TextKey::IauNamesTitle),
+            Lang::Arabic => (TextKey::ArabicNames, TextKey::ArabicNamesTitle),
+            Lang::Chinese => (TextKey::ChineseNames, TextKey::ChineseNamesTitle),
+        }
+    }
+
+    /// The short label of a compass point
+    pub fn for_cardinal(cardinal: Cardinal) -> Self {
+        match cardinal {
+            Cardinal::North => TextKey::North,
+            Cardinal::East => TextKey::East,
+            Cardinal::South => TextKey::South,
+            Cardinal::West => TextKey::West,
+        }
+    }
+
+    /// The name of a label density setting
+    pub fn for_density(density: LabelDensity) -> Self {
+        match density {
+            LabelDensity::Off => TextKey::LabelsOff,
+            LabelDensity::Sparse => TextKey::LabelsSparse,
+            LabelDensity::Normal => TextKey::LabelsNormal,
+            LabelDensity::Dense => TextKey::LabelsDense,
+        }
+    }
+
+    /// The name of a difficulty setting, where `None` quizzes every star
+    pub fn for_difficulty(difficulty: Option<Difficulty>) -> Self {
+        match difficulty {
+            None => TextKey::AllStars,
+            Some(Difficulty::Easy) => TextKey::Easy,
+            Some(Difficulty::Medium) => TextKey::Medium,
+            Some(Difficulty::Hard) => TextKey::Hard,
+        }
+    }
+
+    /// The name of an answer mode
+    pub fn for_answer_mode(mode: AnswerMode) -> Self {
+        match mode {
+            AnswerMode::Choices => TextKey::PickAnswer,
+            AnswerMode::Typed => TextKey::TypeAnswer,
+        }
+    }
+
+    /// The name of a tour
+    pub fn for_tour(tour: TourId) -> Self {
+        match tour {
+            TourId::BrightestStars => TextKey::BrightestStarsTour,
+            TourId::WinterHexagon => TextKey::WinterHexagonTour,
+        }
+    }
+
+    /// What a tour says at its stop at `star`
+    pub fn tour_note(tour: TourId, star: &str) -> Option<Self> {
+        let key = match (tour, star) {
+            (TourId::BrightestStars, "Sirius") => TextKey::BrightestSiriusNote,
+            (TourId::BrightestStars, "Canopus") => TextKey::BrightestCanopusNote,
+            (TourId::BrightestStars, "Rigil Kentaurus") => TextKey::BrightestRigilKentaurusNote,
+            (TourId::BrightestStars, "Arcturus") => TextKey::BrightestArcturusNote,
+            (TourId::BrightestStars, "Vega") => TextKey::BrightestVegaNote,
+            (TourId::BrightestStars, "Capella") => TextKey::BrightestCapellaNote,
+            (TourId::BrightestStars, "Rigel") => TextKey::BrightestRigelNote,
+            (TourId::BrightestStars, "Procyon") => TextKey::BrightestProcyonNote,
+            (TourId::BrightestStars, "Achernar") => TextKey::BrightestAchernarNote,
+            (TourId::BrightestStars, "Betelgeuse") => TextKey::BrightestBetelgeuseNote,
+            (TourId::BrightestStars, "Hadar") => TextKey::BrightestHadarNote,
+            (TourId::BrightestStars, "Altair") => TextKey::BrightestAltairNote,
+            (TourId::BrightestStars, "Acrux") => TextKey::BrightestAcruxNote,
+            (TourId::BrightestStars, "Aldebaran") => TextKey::BrightestAldebaranNote,
+            (TourId::BrightestStars, "Antares") => TextKey::BrightestAntaresNote,
+            (TourId::BrightestStars, "Spica") => TextKey::BrightestSpicaNote,
+            (TourId::BrightestStars, "Pollux") => TextKey::BrightestPolluxNote,
+            (TourId::BrightestStars, "Fomalhaut") => TextKey::BrightestFomalhautNote,
+            (TourId::BrightestStars, "Deneb") => TextKey::BrightestDenebNote,
+            (TourId::BrightestStars, "Mimosa") => TextKey::BrightestMimosaNote,
+            (TourId::WinterHexagon, "Sirius") => TextKey::HexagonSiriusNote,
+            (TourId::WinterHexagon, "Rigel") => TextKey::HexagonRigelNote,
+            (TourId::WinterHexagon, "Aldebaran") => TextKey::HexagonAldebaranNote,
+            (TourId::WinterHexagon, "Capella") => TextKey::HexagonCapellaNote,
+            (TourId::WinterHexagon, "Pollux") => TextKey::HexagonPolluxNote,
+            (TourId::WinterHexagon, "Procyon") => TextKey::HexagonProcyonNote,
+            (TourId::WinterHexagon, "Betelgeuse") => TextKey::HexagonBetelgeuseNote,
+            _ => return None,
+        };
+        Some(key)
+    }
+
+    /// The name of an achievement
+    pub fn for_achievement(achievement: Achievement) -> Self {
+        match achievement {
+            Achievement::FirstCorrect => TextKey::FirstCorrect,
+            Achievement::Streak => TextKey::StreakAchievement,
+            Achievement::OrionMastered => TextKey::OrionMastered,
+            Achievement::HundredQuestions => TextKey::HundredQuestions,
+        }
+    }
+
+    /// What an achievement asks for; some take the number it asks for
+    pub fn achievement_goal(achievement: Achievement) -> Self {
+        match achievement {
+            Achievement::FirstCorrect => TextKey::FirstCorrectGoal,
+            Achievement::Streak => TextKey::StreakGoal,
+            Achievement::OrionMastered => TextKey::OrionMasteredGoal,
+            Achievement::HundredQuestions => TextKey::HundredQuestionsGoal,
+        }
+    }
+}
+
+/// The text for `key` in `locale`
+pub fn text(locale: Locale, key: TextKey) -> &'static str {
+    match locale {
+        Locale::English => english(key),
+        Locale::Spanish => spanish(key),
+    }
+}
+
+fn english(key: TextKey) -> &'static str {
+    match key {
+        TextKey::Subtitle => "Test your knowledge of night sky",
+        TextKey::ForkMe => "Fork me on GitHub",
+        TextKey::ControlsTitle => "Controls",
+        TextKey::DemoData => "Demo data",
+        TextKey::DemoDataTitle => "A small built-in catalog is shown in place of the full one",
+        TextKey::LoadingCatalog => "Loading star catalog…",
+        TextKey::LoadingCatalogPercent => "Loading star catalog… {}%",
+        TextKey::CatalogError => "Could not load the star catalog ({}). Showing demo data.",
+        TextKey::Retry => "Retry",
+        TextKey::Dismiss => "Dismiss",
+        TextKey::CrashTitle => "Something went wrong",
+        TextKey::CrashMessage => {
+            "Stargazer hit an error and stopped. Reloading the page starts it again."
+        }
+        TextKey::Reload => "Reload",
+        TextKey::CopyErrorDetails => "Copy error details",
+        TextKey::ErrorDetails => "Error details",
+        TextKey::CrashBanner => "The last session ended with an error.",
+        TextKey::Copied => "Copied",
+        TextKey::QuizTitle => "What star is this?",
+        TextKey::QuizTitleDeepSky => "What object is this?",
+        TextKey::QuizTitleAsterism => "Which asterism is this star part of?",
+        TextKey::QuizTitleConstellation => "Which constellation is this star in?",
+        TextKey::QuizTitleBrighter => "Which of the two ringed stars is brighter?",
+        TextKey::CloseQuiz => "Close quiz",
+        TextKey::NoneOfAbove => "none of above",
+        TextKey::Correct => "Correct!",
+        TextKey::Incorrect => "Incorrect",
+        TextKey::AnswerWas => "The answer was: {}",
+        TextKey::AnnounceCorrect => "Correct",
+        TextKey::AnnounceIncorrect => "Incorrect, the answer was {}",
+        TextKey::Score => "Score",
+        TextKey::Accuracy => "Accuracy",
+        TextKey::Streak => "Streak",
+        TextKey::Best => "Best",
+        TextKey::SessionSummary => "Session Summary",
+        TextKey::CloseSummary => "Close summary",
+        TextKey::TotalQuestions => "Total Questions:",
+        TextKey::CorrectCount => "Correct:",
+        TextKey::IncorrectCount => "Incorrect:",
+        TextKey::AccuracyPercent => "Accuracy:",
+        TextKey::CurrentStreak => "Streak:",
+        TextKey::BestStreak => "Best Streak:",
+        TextKey::GuessHistory => "Guess History",
+        TextKey::NoGuesses => "No guesses yet! Start quizzing some stars.",
+        TextKey::AccuracyTrend => "Accuracy, last {} answers",
+        TextKey::MostMissed => "Most missed stars",
+        TextKey::MissedCount => "{} of {} wrong",
+        TextKey::ConstellationAccuracy => "Accuracy by constellation",
+        TextKey::NoStatsYet => "Answer a few questions to see your statistics.",
+        TextKey::DailyChallenge => "Daily Challenge",
+        TextKey::DailyChallengeTitle => "Ten questions, the same for everyone today",
+        TextKey::QuestionProgress => "Question {} of {}",
+        TextKey::NextQuestion => "Next",
+        TextKey::SeeResults => "See results",
+        TextKey::ChallengeScore => "{} of {} right",
+        TextKey::CopyResults => "Copy results",
+        TextKey::CloseResults => "Close challenge results",
+        TextKey::Survival => "Survival",
+        TextKey::SurvivalTitle => "Three lives: each wrong answer costs one",
+        TextKey::Lives => "Lives",
+        TextKey::OutOfLives => "Out of lives",
+        TextKey::SurvivedScore => "{} questions survived",
+        TextKey::PlayAgain => "Play again",
+        TextKey::CloseRunResults => "Close run results",
+        TextKey::TimeAttack => "Time attack",
+        TextKey::TimeAttackTitle => "Answer as many questions as you can before the clock runs out",
+        TextKey::TimeLeft => "Time left",
+        TextKey::TimeUp => "Time's up",
+        TextKey::TimeAttackScore => "{} right of {} answered",
+        TextKey::Round => "10-question round",
+        TextKey::RoundTitle => "Ten random stars, one after another, graded at the end",
+        TextKey::RoundComplete => "Round complete",
+        TextKey::Grade => "Grade",
+        TextKey::Practice => "Practice",
+        TextKey::PracticeTitle => {
+            "Study without a score: clicking a named star shows its name, constellation and magnitude instead of asking"
+        }
+        TextKey::InConstellation => "In {}",
+        TextKey::Tours => "Tours",
+        TextKey::BrightestStarsTour => "20 brightest stars",
+        TextKey::WinterHexagonTour => "Winter Hexagon",
+        TextKey::BrightestSiriusNote => {
+            "The brightest star in the night sky, 8.6 light-years away in Canis Major."
+        }
+        TextKey::BrightestCanopusNote => {
+            "The second brightest, a white giant far to the south in Carina."
+        }
+        TextKey::BrightestRigilKentaurusNote => {
+            "The nearest star system to the Sun, 4.4 light-years away."
+        }
+        TextKey::BrightestArcturusNote => {
+            "An orange giant, the brightest star north of the celestial equator."
+        }
+        TextKey::BrightestVegaNote => {
+            "The pole star some 12,000 years ago, and again 12,000 years from now."
+        }
+        TextKey::BrightestCapellaNote => {
+            "Two yellow giants orbiting each other, overhead on northern winter evenings."
+        }
+        TextKey::BrightestRigelNote => {
+            "A blue supergiant at Orion's foot, tens of thousands of times as luminous as the Sun."
+        }
+        TextKey::BrightestProcyonNote => "The Little Dog Star, with a white dwarf companion.",
+        TextKey::BrightestAchernarNote => {
+            "Spins so fast that it bulges, far wider at its equator than from pole to pole."
+        }
+        TextKey::BrightestBetelgeuseNote => {
+            "A red supergiant that will one day explode as a supernova."
+        }
+        TextKey::BrightestHadarNote => {
+            "Hot blue stars that, with Rigil Kentaurus, point the way to the Southern Cross."
+        }
+        TextKey::BrightestAltairNote => {
+            "Turns once every nine hours, one of the fastest-spinning bright stars."
+        }
+        TextKey::BrightestAcruxNote => {
+            "The foot of the Southern Cross, which points toward the south celestial pole."
+        }
+        TextKey::BrightestAldebaranNote => {
+            "The orange eye of Taurus, in front of the Hyades cluster rather than part of it."
+        }
+        TextKey::BrightestAntaresNote => "The red heart of Scorpius; its name means rival of Mars.",
+        TextKey::BrightestSpicaNote => {
+            "Two hot stars orbiting so closely that each is pulled into an egg shape."
+        }
+        TextKey::BrightestPolluxNote => {
+            "An orange giant with a planet, the brighter of the Gemini twins."
+        }
+        TextKey::BrightestFomalhautNote => {
+            "The lonely star of the southern autumn sky, ringed by a disk of dust."
+        }
+        TextKey::BrightestDenebNote => {
+            "A supergiant so luminous that it is among the farthest stars seen by eye."
+        }
+        TextKey::BrightestMimosaNote => {
+            "The second star of the Southern Cross, at its eastern arm."
+        }
+        TextKey::HexagonSiriusNote => {
+            "The hexagon's brightest corner, below and left of Orion's belt."
+        }
+        TextKey::HexagonRigelNote => "Up to Orion's bright blue foot.",
+        TextKey::HexagonAldebaranNote => "On to the orange eye of Taurus.",
+        TextKey::HexagonCapellaNote => "High overhead, the brightest star of Auriga.",
+        TextKey::HexagonPolluxNote => "Down to the brighter of the Gemini twins.",
+        TextKey::HexagonProcyonNote => "The Little Dog Star, closing the ring back near Sirius.",
+        TextKey::HexagonBetelgeuseNote => "Inside the hexagon, the red shoulder of Orion.",
+        TextKey::TourProgress => "{} of {}",
+        TextKey::EndTour => "End the tour",
+        TextKey::PreviousStop => "Previous",
+        TextKey::NextStop => "Next",
+        TextKey::AboutStar => "About this star",
+        TextKey::SpectralType => "Spectral type {}",
+        TextKey::DistanceLy => "{} light-years away",
+        TextKey::Luminosity => "{} times as luminous as the Sun",
+        TextKey::Points => "Points",
+        TextKey::ReviewMistakes => "Review mistakes",
+        TextKey::ReviewMistakesTitle => {
+            "Ask again about each star you missed, until you have named every one"
+        }
+        TextKey::MistakesReviewed => "Every mistake named",
+        TextKey::MistakesScore => "{} stars named in {} answers",
+        TextKey::Hint => "Hint",
+        TextKey::HintTitle => "Show the next letter of the answer; each hint takes points off a right answer",
+        TextKey::Trophies => "Trophies",
+        TextKey::TrophiesTitle => "Achievements you have unlocked",
+        TextKey::CloseTrophies => "Close trophies",
+        TextKey::ProgressMap => "Progress map",
+        TextKey::ProgressMapTitle => "See which parts of the sky you have learned",
+        TextKey::CloseProgressMap => "Close progress map",
+        TextKey::ProgressMapHint => "Click a region to go there",
+        TextKey::RegionMastery => "{} of {} stars answered, {}% known",
+        TextKey::Untouched => "Not yet asked",
+        TextKey::Shaky => "Shaky",
+        TextKey::Learned => "Learned",
+        TextKey::AchievementUnlocked => "Achievement unlocked: {}",
+        TextKey::EarnedOn => "Earned {}",
+        TextKey::NotYetEarned => "Not yet earned",
+        TextKey::FirstCorrect => "First Light",
+        TextKey::FirstCorrectGoal => "Name your first star",
+        TextKey::StreakAchievement => "On a Roll",
+        TextKey::StreakGoal => "Answer {} questions in a row right",
+        TextKey::OrionMastered => "Hunter's Friend",
+        TextKey::OrionMasteredGoal => "Master every named star in Orion",
+        TextKey::HundredQuestions => "Centurion",
+        TextKey::HundredQuestionsGoal => "Answer {} questions",
+        TextKey::ResetAndStartOver => "Reset & Start Over",
+        TextKey::Close => "Close",
+        TextKey::Zoom => "Zoom",
+        TextKey::ZoomIn => "Zoom In",
+        TextKey::ZoomOut => "Zoom Out",
+        TextKey::ResetView => "Reset View",
+        TextKey::StarBrightness => "Star Brightness",
+        TextKey::MagnitudeHint => " (mag < {})",
+        TextKey::StarCount => "~{} stars",
+        TextKey::Bright => "Bright",
+        TextKey::Faint => "Faint",
+        TextKey::StarSize => "Star Size",
+        TextKey::Display => "Display",
+        TextKey::Grid => "Grid",
+        TextKey::Figures => "Figures",
+        TextKey::FiguresTitle => "Draw the constellation figures",
+        TextKey::DeepSky => "Deep sky",
+        TextKey::DeepSkyTitle => "Mark the galaxies, clusters and nebulae of the Messier catalog",
+        TextKey::Asterisms => "Asterisms",
+        TextKey::AsterismsTitle => {
+            "Draw star patterns such as the Big Dipper and the Summer Triangle"
+        }
+        TextKey::StarInfo => "Star Info",
+        TextKey::StarInfoTitle => "Show details when clicking unnamed stars",
+        TextKey::Sound => "Sound",
+        TextKey::SoundTitle => "Play a short tone for each answer",
+        TextKey::Labels => "Labels",
+        TextKey::LabelsOff => "Off",
+        TextKey::LabelsSparse => "Sparse",
+        TextKey::LabelsNormal => "Normal",
+        TextKey::LabelsDense => "Dense",
+        TextKey::Language => "Language",
+        TextKey::ExportImage => "Export image",
+        TextKey::Progress => "Progress",
+        TextKey::ExportProgress => "Export",
+        TextKey::ExportProgressTitle => {
+            "Save your statistics, review deck and achievements to a file"
+        }
+        TextKey::ImportProgress => "Import",
+        TextKey::ImportProgressTitle => "Replace your progress with one saved to a file",
+        TextKey::ProgressImported => "Progress imported: {} stars, {} achievements",
+        TextKey::ProgressImportFailed => "Could not import progress: {}",
+        TextKey::NoStarsToAsk => "No named stars to ask about in this sky",
+        TextKey::ExportSvgTitle => "Download the current view as SVG",
+        TextKey::ExportPngTitle => "Download the current view as PNG",
+        TextKey::Done => "Done",
+        TextKey::HelpPanZoom => "🖱️ Drag to pan • Scroll to zoom",
+        TextKey::HelpClickBefore => "Click on a ",
+        TextKey::HelpBrightStar => "bright star",
+        TextKey::HelpClickAfter => " to test your knowledge!",
+        TextKey::Sky => "Sky",
+        TextKey::WholeSky => "Whole sky",
+        TextKey::Tonight => "Tonight",
+        TextKey::TonightTitle => "Show what is above the horizon where you are, right now",
+        TextKey::Latitude => "Latitude",
+        TextKey::Longitude => "Longitude",
+        TextKey::SetLocation => "Set location",
+        TextKey::LocationHint => {
+            "Allow location access, or enter your latitude and longitude (east is positive)"
+        }
+        TextKey::LocationInvalid => {
+            "Latitude must be between -90 and 90, longitude between -180 and 180"
+        }
+        TextKey::UseMyLocation => "Use my location",
+        TextKey::UseMyLocationTitle => "Ask the browser where you are",
+        TextKey::HideBelowHorizon => "Hide below horizon",
+        TextKey::HideBelowHorizonTitle => {
+            "Leave out stars below the horizon instead of dimming them"
+        }
+        TextKey::ObservationTime => "Date and time",
+        TextKey::TimeOfDay => "Time of day",
+        TextKey::Play => "Play",
+        TextKey::Pause => "Pause",
+        TextKey::TimeLapseTitle => "Run the clock forward to watch the sky turn",
+        TextKey::Now => "Now",
+        TextKey::NowTitle => "Show the sky at the current time",
+        TextKey::North => "N",
+        TextKey::East => "E",
+        TextKey::South => "S",
+        TextKey::West => "W",
+        TextKey::BelowHorizon => "{} (below the horizon)",
+        TextKey::Projection => "Projection",
+        TextKey::Flat => "Flat",
+        TextKey::FlatTitle => "RA and Dec as a grid; simple, but stretched near the poles",
+        TextKey::Stereographic => "Round",
+        TextKey::StereographicTitle => {
+            "Stereographic projection: true shapes around the center, even at the poles"
+        }
+        TextKey::Renderer => "Renderer",
+        TextKey::SvgRendererTitle => "Draw each star as an SVG element; sharp and accessible",
+        TextKey::Canvas => "Canvas",
+        TextKey::CanvasRendererTitle => {
+            "Paint the stars onto a canvas; faster with thousands of stars"
+        }
+        TextKey::KeyboardShortcuts => "Keyboard Shortcuts",
+        TextKey::CloseHelp => "Close keyboard shortcuts",
+        TextKey::ShortcutPan => "Pan the map",
+        TextKey::ShortcutZoom => "Zoom in or out",
+        TextKey::ShortcutAnswer => "Pick a quiz answer",
+        TextKey::ShortcutClose => "Close the quiz or a popup",
+        TextKey::ShortcutHelp => "Show or hide these shortcuts",
+        TextKey::QuizSettings => "Quiz Settings",
+        TextKey::QuizSettingsTitle => "Choose how questions are asked",
+        TextKey::CloseSettings => "Close quiz settings",
+        TextKey::Choices => "Choices",
+        TextKey::WrongChoices => "Wrong choices",
+        TextKey::NearbyStars => "Nearby",
+        TextKey::LookAlikeNames => "Look-alike",
+        TextKey::ChoiceNames => "Star names",
+        TextKey::ProperNames => "Proper",
+        TextKey::ProperNamesTitle => "Choices are proper names, such as Betelgeuse",
+        TextKey::BayerNames => "Bayer",
+        TextKey::BayerNamesTitle => {
+            "Choices are Bayer designations, such as α Ori, for advanced players"
+        }
+        TextKey::AskFor => "Ask for",
+        TextKey::AskName => "Name",
+        TextKey::AskNameTitle => "Questions ask for the star's name",
+        TextKey::AskAsterism => "Asterism",
+        TextKey::AskAsterismTitle => {
+            "Questions ask which asterism the star is part of; stars in none are asked by name"
+        }
+        TextKey::AskConstellation => "Constellation",
+        TextKey::AskConstellationTitle => "Questions ask which constellation the star is in",
+        TextKey::AskBrighter => "Brighter",
+        TextKey::AskBrighterTitle => {
+            "Questions ring a second star nearby and ask which of the two is brighter"
+        }
+        TextKey::LabelNames => "Map names",
+        TextKey::IauNames => "IAU",
+        TextKey::IauNamesTitle => "Label stars with their IAU names, such as Betelgeuse",
+        TextKey::ArabicNames => "Arabic",
+        TextKey::ArabicNamesTitle => {
+            "Label stars with the Arabic names most IAU names come from, such as يد الجوزاء"
+        }
+        TextKey::ChineseNames => "Chinese",
+        TextKey::ChineseNamesTitle => {
+            "Label stars with their traditional Chinese names, such as 参宿四"
+        }
+        TextKey::NoneOfAboveChance => "\"None of above\" answers ({}%)",
+        TextKey::Difficulty => "Difficulty",
+        TextKey::AllStars => "All",
+        TextKey::Easy => "Easy",
+        TextKey::Medium => "Medium",
+        TextKey::Hard => "Hard",
+        TextKey::Answers => "Answers",
+        TextKey::PickAnswer => "Pick",
+        TextKey::TypeAnswer => "Type",
+        TextKey::StarName => "Star name",
+        TextKey::CheckAnswer => "Check",
+        TextKey::CloseEnough => "Close enough! It's spelled {}",
+        TextKey::Legend => "Legend",
+        TextKey::MapLegend => "Map legend",
+        TextKey::NamedStar => "Named star (click to quiz)",
+        TextKey::UnnamedStar => "Unnamed star",
+        TextKey::StarColors => "Star color, hot to cool",
+        TextKey::Magnitude => "Magnitude {}",
+        TextKey::RaDecGrid => "RA/Dec grid",
+        TextKey::CelestialEquator => "Celestial equator",
+        TextKey::StarPosition => "RA {}h, Dec {}°",
+        TextKey::NearNeighbor => "This is near {} ({}° away)",
+        TextKey::Galaxy => "Galaxy",
+        TextKey::GlobularCluster => "Globular cluster",
+        TextKey::OpenCluster => "Open cluster",
+        TextKey::Nebula => "Nebula",
+        TextKey::PlanetaryNebula => "Planetary nebula",
+        TextKey::SupernovaRemnant => "Supernova remnant",
+        TextKey::Asterism => "Asterism",
+    }
+}
+
+fn spanish(key: TextKey) -> &'static str {
+    match key {
+        TextKey::Subtitle => "Pon a prueba tu conocimiento del cielo nocturno",
+        TextKey::ForkMe => "Bifúrcame en GitHub",
+        TextKey::ControlsTitle => "Controles",
+        TextKey::DemoData => "Datos de demostración",
+        TextKey::DemoDataTitle => {
+            "Se muestra un pequeño catálogo integrado en lugar del catálogo completo"
+        }
+        TextKey::LoadingCatalog => "Cargando el catálogo de estrellas…",
+        TextKey::LoadingCatalogPercent => "Cargando el catálogo de estrellas… {}%",
+        TextKey::CatalogError => {
+            "No se pudo cargar el catálogo de estrellas ({}). Se muestran datos de demostración."
+        }
+        TextKey::Retry => "Reintentar",
+        TextKey::Dismiss => "Descartar",
+        TextKey::CrashTitle => "Algo salió mal",
+        TextKey::CrashMessage => {
+            "Stargazer encontró un error y se detuvo. Recargar la página lo inicia de nuevo."
+        }
+        TextKey::Reload => "Recargar",
+        TextKey::CopyErrorDetails => "Copiar detalles del error",
+        TextKey::ErrorDetails => "Detalles del error",
+        TextKey::CrashBanner => "La última sesión terminó con un error.",
+        TextKey::Copied => "Copiado",
+        TextKey::QuizTitle => "¿Qué estrella es esta?",
+        TextKey::QuizTitleDeepSky => "¿Qué objeto es este?",
+        TextKey::QuizTitleAsterism => "¿De qué asterismo forma parte esta estrella?",
+        TextKey::QuizTitleConstellation => "¿En qué constelación está esta estrella?",
+        TextKey::QuizTitleBrighter => "¿Cuál de las dos estrellas marcadas es más brillante?",
+        TextKey::CloseQuiz => "Cerrar la pregunta",
+        TextKey::NoneOfAbove => "ninguna de las anteriores",
+        TextKey::Correct => "¡Correcto!",
+        TextKey::Incorrect => "Incorrecto",
+        TextKey::AnswerWas => "La respuesta era: {}",
+        TextKey::AnnounceCorrect => "Correcto",
+        TextKey::AnnounceIncorrect => "Incorrecto, la respuesta era {}",
+        TextKey::Score => "Puntos",
+        TextKey::Accuracy => "Precisión",
+        TextKey::Streak => "Racha",
+        TextKey::Best => "Mejor",
+        TextKey::SessionSummary => "Resumen de la sesión",
+        TextKey::CloseSummary => "Cerrar el resumen",
+        TextKey::TotalQuestions => "Preguntas en total:",
+        TextKey::CorrectCount => "Correctas:",
+        TextKey::IncorrectCount => "Incorrectas:",
+        TextKey::AccuracyPercent => "Precisión:",
+        TextKey::CurrentStreak => "Racha:",
+        TextKey::BestStreak => "Mejor racha:",
+        TextKey::GuessHistory => "Historial de respuestas",
+        TextKey::NoGuesses => "¡Aún no hay respuestas! Empieza a identificar estrellas.",
+        TextKey::AccuracyTrend => "Aciertos, últimas {} respuestas",
+        TextKey::MostMissed => "Estrellas más falladas",
+        TextKey::MissedCount => "{} de {} mal",
+        TextKey::ConstellationAccuracy => "Aciertos por constelación",
+        TextKey::NoStatsYet => "Responde algunas preguntas para ver tus estadísticas.",
+        TextKey::DailyChallenge => "Reto diario",
+        TextKey::DailyChallengeTitle => "Diez preguntas, las mismas para todos hoy",
+        TextKey::QuestionProgress => "Pregunta {} de {}",
+        TextKey::NextQuestion => "Siguiente",
+        TextKey::SeeResults => "Ver resultados",
+        TextKey::ChallengeScore => "{} de {} correctas",
+        TextKey::CopyResults => "Copiar resultados",
+        TextKey::CloseResults => "Cerrar los resultados del reto",
+        TextKey::Survival => "Supervivencia",
+        TextKey::SurvivalTitle => "Tres vidas: cada respuesta incorrecta cuesta una",
+        TextKey::Lives => "Vidas",
+        TextKey::OutOfLives => "Sin vidas",
+        TextKey::SurvivedScore => "{} preguntas superadas",
+        TextKey::PlayAgain => "Jugar otra vez",
+        TextKey::CloseRunResults => "Cerrar los resultados de la partida",
+        TextKey::TimeAttack => "Contrarreloj",
+        TextKey::TimeAttackTitle => {
+            "Responde tantas preguntas como puedas antes de que se acabe el tiempo"
+        }
+        TextKey::TimeLeft => "Tiempo restante",
+        TextKey::TimeUp => "Se acabó el tiempo",
+        TextKey::TimeAttackScore => "{} correctas de {} respondidas",
+        TextKey::Round => "Ronda de 10 preguntas",
+        TextKey::RoundTitle => "Diez estrellas al azar, una tras otra, con nota al final",
+        TextKey::RoundComplete => "Ronda terminada",
+        TextKey::Grade => "Nota",
+        TextKey::Practice => "Práctica",
+        TextKey::PracticeTitle => {
+            "Estudia sin puntuación: al pulsar una estrella con nombre se muestran su nombre, constelación y magnitud en lugar de una pregunta"
+        }
+        TextKey::InConstellation => "En {}",
+        TextKey::Tours => "Recorridos",
+        TextKey::BrightestStarsTour => "Las 20 estrellas más brillantes",
+        TextKey::WinterHexagonTour => "Hexágono de invierno",
+        TextKey::BrightestSiriusNote => {
+            "La estrella más brillante del cielo nocturno, a 8,6 años luz en el Can Mayor."
+        }
+        TextKey::BrightestCanopusNote => {
+            "La segunda más brillante, una gigante blanca muy al sur, en Carina."
+        }
+        TextKey::BrightestRigilKentaurusNote => {
+            "El sistema estelar más cercano al Sol, a 4,4 años luz."
+        }
+        TextKey::BrightestArcturusNote => {
+            "Una gigante naranja, la estrella más brillante al norte del ecuador celeste."
+        }
+        TextKey::BrightestVegaNote => {
+            "La estrella polar hace unos 12.000 años, y de nuevo dentro de 12.000 años."
+        }
+        TextKey::BrightestCapellaNote => {
+            "Dos gigantes amarillas que orbitan una alrededor de la otra, en lo alto en las noches de invierno del norte."
+        }
+        TextKey::BrightestRigelNote => {
+            "Una supergigante azul en el pie de Orión, decenas de miles de veces más luminosa que el Sol."
+        }
+        TextKey::BrightestProcyonNote => {
+            "La estrella del Can Menor, con una enana blanca como compañera."
+        }
+        TextKey::BrightestAchernarNote => {
+            "Gira tan deprisa que se abulta, mucho más ancha en su ecuador que de polo a polo."
+        }
+        TextKey::BrightestBetelgeuseNote => {
+            "Una supergigante roja que algún día estallará como supernova."
+        }
+        TextKey::BrightestHadarNote => {
+            "Estrellas azules y calientes que, con Rigil Kentaurus, señalan el camino a la Cruz del Sur."
+        }
+        TextKey::BrightestAltairNote => {
+            "Da una vuelta cada nueve horas, una de las estrellas brillantes que más deprisa giran."
+        }
+        TextKey::BrightestAcruxNote => {
+            "El pie de la Cruz del Sur, que apunta hacia el polo sur celeste."
+        }
+        TextKey::BrightestAldebaranNote => {
+            "El ojo naranja de Tauro, delante del cúmulo de las Híades y no dentro de él."
+        }
+        TextKey::BrightestAntaresNote => {
+            "El corazón rojo de Escorpio; su nombre significa rival de Marte."
+        }
+        TextKey::BrightestSpicaNote => {
+            "Dos estrellas calientes que orbitan tan cerca que cada una se estira en forma de huevo."
+        }
+        TextKey::BrightestPolluxNote => {
+            "Una gigante naranja con un planeta, la más brillante de los gemelos de Géminis."
+        }
+        TextKey::BrightestFomalhautNote => {
+            "La estrella solitaria del cielo sur en otoño, rodeada de un disco de polvo."
+        }
+        TextKey::BrightestDenebNote => {
+            "Una supergigante tan luminosa que está entre las estrellas más lejanas que se ven a simple vista."
+        }
+        TextKey::BrightestMimosaNote => {
+            "La segunda estrella de la Cruz del Sur, en su brazo oriental."
+        }
+        TextKey::HexagonSiriusNote => {
+            "La esquina más brillante del hexágono, abajo a la izquierda del cinturón de Orión."
+        }
+        TextKey::HexagonRigelNote => "Subimos al brillante pie azul de Orión.",
+        TextKey::HexagonAldebaranNote => "Seguimos hasta el ojo naranja de Tauro.",
+        TextKey::HexagonCapellaNote => "En lo alto, la estrella más brillante de Auriga.",
+        TextKey::HexagonPolluxNote => "Bajamos al más brillante de los gemelos de Géminis.",
+        TextKey::HexagonProcyonNote => {
+            "La estrella del Can Menor, que cierra el anillo de vuelta cerca de Sirius."
+        }
+        TextKey::HexagonBetelgeuseNote => "Dentro del hexágono, el hombro rojo de Orión.",
+        TextKey::TourProgress => "{} de {}",
+        TextKey::EndTour => "Terminar el recorrido",
+        TextKey::PreviousStop => "Anterior",
+        TextKey::NextStop => "Siguiente",
+        TextKey::AboutStar => "Sobre esta estrella",
+        TextKey::SpectralType => "Tipo espectral {}",
+        TextKey::DistanceLy => "A {} años luz",
+        TextKey::Luminosity => "{} veces más luminosa que el Sol",
+        TextKey::Points => "Puntos",
+        TextKey::ReviewMistakes => "Repasar errores",
+        TextKey::ReviewMistakesTitle => {
+            "Vuelve a preguntar por cada estrella que fallaste, hasta que las nombres todas"
+        }
+        TextKey::MistakesReviewed => "Todos los errores corregidos",
+        TextKey::MistakesScore => "{} estrellas nombradas en {} respuestas",
+        TextKey::Hint => "Pista",
+        TextKey::HintTitle => "Muestra la siguiente letra de la respuesta; cada pista resta puntos a un acierto",
+        TextKey::Trophies => "Trofeos",
+        TextKey::TrophiesTitle => "Los logros que has desbloqueado",
+        TextKey::CloseTrophies => "Cerrar los trofeos",
+        TextKey::ProgressMap => "Mapa de progreso",
+        TextKey::ProgressMapTitle => "Mira qué partes del cielo has aprendido",
+        TextKey::CloseProgressMap => "Cerrar el mapa de progreso",
+        TextKey::ProgressMapHint => "Haz clic en una región para ir allí",
+        TextKey::RegionMastery => "{} de {} estrellas respondidas, {}% conocido",
+        TextKey::Untouched => "Aún sin preguntar",
+        TextKey::Shaky => "Poco conocido",
+        TextKey::Learned => "Aprendido",
+        TextKey::AchievementUnlocked => "Logro desbloqueado: {}",
+        TextKey::EarnedOn => "Conseguido el {}",
+        TextKey::NotYetEarned => "Aún sin conseguir",
+        TextKey::FirstCorrect => "Primera luz",
+        TextKey::FirstCorrectGoal => "Nombra tu primera estrella",
+        TextKey::StreakAchievement => "En racha",
+        TextKey::StreakGoal => "Acierta {} preguntas seguidas",
+        TextKey::OrionMastered => "Amigo del cazador",
+        TextKey::OrionMasteredGoal => "Domina todas las estrellas con nombre de Orión",
+        TextKey::HundredQuestions => "Centurión",
+        TextKey::HundredQuestionsGoal => "Responde {} preguntas",
+        TextKey::ResetAndStartOver => "Reiniciar y empezar de nuevo",
+        TextKey::Close => "Cerrar",
+        TextKey::Zoom => "Zoom",
+        TextKey::ZoomIn => "Acercar",
+        TextKey::ZoomOut => "Alejar",
+        TextKey::ResetView => "Restablecer la vista",
+        TextKey::StarBrightness => "Brillo de las estrellas",
+        TextKey::MagnitudeHint => " (mag < {})",
+        TextKey::StarCount => "~{} estrellas",
+        TextKey::Bright => "Brillantes",
+        TextKey::Faint => "Débiles",
+        TextKey::StarSize => "Tamaño de las estrellas",
+        TextKey::Display => "Mostrar",
+        TextKey::Grid => "Cuadrícula",
+        TextKey::Figures => "Figuras",
+        TextKey::FiguresTitle => "Dibujar las figuras de las constelaciones",
+        TextKey::DeepSky => "Cielo profundo",
+        TextKey::DeepSkyTitle => "Marcar las galaxias, cúmulos y nebulosas del catálogo Messier",
+        TextKey::Asterisms => "Asterismos",
+        TextKey::AsterismsTitle => {
+            "Dibujar figuras de estrellas como el Carro y el Triángulo de verano"
+        }
+        TextKey::StarInfo => "Datos de estrellas",
+        TextKey::StarInfoTitle => "Mostrar detalles al hacer clic en estrellas sin nombre",
+        TextKey::Sound => "Sonido",
+        TextKey::SoundTitle => "Reproducir un tono breve con cada respuesta",
+        TextKey::Labels => "Etiquetas",
+        TextKey::LabelsOff => "Ninguna",
+        TextKey::LabelsSparse => "Pocas",
+        TextKey::LabelsNormal => "Normal",
+        TextKey::LabelsDense => "Muchas",
+        TextKey::Language => "Idioma",
+        TextKey::ExportImage => "Exportar imagen",
+        TextKey::Progress => "Progreso",
+        TextKey::ExportProgress => "Exportar",
+        TextKey::ExportProgressTitle => {
+            "Guarda tus estadísticas, tu mazo de repaso y tus logros en un archivo"
+        }
+        TextKey::ImportProgress => "Importar",
+        TextKey::ImportProgressTitle => "Reemplaza tu progreso con uno guardado en un archivo",
+        TextKey::ProgressImported => "Progreso importado: {} estrellas, {} logros",
+        TextKey::ProgressImportFailed => "No se pudo importar el progreso: {}",
+        TextKey::NoStarsToAsk => "No hay estrellas con nombre por las que preguntar en este cielo",
+        TextKey::ExportSvgTitle => "Descargar la vista actual como SVG",
+        TextKey::ExportPngTitle => "Descargar la vista actual como PNG",
+        TextKey::Done => "Terminar",
+        TextKey::HelpPanZoom => "🖱️ Arrastra para mover • Usa la rueda para hacer zoom",
+        TextKey::HelpClickBefore => "¡Haz clic en una ",
+        TextKey::HelpBrightStar => "estrella brillante",
+        TextKey::HelpClickAfter => " para poner a prueba tus conocimientos!",
+        TextKey::Sky => "Cielo",
+        TextKey::WholeSky => "Todo el cielo",
+        TextKey::Tonight => "Esta noche",
+        TextKey::TonightTitle => "Mostrar lo que está sobre el horizonte donde estás, ahora mismo",
+        TextKey::Latitude => "Latitud",
+        TextKey::Longitude => "Longitud",
+        TextKey::SetLocation => "Fijar ubicación",
+        TextKey::LocationHint => {
+            "Permite el acceso a tu ubicación o introduce tu latitud y longitud (el este es positivo)"
+        }
+        TextKey::LocationInvalid => {
+            "La latitud debe estar entre -90 y 90 y la longitud entre -180 y 180"
+        }
+        TextKey::UseMyLocation => "Usar mi ubicación",
+        TextKey::UseMyLocationTitle => "Preguntar al navegador dónde estás",
+        TextKey::HideBelowHorizon => "Ocultar bajo el horizonte",
+        TextKey::HideBelowHorizonTitle => {
+            "Omitir las estrellas bajo el horizonte en lugar de atenuarlas"
+        }
+        TextKey::ObservationTime => "Fecha y hora",
+        TextKey::TimeOfDay => "Hora del día",
+        TextKey::Play => "Reproducir",
+        TextKey::Pause => "Pausa",
+        TextKey::TimeLapseTitle => "Adelantar el reloj para ver girar el cielo",
+        TextKey::Now => "Ahora",
+        TextKey::NowTitle => "Mostrar el cielo a la hora actual",
+        TextKey::North => "N",
+        TextKey::East => "E",
+        TextKey::South => "S",
+        TextKey::West => "O",
+        TextKey::BelowHorizon => "{} (bajo el horizonte)",
+        TextKey::Projection => "Proyección",
+        TextKey::Flat => "Plana",
+        TextKey::FlatTitle => "AR y Dec como cuadrícula; sencilla, pero estirada cerca de los polos",
+        TextKey::Stereographic => "Redonda",
+        TextKey::StereographicTitle => {
+            "Proyección estereográfica: formas fieles alrededor del centro, incluso en los polos"
+        }
+        TextKey::Renderer => "Dibujo",
+        TextKey::SvgRendererTitle => "Dibujar cada estrella como elemento SVG; nítido y accesible",
+        TextKey::Canvas => "Lienzo",
+        TextKey::CanvasRendererTitle => {
+            "Pintar las estrellas en un lienzo; más rápido con miles de estrellas"
+        }
+        TextKey::KeyboardShortcuts => "Atajos de teclado",
+        TextKey::CloseHelp => "Cerrar los atajos de teclado",
+        TextKey::ShortcutPan => "Mover el mapa",
+        TextKey::ShortcutZoom => "Acercar o alejar",
+        TextKey::ShortcutAnswer => "Elegir una respuesta",
+        TextKey::ShortcutClose => "Cerrar el cuestionario o una ventana",
+        TextKey::ShortcutHelp => "Mostrar u ocultar estos atajos",
+        TextKey::QuizSettings => "Ajustes del cuestionario",
+        TextKey::QuizSettingsTitle => "Elegir cómo se hacen las preguntas",
+        TextKey::CloseSettings => "Cerrar los ajustes del cuestionario",
+        TextKey::Choices => "Opciones",
+        TextKey::WrongChoices => "Opciones incorrectas",
+        TextKey::NearbyStars => "Cercanas",
+        TextKey::LookAlikeNames => "Parecidas",
+        TextKey::ChoiceNames => "Nombres de estrellas",
+        TextKey::ProperNames => "Propios",
+        TextKey::ProperNamesTitle => "Las opciones son nombres propios, como Betelgeuse",
+        TextKey::BayerNames => "Bayer",
+        TextKey::BayerNamesTitle => {
+            "Las opciones son designaciones de Bayer, como α Ori, para jugadores avanzados"
+        }
+        TextKey::AskFor => "Preguntar por",
+        TextKey::AskName => "Nombre",
+        TextKey::AskNameTitle => "Las preguntas piden el nombre de la estrella",
+        TextKey::AskAsterism => "Asterismo",
+        TextKey::AskAsterismTitle => {
+            "Las preguntas piden el asterismo del que forma parte la estrella; las que no están en ninguno se preguntan por su nombre"
+        }
+        TextKey::AskConstellation => "Constelación",
+        TextKey::AskConstellationTitle => {
+            "Las preguntas piden la constelación en la que está la estrella"
+        }
+        TextKey::AskBrighter => "Más brillante",
+        TextKey::AskBrighterTitle => {
+            "Las preguntas marcan una segunda estrella cercana y piden cuál de las dos es más brillante"
+        }
+        TextKey::LabelNames => "Nombres en el mapa",
+        TextKey::IauNames => "UAI",
+        TextKey::IauNamesTitle => {
+            "Rotula las estrellas con sus nombres de la UAI, como Betelgeuse"
+        }
+        TextKey::ArabicNames => "Árabe",
+        TextKey::ArabicNamesTitle => {
+            "Rotula las estrellas con los nombres árabes de los que vienen casi todos los de la UAI, como يد الجوزاء"
+        }
+        TextKey::ChineseNames => "Chino",
+        TextKey::ChineseNamesTitle => {
+            "Rotula las estrellas con sus nombres chinos tradicionales, como 参宿四"
+        }
+        TextKey::NoneOfAboveChance => "Respuestas «ninguna de las anteriores» ({}%)",
+        TextKey::Difficulty => "Dificultad",
+        TextKey::AllStars => "Todas",
+        TextKey::Easy => "Fácil",
+        TextKey::Medium => "Media",
+        TextKey::Hard => "Difícil",
+        TextKey::Answers => "Respuestas",
+        TextKey::PickAnswer => "Elegir",
+        TextKey::TypeAnswer => "Escribir",
+        TextKey::StarName => "Nombre de la estrella",
+        TextKey::CheckAnswer => "Comprobar",
+        TextKey::CloseEnough => "¡Casi! Se escribe {}",
+        TextKey::Legend => "Leyenda",
+        TextKey::MapLegend => "Leyenda del mapa",
+        TextKey::NamedStar => "Estrella con nombre (haz clic para jugar)",
+        TextKey::UnnamedStar => "Estrella sin nombre",
+        TextKey::StarColors => "Color de la estrella, de caliente a fría",
+        TextKey::Magnitude => "Magnitud {}",
+        TextKey::RaDecGrid => "Cuadrícula AR/Dec",
+        TextKey::CelestialEquator => "Ecuador celeste",
+        TextKey::StarPosition => "AR {}h, Dec {}°",
+        TextKey::NearNeighbor => "Está cerca de {} (a {}°)",
+        TextKey::Galaxy => "Galaxia",
+        TextKey::GlobularCluster => "Cúmulo globular",
+        TextKey::OpenCluster => "Cúmulo abierto",
+        TextKey::Nebula => "Nebulosa",
+        TextKey::PlanetaryNebula => "Nebulosa planetaria",
+        TextKey::SupernovaRemnant => "Remanente de supernova",
+        TextKey::Asterism => "Asterismo",
+    }
+}
+
+thread_local! {
+    static LOCALE: Cell<Locale> = Cell::new(Locale::default());
+}
+
+/// Set the language [`t`] and [`tf`] use on this thread
+pub fn set_locale(locale: Locale) {
+    LOCALE.with(|current| current.set(locale));
+}
+
+/// The language [`t`] and [`tf`] use on this thread
+pub fn locale() -> Locale {
+    LOCALE.with(Cell::get)
+}
+
+/// The text for `key` in the current language
+pub fn t(key: TextKey) -> &'static str {
+    text(locale(), key)
+}
+
+/// The text for `key` in the current language, with each `{}` replaced
+/// by the next of `args`
+pub fn tf(key: TextKey, args: &[&dyn Display]) -> String {
+    fill(t(key), args)
+}
+
+/// Replace each `{}` in `template` with the next of `args`
+///
+/// Placeholders beyond the last argument are left as they are.
+pub fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut rest = template;
+    while let Some(at) = rest.find("{}") {
+        out.push_str(&rest[..at]);
+        match args.next() {
+            Some(arg) => {
+                let _ = write!(out, "{}", arg);
+            }
+            None => out.push_str("{}"),
+        }
+        rest = &rest[at + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// The interface language, re-rendering the component when it changes
+///
+/// Outside a provider (as in component tests) this is the thread's
+/// current language.
+#[hook]
+pub fn use_locale() -> Locale {
+    match use_context::<Locale>() {
+        Some(locale) => {
+            set_locale(locale);
+            locale
+        }
+        None => locale(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_key_is_translated() {
+        for locale in Locale::ALL {
+            for key in TextKey::ALL {
+                assert!(
+                    !text(locale, key).trim().is_empty(),
+                    "{:?} has no {:?} text",
+                    key,
+                    locale
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_tour_stop_has_a_note() {
+        for tour in &crate::game::TOURS {
+            for stop in tour.stops {
+                assert!(
+                    TextKey::tour_note(tour.id, stop.star).is_some(),
+                    "{:?} has no note at {}",
+                    tour.id,
+                    stop.star
+                );
+            }
+        }
+        assert_eq!(TextKey::tour_note(TourId::WinterHexagon, "Vega"), None);
+    }
+
+    #[test]
+    fn test_translations_take_the_same_values() {
+        let placeholders = |locale, key| text(locale, key).matches("{}").count();
+        for key in TextKey::ALL {
+            for locale in Locale::ALL {
+                assert_eq!(
+                    placeholders(locale, key),
+                    placeholders(Locale::English, key),
+                    "{:?} in {:?}",
+                    key,
+                    locale
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fill_replaces_placeholders_in_order() {
+        assert_eq!(
+            fill(
+                "This is near {} ({}° away)",
+                &[&"Vega", &format_args!("{:.1}", 2.25)]
+            ),
+            "This is near Vega (2.2° away)"
+        );
+        assert_eq!(fill("~{} stars", &[]), "~{} stars");
+        assert_eq!(fill("Done", &[&1]), "Done");
+    }
+
+    #[test]
+    fn test_t_follows_the_current_locale() {
+        assert_eq!(t(TextKey::Correct), "Correct!");
+        set_locale(Locale::Spanish);
+        assert_eq!(t(TextKey::Correct), "¡Correcto!");
+        assert_eq!(
+            tf(TextKey::AnswerWas, &[&"Sirius"]),
+            "La respuesta era: Sirius"
+        );
+        set_locale(Locale::English);
+    }
+}
@@ -0,0 +1,234 @@
+//! State slices shared through contexts
+//!
+//! `App` owns the whole [`GameState`], but each part of the page only
+//! reads a slice of it. [`Slices::provide`] puts each slice in its own
+//! context, and a context only notifies its consumers when its slice
+//! actually changes, so answering a question or showing a toast leaves
+//! the star map alone. The interface language is provided the same way,
+//! for [`use_locale`](super::i18n::use_locale).
+
+use crate::data::star_names::Lang;
+use crate::data::StarId;
+use crate::game::{
+    Achievements, AnswerMode, DailyChallenge, Difficulty, GameMode, GameState, GuessSummary,
+    MapRenderer, QuizConfig, QuizState, ScoreState, StarStats, UiState, ViewMode,
+};
+use crate::utils::{LabelDensity, Locale, Observer, Viewport};
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// What the star map, legend and controls draw
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapSlice {
+    pub viewport: Viewport,
+    pub magnitude_limit: f64,
+    pub show_grid: bool,
+    pub show_constellations: bool,
+    pub show_deep_sky: bool,
+    pub show_asterisms: bool,
+    pub selected_star: Option<StarId>,
+    /// The other star of a which-is-brighter question
+    pub compared_star: Option<StarId>,
+    pub star_scale: f64,
+    /// Whether the "ping" highlight is playing on the selected star
+    pub ping: bool,
+    pub unnamed_star_info: bool,
+    pub label_density: LabelDensity,
+    pub name_lang: Lang,
+    /// Whether a question is open, which hides star names on the map
+    pub quiz_active: bool,
+    /// Whether this is practice, where stars are described, not asked about
+    pub practice: bool,
+    pub sound_enabled: bool,
+    pub renderer: MapRenderer,
+    pub view_mode: ViewMode,
+    pub observer: Option<Observer>,
+    pub hide_below_horizon: bool,
+    pub observation_time: Option<f64>,
+}
+
+/// The current question, where it is shown and how questions are asked
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuizSlice {
+    pub quiz: Option<QuizState>,
+    pub position: Option<(f64, f64)>,
+    /// Width and height of the map the question is shown over
+    pub map_size: (f64, f64),
+    pub config: QuizConfig,
+    pub difficulty: Option<Difficulty>,
+    pub answer_mode: AnswerMode,
+    pub challenge: Option<DailyChallenge>,
+    pub mode: GameMode,
+}
+
+/// The score and the guesses behind it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreSlice {
+    pub score: ScoreState,
+    pub guesses: Vec<GuessSummary>,
+    pub stats: Rc<StarStats>,
+    pub achievements: Achievements,
+    /// Lives left, in a mode that has them
+    pub lives: Option<u32>,
+}
+
+/// Each slice of a game state
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slices {
+    pub map: Rc<MapSlice>,
+    pub quiz: Rc<QuizSlice>,
+    pub score: Rc<ScoreSlice>,
+    pub ui: Rc<UiState>,
+    pub locale: Locale,
+}
+
+impl Slices {
+    pub fn of(state: &GameState) -> Self {
+        Self {
+            map: Rc::new(MapSlice {
+                viewport: state.viewport,
+                magnitude_limit: state.magnitude_limit,
+                show_grid: state.show_grid,
+                show_constellations: state.show_constellations,
+                show_deep_sky: state.show_deep_sky,
+                show_asterisms: state.show_asterisms,
+                selected_star: state.selected_star,
+                compared_star: state.quiz.as_ref().and_then(QuizState::compared_star),
+                star_scale: state.preferences.star_scale,
+                ping: state.ui.quiz_ping,
+                unnamed_star_info: state.preferences.unnamed_star_info,
+                label_density: state.preferences.label_density,
+                name_lang: state.preferences.star_name_lang,
+                quiz_active: state.quiz.is_some(),
+                practice: !state.mode.asks_questions(),
+                sound_enabled: state.preferences.sound_enabled,
+                renderer: state.preferences.renderer,
+                view_mode: state.view_mode,
+                observer: state.preferences.observer,
+                hide_below_horizon: state.preferences.hide_below_horizon,
+                observation_time: state.observation_time,
+            }),
+            quiz: Rc::new(QuizSlice {
+                quiz: state.quiz.clone(),
+                position: state.ui.dropdown_position,
+                map_size: (state.viewport.width, state.viewport.height),
+                config: state.quiz_config.clone(),
+                difficulty: state.difficulty,
+                answer_mode: state.preferences.answer_mode,
+                challenge: state.challenge.clone(),
+                mode: state.mode.clone(),
+            }),
+            score: Rc::new(ScoreSlice {
+                score: state.score.clone(),
+                guesses: state.guess_history.clone(),
+                stats: state.stats.clone(),
+                achievements: state.achievements.clone(),
+                lives: state.mode.lives(),
+            }),
+            ui: Rc::new(state.ui.clone()),
+            locale: state.preferences.locale,
+        }
+    }
+
+    /// `children` with every slice available as a context
+    pub fn provide(&self, children: Html) -> Html {
+        html! {
+            <ContextProvider<Locale> context={self.locale}>
+                <ContextProvider<Rc<MapSlice>> context={self.map.clone()}>
+                    <ContextProvider<Rc<QuizSlice>> context={self.quiz.clone()}>
+                        <ContextProvider<Rc<ScoreSlice>> context={self.score.clone()}>
+                            <ContextProvider<Rc<UiState>> context={self.ui.clone()}>
+                                { children }
+                            </ContextProvider<Rc<UiState>>>
+                        </ContextProvider<Rc<ScoreSlice>>>
+                    </ContextProvider<Rc<QuizSlice>>>
+                </ContextProvider<Rc<MapSlice>>>
+            </ContextProvider<Locale>>
+        }
+    }
+}
+
+/// The map slice from the nearest [`Slices::provide`]
+#[hook]
+pub fn use_map_slice() -> Rc<MapSlice> {
+    use_context().expect("no map slice provided")
+}
+
+/// The quiz slice from the nearest [`Slices::provide`]
+#[hook]
+pub fn use_quiz_slice() -> Rc<QuizSlice> {
+    use_context().expect("no quiz slice provided")
+}
+
+/// The score slice from the nearest [`Slices::provide`]
+#[hook]
+pub fn use_score_slice() -> Rc<ScoreSlice> {
+    use_context().expect("no score slice provided")
+}
+
+/// The UI slice from the nearest [`Slices::provide`]
+#[hook]
+pub fn use_ui_slice() -> Rc<UiState> {
+    use_context().expect("no UI slice provided")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{game_reducer, GameAction};
+
+    fn quizzing() -> Rc<GameState> {
+        let state = Rc::new(GameState::default());
+        let state = game_reducer(state, GameAction::SetDropdownPosition(100.0, 100.0));
+        game_reducer(
+            state,
+            GameAction::StartQuiz {
+                target_star_id: StarId(1),
+                correct_name: "Sirius".into(),
+                choices: vec!["Vega".into(), "Sirius".into()],
+            },
+        )
+    }
+
+    fn after(state: &Rc<GameState>, action: GameAction) -> Slices {
+        Slices::of(&game_reducer(state.clone(), action))
+    }
+
+    #[test]
+    fn test_answering_touches_only_quiz_and_score() {
+        let state = quizzing();
+        let before = Slices::of(&state);
+
+        let selected = after(&state, GameAction::SelectAnswer("Vega".into()));
+        assert_eq!(selected.map, before.map);
+        assert_eq!(selected.score, before.score);
+        assert_ne!(selected.quiz, before.quiz);
+
+        let answered = after(&state, GameAction::SelectAndSubmitAnswer("Sirius".into()));
+        assert_eq!(answered.map, before.map);
+        assert_ne!(answered.score, before.score);
+    }
+
+    #[test]
+    fn test_ui_changes_leave_the_map_alone() {
+        let state = quizzing();
+        let before = Slices::of(&state);
+
+        for action in [GameAction::ShowToast("Hi".into()), GameAction::ShowHelp] {
+            let slices = after(&state, action);
+            assert_eq!(slices.map, before.map);
+            assert_eq!(slices.quiz, before.quiz);
+            assert_ne!(slices.ui, before.ui);
+        }
+
+        let dimmer = after(&state, GameAction::SetMagnitudeLimit(3.0));
+        assert_ne!(dimmer.map, before.map);
+    }
+
+    #[test]
+    fn test_map_knows_when_a_question_is_open() {
+        let state = quizzing();
+        assert!(Slices::of(&state).map.quiz_active);
+        assert!(!after(&state, GameAction::CloseQuiz).map.quiz_active);
+    }
+}
@@ -0,0 +1,270 @@
+//! Shareable app state in the URL hash
+//!
+//! The view and session settings are written to the hash as
+//! `key=value` pairs, e.g.
+//! `#ra=5.5&dec=-5&zoom=3&star=1&diff=easy&mag=4.5&grid=1&const=0&mw=1&daily=1&seed=42`,
+//! so one link opens the app set up the same way, with the selected star
+//! and its info card shown. The star is left out while a question is
+//! open, so a link never gives away an answer. Decoding turns a hash into
+//! reducer actions; keys it does not know, and values it cannot read, are
+//! skipped so older builds can open links from newer ones.
+
+use crate::data::StarId;
+use crate::game::{Difficulty, GameAction, GameState};
+use crate::utils::Viewport;
+
+/// Hash for the shareable parts of `state`
+pub fn encode(state: &GameState) -> String {
+    let flag = |on: bool| if on { "1" } else { "0" };
+    let mut pairs = vec![
+        format!("ra={}", number(state.viewport.center_ra, 4)),
+        format!("dec={}", number(state.viewport.center_dec, 4)),
+        format!("zoom={}", number(state.viewport.zoom, 3)),
+    ];
+    if let Some(star) = state.selected_star.filter(|_| state.quiz.is_none()) {
+        pairs.push(format!("star={}", star.0));
+    }
+    if let Some(difficulty) = state.difficulty {
+        pairs.push(format!("diff={}", difficulty_key(difficulty)));
+    }
+    pairs.push(format!("mag={}", number(state.magnitude_limit, 2)));
+    pairs.push(format!("grid={}", flag(state.show_grid)));
+    pairs.push(format!("const={}", flag(state.show_constellations)));
+    pairs.push(format!("mw={}", flag(state.show_milky_way)));
+    pairs.push(format!("daily={}", flag(state.daily_challenge)));
+    if let Some(seed) = state.seed {
+        pairs.push(format!("seed={}", seed));
+    }
+    pairs.join("&")
+}
+
+/// Actions that restore the state described by `hash`
+///
+/// A leading `#` is optional. When only one of `ra` and `dec` is given,
+/// the other keeps its default.
+pub fn decode(hash: &str) -> Vec<GameAction> {
+    let mut actions = Vec::new();
+    let mut center: (Option<f64>, Option<f64>) = (None, None);
+
+    for (key, value) in hash
+        .trim_start_matches('#')
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+    {
+        match key {
+            "ra" => center.0 = finite(value).map(|ra| ra.rem_euclid(24.0)),
+            "dec" => center.1 = finite(value).map(|dec| dec.clamp(-90.0, 90.0)),
+            "zoom" => actions.extend(finite(value).map(GameAction::SetZoom)),
+            "star" => {
+                if let Ok(id) = value.parse() {
+                    actions.push(GameAction::SelectStar(StarId(id)));
+                    actions.push(GameAction::ShowStarInfo);
+                }
+            }
+            "diff" => actions.extend(parse_difficulty(value).map(GameAction::SetDifficulty)),
+            "mag" => actions.extend(finite(value).map(GameAction::SetMagnitudeLimit)),
+            "grid" => actions.extend(parse_flag(value).map(GameAction::SetShowGrid)),
+            "const" => actions.extend(parse_flag(value).map(GameAction::SetShowConstellations)),
+            "mw" => actions.extend(parse_flag(value).map(GameAction::SetShowMilkyWay)),
+            "daily" => actions.extend(parse_flag(value).map(GameAction::SetDailyChallenge)),
+            "seed" => actions.extend(value.parse().ok().map(|s| GameAction::SetSeed(Some(s)))),
+            _ => {}
+        }
+    }
+
+    if center.0.is_some() || center.1.is_some() {
+        let default = Viewport::default();
+        actions.insert(
+            0,
+            GameAction::SetCenter(
+                center.0.unwrap_or(default.center_ra),
+                center.1.unwrap_or(default.center_dec),
+            ),
+        );
+    }
+    actions
+}
+
+/// The page's current hash, without the `#`
+pub fn current_hash() -> String {
+    web_sys::window()
+        .and_then(|w| w.location().hash().ok())
+        .map(|hash| hash.trim_start_matches('#').to_string())
+        .unwrap_or_default()
+}
+
+/// Replace the page's hash without adding a history entry
+pub fn replace_hash(hash: &str) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    if let Ok(history) = window.history() {
+        let url = format!("#{}", hash);
+        if let Err(e) = history.replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&url))
+        {
+            log::warn!("Could not update the URL: {:?}", e);
+        }
+    }
+}
+
+/// `value` with at most `decimals` places and no trailing zeros
+fn number(value: f64, decimals: usize) -> String {
+    let text = format!("{:.*}", decimals, value);
+    if text.contains('.') {
+        let trimmed = text.trim_end_matches('0').trim_end_matches('.');
+        if trimmed == "-0" {
+            "0".to_string()
+        } else {
+            trimmed.to_string()
+        }
+    } else {
+        text
+    }
+}
+
+fn finite(value: &str) -> Option<f64> {
+    value.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+fn parse_flag(value: &str) -> Option<bool> {
+    match value {
+        "1" | "true" => Some(true),
+        "0" | "false" => Some(false),
+        _ => None,
+    }
+}
+
+fn difficulty_key(difficulty: Difficulty) -> &'static str {
+    match difficulty {
+        Difficulty::Easy => "easy",
+        Difficulty::Medium => "medium",
+        Difficulty::Hard => "hard",
+    }
+}
+
+/// A difficulty, or `Some(None)` for `any`
+fn parse_difficulty(value: &str) -> Option<Option<Difficulty>> {
+    match value {
+        "easy" => Some(Some(Difficulty::Easy)),
+        "medium" => Some(Some(Difficulty::Medium)),
+        "hard" => Some(Some(Difficulty::Hard)),
+        "any" => Some(None),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{game_reducer, QuestionKind, QuizQuestion};
+    use std::rc::Rc;
+
+    fn apply(hash: &str) -> GameState {
+        let state = decode(hash)
+            .into_iter()
+            .fold(Rc::new(GameState::default()), game_reducer);
+        (*state).clone()
+    }
+
+    fn shared_state() -> GameState {
+        let mut state = GameState::default();
+        state.viewport.center_ra = 5.5;
+        state.viewport.center_dec = -5.25;
+        state.viewport.zoom = 3.0;
+        state.difficulty = Some(Difficulty::Easy);
+        state.magnitude_limit = 4.5;
+        state.show_grid = false;
+        state.show_constellations = true;
+        state.show_milky_way = true;
+        state.daily_challenge = true;
+        state.seed = Some(42);
+        state.selected_star = Some(StarId(32349));
+        state
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let state = shared_state();
+        let hash = encode(&state);
+        assert_eq!(
+            hash,
+            "ra=5.5&dec=-5.25&zoom=3&star=32349&diff=easy&mag=4.5&grid=0&const=1&mw=1&daily=1&seed=42"
+        );
+
+        let restored = apply(&hash);
+        assert_eq!(encode(&restored), hash);
+        assert_eq!(restored.viewport, state.viewport);
+        assert_eq!(restored.difficulty, state.difficulty);
+        assert_eq!(restored.seed, Some(42));
+        assert!(restored.show_milky_way && restored.daily_challenge);
+        assert_eq!(restored.selected_star, Some(StarId(32349)));
+        assert!(restored.ui.star_info_shown);
+    }
+
+    #[test]
+    fn test_star_is_hidden_during_a_question() {
+        let state = game_reducer(
+            Rc::new(shared_state()),
+            GameAction::start(QuizQuestion {
+                target_star: StarId(32349),
+                correct_answer: "Sirius".into(),
+                choices: vec!["Sirius".into(), "Vega".into()],
+                is_none_question: false,
+                question_kind: QuestionKind::Name,
+                second_star: None,
+            }),
+        );
+        assert!(!encode(&state).contains("star="));
+        let state = game_reducer(state, GameAction::CloseQuiz);
+        let state = game_reducer(state, GameAction::SelectStar(StarId(7)));
+        assert!(encode(&state).contains("&star=7&"));
+        assert!(decode("star=abc").is_empty());
+    }
+
+    #[test]
+    fn test_default_state_round_trips() {
+        let state = GameState::default();
+        assert_eq!(encode(&apply(&encode(&state))), encode(&state));
+    }
+
+    #[test]
+    fn test_partial_hash_keeps_other_defaults() {
+        let state = apply("#dec=30&mw=1");
+        let default = GameState::default();
+        assert_eq!(state.viewport.center_dec, 30.0);
+        assert_eq!(state.viewport.center_ra, default.viewport.center_ra);
+        assert_eq!(state.viewport.zoom, default.viewport.zoom);
+        assert!(state.show_milky_way);
+        assert_eq!(state.show_grid, default.show_grid);
+        assert!(state.seed.is_none());
+
+        assert!(decode("").is_empty());
+        assert_eq!(decode("seed=7").len(), 1);
+    }
+
+    #[test]
+    fn test_unknown_and_bad_values_are_ignored() {
+        let state = apply("theme=dark&zoom=abc&ra=NaN&grid=maybe&diff=expert&mag=5&v2.layers=all");
+        let default = GameState::default();
+        assert_eq!(state.viewport, default.viewport);
+        assert_eq!(state.show_grid, default.show_grid);
+        assert!(state.difficulty.is_none());
+        assert_eq!(state.magnitude_limit, 5.0);
+    }
+
+    #[test]
+    fn test_values_are_normalized() {
+        let state = apply("ra=25&dec=120");
+        assert_eq!(state.viewport.center_ra, 1.0);
+        assert_eq!(state.viewport.center_dec, 90.0);
+        assert!(apply("diff=easy&diff=any").difficulty.is_none());
+    }
+
+    #[test]
+    fn test_number_formatting() {
+        assert_eq!(number(5.0, 4), "5");
+        assert_eq!(number(-0.00001, 4), "0");
+        assert_eq!(number(6.752345, 4), "6.7523");
+        assert_eq!(number(12.0, 0), "12");
+    }
+}
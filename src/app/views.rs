@@ -0,0 +1,500 @@
+//! Components wired to the state slices
+//!
+//! Each view reads one slice from context and hands it to a
+//! presentational component. Everything else comes in as props that stay
+//! equal between `App` renders, so a view only re-renders when its slice
+//! changes.
+
+use super::slices::{use_map_slice, use_quiz_slice, use_score_slice, use_ui_slice};
+use crate::components::star_map::StarMapProps;
+use crate::components::{
+    ChallengeResults, Controls, Countdown, Legend, ProgressMap, QuizDropdown, RunResults,
+    ScoreDisplay, SettingsPanel, ShortcutsHelp, StarInfo, StarMap, StarMapCanvas, StarPanel,
+    StatsDashboard, SummaryPopup, TourCard, TrophyPanel,
+};
+use crate::data::{CelestialCoord, StarCatalog};
+use crate::game::heatmap::sky_mastery;
+use crate::game::{AnswerMode, GameAction, MapRenderer, ViewMode};
+use crate::utils::{ExportFormat, SkyIndex};
+use gloo::timers::callback::{Interval, Timeout};
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// How often the local sky is redrawn as the Earth turns
+const SKY_REFRESH_MS: u32 = 60_000;
+
+/// How long a toast stays up
+const TOAST_MS: u32 = 4000;
+
+/// The current Unix time in seconds
+fn now_seconds() -> f64 {
+    js_sys::Date::now() / 1000.0
+}
+
+/// Props for [`MapView`]
+#[derive(Properties, PartialEq)]
+pub struct MapViewProps {
+    pub catalog: Rc<StarCatalog>,
+    pub index: Rc<SkyIndex>,
+    pub on_action: Callback<GameAction>,
+}
+
+/// The star map, following the map slice and, in local sky mode, the
+/// clock or the chosen observation time
+///
+/// In local sky mode the map is centered on the zenith, and kept there
+/// as the sky turns until the user pans away or a question moves it.
+#[function_component(MapView)]
+pub fn map_view(props: &MapViewProps) -> Html {
+    let map = use_map_slice();
+
+    let now = use_state(now_seconds);
+    {
+        let now = now.clone();
+        use_effect_with(map.view_mode, move |mode| {
+            let ticking = (*mode == ViewMode::LocalSky).then(|| {
+                now.set(now_seconds());
+                Interval::new(SKY_REFRESH_MS, move || now.set(now_seconds()))
+            });
+            move || drop(ticking)
+        });
+    }
+    let moment = map.observation_time.unwrap_or(*now);
+    let local_sky = map.view_mode.local_sky(map.observer, moment);
+
+    {
+        let on_action = props.on_action.clone();
+        let followed = use_mut_ref(|| None);
+        let center = (map.viewport.center_ra, map.viewport.center_dec);
+        let quiz_active = map.quiz_active;
+        use_effect_with(
+            local_sky.map(|sky| sky.zenith()),
+            move |zenith| match zenith {
+                Some(zenith) => {
+                    let following = followed.borrow().is_none_or(|previous| previous == center);
+                    if following && !quiz_active {
+                        on_action.emit(GameAction::SetCenter(zenith.ra, zenith.dec));
+                        *followed.borrow_mut() = Some((zenith.ra, zenith.dec));
+                    }
+                }
+                None => *followed.borrow_mut() = None,
+            },
+        );
+    }
+
+    let map_props = StarMapProps {
+        catalog: props.catalog.clone(),
+        index: Some(props.index.clone()),
+        viewport: map.viewport,
+        magnitude_limit: map.magnitude_limit,
+        show_grid: map.show_grid,
+        show_constellations: map.show_constellations,
+        show_deep_sky: map.show_deep_sky,
+        show_asterisms: map.show_asterisms,
+        selected_star: map.selected_star,
+        compared_star: map.compared_star,
+        star_scale: map.star_scale,
+        ping: map.ping,
+        unnamed_clickable: map.unnamed_star_info,
+        label_density: map.label_density,
+        name_lang: map.name_lang,
+        quiz_active: map.quiz_active,
+        practice: map.practice,
+        local_sky,
+        hide_below_horizon: map.hide_below_horizon,
+        on_action: props.on_action.clone(),
+    };
+    match map.renderer {
+        MapRenderer::Svg => html! { <StarMap ..map_props /> },
+        MapRenderer::Canvas => html! { <StarMapCanvas ..map_props /> },
+    }
+}
+
+/// The legend, following the map slice
+#[function_component(LegendView)]
+pub fn legend_view() -> Html {
+    let map = use_map_slice();
+    html! {
+        <Legend
+            zoom={map.viewport.zoom}
+            show_grid={map.show_grid}
+            show_deep_sky={map.show_deep_sky}
+            star_scale={map.star_scale}
+        />
+    }
+}
+
+/// Props for [`ControlsView`]
+#[derive(Properties, PartialEq)]
+pub struct ControlsViewProps {
+    pub on_action: Callback<GameAction>,
+    pub on_export: Callback<ExportFormat>,
+    pub on_export_progress: Callback<()>,
+    pub on_import_progress: Callback<web_sys::File>,
+}
+
+/// The control panel, following the map slice
+#[function_component(ControlsView)]
+pub fn controls_view(props: &ControlsViewProps) -> Html {
+    let map = use_map_slice();
+    html! {
+        <Controls
+            zoom={map.viewport.zoom}
+            magnitude_limit={map.magnitude_limit}
+            show_grid={map.show_grid}
+            show_constellations={map.show_constellations}
+            show_deep_sky={map.show_deep_sky}
+            show_asterisms={map.show_asterisms}
+            star_scale={map.star_scale}
+            unnamed_star_info={map.unnamed_star_info}
+            label_density={map.label_density}
+            sound_enabled={map.sound_enabled}
+            view_mode={map.view_mode}
+            observer={map.observer}
+            hide_below_horizon={map.hide_below_horizon}
+            observation_time={map.observation_time}
+            practice={map.practice}
+            projection={map.viewport.projection}
+            renderer={map.renderer}
+            on_action={props.on_action.clone()}
+            on_export={props.on_export.clone()}
+            on_export_progress={props.on_export_progress.clone()}
+            on_import_progress={props.on_import_progress.clone()}
+        />
+    }
+}
+
+/// Props for [`ScoreView`]
+#[derive(Properties, PartialEq)]
+pub struct ScoreViewProps {
+    pub compact: bool,
+}
+
+/// The header score, following the score slice
+#[function_component(ScoreView)]
+pub fn score_view(props: &ScoreViewProps) -> Html {
+    let score = use_score_slice();
+    html! {
+        <ScoreDisplay score={score.score.clone()} compact={props.compact} lives={score.lives} />
+    }
+}
+
+/// Props for [`QuizView`]
+#[derive(Properties, PartialEq)]
+pub struct QuizViewProps {
+    pub on_action: Callback<GameAction>,
+    pub compact: bool,
+    /// Where keyboard focus goes when the quiz closes
+    pub return_focus: NodeRef,
+}
+
+/// The quiz dropdown while a question is open, following the quiz slice
+#[function_component(QuizView)]
+pub fn quiz_view(props: &QuizViewProps) -> Html {
+    let slice = use_quiz_slice();
+    let (Some(quiz), Some(position)) = (slice.quiz.clone(), slice.position) else {
+        return Html::default();
+    };
+    // The open question counts once answered
+    let progress = slice
+        .challenge
+        .as_ref()
+        .map(|c| (c.results.len(), c.questions.len()))
+        .or(slice.mode.progress())
+        .map(|(answered, count)| (answered + usize::from(!quiz.answered), count));
+    html! {
+        <QuizDropdown
+            quiz={quiz}
+            position={position}
+            map_size={slice.map_size}
+            on_action={props.on_action.clone()}
+            compact={props.compact}
+            typed={slice.answer_mode == AnswerMode::Typed}
+            return_focus={props.return_focus.clone()}
+            progress={progress}
+        />
+    }
+}
+
+/// Props for [`ChallengeView`]
+#[derive(Properties, PartialEq)]
+pub struct ChallengeViewProps {
+    pub on_action: Callback<GameAction>,
+}
+
+/// The daily challenge results once the last question is closed,
+/// following the quiz slice
+#[function_component(ChallengeView)]
+pub fn challenge_view(props: &ChallengeViewProps) -> Html {
+    let slice = use_quiz_slice();
+    match &slice.challenge {
+        Some(challenge) if challenge.is_complete() && slice.quiz.is_none() => html! {
+            <ChallengeResults challenge={challenge.clone()} on_action={props.on_action.clone()} />
+        },
+        _ => Html::default(),
+    }
+}
+
+/// Props for [`CountdownView`]
+#[derive(Properties, PartialEq)]
+pub struct CountdownViewProps {
+    pub on_action: Callback<GameAction>,
+}
+
+/// The clock of a timed run while it is running, following the quiz
+/// slice
+#[function_component(CountdownView)]
+pub fn countdown_view(props: &CountdownViewProps) -> Html {
+    let slice = use_quiz_slice();
+    match slice.mode.deadline() {
+        Some(ends_at) => html! {
+            <Countdown ends_at={ends_at} on_action={props.on_action.clone()} />
+        },
+        None => Html::default(),
+    }
+}
+
+/// Props for [`RunView`]
+#[derive(Properties, PartialEq)]
+pub struct RunViewProps {
+    pub on_action: Callback<GameAction>,
+}
+
+/// A run's results once its last question is closed, following the quiz
+/// slice
+#[function_component(RunView)]
+pub fn run_view(props: &RunViewProps) -> Html {
+    let slice = use_quiz_slice();
+    if slice.mode.is_over() && slice.quiz.is_none() {
+        html! { <RunResults mode={slice.mode.clone()} on_action={props.on_action.clone()} /> }
+    } else {
+        Html::default()
+    }
+}
+
+/// Props for [`TourView`]
+#[derive(Properties, PartialEq)]
+pub struct TourViewProps {
+    pub catalog: Rc<StarCatalog>,
+    pub on_action: Callback<GameAction>,
+}
+
+/// The card of the tour being taken, following the quiz and map slices;
+/// keyed by stop, so each stop starts its own glide
+#[function_component(TourView)]
+pub fn tour_view(props: &TourViewProps) -> Html {
+    let slice = use_quiz_slice();
+    let map = use_map_slice();
+    let Some(run) = slice.mode.tour() else {
+        return Html::default();
+    };
+    let star = run.current().find(&props.catalog).cloned();
+    let center = CelestialCoord::new(map.viewport.center_ra, map.viewport.center_dec);
+    html! {
+        <TourCard
+            key={format!("{}-{}", run.tour, run.stop)}
+            run={run}
+            star={star}
+            center={center}
+            name_lang={map.name_lang}
+            on_action={props.on_action.clone()}
+        />
+    }
+}
+
+/// Props for [`StarPanelView`]
+#[derive(Properties, PartialEq)]
+pub struct StarPanelViewProps {
+    pub catalog: Rc<StarCatalog>,
+}
+
+/// The side panel about the star of an answered question, following the
+/// quiz and map slices
+#[function_component(StarPanelView)]
+pub fn star_panel_view(props: &StarPanelViewProps) -> Html {
+    let slice = use_quiz_slice();
+    let map = use_map_slice();
+    let star = slice
+        .quiz
+        .as_ref()
+        .filter(|quiz| quiz.answered)
+        .and_then(|quiz| quiz.target_star())
+        .and_then(|id| props.catalog.get(id));
+    match star {
+        Some(star) => html! { <StarPanel star={star.clone()} name_lang={map.name_lang} /> },
+        None => Html::default(),
+    }
+}
+
+/// Props for [`StarInfoView`]
+#[derive(Properties, PartialEq)]
+pub struct StarInfoViewProps {
+    pub catalog: Rc<StarCatalog>,
+    pub on_action: Callback<GameAction>,
+}
+
+/// The info card for the selected star, following the UI and map slices
+#[function_component(StarInfoView)]
+pub fn star_info_view(props: &StarInfoViewProps) -> Html {
+    let ui = use_ui_slice();
+    let map = use_map_slice();
+    match (
+        ui.star_info_shown,
+        map.selected_star.and_then(|id| props.catalog.get(id)),
+        ui.dropdown_position,
+    ) {
+        (true, Some(star), Some(position)) => html! {
+            <StarInfo
+                catalog={props.catalog.clone()}
+                star={star.clone()}
+                name_lang={map.name_lang}
+                position={position}
+                on_action={props.on_action.clone()}
+            />
+        },
+        _ => Html::default(),
+    }
+}
+
+/// Props for [`SummaryView`]
+#[derive(Properties, PartialEq)]
+pub struct SummaryViewProps {
+    pub catalog: Rc<StarCatalog>,
+    pub on_action: Callback<GameAction>,
+}
+
+/// The session summary, following the score and UI slices
+#[function_component(SummaryView)]
+pub fn summary_view(props: &SummaryViewProps) -> Html {
+    let ui = use_ui_slice();
+    let score = use_score_slice();
+    if !ui.summary_shown {
+        return Html::default();
+    }
+    html! {
+        <SummaryPopup
+            guesses={score.guesses.clone()}
+            score={score.score.clone()}
+            on_action={props.on_action.clone()}
+        >
+            <StatsDashboard
+                guesses={score.guesses.clone()}
+                stats={score.stats.clone()}
+                catalog={props.catalog.clone()}
+            />
+        </SummaryPopup>
+    }
+}
+
+/// Props for [`HelpView`]
+#[derive(Properties, PartialEq)]
+pub struct HelpViewProps {
+    pub on_action: Callback<GameAction>,
+}
+
+/// The keyboard shortcuts help, following the UI slice
+#[function_component(HelpView)]
+pub fn help_view(props: &HelpViewProps) -> Html {
+    let ui = use_ui_slice();
+    if !ui.help_shown {
+        return Html::default();
+    }
+    html! { <ShortcutsHelp on_action={props.on_action.clone()} /> }
+}
+
+/// Props for [`SettingsView`]
+#[derive(Properties, PartialEq)]
+pub struct SettingsViewProps {
+    pub on_action: Callback<GameAction>,
+}
+
+/// The quiz settings panel, following the UI, quiz and map slices
+#[function_component(SettingsView)]
+pub fn settings_view(props: &SettingsViewProps) -> Html {
+    let ui = use_ui_slice();
+    let quiz = use_quiz_slice();
+    let map = use_map_slice();
+    if !ui.settings_open {
+        return Html::default();
+    }
+    html! {
+        <SettingsPanel
+            config={quiz.config.clone()}
+            difficulty={quiz.difficulty}
+            answer_mode={quiz.answer_mode}
+            label_density={map.label_density}
+            name_lang={map.name_lang}
+            projection={map.viewport.projection}
+            on_action={props.on_action.clone()}
+        />
+    }
+}
+
+/// Props for [`TrophiesView`]
+#[derive(Properties, PartialEq)]
+pub struct TrophiesViewProps {
+    pub on_action: Callback<GameAction>,
+}
+
+/// The trophy panel, following the UI and score slices
+#[function_component(TrophiesView)]
+pub fn trophies_view(props: &TrophiesViewProps) -> Html {
+    let ui = use_ui_slice();
+    let score = use_score_slice();
+    if !ui.trophies_open {
+        return Html::default();
+    }
+    html! {
+        <TrophyPanel
+            achievements={score.achievements.clone()}
+            on_action={props.on_action.clone()}
+        />
+    }
+}
+
+/// Props for [`ProgressMapView`]
+#[derive(Properties, PartialEq)]
+pub struct ProgressMapViewProps {
+    pub catalog: Rc<StarCatalog>,
+    pub index: Rc<SkyIndex>,
+    pub on_action: Callback<GameAction>,
+}
+
+/// The progress map, following the UI and score slices
+#[function_component(ProgressMapView)]
+pub fn progress_map_view(props: &ProgressMapViewProps) -> Html {
+    let ui = use_ui_slice();
+    let score = use_score_slice();
+    if !ui.progress_map_open {
+        return Html::default();
+    }
+    let regions = sky_mastery(&props.catalog, &props.index, &score.stats);
+    html! {
+        <ProgressMap regions={Rc::new(regions)} on_action={props.on_action.clone()} />
+    }
+}
+
+/// Props for [`ToastView`]
+#[derive(Properties, PartialEq)]
+pub struct ToastViewProps {
+    pub on_action: Callback<GameAction>,
+}
+
+/// The latest toast, cleared after [`TOAST_MS`], following the UI slice
+#[function_component(ToastView)]
+pub fn toast_view(props: &ToastViewProps) -> Html {
+    let ui = use_ui_slice();
+    {
+        let on_action = props.on_action.clone();
+        use_effect_with(ui.toast_message.clone(), move |message| {
+            let timeout = message
+                .is_some()
+                .then(|| Timeout::new(TOAST_MS, move || on_action.emit(GameAction::ClearToast)));
+            move || drop(timeout)
+        });
+    }
+    match &ui.toast_message {
+        Some(message) => html! { <div class="toast" role="status">{ message }</div> },
+        None => Html::default(),
+    }
+}
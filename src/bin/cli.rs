@@ -8,34 +8,205 @@
 //! # Generate placeholder star catalog
 //! cargo run --bin stargazer-cli --features cli -- generate
 //!
+//! # Export the full catalog (including unnamed stars) for the web app
+//! cargo run --bin stargazer-cli --features cli -- generate --format json --output stars.json
+//!
 //! # Run quiz in terminal (for testing)
 //! cargo run --bin stargazer-cli --features cli -- quiz
 //!
+//! # Full-screen quiz with a mini-map (line mode when not on a terminal)
+//! cargo run --bin stargazer-cli --features cli -- quiz --tui
+//!
+//! # Replay an earlier quiz run
+//! cargo run --bin stargazer-cli --features cli -- quiz --seed 42
+//!
+//! # Score pre-recorded answers (one per line, number or name) without prompting
+//! cargo run --bin stargazer-cli --features cli -- quiz --count 20 --seed 7 \
+//!     --answers-file answers.txt --format json
+//!
+//! # Append a JSON line per answer and one for the session to a telemetry log
+//! cargo run --bin stargazer-cli --features cli -- quiz --telemetry-log telemetry.jsonl
+//!
+//! # Bright stars only, three choices, no "none of above"
+//! cargo run --bin stargazer-cli --features cli -- quiz --difficulty easy --choices 3 --no-none
+//!
+//! # Daily spaced-repetition review, five new stars a day
+//! cargo run --bin stargazer-cli --features cli -- flashcards --deck ~/.stargazer/deck.json --new-per-day 5
+//!
+//! # Bring in progress exported from the app, and hand it back later
+//! cargo run --bin stargazer-cli --features cli -- progress import stargazer-progress.json
+//! cargo run --bin stargazer-cli --features cli -- progress export --output stargazer-progress.json
+//!
+//! # Quiz balance statistics over many generated questions
+//! cargo run --bin stargazer-cli --features cli -- simulate --questions 10000 --difficulty hard
+//!
+//! # Named stars in one constellation, west to east, as CSV
+//! cargo run --bin stargazer-cli --features cli -- list-named --constellation ori --sort ra --format csv
+//!
 //! # Show catalog statistics
 //! cargo run --bin stargazer-cli --features cli -- stats
+//!
+//! # Per-constellation table, or every star in one constellation
+//! cargo run --bin stargazer-cli --features cli -- stats --by-constellation
+//! cargo run --bin stargazer-cli --features cli -- stats --constellation Ori
+//!
+//! # Star of the day (same star for everyone on a given UTC date)
+//! cargo run --bin stargazer-cli --features cli -- star-of-the-day --date 2025-03-01
+//!
+//! # Show details for one star (by name, name prefix, designation, or id)
+//! cargo run --bin stargazer-cli --features cli -- lookup Sirius
+//! cargo run --bin stargazer-cli --features cli -- lookup "alf Ori"
+//!
+//! # Closest stars to a point (decimal or sexagesimal)
+//! cargo run --bin stargazer-cli --features cli -- nearest --ra 6h45m --dec -16.7 --count 5
+//!
+//! # Draw the sky around Orion as text
+//! cargo run --bin stargazer-cli --features cli -- map --ra 5.5 --dec 0 --zoom 4 --labels
+//!
+//! # Save the same view as an SVG figure
+//! cargo run --bin stargazer-cli --features cli -- render --ra 20.6 --dec 40 --zoom 6 \
+//!     --output cygnus.svg --mag 5.5 --grid --constellations
+//!
+//! # Inspect the tile holding a star, or summarize all tiles
+//! cargo run --bin stargazer-cli --features cli -- tiles --zoom 3 --star Sirius
+//! cargo run --bin stargazer-cli --features cli -- tiles --summary
+//!
+//! # Debug distractor selection for one star
+//! cargo run --bin stargazer-cli --features cli -- distractors --star Betelgeuse --zoom 2 --count 4
+//!
+//! # Check a catalog file for CI (exit code 1 on errors, or warnings with --strict)
+//! cargo run --bin stargazer-cli --features cli -- validate --input stars.json --strict
+//!
+//! # Convert between catalog formats (inferred from extensions)
+//! cargo run --bin stargazer-cli --features cli -- convert --input stars.json --output stars.bin
+//!
+//! # Overlay corrected names onto a catalog, matching stars within 30 arcseconds
+//! cargo run --bin stargazer-cli --features cli -- merge base.json overlay.json \
+//!     --policy prefer-incoming --tolerance-arcsec 30 --output merged.json --verbose
+//!
+//! # Import the HYG v3 database, keeping naked-eye stars
+//! cargo run --bin stargazer-cli --features cli -- import-hyg \
+//!     --input hygdata_v3.csv --output stars.json --max-magnitude 6.5
+//!
+//! # Time catalog indexing, range queries and question generation
+//! cargo run --release --bin stargazer-cli --features cli -- bench --stars 50000 --iterations 200
 //! ```
 
 #[cfg(feature = "cli")]
-use clap::{Parser, Subcommand};
+#[path = "cli/tui.rs"]
+mod tui;
+
+#[cfg(feature = "cli")]
+#[path = "cli/bench.rs"]
+mod bench;
+
+#[cfg(feature = "cli")]
+#[path = "cli/flashcards.rs"]
+mod flashcards;
+
+#[cfg(feature = "cli")]
+#[path = "cli/progress.rs"]
+mod progress;
+
+#[cfg(feature = "cli")]
+use clap::{error::ErrorKind, Args, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+
+#[cfg(feature = "cli")]
+use stargazer_poc::build_info;
+
+#[cfg(feature = "cli")]
+use stargazer_poc::data::constellations::summarize_constellations;
+
+#[cfg(feature = "cli")]
+use stargazer_poc::data::embedded::embedded_catalog;
+
+#[cfg(feature = "cli")]
+use stargazer_poc::data::formats::{decode_stars, encode_stars, write_csv, CatalogFormat};
+
+#[cfg(feature = "cli")]
+use stargazer_poc::data::import::{import_catalog, ImportFormat, Imported};
+
+#[cfg(feature = "cli")]
+use stargazer_poc::data::listing::{NamedStarFilter, StarSort};
+
+#[cfg(feature = "cli")]
+use stargazer_poc::data::merge::{merge_catalogs, MergeOptions, MergePolicy};
+
+#[cfg(feature = "cli")]
+use stargazer_poc::data::nearest::angular_separation;
+
+#[cfg(feature = "cli")]
+use stargazer_poc::data::search::LookupResult;
+
+#[cfg(feature = "cli")]
+use stargazer_poc::data::tile_stats::{summarize_tiles, TileSummaryTable};
+
+#[cfg(feature = "cli")]
+use stargazer_poc::data::validate::{ConstellationLines, ValidationReport};
+
+#[cfg(feature = "cli")]
+use stargazer_poc::data::{
+    generate_placeholder_catalog, BrightnessCategory, CelestialCoord, Star, StarCatalog,
+    TileSystem, ZoomLevel,
+};
+
+#[cfg(feature = "cli")]
+use stargazer_poc::utils::sky_svg::{render_to_svg_string, SvgExportOptions};
+
+#[cfg(feature = "cli")]
+use stargazer_poc::utils::ascii_render::{render_ascii, AsciiRenderOptions};
+
+#[cfg(feature = "cli")]
+use stargazer_poc::utils::{LodSettings, Viewport, MAX_ZOOM, MIN_ZOOM};
+
+#[cfg(feature = "cli")]
+use stargazer_poc::utils::sexagesimal::{format_dec_dms, format_ra_hms, parse_dec, parse_ra};
 
 #[cfg(feature = "cli")]
-use stargazer_poc::data::{generate_placeholder_catalog, BrightnessCategory};
+use stargazer_poc::game::simulation::simulate;
 
 #[cfg(feature = "cli")]
-use stargazer_poc::game::{QuizConfig, QuizGenerator};
+use stargazer_poc::game::scripted::run_scripted;
+
+#[cfg(feature = "cli")]
+use stargazer_poc::game::engine::HeadlessGame;
+
+#[cfg(feature = "cli")]
+use stargazer_poc::game::telemetry::{report_scripted, JsonLinesTelemetry};
+
+#[cfg(feature = "cli")]
+use stargazer_poc::game::daily::{star_fact, star_of_the_day, SEQUENCE_START};
+
+#[cfg(feature = "cli")]
+use stargazer_poc::utils::dates::{
+    day_from_unix_seconds, format_date, parse_date, unix_millis_now,
+};
+
+#[cfg(feature = "cli")]
+use stargazer_poc::game::{
+    events, Difficulty, DistractorStrategy, GuessSummary, NoTelemetry, QuizConfig, QuizGenerator,
+    ScoreState, Telemetry,
+};
 
 #[cfg(feature = "cli")]
 use rand::SeedableRng;
 
 #[cfg(feature = "cli")]
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
+
+#[cfg(feature = "cli")]
+use std::path::{Path, PathBuf};
 
 #[cfg(feature = "cli")]
 #[derive(Parser)]
 #[command(name = "stargazer-cli")]
 #[command(about = "Stargazer CLI - Star catalog and quiz tools")]
-#[command(version)]
 struct Cli {
+    /// Write output to this file instead of stdout (for import, convert
+    /// and render: the file to produce)
+    #[arg(short, long, global = true)]
+    output: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -48,16 +219,352 @@ enum Commands {
         /// Output format (json, csv, summary)
         #[arg(short, long, default_value = "summary")]
         format: String,
+
+        /// Only include named stars
+        #[arg(long)]
+        named_only: bool,
     },
 
     /// Show catalog statistics
-    Stats,
+    Stats {
+        /// Print a table of per-constellation statistics
+        #[arg(long)]
+        by_constellation: bool,
+
+        /// List every star in one constellation (e.g. Ori)
+        #[arg(long, conflicts_with = "by_constellation")]
+        constellation: Option<String>,
+    },
 
     /// Run interactive quiz in terminal
     Quiz {
         /// Number of questions
         #[arg(short, long, default_value = "10")]
         count: usize,
+
+        /// Random seed; reuse a printed seed to replay the same questions
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Full-screen mode; falls back to line mode when not on a terminal
+        #[arg(long, conflicts_with = "answers_file")]
+        tui: bool,
+
+        /// Answer from a file instead of prompting: one choice number or name per line
+        #[arg(long)]
+        answers_file: Option<PathBuf>,
+
+        /// Output format for --answers-file results
+        #[arg(
+            short,
+            long,
+            value_enum,
+            default_value = "table",
+            requires = "answers_file"
+        )]
+        format: ReportFormatArg,
+
+        /// Append a JSON line per answer and one for the session to this file
+        #[arg(long, conflicts_with = "tui")]
+        telemetry_log: Option<PathBuf>,
+
+        #[command(flatten)]
+        filters: QuizFilterArgs,
+    },
+
+    /// Review named stars with spaced repetition, keeping progress in a deck file
+    Flashcards {
+        /// Deck file (default: ~/.stargazer/deck.json); created if missing
+        #[arg(long)]
+        deck: Option<PathBuf>,
+
+        /// New stars to introduce per day, brightest first
+        #[arg(long, default_value = "5")]
+        new_per_day: usize,
+
+        /// Review as of this date (YYYY-MM-DD) instead of today (UTC)
+        #[arg(long, value_parser = parse_date)]
+        date: Option<i64>,
+    },
+
+    /// Move learning progress between the app and the CLI as one JSON file
+    Progress {
+        #[command(subcommand)]
+        action: ProgressAction,
+    },
+
+    /// Generate many questions without playing them and report statistics
+    Simulate {
+        /// Number of questions to generate
+        #[arg(short, long, default_value = "1000")]
+        questions: usize,
+
+        /// Random seed; reuse a printed seed to reproduce a run
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Zoom level used for tile-aware distractors, as in the app
+        #[arg(short, long, default_value = "2")]
+        zoom: u8,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "table")]
+        format: ReportFormatArg,
+
+        #[command(flatten)]
+        filters: QuizFilterArgs,
+    },
+
+    /// Import an external catalog into the app's JSON format
+    ///
+    /// `import-hyg` is kept as a name for the common case; HYG is the
+    /// default format. The designations, spectral types, distances and
+    /// luminosities HYG rows give are written beside the output, as
+    /// `stars.facts.json` for `stars.json`.
+    #[command(visible_alias = "import-hyg")]
+    Import {
+        /// Input catalog format
+        #[arg(long, value_enum, default_value_t = ImportFormatArg::Hyg)]
+        format: ImportFormatArg,
+
+        /// Catalog file to read
+        #[arg(short, long)]
+        input: PathBuf,
+
+        /// Drop stars fainter than this magnitude
+        #[arg(long)]
+        max_magnitude: Option<f64>,
+
+        /// Validate and report without writing the output
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Show a detail card for a single star
+    Lookup {
+        /// Star name, unique name prefix, Bayer or Flamsteed designation
+        /// ("α Ori", "alf Ori", "58 Ori"), or numeric id
+        query: String,
+    },
+
+    /// Show the star of the day, the same for everyone on a given UTC date
+    StarOfTheDay {
+        /// Date (YYYY-MM-DD) to show instead of today (UTC)
+        #[arg(long, value_parser = parse_date)]
+        date: Option<i64>,
+    },
+
+    /// List the stars closest to a point on the sky
+    Nearest {
+        /// Right ascension in hours (e.g. 6.75 or 6h45m00s)
+        #[arg(long, value_parser = parse_ra)]
+        ra: f64,
+
+        /// Declination in degrees (e.g. -16.7 or -16:42:00)
+        #[arg(long, allow_hyphen_values = true, value_parser = parse_dec)]
+        dec: f64,
+
+        /// Number of stars to list
+        #[arg(short, long, default_value = "5")]
+        count: usize,
+
+        /// Only consider named stars
+        #[arg(long)]
+        named_only: bool,
+    },
+
+    /// Draw the stars around a point as text
+    Map {
+        /// Right ascension of the centre in hours (e.g. 5.5 or 5h30m)
+        #[arg(long, default_value = "12", value_parser = parse_ra)]
+        ra: f64,
+
+        /// Declination of the centre in degrees (e.g. 0 or -16:42:00)
+        #[arg(long, default_value = "0", allow_hyphen_values = true, value_parser = parse_dec)]
+        dec: f64,
+
+        /// Zoom level (1 = full sky)
+        #[arg(short, long, default_value = "1")]
+        zoom: f64,
+
+        /// Columns of output
+        #[arg(long, default_value = "120")]
+        width: u16,
+
+        /// Rows of output
+        #[arg(long, default_value = "40")]
+        height: u16,
+
+        /// Faintest magnitude to draw (default: the app's limit for the zoom)
+        #[arg(long)]
+        mag: Option<f64>,
+
+        /// Write names next to named stars
+        #[arg(long)]
+        labels: bool,
+
+        /// Mark RA/Dec grid intersections with `+`
+        #[arg(long)]
+        grid: bool,
+    },
+
+    /// Render the map around a point to an SVG file
+    Render {
+        /// Right ascension of the centre in hours (e.g. 20.6 or 20h36m)
+        #[arg(long, default_value = "12", value_parser = parse_ra)]
+        ra: f64,
+
+        /// Declination of the centre in degrees (e.g. 40 or -16:42:00)
+        #[arg(long, default_value = "0", allow_hyphen_values = true, value_parser = parse_dec)]
+        dec: f64,
+
+        /// Zoom level (1 = full sky)
+        #[arg(short, long, default_value = "1")]
+        zoom: f64,
+
+        /// Faintest magnitude to draw (default: the app's limit for the zoom)
+        #[arg(long)]
+        mag: Option<f64>,
+
+        /// Draw RA/Dec grid lines
+        #[arg(long)]
+        grid: bool,
+
+        /// Draw the constellation figures
+        #[arg(long)]
+        constellations: bool,
+
+        /// Image width in pixels
+        #[arg(long, default_value = "1200")]
+        width: u32,
+
+        /// Image height in pixels
+        #[arg(long, default_value = "600")]
+        height: u32,
+    },
+
+    /// Inspect the tile system
+    Tiles {
+        /// Zoom level of the tile to inspect
+        #[arg(short, long, default_value = "2")]
+        zoom: u8,
+
+        /// Inspect the tile containing this star
+        #[arg(long, conflicts_with_all = ["ra", "dec"])]
+        star: Option<String>,
+
+        /// Inspect the tile at this right ascension (hours or sexagesimal)
+        #[arg(long, value_parser = parse_ra, requires = "dec")]
+        ra: Option<f64>,
+
+        /// Inspect the tile at this declination (degrees or sexagesimal)
+        #[arg(long, allow_hyphen_values = true, value_parser = parse_dec, requires = "ra")]
+        dec: Option<f64>,
+
+        /// Print per-zoom statistics of named stars per tile instead
+        #[arg(long)]
+        summary: bool,
+    },
+
+    /// Show which distractors the quiz would pick for a star, and why
+    Distractors {
+        /// Target star name, unique name prefix, or numeric id
+        #[arg(long)]
+        star: String,
+
+        /// Zoom level used for tile-aware selection
+        #[arg(short, long, default_value = "2")]
+        zoom: u8,
+
+        /// Number of distractors to pick
+        #[arg(short, long, default_value = "4")]
+        count: usize,
+
+        /// Where distractors are drawn from first
+        #[arg(long, value_enum, default_value = "tile")]
+        strategy: StrategyArg,
+
+        /// Random seed; reuse a printed seed to get the same picks
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+
+    /// Check a star list for errors and warnings
+    Validate {
+        /// Star list to check
+        #[arg(short, long)]
+        input: PathBuf,
+
+        /// Input format (default: inferred from the extension)
+        #[arg(long, value_enum)]
+        format: Option<CatalogFormatArg>,
+
+        /// Fail on warnings as well as errors
+        #[arg(long)]
+        strict: bool,
+
+        /// Constellation lines JSON ({"Ori": [[id, id], ...]}) to check against the stars
+        #[arg(long)]
+        constellation_lines: Option<PathBuf>,
+    },
+
+    /// Convert a star list between JSON, gzipped JSON, CSV and binary
+    Convert {
+        /// File to read
+        #[arg(short, long)]
+        input: PathBuf,
+
+        /// Input format (default: inferred from the extension)
+        #[arg(long, value_enum)]
+        from: Option<CatalogFormatArg>,
+
+        /// Output format (default: inferred from the extension)
+        #[arg(long, value_enum)]
+        to: Option<CatalogFormatArg>,
+    },
+
+    /// Merge an overlay star list into a base list, matching stars by position
+    Merge {
+        /// Base star list
+        base: PathBuf,
+
+        /// Star list to merge in
+        overlay: PathBuf,
+
+        /// What to do with overlay stars that match a base star
+        #[arg(long, value_enum, default_value = "prefer-existing")]
+        policy: MergePolicyArg,
+
+        /// Maximum distance for two stars to count as the same, in arcseconds
+        #[arg(long, default_value = "30")]
+        tolerance_arcsec: f64,
+
+        /// Also list every name change
+        #[arg(short, long)]
+        verbose: bool,
+
+        /// Write the merged list even if it fails validation
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Time the app's hot paths against a large random catalog
+    Bench {
+        /// Number of stars to synthesize
+        #[arg(long, default_value = "50000")]
+        stars: usize,
+
+        /// Repetitions of each operation
+        #[arg(short, long, default_value = "100")]
+        iterations: usize,
+
+        /// Random seed for the catalog and queries
+        #[arg(long, default_value = "1")]
+        seed: u64,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "table")]
+        format: ReportFormatArg,
     },
 
     /// List all named stars
@@ -65,75 +572,668 @@ enum Commands {
         /// Maximum magnitude to show
         #[arg(short, long, default_value = "6.5")]
         max_magnitude: f64,
+
+        /// Minimum magnitude to show (hides the brightest stars)
+        #[arg(long)]
+        min_mag: Option<f64>,
+
+        /// Only stars in this constellation (abbreviation, any case)
+        #[arg(short, long)]
+        constellation: Option<String>,
+
+        /// Sort order
+        #[arg(short, long, value_enum, default_value = "mag")]
+        sort: SortArg,
+
+        /// Show at most this many stars
+        #[arg(short, long)]
+        limit: Option<usize>,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "table")]
+        format: ListFormatArg,
     },
 }
 
+#[cfg(feature = "cli")]
+impl Commands {
+    /// Whether `--output` redirects this command's report, rather than
+    /// naming a file the command produces (or being unsupported)
+    fn reports_to_output(&self) -> bool {
+        !matches!(
+            self,
+            Commands::Quiz {
+                answers_file: None,
+                ..
+            } | Commands::Flashcards { .. }
+                | Commands::Import { .. }
+                | Commands::Render { .. }
+                | Commands::Convert { .. }
+                | Commands::Merge { .. }
+        )
+    }
+}
+
+/// What to do with a progress file
+#[cfg(feature = "cli")]
+#[derive(Subcommand)]
+enum ProgressAction {
+    /// Write the flashcard deck, with the statistics and achievements
+    /// imported alongside it, as a progress file
+    Export {
+        /// Deck file (default: ~/.stargazer/deck.json)
+        #[arg(long)]
+        deck: Option<PathBuf>,
+    },
+
+    /// Replace the flashcard deck with a progress file's, keeping its
+    /// statistics and achievements for the next export
+    Import {
+        /// Progress file exported from the app or the CLI
+        input: PathBuf,
+
+        /// Deck file (default: ~/.stargazer/deck.json); created if missing
+        #[arg(long)]
+        deck: Option<PathBuf>,
+    },
+}
+
+/// Difficulty presets selectable from the command line
+#[cfg(feature = "cli")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum DifficultyArg {
+    Easy,
+    Medium,
+    Hard,
+}
+
+#[cfg(feature = "cli")]
+impl From<DifficultyArg> for Difficulty {
+    fn from(arg: DifficultyArg) -> Self {
+        match arg {
+            DifficultyArg::Easy => Difficulty::Easy,
+            DifficultyArg::Medium => Difficulty::Medium,
+            DifficultyArg::Hard => Difficulty::Hard,
+        }
+    }
+}
+
+/// Output formats for reports
+#[cfg(feature = "cli")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ReportFormatArg {
+    /// Human-readable table
+    Table,
+    /// JSON for scripts
+    Json,
+}
+
+/// Distractor strategies selectable from the command line
+#[cfg(feature = "cli")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum StrategyArg {
+    /// Same tile, then adjacent tiles, then random
+    Tile,
+    /// Same constellation, then random
+    Constellation,
+    /// Names that look like the answer, then random
+    Confusable,
+    /// Random named stars only
+    Random,
+}
+
+#[cfg(feature = "cli")]
+impl From<StrategyArg> for DistractorStrategy {
+    fn from(arg: StrategyArg) -> Self {
+        match arg {
+            StrategyArg::Tile => DistractorStrategy::Tile,
+            StrategyArg::Constellation => DistractorStrategy::Constellation,
+            StrategyArg::Confusable => DistractorStrategy::Confusable,
+            StrategyArg::Random => DistractorStrategy::Random,
+        }
+    }
+}
+
+/// Catalog formats accepted by the import command
+#[cfg(feature = "cli")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ImportFormatArg {
+    /// HYG database CSV
+    Hyg,
+    /// id,name,ra,dec,magnitude,constellation (as written by `generate --format csv`)
+    SimpleCsv,
+}
+
+#[cfg(feature = "cli")]
+impl From<ImportFormatArg> for ImportFormat {
+    fn from(arg: ImportFormatArg) -> Self {
+        match arg {
+            ImportFormatArg::Hyg => ImportFormat::Hyg,
+            ImportFormatArg::SimpleCsv => ImportFormat::SimpleCsv,
+        }
+    }
+}
+
+/// The deck file given, or `~/.stargazer/deck.json`
+#[cfg(feature = "cli")]
+fn deck_path(deck: Option<PathBuf>) -> PathBuf {
+    deck.unwrap_or_else(|| {
+        let home = std::env::var_os("HOME").unwrap_or_else(|| {
+            Cli::command()
+                .error(
+                    ErrorKind::MissingRequiredArgument,
+                    "HOME is not set; give the deck file with --deck",
+                )
+                .exit()
+        });
+        PathBuf::from(home).join(".stargazer").join("deck.json")
+    })
+}
+
+/// Today's day number (UTC)
+#[cfg(feature = "cli")]
+fn today_utc() -> i64 {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    day_from_unix_seconds(now.as_secs() as i64)
+}
+
+/// Finder chart size and zoom for the star of the day: about 3h by 22°
+#[cfg(feature = "cli")]
+const FINDER_WIDTH: f64 = 48.0;
+#[cfg(feature = "cli")]
+const FINDER_HEIGHT: f64 = 16.0;
+#[cfg(feature = "cli")]
+const FINDER_ZOOM: f64 = 8.0;
+
+/// The catalog commands work on: the built-in one when this binary has
+/// one, otherwise the placeholder
+#[cfg(feature = "cli")]
+fn app_catalog() -> StarCatalog {
+    embedded_catalog().unwrap_or_else(generate_placeholder_catalog)
+}
+
+/// Print the lookup detail card for a star
+#[cfg(feature = "cli")]
+fn print_star_card(out: &mut dyn Write, catalog: &StarCatalog, star: &Star) -> io::Result<()> {
+    writeln!(out, "=== {} ===", star.display_name())?;
+    writeln!(out, "Id:            {}", star.id.0)?;
+    writeln!(
+        out,
+        "RA:            {:.4}h  ({})",
+        star.coord.ra,
+        format_ra_hms(star.coord.ra)
+    )?;
+    writeln!(
+        out,
+        "Dec:           {:+.4}°  ({})",
+        star.coord.dec,
+        format_dec_dms(star.coord.dec)
+    )?;
+    writeln!(out, "Magnitude:     {:.2}", star.magnitude)?;
+    writeln!(
+        out,
+        "Constellation: {}",
+        star.constellation.as_deref().unwrap_or("-")
+    )?;
+    if let Some(facts) = star.facts() {
+        writeln!(out, "Designation:   {}", facts.designations())?;
+        writeln!(out, "Spectral type: {}", facts.spectral_type)?;
+        writeln!(out, "Distance:      {} ly", facts.distance_ly)?;
+    }
+
+    if star.has_name() {
+        let mut named = catalog.named_stars();
+        named.sort_by(|a, b| a.magnitude.total_cmp(&b.magnitude));
+        if let Some(rank) = named.iter().position(|s| s.id == star.id) {
+            writeln!(
+                out,
+                "Brightness:    #{} of {} named stars",
+                rank + 1,
+                named.len()
+            )?;
+        }
+    }
+
+    let stars: Vec<Star> = catalog.all_stars().cloned().collect();
+    let tiles = TileSystem::from_stars(&stars);
+    writeln!(out, "\nTiles:")?;
+    for tile_id in tiles.get_tiles_for_star(star.id).into_iter().flatten() {
+        let named_in_tile = tiles
+            .get_tile(tile_id)
+            .map(|t| t.named_star_ids.len())
+            .unwrap_or(0);
+        let adjacent_named: usize = tiles
+            .get_adjacent_tiles(tile_id)
+            .iter()
+            .map(|t| t.named_star_ids.len())
+            .sum();
+        writeln!(
+            out,
+            "  zoom {}: {:?} ({} named here, {} in adjacent tiles)",
+            tile_id.zoom.0, tile_id, named_in_tile, adjacent_named
+        )?;
+    }
+    Ok(())
+}
+
+/// Print a tile's named stars and its neighbours
+#[cfg(feature = "cli")]
+fn print_tile(
+    out: &mut dyn Write,
+    catalog: &StarCatalog,
+    tiles: &TileSystem,
+    anchor: &Star,
+    zoom: ZoomLevel,
+) -> io::Result<()> {
+    let Some(tile_id) = tiles
+        .get_tiles_for_star(anchor.id)
+        .into_iter()
+        .flatten()
+        .find(|t| t.zoom == zoom)
+    else {
+        eprintln!("{} has no tile at zoom {}", anchor.display_name(), zoom.0);
+        std::process::exit(1);
+    };
+    let Some(tile) = tiles.get_tile(tile_id) else {
+        eprintln!("Tile {:?} is missing from the tile system", tile_id);
+        std::process::exit(1);
+    };
+
+    let mut named: Vec<&Star> = tile
+        .named_star_ids
+        .iter()
+        .filter_map(|&id| catalog.get(id))
+        .collect();
+    named.sort_by(|a, b| a.magnitude.total_cmp(&b.magnitude));
+
+    writeln!(
+        out,
+        "Tile {:?} (containing {})",
+        tile_id,
+        anchor.display_name()
+    )?;
+    if !named.is_empty() {
+        let ras = named.iter().map(|s| s.coord.ra);
+        let decs = named.iter().map(|s| s.coord.dec);
+        writeln!(
+            out,
+            "Named-star extent: RA {:.2}h..{:.2}h, Dec {:+.1}°..{:+.1}°",
+            ras.clone().fold(f64::INFINITY, f64::min),
+            ras.fold(f64::NEG_INFINITY, f64::max),
+            decs.clone().fold(f64::INFINITY, f64::min),
+            decs.fold(f64::NEG_INFINITY, f64::max),
+        )?;
+    }
+
+    writeln!(out, "\nNamed stars ({}):", named.len())?;
+    for star in &named {
+        writeln!(
+            out,
+            "  {:<20} mag {:>5.2}",
+            star.display_name(),
+            star.magnitude
+        )?;
+    }
+
+    let adjacent = tiles.get_adjacent_tiles(tile_id);
+    writeln!(out, "\nAdjacent tiles ({}):", adjacent.len())?;
+    for neighbor in adjacent {
+        writeln!(
+            out,
+            "  {:?}: {} named",
+            neighbor.id,
+            neighbor.named_star_ids.len()
+        )?;
+    }
+    Ok(())
+}
+
+/// Merge policies selectable from the command line
+#[cfg(feature = "cli")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum MergePolicyArg {
+    /// Keep base stars unchanged; only add new ones
+    PreferExisting,
+    /// Replace base fields with the overlay's
+    PreferIncoming,
+    /// Fill in missing names and constellations from the overlay
+    FillMissing,
+}
+
+#[cfg(feature = "cli")]
+impl From<MergePolicyArg> for MergePolicy {
+    fn from(arg: MergePolicyArg) -> Self {
+        match arg {
+            MergePolicyArg::PreferExisting => MergePolicy::PreferExisting,
+            MergePolicyArg::PreferIncoming => MergePolicy::PreferIncoming,
+            MergePolicyArg::FillMissing => MergePolicy::FillMissing,
+        }
+    }
+}
+
+/// Sort keys for star listings
+#[cfg(feature = "cli")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum SortArg {
+    Name,
+    Mag,
+    Ra,
+    Dec,
+    Constellation,
+}
+
+#[cfg(feature = "cli")]
+impl From<SortArg> for StarSort {
+    fn from(arg: SortArg) -> Self {
+        match arg {
+            SortArg::Name => StarSort::Name,
+            SortArg::Mag => StarSort::Magnitude,
+            SortArg::Ra => StarSort::RightAscension,
+            SortArg::Dec => StarSort::Declination,
+            SortArg::Constellation => StarSort::Constellation,
+        }
+    }
+}
+
+/// Output formats for star listings
+#[cfg(feature = "cli")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ListFormatArg {
+    /// Aligned columns
+    Table,
+    /// Same columns as `generate --format csv`
+    Csv,
+    /// Same schema as the catalog JSON the app loads
+    Json,
+}
+
+/// Star list formats accepted by the convert command
+#[cfg(feature = "cli")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum CatalogFormatArg {
+    Json,
+    JsonGz,
+    Csv,
+    Bin,
+}
+
+#[cfg(feature = "cli")]
+impl From<CatalogFormatArg> for CatalogFormat {
+    fn from(arg: CatalogFormatArg) -> Self {
+        match arg {
+            CatalogFormatArg::Json => CatalogFormat::Json,
+            CatalogFormatArg::JsonGz => CatalogFormat::JsonGz,
+            CatalogFormatArg::Csv => CatalogFormat::Csv,
+            CatalogFormatArg::Bin => CatalogFormat::Binary,
+        }
+    }
+}
+
+/// Resolve a file's format from an explicit flag or its extension
+#[cfg(feature = "cli")]
+fn resolve_format(
+    path: &Path,
+    explicit: Option<CatalogFormatArg>,
+) -> Result<CatalogFormat, String> {
+    explicit
+        .map(CatalogFormat::from)
+        .or_else(|| CatalogFormat::from_path(path))
+        .ok_or_else(|| {
+            format!(
+                "cannot infer format of {}; give it explicitly",
+                path.display()
+            )
+        })
+}
+
+/// Flags controlling which stars the quiz asks about and how
+#[cfg(feature = "cli")]
+#[derive(Debug, Clone, Args)]
+struct QuizFilterArgs {
+    /// Difficulty preset limiting target stars by magnitude
+    #[arg(long, value_enum)]
+    difficulty: Option<DifficultyArg>,
+
+    /// Lower magnitude bound, inclusive (overrides the difficulty preset)
+    #[arg(long)]
+    min_mag: Option<f64>,
+
+    /// Upper magnitude bound, exclusive (overrides the difficulty preset)
+    #[arg(long)]
+    max_mag: Option<f64>,
+
+    /// Number of answer choices
+    #[arg(
+        long,
+        default_value = "5",
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(2..)
+    )]
+    choices: usize,
+
+    /// Never offer "none of above"
+    #[arg(long)]
+    no_none: bool,
+
+    /// Make the wrong choices names that look like the answer
+    #[arg(long)]
+    confusable: bool,
+}
+
+#[cfg(feature = "cli")]
+impl Default for QuizFilterArgs {
+    fn default() -> Self {
+        Self {
+            difficulty: None,
+            min_mag: None,
+            max_mag: None,
+            choices: QuizConfig::default().num_choices,
+            no_none: false,
+            confusable: false,
+        }
+    }
+}
+
+/// Quiz settings resolved from command-line flags
+#[cfg(feature = "cli")]
+#[derive(Debug, Clone)]
+struct QuizSettings {
+    config: QuizConfig,
+    magnitude_range: (f64, f64),
+    /// Human-readable description of each active filter
+    active_filters: Vec<String>,
+}
+
+/// Map quiz flags onto a generator config and target magnitude range
+#[cfg(feature = "cli")]
+fn quiz_settings(args: &QuizFilterArgs) -> Result<QuizSettings, String> {
+    let (preset_min, preset_max) = args
+        .difficulty
+        .map(|d| Difficulty::from(d).magnitude_range())
+        .unwrap_or((f64::NEG_INFINITY, f64::INFINITY));
+    let min_mag = args.min_mag.unwrap_or(preset_min);
+    let max_mag = args.max_mag.unwrap_or(preset_max);
+
+    if min_mag >= max_mag {
+        return Err(format!(
+            "--min-mag ({}) must be less than --max-mag ({})",
+            min_mag, max_mag
+        ));
+    }
+
+    let defaults = QuizConfig::default();
+    let config = QuizConfig {
+        num_choices: args.choices,
+        include_none_option: defaults.include_none_option && !args.no_none,
+        distractor_strategy: if args.confusable {
+            DistractorStrategy::Confusable
+        } else {
+            defaults.distractor_strategy
+        },
+        ..defaults
+    };
+
+    let mut active_filters = Vec::new();
+    if let Some(difficulty) = args.difficulty {
+        active_filters.push(format!(
+            "difficulty {}",
+            Difficulty::from(difficulty).name()
+        ));
+    }
+    if let Some(min) = args.min_mag {
+        active_filters.push(format!("min magnitude {:.1}", min));
+    }
+    if let Some(max) = args.max_mag {
+        active_filters.push(format!("max magnitude {:.1}", max));
+    }
+    if config.num_choices != defaults.num_choices {
+        active_filters.push(format!("{} choices", config.num_choices));
+    }
+    if args.no_none {
+        active_filters.push("no \"none of above\"".to_string());
+    }
+    if args.confusable {
+        active_filters.push("look-alike choices".to_string());
+    }
+
+    Ok(QuizSettings {
+        config,
+        magnitude_range: (min_mag, max_mag),
+        active_filters,
+    })
+}
+
 #[cfg(feature = "cli")]
 fn main() {
-    let cli = Cli::parse();
+    // -V and --version come from the library's build info
+    let build = build_info();
+    let matches = Cli::command()
+        .version(build.version_text())
+        .long_version(build.long_version_text())
+        .get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    if let Err(e) = run(cli) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(feature = "cli")]
+fn run(cli: Cli) -> io::Result<()> {
+    let Cli { command, output } = cli;
+
+    // Import, convert and render take --output as the file they produce;
+    // every other command sends its report there
+    let mut out: Box<dyn Write> = match &output {
+        Some(path) if command.reports_to_output() => {
+            let file = std::fs::File::create(path).map_err(|e| {
+                io::Error::new(e.kind(), format!("cannot write {}: {}", path.display(), e))
+            })?;
+            Box::new(io::BufWriter::new(file))
+        }
+        _ => Box::new(io::stdout().lock()),
+    };
 
-    match cli.command {
-        Commands::Generate { format } => {
+    match command {
+        Commands::Generate { format, named_only } => {
             let catalog = generate_placeholder_catalog();
+            let mut stars: Vec<&Star> = if named_only {
+                catalog.named_stars()
+            } else {
+                catalog.all_stars().collect()
+            };
+            stars.sort_by_key(|s| s.id.0);
 
             match format.as_str() {
+                // Same schema as the catalog JSON the app loads
                 "json" => {
-                    let named: Vec<_> = catalog
-                        .named_stars()
-                        .iter()
-                        .map(|s| {
-                            serde_json::json!({
-                                "id": s.id.0,
-                                "name": s.name,
-                                "ra": s.coord.ra,
-                                "dec": s.coord.dec,
-                                "magnitude": s.magnitude,
-                                "constellation": s.constellation,
-                            })
-                        })
-                        .collect();
-                    println!("{}", serde_json::to_string_pretty(&named).unwrap());
-                }
-                "csv" => {
-                    println!("id,name,ra,dec,magnitude,constellation");
-                    for star in catalog.named_stars() {
-                        println!(
-                            "{},{},{:.3},{:.2},{:.2},{}",
-                            star.id.0,
-                            star.name.as_deref().unwrap_or(""),
-                            star.coord.ra,
-                            star.coord.dec,
-                            star.magnitude,
-                            star.constellation.as_deref().unwrap_or("")
-                        );
-                    }
+                    serde_json::to_writer_pretty(&mut out, &stars)?;
+                    writeln!(out)?;
                 }
+                "csv" => write_csv(&mut out, stars)?,
                 _ => {
-                    println!("Generated placeholder catalog:");
-                    println!("  Total stars: {}", catalog.count());
-                    println!("  Named stars: {}", catalog.named_count());
-                    println!("\nSample of named stars:");
+                    writeln!(out, "Generated placeholder catalog:")?;
+                    writeln!(out, "  Total stars: {}", catalog.count())?;
+                    writeln!(out, "  Named stars: {}", catalog.named_count())?;
+                    writeln!(out, "\nSample of named stars:")?;
                     for star in catalog.named_stars().iter().take(10) {
-                        println!(
+                        writeln!(
+                            out,
                             "  {} (mag {:.2}) at RA {:.2}h, Dec {:.1}°",
                             star.display_name(),
                             star.magnitude,
                             star.coord.ra,
                             star.coord.dec
-                        );
+                        )?;
                     }
                 }
             }
         }
 
-        Commands::Stats => {
-            let catalog = generate_placeholder_catalog();
+        Commands::Stats {
+            by_constellation,
+            constellation,
+        } => {
+            let catalog = app_catalog();
 
-            println!("=== Star Catalog Statistics ===\n");
-            println!("Total stars:     {}", catalog.count());
-            println!("Named stars:     {}", catalog.named_count());
+            if by_constellation {
+                writeln!(
+                    out,
+                    "{:<8} {:>6} {:>6}  {:<20} {:>6} {:>9}",
+                    "Const", "Stars", "Named", "Brightest", "Mag", "Mean mag"
+                )?;
+                writeln!(out, "{}", "-".repeat(61))?;
+                for summary in summarize_constellations(&catalog) {
+                    writeln!(
+                        out,
+                        "{:<8} {:>6} {:>6}  {:<20} {:>6.2} {:>9.2}",
+                        summary.name,
+                        summary.total,
+                        summary.named,
+                        summary.brightest.display_name(),
+                        summary.brightest.magnitude,
+                        summary.mean_magnitude
+                    )?;
+                }
+                return Ok(());
+            }
 
-            println!("\nBy brightness category:");
+            if let Some(query) = constellation {
+                let groups = catalog.constellations();
+                let Some((name, stars)) = groups
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case(&query))
+                else {
+                    eprintln!("No stars in constellation '{}'.", query);
+                    std::process::exit(1);
+                };
+
+                writeln!(out, "=== {} ({} stars) ===\n", name, stars.len())?;
+                writeln!(
+                    out,
+                    "{:<20} {:>6} {:>8} {:>8}",
+                    "Name", "Mag", "RA(h)", "Dec(°)"
+                )?;
+                writeln!(out, "{}", "-".repeat(45))?;
+                for star in stars {
+                    writeln!(
+                        out,
+                        "{:<20} {:>6.2} {:>8.3} {:>8.2}",
+                        star.display_name(),
+                        star.magnitude,
+                        star.coord.ra,
+                        star.coord.dec
+                    )?;
+                }
+                return Ok(());
+            }
+
+            writeln!(out, "=== Star Catalog Statistics ===\n")?;
+            writeln!(out, "Total stars:     {}", catalog.count())?;
+            writeln!(out, "Named stars:     {}", catalog.named_count())?;
+
+            writeln!(out, "\nBy brightness category:")?;
             for category in [
                 BrightnessCategory::Brilliant,
                 BrightnessCategory::Bright,
@@ -142,135 +1242,1109 @@ fn main() {
                 BrightnessCategory::VeryFaint,
             ] {
                 let count = catalog.stars_in_category(category).len();
-                println!(
+                writeln!(
+                    out,
                     "  {:?} (mag < {:.1}): {} stars",
                     category,
                     category.magnitude_limit(),
                     count
-                );
+                )?;
             }
 
             let named = catalog.named_stars();
             if !named.is_empty() {
                 let avg_mag: f64 =
                     named.iter().map(|s| s.magnitude).sum::<f64>() / named.len() as f64;
-                println!("\nNamed star statistics:");
-                println!("  Average magnitude: {:.2}", avg_mag);
+                writeln!(out, "\nNamed star statistics:")?;
+                writeln!(out, "  Average magnitude: {:.2}", avg_mag)?;
 
                 let brightest = named
                     .iter()
                     .min_by(|a, b| a.magnitude.partial_cmp(&b.magnitude).unwrap());
                 if let Some(star) = brightest {
-                    println!(
+                    writeln!(
+                        out,
                         "  Brightest: {} (mag {:.2})",
                         star.display_name(),
                         star.magnitude
-                    );
+                    )?;
                 }
             }
         }
 
-        Commands::Quiz { count } => {
-            let catalog = generate_placeholder_catalog();
-            let config = QuizConfig::default();
-            let generator = QuizGenerator::new(&catalog, config);
-            let mut rng = rand::rngs::SmallRng::from_entropy();
+        Commands::Quiz {
+            count,
+            seed,
+            tui,
+            answers_file,
+            format,
+            telemetry_log,
+            filters,
+        } => {
+            if output.is_some() && answers_file.is_none() {
+                Cli::command()
+                    .error(
+                        ErrorKind::ArgumentConflict,
+                        "quiz is interactive and cannot write to --output",
+                    )
+                    .exit();
+            }
+            let settings = quiz_settings(&filters)
+                .unwrap_or_else(|msg| Cli::command().error(ErrorKind::ValueValidation, msg).exit());
+            let catalog = app_catalog();
+            let generator = QuizGenerator::new(&catalog, settings.config.clone());
+            let seed = seed.unwrap_or_else(rand::random);
+            let mut rng = rand::rngs::SmallRng::seed_from_u64(seed);
+            let (min_mag, max_mag) = settings.magnitude_range;
+            let questions =
+                generator.generate_batch_for_magnitude_range(min_mag, max_mag, count, &mut rng);
+
+            if questions.is_empty() {
+                eprintln!("No named stars match the selected filters.");
+                std::process::exit(1);
+            }
+
+            let difficulty = filters.difficulty.map(Difficulty::from);
+            let telemetry: Box<dyn Telemetry> = match &telemetry_log {
+                Some(path) => Box::new(JsonLinesTelemetry::append(path).map_err(|e| {
+                    io::Error::new(e.kind(), format!("cannot write {}: {}", path.display(), e))
+                })?),
+                None => Box::new(NoTelemetry),
+            };
 
-            let mut correct = 0;
-            let mut total = 0;
-
-            println!("=== Stargazer Quiz ===\n");
-            println!("Answer each question by typing the number of your choice.\n");
-
-            for q_num in 1..=count {
-                if let Some(question) = generator.generate_random(&mut rng) {
-                    println!("Question {}/{}:", q_num, count);
-                    println!(
-                        "Which star is located at RA {:.2}h, Dec {:.1}°?",
-                        catalog
-                            .get(question.target_star)
-                            .map(|s| s.coord.ra)
-                            .unwrap_or(0.0),
-                        catalog
-                            .get(question.target_star)
-                            .map(|s| s.coord.dec)
-                            .unwrap_or(0.0)
-                    );
-
-                    for (i, choice) in question.choices.iter().enumerate() {
-                        println!("  {}. {}", i + 1, choice);
+            if let Some(path) = answers_file {
+                let answers: Vec<String> = std::fs::read_to_string(&path)
+                    .map_err(|e| {
+                        io::Error::new(e.kind(), format!("cannot read {}: {}", path.display(), e))
+                    })?
+                    .lines()
+                    .map(str::to_string)
+                    .collect();
+                let report = run_scripted(&catalog, &questions, &answers);
+                report_scripted(&report, difficulty, telemetry.as_ref());
+
+                match format {
+                    ReportFormatArg::Table => {
+                        writeln!(out, "Seed: {}\n", seed)?;
+                        writeln!(out, "{:>3}  {:<20} {:<20} Result", "#", "Target", "Given")?;
+                        writeln!(out, "{}", "-".repeat(55))?;
+                        for answer in &report.answers {
+                            let result = match (&answer.note, answer.correct) {
+                                (_, true) => "correct".to_string(),
+                                (Some(note), false) => format!("incorrect ({})", note),
+                                (None, false) => {
+                                    format!("incorrect (answer: {})", answer.correct_answer)
+                                }
+                            };
+                            writeln!(
+                                out,
+                                "{:>3}  {:<20} {:<20} {}",
+                                answer.question,
+                                answer.target_name,
+                                answer.given.as_deref().unwrap_or("-"),
+                                result
+                            )?;
+                        }
+                        writeln!(
+                            out,
+                            "\nScore: {}/{} ({:.0}%), best streak {}",
+                            report.score.correct,
+                            report.answers.len(),
+                            report.accuracy,
+                            report.score.best_streak
+                        )?;
                     }
+                    ReportFormatArg::Json => {
+                        let mut value = serde_json::to_value(&report).unwrap();
+                        value["seed"] = seed.into();
+                        writeln!(out, "{}", serde_json::to_string_pretty(&value).unwrap())?;
+                    }
+                }
+                return out.flush();
+            }
+
+            if tui && io::stdin().is_terminal() && io::stdout().is_terminal() {
+                let game = HeadlessGame::new(catalog, settings.config, seed);
+                let quiz = tui::run(game, questions)?;
+                let score = quiz.score();
+                writeln!(
+                    out,
+                    "Score: {}/{} ({:.0}%), best streak {}",
+                    score.correct,
+                    quiz.guesses().len(),
+                    score.accuracy(),
+                    score.best_streak
+                )?;
+                writeln!(out, "Seed: {} (replay with --seed {})", seed, seed)?;
+                return Ok(());
+            }
+
+            let mut score = ScoreState::default();
+            let mut guesses = Vec::new();
+
+            writeln!(out, "=== Stargazer Quiz ===\n")?;
+            writeln!(out, "Seed: {} (replay with --seed {})", seed, seed)?;
+            writeln!(
+                out,
+                "Answer each question by typing the number of your choice.\n"
+            )?;
+
+            for (index, question) in questions.iter().enumerate() {
+                writeln!(out, "Question {}/{}:", index + 1, count)?;
+                writeln!(
+                    out,
+                    "Which star is located at RA {:.2}h, Dec {:.1}°?",
+                    catalog
+                        .get(question.target_star)
+                        .map(|s| s.coord.ra)
+                        .unwrap_or(0.0),
+                    catalog
+                        .get(question.target_star)
+                        .map(|s| s.coord.dec)
+                        .unwrap_or(0.0)
+                )?;
 
-                    print!("\nYour answer: ");
-                    io::stdout().flush().unwrap();
-
-                    let mut input = String::new();
-                    io::stdin().read_line(&mut input).unwrap();
-
-                    if let Ok(choice_num) = input.trim().parse::<usize>() {
-                        if choice_num > 0 && choice_num <= question.choices.len() {
-                            let selected = &question.choices[choice_num - 1];
-                            if selected == &question.correct_answer {
-                                println!("✓ Correct!\n");
-                                correct += 1;
-                            } else {
-                                println!("✗ Wrong! The answer was: {}\n", question.correct_answer);
-                            }
-                            total += 1;
+                for (i, choice) in question.choices.iter().enumerate() {
+                    writeln!(out, "  {}. {}", i + 1, choice)?;
+                }
+
+                write!(out, "\nYour answer: ")?;
+                out.flush()?;
+                let asked_at = std::time::Instant::now();
+
+                let mut input = String::new();
+                io::stdin().read_line(&mut input).unwrap();
+
+                if let Ok(choice_num) = input.trim().parse::<usize>() {
+                    if choice_num > 0 && choice_num <= question.choices.len() {
+                        let selected = &question.choices[choice_num - 1];
+                        let was_correct = selected == &question.correct_answer;
+                        if was_correct {
+                            writeln!(out, "✓ Correct!\n")?;
+                            score.record_correct();
                         } else {
-                            println!("Invalid choice.\n");
+                            writeln!(
+                                out,
+                                "✗ Wrong! The answer was: {}\n",
+                                question.correct_answer
+                            )?;
+                            score.record_incorrect();
                         }
+                        let elapsed_ms = asked_at.elapsed().as_millis() as u64;
+                        telemetry.question_answered(was_correct, difficulty, elapsed_ms);
+                        guesses.push(GuessSummary {
+                            star_name: question.correct_answer.clone(),
+                            user_answer: selected.clone(),
+                            was_correct,
+                            timestamp: unix_millis_now(),
+                        });
                     } else {
-                        println!("Please enter a number.\n");
+                        writeln!(out, "Invalid choice.\n")?;
                     }
+                } else {
+                    writeln!(out, "Please enter a number.\n")?;
                 }
             }
 
-            println!("=== Results ===");
-            println!(
+            if !guesses.is_empty() {
+                telemetry.session_ended(&events::session_ended(&guesses, &score));
+            }
+
+            writeln!(out, "=== Results ===")?;
+            writeln!(
+                out,
                 "Score: {}/{} ({:.0}%)",
-                correct,
-                total,
-                if total > 0 {
-                    (correct as f64 / total as f64) * 100.0
-                } else {
-                    0.0
-                }
-            );
+                score.correct,
+                guesses.len(),
+                score.accuracy()
+            )?;
+            if settings.active_filters.is_empty() {
+                writeln!(out, "Filters: none")?;
+            } else {
+                writeln!(out, "Filters: {}", settings.active_filters.join(", "))?;
+            }
         }
 
-        Commands::ListNamed { max_magnitude } => {
-            let catalog = generate_placeholder_catalog();
+        Commands::Flashcards {
+            deck,
+            new_per_day,
+            date,
+        } => {
+            if output.is_some() {
+                Cli::command()
+                    .error(
+                        ErrorKind::ArgumentConflict,
+                        "flashcards is interactive and cannot write to --output",
+                    )
+                    .exit();
+            }
+            let path = deck_path(deck);
+            let today = date.unwrap_or_else(today_utc);
+
+            let catalog = app_catalog();
+            let mut deck = flashcards::load_deck(&path)?;
+            let new = deck.introduce_new(&catalog, today, new_per_day);
+            let due = deck.due(today).len();
+            flashcards::save_deck(&path, &deck)?;
+
+            writeln!(out, "Deck: {} ({} cards)", path.display(), deck.cards.len())?;
+            writeln!(
+                out,
+                "{}: {} cards due, including {} new\n",
+                format_date(today),
+                due,
+                new.len()
+            )?;
 
-            println!("Named stars (magnitude < {:.1}):\n", max_magnitude);
-            println!(
-                "{:<20} {:>6} {:>8} {:>8} {:>10}",
-                "Name", "Mag", "RA(h)", "Dec(°)", "Const"
+            let mut rng = rand::rngs::SmallRng::from_entropy();
+            let summary = flashcards::review(
+                &catalog,
+                &mut deck,
+                &path,
+                today,
+                &mut io::stdin().lock(),
+                &mut out,
+                &mut rng,
+            )?;
+
+            writeln!(out, "=== Today's results ===")?;
+            writeln!(
+                out,
+                "Reviewed {} of {} ({} correct)",
+                summary.reviewed, due, summary.correct
+            )?;
+            if summary.remaining > 0 {
+                writeln!(out, "{} cards still due today", summary.remaining)?;
+            }
+            if let Some(next) = deck.next_due().filter(|&day| day > today) {
+                let count = deck.due(next).len();
+                writeln!(out, "Next review: {} ({} cards)", format_date(next), count)?;
+            }
+        }
+
+        Commands::Progress { action } => match action {
+            ProgressAction::Export { deck } => {
+                let progress = progress::export(&deck_path(deck))?;
+                writeln!(out, "{}", progress.to_json())?;
+            }
+            ProgressAction::Import { input, deck } => {
+                let path = deck_path(deck);
+                let imported = progress::load_progress(&input)?;
+                let (cards, stars, achievements) = (
+                    imported.deck.cards.len(),
+                    imported.stats.len(),
+                    imported.achievements.len(),
+                );
+                progress::import(&path, imported)?;
+                writeln!(out, "Imported {}", input.display())?;
+                writeln!(out, "Deck:         {} ({} cards)", path.display(), cards)?;
+                writeln!(out, "Stars:        {}", stars)?;
+                writeln!(out, "Achievements: {}", achievements)?;
+            }
+        },
+
+        Commands::Simulate {
+            questions,
+            seed,
+            zoom,
+            format,
+            filters,
+        } => {
+            let settings = quiz_settings(&filters)
+                .unwrap_or_else(|msg| Cli::command().error(ErrorKind::ValueValidation, msg).exit());
+            let catalog = app_catalog();
+            let stars: Vec<Star> = catalog.all_stars().cloned().collect();
+            let tiles = TileSystem::from_stars(&stars);
+            let generator = QuizGenerator::with_tiles(
+                &catalog,
+                settings.config.clone(),
+                &tiles,
+                ZoomLevel(zoom),
             );
-            println!("{}", "-".repeat(56));
+            let seed = seed.unwrap_or_else(rand::random);
+            let mut rng = rand::rngs::SmallRng::seed_from_u64(seed);
+            let report = simulate(
+                &catalog,
+                &generator,
+                settings.magnitude_range,
+                questions,
+                &mut rng,
+            );
+
+            match format {
+                ReportFormatArg::Table => {
+                    writeln!(out, "=== Quiz Simulation ===\n")?;
+                    writeln!(out, "Seed: {} (replay with --seed {})", seed, seed)?;
+                    if settings.active_filters.is_empty() {
+                        writeln!(out, "Filters: none\n")?;
+                    } else {
+                        writeln!(out, "Filters: {}\n", settings.active_filters.join(", "))?;
+                    }
+                    write!(out, "{}", report)?;
+                }
+                ReportFormatArg::Json => {
+                    let mut value = serde_json::to_value(&report).unwrap();
+                    value["seed"] = seed.into();
+                    writeln!(out, "{}", serde_json::to_string_pretty(&value).unwrap())?;
+                }
+            }
+
+            if !report.violations.is_empty() {
+                out.flush()?;
+                std::process::exit(1);
+            }
+        }
+
+        Commands::Import {
+            format,
+            input,
+            max_magnitude,
+            dry_run,
+        } => {
+            let output = output.unwrap_or_else(|| PathBuf::from("stars.json"));
+            let file = std::fs::File::open(&input).unwrap_or_else(|e| {
+                eprintln!("Cannot open {}: {}", input.display(), e);
+                std::process::exit(1);
+            });
+
+            let Imported {
+                stars,
+                facts,
+                report,
+            } = import_catalog(format.into(), io::BufReader::new(file), max_magnitude)
+                .unwrap_or_else(|e| {
+                    eprintln!("Import failed: {}", e);
+                    std::process::exit(1);
+                });
+
+            writeln!(out, "=== Import Summary ===")?;
+            writeln!(out, "Rows read:    {}", report.rows_read)?;
+            writeln!(out, "Stars kept:   {}", report.stars_kept)?;
+            if let Some(max) = max_magnitude {
+                writeln!(out, "Fainter than {:.1}: {}", max, report.filtered_out)?;
+            }
+            writeln!(out, "Warnings:     {}", report.warnings.len())?;
+            for warning in report.warnings.iter().take(20) {
+                writeln!(out, "  {}", warning)?;
+            }
+            if report.warnings.len() > 20 {
+                writeln!(out, "  ... and {} more", report.warnings.len() - 20)?;
+            }
+
+            if dry_run {
+                writeln!(out, "\nDry run: nothing written.")?;
+            } else {
+                let json = serde_json::to_string(&stars).unwrap();
+                if let Err(e) = std::fs::write(&output, json) {
+                    eprintln!("Cannot write {}: {}", output.display(), e);
+                    std::process::exit(1);
+                }
+                writeln!(out, "\nWrote {} stars to {}", stars.len(), output.display())?;
+                if !facts.is_empty() {
+                    let facts_path = output.with_extension("facts.json");
+                    let json = serde_json::to_string(&facts).unwrap();
+                    if let Err(e) = std::fs::write(&facts_path, json) {
+                        eprintln!("Cannot write {}: {}", facts_path.display(), e);
+                        std::process::exit(1);
+                    }
+                    writeln!(
+                        out,
+                        "Wrote facts for {} stars to {}",
+                        facts.len(),
+                        facts_path.display()
+                    )?;
+                }
+            }
+        }
+
+        Commands::Lookup { query } => {
+            let catalog = app_catalog();
+
+            match catalog.lookup(&query) {
+                LookupResult::Found(star) => print_star_card(&mut out, &catalog, star)?,
+                LookupResult::Ambiguous(candidates) => {
+                    writeln!(out, "'{}' matches {} stars:", query, candidates.len())?;
+                    for star in candidates {
+                        writeln!(out, "  {:<20} (id {})", star.display_name(), star.id.0)?;
+                    }
+                }
+                LookupResult::NotFound(suggestions) => {
+                    eprintln!("No star matches '{}'.", query);
+                    if !suggestions.is_empty() {
+                        let names: Vec<_> = suggestions.iter().map(|s| s.display_name()).collect();
+                        eprintln!("Did you mean: {}?", names.join(", "));
+                    }
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        Commands::StarOfTheDay { date } => {
+            let day = date.unwrap_or_else(today_utc);
+            let catalog = app_catalog();
+            let Some(star) = star_of_the_day(&catalog, day) else {
+                eprintln!("No star of the day before {}.", format_date(SEQUENCE_START));
+                std::process::exit(1);
+            };
 
-            let mut named: Vec<_> = catalog
-                .named_stars()
+            writeln!(out, "Star of the day for {}\n", format_date(day))?;
+            print_star_card(&mut out, &catalog, star)?;
+            writeln!(out, "\n{}", star_fact(&catalog, star))?;
+            if let Some((neighbor, separation)) = catalog
+                .nearest_stars(&star.coord, 2, true)
                 .into_iter()
-                .filter(|s| s.magnitude < max_magnitude)
-                .collect();
-            named.sort_by(|a, b| a.magnitude.partial_cmp(&b.magnitude).unwrap());
+                .find(|(s, _)| s.id != star.id)
+            {
+                writeln!(
+                    out,
+                    "Its nearest named neighbor is {} ({:.1}° away).",
+                    neighbor.display_name(),
+                    separation
+                )?;
+            }
+
+            let viewport = Viewport {
+                center_ra: star.coord.ra,
+                center_dec: star.coord.dec,
+                zoom: FINDER_ZOOM,
+                ..Viewport::new(FINDER_WIDTH, FINDER_HEIGHT)
+            };
+            let options = AsciiRenderOptions {
+                show_labels: true,
+                ..Default::default()
+            };
+            writeln!(out, "\nFinder chart:")?;
+            writeln!(out, "{}", "-".repeat(FINDER_WIDTH as usize))?;
+            for line in render_ascii(&catalog, &viewport, &options) {
+                writeln!(out, "{}", line.trim_end())?;
+            }
+            writeln!(out, "{}", "-".repeat(FINDER_WIDTH as usize))?;
+        }
+
+        Commands::Nearest {
+            ra,
+            dec,
+            count,
+            named_only,
+        } => {
+            let catalog = app_catalog();
+            let coord = CelestialCoord::new(ra, dec);
+
+            writeln!(
+                out,
+                "Closest {}stars to RA {} Dec {}:\n",
+                if named_only { "named " } else { "" },
+                format_ra_hms(ra),
+                format_dec_dms(dec)
+            )?;
+            writeln!(
+                out,
+                "{:<20} {:>6} {:>10} {:>10}",
+                "Star", "Mag", "Sep(°)", "Sep(′)"
+            )?;
+            writeln!(out, "{}", "-".repeat(49))?;
+
+            for (star, separation) in catalog.nearest_stars(&coord, count, named_only) {
+                writeln!(
+                    out,
+                    "{:<20} {:>6.2} {:>10.3} {:>10.1}",
+                    star.display_name(),
+                    star.magnitude,
+                    separation,
+                    separation * 60.0
+                )?;
+            }
+        }
+
+        Commands::Map {
+            ra,
+            dec,
+            zoom,
+            width,
+            height,
+            mag,
+            labels,
+            grid,
+        } => {
+            if !(MIN_ZOOM..=MAX_ZOOM).contains(&zoom) {
+                Cli::command()
+                    .error(
+                        ErrorKind::ValueValidation,
+                        format!("--zoom must be between {} and {}", MIN_ZOOM, MAX_ZOOM),
+                    )
+                    .exit();
+            }
+
+            let catalog = app_catalog();
+            let viewport = Viewport {
+                center_ra: ra,
+                center_dec: dec,
+                zoom,
+                ..Viewport::new(f64::from(width), f64::from(height))
+            };
+            let options = AsciiRenderOptions {
+                magnitude_limit: mag
+                    .unwrap_or_else(|| LodSettings::default().magnitude_limit(zoom)),
+                show_labels: labels,
+                show_grid: grid,
+                ..Default::default()
+            };
+
+            writeln!(out, "{}", "-".repeat(usize::from(width)))?;
+            for line in render_ascii(&catalog, &viewport, &options) {
+                writeln!(out, "{}", line.trim_end())?;
+            }
+            writeln!(out, "{}", "-".repeat(usize::from(width)))?;
+            writeln!(
+                out,
+                "Centre RA {} Dec {}, zoom {}, magnitude < {:.1}   @ < 1  * < 3  . fainter",
+                format_ra_hms(ra),
+                format_dec_dms(dec),
+                zoom,
+                options.magnitude_limit
+            )?;
+        }
+
+        Commands::Render {
+            ra,
+            dec,
+            zoom,
+            mag,
+            grid,
+            constellations,
+            width,
+            height,
+        } => {
+            let output = output.unwrap_or_else(|| {
+                Cli::command()
+                    .error(
+                        ErrorKind::MissingRequiredArgument,
+                        "render needs --output <file>",
+                    )
+                    .exit()
+            });
+            if !(MIN_ZOOM..=MAX_ZOOM).contains(&zoom) {
+                Cli::command()
+                    .error(
+                        ErrorKind::ValueValidation,
+                        format!("--zoom must be between {} and {}", MIN_ZOOM, MAX_ZOOM),
+                    )
+                    .exit();
+            }
+
+            let catalog = app_catalog();
+            let viewport = Viewport {
+                center_ra: ra,
+                center_dec: dec,
+                zoom,
+                ..Viewport::new(f64::from(width), f64::from(height))
+            };
+            let options = SvgExportOptions {
+                magnitude_limit: mag
+                    .unwrap_or_else(|| LodSettings::default().magnitude_limit(zoom)),
+                show_grid: grid,
+                show_constellations: constellations,
+                ..Default::default()
+            };
+
+            let svg = render_to_svg_string(&catalog, &viewport, &options);
+            if let Err(e) = std::fs::write(&output, &svg) {
+                eprintln!("Cannot write {}: {}", output.display(), e);
+                std::process::exit(1);
+            }
+
+            writeln!(
+                out,
+                "Wrote {} ({}x{}, RA {} Dec {}, zoom {}, magnitude < {:.1})",
+                output.display(),
+                width,
+                height,
+                format_ra_hms(ra),
+                format_dec_dms(dec),
+                zoom,
+                options.magnitude_limit
+            )?;
+        }
+
+        Commands::Tiles {
+            zoom,
+            star,
+            ra,
+            dec,
+            summary,
+        } => {
+            let catalog = app_catalog();
+            let stars: Vec<Star> = catalog.all_stars().cloned().collect();
+            let tiles = TileSystem::from_stars(&stars);
+
+            if summary {
+                writeln!(out, "Named stars per tile:\n")?;
+                write!(
+                    out,
+                    "{}",
+                    TileSummaryTable(&summarize_tiles(&catalog, &tiles))
+                )?;
+                return Ok(());
+            }
+
+            let anchor = match (star, ra.zip(dec)) {
+                (Some(query), _) => match catalog.lookup(&query) {
+                    LookupResult::Found(star) => star,
+                    _ => {
+                        eprintln!("No unique star matches '{}'; try `lookup` first.", query);
+                        std::process::exit(1);
+                    }
+                },
+                // The tile holding a point is the tile of the star closest to it
+                (None, Some((ra, dec))) => {
+                    let coord = CelestialCoord::new(ra, dec);
+                    match catalog.nearest_stars(&coord, 1, false).first() {
+                        Some((star, _)) => star,
+                        None => {
+                            eprintln!("Catalog is empty.");
+                            std::process::exit(1);
+                        }
+                    }
+                }
+                (None, None) => Cli::command()
+                    .error(
+                        ErrorKind::MissingRequiredArgument,
+                        "give --star, --ra/--dec, or --summary",
+                    )
+                    .exit(),
+            };
+
+            print_tile(&mut out, &catalog, &tiles, anchor, ZoomLevel(zoom))?;
+        }
+
+        Commands::Distractors {
+            star,
+            zoom,
+            count,
+            strategy,
+            seed,
+        } => {
+            let catalog = app_catalog();
+            let target = match catalog.lookup(&star) {
+                LookupResult::Found(target) => target,
+                _ => {
+                    eprintln!("No unique star matches '{}'; try `lookup` first.", star);
+                    std::process::exit(1);
+                }
+            };
+
+            let stars: Vec<Star> = catalog.all_stars().cloned().collect();
+            let tiles = TileSystem::from_stars(&stars);
+            let generator =
+                QuizGenerator::with_tiles(&catalog, QuizConfig::default(), &tiles, ZoomLevel(zoom));
+            let seed = seed.unwrap_or_else(rand::random);
+            let mut rng = rand::rngs::SmallRng::seed_from_u64(seed);
+            let distractors =
+                generator.generate_distractors(target, count, strategy.into(), &mut rng);
+
+            writeln!(
+                out,
+                "Distractors for {} (mag {:.2}, zoom {}, {:?} strategy)",
+                target.display_name(),
+                target.magnitude,
+                zoom,
+                strategy
+            )?;
+            writeln!(out, "Seed: {} (replay with --seed {})\n", seed, seed)?;
+            writeln!(out, "{:<20} {:>6} {:>8}  Source", "Star", "Mag", "Sep(°)")?;
+            writeln!(out, "{}", "-".repeat(56))?;
+
+            for distractor in &distractors {
+                let Some(star) = catalog.get(distractor.star_id) else {
+                    continue;
+                };
+                writeln!(
+                    out,
+                    "{:<20} {:>6.2} {:>8.2}  {}",
+                    distractor.name,
+                    star.magnitude,
+                    angular_separation(&target.coord, &star.coord),
+                    distractor.source.name()
+                )?;
+            }
+
+            if distractors.len() < count {
+                writeln!(
+                    out,
+                    "\nOnly {} of {} distractors available.",
+                    distractors.len(),
+                    count
+                )?;
+            }
+        }
+
+        Commands::Validate {
+            input,
+            format,
+            strict,
+            constellation_lines,
+        } => {
+            let fail = |msg: String| -> ! {
+                eprintln!("Validation failed: {}", msg);
+                std::process::exit(1);
+            };
+
+            let in_format = resolve_format(&input, format).unwrap_or_else(|e| fail(e));
+            let bytes = std::fs::read(&input)
+                .unwrap_or_else(|e| fail(format!("cannot read {}: {}", input.display(), e)));
+            let stars = decode_stars(&bytes, in_format).unwrap_or_else(|e| fail(e));
+
+            let mut report = ValidationReport::for_stars(&stars);
+            if let Some(path) = constellation_lines {
+                let text = std::fs::read_to_string(&path)
+                    .unwrap_or_else(|e| fail(format!("cannot read {}: {}", path.display(), e)));
+                let lines: ConstellationLines = serde_json::from_str(&text)
+                    .unwrap_or_else(|e| fail(format!("{}: {}", path.display(), e)));
+                report.check_constellation_lines(&stars, &lines);
+            }
+
+            writeln!(out, "Checked {} stars in {}", stars.len(), input.display())?;
+            if report.is_clean() {
+                writeln!(out, "No problems found.")?;
+            } else {
+                write!(out, "{}", report)?;
+            }
+
+            let code = report.exit_code(strict);
+            if code != 0 {
+                out.flush()?;
+                std::process::exit(code);
+            }
+        }
+
+        Commands::Convert { input, from, to } => {
+            let output = output.unwrap_or_else(|| {
+                Cli::command()
+                    .error(
+                        ErrorKind::MissingRequiredArgument,
+                        "convert needs --output <file>",
+                    )
+                    .exit()
+            });
+            let fail = |msg: String| -> ! {
+                eprintln!("Convert failed: {}", msg);
+                std::process::exit(1);
+            };
+
+            let in_format = resolve_format(&input, from).unwrap_or_else(|e| fail(e));
+            let out_format = resolve_format(&output, to).unwrap_or_else(|e| fail(e));
+
+            let bytes = std::fs::read(&input)
+                .unwrap_or_else(|e| fail(format!("cannot read {}: {}", input.display(), e)));
+            let stars = decode_stars(&bytes, in_format).unwrap_or_else(|e| fail(e));
+            let encoded = encode_stars(&stars, out_format).unwrap_or_else(|e| fail(e));
+            std::fs::write(&output, &encoded)
+                .unwrap_or_else(|e| fail(format!("cannot write {}: {}", output.display(), e)));
+
+            writeln!(out, "Converted {} stars", stars.len())?;
+            writeln!(
+                out,
+                "  {} ({}): {} bytes",
+                input.display(),
+                in_format.name(),
+                bytes.len()
+            )?;
+            writeln!(
+                out,
+                "  {} ({}): {} bytes ({:.0}%)",
+                output.display(),
+                out_format.name(),
+                encoded.len(),
+                encoded.len() as f64 / bytes.len().max(1) as f64 * 100.0
+            )?;
+        }
+
+        Commands::Merge {
+            base,
+            overlay,
+            policy,
+            tolerance_arcsec,
+            verbose,
+            force,
+        } => {
+            let output = output.unwrap_or_else(|| {
+                Cli::command()
+                    .error(
+                        ErrorKind::MissingRequiredArgument,
+                        "merge needs --output <file>",
+                    )
+                    .exit()
+            });
+            let fail = |msg: String| -> ! {
+                eprintln!("Merge failed: {}", msg);
+                std::process::exit(1);
+            };
+            let read = |path: &Path| -> Vec<Star> {
+                let format = resolve_format(path, None).unwrap_or_else(|e| fail(e));
+                let bytes = std::fs::read(path)
+                    .unwrap_or_else(|e| fail(format!("cannot read {}: {}", path.display(), e)));
+                decode_stars(&bytes, format)
+                    .unwrap_or_else(|e| fail(format!("{}: {}", path.display(), e)))
+            };
+
+            let base_stars = read(&base);
+            let overlay_stars = read(&overlay);
+            let options = MergeOptions {
+                policy: policy.into(),
+                tolerance_arcsec,
+            };
+            let (merged, summary) = merge_catalogs(&base_stars, &overlay_stars, &options);
+
+            writeln!(
+                out,
+                "Merged {} overlay stars into {} ({}, {}\" tolerance)",
+                overlay_stars.len(),
+                base_stars.len(),
+                options.policy.name(),
+                tolerance_arcsec
+            )?;
+            write!(out, "{}", summary)?;
+
+            if verbose && summary.name_changes().next().is_some() {
+                writeln!(out, "\nName changes:")?;
+                for change in summary.name_changes() {
+                    writeln!(out, "  star {}", change.id.0)?;
+                    writeln!(
+                        out,
+                        "  - {}",
+                        change.old_name.as_deref().unwrap_or("(unnamed)")
+                    )?;
+                    writeln!(
+                        out,
+                        "  + {}",
+                        change.new_name.as_deref().unwrap_or("(unnamed)")
+                    )?;
+                }
+            }
+
+            let report = ValidationReport::for_stars(&merged);
+            if report.exit_code(false) != 0 {
+                write!(out, "\n{}", report)?;
+                if !force {
+                    out.flush()?;
+                    fail(format!(
+                        "not writing {}; the merged list has errors (use --force to write anyway)",
+                        output.display()
+                    ));
+                }
+            }
+
+            let format = resolve_format(&output, None).unwrap_or_else(|e| fail(e));
+            let encoded = encode_stars(&merged, format).unwrap_or_else(|e| fail(e));
+            std::fs::write(&output, encoded)
+                .unwrap_or_else(|e| fail(format!("cannot write {}: {}", output.display(), e)));
+            writeln!(
+                out,
+                "\nWrote {} stars to {}",
+                merged.len(),
+                output.display()
+            )?;
+        }
+
+        Commands::ListNamed {
+            max_magnitude,
+            min_mag,
+            constellation,
+            sort,
+            limit,
+            format,
+        } => {
+            let catalog = app_catalog();
+            let filter = NamedStarFilter {
+                min_magnitude: min_mag,
+                max_magnitude,
+                constellation,
+                limit,
+            };
+            let named = catalog.list_named(&filter, sort.into());
+
+            match format {
+                ListFormatArg::Json => {
+                    serde_json::to_writer_pretty(&mut out, &named)?;
+                    writeln!(out)?;
+                    return out.flush();
+                }
+                ListFormatArg::Csv => {
+                    write_csv(&mut out, named)?;
+                    return out.flush();
+                }
+                ListFormatArg::Table => {}
+            }
+
+            match min_mag {
+                Some(min) => writeln!(
+                    out,
+                    "Named stars ({:.1} <= magnitude < {:.1}):\n",
+                    min, max_magnitude
+                )?,
+                None => writeln!(out, "Named stars (magnitude < {:.1}):\n", max_magnitude)?,
+            }
+            let name_width = named
+                .iter()
+                .map(|s| s.display_name().chars().count())
+                .max()
+                .unwrap_or(0)
+                .max("Name".len());
+            writeln!(
+                out,
+                "{:<w$} {:>6} {:>8} {:>8} {:>10}",
+                "Name",
+                "Mag",
+                "RA(h)",
+                "Dec(°)",
+                "Const",
+                w = name_width
+            )?;
+            writeln!(out, "{}", "-".repeat(name_width + 36))?;
 
             for star in named {
-                println!(
-                    "{:<20} {:>6.2} {:>8.3} {:>8.2} {:>10}",
+                writeln!(
+                    out,
+                    "{:<w$} {:>6.2} {:>8.3} {:>8.2} {:>10}",
                     star.display_name(),
                     star.magnitude,
                     star.coord.ra,
                     star.coord.dec,
-                    star.constellation.as_deref().unwrap_or("-")
-                );
+                    star.constellation.as_deref().unwrap_or("-"),
+                    w = name_width
+                )?;
+            }
+        }
+
+        Commands::Bench {
+            stars,
+            iterations,
+            seed,
+            format,
+        } => {
+            let mut rng = rand::rngs::SmallRng::seed_from_u64(seed);
+            let stars = bench::synthesize_stars(stars, &mut rng);
+            let report = bench::run(stars, iterations, seed, &mut rng);
+
+            match format {
+                ReportFormatArg::Table => write!(out, "{}", report)?,
+                ReportFormatArg::Json => {
+                    writeln!(out, "{}", serde_json::to_string_pretty(&report).unwrap())?
+                }
             }
         }
     }
+
+    out.flush()
 }
 
 #[cfg(not(feature = "cli"))]
 fn main() {
     eprintln!("CLI feature not enabled. Run with: cargo run --features cli");
 }
+
+#[cfg(all(test, feature = "cli"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_flags_match_default_config() {
+        let settings = quiz_settings(&QuizFilterArgs::default()).unwrap();
+        let defaults = QuizConfig::default();
+
+        assert_eq!(settings.config.num_choices, defaults.num_choices);
+        assert_eq!(
+            settings.config.include_none_option,
+            defaults.include_none_option
+        );
+        assert_eq!(settings.magnitude_range, (f64::NEG_INFINITY, f64::INFINITY));
+        assert!(settings.active_filters.is_empty());
+    }
+
+    #[test]
+    fn test_difficulty_with_override() {
+        let args = QuizFilterArgs {
+            difficulty: Some(DifficultyArg::Medium),
+            min_mag: Some(1.0),
+            ..QuizFilterArgs::default()
+        };
+        let settings = quiz_settings(&args).unwrap();
+
+        assert_eq!(
+            settings.magnitude_range,
+            (1.0, Difficulty::Medium.magnitude_range().1)
+        );
+        assert_eq!(settings.active_filters.len(), 2);
+    }
+
+    #[test]
+    fn test_choices_and_no_none() {
+        let args = QuizFilterArgs {
+            choices: 3,
+            no_none: true,
+            ..QuizFilterArgs::default()
+        };
+        let settings = quiz_settings(&args).unwrap();
+
+        assert_eq!(settings.config.num_choices, 3);
+        assert!(!settings.config.include_none_option);
+        assert_eq!(
+            settings.config.distractor_strategy,
+            DistractorStrategy::Tile
+        );
+
+        let args = QuizFilterArgs {
+            confusable: true,
+            ..QuizFilterArgs::default()
+        };
+        let settings = quiz_settings(&args).unwrap();
+        assert_eq!(
+            settings.config.distractor_strategy,
+            DistractorStrategy::Confusable
+        );
+        assert_eq!(settings.active_filters, ["look-alike choices"]);
+    }
+
+    #[test]
+    fn test_inverted_magnitude_range_is_rejected() {
+        let args = QuizFilterArgs {
+            min_mag: Some(4.0),
+            max_mag: Some(2.0),
+            ..QuizFilterArgs::default()
+        };
+        assert!(quiz_settings(&args).is_err());
+    }
+
+    #[test]
+    fn test_too_few_choices_is_a_parse_error() {
+        let result = Cli::try_parse_from(["stargazer-cli", "quiz", "--choices", "1"]);
+        assert!(result.is_err());
+
+        let result = Cli::try_parse_from(["stargazer-cli", "quiz", "--choices", "2"]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_nearest_accepts_negative_and_sexagesimal_input() {
+        let cli = Cli::try_parse_from([
+            "stargazer-cli",
+            "nearest",
+            "--ra",
+            "6h45m",
+            "--dec",
+            "-16:42",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Nearest { ra, dec, .. } => {
+                assert!((ra - 6.75).abs() < 1e-9);
+                assert!((dec + 16.7).abs() < 1e-9);
+            }
+            _ => panic!("expected nearest command"),
+        }
+
+        assert!(
+            Cli::try_parse_from(["stargazer-cli", "nearest", "--ra", "25", "--dec", "0"]).is_err()
+        );
+    }
+}
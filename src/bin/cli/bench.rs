@@ -0,0 +1,189 @@
+//! Timing of the app's hot paths
+//!
+//! Synthesizes a random catalog and times the same library calls the web
+//! app makes when loading data, panning the map and starting a quiz.
+//! `stars_in_range` and `query_viewport` answer the same visibility
+//! queries, by scanning the catalog and through the spatial index.
+
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+use stargazer_poc::data::{CelestialCoord, Star, StarCatalog, StarId, TileSystem, ZoomLevel};
+use stargazer_poc::game::{QuizConfig, QuizGenerator};
+use stargazer_poc::utils::{LodSettings, SkyIndex, Viewport, MAX_ZOOM, MIN_ZOOM};
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Fraction of synthesized stars that get a name
+const NAMED_FRACTION: f64 = 0.05;
+
+/// Constellations assigned to synthesized named stars
+const CONSTELLATIONS: [&str; 8] = ["And", "Aql", "CMa", "Cyg", "Lyr", "Ori", "Sco", "UMa"];
+
+/// Zoom level used for tile-aware distractors, as in the app at 4x zoom
+const QUIZ_ZOOM: ZoomLevel = ZoomLevel(2);
+
+/// Timing of one operation
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Measurement {
+    pub name: &'static str,
+    pub iterations: usize,
+    pub total_ns: u128,
+    pub ns_per_op: f64,
+}
+
+/// All measurements from one run
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BenchReport {
+    pub stars: usize,
+    pub named: usize,
+    pub seed: u64,
+    pub measurements: Vec<Measurement>,
+}
+
+/// A random catalog of `count` stars, brighter stars rarer as in the sky
+pub fn synthesize_stars<R: Rng>(count: usize, rng: &mut R) -> Vec<Star> {
+    (0..count)
+        .map(|i| {
+            let named = rng.gen_bool(NAMED_FRACTION);
+            Star {
+                id: StarId(i as u32),
+                name: named.then(|| format!("Star {}", i)),
+                coord: CelestialCoord {
+                    ra: rng.gen_range(0.0..24.0),
+                    // Uniform over the sphere rather than over declination
+                    dec: rng.gen_range(-1.0f64..1.0).asin().to_degrees(),
+                },
+                magnitude: -1.5 + 11.0 * rng.gen::<f64>().sqrt(),
+                constellation: named.then(|| CONSTELLATIONS.choose(rng).unwrap().to_string()),
+            }
+        })
+        .collect()
+}
+
+fn time(name: &'static str, iterations: usize, mut op: impl FnMut(usize)) -> Measurement {
+    let start = Instant::now();
+    for i in 0..iterations {
+        op(i);
+    }
+    let total: Duration = start.elapsed();
+    Measurement {
+        name,
+        iterations,
+        total_ns: total.as_nanos(),
+        ns_per_op: total.as_nanos() as f64 / iterations.max(1) as f64,
+    }
+}
+
+/// Time each hot path `iterations` times against `stars`
+pub fn run<R: Rng>(stars: Vec<Star>, iterations: usize, seed: u64, rng: &mut R) -> BenchReport {
+    let mut measurements = Vec::new();
+
+    measurements.push(time("build_indices", iterations, |_| {
+        let mut catalog = StarCatalog::new();
+        for star in &stars {
+            catalog.add_star(star.clone());
+        }
+        catalog.rebuild_indices();
+        black_box(catalog);
+    }));
+
+    let mut catalog = StarCatalog::new();
+    for star in &stars {
+        catalog.add_star(star.clone());
+    }
+    catalog.rebuild_indices();
+
+    let lod = LodSettings::default();
+    let viewports: Vec<Viewport> = (0..iterations)
+        .map(|_| Viewport {
+            center_ra: rng.gen_range(0.0..24.0),
+            center_dec: rng.gen_range(-60.0..60.0),
+            zoom: rng.gen_range(MIN_ZOOM..MAX_ZOOM),
+            ..Viewport::new(1200.0, 600.0)
+        })
+        .collect();
+    measurements.push(time("stars_in_range", iterations, |i| {
+        let viewport = &viewports[i];
+        let (ra_min, ra_max) = viewport.ra_range();
+        let (dec_min, dec_max) = viewport.dec_range();
+        black_box(catalog.stars_in_range(
+            ra_min,
+            ra_max,
+            dec_min,
+            dec_max,
+            lod.magnitude_limit(viewport.zoom),
+        ));
+    }));
+
+    // The same views through the spatial index, as the map queries them
+    let index = SkyIndex::new(&catalog);
+    measurements.push(time("query_viewport", iterations, |i| {
+        let viewport = &viewports[i];
+        black_box(index.query_viewport(&catalog, viewport, lod.magnitude_limit(viewport.zoom)));
+    }));
+
+    let named = catalog.named_stars();
+    let targets: Vec<&Star> = (0..iterations)
+        .filter_map(|_| named.choose(rng).copied())
+        .collect();
+    let mut op_rng = rand::rngs::SmallRng::seed_from_u64(seed);
+    measurements.push(time("random_distractors", targets.len(), |i| {
+        let name = targets[i].name.as_deref().unwrap_or_default();
+        black_box(catalog.random_distractors(name, 4, &mut op_rng));
+    }));
+
+    measurements.push(time("tile_system", iterations, |_| {
+        black_box(TileSystem::from_stars(&stars));
+    }));
+
+    measurements.push(time("nearest_star", iterations, |i| {
+        let viewport = &viewports[i];
+        let coord = CelestialCoord {
+            ra: viewport.center_ra,
+            dec: viewport.center_dec,
+        };
+        black_box(catalog.nearest_named_star(&coord, 180.0));
+    }));
+
+    let tiles = TileSystem::from_stars(&stars);
+    let generator = QuizGenerator::with_tiles(&catalog, QuizConfig::default(), &tiles, QUIZ_ZOOM);
+    measurements.push(time("generate_question", targets.len(), |i| {
+        black_box(generator.generate_for_star(targets[i], &mut op_rng));
+    }));
+
+    BenchReport {
+        stars: catalog.count(),
+        named: named.len(),
+        seed,
+        measurements,
+    }
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Catalog: {} stars ({} named), seed {}\n",
+            self.stars, self.named, self.seed
+        )?;
+        writeln!(
+            f,
+            "{:<20} {:>10} {:>14} {:>12}",
+            "Operation", "Iterations", "ns/op", "Total ms"
+        )?;
+        writeln!(f, "{}", "-".repeat(59))?;
+        for m in &self.measurements {
+            writeln!(
+                f,
+                "{:<20} {:>10} {:>14.0} {:>12.3}",
+                m.name,
+                m.iterations,
+                m.ns_per_op,
+                m.total_ns as f64 / 1e6
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,205 @@
+//! Spaced-repetition flashcards in the terminal
+//!
+//! Loads a [`ReviewDeck`] from disk, asks about each due card with fresh
+//! distractors, and saves the deck after every answer. Saves go through a
+//! temporary file and a rename, so an interrupted session never leaves a
+//! half-written deck behind.
+
+use rand::Rng;
+use serde::Serialize;
+use stargazer_poc::data::{StarCatalog, TileSystem, ZoomLevel};
+use stargazer_poc::game::review::ReviewDeck;
+use stargazer_poc::game::scripted::resolve_answer;
+use stargazer_poc::game::{QuizConfig, QuizGenerator};
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+/// Zoom level used for tile-aware distractors
+const DISTRACTOR_ZOOM: ZoomLevel = ZoomLevel(2);
+
+/// Results of one review session
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionSummary {
+    pub reviewed: usize,
+    pub correct: usize,
+    /// Due cards left unanswered when input ended
+    pub remaining: usize,
+}
+
+/// Load a deck, or start an empty one if the file does not exist
+pub fn load_deck(path: &Path) -> io::Result<ReviewDeck> {
+    match std::fs::read_to_string(path) {
+        Ok(json) => serde_json::from_str(&json).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        }),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(ReviewDeck::default()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Write a deck by replacing the file, creating its directory if needed
+pub fn save_deck(path: &Path, deck: &ReviewDeck) -> io::Result<()> {
+    save_json(path, deck)
+}
+
+/// Write `value` as JSON by replacing the file, creating its directory if
+/// needed
+pub fn save_json<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    let json = serde_json::to_string_pretty(value).map_err(io::Error::other)?;
+    std::fs::write(&temp, json)?;
+    std::fs::rename(&temp, path)
+}
+
+/// Review every card due on `today`, reading answers from `input`
+///
+/// The deck is saved to `path` after each answer. Unreadable answers are
+/// asked again; the session ends early when `input` runs out.
+pub fn review<R: BufRead, W: Write, G: Rng>(
+    catalog: &StarCatalog,
+    deck: &mut ReviewDeck,
+    path: &Path,
+    today: i64,
+    input: &mut R,
+    out: &mut W,
+    rng: &mut G,
+) -> io::Result<SessionSummary> {
+    let stars: Vec<_> = catalog.all_stars().cloned().collect();
+    let tiles = TileSystem::from_stars(&stars);
+    let generator =
+        QuizGenerator::with_tiles(catalog, QuizConfig::default(), &tiles, DISTRACTOR_ZOOM);
+
+    let due = deck.due(today);
+    let mut summary = SessionSummary::default();
+
+    for (index, &id) in due.iter().enumerate() {
+        let Some(star) = catalog.get(id) else {
+            continue;
+        };
+        let Some(question) = generator.generate_for_star(star, rng) else {
+            continue;
+        };
+
+        writeln!(out, "Card {}/{}:", index + 1, due.len())?;
+        writeln!(
+            out,
+            "Which star is located at RA {:.2}h, Dec {:.1}°?",
+            star.coord.ra, star.coord.dec
+        )?;
+        for (i, choice) in question.choices.iter().enumerate() {
+            writeln!(out, "  {}. {}", i + 1, choice)?;
+        }
+
+        let answer = loop {
+            write!(out, "\nYour answer: ")?;
+            out.flush()?;
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 {
+                summary.remaining = due.len() - index;
+                writeln!(out)?;
+                return Ok(summary);
+            }
+            match resolve_answer(&question, Some(&line)) {
+                Ok(answer) => break answer,
+                Err(reason) => writeln!(out, "{}; enter a number or a name.", reason)?,
+            }
+        };
+
+        let correct = *answer == *question.correct_answer;
+        if correct {
+            writeln!(out, "✓ Correct!\n")?;
+            summary.correct += 1;
+        } else {
+            writeln!(
+                out,
+                "✗ Wrong! The answer was: {}\n",
+                question.correct_answer
+            )?;
+        }
+        summary.reviewed += 1;
+
+        if let Some(card) = deck.card_mut(id) {
+            card.record(correct, today);
+        }
+        save_deck(path, deck)?;
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use stargazer_poc::data::generate_placeholder_catalog;
+    use std::path::PathBuf;
+
+    fn temp_deck(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("stargazer-flashcards-{}", std::process::id()))
+            .join(name)
+    }
+
+    #[test]
+    fn test_missing_deck_starts_empty() {
+        let deck = load_deck(&temp_deck("missing.json")).unwrap();
+        assert!(deck.cards.is_empty());
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let catalog = generate_placeholder_catalog();
+        let path = temp_deck("round-trip.json");
+        let mut deck = ReviewDeck::default();
+        deck.introduce_new(&catalog, 20_000, 3);
+        deck.cards[1].record(false, 20_000);
+
+        save_deck(&path, &deck).unwrap();
+        assert_eq!(load_deck(&path).unwrap(), deck);
+        // The temporary file is renamed away
+        assert!(!path.with_extension("json.tmp").exists());
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_two_day_session() {
+        let catalog = generate_placeholder_catalog();
+        let path = temp_deck("two-days.json");
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(5);
+        let mut out = Vec::new();
+
+        // Day 1: two new cards; garbage is asked again, then answered
+        let mut deck = load_deck(&path).unwrap();
+        deck.introduce_new(&catalog, 1, 2);
+        let mut input = io::Cursor::new("bogus\n1\n2\n");
+        let summary = review(
+            &catalog, &mut deck, &path, 1, &mut input, &mut out, &mut rng,
+        )
+        .unwrap();
+        assert_eq!(summary.reviewed, 2);
+        assert!(String::from_utf8_lossy(&out).contains("not one of the choices"));
+
+        // Day 2: both come back from disk, with two more new cards
+        let mut deck = load_deck(&path).unwrap();
+        assert_eq!(deck.due(2).len(), 2);
+        deck.introduce_new(&catalog, 2, 2);
+        assert_eq!(deck.due(2).len(), 4);
+
+        // Input ends after one answer; the rest stay due
+        let mut input = io::Cursor::new("1\n");
+        let summary = review(
+            &catalog, &mut deck, &path, 2, &mut input, &mut out, &mut rng,
+        )
+        .unwrap();
+        assert_eq!((summary.reviewed, summary.remaining), (1, 3));
+        assert_eq!(load_deck(&path).unwrap().due(2).len(), 3);
+        std::fs::remove_file(&path).ok();
+    }
+}
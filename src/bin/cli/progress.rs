@@ -0,0 +1,93 @@
+//! Progress files in the terminal
+//!
+//! The CLI keeps only a flashcard deck, so importing a progress file
+//! writes its deck over the deck file and keeps the rest, the statistics
+//! and achievements, in [`KEPT_FILE`] beside it. Exporting puts the
+//! current deck back together with what was kept, so progress can go from
+//! a browser to the CLI and back without losing anything.
+
+use crate::flashcards::{load_deck, save_deck, save_json};
+use stargazer_poc::game::progress::{Progress, PROGRESS_VERSION};
+use stargazer_poc::game::review::ReviewDeck;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// File beside the deck holding the rest of imported progress
+pub const KEPT_FILE: &str = "progress.json";
+
+/// Where the rest of the progress imported with the deck at `deck` is kept
+pub fn kept_path(deck: &Path) -> PathBuf {
+    deck.with_file_name(KEPT_FILE)
+}
+
+/// Read a progress file
+pub fn load_progress(path: &Path) -> io::Result<Progress> {
+    let json = std::fs::read_to_string(path)?;
+    Progress::from_json(&json).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e),
+        )
+    })
+}
+
+/// The deck at `deck` and whatever was imported with it
+pub fn export(deck: &Path) -> io::Result<Progress> {
+    let kept = match load_progress(&kept_path(deck)) {
+        Ok(kept) => kept,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Progress::default(),
+        Err(e) => return Err(e),
+    };
+    Ok(Progress {
+        version: PROGRESS_VERSION,
+        deck: load_deck(deck)?,
+        ..kept
+    })
+}
+
+/// Replace the deck at `deck` with `progress`'s, keeping the rest beside it
+pub fn import(deck: &Path, progress: Progress) -> io::Result<()> {
+    save_deck(deck, &progress.deck)?;
+    save_json(
+        &kept_path(deck),
+        &Progress {
+            deck: ReviewDeck::default(),
+            ..progress
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stargazer_poc::data::{generate_placeholder_catalog, StarId};
+    use stargazer_poc::game::Achievement;
+
+    #[test]
+    fn test_import_then_export_round_trip() {
+        let dir = std::env::temp_dir().join(format!("stargazer-progress-{}", std::process::id()));
+        let deck = dir.join("deck.json");
+
+        // Nothing imported yet: just the (empty) deck
+        assert_eq!(export(&deck).unwrap(), Progress::default());
+
+        let mut progress = Progress::default();
+        progress.stats.record(StarId(2), false, 500.0);
+        progress
+            .deck
+            .introduce_new(&generate_placeholder_catalog(), 20_000, 3);
+        progress
+            .achievements
+            .unlock(Achievement::FirstCorrect, 500.0);
+        import(&deck, progress.clone()).unwrap();
+
+        assert_eq!(load_deck(&deck).unwrap(), progress.deck);
+        assert!(load_progress(&kept_path(&deck))
+            .unwrap()
+            .deck
+            .cards
+            .is_empty());
+        assert_eq!(export(&deck).unwrap(), progress);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,443 @@
+//! Full-screen terminal quiz
+//!
+//! [`TuiQuiz`] is the screen state machine: it takes key presses and
+//! renders frames as plain lines without touching the terminal, so its
+//! transitions can be tested directly. Scoring is left to a
+//! [`HeadlessGame`]. [`run`] drives it with crossterm.
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::{cursor, execute, queue, style, terminal};
+use stargazer_poc::game::engine::HeadlessGame;
+use stargazer_poc::game::{GuessSummary, QuizQuestion, ScoreState};
+use stargazer_poc::utils::ascii_render::{render_ascii, AsciiRenderOptions};
+use stargazer_poc::utils::Viewport;
+use std::io::{self, Write};
+
+/// Zoom of the mini-map around the target
+const MINI_MAP_ZOOM: f64 = 6.0;
+
+/// Width of the score progress bar
+const PROGRESS_WIDTH: usize = 20;
+
+/// Input the quiz reacts to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Up,
+    Down,
+    /// A number key, 1-based
+    Digit(usize),
+    Enter,
+    Quit,
+}
+
+/// Which screen is showing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Screen {
+    /// Waiting for an answer
+    Question,
+    /// Showing whether the last answer was right
+    Answered { correct: bool },
+    /// Final results
+    Summary,
+}
+
+/// State of a full-screen quiz session
+pub struct TuiQuiz {
+    game: HeadlessGame,
+    questions: Vec<QuizQuestion>,
+    current: usize,
+    selected: usize,
+    screen: Screen,
+}
+
+impl TuiQuiz {
+    /// Ask `questions` in order, scoring them in `game`
+    pub fn new(mut game: HeadlessGame, questions: Vec<QuizQuestion>) -> Self {
+        let screen = match questions.first() {
+            Some(first) => {
+                game.ask(first.clone());
+                Screen::Question
+            }
+            None => Screen::Summary,
+        };
+        Self {
+            game,
+            questions,
+            current: 0,
+            selected: 0,
+            screen,
+        }
+    }
+
+    pub fn score(&self) -> &ScoreState {
+        &self.game.state().score
+    }
+
+    pub fn guesses(&self) -> &[GuessSummary] {
+        &self.game.state().guess_history
+    }
+
+    fn question(&self) -> &QuizQuestion {
+        &self.questions[self.current]
+    }
+
+    /// Apply a key press; returns `false` once the session is over
+    pub fn handle_key(&mut self, key: Key) -> bool {
+        match (self.screen, key) {
+            (Screen::Summary, Key::Enter | Key::Quit) => return false,
+            (_, Key::Quit) => self.screen = Screen::Summary,
+            (Screen::Question, Key::Up) => {
+                let len = self.question().choices.len();
+                self.selected = (self.selected + len - 1) % len;
+            }
+            (Screen::Question, Key::Down) => {
+                self.selected = (self.selected + 1) % self.question().choices.len();
+            }
+            (Screen::Question, Key::Digit(n))
+                if (1..=self.question().choices.len()).contains(&n) =>
+            {
+                self.selected = n - 1;
+                self.answer();
+            }
+            (Screen::Question, Key::Enter) => self.answer(),
+            (Screen::Answered { .. }, Key::Enter) => {
+                if self.current + 1 < self.questions.len() {
+                    self.current += 1;
+                    self.selected = 0;
+                    self.game.ask(self.questions[self.current].clone());
+                    self.screen = Screen::Question;
+                } else {
+                    self.screen = Screen::Summary;
+                }
+            }
+            _ => {}
+        }
+        true
+    }
+
+    fn answer(&mut self) {
+        let given = self.question().choices[self.selected].clone();
+        if let Some(correct) = self.game.answer(&given) {
+            self.screen = Screen::Answered { correct };
+        }
+    }
+
+    /// Render the current screen as `height` lines of at most `width` characters
+    pub fn render(&self, width: usize, height: usize) -> Vec<String> {
+        let mut lines = match self.screen {
+            Screen::Summary => self.render_summary(),
+            Screen::Question | Screen::Answered { .. } => self.render_question(width, height),
+        };
+        lines.resize(height, String::new());
+        lines
+            .into_iter()
+            .map(|line| line.chars().take(width).collect())
+            .collect()
+    }
+
+    fn render_question(&self, width: usize, height: usize) -> Vec<String> {
+        let question = self.question();
+        let catalog = self.game.catalog();
+        let target = catalog.get(question.target_star);
+        let left_width = (width * 3 / 5).max(10);
+        let map_height = height.saturating_sub(6).max(3);
+
+        let mut left = vec![
+            format!("Question {}/{}", self.current + 1, self.questions.len()),
+            match target {
+                Some(star) => format!(
+                    "Which star is at RA {:.2}h, Dec {:.1}°?",
+                    star.coord.ra, star.coord.dec
+                ),
+                None => String::new(),
+            },
+            String::new(),
+        ];
+        if let Some(star) = target {
+            let viewport = Viewport {
+                center_ra: star.coord.ra,
+                center_dec: star.coord.dec,
+                zoom: MINI_MAP_ZOOM,
+                ..Viewport::new(left_width as f64, map_height as f64)
+            };
+            let mut map = render_ascii(catalog, &viewport, &AsciiRenderOptions::default());
+            let target = viewport.project(&star.coord);
+            if target.on_screen {
+                mark_cell(&mut map, target.point.x as usize, target.point.y as usize);
+            }
+            left.extend(map);
+        }
+
+        let mut right = Vec::new();
+        right.push("Choices (↑/↓ or 1-9, Enter):".to_string());
+        right.push(String::new());
+        for (i, choice) in question.choices.iter().enumerate() {
+            let cursor = if i == self.selected { '>' } else { ' ' };
+            let verdict = match self.screen {
+                Screen::Answered { .. } if *choice == question.correct_answer => "  ✓",
+                Screen::Answered { correct: false } if i == self.selected => "  ✗",
+                _ => "",
+            };
+            right.push(format!("{} {}. {}{}", cursor, i + 1, choice, verdict));
+        }
+        right.push(String::new());
+        match self.screen {
+            Screen::Answered { correct: true } => right.push("Correct! Enter to continue".into()),
+            Screen::Answered { correct: false } => right.push(format!(
+                "Wrong, it was {}. Enter to continue",
+                question.correct_answer
+            )),
+            _ => {}
+        }
+
+        let rows = left.len().max(right.len());
+        let mut lines: Vec<String> = (0..rows)
+            .map(|i| {
+                let l = left.get(i).map(String::as_str).unwrap_or("");
+                let r = right.get(i).map(String::as_str).unwrap_or("");
+                format!("{:<w$} │ {}", l, r, w = left_width)
+            })
+            .collect();
+
+        lines.truncate(height.saturating_sub(2));
+        lines.resize(height.saturating_sub(2), String::new());
+        lines.push("─".repeat(width));
+        lines.push(self.score_bar());
+        lines
+    }
+
+    fn score_bar(&self) -> String {
+        let score = self.score();
+        let answered = self.guesses().len();
+        let filled = answered * PROGRESS_WIDTH / self.questions.len().max(1);
+        format!(
+            "Score {}/{}  Streak {}  Best {}  [{}{}]  q to finish",
+            score.correct,
+            answered,
+            score.streak,
+            score.best_streak,
+            "#".repeat(filled),
+            "-".repeat(PROGRESS_WIDTH - filled)
+        )
+    }
+
+    fn render_summary(&self) -> Vec<String> {
+        let score = self.score();
+        let guesses = self.guesses();
+        let mut lines = vec![
+            "=== Quiz Summary ===".to_string(),
+            String::new(),
+            format!("Total guesses: {}", guesses.len()),
+            format!("Correct:       {}", score.correct),
+            format!("Incorrect:     {}", score.incorrect),
+            format!("Accuracy:      {:.0}%", score.accuracy()),
+            format!("Best streak:   {}", score.best_streak),
+            String::new(),
+        ];
+        if guesses.is_empty() {
+            lines.push("No guesses yet!".to_string());
+        }
+        // Most recent first, as in the web summary
+        for guess in guesses.iter().rev() {
+            let icon = if guess.was_correct { '✓' } else { '✗' };
+            lines.push(format!(
+                "{} {:<20} {}",
+                icon, guess.star_name, guess.user_answer
+            ));
+        }
+        lines.push(String::new());
+        lines.push("Press Enter to exit".to_string());
+        lines
+    }
+}
+
+/// Bracket a mini-map cell, where the target star sits
+fn mark_cell(map: &mut [String], col: usize, row: usize) {
+    if let Some(line) = map.get_mut(row) {
+        let mut cells: Vec<char> = line.chars().collect();
+        if col >= 1 && col + 1 < cells.len() {
+            cells[col - 1] = '[';
+            cells[col + 1] = ']';
+            *line = cells.into_iter().collect();
+        }
+    }
+}
+
+/// Map a terminal key to quiz input
+fn key_from(code: KeyCode) -> Option<Key> {
+    match code {
+        KeyCode::Up | KeyCode::Char('k') => Some(Key::Up),
+        KeyCode::Down | KeyCode::Char('j') => Some(Key::Down),
+        KeyCode::Enter | KeyCode::Char(' ') => Some(Key::Enter),
+        KeyCode::Esc | KeyCode::Char('q') => Some(Key::Quit),
+        KeyCode::Char(c) => c.to_digit(10).map(|d| Key::Digit(d as usize)),
+        _ => None,
+    }
+}
+
+/// Restores the terminal however the session ends
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Play the quiz full-screen, returning the final state for reporting
+pub fn run(game: HeadlessGame, questions: Vec<QuizQuestion>) -> io::Result<TuiQuiz> {
+    let mut quiz = TuiQuiz::new(game, questions);
+    let _guard = TerminalGuard::enter()?;
+    let mut stdout = io::stdout();
+
+    loop {
+        let (cols, rows) = terminal::size()?;
+        queue!(stdout, terminal::Clear(terminal::ClearType::All))?;
+        for (i, line) in quiz
+            .render(usize::from(cols), usize::from(rows))
+            .iter()
+            .enumerate()
+        {
+            queue!(stdout, cursor::MoveTo(0, i as u16), style::Print(line))?;
+        }
+        stdout.flush()?;
+
+        if let Event::Key(event) = event::read()? {
+            if event.kind != KeyEventKind::Press {
+                continue;
+            }
+            if let Some(key) = key_from(event.code) {
+                if !quiz.handle_key(key) {
+                    return Ok(quiz);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use stargazer_poc::data::{generate_placeholder_catalog, StarCatalog};
+    use stargazer_poc::game::{QuizConfig, QuizGenerator};
+
+    fn questions(catalog: &StarCatalog, count: usize) -> Vec<QuizQuestion> {
+        let config = QuizConfig {
+            include_none_option: false,
+            ..QuizConfig::default()
+        };
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(1);
+        QuizGenerator::new(catalog, config).generate_batch(count, &mut rng)
+    }
+
+    fn start(questions: Vec<QuizQuestion>, catalog: StarCatalog) -> TuiQuiz {
+        TuiQuiz::new(
+            HeadlessGame::new(catalog, QuizConfig::default(), 1),
+            questions,
+        )
+    }
+
+    fn index_of_correct(question: &QuizQuestion) -> usize {
+        question
+            .choices
+            .iter()
+            .position(|c| *c == question.correct_answer)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_navigation_wraps() {
+        let catalog = generate_placeholder_catalog();
+        let mut quiz = start(questions(&catalog, 1), catalog);
+        let len = quiz.question().choices.len();
+
+        quiz.handle_key(Key::Up);
+        assert_eq!(quiz.selected, len - 1);
+        quiz.handle_key(Key::Down);
+        assert_eq!(quiz.selected, 0);
+        assert_eq!(quiz.screen, Screen::Question);
+    }
+
+    #[test]
+    fn test_answer_then_advance_to_summary() {
+        let catalog = generate_placeholder_catalog();
+        let questions = questions(&catalog, 2);
+        let right = index_of_correct(&questions[0]);
+        let wrong = (index_of_correct(&questions[1]) + 1) % questions[1].choices.len();
+        let mut quiz = start(questions, catalog);
+
+        assert!(quiz.handle_key(Key::Digit(right + 1)));
+        assert_eq!(quiz.screen, Screen::Answered { correct: true });
+        quiz.handle_key(Key::Enter);
+        assert_eq!(quiz.screen, Screen::Question);
+        assert_eq!(quiz.selected, 0);
+
+        quiz.handle_key(Key::Digit(wrong + 1));
+        assert_eq!(quiz.screen, Screen::Answered { correct: false });
+        quiz.handle_key(Key::Enter);
+        assert_eq!(quiz.screen, Screen::Summary);
+
+        assert_eq!(quiz.score().correct, 1);
+        assert_eq!(quiz.score().incorrect, 1);
+        assert_eq!(quiz.score().best_streak, 1);
+        assert_eq!(quiz.guesses().len(), 2);
+        assert!(!quiz.handle_key(Key::Enter));
+    }
+
+    #[test]
+    fn test_out_of_range_digit_is_ignored() {
+        let catalog = generate_placeholder_catalog();
+        let mut quiz = start(questions(&catalog, 1), catalog);
+
+        quiz.handle_key(Key::Digit(9));
+        quiz.handle_key(Key::Digit(0));
+        assert_eq!(quiz.screen, Screen::Question);
+        assert!(quiz.guesses().is_empty());
+    }
+
+    #[test]
+    fn test_quit_shows_summary_first() {
+        let catalog = generate_placeholder_catalog();
+        let mut quiz = start(questions(&catalog, 3), catalog);
+
+        assert!(quiz.handle_key(Key::Quit));
+        assert_eq!(quiz.screen, Screen::Summary);
+        assert!(!quiz.handle_key(Key::Quit));
+    }
+
+    #[test]
+    fn test_render_fits_terminal() {
+        let catalog = generate_placeholder_catalog();
+        let mut quiz = start(questions(&catalog, 2), catalog);
+
+        // Answer and advance through both questions
+        for _ in 0..4 {
+            let frame = quiz.render(80, 24);
+            assert_eq!(frame.len(), 24);
+            assert!(frame.iter().all(|l| l.chars().count() <= 80));
+            quiz.handle_key(Key::Enter);
+        }
+        assert!(quiz.render(80, 24)[0].contains("Summary"));
+    }
+
+    #[test]
+    fn test_question_frame_hides_target_name() {
+        let catalog = generate_placeholder_catalog();
+        let quiz = start(questions(&catalog, 1), catalog);
+        let answer = quiz.question().correct_answer.clone();
+
+        // The name may only appear once, among the choices on the right
+        let frame = quiz.render(100, 30).join("\n");
+        assert_eq!(frame.matches(&*answer).count(), 1);
+    }
+}
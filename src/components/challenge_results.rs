@@ -0,0 +1,83 @@
+//! Challenge Results Component
+//!
+//! Shown when the last daily challenge question is answered: the score,
+//! a mark per question and a summary to copy and share.
+
+use crate::app::crash::copy_to_clipboard;
+use crate::app::i18n::{t, tf, use_locale, TextKey};
+use crate::components::focus::use_dialog_focus;
+use crate::game::{DailyChallenge, GameAction};
+use crate::utils::dates::format_date;
+use yew::prelude::*;
+
+/// Props for ChallengeResults component
+#[derive(Properties, PartialEq)]
+pub struct ChallengeResultsProps {
+    /// The finished challenge
+    pub challenge: DailyChallenge,
+
+    /// Callback for dispatching game actions
+    pub on_action: Callback<GameAction>,
+}
+
+/// The challenge results component
+#[function_component(ChallengeResults)]
+pub fn challenge_results(props: &ChallengeResultsProps) -> Html {
+    use_locale();
+    let challenge = &props.challenge;
+    let share_text = challenge.share_text();
+
+    let dialog = use_dialog_focus();
+    let copied = use_state(|| false);
+
+    let on_copy = {
+        let share_text = share_text.clone();
+        let copied = copied.clone();
+        Callback::from(move |_| {
+            copy_to_clipboard(&share_text);
+            copied.set(true);
+        })
+    };
+
+    dialog.overlay(
+        "challenge-results",
+        "challenge-title",
+        html! {
+            <>
+                <div class="summary-header">
+                    <h2 id="challenge-title">{ t(TextKey::DailyChallenge) }</h2>
+                    <button
+                        ref={dialog.close_ref.clone()}
+                        onclick={props.on_action.reform(|_| GameAction::EndChallenge)}
+                        class="close-button"
+                        aria-label={t(TextKey::CloseResults)}
+                    >
+                        { "×" }
+                    </button>
+                </div>
+
+                <p class="challenge-date">{ format_date(challenge.day) }</p>
+                <p class="challenge-score">
+                    { tf(TextKey::ChallengeScore, &[&challenge.correct(), &challenge.questions.len()]) }
+                </p>
+                <ol class="challenge-marks">
+                    { for challenge.results.iter().map(|&right| html! {
+                        <li class={if right { "correct" } else { "incorrect" }}>
+                            { if right { "✓" } else { "✗" } }
+                        </li>
+                    }) }
+                </ol>
+                <pre class="share-text">{ &share_text }</pre>
+
+                <div class="summary-actions">
+                    <button class="copy-button" onclick={on_copy}>
+                        { t(if *copied { TextKey::Copied } else { TextKey::CopyResults }) }
+                    </button>
+                    <button class="close-btn" onclick={props.on_action.reform(|_| GameAction::EndChallenge)}>
+                        { t(TextKey::Close) }
+                    </button>
+                </div>
+            </>
+        },
+    )
+}
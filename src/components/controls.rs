@@ -2,10 +2,47 @@
 //!
 //! Provides UI controls for zoom, magnitude filter, and display settings.
 
-use crate::game::GameAction;
-use web_sys::HtmlInputElement;
+use super::countdown::format_countdown;
+use super::debounce::use_debounced_callback;
+use super::time_control::TimeControl;
+use crate::app::audio;
+use crate::app::i18n::{t, tf, use_locale, Locale, TextKey};
+use crate::game::modes::TIME_ATTACK_SECONDS;
+use crate::game::{GameAction, MapRenderer, ViewMode, TOURS};
+use crate::utils::{ExportFormat, LabelDensity, Observer, ProjectionKind, MAX_ZOOM, MIN_ZOOM};
+use web_sys::{File, HtmlInputElement};
 use yew::prelude::*;
 
+/// Number of discrete positions on the zoom slider
+const ZOOM_SLIDER_STEPS: f64 = 1000.0;
+
+/// Zoom slider updates are applied at most once per frame
+const ZOOM_DEBOUNCE_MS: u32 = 16;
+
+/// Magnitude and star-size updates re-cull the map, so are spaced further apart
+const SLIDER_DEBOUNCE_MS: u32 = 60;
+
+/// Map a slider position in [0, 1] logarithmically onto the zoom range
+pub fn slider_to_zoom(position: f64) -> f64 {
+    let position = position.clamp(0.0, 1.0);
+    MIN_ZOOM * (MAX_ZOOM / MIN_ZOOM).powf(position)
+}
+
+/// Map a zoom level onto a slider position in [0, 1] (inverse of `slider_to_zoom`)
+pub fn zoom_to_slider(zoom: f64) -> f64 {
+    let zoom = zoom.clamp(MIN_ZOOM, MAX_ZOOM);
+    (zoom / MIN_ZOOM).ln() / (MAX_ZOOM / MIN_ZOOM).ln()
+}
+
+/// An observer from the location form's text, or `None` unless both
+/// fields are numbers in range
+pub fn parse_observer(latitude: &str, longitude: &str) -> Option<Observer> {
+    Observer::new(
+        latitude.trim().parse().ok()?,
+        longitude.trim().parse().ok()?,
+    )
+}
+
 /// Props for the Controls component
 #[derive(Properties, PartialEq)]
 pub struct ControlsProps {
@@ -18,13 +55,68 @@ pub struct ControlsProps {
     /// Whether grid is shown
     pub show_grid: bool,
 
+    /// Whether constellation figures are shown
+    pub show_constellations: bool,
+
+    /// Whether the Messier objects are marked
+    pub show_deep_sky: bool,
+
+    /// Whether the asterisms are drawn
+    #[prop_or_default]
+    pub show_asterisms: bool,
+
+    /// Star size multiplier
+    pub star_scale: f64,
+
+    /// Whether unnamed stars show an info card when clicked
+    pub unnamed_star_info: bool,
+
+    /// How many star labels to draw
+    pub label_density: LabelDensity,
+
+    /// Whether answers play a sound
+    pub sound_enabled: bool,
+
+    /// Whether the map shows the whole sky or the local sky
+    pub view_mode: ViewMode,
+
+    /// Where the local sky is seen from, once known
+    pub observer: Option<Observer>,
+
+    /// Whether the local sky leaves out stars below the horizon
+    pub hide_below_horizon: bool,
+
+    /// The moment the local sky is shown for, or `None` for now
+    pub observation_time: Option<f64>,
+
+    /// Whether this is practice, where clicked stars are described
+    #[prop_or_default]
+    pub practice: bool,
+
+    /// How the map flattens the sky
+    pub projection: ProjectionKind,
+
+    /// How the map is drawn
+    pub renderer: MapRenderer,
+
     /// Callback for dispatching game actions
     pub on_action: Callback<GameAction>,
+
+    /// Callback for exporting the current map view
+    pub on_export: Callback<ExportFormat>,
+
+    /// Callback for downloading the learning progress
+    pub on_export_progress: Callback<()>,
+
+    /// Callback with a progress file the user picked to import
+    pub on_import_progress: Callback<File>,
 }
 
 /// The controls panel component
 #[function_component(Controls)]
 pub fn controls(props: &ControlsProps) -> Html {
+    let locale = use_locale();
+
     // Zoom controls
     let on_zoom_in = {
         let on_action = props.on_action.clone();
@@ -47,16 +139,35 @@ pub fn controls(props: &ControlsProps) -> Html {
         })
     };
 
+    // Sliders fire on every step of a drag, so their actions are debounced
+    let set_zoom = use_debounced_callback(props.on_action.clone(), ZOOM_DEBOUNCE_MS);
+    let set_magnitude = use_debounced_callback(props.on_action.clone(), SLIDER_DEBOUNCE_MS);
+    let set_star_scale = use_debounced_callback(props.on_action.clone(), SLIDER_DEBOUNCE_MS);
+
+    // Zoom slider
+    let on_zoom_slider = Callback::from(move |e: InputEvent| {
+        let input: HtmlInputElement = e.target_unchecked_into();
+        if let Ok(value) = input.value().parse::<f64>() {
+            let zoom = slider_to_zoom(value / ZOOM_SLIDER_STEPS);
+            set_zoom.emit(GameAction::SetZoom(zoom));
+        }
+    });
+
     // Magnitude slider
-    let on_magnitude_change = {
-        let on_action = props.on_action.clone();
-        Callback::from(move |e: InputEvent| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            if let Ok(value) = input.value().parse::<f64>() {
-                on_action.emit(GameAction::SetMagnitudeLimit(value));
-            }
-        })
-    };
+    let on_magnitude_change = Callback::from(move |e: InputEvent| {
+        let input: HtmlInputElement = e.target_unchecked_into();
+        if let Ok(value) = input.value().parse::<f64>() {
+            set_magnitude.emit(GameAction::SetMagnitudeLimit(value));
+        }
+    });
+
+    // Star size slider
+    let on_star_scale_change = Callback::from(move |e: InputEvent| {
+        let input: HtmlInputElement = e.target_unchecked_into();
+        if let Ok(value) = input.value().parse::<f64>() {
+            set_star_scale.emit(GameAction::SetStarScale(value));
+        }
+    });
 
     // Grid toggle
     let on_grid_toggle = {
@@ -66,6 +177,232 @@ pub fn controls(props: &ControlsProps) -> Html {
         })
     };
 
+    // Constellation figures toggle
+    let on_constellations_toggle = {
+        let on_action = props.on_action.clone();
+        Callback::from(move |_| {
+            on_action.emit(GameAction::ToggleConstellations);
+        })
+    };
+
+    // Deep-sky objects toggle
+    let on_deep_sky_toggle = {
+        let on_action = props.on_action.clone();
+        Callback::from(move |_| {
+            on_action.emit(GameAction::ToggleDeepSky);
+        })
+    };
+
+    // Asterisms toggle
+    let on_asterisms_toggle = {
+        let on_action = props.on_action.clone();
+        Callback::from(move |_| {
+            on_action.emit(GameAction::ToggleAsterisms);
+        })
+    };
+
+    // Unnamed star info toggle
+    let on_unnamed_info_toggle = {
+        let on_action = props.on_action.clone();
+        Callback::from(move |_| {
+            on_action.emit(GameAction::ToggleUnnamedStarInfo);
+        })
+    };
+
+    // Sound toggle; turning it on is a user gesture, so audio can start
+    let on_sound_toggle = {
+        let on_action = props.on_action.clone();
+        let enabling = !props.sound_enabled;
+        Callback::from(move |_| {
+            if enabling {
+                audio::unlock();
+            }
+            on_action.emit(GameAction::ToggleSound);
+        })
+    };
+
+    let label_buttons = label_density_buttons(props.label_density, &props.on_action);
+
+    // Language picker
+    let language_buttons: Html = Locale::ALL
+        .iter()
+        .map(|&choice| {
+            let on_action = props.on_action.clone();
+            html! {
+                <button
+                    class={classes!("toggle-btn", (locale == choice).then_some("active"))}
+                    lang={choice.code()}
+                    onclick={Callback::from(move |_| {
+                        on_action.emit(GameAction::SetLocale(choice));
+                    })}
+                >
+                    { choice.name() }
+                </button>
+            }
+        })
+        .collect();
+
+    // Sky mode selector
+    let view_buttons: Html = [
+        (ViewMode::Celestial, TextKey::WholeSky, None),
+        (
+            ViewMode::LocalSky,
+            TextKey::Tonight,
+            Some(TextKey::TonightTitle),
+        ),
+    ]
+    .into_iter()
+    .map(|(mode, label, title)| {
+        let on_action = props.on_action.clone();
+        html! {
+            <button
+                class={classes!("toggle-btn", (props.view_mode == mode).then_some("active"))}
+                title={title.map(t)}
+                onclick={Callback::from(move |_| {
+                    on_action.emit(GameAction::SetViewMode(mode));
+                })}
+            >
+                { t(label) }
+            </button>
+        }
+    })
+    .collect();
+
+    let projection_buttons = projection_buttons(props.projection, &props.on_action);
+
+    // Renderer selector
+    let renderer_buttons: Html = [
+        (MapRenderer::Svg, "SVG", TextKey::SvgRendererTitle),
+        (
+            MapRenderer::Canvas,
+            t(TextKey::Canvas),
+            TextKey::CanvasRendererTitle,
+        ),
+    ]
+    .into_iter()
+    .map(|(renderer, label, title)| {
+        let on_action = props.on_action.clone();
+        html! {
+            <button
+                class={classes!("toggle-btn", (props.renderer == renderer).then_some("active"))}
+                title={t(title)}
+                onclick={Callback::from(move |_| {
+                    on_action.emit(GameAction::SetRenderer(renderer));
+                })}
+            >
+                { label }
+            </button>
+        }
+    })
+    .collect();
+
+    // Manual location, for when the browser cannot or may not tell
+    let latitude_ref = use_node_ref();
+    let longitude_ref = use_node_ref();
+    let location_invalid = use_state(|| false);
+    let on_location_submit = {
+        let on_action = props.on_action.clone();
+        let latitude_ref = latitude_ref.clone();
+        let longitude_ref = longitude_ref.clone();
+        let location_invalid = location_invalid.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let value = |input: &NodeRef| {
+                input
+                    .cast::<HtmlInputElement>()
+                    .map(|input| input.value())
+                    .unwrap_or_default()
+            };
+            match parse_observer(&value(&latitude_ref), &value(&longitude_ref)) {
+                Some(observer) => {
+                    location_invalid.set(false);
+                    on_action.emit(GameAction::SetObserver(observer));
+                }
+                None => location_invalid.set(true),
+            }
+        })
+    };
+    let location_form = if props.view_mode == ViewMode::LocalSky {
+        let (latitude, longitude) = props
+            .observer
+            .map(|o| (format!("{:.2}", o.latitude), format!("{:.2}", o.longitude)))
+            .unwrap_or_default();
+        html! {
+            <form class="location-form" onsubmit={on_location_submit}>
+                if props.observer.is_none() {
+                    <p class="control-hint">{ t(TextKey::LocationHint) }</p>
+                }
+                <label>
+                    { t(TextKey::Latitude) }
+                    <input
+                        ref={latitude_ref}
+                        type="number"
+                        step="any"
+                        min="-90"
+                        max="90"
+                        value={latitude}
+                    />
+                </label>
+                <label>
+                    { t(TextKey::Longitude) }
+                    <input
+                        ref={longitude_ref}
+                        type="number"
+                        step="any"
+                        min="-180"
+                        max="180"
+                        value={longitude}
+                    />
+                </label>
+                <button type="submit" class="toggle-btn">{ t(TextKey::SetLocation) }</button>
+                <button
+                    type="button"
+                    class="toggle-btn"
+                    title={t(TextKey::UseMyLocationTitle)}
+                    onclick={props.on_action.reform(|_| GameAction::LocateObserver)}
+                >
+                    { t(TextKey::UseMyLocation) }
+                </button>
+                if *location_invalid {
+                    <p class="location-error" role="alert">{ t(TextKey::LocationInvalid) }</p>
+                }
+                <button
+                    type="button"
+                    class={classes!("toggle-btn", props.hide_below_horizon.then_some("active"))}
+                    title={t(TextKey::HideBelowHorizonTitle)}
+                    onclick={props.on_action.reform(|_| GameAction::ToggleHideBelowHorizon)}
+                >
+                    { t(TextKey::HideBelowHorizon) }
+                </button>
+            </form>
+        }
+    } else {
+        Html::default()
+    };
+
+    // Export buttons
+    let on_export_svg = {
+        let on_export = props.on_export.clone();
+        Callback::from(move |_| on_export.emit(ExportFormat::Svg))
+    };
+    let on_export_png = {
+        let on_export = props.on_export.clone();
+        Callback::from(move |_| on_export.emit(ExportFormat::Png))
+    };
+
+    // Progress file picker; cleared after each pick so that the same file
+    // can be picked again
+    let on_import_progress = {
+        let on_import = props.on_import_progress.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Some(file) = input.files().and_then(|files| files.get(0)) {
+                on_import.emit(file);
+            }
+            input.set_value("");
+        })
+    };
+
     // Done button - show summary and reset
     let on_show_summary = {
         let on_action = props.on_action.clone();
@@ -81,27 +418,37 @@ pub fn controls(props: &ControlsProps) -> Html {
         <div class="controls-panel">
             // Zoom controls
             <div class="control-group">
-                <label class="control-label">{ "Zoom" }</label>
+                <label class="control-label">{ t(TextKey::Zoom) }</label>
                 <div class="zoom-buttons">
-                    <button class="control-btn" onclick={on_zoom_out} title="Zoom Out">
+                    <button class="control-btn" onclick={on_zoom_out} title={t(TextKey::ZoomOut)}>
                         { "−" }
                     </button>
                     <span class="zoom-level">{ format!("{:.1}×", props.zoom) }</span>
-                    <button class="control-btn" onclick={on_zoom_in} title="Zoom In">
+                    <button class="control-btn" onclick={on_zoom_in} title={t(TextKey::ZoomIn)}>
                         { "+" }
                     </button>
-                    <button class="control-btn reset" onclick={on_reset} title="Reset View">
+                    <button class="control-btn reset" onclick={on_reset} title={t(TextKey::ResetView)}>
                         { "⟲" }
                     </button>
                 </div>
+                <input
+                    type="range"
+                    class="magnitude-slider zoom-slider"
+                    min="0"
+                    max={ZOOM_SLIDER_STEPS.to_string()}
+                    step="1"
+                    value={(zoom_to_slider(props.zoom) * ZOOM_SLIDER_STEPS).round().to_string()}
+                    oninput={on_zoom_slider}
+                    title={t(TextKey::Zoom)}
+                />
             </div>
 
             // Magnitude slider
             <div class="control-group">
                 <label class="control-label">
-                    { "Star Brightness" }
+                    { t(TextKey::StarBrightness) }
                     <span class="control-hint">
-                        { format!(" (mag < {:.1})", props.magnitude_limit) }
+                        { tf(TextKey::MagnitudeHint, &[&format_args!("{:.1}", props.magnitude_limit)]) }
                     </span>
                 </label>
                 <input
@@ -114,41 +461,347 @@ pub fn controls(props: &ControlsProps) -> Html {
                     oninput={on_magnitude_change}
                 />
                 <div class="slider-labels">
-                    <span>{ "Bright" }</span>
-                    <span class="star-count">{ format!("~{} stars", star_estimate) }</span>
-                    <span>{ "Faint" }</span>
+                    <span>{ t(TextKey::Bright) }</span>
+                    <span class="star-count">{ tf(TextKey::StarCount, &[&star_estimate]) }</span>
+                    <span>{ t(TextKey::Faint) }</span>
                 </div>
             </div>
 
+            // Star size slider
+            <div class="control-group">
+                <label class="control-label">
+                    { t(TextKey::StarSize) }
+                    <span class="control-hint">
+                        { format!(" ({:.1}×)", props.star_scale) }
+                    </span>
+                </label>
+                <input
+                    type="range"
+                    class="magnitude-slider"
+                    min="0.5"
+                    max="3.0"
+                    step="0.1"
+                    value={props.star_scale.to_string()}
+                    oninput={on_star_scale_change}
+                />
+            </div>
+
             // Display toggles
             <div class="control-group">
-                <label class="control-label">{ "Display" }</label>
+                <label class="control-label">{ t(TextKey::Display) }</label>
                 <div class="toggle-buttons">
                     <button
                         class={classes!("toggle-btn", props.show_grid.then_some("active"))}
                         onclick={on_grid_toggle}
                     >
-                        { "Grid" }
+                        { t(TextKey::Grid) }
+                    </button>
+                    <button
+                        class={classes!("toggle-btn", props.show_constellations.then_some("active"))}
+                        onclick={on_constellations_toggle}
+                        title={t(TextKey::FiguresTitle)}
+                    >
+                        { t(TextKey::Figures) }
                     </button>
+                    <button
+                        class={classes!("toggle-btn", props.show_deep_sky.then_some("active"))}
+                        onclick={on_deep_sky_toggle}
+                        title={t(TextKey::DeepSkyTitle)}
+                    >
+                        { t(TextKey::DeepSky) }
+                    </button>
+                    <button
+                        class={classes!("toggle-btn", props.show_asterisms.then_some("active"))}
+                        onclick={on_asterisms_toggle}
+                        title={t(TextKey::AsterismsTitle)}
+                    >
+                        { t(TextKey::Asterisms) }
+                    </button>
+                    <button
+                        class={classes!("toggle-btn", props.unnamed_star_info.then_some("active"))}
+                        onclick={on_unnamed_info_toggle}
+                        title={t(TextKey::StarInfoTitle)}
+                    >
+                        { t(TextKey::StarInfo) }
+                    </button>
+                    <button
+                        class={classes!("toggle-btn", props.sound_enabled.then_some("active"))}
+                        onclick={on_sound_toggle}
+                        title={t(TextKey::SoundTitle)}
+                    >
+                        { t(TextKey::Sound) }
+                    </button>
+                </div>
+            </div>
+
+            // Whole sky or the local sky
+            <div class="control-group">
+                <label class="control-label">{ t(TextKey::Sky) }</label>
+                <div class="toggle-buttons">
+                    { view_buttons }
+                </div>
+                { location_form }
+                if props.view_mode == ViewMode::LocalSky {
+                    <TimeControl time={props.observation_time} on_action={props.on_action.clone()} />
+                }
+            </div>
+
+            // Map projection
+            <div class="control-group">
+                <label class="control-label">{ t(TextKey::Projection) }</label>
+                <div class="toggle-buttons">
+                    { projection_buttons }
+                </div>
+            </div>
+
+            // Map renderer
+            <div class="control-group">
+                <label class="control-label">{ t(TextKey::Renderer) }</label>
+                <div class="toggle-buttons">
+                    { renderer_buttons }
+                </div>
+            </div>
+
+            // Label density
+            <div class="control-group">
+                <label class="control-label">{ t(TextKey::Labels) }</label>
+                <div class="toggle-buttons">
+                    { label_buttons }
+                </div>
+            </div>
+
+            // Interface language
+            <div class="control-group">
+                <label class="control-label">{ t(TextKey::Language) }</label>
+                <div class="toggle-buttons">
+                    { language_buttons }
+                </div>
+            </div>
+
+            // Export image
+            <div class="control-group">
+                <label class="control-label">{ t(TextKey::ExportImage) }</label>
+                <div class="toggle-buttons">
+                    <button class="toggle-btn" onclick={on_export_svg} title={t(TextKey::ExportSvgTitle)}>
+                        { "SVG" }
+                    </button>
+                    <button class="toggle-btn" onclick={on_export_png} title={t(TextKey::ExportPngTitle)}>
+                        { "PNG" }
+                    </button>
+                </div>
+            </div>
+
+            // Learning progress
+            <div class="control-group">
+                <label class="control-label">{ t(TextKey::Progress) }</label>
+                <div class="toggle-buttons">
+                    <button
+                        class="toggle-btn export-progress"
+                        onclick={props.on_export_progress.reform(|_| ())}
+                        title={t(TextKey::ExportProgressTitle)}
+                    >
+                        { t(TextKey::ExportProgress) }
+                    </button>
+                    <label class="toggle-btn import-progress" title={t(TextKey::ImportProgressTitle)}>
+                        { t(TextKey::ImportProgress) }
+                        <input
+                            type="file"
+                            class="sr-only"
+                            accept=".json,application/json"
+                            onchange={on_import_progress}
+                        />
+                    </label>
+                </div>
+            </div>
+
+            // Quiz settings
+            <div class="control-group">
+                <button
+                    class="control-btn settings"
+                    onclick={props.on_action.reform(|_| GameAction::ToggleSettings)}
+                    title={t(TextKey::QuizSettingsTitle)}
+                >
+                    { t(TextKey::QuizSettings) }
+                </button>
+            </div>
+
+            // Achievements
+            <div class="control-group">
+                <button
+                    class="control-btn trophies"
+                    onclick={props.on_action.reform(|_| GameAction::ToggleTrophies)}
+                    title={t(TextKey::TrophiesTitle)}
+                >
+                    { t(TextKey::Trophies) }
+                </button>
+                if cfg!(feature = "tiles") {
+                    <button
+                        class="control-btn progress"
+                        onclick={props.on_action.reform(|_| GameAction::ToggleProgressMap)}
+                        title={t(TextKey::ProgressMapTitle)}
+                    >
+                        { t(TextKey::ProgressMap) }
+                    </button>
+                }
+            </div>
+
+            // Daily challenge
+            <div class="control-group">
+                <button
+                    class="control-btn daily"
+                    onclick={props.on_action.reform(|_| GameAction::PlayDailyChallenge)}
+                    title={t(TextKey::DailyChallengeTitle)}
+                >
+                    { t(TextKey::DailyChallenge) }
+                </button>
+            </div>
+
+            // Survival run
+            <div class="control-group">
+                <button
+                    class="control-btn survival"
+                    onclick={props.on_action.reform(|_| GameAction::StartSurvival)}
+                    title={t(TextKey::SurvivalTitle)}
+                >
+                    { t(TextKey::Survival) }
+                </button>
+            </div>
+
+            // Practice
+            <div class="control-group">
+                <button
+                    class={classes!("control-btn", "practice", props.practice.then_some("active"))}
+                    onclick={props.on_action.reform(|_| GameAction::TogglePractice)}
+                    title={t(TextKey::PracticeTitle)}
+                >
+                    { t(TextKey::Practice) }
+                </button>
+            </div>
+
+            // Guided tours
+            <div class="control-group">
+                <label class="control-label">{ t(TextKey::Tours) }</label>
+                <div class="toggle-buttons tours">
+                    { for TOURS.iter().enumerate().map(|(index, tour)| html! {
+                        <button
+                            class="toggle-btn tour"
+                            onclick={props.on_action.reform(move |_| GameAction::StartTour(index))}
+                        >
+                            { t(TextKey::for_tour(tour.id)) }
+                        </button>
+                    }) }
+                </div>
+            </div>
+
+            // Round of questions
+            <div class="control-group">
+                <button
+                    class="control-btn round"
+                    onclick={props.on_action.reform(|_| GameAction::StartRound)}
+                    title={t(TextKey::RoundTitle)}
+                >
+                    { t(TextKey::Round) }
+                </button>
+            </div>
+
+            // Time-attack run
+            <div class="control-group">
+                <label class="control-label">{ t(TextKey::TimeAttack) }</label>
+                <div class="toggle-buttons">
+                    { for TIME_ATTACK_SECONDS.into_iter().map(|seconds| html! {
+                        <button
+                            class="toggle-btn time-attack"
+                            onclick={props.on_action.reform(move |_| GameAction::StartTimeAttack(seconds))}
+                            title={t(TextKey::TimeAttackTitle)}
+                        >
+                            { format_countdown(f64::from(seconds) * 1000.0) }
+                        </button>
+                    }) }
                 </div>
             </div>
 
             // Done button
             <div class="control-group">
                 <button class="control-btn done" onclick={on_show_summary}>
-                    { "Done" }
+                    { t(TextKey::Done) }
                 </button>
             </div>
 
             // Help text
             <div class="control-help">
-                <p>{ "🖱️ Drag to pan • Scroll to zoom" }</p>
-                <p>{ "Click on a " }<span class="named-star-hint">{ "bright star" }</span>{ " to test your knowledge!" }</p>
+                <p>{ t(TextKey::HelpPanZoom) }</p>
+                <p>
+                    { t(TextKey::HelpClickBefore) }
+                    <span class="named-star-hint">{ t(TextKey::HelpBrightStar) }</span>
+                    { t(TextKey::HelpClickAfter) }
+                </p>
             </div>
         </div>
     }
 }
 
+/// Buttons choosing the label density, with `current` highlighted
+///
+/// Shared with the quiz settings panel.
+pub(crate) fn label_density_buttons(
+    current: LabelDensity,
+    on_action: &Callback<GameAction>,
+) -> Html {
+    LabelDensity::ALL
+        .iter()
+        .map(|&density| {
+            let on_action = on_action.clone();
+            html! {
+                <button
+                    class={classes!("toggle-btn", (current == density).then_some("active"))}
+                    onclick={Callback::from(move |_| {
+                        on_action.emit(GameAction::SetLabelDensity(density));
+                    })}
+                >
+                    { t(TextKey::for_density(density)) }
+                </button>
+            }
+        })
+        .collect()
+}
+
+/// Buttons choosing the map projection, with `current` highlighted
+///
+/// Shared with the quiz settings panel.
+pub(crate) fn projection_buttons(
+    current: ProjectionKind,
+    on_action: &Callback<GameAction>,
+) -> Html {
+    [
+        (
+            ProjectionKind::Equirectangular,
+            TextKey::Flat,
+            TextKey::FlatTitle,
+        ),
+        (
+            ProjectionKind::Stereographic,
+            TextKey::Stereographic,
+            TextKey::StereographicTitle,
+        ),
+    ]
+    .into_iter()
+    .map(|(projection, label, title)| {
+        let on_action = on_action.clone();
+        html! {
+            <button
+                class={classes!("toggle-btn", (current == projection).then_some("active"))}
+                title={t(title)}
+                onclick={Callback::from(move |_| {
+                    on_action.emit(GameAction::SetProjection(projection));
+                })}
+            >
+                { t(label) }
+            </button>
+        }
+    })
+    .collect()
+}
+
 /// Estimate number of visible stars for a given magnitude limit
 fn estimate_visible_stars(magnitude_limit: f64) -> u32 {
     // Rough approximation based on real star counts
@@ -171,6 +824,37 @@ fn estimate_visible_stars(magnitude_limit: f64) -> u32 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_zoom_slider_endpoints() {
+        assert!((slider_to_zoom(0.0) - MIN_ZOOM).abs() < 1e-9);
+        assert!((slider_to_zoom(1.0) - MAX_ZOOM).abs() < 1e-9);
+        assert!(zoom_to_slider(MIN_ZOOM).abs() < 1e-9);
+        assert!((zoom_to_slider(MAX_ZOOM) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_zoom_slider_midpoint_is_geometric_mean() {
+        let mid = slider_to_zoom(0.5);
+        assert!((mid - (MIN_ZOOM * MAX_ZOOM).sqrt()).abs() < 1e-9);
+        assert!((zoom_to_slider(mid) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_zoom_slider_roundtrip() {
+        for i in 0..=20 {
+            let position = i as f64 / 20.0;
+            assert!((zoom_to_slider(slider_to_zoom(position)) - position).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_parse_observer() {
+        assert_eq!(parse_observer(" 50.5", "-3 "), Observer::new(50.5, -3.0));
+        assert_eq!(parse_observer("95", "0"), None);
+        assert_eq!(parse_observer("", "10"), None);
+        assert_eq!(parse_observer("north", "10"), None);
+    }
+
     #[test]
     fn test_star_estimates() {
         assert!(estimate_visible_stars(2.0) < estimate_visible_stars(4.0));
@@ -0,0 +1,85 @@
+//! Countdown Component
+//!
+//! The clock of a time-attack run, shown large over the map. It redraws a
+//! few times a second and dispatches [`GameAction::TimeUp`] when it
+//! reaches zero.
+
+use crate::app::i18n::{t, use_locale, TextKey};
+use crate::game::GameAction;
+use gloo::timers::callback::Interval;
+use yew::prelude::*;
+
+/// How often the clock redraws
+const TICK_MS: u32 = 250;
+
+/// Seconds left below which the clock turns urgent
+const URGENT_SECONDS: f64 = 10.0;
+
+/// Milliseconds left as minutes and seconds, rounding up so the clock
+/// reads 0:00 only once time is up
+pub fn format_countdown(remaining_ms: f64) -> String {
+    let seconds = (remaining_ms.max(0.0) / 1000.0).ceil() as u64;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Props for Countdown component
+#[derive(Properties, PartialEq)]
+pub struct CountdownProps {
+    /// When time is up, in Unix milliseconds
+    pub ends_at: f64,
+
+    /// Callback for dispatching game actions
+    pub on_action: Callback<GameAction>,
+}
+
+/// The countdown component
+#[function_component(Countdown)]
+pub fn countdown(props: &CountdownProps) -> Html {
+    use_locale();
+    let now = use_state(js_sys::Date::now);
+
+    {
+        let now = now.clone();
+        use_effect_with((), move |_| {
+            let interval = Interval::new(TICK_MS, move || now.set(js_sys::Date::now()));
+            move || drop(interval)
+        });
+    }
+
+    let remaining = (props.ends_at - *now).max(0.0);
+    {
+        let on_action = props.on_action.clone();
+        let time_up = remaining == 0.0;
+        use_effect_with(time_up, move |&time_up| {
+            if time_up {
+                on_action.emit(GameAction::TimeUp);
+            }
+        });
+    }
+
+    let urgent = remaining < URGENT_SECONDS * 1000.0;
+    html! {
+        <div
+            class={classes!("countdown", urgent.then_some("urgent"))}
+            role="timer"
+            aria-label={t(TextKey::TimeLeft)}
+        >
+            { format_countdown(remaining) }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(120_000.0), "2:00");
+        assert_eq!(format_countdown(59_001.0), "1:00");
+        assert_eq!(format_countdown(9_500.0), "0:10");
+        assert_eq!(format_countdown(1.0), "0:01");
+        assert_eq!(format_countdown(0.0), "0:00");
+        assert_eq!(format_countdown(-50.0), "0:00");
+    }
+}
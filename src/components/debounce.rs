@@ -0,0 +1,206 @@
+//! Debounced callbacks
+//!
+//! Sliders fire an input event for every intermediate value, and each
+//! dispatched change re-culls and re-renders the map. [`Debouncer`]
+//! passes the first value of a burst straight through, then holds later
+//! ones so at most one goes out per interval, always ending on the last
+//! value. [`use_debounced_callback`] wires it to a timer for components.
+
+use gloo::timers::callback::Timeout;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// A source of the current time in milliseconds
+pub trait Clock {
+    fn now_ms(&self) -> f64;
+}
+
+/// Wall-clock time in the browser
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PageClock;
+
+impl Clock for PageClock {
+    fn now_ms(&self) -> f64 {
+        js_sys::Date::now()
+    }
+}
+
+/// What to do with a value given to [`Debouncer::push`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Push<T> {
+    /// Deliver the value now
+    Emit(T),
+    /// The value is held; call [`Debouncer::flush`] after this many ms
+    Schedule(f64),
+    /// The value replaced one already held for a scheduled flush
+    Queued,
+}
+
+/// Coalesces rapid values into at most one per interval
+#[derive(Debug)]
+pub struct Debouncer<T, C> {
+    interval_ms: f64,
+    clock: C,
+    last_emit: Option<f64>,
+    pending: Option<T>,
+}
+
+impl<T, C: Clock> Debouncer<T, C> {
+    pub fn new(interval_ms: f64, clock: C) -> Self {
+        Self {
+            interval_ms,
+            clock,
+            last_emit: None,
+            pending: None,
+        }
+    }
+
+    /// Offer a new value
+    pub fn push(&mut self, value: T) -> Push<T> {
+        let now = self.clock.now_ms();
+        if self.pending.replace(value).is_some() {
+            return Push::Queued;
+        }
+        match self.last_emit {
+            Some(last) if now - last < self.interval_ms => {
+                Push::Schedule(last + self.interval_ms - now)
+            }
+            _ => {
+                self.last_emit = Some(now);
+                Push::Emit(self.pending.take().expect("just stored"))
+            }
+        }
+    }
+
+    /// Take the held value, if any, once its timer has fired
+    pub fn flush(&mut self) -> Option<T> {
+        let value = self.pending.take()?;
+        self.last_emit = Some(self.clock.now_ms());
+        Some(value)
+    }
+
+    /// Drop any held value without delivering it
+    pub fn cancel(&mut self) {
+        self.pending = None;
+    }
+
+    pub fn has_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+}
+
+/// A callback that forwards to `callback` at most once per `interval_ms`
+///
+/// The last value of a burst is always delivered unless the component
+/// unmounts first, in which case it is dropped.
+#[hook]
+pub fn use_debounced_callback<T>(callback: Callback<T>, interval_ms: u32) -> Callback<T>
+where
+    T: 'static,
+{
+    let debouncer =
+        use_mut_ref(|| Debouncer::<T, PageClock>::new(f64::from(interval_ms), PageClock));
+    let timer = use_mut_ref(|| None::<Timeout>);
+    let target = use_mut_ref(|| callback.clone());
+    *target.borrow_mut() = callback;
+
+    {
+        let debouncer = debouncer.clone();
+        let timer = timer.clone();
+        use_effect_with((), move |_| {
+            move || {
+                debouncer.borrow_mut().cancel();
+                timer.borrow_mut().take();
+            }
+        });
+    }
+
+    // Emitting can re-render this component, so no borrow is held across it
+    use_callback((), move |value: T, _| {
+        let push = debouncer.borrow_mut().push(value);
+        match push {
+            Push::Emit(value) => target.borrow().clone().emit(value),
+            Push::Schedule(delay_ms) => {
+                let debouncer = Rc::clone(&debouncer);
+                let target = Rc::clone(&target);
+                // A fired timer is only dropped when the next one replaces it
+                *timer.borrow_mut() = Some(Timeout::new(delay_ms.ceil() as u32, move || {
+                    let value = debouncer.borrow_mut().flush();
+                    if let Some(value) = value {
+                        target.borrow().clone().emit(value);
+                    }
+                }));
+            }
+            Push::Queued => {}
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    /// A clock the test moves by hand
+    #[derive(Clone, Default)]
+    struct ManualClock(Rc<Cell<f64>>);
+
+    impl ManualClock {
+        fn set(&self, ms: f64) {
+            self.0.set(ms);
+        }
+    }
+
+    impl Clock for ManualClock {
+        fn now_ms(&self) -> f64 {
+            self.0.get()
+        }
+    }
+
+    fn debouncer() -> (Debouncer<f64, ManualClock>, ManualClock) {
+        let clock = ManualClock::default();
+        (Debouncer::new(100.0, clock.clone()), clock)
+    }
+
+    #[test]
+    fn test_burst_delivers_first_and_last() {
+        let (mut debouncer, clock) = debouncer();
+        assert_eq!(debouncer.push(1.0), Push::Emit(1.0));
+
+        clock.set(10.0);
+        assert_eq!(debouncer.push(2.0), Push::Schedule(90.0));
+        clock.set(20.0);
+        assert_eq!(debouncer.push(3.0), Push::Queued);
+        assert!(debouncer.has_pending());
+
+        // The trailing value goes out when the timer fires
+        clock.set(100.0);
+        assert_eq!(debouncer.flush(), Some(3.0));
+        assert_eq!(debouncer.flush(), None);
+
+        // The next value waits out a full interval from that delivery
+        clock.set(150.0);
+        assert_eq!(debouncer.push(4.0), Push::Schedule(50.0));
+    }
+
+    #[test]
+    fn test_quiet_period_emits_immediately() {
+        let (mut debouncer, clock) = debouncer();
+        debouncer.push(1.0);
+        clock.set(250.0);
+        assert_eq!(debouncer.push(2.0), Push::Emit(2.0));
+        assert!(!debouncer.has_pending());
+    }
+
+    #[test]
+    fn test_cancel_drops_held_value() {
+        let (mut debouncer, clock) = debouncer();
+        debouncer.push(1.0);
+        clock.set(30.0);
+        debouncer.push(2.0);
+
+        debouncer.cancel();
+        clock.set(100.0);
+        assert_eq!(debouncer.flush(), None);
+    }
+}
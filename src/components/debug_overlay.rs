@@ -0,0 +1,77 @@
+//! Debug Overlay Component
+//!
+//! Shows render metrics, the viewport and recent actions for diagnosing
+//! slow frames. Hidden until toggled with Ctrl+Shift+D.
+
+use crate::utils::perf;
+use crate::utils::Viewport;
+use gloo::timers::callback::Interval;
+use yew::prelude::*;
+
+/// How often the overlay re-reads the metrics
+const REFRESH_MS: u32 = 500;
+
+/// Props for the DebugOverlay component
+#[derive(Properties, PartialEq)]
+pub struct DebugOverlayProps {
+    pub viewport: Viewport,
+
+    /// Where the star catalog came from
+    pub catalog_source: String,
+}
+
+/// The debug overlay component
+#[function_component(DebugOverlay)]
+pub fn debug_overlay(props: &DebugOverlayProps) -> Html {
+    // Metrics are recorded outside Yew state, so poll for them
+    let refresh = use_force_update();
+    use_effect_with((), move |_| {
+        let interval = Interval::new(REFRESH_MS, move || refresh.force_update());
+        move || drop(interval)
+    });
+
+    let log = perf::snapshot();
+    let viewport = &props.viewport;
+    let (stars, render) = match log.last_render() {
+        Some(sample) => (
+            sample.visible_stars.to_string(),
+            format!(
+                "{:.2} ms (avg {:.2})",
+                sample.duration_ms,
+                log.average_render_ms().unwrap_or_default()
+            ),
+        ),
+        None => ("–".to_string(), "–".to_string()),
+    };
+
+    html! {
+        <div class="debug-overlay" aria-hidden="true">
+            <dl>
+                <dt>{ "Visible stars" }</dt>
+                <dd>{ stars }</dd>
+                <dt>{ "Render" }</dt>
+                <dd>{ render }</dd>
+                <dt>{ "Renders" }</dt>
+                <dd>{ log.render_count() }</dd>
+                <dt>{ "Viewport" }</dt>
+                <dd>
+                    { format!(
+                        "RA {:.3}h Dec {:.2}° ×{:.2} {}×{}",
+                        viewport.center_ra,
+                        viewport.center_dec,
+                        viewport.zoom,
+                        viewport.width,
+                        viewport.height
+                    ) }
+                </dd>
+                <dt>{ "Catalog" }</dt>
+                <dd>{ props.catalog_source.clone() }</dd>
+            </dl>
+            <ol class="debug-actions">
+                { for log.recent_actions().into_iter().map(|action| html! {
+                    <li title={action.clone()}>{ action }</li>
+                }) }
+            </ol>
+        </div>
+    }
+}
@@ -0,0 +1,156 @@
+//! Focus management helpers
+//!
+//! Keyboard focus utilities shared by dialogs and popups.
+
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent};
+use yew::prelude::*;
+
+/// Selector for elements that can take keyboard focus inside a dialog
+const FOCUSABLE: &str =
+    "button:not([disabled]), [href], input, select, [tabindex]:not([tabindex='-1'])";
+
+/// Index to focus when tabbing from `current` among `count` focusable elements
+///
+/// Wraps around at both ends so focus never leaves the container.
+pub fn next_focus_index(current: Option<usize>, count: usize, backwards: bool) -> usize {
+    if count == 0 {
+        return 0;
+    }
+    match (current, backwards) {
+        (None, false) => 0,
+        (None, true) => count - 1,
+        (Some(i), false) => (i + 1) % count,
+        (Some(i), true) => (i + count - 1) % count,
+    }
+}
+
+/// Keep Tab / Shift+Tab cycling within `container`
+pub fn trap_tab(container: &Element, event: &KeyboardEvent) {
+    if event.key() != "Tab" {
+        return;
+    }
+    let Ok(nodes) = container.query_selector_all(FOCUSABLE) else {
+        return;
+    };
+    let elements: Vec<HtmlElement> = (0..nodes.length())
+        .filter_map(|i| nodes.get(i))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect();
+    if elements.is_empty() {
+        return;
+    }
+
+    let active = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.active_element());
+    let current = active.and_then(|active| {
+        elements
+            .iter()
+            .position(|el| el.unchecked_ref::<Element>() == &active)
+    });
+
+    event.prevent_default();
+    let next = next_focus_index(current, elements.len(), event.shift_key());
+    let _ = elements[next].focus();
+}
+
+/// The element that currently has focus, if it can be re-focused later
+pub fn active_html_element() -> Option<HtmlElement> {
+    web_sys::window()?
+        .document()?
+        .active_element()?
+        .dyn_into::<HtmlElement>()
+        .ok()
+}
+
+/// The focus handling of a modal dialog, from [`use_dialog_focus`]
+pub struct DialogFocus {
+    /// The button focused while the dialog is open, usually its close button
+    pub close_ref: NodeRef,
+    popup_ref: NodeRef,
+    on_keydown: Callback<KeyboardEvent>,
+}
+
+impl DialogFocus {
+    /// `content` in a modal dialog over the page, styled as a
+    /// `summary-popup` with `class` and labelled by the element `title_id`
+    pub fn overlay(&self, class: &'static str, title_id: &'static str, content: Html) -> Html {
+        html! {
+            <div class="summary-overlay">
+                <div
+                    ref={self.popup_ref.clone()}
+                    class={classes!("summary-popup", class)}
+                    role="dialog"
+                    aria-modal="true"
+                    aria-labelledby={title_id}
+                    onkeydown={self.on_keydown.clone()}
+                >
+                    { content }
+                </div>
+            </div>
+        }
+    }
+}
+
+/// Focus a dialog's close button when it opens and restore the previous
+/// focus when it closes, keeping Tab within the dialog meanwhile
+#[hook]
+pub fn use_dialog_focus() -> DialogFocus {
+    let popup_ref = use_node_ref();
+    let close_ref = use_node_ref();
+
+    {
+        let close_ref = close_ref.clone();
+        use_effect_with((), move |_| {
+            let previous = active_html_element();
+            if let Some(el) = close_ref.cast::<HtmlElement>() {
+                let _ = el.focus();
+            }
+            move || {
+                if let Some(el) = previous {
+                    let _ = el.focus();
+                }
+            }
+        });
+    }
+
+    let on_keydown = {
+        let popup_ref = popup_ref.clone();
+        use_callback((), move |e: KeyboardEvent, _| {
+            if let Some(container) = popup_ref.cast::<Element>() {
+                trap_tab(&container, &e);
+            }
+        })
+    };
+
+    DialogFocus {
+        close_ref,
+        popup_ref,
+        on_keydown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_focus_wraps_forward() {
+        assert_eq!(next_focus_index(Some(0), 3, false), 1);
+        assert_eq!(next_focus_index(Some(2), 3, false), 0);
+        assert_eq!(next_focus_index(None, 3, false), 0);
+    }
+
+    #[test]
+    fn test_next_focus_wraps_backward() {
+        assert_eq!(next_focus_index(Some(1), 3, true), 0);
+        assert_eq!(next_focus_index(Some(0), 3, true), 2);
+        assert_eq!(next_focus_index(None, 3, true), 2);
+    }
+
+    #[test]
+    fn test_next_focus_empty() {
+        assert_eq!(next_focus_index(None, 0, false), 0);
+    }
+}
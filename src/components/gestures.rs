@@ -0,0 +1,221 @@
+//! Touch gestures on the star map
+//!
+//! [`TouchGesture`] turns the fingers' positions into pans and pinch
+//! zooms, and [`Momentum`] keeps a flicked map gliding after the finger
+//! lifts. Both are plain state machines fed with positions and times, so
+//! they are tested without a browser.
+
+/// Fingers closer together than this, in pixels, do not pinch: the
+/// distance between them is too noisy to scale by
+const MIN_PINCH_DISTANCE: f64 = 10.0;
+
+/// Fraction of a glide's speed kept after each millisecond
+const FRICTION_PER_MS: f64 = 0.995;
+
+/// Slowest glide, in pixels per millisecond, before the map stops
+const MIN_GLIDE_SPEED: f64 = 0.02;
+
+/// A finger that rests this long before lifting does not flick
+const MAX_FLICK_PAUSE_MS: f64 = 80.0;
+
+/// Weight of the newest move in the tracked flick velocity
+const VELOCITY_SMOOTHING: f64 = 0.8;
+
+/// The change to the view from one touch move
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GestureStep {
+    /// Movement of the fingers, or of their midpoint, in pixels
+    pub pan: (f64, f64),
+    /// Pinch zoom factor and the midpoint it centers on, with two fingers
+    pub zoom: Option<(f64, f64, f64)>,
+}
+
+/// Positions of the fingers on the map, one or two of them
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TouchGesture {
+    points: Vec<(f64, f64)>,
+}
+
+impl TouchGesture {
+    /// Start over with the fingers now down, after one touches or lifts
+    ///
+    /// Only the first two fingers count.
+    pub fn reset(&mut self, points: &[(f64, f64)]) {
+        self.points = points.iter().take(2).copied().collect();
+    }
+
+    /// Whether two fingers are down
+    pub fn is_pinch(&self) -> bool {
+        self.points.len() == 2
+    }
+
+    /// Follow the fingers to new positions
+    ///
+    /// `None` when no finger is down or their number changed without a
+    /// [`reset`](Self::reset); the new positions are kept either way.
+    pub fn update(&mut self, points: &[(f64, f64)]) -> Option<GestureStep> {
+        let previous = std::mem::take(&mut self.points);
+        self.reset(points);
+        let step = match (previous.as_slice(), self.points.as_slice()) {
+            (&[from], &[to]) => GestureStep {
+                pan: (to.0 - from.0, to.1 - from.1),
+                zoom: None,
+            },
+            (&[a0, b0], &[a1, b1]) => {
+                let (from, to) = (midpoint(a0, b0), midpoint(a1, b1));
+                let (before, after) = (distance(a0, b0), distance(a1, b1));
+                GestureStep {
+                    pan: (to.0 - from.0, to.1 - from.1),
+                    zoom: (before >= MIN_PINCH_DISTANCE && after >= MIN_PINCH_DISTANCE)
+                        .then(|| (after / before, to.0, to.1)),
+                }
+            }
+            _ => return None,
+        };
+        Some(step)
+    }
+}
+
+fn midpoint(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+}
+
+fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    (a.0 - b.0).hypot(a.1 - b.1)
+}
+
+/// The glide of a flicked map
+///
+/// Moves are tracked while the finger is down; once it lifts, each
+/// [`step`](Self::step) yields the next stretch of the glide, slowing by
+/// friction until the map stops.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Momentum {
+    /// Pixels per millisecond
+    velocity: (f64, f64),
+    /// Time of the last tracked move, while the finger is down
+    last_move: Option<f64>,
+}
+
+impl Momentum {
+    /// Record a pan of the finger at `now_ms`
+    pub fn track(&mut self, dx: f64, dy: f64, now_ms: f64) {
+        if let Some(last) = self.last_move {
+            let elapsed = (now_ms - last).max(1.0);
+            let (vx, vy) = (dx / elapsed, dy / elapsed);
+            self.velocity = (
+                VELOCITY_SMOOTHING * vx + (1.0 - VELOCITY_SMOOTHING) * self.velocity.0,
+                VELOCITY_SMOOTHING * vy + (1.0 - VELOCITY_SMOOTHING) * self.velocity.1,
+            );
+        }
+        self.last_move = Some(now_ms);
+    }
+
+    /// The finger lifted at `now_ms`; returns whether the map glides on
+    pub fn release(&mut self, now_ms: f64) -> bool {
+        let paused = self
+            .last_move
+            .take()
+            .is_none_or(|last| now_ms - last > MAX_FLICK_PAUSE_MS);
+        if paused {
+            self.stop();
+        }
+        self.is_gliding()
+    }
+
+    /// Stop gliding and forget the tracked moves
+    pub fn stop(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Whether the map is still moving fast enough to glide
+    pub fn is_gliding(&self) -> bool {
+        self.velocity.0.hypot(self.velocity.1) >= MIN_GLIDE_SPEED
+    }
+
+    /// The pan for the next `elapsed_ms` of the glide, or `None` once it
+    /// has stopped
+    pub fn step(&mut self, elapsed_ms: f64) -> Option<(f64, f64)> {
+        if !self.is_gliding() {
+            self.stop();
+            return None;
+        }
+        let pan = (self.velocity.0 * elapsed_ms, self.velocity.1 * elapsed_ms);
+        let decay = FRICTION_PER_MS.powf(elapsed_ms);
+        self.velocity = (self.velocity.0 * decay, self.velocity.1 * decay);
+        Some(pan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_finger_pans() {
+        let mut gesture = TouchGesture::default();
+        gesture.reset(&[(10.0, 10.0)]);
+        let step = gesture.update(&[(15.0, 7.0)]).unwrap();
+        assert_eq!(step.pan, (5.0, -3.0));
+        assert_eq!(step.zoom, None);
+    }
+
+    #[test]
+    fn test_two_fingers_pinch_about_their_midpoint() {
+        let mut gesture = TouchGesture::default();
+        gesture.reset(&[(100.0, 100.0), (200.0, 100.0)]);
+        assert!(gesture.is_pinch());
+
+        // Spread apart to twice the distance, moving right by 10
+        let step = gesture.update(&[(60.0, 100.0), (260.0, 100.0)]).unwrap();
+        assert_eq!(step.pan, (10.0, 0.0));
+        assert_eq!(step.zoom, Some((2.0, 160.0, 100.0)));
+
+        // Fingers on top of each other do not zoom
+        gesture.reset(&[(50.0, 50.0), (52.0, 50.0)]);
+        assert_eq!(
+            gesture.update(&[(50.0, 50.0), (60.0, 50.0)]).unwrap().zoom,
+            None
+        );
+    }
+
+    #[test]
+    fn test_changing_finger_count_restarts() {
+        let mut gesture = TouchGesture::default();
+        assert_eq!(gesture.update(&[(1.0, 1.0)]), None);
+        gesture.reset(&[(0.0, 0.0)]);
+        assert_eq!(gesture.update(&[(0.0, 0.0), (50.0, 0.0)]), None);
+        // The new positions are followed from then on
+        assert!(gesture.update(&[(0.0, 0.0), (100.0, 0.0)]).is_some());
+    }
+
+    #[test]
+    fn test_flick_glides_to_a_stop() {
+        let mut momentum = Momentum::default();
+        momentum.track(0.0, 0.0, 0.0);
+        momentum.track(16.0, 0.0, 16.0);
+        momentum.track(16.0, 0.0, 32.0);
+        assert!(momentum.release(40.0));
+
+        let mut travelled = 0.0;
+        let mut frames = 0;
+        while let Some((dx, dy)) = momentum.step(16.0) {
+            assert!(dx > 0.0 && dy == 0.0);
+            travelled += dx;
+            frames += 1;
+            assert!(frames < 1000, "the glide never stopped");
+        }
+        // About 1 px/ms with 0.5% friction a millisecond glides ~200 px
+        assert!((150.0..250.0).contains(&travelled), "{}", travelled);
+        assert!(!momentum.is_gliding());
+    }
+
+    #[test]
+    fn test_resting_finger_does_not_flick() {
+        let mut momentum = Momentum::default();
+        momentum.track(0.0, 0.0, 0.0);
+        momentum.track(20.0, 0.0, 16.0);
+        assert!(!momentum.release(300.0));
+        assert_eq!(momentum.step(16.0), None);
+    }
+}
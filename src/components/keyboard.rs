@@ -0,0 +1,283 @@
+//! Keyboard shortcuts
+//!
+//! One window-wide key handler for the whole page: arrow keys pan the map,
+//! `+` and `-` zoom it, digits pick quiz answers, Escape closes whatever is
+//! open and `?` shows the list of shortcuts. Which action a key maps to is
+//! decided by [`shortcut_action`], so it can be tested without a browser.
+
+use crate::app::i18n::TextKey;
+use crate::game::{GameAction, QuizState};
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, KeyboardEvent};
+use yew::prelude::*;
+
+/// How far one arrow key press pans the map, in pixels
+pub const PAN_STEP: f64 = 60.0;
+
+/// Zoom factors for `+` and `-`, the same steps as the mouse wheel
+const ZOOM_IN: f64 = 1.2;
+const ZOOM_OUT: f64 = 0.8;
+
+/// The shortcuts as shown in the help overlay: keys and what they do
+pub const SHORTCUTS: [(&str, TextKey); 5] = [
+    ("← ↑ → ↓", TextKey::ShortcutPan),
+    ("+ −", TextKey::ShortcutZoom),
+    ("1–5", TextKey::ShortcutAnswer),
+    ("Esc", TextKey::ShortcutClose),
+    ("?", TextKey::ShortcutHelp),
+];
+
+/// What is open on the page, which decides what some keys do
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShortcutContext {
+    /// The open question, if any
+    pub quiz: Option<QuizState>,
+    /// Whether answers are typed, which leaves the digits to the text box
+    pub typed_answers: bool,
+    /// Whether a star is selected, such as one with an info card
+    pub has_selection: bool,
+    /// Whether the quiz settings panel is open
+    pub settings_open: bool,
+    /// Whether the trophy panel is open
+    pub trophies_open: bool,
+    /// Whether the progress map is open
+    pub progress_map_open: bool,
+    /// Whether the session summary is shown
+    pub summary_shown: bool,
+    /// Whether the daily challenge results are shown
+    pub challenge_results: bool,
+    /// Whether a run's results are shown
+    pub run_results: bool,
+    /// Whether the shortcuts help is shown
+    pub help_shown: bool,
+}
+
+/// The action for pressing `key`, a [`KeyboardEvent::key`] value
+///
+/// While a popup is open only Escape and `?` do anything, so keys do not
+/// act on the page behind it. Escape closes the topmost thing open: help,
+/// then the settings, the trophies, the progress map, the summary, the
+/// challenge or run results, and last the quiz or selection.
+pub fn shortcut_action(key: &str, context: &ShortcutContext) -> Option<GameAction> {
+    let modal = context.help_shown
+        || context.settings_open
+        || context.trophies_open
+        || context.progress_map_open
+        || context.summary_shown
+        || context.challenge_results
+        || context.run_results;
+    match key {
+        "Escape" if context.help_shown => Some(GameAction::HideHelp),
+        "Escape" if context.settings_open => Some(GameAction::ToggleSettings),
+        "Escape" if context.trophies_open => Some(GameAction::ToggleTrophies),
+        "Escape" if context.progress_map_open => Some(GameAction::ToggleProgressMap),
+        "Escape" if context.summary_shown => Some(GameAction::HideSummary),
+        "Escape" if context.challenge_results => Some(GameAction::EndChallenge),
+        "Escape" if context.run_results => Some(GameAction::EndRun),
+        "Escape" if context.quiz.is_some() => Some(GameAction::CloseQuiz),
+        "Escape" if context.has_selection => Some(GameAction::ClearSelection),
+        "?" if context.help_shown => Some(GameAction::HideHelp),
+        "?" if !context.summary_shown && !context.settings_open => Some(GameAction::ShowHelp),
+        _ if modal => None,
+        "ArrowLeft" => Some(GameAction::Pan(PAN_STEP, 0.0)),
+        "ArrowRight" => Some(GameAction::Pan(-PAN_STEP, 0.0)),
+        "ArrowUp" => Some(GameAction::Pan(0.0, PAN_STEP)),
+        "ArrowDown" => Some(GameAction::Pan(0.0, -PAN_STEP)),
+        "+" | "=" => Some(GameAction::ZoomBy(ZOOM_IN)),
+        "-" | "_" => Some(GameAction::ZoomBy(ZOOM_OUT)),
+        _ if context.typed_answers => None,
+        _ => answer_action(key, context.quiz.as_ref()?),
+    }
+}
+
+/// Submit the choice numbered `key` (from 1) of an unanswered question
+fn answer_action(key: &str, quiz: &QuizState) -> Option<GameAction> {
+    if quiz.answered || key.len() != 1 {
+        return None;
+    }
+    let number: usize = key.parse().ok()?;
+    let choice = quiz.choices.get(number.checked_sub(1)?)?;
+    Some(GameAction::SelectAndSubmitAnswer(choice.clone()))
+}
+
+/// Whether a key press is typing into a form field, which keeps its keys
+fn is_typing(event: &KeyboardEvent) -> bool {
+    event
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+        .is_some_and(|el| matches!(el.tag_name().as_str(), "INPUT" | "SELECT" | "TEXTAREA"))
+}
+
+/// Listen for the shortcuts on the window while the page is mounted
+///
+/// Key presses with Ctrl, Alt or Meta held are left to the browser and
+/// other handlers, as are those typed into form fields.
+#[hook]
+pub fn use_keyboard_shortcuts(context: ShortcutContext, on_action: Callback<GameAction>) {
+    use_effect_with(context, move |context| {
+        let context = context.clone();
+        let window = web_sys::window().expect("no window");
+        let listener = EventListener::new(&window, "keydown", move |event| {
+            let Some(event) = event.dyn_ref::<KeyboardEvent>() else {
+                return;
+            };
+            if event.ctrl_key() || event.alt_key() || event.meta_key() || is_typing(event) {
+                return;
+            }
+            if let Some(action) = shortcut_action(&event.key(), &context) {
+                event.prevent_default();
+                on_action.emit(action);
+            }
+        });
+        move || drop(listener)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::StarId;
+    use crate::game::QuizTarget;
+
+    fn quiz() -> QuizState {
+        QuizState {
+            target: QuizTarget::Star(StarId(1)),
+            correct_name: "Sirius".into(),
+            choices: ["Vega", "Sirius", "Arcturus", "Rigel"]
+                .map(Into::into)
+                .to_vec(),
+            selected_answer: None,
+            answered: false,
+            was_correct: None,
+            started_at: 0.0,
+            hints: 0,
+        }
+    }
+
+    #[test]
+    fn test_arrows_pan_and_plus_minus_zoom() {
+        let context = ShortcutContext::default();
+        assert_eq!(
+            shortcut_action("ArrowLeft", &context),
+            Some(GameAction::Pan(PAN_STEP, 0.0))
+        );
+        assert_eq!(
+            shortcut_action("ArrowDown", &context),
+            Some(GameAction::Pan(0.0, -PAN_STEP))
+        );
+        assert_eq!(
+            shortcut_action("+", &context),
+            Some(GameAction::ZoomBy(ZOOM_IN))
+        );
+        assert_eq!(
+            shortcut_action("-", &context),
+            Some(GameAction::ZoomBy(ZOOM_OUT))
+        );
+        assert_eq!(shortcut_action("x", &context), None);
+    }
+
+    #[test]
+    fn test_digits_answer_an_open_question() {
+        let mut context = ShortcutContext {
+            quiz: Some(quiz()),
+            ..Default::default()
+        };
+        assert_eq!(
+            shortcut_action("2", &context),
+            Some(GameAction::SelectAndSubmitAnswer("Sirius".into()))
+        );
+        assert_eq!(shortcut_action("0", &context), None);
+        assert_eq!(shortcut_action("5", &context), None);
+
+        context.typed_answers = true;
+        assert_eq!(shortcut_action("2", &context), None);
+
+        context.typed_answers = false;
+        context.quiz.as_mut().unwrap().answered = true;
+        assert_eq!(shortcut_action("1", &context), None);
+        assert_eq!(shortcut_action("1", &ShortcutContext::default()), None);
+    }
+
+    #[test]
+    fn test_escape_closes_the_topmost_thing() {
+        let mut context = ShortcutContext {
+            quiz: Some(quiz()),
+            typed_answers: false,
+            has_selection: true,
+            settings_open: true,
+            trophies_open: true,
+            progress_map_open: true,
+            summary_shown: true,
+            challenge_results: true,
+            run_results: true,
+            help_shown: true,
+        };
+        assert_eq!(
+            shortcut_action("Escape", &context),
+            Some(GameAction::HideHelp)
+        );
+        context.help_shown = false;
+        assert_eq!(
+            shortcut_action("Escape", &context),
+            Some(GameAction::ToggleSettings)
+        );
+        context.settings_open = false;
+        assert_eq!(
+            shortcut_action("Escape", &context),
+            Some(GameAction::ToggleTrophies)
+        );
+        context.trophies_open = false;
+        assert_eq!(
+            shortcut_action("Escape", &context),
+            Some(GameAction::ToggleProgressMap)
+        );
+        context.progress_map_open = false;
+        assert_eq!(
+            shortcut_action("Escape", &context),
+            Some(GameAction::HideSummary)
+        );
+        context.summary_shown = false;
+        assert_eq!(
+            shortcut_action("Escape", &context),
+            Some(GameAction::EndChallenge)
+        );
+        assert_eq!(shortcut_action("1", &context), None);
+        context.challenge_results = false;
+        assert_eq!(
+            shortcut_action("Escape", &context),
+            Some(GameAction::EndRun)
+        );
+        context.run_results = false;
+        assert_eq!(
+            shortcut_action("Escape", &context),
+            Some(GameAction::CloseQuiz)
+        );
+        context.quiz = None;
+        assert_eq!(
+            shortcut_action("Escape", &context),
+            Some(GameAction::ClearSelection)
+        );
+        context.has_selection = false;
+        assert_eq!(shortcut_action("Escape", &context), None);
+    }
+
+    #[test]
+    fn test_help_toggles_and_popups_block_the_map_keys() {
+        let mut context = ShortcutContext {
+            quiz: Some(quiz()),
+            ..Default::default()
+        };
+        assert_eq!(shortcut_action("?", &context), Some(GameAction::ShowHelp));
+
+        context.help_shown = true;
+        assert_eq!(shortcut_action("?", &context), Some(GameAction::HideHelp));
+        assert_eq!(shortcut_action("ArrowUp", &context), None);
+        assert_eq!(shortcut_action("1", &context), None);
+
+        context.help_shown = false;
+        context.summary_shown = true;
+        assert_eq!(shortcut_action("?", &context), None);
+        assert_eq!(shortcut_action("+", &context), None);
+    }
+}
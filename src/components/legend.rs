@@ -0,0 +1,220 @@
+//! Map Legend Component
+//!
+//! Collapsible overlay explaining the symbology of the star map.
+
+use crate::app::i18n::{t, tf, use_locale, TextKey};
+use crate::data::dso::DsoKind;
+use crate::utils::sky_svg::{
+    glyph_path, render_style_for, tint_for_color_index, DsoGlyph, EQUATOR_STROKE, GRID_STROKE,
+    NAMED_STAR_FILL, UNNAMED_STAR_FILL,
+};
+use yew::prelude::*;
+
+/// Sample magnitudes shown in the size-vs-magnitude section
+const SAMPLE_MAGNITUDES: [f64; 3] = [0.0, 2.5, 5.0];
+
+/// Sample B−V color indices, hot to cool, shown in the star color row
+const SAMPLE_COLOR_INDICES: [f64; 3] = [-0.3, 0.6, 1.8];
+
+/// Props for the Legend component
+#[derive(Properties, PartialEq)]
+pub struct LegendProps {
+    /// Current zoom level (star sizes depend on it)
+    pub zoom: f64,
+
+    /// Star size multiplier
+    pub star_scale: f64,
+
+    /// Whether grid lines are shown
+    pub show_grid: bool,
+
+    /// Whether Messier objects are marked
+    pub show_deep_sky: bool,
+}
+
+/// A single legend entry
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LegendEntry {
+    /// Clickable named star
+    NamedStar,
+    /// Unnamed background star
+    UnnamedStar,
+    /// Tints of named stars, hot to cool
+    StarColors,
+    /// Star size at a sample magnitude
+    Magnitude(f64),
+    /// RA/Dec grid line
+    GridLine,
+    /// Celestial equator
+    Equator,
+    /// Map glyph of one kind of Messier object
+    DeepSky(DsoKind),
+}
+
+/// Build the list of legend entries for the currently enabled layers
+pub fn legend_entries(show_grid: bool, show_deep_sky: bool) -> Vec<LegendEntry> {
+    let mut entries = vec![
+        LegendEntry::NamedStar,
+        LegendEntry::UnnamedStar,
+        LegendEntry::StarColors,
+    ];
+    entries.extend(SAMPLE_MAGNITUDES.iter().map(|&m| LegendEntry::Magnitude(m)));
+
+    if show_grid {
+        entries.push(LegendEntry::GridLine);
+        entries.push(LegendEntry::Equator);
+    }
+
+    if show_deep_sky {
+        entries.extend(DsoKind::ALL.map(LegendEntry::DeepSky));
+    }
+
+    entries
+}
+
+/// The legend component
+#[function_component(Legend)]
+pub fn legend(props: &LegendProps) -> Html {
+    use_locale();
+    let expanded = use_state(|| false);
+
+    let on_toggle = {
+        let expanded = expanded.clone();
+        Callback::from(move |_| {
+            expanded.set(!*expanded);
+        })
+    };
+
+    let rows: Html = if *expanded {
+        legend_entries(props.show_grid, props.show_deep_sky)
+            .into_iter()
+            .map(|entry| render_entry(entry, props.zoom, props.star_scale))
+            .collect()
+    } else {
+        Html::default()
+    };
+
+    html! {
+        <div class={classes!("map-legend", (*expanded).then_some("expanded"))}>
+            <button class="legend-toggle" onclick={on_toggle} title={t(TextKey::MapLegend)}>
+                { t(TextKey::Legend) }{ if *expanded { " ▾" } else { " ▸" } }
+            </button>
+            <div class="legend-rows">
+                { rows }
+            </div>
+        </div>
+    }
+}
+
+/// Render one legend row: a small SVG swatch plus its description
+fn render_entry(entry: LegendEntry, zoom: f64, star_scale: f64) -> Html {
+    let (swatch, label) = match entry {
+        LegendEntry::NamedStar => (
+            star_swatch(3.0, NAMED_STAR_FILL),
+            t(TextKey::NamedStar).to_string(),
+        ),
+        LegendEntry::UnnamedStar => (
+            star_swatch(3.0, UNNAMED_STAR_FILL),
+            t(TextKey::UnnamedStar).to_string(),
+        ),
+        LegendEntry::StarColors => (
+            SAMPLE_COLOR_INDICES
+                .iter()
+                .enumerate()
+                .map(|(i, &color_index)| {
+                    html! {
+                        <circle
+                            cx={(4 + 8 * i).to_string()}
+                            cy="8"
+                            r="3"
+                            fill={tint_for_color_index(color_index)}
+                        />
+                    }
+                })
+                .collect(),
+            t(TextKey::StarColors).to_string(),
+        ),
+        LegendEntry::Magnitude(mag) => {
+            let style = render_style_for(mag, true, zoom, star_scale);
+            (
+                star_swatch(style.radius, style.fill),
+                tf(TextKey::Magnitude, &[&format_args!("{:.1}", mag)]),
+            )
+        }
+        LegendEntry::GridLine => (
+            line_swatch(GRID_STROKE, "1"),
+            t(TextKey::RaDecGrid).to_string(),
+        ),
+        LegendEntry::Equator => (
+            line_swatch(EQUATOR_STROKE, "2"),
+            t(TextKey::CelestialEquator).to_string(),
+        ),
+        LegendEntry::DeepSky(kind) => {
+            (dso_swatch(kind), t(TextKey::for_dso_kind(kind)).to_string())
+        }
+    };
+
+    html! {
+        <div class="legend-row">
+            <svg class="legend-swatch" width="24" height="16" viewBox="0 0 24 16">
+                { swatch }
+            </svg>
+            <span class="legend-label">{ label }</span>
+        </div>
+    }
+}
+
+fn star_swatch(radius: f64, fill: &'static str) -> Html {
+    html! {
+        <circle cx="12" cy="8" r={radius.min(7.0).to_string()} fill={fill} />
+    }
+}
+
+fn dso_swatch(kind: DsoKind) -> Html {
+    let glyph = DsoGlyph {
+        messier: 0,
+        kind,
+        x: 12.0,
+        y: 8.0,
+        below_horizon: false,
+    };
+    html! {
+        <path class="dso-glyph" d={glyph_path(&glyph)} />
+    }
+}
+
+fn line_swatch(stroke: &'static str, width: &'static str) -> Html {
+    html! {
+        <line x1="0" y1="8" x2="24" y2="8" stroke={stroke} stroke-width={width} />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_match_enabled_layers() {
+        let without_grid = legend_entries(false, false);
+        assert!(!without_grid.contains(&LegendEntry::GridLine));
+        assert!(!without_grid.contains(&LegendEntry::Equator));
+        assert_eq!(without_grid.len(), 3 + SAMPLE_MAGNITUDES.len());
+
+        let with_grid = legend_entries(true, false);
+        assert!(with_grid.contains(&LegendEntry::GridLine));
+        assert!(with_grid.contains(&LegendEntry::Equator));
+        assert_eq!(with_grid.len(), without_grid.len() + 2);
+
+        let deep_sky = |entries: &[LegendEntry]| {
+            entries
+                .iter()
+                .filter(|entry| matches!(entry, LegendEntry::DeepSky(_)))
+                .count()
+        };
+        assert_eq!(deep_sky(&with_grid), 0);
+        let with_deep_sky = legend_entries(false, true);
+        assert_eq!(deep_sky(&with_deep_sky), DsoKind::ALL.len());
+        assert!(with_deep_sky.contains(&LegendEntry::DeepSky(DsoKind::Galaxy)));
+        assert_eq!(with_deep_sky.len(), without_grid.len() + DsoKind::ALL.len());
+    }
+}
@@ -2,14 +2,46 @@
 //!
 //! Built with Yew framework for WebAssembly rendering.
 
+pub mod challenge_results;
 pub mod controls;
+pub mod countdown;
+pub mod debounce;
+pub mod debug_overlay;
+pub mod focus;
+pub mod gestures;
+pub mod keyboard;
+pub mod legend;
+pub mod progress_map;
 pub mod quiz_dropdown;
+pub mod run_results;
 pub mod score_display;
+pub mod settings_panel;
+pub mod shortcuts_help;
+pub mod star_info;
 pub mod star_map;
+pub mod star_map_canvas;
+pub mod stats_dashboard;
 pub mod summary_popup;
+pub mod time_control;
+pub mod tour_card;
+pub mod trophy_panel;
 
+pub use challenge_results::ChallengeResults;
 pub use controls::Controls;
+pub use countdown::Countdown;
+pub use debug_overlay::DebugOverlay;
+pub use legend::Legend;
+pub use progress_map::ProgressMap;
 pub use quiz_dropdown::QuizDropdown;
+pub use run_results::RunResults;
 pub use score_display::ScoreDisplay;
+pub use settings_panel::SettingsPanel;
+pub use shortcuts_help::ShortcutsHelp;
+pub use star_info::{StarInfo, StarPanel};
 pub use star_map::StarMap;
+pub use star_map_canvas::StarMapCanvas;
+pub use stats_dashboard::StatsDashboard;
 pub use summary_popup::SummaryPopup;
+pub use time_control::TimeControl;
+pub use tour_card::TourCard;
+pub use trophy_panel::TrophyPanel;
@@ -0,0 +1,126 @@
+//! Progress Map Component
+//!
+//! The whole sky as a small plain-SVG chart, right ascension increasing
+//! to the left as on the map, with a patch over each region of named
+//! stars colored from red for shaky to green for learned, and grey where
+//! nothing has been asked yet. Clicking a patch centers the map on it.
+
+use crate::app::i18n::{t, tf, use_locale, TextKey};
+use crate::components::focus::use_dialog_focus;
+use crate::game::heatmap::RegionMastery;
+use crate::game::GameAction;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// Degrees a patch reaches past its outermost stars, so a region of one
+/// star still shows
+const REGION_PAD: f64 = 2.0;
+
+/// Hue of a region's patch: 0 (red) for a region not known at all up to
+/// 120 (green) for one fully learned
+pub fn mastery_hue(mastery: f64) -> f64 {
+    120.0 * mastery.clamp(0.0, 1.0)
+}
+
+/// Props for ProgressMap component
+#[derive(Properties, PartialEq)]
+pub struct ProgressMapProps {
+    /// Each region of the sky and how well it is known
+    pub regions: Rc<Vec<RegionMastery>>,
+
+    /// Callback for dispatching game actions
+    pub on_action: Callback<GameAction>,
+}
+
+/// The progress map component
+#[function_component(ProgressMap)]
+pub fn progress_map(props: &ProgressMapProps) -> Html {
+    use_locale();
+    let dialog = use_dialog_focus();
+
+    let patches = props.regions.iter().map(|region| {
+        let x = (24.0 - region.ra_max) * 15.0 - REGION_PAD;
+        let y = 90.0 - region.dec_max - REGION_PAD;
+        let width = (region.ra_max - region.ra_min) * 15.0 + 2.0 * REGION_PAD;
+        let height = region.dec_max - region.dec_min + 2.0 * REGION_PAD;
+        let (class, fill) = if region.is_untouched() {
+            ("region untouched", None)
+        } else {
+            let fill = format!("hsl({:.0}, 70%, 45%)", mastery_hue(region.mastery));
+            ("region", Some(fill))
+        };
+        let summary = tf(
+            TextKey::RegionMastery,
+            &[
+                &region.answered,
+                &region.named,
+                &(region.mastery * 100.0).round(),
+            ],
+        );
+        let center = region.center();
+        let on_action = props.on_action.clone();
+        let onclick = Callback::from(move |_: MouseEvent| {
+            on_action.emit(GameAction::SetCenter(center.ra, center.dec));
+            on_action.emit(GameAction::ToggleProgressMap);
+        });
+        html! {
+            <rect
+                class={class}
+                x={format!("{:.1}", x)}
+                y={format!("{:.1}", y)}
+                width={format!("{:.1}", width)}
+                height={format!("{:.1}", height)}
+                fill={fill}
+                onclick={onclick}
+            >
+                <title>{ summary }</title>
+            </rect>
+        }
+    });
+
+    dialog.overlay(
+        "progress-map",
+        "progress-map-title",
+        html! {
+            <>
+                <div class="summary-header">
+                    <h2 id="progress-map-title">{ t(TextKey::ProgressMap) }</h2>
+                    <button
+                        ref={dialog.close_ref.clone()}
+                        onclick={props.on_action.reform(|_| GameAction::ToggleProgressMap)}
+                        class="close-button"
+                        aria-label={t(TextKey::CloseProgressMap)}
+                    >
+                        { "×" }
+                    </button>
+                </div>
+                <svg class="progress-sky" viewBox="0 0 360 180" role="img" aria-label={t(TextKey::ProgressMapTitle)}>
+                    <rect class="progress-background" x="0" y="0" width="360" height="180" />
+                    <line class="progress-equator" x1="0" y1="90" x2="360" y2="90" />
+                    { for patches }
+                </svg>
+                <p class="progress-hint">{ t(TextKey::ProgressMapHint) }</p>
+                <div class="progress-legend">
+                    <span class="progress-swatch" aria-hidden="true"></span>
+                    <span>{ t(TextKey::Untouched) }</span>
+                    <span>{ t(TextKey::Shaky) }</span>
+                    <span class="progress-gradient" aria-hidden="true"></span>
+                    <span>{ t(TextKey::Learned) }</span>
+                </div>
+            </>
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mastery_hue() {
+        assert_eq!(mastery_hue(0.0), 0.0);
+        assert_eq!(mastery_hue(0.5), 60.0);
+        assert_eq!(mastery_hue(1.0), 120.0);
+        assert_eq!(mastery_hue(2.0), 120.0);
+    }
+}
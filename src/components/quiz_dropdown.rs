@@ -1,8 +1,13 @@
 //! Quiz Dropdown Component
 //!
-//! Displays the multiple-choice quiz interface when a star is selected.
+//! Displays the quiz interface when a star is selected: a list of choices,
+//! or a text box for typing the star's name. During the daily challenge it
+//! also shows the question number and moves on to the next question.
 
-use crate::game::{GameAction, QuizState};
+use crate::app::i18n::{t, tf, use_locale, TextKey};
+use crate::components::focus::trap_tab;
+use crate::game::{GameAction, QuizState, QuizTarget, NONE_OF_ABOVE};
+use web_sys::{Element, HtmlElement, HtmlInputElement, KeyboardEvent};
 use yew::prelude::*;
 
 /// Props for the QuizDropdown component
@@ -14,8 +19,57 @@ pub struct QuizDropdownProps {
     /// Position to display the dropdown (x, y)
     pub position: (f64, f64),
 
+    /// Width and height of the map, which the dropdown is kept within
+    pub map_size: (f64, f64),
+
     /// Callback for dispatching game actions
     pub on_action: Callback<GameAction>,
+
+    /// Render as a bottom-anchored panel instead of a floating box
+    #[prop_or_default]
+    pub compact: bool,
+
+    /// Answer by typing the star's name instead of picking a choice
+    #[prop_or_default]
+    pub typed: bool,
+
+    /// Element to return keyboard focus to when the dropdown closes
+    #[prop_or_default]
+    pub return_focus: NodeRef,
+
+    /// Question number and question count, during the daily challenge or
+    /// a round
+    #[prop_or_default]
+    pub progress: Option<(usize, usize)>,
+}
+
+/// Which choice should receive focus when the dropdown opens
+pub fn initial_focus_index(quiz: &QuizState) -> usize {
+    quiz.selected_answer
+        .as_ref()
+        .and_then(|answer| quiz.choices.iter().position(|c| c == answer))
+        .unwrap_or(0)
+}
+
+/// Screen reader announcement for the quiz result, once answered
+pub fn result_announcement(quiz: &QuizState) -> Option<String> {
+    if !quiz.answered {
+        return None;
+    }
+    if quiz.was_correct == Some(true) {
+        Some(t(TextKey::AnnounceCorrect).to_string())
+    } else {
+        Some(tf(TextKey::AnnounceIncorrect, &[&quiz.correct_name]))
+    }
+}
+
+/// How a choice is shown; the "none" choice is in the interface language
+pub fn choice_text(choice: &str) -> &str {
+    if choice == NONE_OF_ABOVE {
+        t(TextKey::NoneOfAbove)
+    } else {
+        choice
+    }
 }
 
 // Approximate dropdown dimensions for positioning
@@ -23,19 +77,15 @@ const DROPDOWN_WIDTH: f64 = 220.0;
 const DROPDOWN_HEIGHT: f64 = 320.0;
 const MARGIN: f64 = 15.0;
 
-// Star map viewport dimensions (SVG coordinate space)
-const MAP_WIDTH: f64 = 1200.0;
-const MAP_HEIGHT: f64 = 600.0;
-
-/// The quiz dropdown component
-#[function_component(QuizDropdown)]
-pub fn quiz_dropdown(props: &QuizDropdownProps) -> Html {
-    let quiz = &props.quiz;
-    let (x, y) = props.position;
-
-    // Position coordinates are in SVG space (0-1200, 0-600)
+/// Top-left corner for a dropdown about a star at `(x, y)` on a map of
+/// `map_width` by `map_height`
+///
+/// Coordinates are in viewport pixels, which the map keeps equal to CSS
+/// pixels. The dropdown sits beside the star, flipping left or above it
+/// to stay on the map.
+pub fn dropdown_position(x: f64, y: f64, (map_width, map_height): (f64, f64)) -> (f64, f64) {
     // Adjust X position to keep dropdown on screen
-    let adjusted_x = if x + DROPDOWN_WIDTH + MARGIN > MAP_WIDTH {
+    let adjusted_x = if x + DROPDOWN_WIDTH + MARGIN > map_width {
         // Would overflow right - position to the left of the star
         (x - DROPDOWN_WIDTH - MARGIN).max(MARGIN)
     } else {
@@ -44,7 +94,7 @@ pub fn quiz_dropdown(props: &QuizDropdownProps) -> Html {
 
     // Adjust Y position to keep dropdown on screen
     // If star is in lower half, show dropdown above the star
-    let adjusted_y = if y > MAP_HEIGHT / 2.0 {
+    let adjusted_y = if y > map_height / 2.0 {
         // Star is in lower half - position dropdown above
         (y - DROPDOWN_HEIGHT - MARGIN).max(MARGIN)
     } else {
@@ -52,6 +102,58 @@ pub fn quiz_dropdown(props: &QuizDropdownProps) -> Html {
         y + MARGIN
     };
 
+    (adjusted_x, adjusted_y)
+}
+
+/// The quiz dropdown component
+#[function_component(QuizDropdown)]
+pub fn quiz_dropdown(props: &QuizDropdownProps) -> Html {
+    use_locale();
+    let quiz = &props.quiz;
+    let (adjusted_x, adjusted_y) =
+        dropdown_position(props.position.0, props.position.1, props.map_size);
+
+    let dropdown_ref = use_node_ref();
+    let focus_ref = use_node_ref();
+
+    // Move focus into the dropdown for each new question, with the text
+    // box emptied of the last answer
+    {
+        let focus_ref = focus_ref.clone();
+        use_effect_with(quiz.target, move |_| {
+            if let Some(input) = focus_ref.cast::<HtmlInputElement>() {
+                input.set_value("");
+            }
+            if let Some(el) = focus_ref.cast::<HtmlElement>() {
+                let _ = el.focus();
+            }
+        });
+    }
+
+    // Hand focus back to the map when the dropdown goes away
+    {
+        let return_focus = props.return_focus.clone();
+        use_effect_with((), move |_| {
+            move || {
+                if let Some(el) = return_focus.cast::<HtmlElement>() {
+                    let _ = el.focus();
+                }
+            }
+        });
+    }
+
+    // Escape and the number keys are handled by the page's shortcuts
+    let on_keydown = {
+        let dropdown_ref = dropdown_ref.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if let Some(container) = dropdown_ref.cast::<Element>() {
+                trap_tab(&container, &e);
+            }
+        })
+    };
+
+    let focus_index = initial_focus_index(quiz);
+
     let on_close = {
         let on_action = props.on_action.clone();
         Callback::from(move |_| {
@@ -59,6 +161,17 @@ pub fn quiz_dropdown(props: &QuizDropdownProps) -> Html {
         })
     };
 
+    let on_typed_submit = {
+        let on_action = props.on_action.clone();
+        let focus_ref = focus_ref.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if let Some(input) = focus_ref.cast::<HtmlInputElement>() {
+                on_action.emit(GameAction::SubmitTypedAnswer(input.value()));
+            }
+        })
+    };
+
     let choice_elements: Html = quiz
         .choices
         .iter()
@@ -85,23 +198,92 @@ pub fn quiz_dropdown(props: &QuizDropdownProps) -> Html {
                 }
             });
 
+            let choice_ref = if i == focus_index {
+                focus_ref.clone()
+            } else {
+                NodeRef::default()
+            };
+
             html! {
-                <div
+                <button
                     key={i}
+                    ref={choice_ref}
+                    type="button"
+                    role="radio"
+                    aria-checked={is_selected.to_string()}
+                    aria-disabled={answered.to_string()}
                     class={choice_class}
                     onclick={on_click}
                 >
-                    <span class="choice-number">{ i + 1 }</span>
-                    <span class="choice-text">{ choice }</span>
-                </div>
+                    <span class="choice-number" aria-hidden="true">{ i + 1 }</span>
+                    <span class="choice-text">{ choice_text(choice) }</span>
+                </button>
             }
         })
         .collect();
 
+    let answer_area = if props.typed {
+        let answer_class = classes!(
+            "typed-answer-input",
+            (quiz.was_correct == Some(true)).then_some("correct"),
+            (quiz.was_correct == Some(false)).then_some("wrong"),
+        );
+        html! {
+            <form class="typed-answer" onsubmit={on_typed_submit}>
+                <input
+                    ref={focus_ref.clone()}
+                    type="text"
+                    class={answer_class}
+                    autocomplete="off"
+                    spellcheck="false"
+                    aria-label={t(TextKey::StarName)}
+                    placeholder={t(TextKey::StarName)}
+                    disabled={quiz.answered}
+                />
+                <button type="submit" class="submit-button" disabled={quiz.answered}>
+                    { t(TextKey::CheckAnswer) }
+                </button>
+            </form>
+        }
+    } else {
+        html! {
+            <div class="quiz-choices" role="radiogroup" aria-labelledby="quiz-title">
+                { choice_elements }
+            </div>
+        }
+    };
+
+    // Letters of the answer, for fewer points
+    let hint_area = if quiz.hints > 0 || quiz.can_hint() {
+        html! {
+            <div class="quiz-hint">
+                if let Some(hint) = quiz.hint() {
+                    <span class="hint-text">{ hint }</span>
+                }
+                if quiz.can_hint() {
+                    <button
+                        type="button"
+                        class="hint-button"
+                        title={t(TextKey::HintTitle)}
+                        onclick={props.on_action.reform(|_| GameAction::TakeHint)}
+                    >
+                        { t(TextKey::Hint) }
+                    </button>
+                }
+            </div>
+        }
+    } else {
+        Html::default()
+    };
+
     // Result message (shown after clicking a choice)
     let action_area = if quiz.answered {
         let was_correct = quiz.was_correct.unwrap_or(false);
-        let message = if was_correct { "Correct!" } else { "Incorrect" };
+        let message = t(if was_correct {
+            TextKey::Correct
+        } else {
+            TextKey::Incorrect
+        });
         let message_class = if was_correct {
             "result correct"
         } else {
@@ -114,37 +296,82 @@ pub fn quiz_dropdown(props: &QuizDropdownProps) -> Html {
                 { if !was_correct {
                     html! {
                         <div class="correct-answer">
-                            { format!("The answer was: {}", quiz.correct_name) }
+                            { tf(TextKey::AnswerWas, &[&quiz.correct_name]) }
+                        </div>
+                    }
+                } else if quiz.misspelled() {
+                    html! {
+                        <div class="spelling-warning">
+                            { tf(TextKey::CloseEnough, &[&quiz.correct_name]) }
                         </div>
                     }
                 } else {
                     Html::default()
                 }}
+                if let Some((number, count)) = props.progress {
+                    <button
+                        class="next-button"
+                        onclick={props.on_action.reform(|_| GameAction::NextQuestion)}
+                    >
+                        { t(if number < count { TextKey::NextQuestion } else { TextKey::SeeResults }) }
+                    </button>
+                }
             </div>
         }
     } else {
         Html::default()
     };
 
+    let progress = match props.progress {
+        Some((number, count)) => html! {
+            <div class="challenge-progress">{ tf(TextKey::QuestionProgress, &[&number, &count]) }</div>
+        },
+        None => Html::default(),
+    };
+
+    // On narrow screens the panel docks to the bottom edge instead
+    let (dropdown_class, style) = if props.compact {
+        (classes!("quiz-dropdown", "bottom-sheet"), String::new())
+    } else {
+        (
+            classes!("quiz-dropdown"),
+            format!(
+                "position: absolute; left: {}px; top: {}px;",
+                adjusted_x, adjusted_y
+            ),
+        )
+    };
+
+    let announcement = result_announcement(quiz).unwrap_or_default();
+
+    let title = match props.quiz.target {
+        QuizTarget::Star(_) => TextKey::QuizTitle,
+        QuizTarget::DeepSky(_) => TextKey::QuizTitleDeepSky,
+        QuizTarget::Asterism(_) => TextKey::QuizTitleAsterism,
+        QuizTarget::Constellation(_) => TextKey::QuizTitleConstellation,
+        QuizTarget::Brighter(..) => TextKey::QuizTitleBrighter,
+    };
+
     html! {
         <div
-            class="quiz-dropdown"
-            style={format!(
-                "position: absolute; left: {}px; top: {}px;",
-                adjusted_x,
-                adjusted_y
-            )}
+            ref={dropdown_ref}
+            class={dropdown_class}
+            style={style}
+            role="dialog"
+            aria-labelledby="quiz-title"
+            onkeydown={on_keydown}
         >
             <div class="quiz-header">
-                <span class="quiz-title">{ "What star is this?" }</span>
-                <button class="close-button" onclick={on_close}>{ "×" }</button>
-            </div>
-            <div class="quiz-choices">
-                { choice_elements }
+                <span class="quiz-title" id="quiz-title">{ t(title) }</span>
+                <button class="close-button" onclick={on_close} aria-label={t(TextKey::CloseQuiz)}>{ "×" }</button>
             </div>
+            { progress }
+            { answer_area }
+            { hint_area }
             <div class="quiz-actions">
                 { action_area }
             </div>
+            <div class="sr-only" aria-live="polite">{ announcement }</div>
         </div>
     }
 }
@@ -157,7 +384,7 @@ mod tests {
     #[test]
     fn test_quiz_state_creation() {
         let quiz = QuizState {
-            target_star_id: StarId(1),
+            target: QuizTarget::Star(StarId(1)),
             correct_name: "Sirius".into(),
             choices: vec![
                 "Sirius".into(),
@@ -169,9 +396,65 @@ mod tests {
             selected_answer: None,
             answered: false,
             was_correct: None,
+            started_at: 0.0,
+            hints: 0,
         };
 
         assert_eq!(quiz.choices.len(), 5);
         assert!(!quiz.answered);
     }
+
+    fn sample_quiz() -> QuizState {
+        QuizState {
+            target: QuizTarget::Star(StarId(1)),
+            correct_name: "Sirius".into(),
+            choices: vec!["Vega".into(), "Sirius".into(), "Arcturus".into()],
+            selected_answer: None,
+            answered: false,
+            was_correct: None,
+            started_at: 0.0,
+            hints: 0,
+        }
+    }
+
+    #[test]
+    fn test_dropdown_position() {
+        let map = (1200.0, 600.0);
+        // Beside and below a star in the upper left
+        assert_eq!(dropdown_position(100.0, 100.0, map), (115.0, 115.0));
+        // Flipped left and above near the lower right corner
+        assert_eq!(dropdown_position(1100.0, 500.0, map), (865.0, 165.0));
+        // Never pushed off the top or left edge
+        assert_eq!(dropdown_position(1190.0, 310.0, map).1, 15.0);
+        // A narrower, shorter map flips sooner
+        assert_eq!(
+            dropdown_position(500.0, 250.0, (600.0, 400.0)),
+            (265.0, 15.0)
+        );
+    }
+
+    #[test]
+    fn test_initial_focus_index() {
+        let mut quiz = sample_quiz();
+        assert_eq!(initial_focus_index(&quiz), 0);
+
+        quiz.selected_answer = Some("Arcturus".into());
+        assert_eq!(initial_focus_index(&quiz), 2);
+    }
+
+    #[test]
+    fn test_result_announcement() {
+        let mut quiz = sample_quiz();
+        assert_eq!(result_announcement(&quiz), None);
+
+        quiz.answered = true;
+        quiz.was_correct = Some(true);
+        assert_eq!(result_announcement(&quiz).as_deref(), Some("Correct"));
+
+        quiz.was_correct = Some(false);
+        assert_eq!(
+            result_announcement(&quiz).as_deref(),
+            Some("Incorrect, the answer was Sirius")
+        );
+    }
 }
@@ -0,0 +1,98 @@
+//! Run Results Component
+//!
+//! Shown when a run of a game mode ends, a survival run losing its last
+//! life, a time-attack run its time, a round its last question or a
+//! review its last mistake: the mode's score, with a letter grade for a
+//! round, and a button to play again, except after a review.
+
+use crate::app::i18n::{t, tf, use_locale, TextKey};
+use crate::components::focus::use_dialog_focus;
+use crate::game::{GameAction, GameMode};
+use yew::prelude::*;
+
+/// Props for RunResults component
+#[derive(Properties, PartialEq)]
+pub struct RunResultsProps {
+    /// The mode whose run ended
+    pub mode: GameMode,
+
+    /// Callback for dispatching game actions
+    pub on_action: Callback<GameAction>,
+}
+
+/// The run results component
+#[function_component(RunResults)]
+pub fn run_results(props: &RunResultsProps) -> Html {
+    use_locale();
+    let dialog = use_dialog_focus();
+
+    let grade = match &props.mode {
+        GameMode::Round(run) => html! {
+            <p class="run-grade" aria-label={t(TextKey::Grade)}>{ run.grade().letter() }</p>
+        },
+        _ => Html::default(),
+    };
+
+    let (title, subtitle, score, again) = match &props.mode {
+        GameMode::Free | GameMode::Practice | GameMode::Tour(_) => return Html::default(),
+        GameMode::Survival(run) => (
+            TextKey::Survival,
+            TextKey::OutOfLives,
+            tf(TextKey::SurvivedScore, &[&run.survived]),
+            Some(GameAction::StartSurvival),
+        ),
+        GameMode::TimeAttack(run) => (
+            TextKey::TimeAttack,
+            TextKey::TimeUp,
+            tf(TextKey::TimeAttackScore, &[&run.correct, &run.answered]),
+            Some(GameAction::StartTimeAttack(run.seconds)),
+        ),
+        GameMode::Round(run) => (
+            TextKey::Round,
+            TextKey::RoundComplete,
+            tf(TextKey::ChallengeScore, &[&run.correct, &run.length]),
+            Some(GameAction::StartRound),
+        ),
+        GameMode::Mistakes(run) => (
+            TextKey::ReviewMistakes,
+            TextKey::MistakesReviewed,
+            tf(TextKey::MistakesScore, &[&run.mistakes, &run.answered]),
+            None,
+        ),
+    };
+
+    dialog.overlay(
+        "run-results",
+        "run-title",
+        html! {
+            <>
+                <div class="summary-header">
+                    <h2 id="run-title">{ t(title) }</h2>
+                    <button
+                        ref={dialog.close_ref.clone()}
+                        onclick={props.on_action.reform(|_| GameAction::EndRun)}
+                        class="close-button"
+                        aria-label={t(TextKey::CloseRunResults)}
+                    >
+                        { "×" }
+                    </button>
+                </div>
+
+                <p class="run-ending">{ t(subtitle) }</p>
+                { grade }
+                <p class="challenge-score">{ score }</p>
+
+                <div class="summary-actions">
+                    if let Some(again) = again {
+                        <button class="play-again" onclick={props.on_action.reform(move |_| again.clone())}>
+                            { t(TextKey::PlayAgain) }
+                        </button>
+                    }
+                    <button class="close-btn" onclick={props.on_action.reform(|_| GameAction::EndRun)}>
+                        { t(TextKey::Close) }
+                    </button>
+                </div>
+            </>
+        },
+    )
+}
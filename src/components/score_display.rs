@@ -1,7 +1,10 @@
 //! Score Display Component
 //!
-//! Shows the player's current score, streak, and accuracy.
+//! Shows the player's points, the headline score, then right answers,
+//! streak and accuracy, and the lives left during a survival run.
 
+use crate::app::i18n::{t, use_locale, TextKey};
+use crate::game::modes::SURVIVAL_LIVES;
 use crate::game::ScoreState;
 use yew::prelude::*;
 
@@ -10,42 +13,93 @@ use yew::prelude::*;
 pub struct ScoreDisplayProps {
     /// Current score state
     pub score: ScoreState,
+
+    /// Show icons and numbers only (narrow screens)
+    #[prop_or_default]
+    pub compact: bool,
+
+    /// Lives left, in a mode that has them
+    #[prop_or_default]
+    pub lives: Option<u32>,
+}
+
+/// A full heart per life left and an empty one per life lost
+pub fn hearts(lives: u32) -> String {
+    let lost = SURVIVAL_LIVES.saturating_sub(lives) as usize;
+    format!("{}{}", "♥".repeat(lives as usize), "♡".repeat(lost))
 }
 
 /// The score display component
 #[function_component(ScoreDisplay)]
 pub fn score_display(props: &ScoreDisplayProps) -> Html {
+    use_locale();
     let score = &props.score;
     let total = score.correct + score.incorrect;
 
+    if props.compact {
+        return html! {
+            <div class="score-display compact">
+                <span class="score-value points" title={t(TextKey::Points)}>
+                    { format!("★ {}", score.points) }
+                </span>
+                <span class="score-value correct-score" title={t(TextKey::Score)}>
+                    { format!("✓ {}/{}", score.correct, total) }
+                </span>
+                <span class="score-value" title={t(TextKey::Accuracy)}>
+                    { format!("◎ {:.0}%", score.accuracy()) }
+                </span>
+                <span class="score-value streak" title={t(TextKey::Streak)}>
+                    { format!("🔥 {}", score.streak) }
+                </span>
+                if let Some(lives) = props.lives {
+                    <span class="score-value lives" title={t(TextKey::Lives)}>
+                        { format!("♥ {}", lives) }
+                    </span>
+                }
+            </div>
+        };
+    }
+
     html! {
         <div class="score-display">
             <div class="score-item">
-                <span class="score-label">{ "Score" }</span>
+                <span class="score-label">{ t(TextKey::Points) }</span>
+                <span class="score-value points">{ score.points }</span>
+            </div>
+
+            <div class="score-item">
+                <span class="score-label">{ t(TextKey::Score) }</span>
                 <span class="score-value correct-score">
                     { format!("{}/{}", score.correct, total) }
                 </span>
             </div>
 
             <div class="score-item">
-                <span class="score-label">{ "Accuracy" }</span>
+                <span class="score-label">{ t(TextKey::Accuracy) }</span>
                 <span class="score-value">
                     { format!("{:.0}%", score.accuracy()) }
                 </span>
             </div>
 
             <div class="score-item">
-                <span class="score-label">{ "Streak" }</span>
+                <span class="score-label">{ t(TextKey::Streak) }</span>
                 <span class="score-value streak">
                     { score.streak }
                     { if score.streak > 0 { "🔥" } else { "" } }
                 </span>
             </div>
 
+            if let Some(lives) = props.lives {
+                <div class="score-item">
+                    <span class="score-label">{ t(TextKey::Lives) }</span>
+                    <span class="score-value lives">{ hearts(lives) }</span>
+                </div>
+            }
+
             { if score.best_streak > 0 {
                 html! {
                     <div class="score-item best-streak">
-                        <span class="score-label">{ "Best" }</span>
+                        <span class="score-label">{ t(TextKey::Best) }</span>
                         <span class="score-value">{ score.best_streak }</span>
                     </div>
                 }
@@ -0,0 +1,366 @@
+//! Settings Panel Component
+//!
+//! How questions are asked: picked from choices or typed, the number of
+//! choices and where the wrong ones come from, how often "none of above"
+//! is the answer and which stars are quizzed, with the label, map name
+//! and projection settings that change how hard a question is.
+
+use super::controls::{label_density_buttons, projection_buttons};
+use crate::app::i18n::{t, tf, use_locale, TextKey};
+use crate::components::focus::use_dialog_focus;
+use crate::data::star_names::Lang;
+use crate::game::quiz::{MAX_CHOICES, MAX_NONE_PROBABILITY, MIN_CHOICES};
+use crate::game::{
+    AnswerMode, ChoiceNames, Difficulty, DistractorStrategy, GameAction, QuestionKind, QuizConfig,
+};
+use crate::utils::{LabelDensity, ProjectionKind};
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+/// Step of the "none of above" slider, in percent
+const NONE_PERCENT_STEP: u32 = 5;
+
+/// How often "none of above" is the answer, as a whole percentage
+pub fn none_percent(config: &QuizConfig) -> u32 {
+    if config.include_none_option {
+        (config.none_probability * 100.0).round() as u32
+    } else {
+        0
+    }
+}
+
+/// `config` with "none of above" the answer `percent` of the time; at 0
+/// the option is left out of questions altogether
+pub fn with_none_percent(config: &QuizConfig, percent: u32) -> QuizConfig {
+    QuizConfig {
+        include_none_option: percent > 0,
+        none_probability: f64::from(percent) / 100.0,
+        ..config.clone()
+    }
+}
+
+/// Props for SettingsPanel component
+#[derive(Properties, PartialEq)]
+pub struct SettingsPanelProps {
+    /// How questions are generated
+    pub config: QuizConfig,
+
+    /// Which stars start a quiz, or `None` for all
+    pub difficulty: Option<Difficulty>,
+
+    /// Whether answers are picked from choices or typed
+    pub answer_mode: AnswerMode,
+
+    /// How many star labels to draw
+    pub label_density: LabelDensity,
+
+    /// Tradition star labels name stars in
+    pub name_lang: Lang,
+
+    /// How the map flattens the sky
+    pub projection: ProjectionKind,
+
+    /// Callback for dispatching game actions
+    pub on_action: Callback<GameAction>,
+}
+
+/// The quiz settings panel
+#[function_component(SettingsPanel)]
+pub fn settings_panel(props: &SettingsPanelProps) -> Html {
+    use_locale();
+    let dialog = use_dialog_focus();
+
+    let answer_mode_buttons: Html = [AnswerMode::Choices, AnswerMode::Typed]
+        .into_iter()
+        .map(|mode| {
+            let on_action = props.on_action.clone();
+            html! {
+                <button
+                    class={classes!("toggle-btn", (props.answer_mode == mode).then_some("active"))}
+                    onclick={Callback::from(move |_| {
+                        on_action.emit(GameAction::SetAnswerMode(mode));
+                    })}
+                >
+                    { t(TextKey::for_answer_mode(mode)) }
+                </button>
+            }
+        })
+        .collect();
+
+    let choice_buttons: Html = (MIN_CHOICES..=MAX_CHOICES)
+        .map(|count| {
+            let on_action = props.on_action.clone();
+            let config = QuizConfig {
+                num_choices: count,
+                ..props.config.clone()
+            };
+            html! {
+                <button
+                    class={classes!("toggle-btn", (props.config.num_choices == count).then_some("active"))}
+                    onclick={Callback::from(move |_| {
+                        on_action.emit(GameAction::SetQuizConfig(config.clone()));
+                    })}
+                >
+                    { count }
+                </button>
+            }
+        })
+        .collect();
+
+    let strategy_buttons: Html = [DistractorStrategy::Tile, DistractorStrategy::Confusable]
+        .into_iter()
+        .map(|strategy| {
+            let on_action = props.on_action.clone();
+            let config = QuizConfig {
+                distractor_strategy: strategy,
+                ..props.config.clone()
+            };
+            let text = t(if strategy == DistractorStrategy::Confusable {
+                TextKey::LookAlikeNames
+            } else {
+                TextKey::NearbyStars
+            });
+            html! {
+                <button
+                    class={classes!(
+                        "toggle-btn",
+                        (props.config.distractor_strategy == strategy).then_some("active")
+                    )}
+                    onclick={Callback::from(move |_| {
+                        on_action.emit(GameAction::SetQuizConfig(config.clone()));
+                    })}
+                >
+                    { text }
+                </button>
+            }
+        })
+        .collect();
+
+    let name_buttons: Html = [ChoiceNames::Proper, ChoiceNames::Bayer]
+        .into_iter()
+        .map(|names| {
+            let on_action = props.on_action.clone();
+            let config = QuizConfig {
+                choice_names: names,
+                ..props.config.clone()
+            };
+            let (text, title) = match names {
+                ChoiceNames::Proper => (TextKey::ProperNames, TextKey::ProperNamesTitle),
+                ChoiceNames::Bayer => (TextKey::BayerNames, TextKey::BayerNamesTitle),
+            };
+            html! {
+                <button
+                    class={classes!(
+                        "toggle-btn",
+                        (props.config.choice_names == names).then_some("active")
+                    )}
+                    title={t(title)}
+                    onclick={Callback::from(move |_| {
+                        on_action.emit(GameAction::SetQuizConfig(config.clone()));
+                    })}
+                >
+                    { t(text) }
+                </button>
+            }
+        })
+        .collect();
+
+    let lang_buttons: Html = Lang::ALL
+        .into_iter()
+        .map(|lang| {
+            let on_action = props.on_action.clone();
+            let (text, title) = TextKey::for_name_lang(lang);
+            html! {
+                <button
+                    class={classes!("toggle-btn", (props.name_lang == lang).then_some("active"))}
+                    title={t(title)}
+                    onclick={Callback::from(move |_| {
+                        on_action.emit(GameAction::SetStarNameLang(lang));
+                    })}
+                >
+                    { t(text) }
+                </button>
+            }
+        })
+        .collect();
+
+    let kind_buttons: Html = QuestionKind::ALL
+        .into_iter()
+        .map(|kind| {
+            let on_action = props.on_action.clone();
+            let config = QuizConfig {
+                question_kind: kind,
+                ..props.config.clone()
+            };
+            let (text, title) = TextKey::for_question_kind(kind);
+            html! {
+                <button
+                    class={classes!(
+                        "toggle-btn",
+                        (props.config.question_kind == kind).then_some("active")
+                    )}
+                    title={t(title)}
+                    onclick={Callback::from(move |_| {
+                        on_action.emit(GameAction::SetQuizConfig(config.clone()));
+                    })}
+                >
+                    { t(text) }
+                </button>
+            }
+        })
+        .collect();
+
+    let percent = none_percent(&props.config);
+    let on_none_change = {
+        let on_action = props.on_action.clone();
+        let config = props.config.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(percent) = input.value().parse::<u32>() {
+                on_action.emit(GameAction::SetQuizConfig(with_none_percent(
+                    &config, percent,
+                )));
+            }
+        })
+    };
+
+    let difficulty_buttons: Html = [
+        None,
+        Some(Difficulty::Easy),
+        Some(Difficulty::Medium),
+        Some(Difficulty::Hard),
+    ]
+    .into_iter()
+    .map(|difficulty| {
+        let on_action = props.on_action.clone();
+        html! {
+            <button
+                class={classes!("toggle-btn", (props.difficulty == difficulty).then_some("active"))}
+                onclick={Callback::from(move |_| {
+                    on_action.emit(GameAction::SetDifficulty(difficulty));
+                })}
+            >
+                { t(TextKey::for_difficulty(difficulty)) }
+            </button>
+        }
+    })
+    .collect();
+
+    dialog.overlay(
+        "settings-panel",
+        "settings-title",
+        html! {
+            <>
+                <div class="summary-header">
+                    <h2 id="settings-title">{ t(TextKey::QuizSettings) }</h2>
+                    <button
+                        ref={dialog.close_ref.clone()}
+                        onclick={props.on_action.reform(|_| GameAction::ToggleSettings)}
+                        class="close-button"
+                        aria-label={t(TextKey::CloseSettings)}
+                    >
+                        { "×" }
+                    </button>
+                </div>
+
+                <div class="control-group answer-mode">
+                    <label class="control-label">{ t(TextKey::Answers) }</label>
+                    <div class="toggle-buttons">
+                        { answer_mode_buttons }
+                    </div>
+                </div>
+
+                <div class="control-group choices">
+                    <label class="control-label">{ t(TextKey::Choices) }</label>
+                    <div class="toggle-buttons">
+                        { choice_buttons }
+                    </div>
+                </div>
+
+                <div class="control-group distractors">
+                    <label class="control-label">{ t(TextKey::WrongChoices) }</label>
+                    <div class="toggle-buttons">
+                        { strategy_buttons }
+                    </div>
+                </div>
+
+                <div class="control-group question-kind">
+                    <label class="control-label">{ t(TextKey::AskFor) }</label>
+                    <div class="toggle-buttons">
+                        { kind_buttons }
+                    </div>
+                </div>
+
+                <div class="control-group choice-names">
+                    <label class="control-label">{ t(TextKey::ChoiceNames) }</label>
+                    <div class="toggle-buttons">
+                        { name_buttons }
+                    </div>
+                </div>
+
+                <div class="control-group">
+                    <label class="control-label">
+                        { tf(TextKey::NoneOfAboveChance, &[&percent]) }
+                    </label>
+                    <input
+                        type="range"
+                        class="magnitude-slider none-slider"
+                        min="0"
+                        max={((MAX_NONE_PROBABILITY * 100.0) as u32).to_string()}
+                        step={NONE_PERCENT_STEP.to_string()}
+                        value={percent.to_string()}
+                        oninput={on_none_change}
+                    />
+                </div>
+
+                <div class="control-group difficulty">
+                    <label class="control-label">{ t(TextKey::Difficulty) }</label>
+                    <div class="toggle-buttons">
+                        { difficulty_buttons }
+                    </div>
+                </div>
+
+                <div class="control-group">
+                    <label class="control-label">{ t(TextKey::Labels) }</label>
+                    <div class="toggle-buttons">
+                        { label_density_buttons(props.label_density, &props.on_action) }
+                    </div>
+                </div>
+
+                <div class="control-group label-names">
+                    <label class="control-label">{ t(TextKey::LabelNames) }</label>
+                    <div class="toggle-buttons">
+                        { lang_buttons }
+                    </div>
+                </div>
+
+                <div class="control-group">
+                    <label class="control-label">{ t(TextKey::Projection) }</label>
+                    <div class="toggle-buttons">
+                        { projection_buttons(props.projection, &props.on_action) }
+                    </div>
+                </div>
+            </>
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_none_percent_round_trips() {
+        let config = QuizConfig::default();
+        assert_eq!(none_percent(&config), 10);
+
+        let often = with_none_percent(&config, 25);
+        assert!(often.include_none_option);
+        assert_eq!(none_percent(&often), 25);
+        assert_eq!(often.num_choices, config.num_choices);
+
+        let never = with_none_percent(&config, 0);
+        assert!(!never.include_none_option);
+        assert_eq!(none_percent(&never), 0);
+    }
+}
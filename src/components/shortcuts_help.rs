@@ -0,0 +1,52 @@
+//! Shortcuts Help Component
+//!
+//! Lists the keyboard shortcuts, opened with `?`.
+
+use crate::app::i18n::{t, use_locale, TextKey};
+use crate::components::focus::use_dialog_focus;
+use crate::components::keyboard::SHORTCUTS;
+use crate::game::GameAction;
+use yew::prelude::*;
+
+/// Props for ShortcutsHelp component
+#[derive(Properties, PartialEq)]
+pub struct ShortcutsHelpProps {
+    /// Callback for dispatching game actions
+    pub on_action: Callback<GameAction>,
+}
+
+/// The keyboard shortcuts overlay
+#[function_component(ShortcutsHelp)]
+pub fn shortcuts_help(props: &ShortcutsHelpProps) -> Html {
+    use_locale();
+    let dialog = use_dialog_focus();
+
+    dialog.overlay(
+        "shortcuts-help",
+        "shortcuts-title",
+        html! {
+            <>
+                <div class="summary-header">
+                    <h2 id="shortcuts-title">{ t(TextKey::KeyboardShortcuts) }</h2>
+                    <button
+                        ref={dialog.close_ref.clone()}
+                        onclick={props.on_action.reform(|_| GameAction::HideHelp)}
+                        class="close-button"
+                        aria-label={t(TextKey::CloseHelp)}
+                    >
+                        { "×" }
+                    </button>
+                </div>
+
+                <dl class="shortcut-list">
+                    { SHORTCUTS.iter().map(|(keys, description)| html! {
+                        <div key={*keys} class="shortcut-row">
+                            <dt><kbd>{ *keys }</kbd></dt>
+                            <dd>{ t(*description) }</dd>
+                        </div>
+                    }).collect::<Html>() }
+                </dl>
+            </>
+        },
+    )
+}
@@ -0,0 +1,243 @@
+//! Star Info Component
+//!
+//! Shows facts about a selected star that is not being quizzed: an
+//! unnamed star's position and brightness, or in practice a named star's
+//! name, constellation and brightness. Once a question about a star is
+//! answered, a side panel adds how far away it is, its spectral type and
+//! how luminous it is, so a wrong answer still teaches something.
+
+use crate::app::i18n::{t, tf, use_locale, TextKey};
+use crate::data::constellations::constellation_name;
+use crate::data::star_names::Lang;
+use crate::data::{Star, StarCatalog};
+use crate::game::GameAction;
+use crate::utils::sky_svg::tooltip_name;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// How far to look for a named neighbor, in degrees
+pub const NEIGHBOR_SEARCH_DEGREES: f64 = 5.0;
+
+/// Props for the StarInfo component
+#[derive(Properties, PartialEq)]
+pub struct StarInfoProps {
+    /// The star catalog (for the nearest named neighbor)
+    pub catalog: Rc<StarCatalog>,
+
+    /// The star to describe
+    pub star: Star,
+
+    /// Tradition a named star's name is given in
+    #[prop_or_default]
+    pub name_lang: Lang,
+
+    /// Position to display the card (x, y)
+    pub position: (f64, f64),
+
+    /// Callback for dispatching game actions
+    pub on_action: Callback<GameAction>,
+}
+
+/// What practice tells about a star besides its name: the constellation
+/// it is in, if known, and its magnitude
+pub fn star_facts(star: &Star) -> Vec<String> {
+    let constellation = star.constellation.as_deref().map(|abbreviation| {
+        let name = constellation_name(abbreviation).unwrap_or(abbreviation);
+        tf(TextKey::InConstellation, &[&name])
+    });
+    constellation
+        .into_iter()
+        .chain([tf(
+            TextKey::Magnitude,
+            &[&format_args!("{:.2}", star.magnitude)],
+        )])
+        .collect()
+}
+
+/// A distance or luminosity to two or three significant figures
+fn format_figure(value: f64) -> String {
+    if value < 10.0 {
+        format!("{:.1}", value)
+    } else {
+        format!("{:.0}", value)
+    }
+}
+
+/// What the side panel adds about a star: its spectral type, distance
+/// and luminosity, as far as they are known
+pub fn physical_facts(star: &Star) -> Vec<String> {
+    let spectral_type = star
+        .spectral_type()
+        .map(|spectral_type| tf(TextKey::SpectralType, &[&spectral_type]));
+    let distance = star
+        .distance_ly()
+        .map(|distance| tf(TextKey::DistanceLy, &[&format_figure(distance)]));
+    let luminosity = star
+        .luminosity()
+        .map(|luminosity| tf(TextKey::Luminosity, &[&format_figure(luminosity)]));
+    [spectral_type, distance, luminosity]
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Describe the nearest named star as a teaching hint
+pub fn neighbor_hint(catalog: &StarCatalog, star: &Star) -> Option<String> {
+    let (neighbor, separation) =
+        catalog.nearest_named_star(&star.coord, NEIGHBOR_SEARCH_DEGREES)?;
+    Some(tf(
+        TextKey::NearNeighbor,
+        &[&neighbor.display_name(), &format_args!("{:.1}", separation)],
+    ))
+}
+
+/// The star info card component
+#[function_component(StarInfo)]
+pub fn star_info(props: &StarInfoProps) -> Html {
+    use_locale();
+    let star = &props.star;
+    let (x, y) = props.position;
+
+    let on_close = {
+        let on_action = props.on_action.clone();
+        Callback::from(move |_| {
+            on_action.emit(GameAction::ClearSelection);
+        })
+    };
+
+    // A named star is shown in practice, by name; an unnamed one by
+    // where it is and what is near it
+    let (title, body) = if star.has_name() {
+        let facts = star_facts(star)
+            .into_iter()
+            .map(|fact| html! { <p>{ fact }</p> });
+        (tooltip_name(star, props.name_lang), html! { for facts })
+    } else {
+        let hint = match neighbor_hint(&props.catalog, star) {
+            Some(text) => html! { <p class="star-info-hint">{ text }</p> },
+            None => Html::default(),
+        };
+        let body = html! {
+            <>
+                <p>{ tf(TextKey::StarPosition, &[
+                    &format_args!("{:.2}", star.coord.ra),
+                    &format_args!("{:.1}", star.coord.dec),
+                ]) }</p>
+                <p>{ tf(TextKey::Magnitude, &[&format_args!("{:.2}", star.magnitude)]) }</p>
+                { hint }
+            </>
+        };
+        (t(TextKey::UnnamedStar).to_string(), body)
+    };
+
+    html! {
+        <div
+            class="star-info"
+            style={format!("position: absolute; left: {}px; top: {}px;", x + 15.0, y + 15.0)}
+        >
+            <div class="quiz-header">
+                <span class="quiz-title">{ title }</span>
+                <button class="close-button" onclick={on_close} aria-label={t(TextKey::Close)}>{ "×" }</button>
+            </div>
+            <div class="star-info-body">
+                { body }
+            </div>
+        </div>
+    }
+}
+
+/// Props for the StarPanel component
+#[derive(Properties, PartialEq)]
+pub struct StarPanelProps {
+    /// The star just asked about
+    pub star: Star,
+
+    /// Tradition the star's name is given in
+    #[prop_or_default]
+    pub name_lang: Lang,
+}
+
+/// The side panel about the star of an answered question
+#[function_component(StarPanel)]
+pub fn star_panel(props: &StarPanelProps) -> Html {
+    use_locale();
+    let star = &props.star;
+    let facts = star_facts(star)
+        .into_iter()
+        .chain(physical_facts(star))
+        .map(|fact| html! { <p>{ fact }</p> });
+
+    html! {
+        <aside class="star-panel" aria-label={t(TextKey::AboutStar)}>
+            <h3 class="star-panel-title">{ tooltip_name(star, props.name_lang) }</h3>
+            <div class="star-info-body">
+                { for facts }
+            </div>
+        </aside>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{generate_placeholder_catalog, CelestialCoord, StarId};
+
+    #[test]
+    fn test_neighbor_hint_names_nearby_star() {
+        let catalog = generate_placeholder_catalog();
+        let sirius = catalog
+            .named_stars()
+            .into_iter()
+            .find(|s| s.name.as_deref() == Some("Sirius"))
+            .unwrap()
+            .clone();
+
+        // A faint unnamed star a degree and a half north of Sirius
+        let clicked = Star {
+            id: StarId(9000),
+            name: None,
+            coord: CelestialCoord::new(sirius.coord.ra, sirius.coord.dec + 1.5),
+            magnitude: 6.2,
+            constellation: None,
+        };
+        let hint = neighbor_hint(&catalog, &clicked).unwrap();
+        assert_eq!(hint, "This is near Sirius (1.5° away)");
+    }
+
+    #[test]
+    fn test_facts_name_the_constellation() {
+        let mut star = Star {
+            id: StarId(1),
+            name: Some("Vega".to_string()),
+            coord: CelestialCoord::new(18.6, 38.8),
+            magnitude: 0.03,
+            constellation: Some("Lyr".to_string()),
+        };
+        assert_eq!(star_facts(&star), ["In Lyra", "Magnitude 0.03"]);
+        star.constellation = None;
+        assert_eq!(star_facts(&star), ["Magnitude 0.03"]);
+    }
+
+    #[test]
+    fn test_physical_facts() {
+        let mut star = Star {
+            id: StarId(1),
+            name: Some("Rigil Kentaurus".to_string()),
+            coord: CelestialCoord::new(14.66, -60.8),
+            magnitude: -0.27,
+            constellation: Some("Cen".to_string()),
+        };
+        assert_eq!(
+            physical_facts(&star),
+            [
+                "Spectral type G2V",
+                "4.4 light-years away",
+                "1.5 times as luminous as the Sun"
+            ]
+        );
+        star.name = Some("Deneb".to_string());
+        assert_eq!(physical_facts(&star)[1], "2600 light-years away");
+        star.name = None;
+        assert!(physical_facts(&star).is_empty());
+    }
+}
@@ -1,99 +1,679 @@
 //! Star Map SVG Component
 //!
 //! Renders the interactive star map using SVG, handling
-//! pan, zoom, and star selection interactions.
+//! pan, zoom, and star selection interactions, by mouse or touch, and
+//! sizing the viewport to the map as laid out. The hooks and click
+//! handling here are shared with the canvas map in
+//! [`star_map_canvas`](super::star_map_canvas).
 
-use crate::data::{Star, StarCatalog, StarId};
+use super::gestures::{Momentum, TouchGesture};
+use super::star_info::star_facts;
+use crate::app::i18n::{t, tf, TextKey};
+use crate::data::asterisms::asterism_segments;
+use crate::data::constellations::{figure_segments, FigureSegment};
+use crate::data::dso;
+use crate::data::star_names::Lang;
+use crate::data::{StarCatalog, StarId};
 use crate::game::GameAction;
-use crate::utils::{Projection, Viewport};
+use crate::utils::perf;
+use crate::utils::sky_svg::{
+    deep_sky_glyphs, draw_list_for, figure_lines, glyph_path, grid_lines, horizon_overlay,
+    tooltip_name, DrawList, DrawOptions, DsoGlyph, StarLabel, StarRenderInfo, BACKGROUND_FILL,
+    DSO_GLYPH_RADIUS, HIT_SLOP,
+};
+use crate::utils::{LabelDensity, LocalSky, SkyIndex, Viewport};
+use gloo::render::{request_animation_frame, AnimationFrame};
+use std::cell::RefCell;
 use std::rc::Rc;
-use web_sys::{MouseEvent, WheelEvent};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, Event, MouseEvent, ResizeObserver, TouchEvent, WheelEvent};
 use yew::prelude::*;
 
-/// Props for the StarMap component
-#[derive(Properties, PartialEq)]
-pub struct StarMapProps {
-    /// The star catalog to render
-    pub catalog: Rc<StarCatalog>,
+/// Accumulates pointer deltas between animation frames
+///
+/// Drag events can arrive far faster than the display refreshes, so deltas
+/// are summed here and flushed as a single `Pan` once per frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DeltaAccumulator {
+    dx: f64,
+    dy: f64,
+}
 
-    /// Current viewport configuration
-    pub viewport: Viewport,
+impl DeltaAccumulator {
+    /// Add a pointer delta in pixels
+    pub fn add(&mut self, dx: f64, dy: f64) {
+        self.dx += dx;
+        self.dy += dy;
+    }
 
-    /// Current magnitude limit
-    pub magnitude_limit: f64,
+    /// Whether there is no pending movement
+    pub fn is_empty(&self) -> bool {
+        self.dx == 0.0 && self.dy == 0.0
+    }
 
-    /// Whether to show grid lines
-    pub show_grid: bool,
+    /// Take the pending delta (if any) and reset the accumulator
+    pub fn flush(&mut self) -> Option<(f64, f64)> {
+        if self.is_empty() {
+            return None;
+        }
+        let delta = (self.dx, self.dy);
+        self.reset();
+        Some(delta)
+    }
 
-    /// Currently selected star
-    pub selected_star: Option<StarId>,
+    /// Discard any pending movement
+    pub fn reset(&mut self) {
+        self.dx = 0.0;
+        self.dy = 0.0;
+    }
+}
 
-    /// Callback for dispatching game actions
-    pub on_action: Callback<GameAction>,
+/// Handle to the running pan flush loop (dropping it cancels the loop)
+type FrameHandle = Rc<RefCell<Option<AnimationFrame>>>;
+
+/// Flush accumulated deltas once per animation frame until the handle is cleared
+fn schedule_pan_flush(
+    accumulator: Rc<RefCell<DeltaAccumulator>>,
+    handle: FrameHandle,
+    on_action: Callback<GameAction>,
+) {
+    let next_handle = handle.clone();
+    let frame = request_animation_frame(move |_| {
+        if let Some((dx, dy)) = accumulator.borrow_mut().flush() {
+            on_action.emit(GameAction::Pan(dx, dy));
+        }
+        schedule_pan_flush(accumulator, next_handle, on_action);
+    });
+    *handle.borrow_mut() = Some(frame);
 }
 
-/// The star map component
-#[function_component(StarMap)]
-pub fn star_map(props: &StarMapProps) -> Html {
+/// Stop the flush loop, delivering any movement still pending
+fn stop_pan_flush(
+    accumulator: &RefCell<DeltaAccumulator>,
+    handle: &FrameHandle,
+    on_action: &Callback<GameAction>,
+) {
+    handle.borrow_mut().take();
+    if let Some((dx, dy)) = accumulator.borrow_mut().flush() {
+        on_action.emit(GameAction::Pan(dx, dy));
+    }
+}
+
+/// Length of the first frame of a glide, before frame times are known
+const FIRST_GLIDE_FRAME_MS: f64 = 16.0;
+
+/// Longest frame of a glide, so a stalled page does not jump the map
+const MAX_GLIDE_FRAME_MS: f64 = 64.0;
+
+/// Pan the map along a flick's glide once per animation frame until it stops
+fn schedule_glide(
+    momentum: Rc<RefCell<Momentum>>,
+    handle: FrameHandle,
+    on_action: Callback<GameAction>,
+    last_frame: Option<f64>,
+) {
+    let next_handle = handle.clone();
+    let frame = request_animation_frame(move |timestamp| {
+        let elapsed = last_frame.map_or(FIRST_GLIDE_FRAME_MS, |last| {
+            (timestamp - last).clamp(0.0, MAX_GLIDE_FRAME_MS)
+        });
+        let step = momentum.borrow_mut().step(elapsed);
+        if let Some((dx, dy)) = step {
+            on_action.emit(GameAction::Pan(dx, dy));
+            schedule_glide(momentum, next_handle, on_action, Some(timestamp));
+        }
+    });
+    *handle.borrow_mut() = Some(frame);
+}
+
+/// Handlers that pan the map by dragging or swiping, and zoom it with the
+/// wheel or a pinch
+pub(crate) struct PanZoomHandlers {
+    pub on_mouse_down: Callback<MouseEvent>,
+    pub on_mouse_move: Callback<MouseEvent>,
+    pub on_mouse_up: Callback<MouseEvent>,
+    pub on_mouse_leave: Callback<MouseEvent>,
+    pub on_wheel: Callback<WheelEvent>,
+    pub on_touch_start: Callback<TouchEvent>,
+    pub on_touch_move: Callback<TouchEvent>,
+    /// Also handles cancelled touches
+    pub on_touch_end: Callback<TouchEvent>,
+}
+
+/// Where a mouse event happened on the map element, in viewport pixels
+///
+/// The element is stretched to fill its container, which may differ from
+/// the viewport until the next resize is handled.
+pub(crate) fn viewport_point(e: &MouseEvent, viewport: &Viewport) -> Option<(f64, f64)> {
+    client_to_viewport(
+        e,
+        viewport,
+        f64::from(e.client_x()),
+        f64::from(e.client_y()),
+    )
+}
+
+/// Convert a point in client coordinates to viewport pixels of the map
+/// element handling `e`
+fn client_to_viewport(e: &Event, viewport: &Viewport, x: f64, y: f64) -> Option<(f64, f64)> {
+    let element: Element = e.current_target()?.dyn_into().ok()?;
+    let rect = element.get_bounding_client_rect();
+    if rect.width() <= 0.0 || rect.height() <= 0.0 {
+        return None;
+    }
+    Some((
+        (x - rect.left()) * viewport.width / rect.width(),
+        (y - rect.top()) * viewport.height / rect.height(),
+    ))
+}
+
+/// Client coordinates of the fingers still on the screen
+fn touch_points(e: &TouchEvent) -> Vec<(f64, f64)> {
+    let touches = e.touches();
+    (0..touches.length())
+        .filter_map(|i| touches.get(i))
+        .map(|touch| (f64::from(touch.client_x()), f64::from(touch.client_y())))
+        .collect()
+}
+
+/// Pan and zoom handlers for a map element showing `viewport`
+#[hook]
+pub(crate) fn use_pan_zoom(on_action: Callback<GameAction>, viewport: Viewport) -> PanZoomHandlers {
     let is_dragging = use_state(|| false);
     let last_pos = use_state(|| (0.0, 0.0));
 
-    // Get visible stars
-    let (ra_min, ra_max) = props.viewport.ra_range();
-    let (dec_min, dec_max) = props.viewport.dec_range();
-    let visible_stars =
-        props
-            .catalog
-            .stars_in_range(ra_min, ra_max, dec_min, dec_max, props.magnitude_limit);
+    // Pan deltas are accumulated here and flushed once per animation frame
+    let pan_accumulator = use_mut_ref(DeltaAccumulator::default);
+    let pan_frame: FrameHandle = use_mut_ref(|| None);
+
+    // One finger pans and two pinch; a flicked map glides on after the
+    // finger lifts
+    let gesture = use_mut_ref(TouchGesture::default);
+    let momentum = use_mut_ref(Momentum::default);
+    let glide_frame: FrameHandle = use_mut_ref(|| None);
+
+    // Cancel running frame loops if the map unmounts mid-drag or mid-glide
+    {
+        let pan_frame = pan_frame.clone();
+        let glide_frame = glide_frame.clone();
+        use_effect_with((), move |_| {
+            move || {
+                pan_frame.borrow_mut().take();
+                glide_frame.borrow_mut().take();
+            }
+        });
+    }
 
-    // Event handlers
     let on_mouse_down = {
         let is_dragging = is_dragging.clone();
         let last_pos = last_pos.clone();
+        let pan_accumulator = pan_accumulator.clone();
+        let pan_frame = pan_frame.clone();
+        let on_action = on_action.clone();
         Callback::from(move |e: MouseEvent| {
             is_dragging.set(true);
             last_pos.set((e.client_x() as f64, e.client_y() as f64));
+            pan_accumulator.borrow_mut().reset();
+            schedule_pan_flush(
+                pan_accumulator.clone(),
+                pan_frame.clone(),
+                on_action.clone(),
+            );
         })
     };
 
     let on_mouse_move = {
         let is_dragging = is_dragging.clone();
-        let last_pos = last_pos.clone();
-        let on_action = props.on_action.clone();
+        let pan_accumulator = pan_accumulator.clone();
         Callback::from(move |e: MouseEvent| {
             if *is_dragging {
                 let (last_x, last_y) = *last_pos;
                 let dx = e.client_x() as f64 - last_x;
                 let dy = e.client_y() as f64 - last_y;
                 last_pos.set((e.client_x() as f64, e.client_y() as f64));
-                on_action.emit(GameAction::Pan(dx, dy));
+                pan_accumulator.borrow_mut().add(dx, dy);
             }
         })
     };
 
-    let on_mouse_up = {
-        let is_dragging = is_dragging.clone();
+    // Releasing the button and leaving the map both end a drag
+    let end_drag = {
+        let pan_accumulator = pan_accumulator.clone();
+        let pan_frame = pan_frame.clone();
+        let on_action = on_action.clone();
         Callback::from(move |_: MouseEvent| {
             is_dragging.set(false);
+            stop_pan_flush(&pan_accumulator, &pan_frame, &on_action);
         })
     };
 
-    let on_mouse_leave = {
-        let is_dragging = is_dragging.clone();
-        Callback::from(move |_: MouseEvent| {
-            is_dragging.set(false);
+    // A touch catches a gliding map and starts the flush loop, unless
+    // another finger is already down
+    let on_touch_start = {
+        let gesture = gesture.clone();
+        let momentum = momentum.clone();
+        let glide_frame = glide_frame.clone();
+        let pan_accumulator = pan_accumulator.clone();
+        let pan_frame = pan_frame.clone();
+        let on_action = on_action.clone();
+        Callback::from(move |e: TouchEvent| {
+            glide_frame.borrow_mut().take();
+            momentum.borrow_mut().stop();
+            gesture.borrow_mut().reset(&touch_points(&e));
+            if pan_frame.borrow().is_none() {
+                pan_accumulator.borrow_mut().reset();
+                schedule_pan_flush(
+                    pan_accumulator.clone(),
+                    pan_frame.clone(),
+                    on_action.clone(),
+                );
+            }
         })
     };
 
-    let on_wheel = {
-        let on_action = props.on_action.clone();
-        Callback::from(move |e: WheelEvent| {
-            e.prevent_default();
-            let factor = if e.delta_y() < 0.0 { 1.2 } else { 0.8 };
-            on_action.emit(GameAction::ZoomBy(factor));
+    // Pinches pan immediately, so the zoom centers on where the fingers
+    // are now rather than where the pending pan would leave them
+    let on_touch_move = {
+        let gesture = gesture.clone();
+        let momentum = momentum.clone();
+        let pan_accumulator = pan_accumulator.clone();
+        let on_action = on_action.clone();
+        Callback::from(move |e: TouchEvent| {
+            let Some(step) = gesture.borrow_mut().update(&touch_points(&e)) else {
+                return;
+            };
+            let (dx, dy) = step.pan;
+            pan_accumulator.borrow_mut().add(dx, dy);
+            if !gesture.borrow().is_pinch() {
+                momentum.borrow_mut().track(dx, dy, now_ms());
+                return;
+            }
+            momentum.borrow_mut().stop();
+            if let Some((dx, dy)) = pan_accumulator.borrow_mut().flush() {
+                on_action.emit(GameAction::Pan(dx, dy));
+            }
+            if let Some((factor, x, y)) = step.zoom {
+                if let Some((x, y)) = client_to_viewport(&e, &viewport, x, y) {
+                    on_action.emit(GameAction::ZoomAt(factor, x, y));
+                }
+            }
+        })
+    };
+
+    // Lifting one finger of a pinch carries on as a swipe; lifting the
+    // last ends the drag, gliding on if it was flicked
+    let on_touch_end = {
+        let on_action = on_action.clone();
+        Callback::from(move |e: TouchEvent| {
+            let remaining = touch_points(&e);
+            gesture.borrow_mut().reset(&remaining);
+            if !remaining.is_empty() {
+                momentum.borrow_mut().stop();
+                return;
+            }
+            stop_pan_flush(&pan_accumulator, &pan_frame, &on_action);
+            if momentum.borrow_mut().release(now_ms()) {
+                schedule_glide(
+                    momentum.clone(),
+                    glide_frame.clone(),
+                    on_action.clone(),
+                    None,
+                );
+            }
+        })
+    };
+
+    // The wheel zooms about the cursor
+    let on_wheel = Callback::from(move |e: WheelEvent| {
+        e.prevent_default();
+        let factor = if e.delta_y() < 0.0 { 1.2 } else { 0.8 };
+        match viewport_point(&e, &viewport) {
+            Some((x, y)) => on_action.emit(GameAction::ZoomAt(factor, x, y)),
+            None => on_action.emit(GameAction::ZoomBy(factor)),
+        }
+    });
+
+    PanZoomHandlers {
+        on_mouse_down,
+        on_mouse_move,
+        on_mouse_up: end_drag.clone(),
+        on_mouse_leave: end_drag,
+        on_wheel,
+        on_touch_start,
+        on_touch_move,
+        on_touch_end,
+    }
+}
+
+/// Props for the StarMap component
+#[derive(Properties, PartialEq)]
+pub struct StarMapProps {
+    /// The star catalog to render
+    pub catalog: Rc<StarCatalog>,
+
+    /// Index over `catalog`, used to cull stars outside the view quickly
+    #[prop_or_default]
+    pub index: Option<Rc<SkyIndex>>,
+
+    /// Current viewport configuration
+    pub viewport: Viewport,
+
+    /// Current magnitude limit
+    pub magnitude_limit: f64,
+
+    /// Whether to show grid lines
+    pub show_grid: bool,
+
+    /// Whether to draw the constellation figures
+    pub show_constellations: bool,
+
+    /// Whether to mark the Messier objects, each of which starts a
+    /// deep-sky quiz when clicked
+    #[prop_or_default]
+    pub show_deep_sky: bool,
+
+    /// Whether to draw the asterisms
+    #[prop_or_default]
+    pub show_asterisms: bool,
+
+    /// Currently selected star
+    pub selected_star: Option<StarId>,
+
+    /// A second star ringed alongside the selected one, when a question
+    /// compares two stars
+    #[prop_or_default]
+    pub compared_star: Option<StarId>,
+
+    /// Star size multiplier
+    pub star_scale: f64,
+
+    /// Whether to play the "ping" highlight on the selected star
+    pub ping: bool,
+
+    /// Whether unnamed stars respond to clicks with an info card
+    pub unnamed_clickable: bool,
+
+    /// How many star labels to draw
+    pub label_density: LabelDensity,
+
+    /// Tradition star labels and tooltips name stars in
+    #[prop_or_default]
+    pub name_lang: Lang,
+
+    /// Whether a question is open: star names are neither labeled nor
+    /// shown on hover, so the map cannot give the answer away
+    #[prop_or_default]
+    pub quiz_active: bool,
+
+    /// Whether this is practice: hovering a named star shows its info
+    /// card, and star tooltips add the constellation and magnitude
+    #[prop_or_default]
+    pub practice: bool,
+
+    /// The observer's sky, in local sky mode: stars below its horizon are
+    /// dimmed and cannot be clicked
+    #[prop_or_default]
+    pub local_sky: Option<LocalSky>,
+
+    /// Whether stars below the local horizon are left out rather than
+    /// dimmed
+    #[prop_or_default]
+    pub hide_below_horizon: bool,
+
+    /// Callback for dispatching game actions
+    pub on_action: Callback<GameAction>,
+}
+
+/// High-resolution page clock in milliseconds
+pub(crate) fn now_ms() -> f64 {
+    web_sys::window()
+        .and_then(|w| w.performance())
+        .map(|p| p.now())
+        .unwrap_or_default()
+}
+
+/// A ref for the map element, whose laid-out size is sent as the viewport
+/// size whenever it changes, so one viewport pixel is one CSS pixel
+#[hook]
+pub(crate) fn use_map_size(on_action: Callback<GameAction>) -> NodeRef {
+    let node_ref = use_node_ref();
+    {
+        let node_ref = node_ref.clone();
+        use_effect_with((), move |_| {
+            let observer = node_ref.cast::<Element>().and_then(|element| {
+                let target = element.clone();
+                // Also called once when observing starts
+                let callback = Closure::<dyn Fn()>::new(move || {
+                    let (width, height) = (target.client_width(), target.client_height());
+                    if width > 0 && height > 0 {
+                        on_action.emit(GameAction::SetViewportSize(
+                            f64::from(width),
+                            f64::from(height),
+                        ));
+                    }
+                });
+                let observer = ResizeObserver::new(callback.as_ref().unchecked_ref()).ok()?;
+                observer.observe(&element);
+                Some((observer, callback))
+            });
+            move || {
+                if let Some((observer, _callback)) = observer {
+                    observer.disconnect();
+                }
+            }
+        });
+    }
+    node_ref
+}
+
+/// The stars and labels in view, culled and projected only when the view
+/// or catalog changes, not for quiz or popup updates
+#[hook]
+pub(crate) fn use_draw_list(props: &StarMapProps) -> Rc<DrawList> {
+    let draw_options = DrawOptions {
+        magnitude_limit: props.magnitude_limit,
+        star_scale: props.star_scale,
+        label_density: if props.quiz_active {
+            LabelDensity::Off
+        } else {
+            props.label_density
+        },
+        name_lang: props.name_lang,
+    };
+    let catalog_key = Rc::as_ptr(&props.catalog) as usize;
+    let horizon = props.local_sky.filter(|_| props.hide_below_horizon);
+
+    // With an index, only stars in on-screen cells are considered, and
+    // that candidate list is reused while pans stay within the same cells.
+    // When the index cannot narrow the view the whole catalog is scanned.
+    let (ra_min, ra_max) = props.viewport.ra_range();
+    let (dec_min, dec_max) = props.viewport.dec_range();
+    let cells = props
+        .index
+        .as_ref()
+        .and_then(|index| index.cells_in_range(ra_min, ra_max, dec_min, dec_max));
+    let candidates = {
+        let index = props.index.clone();
+        let index_key = props.index.as_ref().map(|i| Rc::as_ptr(i) as usize);
+        use_memo((cells, catalog_key, index_key), move |(cells, _, _)| {
+            Some(index.as_ref()?.candidates(cells.as_ref()?))
         })
     };
 
+    let catalog = props.catalog.clone();
+    use_memo(
+        (
+            props.viewport,
+            draw_options,
+            catalog_key,
+            Rc::as_ptr(&candidates) as usize,
+            horizon,
+        ),
+        move |(viewport, options, _, _, horizon)| {
+            let mut visible = match candidates.as_ref() {
+                Some(candidates) => candidates.in_range(
+                    &catalog,
+                    ra_min,
+                    ra_max,
+                    dec_min,
+                    dec_max,
+                    options.magnitude_limit,
+                ),
+                None => catalog.stars_in_range(
+                    ra_min,
+                    ra_max,
+                    dec_min,
+                    dec_max,
+                    options.magnitude_limit,
+                ),
+            };
+            if let Some(sky) = horizon {
+                visible.retain(|star| sky.is_visible(&star.coord));
+            }
+            draw_list_for(&visible, viewport, options)
+        },
+    )
+}
+
+/// The constellation figures of a catalog
+///
+/// Figure ends snap to the catalog's stars, so are found once per catalog.
+#[hook]
+pub(crate) fn use_figures(catalog: &Rc<StarCatalog>) -> Rc<Vec<FigureSegment>> {
+    let catalog = catalog.clone();
+    use_memo(Rc::as_ptr(&catalog) as usize, move |_| {
+        figure_segments(&catalog)
+    })
+}
+
+/// The asterisms' lines between the stars of a catalog, found once per
+/// catalog
+#[hook]
+pub(crate) fn use_asterisms(catalog: &Rc<StarCatalog>) -> Rc<Vec<FigureSegment>> {
+    let catalog = catalog.clone();
+    use_memo(Rc::as_ptr(&catalog) as usize, move |_| {
+        asterism_segments(&catalog)
+    })
+}
+
+/// The Messier objects in view when they are shown, placed only when the
+/// view or the local sky changes
+#[hook]
+pub(crate) fn use_deep_sky(props: &StarMapProps) -> Rc<Vec<DsoGlyph>> {
+    use_memo(
+        (
+            props.show_deep_sky,
+            props.viewport,
+            props.local_sky,
+            props.hide_below_horizon,
+        ),
+        |&(show, viewport, local_sky, hide_below_horizon)| {
+            if !show {
+                return Vec::new();
+            }
+            let mut glyphs = deep_sky_glyphs(&viewport, local_sky.as_ref());
+            if hide_below_horizon {
+                glyphs.retain(|glyph| !glyph.below_horizon);
+            }
+            glyphs
+        },
+    )
+}
+
+/// Dispatch the actions for a click on a deep-sky glyph, which asks the
+/// app for a quiz about the object unless it is below the horizon
+pub(crate) fn click_deep_sky(on_action: &Callback<GameAction>, glyph: &DsoGlyph) {
+    if glyph.below_horizon {
+        return;
+    }
+    on_action.emit(GameAction::SetDropdownPosition(glyph.x, glyph.y));
+    on_action.emit(GameAction::SelectDeepSky(glyph.messier));
+}
+
+/// Whether a star is out of sight in the local sky, if one is shown
+pub(crate) fn is_below_horizon(
+    catalog: &StarCatalog,
+    local_sky: Option<LocalSky>,
+    id: StarId,
+) -> bool {
+    local_sky
+        .zip(catalog.get(id))
+        .is_some_and(|(sky, star)| !sky.is_visible(&star.coord))
+}
+
+/// Dispatch the actions for a click on a drawn star
+///
+/// Named stars start a quiz and unnamed ones, if enabled, show their info
+/// card. Stars below the local horizon are not quizzed or described.
+pub(crate) fn click_star(
+    on_action: &Callback<GameAction>,
+    star: &StarRenderInfo,
+    unnamed_clickable: bool,
+    below_horizon: bool,
+) {
+    if below_horizon || !(star.named || unnamed_clickable) {
+        return;
+    }
+    // Position first: selecting may re-center the view and move it
+    on_action.emit(GameAction::SetDropdownPosition(star.x, star.y));
+    on_action.emit(GameAction::SelectStar(star.id));
+    if !star.named {
+        on_action.emit(GameAction::ShowStarInfo);
+    }
+}
+
+/// The map's mouse-move handler: `on_mouse_move`'s panning, and in
+/// practice the info card of the named star under the pointer
+///
+/// The star is found with [`DrawList::star_at`], as the canvas finds
+/// clicked stars, so hovering works the same on either renderer. A star
+/// is described once as the pointer reaches it, as a click would.
+#[hook]
+pub(crate) fn use_practice_hover(
+    props: &StarMapProps,
+    draw_list: Rc<DrawList>,
+    on_mouse_move: Callback<MouseEvent>,
+) -> Callback<MouseEvent> {
+    let hovered = use_mut_ref(|| None::<StarId>);
+    let practice = props.practice;
+    let viewport = props.viewport;
+    let catalog = props.catalog.clone();
+    let local_sky = props.local_sky;
+    let on_action = props.on_action.clone();
+
+    Callback::from(move |e: MouseEvent| {
+        on_mouse_move.emit(e.clone());
+        // Not while a button is down, which is a drag
+        if !practice || e.buttons() != 0 {
+            return;
+        }
+        let star = viewport_point(&e, &viewport)
+            .and_then(|(x, y)| draw_list.star_at(x, y))
+            .filter(|star| star.named && !is_below_horizon(&catalog, local_sky, star.id));
+        let id = star.map(|star| star.id);
+        if hovered.replace(id) == id {
+            return;
+        }
+        if let Some(star) = star {
+            click_star(&on_action, star, false, false);
+        }
+    })
+}
+
+/// The star map component
+#[function_component(StarMap)]
+pub fn star_map(props: &StarMapProps) -> Html {
+    // Timed for the debug overlay
+    let started = now_ms();
+    let draw_list = use_draw_list(props);
+    let figures = use_figures(&props.catalog);
+    let asterisms = use_asterisms(&props.catalog);
+    let deep_sky = use_deep_sky(props);
+    let handlers = use_pan_zoom(props.on_action.clone(), props.viewport);
+    let on_mouse_move = use_practice_hover(props, draw_list.clone(), handlers.on_mouse_move);
+    let map_ref = use_map_size(props.on_action.clone());
+
     // Background click to dismiss quiz dialog
     let on_background_click = {
         let on_action = props.on_action.clone();
@@ -109,28 +689,75 @@ pub fn star_map(props: &StarMapProps) -> Html {
         Html::default()
     };
 
-    let star_elements: Html = visible_stars
+    let constellations = if props.show_constellations {
+        render_figure_lines(&props.viewport, &figures, "constellation-line")
+    } else {
+        Html::default()
+    };
+
+    let asterism_lines = if props.show_asterisms {
+        render_figure_lines(&props.viewport, &asterisms, "asterism-line")
+    } else {
+        Html::default()
+    };
+
+    let horizon = match &props.local_sky {
+        Some(sky) => render_horizon(&props.viewport, sky),
+        None => Html::default(),
+    };
+
+    let deep_sky_elements = render_deep_sky(&deep_sky, props.quiz_active, &props.on_action);
+
+    let star_elements: Html = draw_list
+        .stars
         .iter()
-        .map(|star| {
+        .map(|info| {
+            let star = props.catalog.get(info.id);
+            let below_horizon = is_below_horizon(&props.catalog, props.local_sky, info.id);
+            let title = star
+                .filter(|_| info.named && !props.quiz_active)
+                .map(|star| {
+                    let mut lines = vec![tooltip_name(star, props.name_lang)];
+                    if props.practice {
+                        lines.extend(star_facts(star));
+                    }
+                    let name = lines.join("\n");
+                    if below_horizon {
+                        tf(TextKey::BelowHorizon, &[&name])
+                    } else {
+                        name
+                    }
+                });
             render_star(
-                star,
-                &props.viewport,
-                props.selected_star == Some(star.id),
+                info,
+                title,
+                props.selected_star == Some(info.id) || props.compared_star == Some(info.id),
+                props.ping,
+                props.unnamed_clickable,
+                below_horizon,
                 props.on_action.clone(),
             )
         })
         .collect();
 
+    let label_elements = render_labels(&draw_list.labels);
+    perf::record_render(draw_list.stars.len(), now_ms() - started);
+
     html! {
         <svg
+            ref={map_ref}
             class="star-map"
             viewBox={format!("0 0 {} {}", props.viewport.width, props.viewport.height)}
             preserveAspectRatio="xMidYMid slice"
-            onmousedown={on_mouse_down}
+            onmousedown={handlers.on_mouse_down}
             onmousemove={on_mouse_move}
-            onmouseup={on_mouse_up}
-            onmouseleave={on_mouse_leave}
-            onwheel={on_wheel}
+            onmouseup={handlers.on_mouse_up}
+            onmouseleave={handlers.on_mouse_leave}
+            onwheel={handlers.on_wheel}
+            ontouchstart={handlers.on_touch_start}
+            ontouchmove={handlers.on_touch_move}
+            ontouchend={handlers.on_touch_end.clone()}
+            ontouchcancel={handlers.on_touch_end}
         >
             // Background (click to dismiss quiz)
             <rect
@@ -138,148 +765,261 @@ pub fn star_map(props: &StarMapProps) -> Html {
                 y="0"
                 width={props.viewport.width.to_string()}
                 height={props.viewport.height.to_string()}
-                fill="#0a0a14"
+                fill={BACKGROUND_FILL}
                 onclick={on_background_click}
             />
 
             // Grid
             {grid_lines}
 
+            // Constellation figures
+            {constellations}
+
+            // Asterisms
+            {asterism_lines}
+
+            // Horizon, in local sky mode
+            {horizon}
+
+            // Messier objects, under the stars
+            {deep_sky_elements}
+
             // Stars
             {star_elements}
+
+            // Labels
+            {label_elements}
         </svg>
     }
 }
 
 /// Render grid lines
 fn render_grid(viewport: &Viewport) -> Html {
-    let mut lines = Vec::new();
-
-    // RA lines (every hour at zoom 1, more at higher zooms)
-    let ra_step = (2.0 / viewport.zoom).max(0.5);
-    let mut ra = 0.0;
-    while ra < 24.0 {
-        let _coord = crate::data::CelestialCoord::new(ra, 0.0);
-        let screen_top = viewport.celestial_to_screen(&crate::data::CelestialCoord::new(ra, 90.0));
-        let screen_bot = viewport.celestial_to_screen(&crate::data::CelestialCoord::new(ra, -90.0));
-
-        if screen_top.x >= 0.0 && screen_top.x <= viewport.width {
-            lines.push(html! {
+    grid_lines(viewport)
+        .into_iter()
+        .map(|line| {
+            html! {
                 <line
-                    key={format!("ra-{}", ra)}
-                    x1={screen_top.x.to_string()}
-                    y1={screen_top.y.to_string()}
-                    x2={screen_bot.x.to_string()}
-                    y2={screen_bot.y.to_string()}
-                    stroke="#1a3a5a"
-                    stroke-width="1"
+                    key={line.key}
+                    x1={line.x1.to_string()}
+                    y1={line.y1.to_string()}
+                    x2={line.x2.to_string()}
+                    y2={line.y2.to_string()}
+                    stroke={line.stroke}
+                    stroke-width={line.width}
                     stroke-opacity="0.5"
                 />
-            });
-        }
-        ra += ra_step;
-    }
+            }
+        })
+        .collect()
+}
 
-    // Dec lines (every 10 degrees at zoom 1, more at higher zooms)
-    let dec_step = (30.0 / viewport.zoom).max(5.0);
-    let mut dec = -80.0;
-    while dec <= 80.0 {
-        let screen_left = viewport.celestial_to_screen(&crate::data::CelestialCoord::new(0.0, dec));
-        let screen_right =
-            viewport.celestial_to_screen(&crate::data::CelestialCoord::new(24.0, dec));
-
-        // Celestial equator gets special treatment
-        let stroke_color = if (dec.abs()) < 0.1 {
-            "#7a2a5a"
-        } else {
-            "#1a3a5a"
-        };
-        let stroke_width = if (dec.abs()) < 0.1 { "2" } else { "1" };
-
-        lines.push(html! {
-            <line
-                key={format!("dec-{}", dec)}
-                x1="0"
-                y1={screen_left.y.to_string()}
-                x2={viewport.width.to_string()}
-                y2={screen_right.y.to_string()}
-                stroke={stroke_color}
-                stroke-width={stroke_width}
-                stroke-opacity="0.5"
-            />
-        });
-        dec += dec_step;
-    }
+/// Render constellation or asterism figure lines with the given class
+fn render_figure_lines(
+    viewport: &Viewport,
+    segments: &[FigureSegment],
+    class: &'static str,
+) -> Html {
+    figure_lines(viewport, segments)
+        .into_iter()
+        .map(|line| {
+            html! {
+                <line
+                    key={line.key}
+                    class={class}
+                    x1={line.x1.to_string()}
+                    y1={line.y1.to_string()}
+                    x2={line.x2.to_string()}
+                    y2={line.y2.to_string()}
+                />
+            }
+        })
+        .collect()
+}
 
-    html! { <>{ for lines }</> }
+/// Render the horizon line and compass points of the local sky
+fn render_horizon(viewport: &Viewport, sky: &LocalSky) -> Html {
+    let overlay = horizon_overlay(viewport, sky);
+    let lines: Html = overlay
+        .segments
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| {
+            let points = segment
+                .iter()
+                .map(|p| format!("{:.1},{:.1}", p.x, p.y))
+                .collect::<Vec<_>>()
+                .join(" ");
+            html! { <polyline key={format!("horizon-{}", i)} class="horizon-line" points={points} /> }
+        })
+        .collect();
+    let cardinals: Html = overlay
+        .cardinals
+        .iter()
+        .map(|(cardinal, point)| {
+            html! {
+                <text
+                    key={format!("cardinal-{:?}", cardinal)}
+                    class="cardinal-label"
+                    x={point.x.to_string()}
+                    y={(point.y - 6.0).to_string()}
+                >
+                    { t(TextKey::for_cardinal(*cardinal)) }
+                </text>
+            }
+        })
+        .collect();
+    html! { <g class="horizon">{ lines }{ cardinals }</g> }
+}
+
+/// Render the Messier objects in view, each as its kind's chart symbol
+fn render_deep_sky(
+    glyphs: &[DsoGlyph],
+    quiz_active: bool,
+    on_action: &Callback<GameAction>,
+) -> Html {
+    glyphs
+        .iter()
+        .map(|glyph| {
+            let title = dso::messier(glyph.messier)
+                .filter(|_| !quiz_active)
+                .map(|object| {
+                    let name = format!(
+                        "{} ({})",
+                        object.display_name(),
+                        t(TextKey::for_dso_kind(object.kind))
+                    );
+                    if glyph.below_horizon {
+                        tf(TextKey::BelowHorizon, &[&name])
+                    } else {
+                        name
+                    }
+                });
+            let on_click = {
+                let on_action = on_action.clone();
+                let glyph = *glyph;
+                Callback::from(move |e: MouseEvent| {
+                    e.stop_propagation();
+                    click_deep_sky(&on_action, &glyph);
+                })
+            };
+            html! {
+                <g
+                    key={format!("m{}", glyph.messier)}
+                    class={classes!(
+                        "dso",
+                        glyph.kind.class_name(),
+                        glyph.below_horizon.then_some("below-horizon")
+                    )}
+                    onclick={on_click}
+                >
+                    <circle
+                        class="dso-hit"
+                        cx={glyph.x.to_string()}
+                        cy={glyph.y.to_string()}
+                        r={(DSO_GLYPH_RADIUS + HIT_SLOP).to_string()}
+                    />
+                    <path class="dso-glyph" d={glyph_path(glyph)} />
+                    { for title.map(|title| html! { <title>{ title }</title> }) }
+                </g>
+            }
+        })
+        .collect()
+}
+
+/// Render name labels for the named stars the label policy selected
+fn render_labels(labels: &[StarLabel]) -> Html {
+    labels
+        .iter()
+        .map(|label| {
+            html! {
+                <text
+                    key={format!("label-{}", label.id.0)}
+                    class="star-label"
+                    x={label.x.to_string()}
+                    y={label.y.to_string()}
+                >
+                    { label.text.clone() }
+                </text>
+            }
+        })
+        .collect()
 }
 
 /// Render a single star
 fn render_star(
-    star: &Star,
-    viewport: &Viewport,
+    star: &StarRenderInfo,
+    title: Option<String>,
     is_selected: bool,
+    ping: bool,
+    unnamed_clickable: bool,
+    below_horizon: bool,
     on_action: Callback<GameAction>,
 ) -> Html {
-    let screen = viewport.celestial_to_screen(&star.coord);
-    let base_radius = 3.0 / viewport.zoom.sqrt();
-    let radius = star.render_radius(base_radius);
+    let radius = star.radius;
+    let fill_color = star.fill;
 
-    // Color based on whether star is named
-    let fill_color = if star.has_name() {
-        "#fffaf0" // Warmer white for named stars
-    } else {
-        "#c0c8d0" // Cooler for unnamed
-    };
+    let has_name = star.named;
+    let clickable = !below_horizon && (has_name || unnamed_clickable);
 
-    let star_id = star.id;
-    let has_name = star.has_name();
-    let screen_x = screen.x;
-    let screen_y = screen.y;
-
-    let on_click = Callback::from(move |e: MouseEvent| {
-        e.stop_propagation();
-        if has_name {
-            on_action.emit(GameAction::SelectStar(star_id));
-            on_action.emit(GameAction::SetDropdownPosition(screen_x, screen_y));
-        }
-    });
+    let on_click = {
+        let star = star.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            click_star(&on_action, &star, unnamed_clickable, below_horizon);
+        })
+    };
 
-    // Selection ring for selected star
+    // Selection ring for selected star (pulsing, plus a one-off ping)
     let selection_ring = if is_selected {
         html! {
-            <circle
-                cx={screen.x.to_string()}
-                cy={screen.y.to_string()}
-                r={(radius * 3.0).to_string()}
-                fill="none"
-                stroke="#ff4444"
-                stroke-width="2"
-            />
+            <>
+                <circle
+                    class="selection-ring"
+                    cx={star.x.to_string()}
+                    cy={star.y.to_string()}
+                    r={(radius * 3.0).to_string()}
+                    fill="none"
+                    stroke="#ff4444"
+                    stroke-width="2"
+                />
+                { if ping {
+                    html! {
+                        <circle
+                            class="selection-ping"
+                            cx={star.x.to_string()}
+                            cy={star.y.to_string()}
+                            r={(radius * 3.0).to_string()}
+                            fill="none"
+                            stroke="#ff4444"
+                            stroke-width="2"
+                        />
+                    }
+                } else {
+                    Html::default()
+                }}
+            </>
         }
     } else {
         Html::default()
     };
 
     html! {
-        <g key={format!("star-{}", star.id.0)} class="star-group">
+        <g
+            key={format!("star-{}", star.id.0)}
+            class={classes!("star-group", below_horizon.then_some("below-horizon"))}
+        >
             {selection_ring}
             <circle
-                cx={screen.x.to_string()}
-                cy={screen.y.to_string()}
+                cx={star.x.to_string()}
+                cy={star.y.to_string()}
                 r={radius.to_string()}
                 fill={fill_color}
-                class={if star.has_name() { "star named-star" } else { "star" }}
+                class={if has_name { "star named-star" } else { "star" }}
                 onclick={on_click}
-                style={if star.has_name() { "cursor: pointer;" } else { "" }}
+                style={if clickable { "cursor: pointer;" } else { "" }}
             >
-                { if star.has_name() {
-                    html! {
-                        <title>{ star.display_name() }</title>
-                    }
-                } else {
-                    Html::default()
-                }}
+                { for title.map(|title| html! { <title>{ title }</title> }) }
             </circle>
         </g>
     }
@@ -297,5 +1037,31 @@ mod tests {
         // Just ensure the render functions are valid Rust
         let viewport = Viewport::default();
         let _grid = render_grid(&viewport);
+        let sky = LocalSky::at(crate::utils::Observer::new(50.0, 0.0).unwrap(), 0.0);
+        let _horizon = render_horizon(&viewport, &sky);
+    }
+
+    #[test]
+    fn test_delta_accumulator_sums_moves() {
+        let mut acc = DeltaAccumulator::default();
+        assert!(acc.is_empty());
+
+        acc.add(3.0, -1.0);
+        acc.add(2.0, 4.0);
+        assert_eq!(acc.flush(), Some((5.0, 3.0)));
+    }
+
+    #[test]
+    fn test_delta_accumulator_flush_resets() {
+        let mut acc = DeltaAccumulator::default();
+        acc.add(1.0, 1.0);
+        acc.flush();
+
+        assert!(acc.is_empty());
+        assert_eq!(acc.flush(), None);
+
+        acc.add(2.0, 0.0);
+        acc.reset();
+        assert_eq!(acc.flush(), None);
     }
 }
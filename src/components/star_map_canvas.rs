@@ -0,0 +1,274 @@
+//! Star Map Canvas Component
+//!
+//! Paints the same map as [`StarMap`](super::StarMap) onto an HTML canvas.
+//! One element holds the whole sky instead of one per star, which keeps
+//! large catalogs responsive. Panning and zooming, by mouse or touch,
+//! share the SVG map's handlers; clicks are matched to deep-sky glyphs
+//! with [`glyph_at`] and to stars with [`DrawList::star_at`], and so is
+//! the pointer for practice's info card on hover.
+//!
+//! Without an element per star there are no name tooltips, and the
+//! selection ring is drawn without its pulse.
+
+use super::star_map::{
+    click_deep_sky, click_star, is_below_horizon, now_ms, use_asterisms, use_deep_sky,
+    use_draw_list, use_figures, use_map_size, use_pan_zoom, use_practice_hover, viewport_point,
+    StarMapProps,
+};
+use crate::app::i18n::{t, TextKey};
+use crate::data::constellations::FigureSegment;
+use crate::data::StarId;
+use crate::game::GameAction;
+use crate::utils::perf;
+use crate::utils::sky_svg::{
+    figure_lines, glyph_at, glyph_path, grid_lines, horizon_overlay, DrawList, DsoGlyph,
+    BACKGROUND_FILL,
+};
+use crate::utils::{LocalSky, Viewport};
+use std::f64::consts::TAU;
+use std::rc::Rc;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, MouseEvent, Path2d};
+use yew::prelude::*;
+
+/// Colors and fonts matching the SVG map's stylesheet rules
+const GRID_OPACITY: f64 = 0.5;
+const CONSTELLATION_STROKE: &str = "rgba(74, 158, 255, 0.45)";
+const ASTERISM_STROKE: &str = "rgba(201, 162, 39, 0.6)";
+const HORIZON_STROKE: &str = "rgba(0, 212, 170, 0.7)";
+const CARDINAL_FILL: &str = "#00d4aa";
+const CARDINAL_FONT: &str = "bold 12px 'Space Mono', 'Courier New', monospace";
+const LABEL_FILL: &str = "#a8a6a3";
+const LABEL_FONT: &str = "10px 'Space Mono', 'Courier New', monospace";
+const SELECTION_STROKE: &str = "#ff4444";
+const DSO_STROKE: &str = "rgba(255, 190, 120, 0.8)";
+
+/// Opacity of stars below the local horizon
+const BELOW_HORIZON_ALPHA: f64 = 0.2;
+
+/// Everything one paint of the canvas needs
+struct Scene {
+    viewport: Viewport,
+    draw_list: Rc<DrawList>,
+    figures: Option<Rc<Vec<FigureSegment>>>,
+    asterisms: Option<Rc<Vec<FigureSegment>>>,
+    deep_sky: Rc<Vec<DsoGlyph>>,
+    show_grid: bool,
+    selected_star: Option<StarId>,
+    compared_star: Option<StarId>,
+    local_sky: Option<LocalSky>,
+    /// Whether each star of `draw_list` is below the local horizon
+    below_horizon: Vec<bool>,
+}
+
+/// The star map, painted onto a canvas
+#[function_component(StarMapCanvas)]
+pub fn star_map_canvas(props: &StarMapProps) -> Html {
+    let canvas_ref = use_map_size(props.on_action.clone());
+    let draw_list = use_draw_list(props);
+    let figures = use_figures(&props.catalog);
+    let asterisms = use_asterisms(&props.catalog);
+    let deep_sky = use_deep_sky(props);
+    let handlers = use_pan_zoom(props.on_action.clone(), props.viewport);
+    let on_mouse_move = use_practice_hover(props, draw_list.clone(), handlers.on_mouse_move);
+
+    // The component only re-renders when its props change, so every
+    // render repaints
+    {
+        let canvas_ref = canvas_ref.clone();
+        let scene = Scene {
+            viewport: props.viewport,
+            draw_list: draw_list.clone(),
+            figures: props.show_constellations.then_some(figures),
+            asterisms: props.show_asterisms.then_some(asterisms),
+            deep_sky: deep_sky.clone(),
+            show_grid: props.show_grid,
+            selected_star: props.selected_star,
+            compared_star: props.compared_star,
+            local_sky: props.local_sky,
+            below_horizon: draw_list
+                .stars
+                .iter()
+                .map(|star| is_below_horizon(&props.catalog, props.local_sky, star.id))
+                .collect(),
+        };
+        use_effect(move || {
+            let started = now_ms();
+            if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                if let Err(e) = paint(&canvas, &scene) {
+                    log::warn!("Could not paint the star map: {:?}", e);
+                }
+            }
+            perf::record_render(scene.draw_list.stars.len(), now_ms() - started);
+        });
+    }
+
+    // A click on a glyph or star acts as on the SVG map; anywhere else
+    // dismisses the quiz
+    let on_click = {
+        let viewport = props.viewport;
+        let on_action = props.on_action.clone();
+        let unnamed_clickable = props.unnamed_clickable;
+        let catalog = props.catalog.clone();
+        let local_sky = props.local_sky;
+        Callback::from(move |e: MouseEvent| {
+            let point = viewport_point(&e, &viewport);
+            if let Some(glyph) = point.and_then(|(x, y)| glyph_at(&deep_sky, x, y)) {
+                click_deep_sky(&on_action, glyph);
+                return;
+            }
+            let hit = point.and_then(|(x, y)| draw_list.star_at(x, y));
+            match hit {
+                Some(star) => {
+                    let below = is_below_horizon(&catalog, local_sky, star.id);
+                    click_star(&on_action, star, unnamed_clickable, below);
+                }
+                None => on_action.emit(GameAction::CloseQuiz),
+            }
+        })
+    };
+
+    html! {
+        <canvas
+            ref={canvas_ref}
+            class="star-map"
+            onclick={on_click}
+            onmousedown={handlers.on_mouse_down}
+            onmousemove={on_mouse_move}
+            onmouseup={handlers.on_mouse_up}
+            onmouseleave={handlers.on_mouse_leave}
+            onwheel={handlers.on_wheel}
+            ontouchstart={handlers.on_touch_start}
+            ontouchmove={handlers.on_touch_move}
+            ontouchend={handlers.on_touch_end.clone()}
+            ontouchcancel={handlers.on_touch_end}
+        />
+    }
+}
+
+/// Paint `scene` onto `canvas`, at the display's pixel density
+fn paint(canvas: &HtmlCanvasElement, scene: &Scene) -> Result<(), JsValue> {
+    let viewport = &scene.viewport;
+    let ratio = web_sys::window().map_or(1.0, |w| w.device_pixel_ratio().max(1.0));
+    let width = (viewport.width * ratio).round() as u32;
+    let height = (viewport.height * ratio).round() as u32;
+    if canvas.width() != width || canvas.height() != height {
+        canvas.set_width(width);
+        canvas.set_height(height);
+    }
+
+    let context: CanvasRenderingContext2d = canvas
+        .get_context("2d")?
+        .ok_or_else(|| JsValue::from_str("2d context unavailable"))?
+        .dyn_into()?;
+    context.set_transform(ratio, 0.0, 0.0, ratio, 0.0, 0.0)?;
+
+    context.set_fill_style_str(BACKGROUND_FILL);
+    context.fill_rect(0.0, 0.0, viewport.width, viewport.height);
+
+    if scene.show_grid {
+        context.set_global_alpha(GRID_OPACITY);
+        for line in grid_lines(viewport) {
+            context.set_stroke_style_str(line.stroke);
+            context.set_line_width(line.width.parse().unwrap_or(1.0));
+            stroke_line(&context, line.x1, line.y1, line.x2, line.y2);
+        }
+        context.set_global_alpha(1.0);
+    }
+
+    if let Some(figures) = &scene.figures {
+        context.set_stroke_style_str(CONSTELLATION_STROKE);
+        context.set_line_width(1.0);
+        for line in figure_lines(viewport, figures) {
+            stroke_line(&context, line.x1, line.y1, line.x2, line.y2);
+        }
+    }
+
+    if let Some(asterisms) = &scene.asterisms {
+        context.set_stroke_style_str(ASTERISM_STROKE);
+        context.set_line_width(1.5);
+        for line in figure_lines(viewport, asterisms) {
+            stroke_line(&context, line.x1, line.y1, line.x2, line.y2);
+        }
+    }
+
+    if let Some(sky) = &scene.local_sky {
+        paint_horizon(&context, viewport, sky);
+    }
+
+    paint_deep_sky(&context, &scene.deep_sky)?;
+
+    for (star, &below) in scene.draw_list.stars.iter().zip(&scene.below_horizon) {
+        context.set_global_alpha(if below { BELOW_HORIZON_ALPHA } else { 1.0 });
+        if scene.selected_star == Some(star.id) || scene.compared_star == Some(star.id) {
+            context.set_stroke_style_str(SELECTION_STROKE);
+            context.set_line_width(2.0);
+            context.begin_path();
+            context.arc(star.x, star.y, star.radius * 3.0, 0.0, TAU)?;
+            context.stroke();
+        }
+        context.set_fill_style_str(star.fill);
+        context.begin_path();
+        context.arc(star.x, star.y, star.radius, 0.0, TAU)?;
+        context.fill();
+    }
+    context.set_global_alpha(1.0);
+
+    context.set_fill_style_str(LABEL_FILL);
+    context.set_font(LABEL_FONT);
+    context.set_text_align("start");
+    for label in &scene.draw_list.labels {
+        context.fill_text(&label.text, label.x, label.y)?;
+    }
+    Ok(())
+}
+
+/// Paint the horizon line and compass points of the local sky
+fn paint_horizon(context: &CanvasRenderingContext2d, viewport: &Viewport, sky: &LocalSky) {
+    let overlay = horizon_overlay(viewport, sky);
+    context.set_stroke_style_str(HORIZON_STROKE);
+    context.set_line_width(1.5);
+    for segment in &overlay.segments {
+        context.begin_path();
+        for (i, point) in segment.iter().enumerate() {
+            if i == 0 {
+                context.move_to(point.x, point.y);
+            } else {
+                context.line_to(point.x, point.y);
+            }
+        }
+        context.stroke();
+    }
+
+    context.set_fill_style_str(CARDINAL_FILL);
+    context.set_font(CARDINAL_FONT);
+    context.set_text_align("center");
+    for (cardinal, point) in &overlay.cardinals {
+        let label = t(TextKey::for_cardinal(*cardinal));
+        let _ = context.fill_text(label, point.x, point.y - 6.0);
+    }
+}
+
+/// Paint the Messier objects' glyphs, from the same paths as the SVG map
+fn paint_deep_sky(context: &CanvasRenderingContext2d, glyphs: &[DsoGlyph]) -> Result<(), JsValue> {
+    context.set_stroke_style_str(DSO_STROKE);
+    context.set_line_width(1.2);
+    for glyph in glyphs {
+        context.set_global_alpha(if glyph.below_horizon {
+            BELOW_HORIZON_ALPHA
+        } else {
+            1.0
+        });
+        context.stroke_with_path(&Path2d::new_with_path_string(&glyph_path(glyph))?);
+    }
+    context.set_global_alpha(1.0);
+    Ok(())
+}
+
+/// Stroke a single straight line
+fn stroke_line(context: &CanvasRenderingContext2d, x1: f64, y1: f64, x2: f64, y2: f64) {
+    context.begin_path();
+    context.move_to(x1, y1);
+    context.line_to(x2, y2);
+    context.stroke();
+}
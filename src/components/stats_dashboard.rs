@@ -0,0 +1,310 @@
+//! Statistics Dashboard Component
+//!
+//! Charts for the session summary: accuracy over the session as a
+//! sparkline, and the most missed stars and accuracy per constellation as
+//! bars. The session's guesses feed the sparkline; the bars come from the
+//! per-star statistics kept across sessions. The charts are plain SVG.
+
+use crate::app::i18n::{t, tf, use_locale, TextKey};
+use crate::data::StarCatalog;
+use crate::game::{GuessSummary, StarStats};
+use std::collections::HashMap;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// Answers each point of the accuracy sparkline averages over
+pub const ACCURACY_WINDOW: usize = 5;
+
+/// Stars listed under most missed
+pub const MOST_MISSED_ROWS: usize = 5;
+
+/// Constellations listed under accuracy by constellation
+pub const CONSTELLATION_ROWS: usize = 8;
+
+/// Size of the sparkline's drawing area, in SVG units
+const SPARKLINE_SIZE: (f64, f64) = (240.0, 48.0);
+
+/// A star that was answered wrong at least once
+#[derive(Debug, Clone, PartialEq)]
+pub struct MissedStar {
+    pub name: String,
+    pub misses: u32,
+    pub attempts: u32,
+}
+
+/// Answers about the stars of one constellation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstellationAccuracy {
+    /// The constellation's abbreviation, as in the catalog
+    pub constellation: String,
+    pub correct: u32,
+    pub attempts: u32,
+}
+
+impl ConstellationAccuracy {
+    /// Share of answers that were right, from 0 to 1
+    pub fn accuracy(&self) -> f64 {
+        if self.attempts == 0 {
+            0.0
+        } else {
+            f64::from(self.correct) / f64::from(self.attempts)
+        }
+    }
+}
+
+/// Percentage of right answers over the last `window` guesses, after
+/// each guess in turn
+pub fn rolling_accuracy(guesses: &[GuessSummary], window: usize) -> Vec<f64> {
+    let window = window.max(1);
+    (0..guesses.len())
+        .map(|i| {
+            let recent = &guesses[(i + 1).saturating_sub(window)..=i];
+            let correct = recent.iter().filter(|guess| guess.was_correct).count();
+            100.0 * correct as f64 / recent.len() as f64
+        })
+        .collect()
+}
+
+/// The `points` of an SVG polyline drawing `values`, from 0 to `max`,
+/// across a `width` by `height` area with 0 at the bottom
+pub fn sparkline_points(values: &[f64], max: f64, width: f64, height: f64) -> String {
+    let step = if values.len() > 1 {
+        width / (values.len() - 1) as f64
+    } else {
+        0.0
+    };
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let y = height - (value / max).clamp(0.0, 1.0) * height;
+            format!("{:.1},{:.1}", i as f64 * step, y)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Up to `count` stars with the most wrong answers, the shakiest first
+/// among equals
+pub fn most_missed(stats: &StarStats, catalog: &StarCatalog, count: usize) -> Vec<MissedStar> {
+    let mut missed: Vec<(MissedStar, f64)> = stats
+        .iter()
+        .filter(|(_, record)| record.correct < record.attempts)
+        .filter_map(|(id, record)| {
+            let star = catalog.get(id)?;
+            let missed = MissedStar {
+                name: star.display_name(),
+                misses: record.attempts - record.correct,
+                attempts: record.attempts,
+            };
+            Some((missed, record.accuracy()))
+        })
+        .collect();
+    missed.sort_by(|(a, a_accuracy), (b, b_accuracy)| {
+        b.misses
+            .cmp(&a.misses)
+            .then(a_accuracy.total_cmp(b_accuracy))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    missed.truncate(count);
+    missed.into_iter().map(|(star, _)| star).collect()
+}
+
+/// Answers grouped by the constellation of each star, the most asked
+/// constellations first
+pub fn constellation_accuracy(
+    stats: &StarStats,
+    catalog: &StarCatalog,
+) -> Vec<ConstellationAccuracy> {
+    let mut totals: HashMap<&str, (u32, u32)> = HashMap::new();
+    for (id, record) in stats.iter() {
+        let Some(constellation) = catalog
+            .get(id)
+            .and_then(|star| star.constellation.as_deref())
+        else {
+            continue;
+        };
+        let total = totals.entry(constellation).or_default();
+        total.0 += record.correct;
+        total.1 += record.attempts;
+    }
+    let mut rows: Vec<_> = totals
+        .into_iter()
+        .map(
+            |(constellation, (correct, attempts))| ConstellationAccuracy {
+                constellation: constellation.to_string(),
+                correct,
+                attempts,
+            },
+        )
+        .collect();
+    rows.sort_by(|a, b| {
+        b.attempts
+            .cmp(&a.attempts)
+            .then_with(|| a.constellation.cmp(&b.constellation))
+    });
+    rows
+}
+
+/// One labelled horizontal bar, filled to `fraction`
+fn bar_row(label: &str, fraction: f64, value: String) -> Html {
+    let fill = format!("{:.1}", fraction.clamp(0.0, 1.0) * 100.0);
+    html! {
+        <div class="stats-bar-row">
+            <span class="stats-bar-label">{ label }</span>
+            <svg class="stats-bar" viewBox="0 0 100 8" preserveAspectRatio="none" aria-hidden="true">
+                <rect class="stats-bar-track" width="100" height="8" />
+                <rect class="stats-bar-fill" width={fill} height="8" />
+            </svg>
+            <span class="stats-bar-value">{ value }</span>
+        </div>
+    }
+}
+
+/// Props for StatsDashboard component
+#[derive(Properties, PartialEq)]
+pub struct StatsDashboardProps {
+    /// This session's guesses, oldest first
+    pub guesses: Vec<GuessSummary>,
+
+    /// Answers per star, across sessions
+    pub stats: Rc<StarStats>,
+
+    /// The star catalog (for names and constellations)
+    pub catalog: Rc<StarCatalog>,
+}
+
+/// The statistics dashboard component
+#[function_component(StatsDashboard)]
+pub fn stats_dashboard(props: &StatsDashboardProps) -> Html {
+    use_locale();
+    let trend = rolling_accuracy(&props.guesses, ACCURACY_WINDOW);
+    let missed = most_missed(&props.stats, &props.catalog, MOST_MISSED_ROWS);
+    let mut constellations = constellation_accuracy(&props.stats, &props.catalog);
+    constellations.truncate(CONSTELLATION_ROWS);
+
+    if trend.len() < 2 && missed.is_empty() && constellations.is_empty() {
+        return html! {
+            <div class="stats-dashboard">
+                <p class="stats-empty">{ t(TextKey::NoStatsYet) }</p>
+            </div>
+        };
+    }
+
+    let trend_section = (trend.len() >= 2).then(|| {
+        let (width, height) = SPARKLINE_SIZE;
+        let title = tf(TextKey::AccuracyTrend, &[&ACCURACY_WINDOW]);
+        html! {
+            <section class="stats-section stats-trend">
+                <h3>{ &title }</h3>
+                <svg
+                    class="sparkline"
+                    viewBox={format!("0 0 {} {}", width, height)}
+                    preserveAspectRatio="none"
+                    role="img"
+                    aria-label={title}
+                >
+                    <polyline points={sparkline_points(&trend, 100.0, width, height)} />
+                </svg>
+            </section>
+        }
+    });
+
+    let missed_section = (!missed.is_empty()).then(|| {
+        html! {
+            <section class="stats-section stats-missed">
+                <h3>{ t(TextKey::MostMissed) }</h3>
+                { for missed.iter().map(|star| bar_row(
+                    &star.name,
+                    f64::from(star.misses) / f64::from(star.attempts),
+                    tf(TextKey::MissedCount, &[&star.misses, &star.attempts]),
+                )) }
+            </section>
+        }
+    });
+
+    let constellation_section = (!constellations.is_empty()).then(|| {
+        html! {
+            <section class="stats-section stats-constellations">
+                <h3>{ t(TextKey::ConstellationAccuracy) }</h3>
+                { for constellations.iter().map(|row| bar_row(
+                    &row.constellation,
+                    row.accuracy(),
+                    format!("{:.0}%", row.accuracy() * 100.0),
+                )) }
+            </section>
+        }
+    });
+
+    html! {
+        <div class="stats-dashboard">
+            { trend_section }
+            { missed_section }
+            { constellation_section }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{catalog_of, StarId};
+
+    fn guesses(results: &[bool]) -> Vec<GuessSummary> {
+        results
+            .iter()
+            .map(|&was_correct| GuessSummary {
+                star_name: "Vega".into(),
+                user_answer: "Vega".into(),
+                was_correct,
+                timestamp: 0.0,
+            })
+            .collect()
+    }
+
+    fn catalog() -> StarCatalog {
+        catalog_of(&[("Rigel", "Ori"), ("Betelgeuse", "Ori"), ("Vega", "Lyr")])
+    }
+
+    #[test]
+    fn test_rolling_accuracy_averages_recent_guesses() {
+        let trend = rolling_accuracy(&guesses(&[true, false, false, true]), 2);
+        assert_eq!(trend, vec![100.0, 50.0, 0.0, 50.0]);
+        assert!(rolling_accuracy(&[], 5).is_empty());
+    }
+
+    #[test]
+    fn test_sparkline_points_span_the_area() {
+        assert_eq!(
+            sparkline_points(&[0.0, 50.0, 100.0], 100.0, 200.0, 40.0),
+            "0.0,40.0 100.0,20.0 200.0,0.0"
+        );
+        assert_eq!(sparkline_points(&[150.0], 100.0, 200.0, 40.0), "0.0,0.0");
+    }
+
+    #[test]
+    fn test_most_missed_and_constellation_accuracy() {
+        let catalog = catalog();
+        let mut stats = StarStats::default();
+        stats.record(StarId(1), false, 0.0);
+        stats.record(StarId(1), true, 0.0);
+        stats.record(StarId(2), false, 0.0);
+        stats.record(StarId(3), true, 0.0);
+        stats.record(StarId(3), true, 0.0);
+        stats.record(StarId(3), true, 0.0);
+        // A star from another catalog is left out
+        stats.record(StarId(99), false, 0.0);
+
+        let missed = most_missed(&stats, &catalog, 5);
+        let names: Vec<_> = missed.iter().map(|star| star.name.as_str()).collect();
+        assert_eq!(names, ["Betelgeuse", "Rigel"]);
+        assert_eq!((missed[1].misses, missed[1].attempts), (1, 2));
+        assert_eq!(most_missed(&stats, &catalog, 1).len(), 1);
+
+        let rows = constellation_accuracy(&stats, &catalog);
+        assert_eq!(rows[0].constellation, "Lyr");
+        assert_eq!(rows[0].accuracy(), 1.0);
+        assert_eq!(rows[1].constellation, "Ori");
+        assert_eq!((rows[1].correct, rows[1].attempts), (1, 3));
+    }
+}
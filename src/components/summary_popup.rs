@@ -1,7 +1,14 @@
 //! Summary Popup Component
 //!
-//! Displays a summary of guesses when user clicks "Done".
+//! Displays a summary of guesses when user clicks "Done", with any
+//! charts passed as children below the totals, and copies the results as
+//! text to share. After any mistake it offers to review the stars missed.
 
+use crate::app::crash::copy_to_clipboard;
+use crate::app::i18n::{t, use_locale, TextKey};
+use crate::components::focus::use_dialog_focus;
+use crate::components::quiz_dropdown::choice_text;
+use crate::game::share::session_text;
 use crate::game::{GameAction, GuessSummary, ScoreState};
 use yew::prelude::*;
 
@@ -16,11 +23,16 @@ pub struct SummaryPopupProps {
 
     /// Callback for dispatching game actions
     pub on_action: Callback<GameAction>,
+
+    /// Shown between the totals and the guess history
+    #[prop_or_default]
+    pub children: Html,
 }
 
 /// The summary popup component
 #[function_component(SummaryPopup)]
 pub fn summary_popup(props: &SummaryPopupProps) -> Html {
+    use_locale();
     let total = props.guesses.len();
     let correct = props.score.correct;
     let incorrect = props.score.incorrect;
@@ -28,10 +40,22 @@ pub fn summary_popup(props: &SummaryPopupProps) -> Html {
     let streak = props.score.streak;
     let best_streak = props.score.best_streak;
 
+    let dialog = use_dialog_focus();
+    let copied = use_state(|| false);
+
+    let on_copy = {
+        let text = session_text(&props.guesses, &props.score);
+        let copied = copied.clone();
+        Callback::from(move |_| {
+            copy_to_clipboard(&text);
+            copied.set(true);
+        })
+    };
+
     let guess_rows: Html = if total == 0 {
         html! {
             <div class="summary-empty">
-                <p>{ "No guesses yet! Start quizzing some stars." }</p>
+                <p>{ t(TextKey::NoGuesses) }</p>
             </div>
         }
     } else {
@@ -45,7 +69,7 @@ pub fn summary_popup(props: &SummaryPopupProps) -> Html {
                         <div key={i} class={classes!("summary-row", result_class)}>
                             <span class="summary-icon">{ result_icon }</span>
                             <span class="summary-star">{ &guess.star_name }</span>
-                            <span class="summary-answer">{ &guess.user_answer }</span>
+                            <span class="summary-answer">{ choice_text(&guess.user_answer) }</span>
                         </div>
                     }
                 }).collect::<Html>() }
@@ -53,57 +77,80 @@ pub fn summary_popup(props: &SummaryPopupProps) -> Html {
         }
     };
 
-    html! {
-        <div class="summary-overlay">
-            <div class="summary-popup">
+    dialog.overlay(
+        "",
+        "summary-title",
+        html! {
+            <>
                 <div class="summary-header">
-                    <h2>{ "Session Summary" }</h2>
-                    <button onclick={props.on_action.reform(|_| GameAction::HideSummary)} class="close-button">
+                    <h2 id="summary-title">{ t(TextKey::SessionSummary) }</h2>
+                    <button
+                        ref={dialog.close_ref.clone()}
+                        onclick={props.on_action.reform(|_| GameAction::HideSummary)}
+                        class="close-button"
+                        aria-label={t(TextKey::CloseSummary)}
+                    >
                         { "×" }
                     </button>
                 </div>
 
                 <div class="summary-stats">
                     <div class="stat-item">
-                        <span class="stat-label">{ "Total Questions:" }</span>
+                        <span class="stat-label">{ t(TextKey::TotalQuestions) }</span>
                         <span class="stat-value">{ total }</span>
                     </div>
                     <div class="stat-item">
-                        <span class="stat-label">{ "Correct:" }</span>
+                        <span class="stat-label">{ t(TextKey::CorrectCount) }</span>
                         <span class="stat-value correct">{ correct }</span>
                     </div>
                     <div class="stat-item">
-                        <span class="stat-label">{ "Incorrect:" }</span>
+                        <span class="stat-label">{ t(TextKey::IncorrectCount) }</span>
                         <span class="stat-value incorrect">{ incorrect }</span>
                     </div>
                     <div class="stat-item">
-                        <span class="stat-label">{ "Accuracy:" }</span>
+                        <span class="stat-label">{ t(TextKey::AccuracyPercent) }</span>
                         <span class="stat-value">{ format!("{:.1}%", accuracy) }</span>
                     </div>
                     <div class="stat-item">
-                        <span class="stat-label">{ "Streak:" }</span>
+                        <span class="stat-label">{ t(TextKey::CurrentStreak) }</span>
                         <span class="stat-value">{ streak }</span>
                     </div>
                     <div class="stat-item">
-                        <span class="stat-label">{ "Best Streak:" }</span>
+                        <span class="stat-label">{ t(TextKey::BestStreak) }</span>
                         <span class="stat-value">{ best_streak }</span>
                     </div>
                 </div>
 
+                { props.children.clone() }
+
                 <div class="summary-guesses">
-                    <h3>{ "Guess History" }</h3>
+                    <h3>{ t(TextKey::GuessHistory) }</h3>
                     { guess_rows }
                 </div>
 
                 <div class="summary-actions">
+                    if props.guesses.iter().any(|guess| !guess.was_correct) {
+                        <button
+                            class="review-button"
+                            title={t(TextKey::ReviewMistakesTitle)}
+                            onclick={props.on_action.reform(|_| GameAction::ReviewMistakes)}
+                        >
+                            { t(TextKey::ReviewMistakes) }
+                        </button>
+                    }
+                    if total > 0 {
+                        <button class="copy-button" onclick={on_copy}>
+                            { t(if *copied { TextKey::Copied } else { TextKey::CopyResults }) }
+                        </button>
+                    }
                     <button class="reset-button" onclick={props.on_action.reform(|_| GameAction::ResetScore)}>
-                        { "Reset & Start Over" }
+                        { t(TextKey::ResetAndStartOver) }
                     </button>
                     <button class="close-btn" onclick={props.on_action.reform(|_| GameAction::HideSummary)}>
-                        { "Close" }
+                        { t(TextKey::Close) }
                     </button>
                 </div>
-            </div>
-        </div>
-    }
+            </>
+        },
+    )
 }
@@ -0,0 +1,168 @@
+//! Time Control Component
+//!
+//! Picks the moment the local sky is shown for: a date and time, a slider
+//! across that day, and a time-lapse that runs the clock forward. Each
+//! change is dispatched as [`GameAction::SetObservationTime`]; "Now" goes
+//! back to following the clock.
+
+use crate::app::i18n::{t, use_locale, TextKey};
+use crate::game::GameAction;
+use crate::utils::dates::{format_datetime, parse_datetime, unix_millis_now, MINUTES_PER_DAY};
+use gloo::timers::callback::Timeout;
+use wasm_bindgen::JsValue;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+/// How far the time-lapse moves the clock each step, in seconds
+pub const TIME_LAPSE_STEP: f64 = 600.0;
+
+/// How often the time-lapse steps
+const TIME_LAPSE_TICK_MS: u32 = 200;
+
+/// Minutes between the stops of the time-of-day slider
+const SLIDER_STEP_MINUTES: i64 = 5;
+
+/// Minutes east of UTC of the browser's time zone at a Unix time in
+/// seconds
+fn local_offset_minutes(unix_seconds: f64) -> i64 {
+    let date = js_sys::Date::new(&JsValue::from_f64(unix_seconds * 1000.0));
+    -(date.get_timezone_offset() as i64)
+}
+
+/// Minute of the local day, `offset_minutes` east of UTC, of a Unix time
+/// in seconds
+pub fn minute_of_day(unix_seconds: f64, offset_minutes: i64) -> i64 {
+    ((unix_seconds / 60.0).floor() as i64 + offset_minutes).rem_euclid(MINUTES_PER_DAY)
+}
+
+/// A Unix time in seconds moved to `minute` of the same local day
+pub fn at_minute_of_day(unix_seconds: f64, offset_minutes: i64, minute: i64) -> f64 {
+    unix_seconds + ((minute - minute_of_day(unix_seconds, offset_minutes)) * 60) as f64
+}
+
+/// Props for TimeControl component
+#[derive(Properties, PartialEq)]
+pub struct TimeControlProps {
+    /// The chosen moment in Unix seconds, or `None` for now
+    pub time: Option<f64>,
+
+    /// Callback for dispatching game actions
+    pub on_action: Callback<GameAction>,
+}
+
+/// The time control component
+#[function_component(TimeControl)]
+pub fn time_control(props: &TimeControlProps) -> Html {
+    use_locale();
+    let playing = use_state(|| false);
+    let shown = props.time.unwrap_or_else(|| unix_millis_now() / 1000.0);
+    let offset = local_offset_minutes(shown);
+
+    // While playing, each step schedules the next
+    {
+        let on_action = props.on_action.clone();
+        use_effect_with((*playing, props.time), move |&(playing, time)| {
+            let step = playing.then(|| {
+                Timeout::new(TIME_LAPSE_TICK_MS, move || {
+                    let from = time.unwrap_or_else(|| unix_millis_now() / 1000.0);
+                    on_action.emit(GameAction::SetObservationTime(Some(from + TIME_LAPSE_STEP)));
+                })
+            });
+            move || drop(step)
+        });
+    }
+
+    let on_pick = {
+        let on_action = props.on_action.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            // Read with the offset in force at the picked moment, which
+            // may differ from now's across a daylight saving change
+            let picked = parse_datetime(&input.value(), offset)
+                .and_then(|guess| parse_datetime(&input.value(), local_offset_minutes(guess)));
+            if let Ok(time) = picked {
+                on_action.emit(GameAction::SetObservationTime(Some(time)));
+            }
+        })
+    };
+
+    let on_slide = {
+        let on_action = props.on_action.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(minute) = input.value().parse() {
+                let time = at_minute_of_day(shown, offset, minute);
+                on_action.emit(GameAction::SetObservationTime(Some(time)));
+            }
+        })
+    };
+
+    let on_play = {
+        let playing = playing.clone();
+        Callback::from(move |_| playing.set(!*playing))
+    };
+
+    let on_now = {
+        let on_action = props.on_action.clone();
+        let playing = playing.clone();
+        Callback::from(move |_| {
+            playing.set(false);
+            on_action.emit(GameAction::SetObservationTime(None));
+        })
+    };
+
+    html! {
+        <div class="time-control">
+            <label class="time-picker">
+                { t(TextKey::ObservationTime) }
+                <input
+                    type="datetime-local"
+                    value={format_datetime(shown, offset)}
+                    onchange={on_pick}
+                />
+            </label>
+            <input
+                type="range"
+                class="magnitude-slider time-slider"
+                min="0"
+                max={(MINUTES_PER_DAY - 1).to_string()}
+                step={SLIDER_STEP_MINUTES.to_string()}
+                value={minute_of_day(shown, offset).to_string()}
+                aria-label={t(TextKey::TimeOfDay)}
+                oninput={on_slide}
+            />
+            <div class="toggle-buttons">
+                <button
+                    class={classes!("toggle-btn", "play-button", playing.then_some("active"))}
+                    title={t(TextKey::TimeLapseTitle)}
+                    onclick={on_play}
+                >
+                    { t(if *playing { TextKey::Pause } else { TextKey::Play }) }
+                </button>
+                <button
+                    class={classes!("toggle-btn", "now-button", props.time.is_none().then_some("active"))}
+                    title={t(TextKey::NowTitle)}
+                    onclick={on_now}
+                >
+                    { t(TextKey::Now) }
+                </button>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minute_of_day() {
+        // 1970-01-02 01:30 UTC
+        let time = 91_800.0;
+        assert_eq!(minute_of_day(time, 0), 90);
+        assert_eq!(minute_of_day(time, -120), 1410);
+        assert_eq!(at_minute_of_day(time, 0, 600), 86_400.0 + 36_000.0);
+        // Moving within the local day keeps the local date
+        assert_eq!(at_minute_of_day(time, -120, 0), 91_800.0 - 1410.0 * 60.0);
+    }
+}
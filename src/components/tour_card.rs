@@ -0,0 +1,128 @@
+//! Tour Card Component
+//!
+//! The current stop of a guided tour: its star, what is said about it
+//! and buttons to move along. Each stop mounts a fresh card, which
+//! selects the stop's star and glides the map to it.
+
+use super::star_info::star_facts;
+use crate::app::i18n::{t, tf, use_locale, TextKey};
+use crate::data::star_names::Lang;
+use crate::data::{CelestialCoord, Star};
+use crate::game::tour::glide_step;
+use crate::game::{GameAction, TourRun};
+use crate::utils::sky_svg::tooltip_name;
+use gloo::render::request_animation_frame;
+use yew::prelude::*;
+
+/// Animation frames in the glide from one stop to the next, about a
+/// second at the usual 60 a second
+const GLIDE_FRAMES: u32 = 60;
+
+/// Props for TourCard component
+#[derive(Properties, PartialEq)]
+pub struct TourCardProps {
+    /// The tour and the stop it is at
+    pub run: TourRun,
+
+    /// The stop's star, if the catalog has it
+    pub star: Option<Star>,
+
+    /// Where the map is centered
+    pub center: CelestialCoord,
+
+    /// Tradition the star's name is given in
+    #[prop_or_default]
+    pub name_lang: Lang,
+
+    /// Callback for dispatching game actions
+    pub on_action: Callback<GameAction>,
+}
+
+/// The tour card component
+#[function_component(TourCard)]
+pub fn tour_card(props: &TourCardProps) -> Html {
+    use_locale();
+    let frame = use_state(|| 0u32);
+
+    {
+        let on_action = props.on_action.clone();
+        let star = props.star.as_ref().map(|star| star.id);
+        use_effect_with((), move |_| {
+            if let Some(id) = star {
+                on_action.emit(GameAction::SelectStar(id));
+            }
+        });
+    }
+
+    // Each animation frame moves the map a step and asks for the next,
+    // from wherever the last one left it; unmounting cancels the glide
+    {
+        let on_action = props.on_action.clone();
+        let target = props.star.as_ref().map(|star| star.coord);
+        let center = props.center;
+        let frame = frame.clone();
+        use_effect_with(*frame, move |&step| {
+            let animation = target.filter(|_| step < GLIDE_FRAMES).map(|target| {
+                request_animation_frame(move |_| {
+                    let at = glide_step(&center, &target, step, GLIDE_FRAMES);
+                    on_action.emit(GameAction::SetCenter(at.ra, at.dec));
+                    frame.set(step + 1);
+                })
+            });
+            move || drop(animation)
+        });
+    }
+
+    let run = props.run;
+    let stop = run.current();
+    let tour_name = t(TextKey::for_tour(run.tour().id));
+    let note = TextKey::tour_note(run.tour().id, stop.star).map(t);
+    let title = match &props.star {
+        Some(star) => tooltip_name(star, props.name_lang),
+        None => stop.star.to_string(),
+    };
+    let facts = props
+        .star
+        .iter()
+        .flat_map(star_facts)
+        .map(|fact| html! { <p class="tour-fact">{ fact }</p> });
+
+    html! {
+        <div class="tour-card" role="region" aria-label={tour_name}>
+            <div class="quiz-header">
+                <span class="tour-name">{ tour_name }</span>
+                <span class="tour-progress">
+                    { tf(TextKey::TourProgress, &[&(run.stop + 1), &run.tour().stops.len()]) }
+                </span>
+                <button
+                    class="close-button"
+                    onclick={props.on_action.reform(|_| GameAction::EndRun)}
+                    aria-label={t(TextKey::EndTour)}
+                >
+                    { "×" }
+                </button>
+            </div>
+            <h3 class="tour-star">{ title }</h3>
+            { for facts }
+            if let Some(note) = note {
+                <p class="tour-note">{ note }</p>
+            }
+            <div class="tour-nav">
+                <button
+                    class="tour-previous"
+                    disabled={run.is_first()}
+                    onclick={props.on_action.reform(|_| GameAction::PreviousTourStop)}
+                >
+                    { t(TextKey::PreviousStop) }
+                </button>
+                <button
+                    class="tour-next"
+                    disabled={run.is_last()}
+                    onclick={props.on_action.reform(|_| GameAction::NextTourStop)}
+                >
+                    { t(TextKey::NextStop) }
+                </button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,102 @@
+//! Trophy Panel Component
+//!
+//! Lists every achievement with what it asks for, marking those unlocked
+//! and the date each was earned.
+
+use crate::app::i18n::{t, tf, use_locale, TextKey};
+use crate::components::focus::use_dialog_focus;
+use crate::game::achievements::{QUESTIONS_GOAL, STREAK_GOAL};
+use crate::game::{Achievement, Achievements, GameAction};
+use crate::utils::dates::{day_from_unix_millis, format_date};
+use yew::prelude::*;
+
+/// What `achievement` asks for, in the interface language
+pub fn goal_text(achievement: Achievement) -> String {
+    let key = TextKey::achievement_goal(achievement);
+    match achievement {
+        Achievement::Streak => tf(key, &[&STREAK_GOAL]),
+        Achievement::HundredQuestions => tf(key, &[&QUESTIONS_GOAL]),
+        Achievement::FirstCorrect | Achievement::OrionMastered => t(key).to_string(),
+    }
+}
+
+/// Props for TrophyPanel component
+#[derive(Properties, PartialEq)]
+pub struct TrophyPanelProps {
+    /// The achievements unlocked so far
+    pub achievements: Achievements,
+
+    /// Callback for dispatching game actions
+    pub on_action: Callback<GameAction>,
+}
+
+/// The trophy panel component
+#[function_component(TrophyPanel)]
+pub fn trophy_panel(props: &TrophyPanelProps) -> Html {
+    use_locale();
+    let dialog = use_dialog_focus();
+
+    let trophies = Achievement::ALL.into_iter().map(|achievement| {
+        let unlocked_at = props.achievements.unlocked_at(achievement);
+        let status = match unlocked_at {
+            Some(millis) => tf(
+                TextKey::EarnedOn,
+                &[&format_date(day_from_unix_millis(millis))],
+            ),
+            None => t(TextKey::NotYetEarned).to_string(),
+        };
+        html! {
+            <li class={classes!("trophy", if unlocked_at.is_some() { "unlocked" } else { "locked" })}>
+                <span class="trophy-icon" aria-hidden="true">
+                    { if unlocked_at.is_some() { "🏆" } else { "🔒" } }
+                </span>
+                <div class="trophy-text">
+                    <span class="trophy-name">{ t(TextKey::for_achievement(achievement)) }</span>
+                    <span class="trophy-goal">{ goal_text(achievement) }</span>
+                    <span class="trophy-status">{ status }</span>
+                </div>
+            </li>
+        }
+    });
+
+    dialog.overlay(
+        "trophy-panel",
+        "trophies-title",
+        html! {
+            <>
+                <div class="summary-header">
+                    <h2 id="trophies-title">{ t(TextKey::Trophies) }</h2>
+                    <button
+                        ref={dialog.close_ref.clone()}
+                        onclick={props.on_action.reform(|_| GameAction::ToggleTrophies)}
+                        class="close-button"
+                        aria-label={t(TextKey::CloseTrophies)}
+                    >
+                        { "×" }
+                    </button>
+                </div>
+                <ul class="trophy-list">
+                    { for trophies }
+                </ul>
+            </>
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_goals_name_their_numbers() {
+        assert_eq!(
+            goal_text(Achievement::Streak),
+            "Answer 10 questions in a row right"
+        );
+        assert_eq!(
+            goal_text(Achievement::HundredQuestions),
+            "Answer 100 questions"
+        );
+        assert!(!goal_text(Achievement::OrionMastered).contains("{}"));
+    }
+}
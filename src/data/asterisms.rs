@@ -0,0 +1,210 @@
+//! Asterisms
+//!
+//! Well-known star patterns that are not constellations, such as the Big
+//! Dipper, which is part of Ursa Major, and the Summer Triangle, which
+//! spans three constellations. Each is drawn through its stars' proper
+//! names, so it resolves to star ids in whichever catalog is loaded;
+//! strokes skip stars a catalog does not name.
+
+use super::constellations::FigureSegment;
+use super::{Star, StarCatalog, StarId};
+use std::collections::HashMap;
+
+/// A named pattern of stars
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Asterism {
+    pub name: &'static str,
+    /// Lines drawn through consecutive stars, by proper name
+    pub strokes: &'static [&'static [&'static str]],
+}
+
+impl Asterism {
+    /// Whether the star named `name` is part of this asterism
+    pub fn contains(&self, name: &str) -> bool {
+        self.strokes.iter().any(|stroke| stroke.contains(&name))
+    }
+
+    /// The asterism's stars in `catalog`, each once, in the order they
+    /// are drawn
+    pub fn star_ids(&self, catalog: &StarCatalog) -> Vec<StarId> {
+        let stars = stars_by_name(catalog);
+        let mut ids: Vec<StarId> = Vec::new();
+        for name in self.strokes.iter().flat_map(|stroke| stroke.iter()) {
+            if let Some(star) = stars.get(name) {
+                if !ids.contains(&star.id) {
+                    ids.push(star.id);
+                }
+            }
+        }
+        ids
+    }
+}
+
+/// The asterisms, roughly from most to least familiar
+pub static ASTERISMS: [Asterism; 10] = [
+    Asterism {
+        name: "Big Dipper",
+        strokes: &[&[
+            "Alkaid", "Mizar", "Alioth", "Megrez", "Dubhe", "Merak", "Phecda", "Megrez",
+        ]],
+    },
+    Asterism {
+        name: "Orion's Belt",
+        strokes: &[&["Alnitak", "Alnilam", "Mintaka"]],
+    },
+    Asterism {
+        name: "Summer Triangle",
+        strokes: &[&["Vega", "Deneb", "Altair", "Vega"]],
+    },
+    Asterism {
+        name: "Winter Triangle",
+        strokes: &[&["Betelgeuse", "Procyon", "Sirius", "Betelgeuse"]],
+    },
+    Asterism {
+        name: "Spring Triangle",
+        strokes: &[&["Arcturus", "Spica", "Denebola", "Arcturus"]],
+    },
+    Asterism {
+        name: "Great Square of Pegasus",
+        strokes: &[&["Alpheratz", "Scheat", "Markab", "Algenib", "Alpheratz"]],
+    },
+    Asterism {
+        name: "Winter Hexagon",
+        strokes: &[&[
+            "Sirius",
+            "Rigel",
+            "Aldebaran",
+            "Capella",
+            "Pollux",
+            "Procyon",
+            "Sirius",
+        ]],
+    },
+    Asterism {
+        name: "Northern Cross",
+        strokes: &[
+            &["Deneb", "Sadr", "Albireo"],
+            &["Aljanah", "Sadr", "Fawaris"],
+        ],
+    },
+    Asterism {
+        name: "Teapot",
+        strokes: &[
+            &[
+                "Alnasl",
+                "Kaus Media",
+                "Kaus Borealis",
+                "Nunki",
+                "Ascella",
+                "Kaus Australis",
+                "Alnasl",
+            ],
+            &["Kaus Media", "Kaus Australis"],
+        ],
+    },
+    Asterism {
+        name: "Sickle",
+        strokes: &[&["Regulus", "Algieba", "Adhafera", "Rasalas"]],
+    },
+];
+
+/// The asterism named `name`
+pub fn asterism(name: &str) -> Option<&'static Asterism> {
+    ASTERISMS.iter().find(|asterism| asterism.name == name)
+}
+
+/// The asterisms the star named `name` is part of; a star can be in
+/// several, as Sirius is in both winter ones
+pub fn asterisms_of(name: &str) -> impl Iterator<Item = &'static Asterism> + '_ {
+    ASTERISMS
+        .iter()
+        .filter(move |asterism| asterism.contains(name))
+}
+
+fn stars_by_name(catalog: &StarCatalog) -> HashMap<&str, &Star> {
+    catalog
+        .named_stars()
+        .into_iter()
+        .filter_map(|star| Some((star.name.as_deref()?, star)))
+        .collect()
+}
+
+/// Every asterism's lines between stars of `catalog`
+///
+/// A line is left out when the catalog does not name both its stars.
+/// Segments carry the asterism's name where figure segments carry a
+/// constellation's.
+pub fn asterism_segments(catalog: &StarCatalog) -> Vec<FigureSegment> {
+    let stars = stars_by_name(catalog);
+    ASTERISMS
+        .iter()
+        .flat_map(|asterism| {
+            asterism.strokes.iter().flat_map(|stroke| {
+                stroke.windows(2).filter_map(|pair| {
+                    Some(FigureSegment {
+                        constellation: asterism.name,
+                        from: stars.get(pair[0])?.coord,
+                        to: stars.get(pair[1])?.coord,
+                    })
+                })
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::catalog_of;
+
+    fn catalog() -> StarCatalog {
+        catalog_of(&[
+            ("Vega", "Lyr"),
+            ("Deneb", "Cyg"),
+            ("Altair", "Aql"),
+            ("Sadr", "Cyg"),
+            ("Polaris", "UMi"),
+        ])
+    }
+
+    #[test]
+    fn test_membership() {
+        let sirius: Vec<_> = asterisms_of("Sirius").map(|a| a.name).collect();
+        assert_eq!(sirius, ["Winter Triangle", "Winter Hexagon"]);
+        assert!(asterism("Big Dipper").unwrap().contains("Megrez"));
+        assert_eq!(asterisms_of("Polaris").count(), 0);
+        // Names are unique
+        for (i, a) in ASTERISMS.iter().enumerate() {
+            assert!(ASTERISMS[i + 1..].iter().all(|b| a.name != b.name));
+        }
+    }
+
+    #[test]
+    fn test_resolves_against_catalog() {
+        let catalog = catalog();
+        let triangle = asterism("Summer Triangle").unwrap();
+        assert_eq!(
+            triangle.star_ids(&catalog),
+            [StarId(1), StarId(2), StarId(3)]
+        );
+        // Only Deneb and Sadr of the Northern Cross are in the catalog
+        assert_eq!(
+            asterism("Northern Cross").unwrap().star_ids(&catalog),
+            [StarId(2), StarId(4)]
+        );
+
+        let segments = asterism_segments(&catalog);
+        let names: Vec<_> = segments.iter().map(|s| s.constellation).collect();
+        assert_eq!(
+            names,
+            [
+                "Summer Triangle",
+                "Summer Triangle",
+                "Summer Triangle",
+                "Northern Cross"
+            ]
+        );
+        assert_eq!(segments[3].from, catalog.get(StarId(2)).unwrap().coord);
+        assert!(asterism_segments(&StarCatalog::new()).is_empty());
+    }
+}
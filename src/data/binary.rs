@@ -0,0 +1,55 @@
+//! Writer for the compact binary catalog layout
+//!
+//! Kept free of crate types so the build script can include it to bake a
+//! catalog into the binary; [`super::formats`] decodes the result.
+//!
+//! The layout is the magic bytes, a little-endian `u32` star count, then
+//! per star its `u32` id, `f64` RA, Dec and magnitude, and its name and
+//! constellation as `u16`-length-prefixed UTF-8 ([`NONE`] when absent).
+
+/// Magic bytes at the start of a binary catalog
+pub const MAGIC: &[u8; 4] = b"SGC1";
+
+/// Length marker for an absent optional string
+pub const NONE: u16 = u16::MAX;
+
+/// Start a catalog of `count` stars
+pub fn write_header(out: &mut Vec<u8>, count: usize) -> Result<(), String> {
+    let count = u32::try_from(count).map_err(|_| "too many stars".to_string())?;
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&count.to_le_bytes());
+    Ok(())
+}
+
+/// Append one star
+pub fn write_star(
+    out: &mut Vec<u8>,
+    id: u32,
+    ra: f64,
+    dec: f64,
+    magnitude: f64,
+    name: Option<&str>,
+    constellation: Option<&str>,
+) -> Result<(), String> {
+    out.extend_from_slice(&id.to_le_bytes());
+    out.extend_from_slice(&ra.to_le_bytes());
+    out.extend_from_slice(&dec.to_le_bytes());
+    out.extend_from_slice(&magnitude.to_le_bytes());
+    write_optional_str(out, name)?;
+    write_optional_str(out, constellation)
+}
+
+fn write_optional_str(out: &mut Vec<u8>, value: Option<&str>) -> Result<(), String> {
+    match value {
+        Some(s) => {
+            let len = u16::try_from(s.len())
+                .ok()
+                .filter(|&len| len != NONE)
+                .ok_or_else(|| format!("string too long: {}", s))?;
+            out.extend_from_slice(&len.to_le_bytes());
+            out.extend_from_slice(s.as_bytes());
+        }
+        None => out.extend_from_slice(&NONE.to_le_bytes()),
+    }
+    Ok(())
+}
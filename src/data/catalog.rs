@@ -0,0 +1,300 @@
+//! The star catalog and its placeholder data
+
+use super::star::{BrightnessCategory, CelestialCoord, Star, StarId};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+
+/// Every star loaded, with an index by id
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StarCatalog {
+    stars: Vec<Star>,
+    by_id: HashMap<StarId, usize>,
+}
+
+impl StarCatalog {
+    /// An empty catalog
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a star, indexing it by id
+    ///
+    /// A star with the id of one already added replaces it in the index;
+    /// call [`StarCatalog::rebuild_indices`] once a batch is loaded.
+    pub fn add_star(&mut self, star: Star) {
+        self.by_id.insert(star.id, self.stars.len());
+        self.stars.push(star);
+    }
+
+    /// Rebuild the id index from the stars
+    pub fn rebuild_indices(&mut self) {
+        self.by_id = self
+            .stars
+            .iter()
+            .enumerate()
+            .map(|(i, star)| (star.id, i))
+            .collect();
+    }
+
+    /// The star with `id`, if the catalog has one
+    pub fn get(&self, id: StarId) -> Option<&Star> {
+        self.by_id.get(&id).map(|&i| &self.stars[i])
+    }
+
+    /// Every star, in the order added
+    pub fn all_stars(&self) -> impl Iterator<Item = &Star> {
+        self.stars.iter()
+    }
+
+    /// The stars with a proper name, in the order added
+    pub fn named_stars(&self) -> Vec<&Star> {
+        self.stars.iter().filter(|s| s.has_name()).collect()
+    }
+
+    /// Number of stars
+    pub fn count(&self) -> usize {
+        self.stars.len()
+    }
+
+    /// Number of stars with a proper name
+    pub fn named_count(&self) -> usize {
+        self.stars.iter().filter(|s| s.has_name()).count()
+    }
+
+    /// Stars inside an RA/Dec box no fainter than `max_magnitude`
+    ///
+    /// An RA range with `ra_min > ra_max` wraps through 0h, and equal ends
+    /// cover the whole circle.
+    pub fn stars_in_range(
+        &self,
+        ra_min: f64,
+        ra_max: f64,
+        dec_min: f64,
+        dec_max: f64,
+        max_magnitude: f64,
+    ) -> Vec<&Star> {
+        self.stars
+            .iter()
+            .filter(|star| {
+                let ra = star.coord.ra;
+                let ra_ok = if (ra_max - ra_min).abs() < 1e-9 {
+                    true
+                } else if ra_min <= ra_max {
+                    ra >= ra_min && ra <= ra_max
+                } else {
+                    ra >= ra_min || ra <= ra_max
+                };
+                ra_ok
+                    && star.coord.dec >= dec_min
+                    && star.coord.dec <= dec_max
+                    && star.magnitude <= max_magnitude
+            })
+            .collect()
+    }
+
+    /// Stars brighter than `magnitude`
+    pub fn stars_brighter_than(&self, magnitude: f64) -> Vec<&Star> {
+        self.stars
+            .iter()
+            .filter(|s| s.magnitude < magnitude)
+            .collect()
+    }
+
+    /// Stars brighter than the category's limit
+    pub fn stars_in_category(&self, category: BrightnessCategory) -> Vec<&Star> {
+        self.stars_brighter_than(category.magnitude_limit())
+    }
+
+    /// Up to `count` random star names other than `correct`
+    pub fn random_distractors<R: Rng>(
+        &self,
+        correct: &str,
+        count: usize,
+        rng: &mut R,
+    ) -> Vec<String> {
+        let names: Vec<&String> = self
+            .stars
+            .iter()
+            .filter_map(|s| s.name.as_ref())
+            .filter(|name| name.as_str() != correct)
+            .collect();
+        names
+            .choose_multiple(rng, count)
+            .map(|name| (*name).clone())
+            .collect()
+    }
+
+    /// A named star picked at random, if there is one
+    pub fn random_named_star<R: Rng>(&self, rng: &mut R) -> Option<&Star> {
+        self.named_stars().choose(rng).copied()
+    }
+}
+
+/// Unnamed background stars in the placeholder catalog
+const PLACEHOLDER_FIELD_STARS: u32 = 500;
+
+/// Seed for the placeholder's background stars, so every build, test and
+/// golden file sees the same sky
+const PLACEHOLDER_SEED: u64 = 0x5747_4152;
+
+/// Name, RA (hours), Dec (degrees), magnitude and constellation of the
+/// famous stars in the placeholder catalog
+fn famous_stars_data() -> Vec<(&'static str, f64, f64, f64, &'static str)> {
+    vec![
+        ("Sirius", 6.752, -16.72, -1.46, "CMa"),
+        ("Canopus", 6.399, -52.70, -0.74, "Car"),
+        ("Arcturus", 14.261, 19.18, -0.05, "Boo"),
+        ("Vega", 18.616, 38.78, 0.03, "Lyr"),
+        ("Capella", 5.278, 46.00, 0.08, "Aur"),
+        ("Rigel", 5.242, -8.20, 0.13, "Ori"),
+        ("Procyon", 7.655, 5.22, 0.34, "CMi"),
+        ("Betelgeuse", 5.920, 7.41, 0.42, "Ori"),
+        ("Achernar", 1.629, -57.24, 0.46, "Eri"),
+        ("Altair", 19.846, 8.87, 0.76, "Aql"),
+        ("Aldebaran", 4.599, 16.51, 0.85, "Tau"),
+        ("Spica", 13.420, -11.16, 0.97, "Vir"),
+        ("Antares", 16.490, -26.43, 1.09, "Sco"),
+        ("Pollux", 7.755, 28.03, 1.14, "Gem"),
+        ("Fomalhaut", 22.961, -29.62, 1.16, "PsA"),
+        ("Deneb", 20.690, 45.28, 1.25, "Cyg"),
+        ("Regulus", 10.139, 11.97, 1.35, "Leo"),
+        ("Castor", 7.577, 31.89, 1.58, "Gem"),
+        ("Polaris", 2.530, 89.26, 1.98, "UMi"),
+        ("Alphard", 9.460, -8.66, 2.00, "Hya"),
+        ("Algenib", 0.220, 15.18, 2.83, "Peg"),
+        ("Alphecca", 15.578, 26.71, 2.23, "CrB"),
+        ("Mizar", 13.399, 54.93, 2.27, "UMa"),
+        ("Dubhe", 11.062, 61.75, 1.79, "UMa"),
+        ("Alioth", 12.900, 55.96, 1.77, "UMa"),
+        ("Alkaid", 13.792, 49.31, 1.86, "UMa"),
+        ("Merak", 11.031, 56.38, 2.37, "UMa"),
+        ("Porrima", 12.694, -1.45, 2.74, "Vir"),
+        ("Heze", 13.579, -0.60, 3.37, "Vir"),
+        ("Denebola", 11.817, 14.57, 2.14, "Leo"),
+        ("Algieba", 10.333, 19.84, 2.28, "Leo"),
+        ("Vindemiatrix", 13.036, 10.96, 2.83, "Vir"),
+        ("Cor Caroli", 12.934, 38.32, 2.90, "CVn"),
+        ("Rasalhague", 17.582, 12.56, 2.07, "Oph"),
+        ("Nunki", 18.921, -26.30, 2.05, "Sgr"),
+        ("Kaus Australis", 18.403, -34.38, 1.79, "Sgr"),
+        ("Avior", 8.376, -59.51, 1.86, "Car"),
+        ("Wezen", 7.140, -26.39, 1.84, "CMa"),
+        ("Mirfak", 3.405, 49.86, 1.82, "Per"),
+        ("Algol", 3.136, 40.96, 2.12, "Per"),
+    ]
+}
+
+/// A small catalog for development and tests: forty famous named stars
+/// and a seeded field of faint unnamed ones
+pub fn generate_placeholder_catalog() -> StarCatalog {
+    let mut catalog = StarCatalog::new();
+
+    let famous = famous_stars_data();
+    for (i, (name, ra, dec, magnitude, constellation)) in famous.iter().enumerate() {
+        catalog.add_star(Star {
+            id: StarId(i as u32 + 1),
+            name: Some(name.to_string()),
+            coord: CelestialCoord::new(*ra, *dec),
+            magnitude: *magnitude,
+            constellation: Some(constellation.to_string()),
+        });
+    }
+
+    let mut rng = StdRng::seed_from_u64(PLACEHOLDER_SEED);
+    let first_id = famous.len() as u32 + 1;
+    for id in first_id..first_id + PLACEHOLDER_FIELD_STARS {
+        catalog.add_star(Star {
+            id: StarId(id),
+            name: None,
+            coord: CelestialCoord::new(rng.gen_range(0.0..24.0), rng.gen_range(-80.0..80.0)),
+            magnitude: rng.gen_range(3.0..6.5),
+            constellation: None,
+        });
+    }
+
+    catalog.rebuild_indices();
+    catalog
+}
+
+/// A catalog for tests of the named stars given as (name, constellation),
+/// with ids counting from 1 and right ascensions an hour apart along the
+/// equator
+#[cfg(test)]
+pub(crate) fn catalog_of(stars: &[(&str, &str)]) -> StarCatalog {
+    let mut catalog = StarCatalog::new();
+    for (i, (name, constellation)) in stars.iter().enumerate() {
+        catalog.add_star(Star {
+            id: StarId(i as u32 + 1),
+            name: Some(name.to_string()),
+            coord: CelestialCoord::new(i as f64, 0.0),
+            magnitude: 1.0,
+            constellation: Some(constellation.to_string()),
+        });
+    }
+    catalog.rebuild_indices();
+    catalog
+}
+
+/// Read the star list from `data/stars.json`, relative to the working
+/// directory, as written by `stargazer-cli generate --format json`
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_stars_from_json() -> Result<Vec<Star>, String> {
+    let path = "data/stars.json";
+    let json = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+    serde_json::from_str(&json).map_err(|e| format!("{}: {}", path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_placeholder_catalog() {
+        let catalog = generate_placeholder_catalog();
+        assert_eq!(catalog.named_count(), 40);
+        assert_eq!(catalog.count(), 40 + PLACEHOLDER_FIELD_STARS as usize);
+        assert_eq!(
+            catalog.get(StarId(1)).and_then(|s| s.name.as_deref()),
+            Some("Sirius")
+        );
+        // The same sky every time
+        assert_eq!(catalog, generate_placeholder_catalog());
+    }
+
+    #[test]
+    fn test_stars_in_range_wraps_through_zero_hours() {
+        let catalog = generate_placeholder_catalog();
+        let wrapped = catalog.stars_in_range(22.0, 2.0, -90.0, 90.0, 3.0);
+        let names: Vec<_> = wrapped.iter().filter_map(|s| s.name.as_deref()).collect();
+        assert!(names.contains(&"Fomalhaut"));
+        assert!(names.contains(&"Algenib"));
+        assert!(!names.contains(&"Sirius"));
+        assert_eq!(
+            catalog.stars_in_range(5.0, 5.0, -90.0, 90.0, 10.0).len(),
+            catalog.count()
+        );
+    }
+
+    #[test]
+    fn test_rebuild_indices_after_loading() {
+        let mut catalog = StarCatalog::new();
+        for star in generate_placeholder_catalog().all_stars().take(3) {
+            catalog.add_star(star.clone());
+        }
+        catalog.rebuild_indices();
+        assert_eq!(catalog.count(), 3);
+        assert_eq!(catalog.get(StarId(3)).unwrap().display_name(), "Arcturus");
+        assert!(catalog.get(StarId(4)).is_none());
+    }
+
+    #[test]
+    fn test_random_distractors_leave_out_the_answer() {
+        let catalog = generate_placeholder_catalog();
+        let mut rng = StdRng::seed_from_u64(1);
+        let names = catalog.random_distractors("Vega", 5, &mut rng);
+        assert_eq!(names.len(), 5);
+        assert!(!names.iter().any(|n| n == "Vega"));
+    }
+}
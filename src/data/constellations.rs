@@ -0,0 +1,385 @@
+//! Stars grouped by constellation
+//!
+//! Per-constellation breakdowns of the catalog for statistics and lists,
+//! and the stick figures the map draws when constellations are shown.
+
+mod figures;
+
+pub use figures::FIGURES;
+
+use super::nearest::angular_separation;
+use super::{CelestialCoord, Star, StarCatalog};
+use std::collections::BTreeMap;
+
+/// Group name for stars without a constellation
+pub const NO_CONSTELLATION: &str = "(none)";
+
+impl StarCatalog {
+    /// Stars grouped by constellation abbreviation, brightest first within each group
+    ///
+    /// Stars without a constellation are collected under [`NO_CONSTELLATION`].
+    pub fn constellations(&self) -> BTreeMap<String, Vec<&Star>> {
+        let mut groups: BTreeMap<String, Vec<&Star>> = BTreeMap::new();
+        for star in self.all_stars() {
+            let key = star.constellation.as_deref().unwrap_or(NO_CONSTELLATION);
+            groups.entry(key.to_string()).or_default().push(star);
+        }
+        for stars in groups.values_mut() {
+            stars.sort_by(|a, b| a.magnitude.total_cmp(&b.magnitude));
+        }
+        groups
+    }
+}
+
+/// IAU abbreviation and full name of each of the 88 constellations, in
+/// order of name
+pub static CONSTELLATION_NAMES: [(&str, &str); 88] = [
+    ("And", "Andromeda"),
+    ("Ant", "Antlia"),
+    ("Aps", "Apus"),
+    ("Aqr", "Aquarius"),
+    ("Aql", "Aquila"),
+    ("Ara", "Ara"),
+    ("Ari", "Aries"),
+    ("Aur", "Auriga"),
+    ("Boo", "Boötes"),
+    ("Cae", "Caelum"),
+    ("Cam", "Camelopardalis"),
+    ("Cnc", "Cancer"),
+    ("CVn", "Canes Venatici"),
+    ("CMa", "Canis Major"),
+    ("CMi", "Canis Minor"),
+    ("Cap", "Capricornus"),
+    ("Car", "Carina"),
+    ("Cas", "Cassiopeia"),
+    ("Cen", "Centaurus"),
+    ("Cep", "Cepheus"),
+    ("Cet", "Cetus"),
+    ("Cha", "Chamaeleon"),
+    ("Cir", "Circinus"),
+    ("Col", "Columba"),
+    ("Com", "Coma Berenices"),
+    ("CrA", "Corona Australis"),
+    ("CrB", "Corona Borealis"),
+    ("Crv", "Corvus"),
+    ("Crt", "Crater"),
+    ("Cru", "Crux"),
+    ("Cyg", "Cygnus"),
+    ("Del", "Delphinus"),
+    ("Dor", "Dorado"),
+    ("Dra", "Draco"),
+    ("Equ", "Equuleus"),
+    ("Eri", "Eridanus"),
+    ("For", "Fornax"),
+    ("Gem", "Gemini"),
+    ("Gru", "Grus"),
+    ("Her", "Hercules"),
+    ("Hor", "Horologium"),
+    ("Hya", "Hydra"),
+    ("Hyi", "Hydrus"),
+    ("Ind", "Indus"),
+    ("Lac", "Lacerta"),
+    ("Leo", "Leo"),
+    ("LMi", "Leo Minor"),
+    ("Lep", "Lepus"),
+    ("Lib", "Libra"),
+    ("Lup", "Lupus"),
+    ("Lyn", "Lynx"),
+    ("Lyr", "Lyra"),
+    ("Men", "Mensa"),
+    ("Mic", "Microscopium"),
+    ("Mon", "Monoceros"),
+    ("Mus", "Musca"),
+    ("Nor", "Norma"),
+    ("Oct", "Octans"),
+    ("Oph", "Ophiuchus"),
+    ("Ori", "Orion"),
+    ("Pav", "Pavo"),
+    ("Peg", "Pegasus"),
+    ("Per", "Perseus"),
+    ("Phe", "Phoenix"),
+    ("Pic", "Pictor"),
+    ("Psc", "Pisces"),
+    ("PsA", "Piscis Austrinus"),
+    ("Pup", "Puppis"),
+    ("Pyx", "Pyxis"),
+    ("Ret", "Reticulum"),
+    ("Sge", "Sagitta"),
+    ("Sgr", "Sagittarius"),
+    ("Sco", "Scorpius"),
+    ("Scl", "Sculptor"),
+    ("Sct", "Scutum"),
+    ("Ser", "Serpens"),
+    ("Sex", "Sextans"),
+    ("Tau", "Taurus"),
+    ("Tel", "Telescopium"),
+    ("Tri", "Triangulum"),
+    ("TrA", "Triangulum Australe"),
+    ("Tuc", "Tucana"),
+    ("UMa", "Ursa Major"),
+    ("UMi", "Ursa Minor"),
+    ("Vel", "Vela"),
+    ("Vir", "Virgo"),
+    ("Vol", "Volans"),
+    ("Vul", "Vulpecula"),
+];
+
+/// The full name of the constellation abbreviated `abbreviation`, such as
+/// "Orion" for "Ori"
+pub fn constellation_name(abbreviation: &str) -> Option<&'static str> {
+    CONSTELLATION_NAMES
+        .iter()
+        .find(|(abbr, _)| *abbr == abbreviation)
+        .map(|(_, name)| *name)
+}
+
+/// Summary statistics for one constellation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstellationSummary<'a> {
+    pub name: String,
+    pub total: usize,
+    pub named: usize,
+    pub brightest: &'a Star,
+    pub mean_magnitude: f64,
+}
+
+/// Summarize every constellation, most named stars first
+pub fn summarize_constellations(catalog: &StarCatalog) -> Vec<ConstellationSummary<'_>> {
+    let mut summaries: Vec<_> = catalog
+        .constellations()
+        .into_iter()
+        .filter_map(|(name, stars)| {
+            let brightest = *stars.first()?;
+            Some(ConstellationSummary {
+                total: stars.len(),
+                named: stars.iter().filter(|s| s.has_name()).count(),
+                brightest,
+                mean_magnitude: stars.iter().map(|s| s.magnitude).sum::<f64>() / stars.len() as f64,
+                name,
+            })
+        })
+        .collect();
+
+    summaries.sort_by(|a, b| b.named.cmp(&a.named).then_with(|| a.name.cmp(&b.name)));
+    summaries
+}
+
+/// A figure point moves onto the nearest catalog star within this many degrees
+pub const SNAP_DEGREES: f64 = 0.5;
+
+/// Fainter stars are never snapped to, so a figure point cannot land on a
+/// faint neighbor of the star it means
+const SNAP_MAGNITUDE: f64 = 6.0;
+
+/// The stick figure of one constellation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConstellationFigure {
+    /// IAU abbreviation, as in [`Star::constellation`]
+    pub abbreviation: &'static str,
+    /// Lines drawn through consecutive points, as (RA hours, Dec degrees)
+    pub strokes: &'static [&'static [(f64, f64)]],
+}
+
+/// One line of a constellation figure
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FigureSegment {
+    pub constellation: &'static str,
+    pub from: CelestialCoord,
+    pub to: CelestialCoord,
+}
+
+impl ConstellationFigure {
+    /// The figure's lines, one per pair of consecutive stroke points
+    pub fn segments(&self) -> impl Iterator<Item = FigureSegment> + '_ {
+        self.strokes.iter().flat_map(move |stroke| {
+            stroke.windows(2).map(move |pair| FigureSegment {
+                constellation: self.abbreviation,
+                from: CelestialCoord::new(pair[0].0, pair[0].1),
+                to: CelestialCoord::new(pair[1].0, pair[1].1),
+            })
+        })
+    }
+}
+
+/// Every figure's lines, with each end moved onto the catalog star nearest
+/// it if one is within [`SNAP_DEGREES`]
+///
+/// The figures' own positions are rounded, so snapping keeps the lines
+/// meeting the stars at any zoom. Points with no star near them are kept
+/// as they are.
+pub fn figure_segments(catalog: &StarCatalog) -> Vec<FigureSegment> {
+    let mut bright: Vec<&Star> = catalog
+        .all_stars()
+        .filter(|star| star.magnitude <= SNAP_MAGNITUDE)
+        .collect();
+    bright.sort_by(|a, b| a.coord.dec.total_cmp(&b.coord.dec));
+
+    let snap = |point: CelestialCoord| {
+        let first = bright.partition_point(|star| star.coord.dec < point.dec - SNAP_DEGREES);
+        bright[first..]
+            .iter()
+            .take_while(|star| star.coord.dec <= point.dec + SNAP_DEGREES)
+            .map(|star| (star.coord, angular_separation(&point, &star.coord)))
+            .filter(|(_, separation)| *separation <= SNAP_DEGREES)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map_or(point, |(coord, _)| coord)
+    };
+
+    FIGURES
+        .iter()
+        .flat_map(ConstellationFigure::segments)
+        .map(|segment| FigureSegment {
+            from: snap(segment.from),
+            to: snap(segment.to),
+            ..segment
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{generate_placeholder_catalog, StarId};
+    use std::collections::HashSet;
+
+    fn star(id: u32, name: Option<&str>, magnitude: f64, constellation: Option<&str>) -> Star {
+        Star {
+            id: StarId(id),
+            name: name.map(str::to_string),
+            coord: CelestialCoord::new(id as f64, 0.0),
+            magnitude,
+            constellation: constellation.map(str::to_string),
+        }
+    }
+
+    fn small_catalog() -> StarCatalog {
+        let mut catalog = StarCatalog::new();
+        for s in [
+            star(1, Some("Betelgeuse"), 0.5, Some("Ori")),
+            star(2, Some("Rigel"), 0.13, Some("Ori")),
+            star(3, None, 4.2, Some("Ori")),
+            star(4, Some("Sirius"), -1.46, Some("CMa")),
+            star(5, None, 5.5, None),
+        ] {
+            catalog.add_star(s);
+        }
+        catalog.rebuild_indices();
+        catalog
+    }
+
+    #[test]
+    fn test_constellations_groups_and_sorts() {
+        let catalog = small_catalog();
+        let groups = catalog.constellations();
+
+        assert_eq!(groups.keys().collect::<Vec<_>>(), ["(none)", "CMa", "Ori"]);
+        let orion: Vec<_> = groups["Ori"].iter().map(|s| s.id.0).collect();
+        assert_eq!(orion, [2, 1, 3]);
+        assert_eq!(groups[NO_CONSTELLATION][0].id, StarId(5));
+    }
+
+    #[test]
+    fn test_constellation_names() {
+        assert_eq!(constellation_name("Ori"), Some("Orion"));
+        assert_eq!(constellation_name("CVn"), Some("Canes Venatici"));
+        assert_eq!(constellation_name("XYZ"), None);
+        let abbreviations: HashSet<_> = CONSTELLATION_NAMES.iter().map(|(abbr, _)| abbr).collect();
+        assert_eq!(abbreviations.len(), 88);
+        // Every figure is of a named constellation
+        for figure in FIGURES.iter() {
+            assert!(
+                constellation_name(figure.abbreviation).is_some(),
+                "{}",
+                figure.abbreviation
+            );
+        }
+    }
+
+    #[test]
+    fn test_constellations_cover_catalog() {
+        let catalog = generate_placeholder_catalog();
+        let total: usize = catalog.constellations().values().map(Vec::len).sum();
+        assert_eq!(total, catalog.count());
+    }
+
+    #[test]
+    fn test_summaries() {
+        let catalog = small_catalog();
+        let summaries = summarize_constellations(&catalog);
+
+        let names: Vec<_> = summaries.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["Ori", "CMa", "(none)"]);
+
+        let orion = &summaries[0];
+        assert_eq!(orion.total, 3);
+        assert_eq!(orion.named, 2);
+        assert_eq!(orion.brightest.name.as_deref(), Some("Rigel"));
+        assert!((orion.mean_magnitude - (0.5 + 0.13 + 4.2) / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_every_constellation_has_a_figure() {
+        let abbreviations: HashSet<&str> = FIGURES.iter().map(|f| f.abbreviation).collect();
+        assert_eq!(abbreviations.len(), 88);
+        for figure in &FIGURES {
+            assert_eq!(figure.abbreviation.chars().count(), 3);
+            assert!(!figure.strokes.is_empty(), "{}", figure.abbreviation);
+            for stroke in figure.strokes {
+                assert!(stroke.len() >= 2, "{}", figure.abbreviation);
+                for &(ra, dec) in stroke.iter() {
+                    assert!((0.0..24.0).contains(&ra), "{} {}", figure.abbreviation, ra);
+                    assert!(
+                        (-90.0..=90.0).contains(&dec),
+                        "{} {}",
+                        figure.abbreviation,
+                        dec
+                    );
+                }
+            }
+            // A figure is a few tens of degrees across; a longer line is a typo
+            for segment in figure.segments() {
+                let length = angular_separation(&segment.from, &segment.to);
+                assert!(length < 40.0, "{:?} is {:.1} degrees", segment, length);
+            }
+        }
+    }
+
+    #[test]
+    fn test_figure_points_snap_to_catalog_stars() {
+        let betelgeuse = CelestialCoord::new(5.919, 7.41);
+        let mut catalog = StarCatalog::new();
+        // Slightly off the figure point, as a real catalog position would be
+        let nearby = CelestialCoord::new(5.9195, 7.407);
+        for s in [
+            Star {
+                coord: nearby,
+                ..star(1, Some("Betelgeuse"), 0.45, Some("Ori"))
+            },
+            // Closer, but too faint to be a figure star
+            Star {
+                coord: CelestialCoord::new(5.919, 7.412),
+                ..star(2, None, 8.0, None)
+            },
+        ] {
+            catalog.add_star(s);
+        }
+        catalog.rebuild_indices();
+
+        let segments = figure_segments(&catalog);
+        assert_eq!(
+            segments.len(),
+            FIGURES.iter().flat_map(|f| f.segments()).count()
+        );
+        let orion: Vec<_> = segments
+            .iter()
+            .filter(|s| s.constellation == "Ori")
+            .collect();
+        assert!(orion.iter().any(|s| s.from == nearby));
+        assert!(orion
+            .iter()
+            .all(|s| s.from != betelgeuse && s.to != betelgeuse));
+        // Points with no star nearby are left alone
+        let rigel = CelestialCoord::new(5.242, -8.20);
+        assert!(orion.iter().any(|s| s.to == rigel));
+    }
+}
@@ -0,0 +1,1298 @@
+//! Stick figures for the 88 IAU constellations
+//!
+//! Each figure is drawn through the constellation's brighter stars as one
+//! or more strokes. Points are J2000 positions, RA in hours and Dec in
+//! degrees, to about a tenth of a degree; [`figure_segments`] moves them
+//! onto the catalog's own stars. The comment above each figure names the
+//! stars of each stroke.
+//!
+//! [`figure_segments`]: super::figure_segments
+
+use super::ConstellationFigure;
+
+/// Every constellation's figure, by abbreviation
+pub const FIGURES: [ConstellationFigure; 88] = [
+    // α δ β γ | β μ ν
+    ConstellationFigure {
+        abbreviation: "And",
+        strokes: &[
+            &[
+                (0.140, 29.09),
+                (0.655, 30.86),
+                (1.162, 35.62),
+                (2.065, 42.33),
+            ],
+            &[(1.162, 35.62), (0.946, 38.50), (0.830, 41.08)],
+        ],
+    },
+    // ε α ι
+    ConstellationFigure {
+        abbreviation: "Ant",
+        strokes: &[&[(9.487, -35.95), (10.452, -31.07), (10.945, -37.14)]],
+    },
+    // α δ γ β
+    ConstellationFigure {
+        abbreviation: "Aps",
+        strokes: &[&[
+            (14.798, -79.04),
+            (16.340, -78.70),
+            (16.557, -78.90),
+            (16.718, -77.52),
+        ]],
+    },
+    // ε β α γ ζ π | ζ η | α θ λ δ
+    ConstellationFigure {
+        abbreviation: "Aqr",
+        strokes: &[
+            &[
+                (20.795, -9.50),
+                (21.526, -5.57),
+                (22.096, -0.32),
+                (22.361, -1.39),
+                (22.481, -0.02),
+                (22.421, 1.38),
+            ],
+            &[(22.481, -0.02), (22.589, -0.12)],
+            &[
+                (22.096, -0.32),
+                (22.280, -7.78),
+                (22.877, -7.58),
+                (22.911, -15.82),
+            ],
+        ],
+    },
+    // γ α β | ζ δ λ | α δ | δ η θ
+    ConstellationFigure {
+        abbreviation: "Aql",
+        strokes: &[
+            &[(19.771, 10.61), (19.846, 8.87), (19.922, 6.41)],
+            &[(19.090, 13.86), (19.425, 3.11), (19.104, -4.88)],
+            &[(19.846, 8.87), (19.425, 3.11)],
+            &[(19.425, 3.11), (19.874, 1.01), (20.188, -0.82)],
+        ],
+    },
+    // θ α ε ζ η | α β γ δ
+    ConstellationFigure {
+        abbreviation: "Ara",
+        strokes: &[
+            &[
+                (18.110, -50.09),
+                (17.531, -49.88),
+                (16.993, -53.16),
+                (16.977, -55.99),
+                (16.830, -59.04),
+            ],
+            &[
+                (17.531, -49.88),
+                (17.422, -55.53),
+                (17.423, -56.38),
+                (17.518, -60.68),
+            ],
+        ],
+    },
+    // 41 α β γ
+    ConstellationFigure {
+        abbreviation: "Ari",
+        strokes: &[&[
+            (2.833, 27.26),
+            (2.120, 23.46),
+            (1.911, 20.81),
+            (1.892, 19.29),
+        ]],
+    },
+    // α β θ β-Tau ι α | α ε ζ η α
+    ConstellationFigure {
+        abbreviation: "Aur",
+        strokes: &[
+            &[
+                (5.278, 46.00),
+                (5.992, 44.95),
+                (5.995, 37.21),
+                (5.438, 28.61),
+                (4.950, 33.17),
+                (5.278, 46.00),
+            ],
+            &[
+                (5.278, 46.00),
+                (5.033, 43.82),
+                (5.041, 41.08),
+                (5.109, 41.23),
+                (5.278, 46.00),
+            ],
+        ],
+    },
+    // α ε δ β γ ρ α | α η | α ζ
+    ConstellationFigure {
+        abbreviation: "Boo",
+        strokes: &[
+            &[
+                (14.261, 19.18),
+                (14.750, 27.07),
+                (15.258, 33.31),
+                (15.032, 40.39),
+                (14.535, 38.31),
+                (14.531, 30.37),
+                (14.261, 19.18),
+            ],
+            &[(14.261, 19.18), (13.911, 18.40)],
+            &[(14.261, 19.18), (14.686, 13.73)],
+        ],
+    },
+    // δ α β γ
+    ConstellationFigure {
+        abbreviation: "Cae",
+        strokes: &[&[
+            (4.514, -44.95),
+            (4.676, -41.86),
+            (4.701, -37.14),
+            (5.073, -35.48),
+        ]],
+    },
+    // 7 β α γ
+    ConstellationFigure {
+        abbreviation: "Cam",
+        strokes: &[&[
+            (4.954, 53.75),
+            (5.057, 60.44),
+            (4.901, 66.34),
+            (3.839, 71.33),
+        ]],
+    },
+    // β δ γ ι | δ α
+    ConstellationFigure {
+        abbreviation: "Cnc",
+        strokes: &[
+            &[
+                (8.275, 9.19),
+                (8.745, 18.15),
+                (8.722, 21.47),
+                (8.778, 28.76),
+            ],
+            &[(8.745, 18.15), (8.975, 11.86)],
+        ],
+    },
+    // α β
+    ConstellationFigure {
+        abbreviation: "CVn",
+        strokes: &[&[(12.934, 38.32), (12.562, 41.36)]],
+    },
+    // θ γ ι α β | α ο² δ η | δ ε ζ
+    ConstellationFigure {
+        abbreviation: "CMa",
+        strokes: &[
+            &[
+                (6.903, -12.04),
+                (7.063, -15.63),
+                (6.936, -17.05),
+                (6.752, -16.72),
+                (6.378, -17.96),
+            ],
+            &[
+                (6.752, -16.72),
+                (7.050, -23.83),
+                (7.140, -26.39),
+                (7.402, -29.30),
+            ],
+            &[(7.140, -26.39), (6.977, -28.97), (6.338, -30.06)],
+        ],
+    },
+    // α β
+    ConstellationFigure {
+        abbreviation: "CMi",
+        strokes: &[&[(7.655, 5.22), (7.453, 8.29)]],
+    },
+    // α β ψ ω ζ δ γ ι θ α
+    ConstellationFigure {
+        abbreviation: "Cap",
+        strokes: &[&[
+            (20.300, -12.54),
+            (20.350, -14.78),
+            (20.768, -25.27),
+            (20.863, -26.92),
+            (21.444, -22.41),
+            (21.784, -16.13),
+            (21.668, -16.66),
+            (21.371, -16.83),
+            (21.099, -17.23),
+            (20.300, -12.54),
+        ]],
+    },
+    // α χ ε ι q p θ | θ ω β υ ι
+    ConstellationFigure {
+        abbreviation: "Car",
+        strokes: &[
+            &[
+                (6.399, -52.70),
+                (7.946, -52.98),
+                (8.375, -59.51),
+                (9.285, -59.28),
+                (10.284, -61.33),
+                (10.533, -61.69),
+                (10.716, -64.39),
+            ],
+            &[
+                (10.716, -64.39),
+                (10.229, -70.04),
+                (9.220, -69.72),
+                (9.785, -65.07),
+                (9.285, -59.28),
+            ],
+        ],
+    },
+    // β α γ δ ε
+    ConstellationFigure {
+        abbreviation: "Cas",
+        strokes: &[&[
+            (0.153, 59.15),
+            (0.675, 56.54),
+            (0.945, 60.72),
+            (1.430, 60.24),
+            (1.907, 63.67),
+        ]],
+    },
+    // α β ε γ δ π | ε ζ η | ζ ν θ | ν ι
+    ConstellationFigure {
+        abbreviation: "Cen",
+        strokes: &[
+            &[
+                (14.660, -60.84),
+                (14.064, -60.37),
+                (13.665, -53.47),
+                (12.692, -48.96),
+                (12.139, -50.72),
+                (11.350, -54.49),
+            ],
+            &[(13.665, -53.47), (13.926, -47.29), (14.592, -42.16)],
+            &[(13.926, -47.29), (13.825, -41.69), (14.111, -36.37)],
+            &[(13.825, -41.69), (13.343, -36.71)],
+        ],
+    },
+    // α β γ ι ζ α | β ι
+    ConstellationFigure {
+        abbreviation: "Cep",
+        strokes: &[
+            &[
+                (21.310, 62.59),
+                (21.478, 70.56),
+                (23.656, 77.63),
+                (22.828, 66.20),
+                (22.181, 58.20),
+                (21.310, 62.59),
+            ],
+            &[(21.478, 70.56), (22.828, 66.20)],
+        ],
+    },
+    // α λ μ γ α | γ δ ο ζ τ β ι η θ ζ
+    ConstellationFigure {
+        abbreviation: "Cet",
+        strokes: &[
+            &[
+                (3.038, 4.09),
+                (2.995, 8.91),
+                (2.749, 10.11),
+                (2.722, 3.24),
+                (3.038, 4.09),
+            ],
+            &[
+                (2.722, 3.24),
+                (2.658, 0.33),
+                (2.322, -2.98),
+                (1.858, -10.34),
+                (1.734, -15.94),
+                (0.726, -17.99),
+                (0.324, -8.82),
+                (1.143, -10.18),
+                (1.400, -8.18),
+                (1.858, -10.34),
+            ],
+        ],
+    },
+    // α γ β | γ δ²
+    ConstellationFigure {
+        abbreviation: "Cha",
+        strokes: &[
+            &[(8.344, -76.92), (10.591, -78.61), (12.306, -79.31)],
+            &[(10.591, -78.61), (10.763, -80.54)],
+        ],
+    },
+    // β α γ
+    ConstellationFigure {
+        abbreviation: "Cir",
+        strokes: &[&[(15.292, -58.80), (14.708, -64.98), (15.390, -59.32)]],
+    },
+    // ε α β γ δ | β η
+    ConstellationFigure {
+        abbreviation: "Col",
+        strokes: &[
+            &[
+                (5.520, -35.47),
+                (5.661, -34.07),
+                (5.849, -35.77),
+                (5.959, -35.28),
+                (6.369, -33.44),
+            ],
+            &[(5.849, -35.77), (5.986, -42.82)],
+        ],
+    },
+    // α β γ
+    ConstellationFigure {
+        abbreviation: "Com",
+        strokes: &[&[(13.166, 17.53), (13.198, 27.88), (12.449, 28.27)]],
+    },
+    // θ ζ δ β α γ ε
+    ConstellationFigure {
+        abbreviation: "CrA",
+        strokes: &[&[
+            (18.558, -42.31),
+            (19.052, -42.10),
+            (19.139, -40.50),
+            (19.167, -39.34),
+            (19.158, -37.90),
+            (19.107, -37.06),
+            (18.979, -37.11),
+        ]],
+    },
+    // θ β α γ δ ε ι
+    ConstellationFigure {
+        abbreviation: "CrB",
+        strokes: &[&[
+            (15.549, 31.36),
+            (15.464, 29.11),
+            (15.578, 26.71),
+            (15.712, 26.30),
+            (15.826, 26.07),
+            (15.960, 26.88),
+            (16.024, 29.85),
+        ]],
+    },
+    // α ε γ δ β ε
+    ConstellationFigure {
+        abbreviation: "Crv",
+        strokes: &[&[
+            (12.140, -24.73),
+            (12.169, -22.62),
+            (12.263, -17.54),
+            (12.498, -16.52),
+            (12.573, -23.40),
+            (12.169, -22.62),
+        ]],
+    },
+    // α β γ δ α | δ ε θ | γ ζ η
+    ConstellationFigure {
+        abbreviation: "Crt",
+        strokes: &[
+            &[
+                (10.996, -18.30),
+                (11.194, -22.83),
+                (11.415, -17.68),
+                (11.322, -14.78),
+                (10.996, -18.30),
+            ],
+            &[(11.322, -14.78), (11.411, -10.86), (11.611, -9.80)],
+            &[(11.415, -17.68), (11.746, -18.35), (11.933, -17.15)],
+        ],
+    },
+    // α γ | β δ
+    ConstellationFigure {
+        abbreviation: "Cru",
+        strokes: &[
+            &[(12.443, -63.10), (12.519, -57.11)],
+            &[(12.795, -59.69), (12.252, -58.75)],
+        ],
+    },
+    // α γ η β | δ γ ε ζ | δ ι κ
+    ConstellationFigure {
+        abbreviation: "Cyg",
+        strokes: &[
+            &[
+                (20.690, 45.28),
+                (20.370, 40.26),
+                (19.938, 35.08),
+                (19.512, 27.96),
+            ],
+            &[
+                (19.750, 45.13),
+                (20.370, 40.26),
+                (20.770, 33.97),
+                (21.216, 30.23),
+            ],
+            &[(19.750, 45.13), (19.495, 51.73), (19.285, 53.37)],
+        ],
+    },
+    // ε β α γ δ β
+    ConstellationFigure {
+        abbreviation: "Del",
+        strokes: &[&[
+            (20.554, 11.30),
+            (20.626, 14.60),
+            (20.661, 15.91),
+            (20.777, 16.12),
+            (20.724, 15.07),
+            (20.626, 14.60),
+        ]],
+    },
+    // γ α ζ β δ
+    ConstellationFigure {
+        abbreviation: "Dor",
+        strokes: &[&[
+            (4.267, -51.49),
+            (4.567, -55.04),
+            (5.092, -57.47),
+            (5.560, -62.49),
+            (5.746, -65.74),
+        ]],
+    },
+    // γ β ν ξ γ | ξ δ ε χ ζ η θ ι α κ λ
+    ConstellationFigure {
+        abbreviation: "Dra",
+        strokes: &[
+            &[
+                (17.943, 51.49),
+                (17.507, 52.30),
+                (17.537, 55.18),
+                (17.892, 56.87),
+                (17.943, 51.49),
+            ],
+            &[
+                (17.892, 56.87),
+                (19.209, 67.66),
+                (19.803, 70.27),
+                (18.351, 72.73),
+                (17.146, 65.71),
+                (16.400, 61.51),
+                (16.031, 58.57),
+                (15.415, 58.97),
+                (14.073, 64.38),
+                (12.558, 69.79),
+                (11.523, 69.33),
+            ],
+        ],
+    },
+    // γ δ α β
+    ConstellationFigure {
+        abbreviation: "Equ",
+        strokes: &[&[
+            (21.172, 10.13),
+            (21.241, 10.01),
+            (21.264, 5.25),
+            (21.381, 6.81),
+        ]],
+    },
+    // β ν ο¹ γ π δ ε η τ¹ τ² τ³ τ⁴ τ⁵ τ⁶ υ¹ υ² 43 υ⁴ θ ι κ φ χ α
+    ConstellationFigure {
+        abbreviation: "Eri",
+        strokes: &[&[
+            (5.131, -5.09),
+            (4.606, -3.35),
+            (4.198, -6.84),
+            (3.967, -13.51),
+            (3.769, -12.10),
+            (3.721, -9.76),
+            (3.549, -9.46),
+            (2.940, -8.90),
+            (2.751, -18.57),
+            (2.851, -21.00),
+            (3.040, -23.62),
+            (3.325, -21.76),
+            (3.563, -21.63),
+            (3.781, -23.25),
+            (4.559, -29.77),
+            (4.593, -30.56),
+            (4.400, -34.02),
+            (4.298, -33.80),
+            (2.971, -40.30),
+            (2.678, -39.86),
+            (2.450, -47.70),
+            (2.275, -51.51),
+            (1.933, -51.61),
+            (1.629, -57.24),
+        ]],
+    },
+    // α β ν
+    ConstellationFigure {
+        abbreviation: "For",
+        strokes: &[&[(3.201, -28.99), (2.818, -32.41), (2.075, -29.30)]],
+    },
+    // η μ ε τ α β δ ζ γ | τ θ | δ λ ξ | β κ
+    ConstellationFigure {
+        abbreviation: "Gem",
+        strokes: &[
+            &[
+                (6.248, 22.51),
+                (6.383, 22.51),
+                (6.732, 25.13),
+                (7.186, 30.25),
+                (7.577, 31.89),
+                (7.755, 28.03),
+                (7.335, 21.98),
+                (7.068, 20.57),
+                (6.629, 16.40),
+            ],
+            &[(7.186, 30.25), (6.880, 33.96)],
+            &[(7.335, 21.98), (7.301, 16.54), (6.755, 12.90)],
+            &[(7.755, 28.03), (7.740, 24.40)],
+        ],
+    },
+    // γ λ δ β ε ζ | α β | β ι
+    ConstellationFigure {
+        abbreviation: "Gru",
+        strokes: &[
+            &[
+                (21.899, -37.37),
+                (22.102, -39.54),
+                (22.488, -43.50),
+                (22.711, -46.88),
+                (22.809, -51.32),
+                (23.015, -52.75),
+            ],
+            &[(22.137, -46.96), (22.711, -46.88)],
+            &[(22.711, -46.88), (23.173, -45.25)],
+        ],
+    },
+    // ζ η π ε ζ | ζ β γ | ε δ λ μ ξ | δ α | π θ ι | η σ τ φ
+    ConstellationFigure {
+        abbreviation: "Her",
+        strokes: &[
+            &[
+                (16.688, 31.60),
+                (16.715, 38.92),
+                (17.251, 36.81),
+                (17.005, 30.93),
+                (16.688, 31.60),
+            ],
+            &[(16.688, 31.60), (16.504, 21.49), (16.365, 19.15)],
+            &[
+                (17.005, 30.93),
+                (17.251, 24.84),
+                (17.512, 26.11),
+                (17.774, 27.72),
+                (17.963, 29.25),
+            ],
+            &[(17.251, 24.84), (17.244, 14.39)],
+            &[(17.251, 36.81), (17.938, 37.25), (17.657, 46.01)],
+            &[
+                (16.715, 38.92),
+                (16.568, 42.44),
+                (16.330, 46.31),
+                (16.146, 44.94),
+            ],
+        ],
+    },
+    // α ι η ζ μ β
+    ConstellationFigure {
+        abbreviation: "Hor",
+        strokes: &[&[
+            (4.233, -42.29),
+            (2.709, -50.80),
+            (2.623, -52.54),
+            (2.677, -54.55),
+            (3.061, -59.74),
+            (2.980, -64.07),
+        ]],
+    },
+    // δ σ η ρ ζ ε δ | ζ θ ι α υ¹ λ μ ν ξ β γ π
+    ConstellationFigure {
+        abbreviation: "Hya",
+        strokes: &[
+            &[
+                (8.628, 5.70),
+                (8.645, 3.34),
+                (8.720, 3.40),
+                (8.807, 5.84),
+                (8.923, 5.95),
+                (8.780, 6.42),
+                (8.628, 5.70),
+            ],
+            &[
+                (8.923, 5.95),
+                (9.239, 2.31),
+                (9.664, -1.14),
+                (9.460, -8.66),
+                (9.857, -14.85),
+                (10.176, -12.35),
+                (10.435, -16.84),
+                (10.827, -16.19),
+                (11.550, -31.86),
+                (11.882, -33.91),
+                (13.315, -23.17),
+                (14.106, -26.68),
+            ],
+        ],
+    },
+    // β α γ β
+    ConstellationFigure {
+        abbreviation: "Hyi",
+        strokes: &[&[
+            (0.429, -77.25),
+            (1.980, -61.57),
+            (3.787, -74.24),
+            (0.429, -77.25),
+        ]],
+    },
+    // α θ δ | θ β
+    ConstellationFigure {
+        abbreviation: "Ind",
+        strokes: &[
+            &[(20.626, -47.29), (21.331, -53.45), (21.965, -54.99)],
+            &[(21.331, -53.45), (20.913, -58.45)],
+        ],
+    },
+    // β α 5 2 6 1
+    ConstellationFigure {
+        abbreviation: "Lac",
+        strokes: &[&[
+            (22.392, 52.23),
+            (22.522, 50.28),
+            (22.492, 47.71),
+            (22.350, 46.54),
+            (22.508, 43.12),
+            (22.266, 37.75),
+        ]],
+    },
+    // α η γ ζ μ ε | γ δ β θ α
+    ConstellationFigure {
+        abbreviation: "Leo",
+        strokes: &[
+            &[
+                (10.140, 11.97),
+                (10.122, 16.76),
+                (10.333, 19.84),
+                (10.278, 23.42),
+                (9.879, 26.01),
+                (9.764, 23.77),
+            ],
+            &[
+                (10.333, 19.84),
+                (11.235, 20.52),
+                (11.818, 14.57),
+                (11.237, 15.43),
+                (10.140, 11.97),
+            ],
+        ],
+    },
+    // 21 β 46
+    ConstellationFigure {
+        abbreviation: "LMi",
+        strokes: &[&[(10.124, 35.24), (10.464, 36.71), (10.889, 34.21)]],
+    },
+    // κ μ α ζ η | α β ε | β γ δ
+    ConstellationFigure {
+        abbreviation: "Lep",
+        strokes: &[
+            &[
+                (5.221, -12.94),
+                (5.216, -16.21),
+                (5.546, -17.82),
+                (5.783, -14.82),
+                (5.940, -14.17),
+            ],
+            &[(5.546, -17.82), (5.471, -20.76), (5.091, -22.37)],
+            &[(5.471, -20.76), (5.741, -22.45), (5.855, -20.88)],
+        ],
+    },
+    // σ α β γ υ | α γ
+    ConstellationFigure {
+        abbreviation: "Lib",
+        strokes: &[
+            &[
+                (15.068, -25.28),
+                (14.848, -16.04),
+                (15.283, -9.38),
+                (15.592, -14.79),
+                (15.617, -28.14),
+            ],
+            &[(14.848, -16.04), (15.592, -14.79)],
+        ],
+    },
+    // ζ α β δ γ η | δ φ¹ | γ ε κ ζ
+    ConstellationFigure {
+        abbreviation: "Lup",
+        strokes: &[
+            &[
+                (15.205, -52.10),
+                (14.699, -47.39),
+                (14.976, -43.13),
+                (15.356, -40.65),
+                (15.586, -41.17),
+                (16.002, -38.40),
+            ],
+            &[(15.356, -40.65), (15.364, -36.26)],
+            &[
+                (15.586, -41.17),
+                (15.378, -44.69),
+                (15.199, -48.74),
+                (15.205, -52.10),
+            ],
+        ],
+    },
+    // α 38 31 21 15 2
+    ConstellationFigure {
+        abbreviation: "Lyn",
+        strokes: &[&[
+            (9.351, 34.39),
+            (9.314, 36.80),
+            (8.381, 43.19),
+            (7.446, 49.21),
+            (6.954, 58.42),
+            (6.327, 59.01),
+        ]],
+    },
+    // α ε ζ α | ζ δ² γ β ζ
+    ConstellationFigure {
+        abbreviation: "Lyr",
+        strokes: &[
+            &[
+                (18.616, 38.78),
+                (18.739, 39.67),
+                (18.746, 37.61),
+                (18.616, 38.78),
+            ],
+            &[
+                (18.746, 37.61),
+                (18.908, 36.90),
+                (18.982, 32.69),
+                (18.835, 33.36),
+                (18.746, 37.61),
+            ],
+        ],
+    },
+    // α γ η β
+    ConstellationFigure {
+        abbreviation: "Men",
+        strokes: &[&[
+            (6.171, -74.75),
+            (5.531, -76.34),
+            (4.835, -74.94),
+            (5.045, -71.31),
+        ]],
+    },
+    // α γ ε θ¹ ι α
+    ConstellationFigure {
+        abbreviation: "Mic",
+        strokes: &[&[
+            (20.833, -33.78),
+            (21.021, -32.26),
+            (21.299, -32.17),
+            (21.346, -40.81),
+            (20.810, -43.99),
+            (20.833, -33.78),
+        ]],
+    },
+    // γ β δ α | δ ζ | δ 18 13 | 18 ε
+    ConstellationFigure {
+        abbreviation: "Mon",
+        strokes: &[
+            &[
+                (6.248, -6.27),
+                (6.480, -7.03),
+                (7.198, -0.49),
+                (7.687, -9.55),
+            ],
+            &[(7.198, -0.49), (8.143, -2.98)],
+            &[(7.198, -0.49), (6.799, 2.41), (6.548, 7.33)],
+            &[(6.799, 2.41), (6.396, 4.59)],
+        ],
+    },
+    // λ ε α β δ γ α
+    ConstellationFigure {
+        abbreviation: "Mus",
+        strokes: &[&[
+            (11.760, -66.73),
+            (12.293, -67.96),
+            (12.620, -69.14),
+            (12.771, -68.11),
+            (13.038, -71.55),
+            (12.541, -72.13),
+            (12.620, -69.14),
+        ]],
+    },
+    // γ² ε δ η γ²
+    ConstellationFigure {
+        abbreviation: "Nor",
+        strokes: &[&[
+            (16.330, -50.16),
+            (16.453, -47.55),
+            (16.108, -45.17),
+            (16.054, -49.23),
+            (16.330, -50.16),
+        ]],
+    },
+    // ν β δ ν
+    ConstellationFigure {
+        abbreviation: "Oct",
+        strokes: &[&[
+            (21.691, -77.39),
+            (22.768, -81.38),
+            (14.449, -83.67),
+            (21.691, -77.39),
+        ]],
+    },
+    // α κ δ ε ζ η β α | η θ
+    ConstellationFigure {
+        abbreviation: "Oph",
+        strokes: &[
+            &[
+                (17.582, 12.56),
+                (16.961, 9.38),
+                (16.239, -3.69),
+                (16.305, -4.69),
+                (16.619, -10.57),
+                (17.173, -15.72),
+                (17.725, 4.57),
+                (17.582, 12.56),
+            ],
+            &[(17.173, -15.72), (17.367, -25.00)],
+        ],
+    },
+    // α ζ ε δ γ | ζ κ | δ β | α λ γ | γ π³ | π¹ π² π³ π⁴ π⁵ | α μ ν χ¹
+    ConstellationFigure {
+        abbreviation: "Ori",
+        strokes: &[
+            &[
+                (5.919, 7.41),
+                (5.679, -1.94),
+                (5.604, -1.20),
+                (5.533, -0.30),
+                (5.419, 6.35),
+            ],
+            &[(5.679, -1.94), (5.796, -9.67)],
+            &[(5.533, -0.30), (5.242, -8.20)],
+            &[(5.919, 7.41), (5.585, 9.93), (5.419, 6.35)],
+            &[(5.419, 6.35), (4.830, 6.96)],
+            &[
+                (4.910, 10.15),
+                (4.843, 8.90),
+                (4.830, 6.96),
+                (4.853, 5.61),
+                (4.904, 2.44),
+            ],
+            &[(5.919, 7.41), (6.040, 9.65), (6.126, 14.77), (5.907, 20.28)],
+        ],
+    },
+    // α β γ | β δ ε ζ | δ κ λ ξ π η
+    ConstellationFigure {
+        abbreviation: "Pav",
+        strokes: &[
+            &[(20.427, -56.74), (20.749, -66.20), (21.441, -65.37)],
+            &[
+                (20.749, -66.20),
+                (20.145, -66.18),
+                (20.010, -72.91),
+                (18.717, -71.43),
+            ],
+            &[
+                (20.145, -66.18),
+                (18.945, -67.23),
+                (18.870, -62.19),
+                (18.386, -61.49),
+                (18.387, -63.67),
+                (17.762, -64.72),
+            ],
+        ],
+    },
+    // α β α-And γ α | α ζ θ ε | β η π | β μ λ ι κ
+    ConstellationFigure {
+        abbreviation: "Peg",
+        strokes: &[
+            &[
+                (23.079, 15.21),
+                (23.063, 28.08),
+                (0.140, 29.09),
+                (0.220, 15.18),
+                (23.079, 15.21),
+            ],
+            &[
+                (23.079, 15.21),
+                (22.691, 10.83),
+                (22.170, 6.20),
+                (21.736, 9.88),
+            ],
+            &[(23.063, 28.08), (22.717, 30.22), (22.166, 33.18)],
+            &[
+                (23.063, 28.08),
+                (22.833, 24.60),
+                (22.776, 23.57),
+                (22.117, 25.35),
+                (21.744, 25.65),
+            ],
+        ],
+    },
+    // η τ ι α δ ν ε ξ ζ | γ α | α κ β ρ | δ μ
+    ConstellationFigure {
+        abbreviation: "Per",
+        strokes: &[
+            &[
+                (2.845, 55.90),
+                (2.905, 52.76),
+                (3.151, 49.61),
+                (3.405, 49.86),
+                (3.715, 47.79),
+                (3.753, 42.58),
+                (3.964, 40.01),
+                (3.983, 35.79),
+                (3.902, 31.88),
+            ],
+            &[(3.080, 53.51), (3.405, 49.86)],
+            &[
+                (3.405, 49.86),
+                (3.158, 44.86),
+                (3.136, 40.96),
+                (3.086, 38.84),
+            ],
+            &[(3.715, 47.79), (4.248, 48.41)],
+        ],
+    },
+    // ε α β γ | β δ | β ζ
+    ConstellationFigure {
+        abbreviation: "Phe",
+        strokes: &[
+            &[
+                (0.157, -45.75),
+                (0.438, -42.31),
+                (1.101, -46.72),
+                (1.473, -43.32),
+            ],
+            &[(1.101, -46.72), (1.521, -49.07)],
+            &[(1.101, -46.72), (1.140, -55.25)],
+        ],
+    },
+    // α γ β
+    ConstellationFigure {
+        abbreviation: "Pic",
+        strokes: &[&[(6.803, -61.94), (5.830, -56.17), (5.788, -51.07)]],
+    },
+    // γ κ λ ι θ γ | ι ω δ ε ζ μ ν ξ α | α ο η χ φ υ τ
+    ConstellationFigure {
+        abbreviation: "Psc",
+        strokes: &[
+            &[
+                (23.286, 3.28),
+                (23.449, 1.26),
+                (23.700, 1.78),
+                (23.666, 5.63),
+                (23.466, 6.38),
+                (23.286, 3.28),
+            ],
+            &[
+                (23.666, 5.63),
+                (23.988, 6.86),
+                (0.811, 7.59),
+                (1.049, 7.89),
+                (1.229, 7.58),
+                (1.503, 6.14),
+                (1.691, 5.49),
+                (1.892, 3.19),
+                (2.034, 2.76),
+            ],
+            &[
+                (2.034, 2.76),
+                (1.756, 9.16),
+                (1.525, 15.35),
+                (1.190, 21.03),
+                (1.229, 24.58),
+                (1.325, 27.26),
+                (1.194, 30.09),
+            ],
+        ],
+    },
+    // α δ γ β μ ι | α ε
+    ConstellationFigure {
+        abbreviation: "PsA",
+        strokes: &[
+            &[
+                (22.961, -29.62),
+                (22.932, -32.54),
+                (22.875, -32.88),
+                (22.525, -32.35),
+                (22.140, -32.99),
+                (21.749, -33.03),
+            ],
+            &[(22.961, -29.62), (22.678, -27.04)],
+        ],
+    },
+    // ξ ρ ζ σ τ ν π ξ
+    ConstellationFigure {
+        abbreviation: "Pup",
+        strokes: &[&[
+            (7.822, -24.86),
+            (8.126, -24.30),
+            (8.060, -40.00),
+            (7.487, -43.30),
+            (6.832, -50.61),
+            (6.629, -43.20),
+            (7.286, -37.10),
+            (7.822, -24.86),
+        ]],
+    },
+    // β α γ
+    ConstellationFigure {
+        abbreviation: "Pyx",
+        strokes: &[&[(8.668, -35.31), (8.727, -33.19), (8.842, -27.71)]],
+    },
+    // α β δ ε α
+    ConstellationFigure {
+        abbreviation: "Ret",
+        strokes: &[&[
+            (4.240, -62.47),
+            (3.737, -64.81),
+            (3.979, -61.40),
+            (4.275, -59.30),
+            (4.240, -62.47),
+        ]],
+    },
+    // α δ γ | β δ
+    ConstellationFigure {
+        abbreviation: "Sge",
+        strokes: &[
+            &[(19.668, 18.01), (19.790, 18.53), (19.979, 19.49)],
+            &[(19.684, 17.48), (19.790, 18.53)],
+        ],
+    },
+    // γ δ λ φ σ τ ζ ε γ | δ ε | φ ζ | λ μ | ε η
+    ConstellationFigure {
+        abbreviation: "Sgr",
+        strokes: &[
+            &[
+                (18.097, -30.42),
+                (18.350, -29.83),
+                (18.466, -25.42),
+                (18.761, -26.99),
+                (18.921, -26.30),
+                (19.116, -27.67),
+                (19.044, -29.88),
+                (18.403, -34.38),
+                (18.097, -30.42),
+            ],
+            &[(18.350, -29.83), (18.403, -34.38)],
+            &[(18.761, -26.99), (19.044, -29.88)],
+            &[(18.466, -25.42), (18.229, -21.06)],
+            &[(18.403, -34.38), (18.294, -36.76)],
+        ],
+    },
+    // β δ π ρ | δ σ α τ ε μ¹ ζ² η θ ι¹ κ λ
+    ConstellationFigure {
+        abbreviation: "Sco",
+        strokes: &[
+            &[
+                (16.091, -19.81),
+                (16.006, -22.62),
+                (15.981, -26.11),
+                (15.948, -29.21),
+            ],
+            &[
+                (16.006, -22.62),
+                (16.353, -25.59),
+                (16.490, -26.43),
+                (16.598, -28.22),
+                (16.836, -34.29),
+                (16.864, -38.05),
+                (16.910, -42.36),
+                (17.203, -43.24),
+                (17.622, -43.00),
+                (17.793, -40.13),
+                (17.708, -39.03),
+                (17.560, -37.10),
+            ],
+        ],
+    },
+    // α δ γ β
+    ConstellationFigure {
+        abbreviation: "Scl",
+        strokes: &[&[
+            (0.977, -29.36),
+            (23.815, -28.13),
+            (23.314, -32.53),
+            (23.550, -37.82),
+        ]],
+    },
+    // α β δ γ α
+    ConstellationFigure {
+        abbreviation: "Sct",
+        strokes: &[&[
+            (18.587, -8.24),
+            (18.786, -4.75),
+            (18.704, -9.05),
+            (18.487, -14.57),
+            (18.587, -8.24),
+        ]],
+    },
+    // μ ε α δ β ι κ γ β | ν ξ η θ
+    ConstellationFigure {
+        abbreviation: "Ser",
+        strokes: &[
+            &[
+                (15.827, -3.43),
+                (15.847, 4.48),
+                (15.738, 6.43),
+                (15.580, 10.54),
+                (15.770, 15.42),
+                (15.693, 19.67),
+                (15.813, 18.14),
+                (15.940, 15.66),
+                (15.770, 15.42),
+            ],
+            &[
+                (17.347, -12.85),
+                (17.627, -15.40),
+                (18.355, -2.90),
+                (18.937, 4.20),
+            ],
+        ],
+    },
+    // γ α β
+    ConstellationFigure {
+        abbreviation: "Sex",
+        strokes: &[&[(9.875, -8.11), (10.132, -0.37), (10.505, -0.64)]],
+    },
+    // ζ α θ² γ δ¹ ε β | γ λ ο
+    ConstellationFigure {
+        abbreviation: "Tau",
+        strokes: &[
+            &[
+                (5.627, 21.14),
+                (4.599, 16.51),
+                (4.478, 15.87),
+                (4.330, 15.63),
+                (4.382, 17.54),
+                (4.477, 19.18),
+                (5.438, 28.61),
+            ],
+            &[(4.330, 15.63), (4.011, 12.49), (3.414, 9.03)],
+        ],
+    },
+    // ε α ζ
+    ConstellationFigure {
+        abbreviation: "Tel",
+        strokes: &[&[(18.187, -45.95), (18.450, -45.97), (18.481, -49.07)]],
+    },
+    // α β γ α
+    ConstellationFigure {
+        abbreviation: "Tri",
+        strokes: &[&[
+            (1.885, 29.58),
+            (2.159, 34.99),
+            (2.289, 33.85),
+            (1.885, 29.58),
+        ]],
+    },
+    // α β γ α
+    ConstellationFigure {
+        abbreviation: "TrA",
+        strokes: &[&[
+            (16.811, -69.03),
+            (15.919, -63.43),
+            (15.315, -68.68),
+            (16.811, -69.03),
+        ]],
+    },
+    // α γ β ζ ε γ
+    ConstellationFigure {
+        abbreviation: "Tuc",
+        strokes: &[&[
+            (22.308, -60.26),
+            (23.290, -58.24),
+            (0.526, -62.96),
+            (0.335, -64.87),
+            (23.998, -65.58),
+            (23.290, -58.24),
+        ]],
+    },
+    // η ζ ε δ α β γ δ | α υ ο | υ θ κ | γ χ ψ μ λ | χ ν ξ
+    ConstellationFigure {
+        abbreviation: "UMa",
+        strokes: &[
+            &[
+                (13.792, 49.31),
+                (13.399, 54.93),
+                (12.900, 55.96),
+                (12.257, 57.03),
+                (11.062, 61.75),
+                (11.031, 56.38),
+                (11.897, 53.69),
+                (12.257, 57.03),
+            ],
+            &[(11.062, 61.75), (9.849, 59.04), (8.504, 60.72)],
+            &[(9.849, 59.04), (9.548, 51.68), (9.060, 47.16)],
+            &[
+                (11.897, 53.69),
+                (11.768, 47.78),
+                (11.161, 44.50),
+                (10.372, 41.50),
+                (10.285, 42.91),
+            ],
+            &[(11.768, 47.78), (11.308, 33.09), (11.303, 31.53)],
+        ],
+    },
+    // α δ ε ζ β γ η ζ
+    ConstellationFigure {
+        abbreviation: "UMi",
+        strokes: &[&[
+            (2.530, 89.26),
+            (17.537, 86.59),
+            (16.766, 82.04),
+            (15.734, 77.79),
+            (14.845, 74.16),
+            (15.345, 71.83),
+            (16.292, 75.76),
+            (15.734, 77.79),
+        ]],
+    },
+    // γ² δ κ φ μ ψ λ γ²
+    ConstellationFigure {
+        abbreviation: "Vel",
+        strokes: &[&[
+            (8.159, -47.34),
+            (8.745, -54.71),
+            (9.368, -55.01),
+            (9.948, -54.57),
+            (10.779, -49.42),
+            (9.511, -40.47),
+            (9.133, -43.43),
+            (8.159, -47.34),
+        ]],
+    },
+    // β η γ δ ε | γ θ α ζ δ | ζ τ 109
+    ConstellationFigure {
+        abbreviation: "Vir",
+        strokes: &[
+            &[
+                (11.845, 1.76),
+                (12.332, -0.67),
+                (12.694, -1.45),
+                (12.927, 3.40),
+                (13.036, 10.96),
+            ],
+            &[
+                (12.694, -1.45),
+                (13.165, -5.54),
+                (13.420, -11.16),
+                (13.578, -0.60),
+                (12.927, 3.40),
+            ],
+            &[(13.578, -0.60), (14.028, 1.54), (14.770, 1.89)],
+        ],
+    },
+    // α β ε δ γ² ζ ε
+    ConstellationFigure {
+        abbreviation: "Vol",
+        strokes: &[&[
+            (9.041, -66.40),
+            (8.429, -66.14),
+            (8.132, -68.62),
+            (7.281, -67.96),
+            (7.145, -70.50),
+            (7.697, -72.61),
+            (8.132, -68.62),
+        ]],
+    },
+    // 1 α 13 23
+    ConstellationFigure {
+        abbreviation: "Vul",
+        strokes: &[&[
+            (19.270, 21.39),
+            (19.478, 24.67),
+            (19.891, 24.08),
+            (20.263, 27.81),
+        ]],
+    },
+];
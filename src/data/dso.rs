@@ -0,0 +1,489 @@
+//! Deep-sky objects
+//!
+//! The 110 objects of Charles Messier's catalog: the galaxies, star
+//! clusters and nebulae a small telescope or binoculars show best.
+//! Positions are J2000, to a tenth of a minute of RA and a minute of
+//! arc; magnitudes are visual, for the whole object.
+
+use super::nearest::angular_separation;
+use super::CelestialCoord;
+
+/// What a deep-sky object is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DsoKind {
+    Galaxy,
+    GlobularCluster,
+    OpenCluster,
+    /// An emission or reflection nebula
+    Nebula,
+    PlanetaryNebula,
+    SupernovaRemnant,
+    /// A star cloud, double star or chance grouping of stars
+    Asterism,
+}
+
+impl DsoKind {
+    pub const ALL: [DsoKind; 7] = [
+        DsoKind::Galaxy,
+        DsoKind::GlobularCluster,
+        DsoKind::OpenCluster,
+        DsoKind::Nebula,
+        DsoKind::PlanetaryNebula,
+        DsoKind::SupernovaRemnant,
+        DsoKind::Asterism,
+    ];
+
+    /// CSS class of the kind's map glyph
+    pub fn class_name(self) -> &'static str {
+        match self {
+            DsoKind::Galaxy => "galaxy",
+            DsoKind::GlobularCluster => "globular-cluster",
+            DsoKind::OpenCluster => "open-cluster",
+            DsoKind::Nebula => "nebula",
+            DsoKind::PlanetaryNebula => "planetary-nebula",
+            DsoKind::SupernovaRemnant => "supernova-remnant",
+            DsoKind::Asterism => "asterism",
+        }
+    }
+}
+
+/// An object of the Messier catalog
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeepSkyObject {
+    /// Number in the catalog, 1 to 110
+    pub messier: u8,
+    pub kind: DsoKind,
+    pub coord: CelestialCoord,
+    /// Visual magnitude of the whole object
+    pub magnitude: f64,
+    /// IAU abbreviation of the constellation it lies in
+    pub constellation: &'static str,
+    /// Common name, for the objects that have one
+    pub name: Option<&'static str>,
+}
+
+impl DeepSkyObject {
+    /// Catalog designation, such as "M42"
+    pub fn designation(&self) -> String {
+        format!("M{}", self.messier)
+    }
+
+    /// Designation and common name, such as "M42 Orion Nebula"
+    pub fn display_name(&self) -> String {
+        match self.name {
+            Some(name) => format!("{} {}", self.designation(), name),
+            None => self.designation(),
+        }
+    }
+}
+
+const fn m(
+    messier: u8,
+    kind: DsoKind,
+    ra: f64,
+    dec: f64,
+    magnitude: f64,
+    constellation: &'static str,
+    name: Option<&'static str>,
+) -> DeepSkyObject {
+    DeepSkyObject {
+        messier,
+        kind,
+        coord: CelestialCoord { ra, dec },
+        magnitude,
+        constellation,
+        name,
+    }
+}
+
+use DsoKind::{
+    Asterism, Galaxy, GlobularCluster, Nebula, OpenCluster, PlanetaryNebula, SupernovaRemnant,
+};
+
+/// The Messier catalog, in order of number
+pub static MESSIER: [DeepSkyObject; 110] = [
+    m(
+        1,
+        SupernovaRemnant,
+        5.575,
+        22.017,
+        8.4,
+        "Tau",
+        Some("Crab Nebula"),
+    ),
+    m(2, GlobularCluster, 21.558, -0.817, 6.5, "Aqr", None),
+    m(3, GlobularCluster, 13.703, 28.383, 6.2, "CVn", None),
+    m(4, GlobularCluster, 16.393, -26.533, 5.6, "Sco", None),
+    m(5, GlobularCluster, 15.310, 2.083, 5.6, "Ser", None),
+    m(
+        6,
+        OpenCluster,
+        17.668,
+        -32.217,
+        4.2,
+        "Sco",
+        Some("Butterfly Cluster"),
+    ),
+    m(
+        7,
+        OpenCluster,
+        17.898,
+        -34.817,
+        3.3,
+        "Sco",
+        Some("Ptolemy Cluster"),
+    ),
+    m(
+        8,
+        Nebula,
+        18.063,
+        -24.383,
+        6.0,
+        "Sgr",
+        Some("Lagoon Nebula"),
+    ),
+    m(9, GlobularCluster, 17.320, -18.517, 7.7, "Oph", None),
+    m(10, GlobularCluster, 16.952, -4.100, 6.6, "Oph", None),
+    m(
+        11,
+        OpenCluster,
+        18.852,
+        -6.267,
+        5.8,
+        "Sct",
+        Some("Wild Duck Cluster"),
+    ),
+    m(12, GlobularCluster, 16.787, -1.950, 6.7, "Oph", None),
+    m(
+        13,
+        GlobularCluster,
+        16.695,
+        36.467,
+        5.8,
+        "Her",
+        Some("Hercules Cluster"),
+    ),
+    m(14, GlobularCluster, 17.627, -3.250, 7.6, "Oph", None),
+    m(15, GlobularCluster, 21.500, 12.167, 6.2, "Peg", None),
+    m(
+        16,
+        Nebula,
+        18.313,
+        -13.783,
+        6.0,
+        "Ser",
+        Some("Eagle Nebula"),
+    ),
+    m(
+        17,
+        Nebula,
+        18.347,
+        -16.183,
+        6.0,
+        "Sgr",
+        Some("Omega Nebula"),
+    ),
+    m(18, OpenCluster, 18.332, -17.133, 7.5, "Sgr", None),
+    m(19, GlobularCluster, 17.043, -26.267, 6.8, "Oph", None),
+    m(
+        20,
+        Nebula,
+        18.043,
+        -23.033,
+        6.3,
+        "Sgr",
+        Some("Trifid Nebula"),
+    ),
+    m(21, OpenCluster, 18.077, -22.500, 6.5, "Sgr", None),
+    m(22, GlobularCluster, 18.607, -23.900, 5.1, "Sgr", None),
+    m(23, OpenCluster, 17.947, -19.017, 6.9, "Sgr", None),
+    m(
+        24,
+        Asterism,
+        18.282,
+        -18.483,
+        4.6,
+        "Sgr",
+        Some("Sagittarius Star Cloud"),
+    ),
+    m(25, OpenCluster, 18.527, -19.250, 4.6, "Sgr", None),
+    m(26, OpenCluster, 18.753, -9.400, 8.0, "Sct", None),
+    m(
+        27,
+        PlanetaryNebula,
+        19.993,
+        22.717,
+        7.5,
+        "Vul",
+        Some("Dumbbell Nebula"),
+    ),
+    m(28, GlobularCluster, 18.408, -24.867, 6.8, "Sgr", None),
+    m(29, OpenCluster, 20.398, 38.517, 7.1, "Cyg", None),
+    m(30, GlobularCluster, 21.673, -23.183, 7.2, "Cap", None),
+    m(
+        31,
+        Galaxy,
+        0.712,
+        41.267,
+        3.4,
+        "And",
+        Some("Andromeda Galaxy"),
+    ),
+    m(32, Galaxy, 0.712, 40.867, 8.1, "And", None),
+    m(
+        33,
+        Galaxy,
+        1.565,
+        30.650,
+        5.7,
+        "Tri",
+        Some("Triangulum Galaxy"),
+    ),
+    m(34, OpenCluster, 2.700, 42.783, 5.5, "Per", None),
+    m(35, OpenCluster, 6.148, 24.333, 5.3, "Gem", None),
+    m(36, OpenCluster, 5.602, 34.133, 6.3, "Aur", None),
+    m(37, OpenCluster, 5.873, 32.550, 6.2, "Aur", None),
+    m(38, OpenCluster, 5.478, 35.833, 7.4, "Aur", None),
+    m(39, OpenCluster, 21.537, 48.433, 4.6, "Cyg", None),
+    m(40, Asterism, 12.373, 58.083, 8.4, "UMa", Some("Winnecke 4")),
+    m(41, OpenCluster, 6.767, -20.733, 4.5, "CMa", None),
+    m(42, Nebula, 5.590, -5.450, 4.0, "Ori", Some("Orion Nebula")),
+    m(
+        43,
+        Nebula,
+        5.593,
+        -5.267,
+        9.0,
+        "Ori",
+        Some("De Mairan's Nebula"),
+    ),
+    m(
+        44,
+        OpenCluster,
+        8.668,
+        19.983,
+        3.7,
+        "Cnc",
+        Some("Beehive Cluster"),
+    ),
+    m(45, OpenCluster, 3.783, 24.117, 1.6, "Tau", Some("Pleiades")),
+    m(46, OpenCluster, 7.697, -14.817, 6.1, "Pup", None),
+    m(47, OpenCluster, 7.610, -14.500, 4.2, "Pup", None),
+    m(48, OpenCluster, 8.230, -5.800, 5.5, "Hya", None),
+    m(49, Galaxy, 12.497, 8.000, 8.4, "Vir", None),
+    m(50, OpenCluster, 7.053, -8.333, 5.9, "Mon", None),
+    m(
+        51,
+        Galaxy,
+        13.498,
+        47.200,
+        8.4,
+        "CVn",
+        Some("Whirlpool Galaxy"),
+    ),
+    m(52, OpenCluster, 23.403, 61.583, 7.3, "Cas", None),
+    m(53, GlobularCluster, 13.215, 18.167, 7.6, "Com", None),
+    m(54, GlobularCluster, 18.918, -30.483, 7.6, "Sgr", None),
+    m(55, GlobularCluster, 19.667, -30.967, 6.3, "Sgr", None),
+    m(56, GlobularCluster, 19.277, 30.183, 8.3, "Lyr", None),
+    m(
+        57,
+        PlanetaryNebula,
+        18.893,
+        33.033,
+        8.8,
+        "Lyr",
+        Some("Ring Nebula"),
+    ),
+    m(58, Galaxy, 12.628, 11.817, 9.7, "Vir", None),
+    m(59, Galaxy, 12.700, 11.650, 9.6, "Vir", None),
+    m(60, Galaxy, 12.728, 11.550, 8.8, "Vir", None),
+    m(61, Galaxy, 12.365, 4.467, 9.7, "Vir", None),
+    m(62, GlobularCluster, 17.020, -30.117, 6.5, "Oph", None),
+    m(
+        63,
+        Galaxy,
+        13.263,
+        42.033,
+        8.6,
+        "CVn",
+        Some("Sunflower Galaxy"),
+    ),
+    m(
+        64,
+        Galaxy,
+        12.945,
+        21.683,
+        8.5,
+        "Com",
+        Some("Black Eye Galaxy"),
+    ),
+    m(65, Galaxy, 11.315, 13.083, 9.3, "Leo", None),
+    m(66, Galaxy, 11.337, 12.983, 8.9, "Leo", None),
+    m(67, OpenCluster, 8.855, 11.817, 6.1, "Cnc", None),
+    m(68, GlobularCluster, 12.658, -26.750, 7.8, "Hya", None),
+    m(69, GlobularCluster, 18.523, -32.350, 7.6, "Sgr", None),
+    m(70, GlobularCluster, 18.720, -32.300, 7.9, "Sgr", None),
+    m(71, GlobularCluster, 19.897, 18.783, 6.1, "Sge", None),
+    m(72, GlobularCluster, 20.892, -12.533, 9.3, "Aqr", None),
+    m(73, Asterism, 20.982, -12.633, 9.0, "Aqr", None),
+    m(
+        74,
+        Galaxy,
+        1.612,
+        15.783,
+        9.4,
+        "Psc",
+        Some("Phantom Galaxy"),
+    ),
+    m(75, GlobularCluster, 20.102, -21.917, 8.5, "Sgr", None),
+    m(
+        76,
+        PlanetaryNebula,
+        1.707,
+        51.567,
+        10.1,
+        "Per",
+        Some("Little Dumbbell Nebula"),
+    ),
+    m(77, Galaxy, 2.712, -0.017, 8.9, "Cet", None),
+    m(78, Nebula, 5.778, 0.050, 8.3, "Ori", None),
+    m(79, GlobularCluster, 5.408, -24.550, 7.7, "Lep", None),
+    m(80, GlobularCluster, 16.283, -22.983, 7.3, "Sco", None),
+    m(81, Galaxy, 9.927, 69.067, 6.9, "UMa", Some("Bode's Galaxy")),
+    m(82, Galaxy, 9.930, 69.683, 8.4, "UMa", Some("Cigar Galaxy")),
+    m(
+        83,
+        Galaxy,
+        13.617,
+        -29.867,
+        7.5,
+        "Hya",
+        Some("Southern Pinwheel Galaxy"),
+    ),
+    m(84, Galaxy, 12.418, 12.883, 9.1, "Vir", None),
+    m(85, Galaxy, 12.423, 18.183, 9.1, "Com", None),
+    m(86, Galaxy, 12.437, 12.950, 8.9, "Vir", None),
+    m(87, Galaxy, 12.513, 12.383, 8.6, "Vir", Some("Virgo A")),
+    m(88, Galaxy, 12.533, 14.417, 9.6, "Com", None),
+    m(89, Galaxy, 12.595, 12.550, 9.8, "Vir", None),
+    m(90, Galaxy, 12.613, 13.167, 9.5, "Vir", None),
+    m(91, Galaxy, 12.590, 14.500, 10.2, "Com", None),
+    m(92, GlobularCluster, 17.285, 43.133, 6.4, "Her", None),
+    m(93, OpenCluster, 7.743, -23.867, 6.0, "Pup", None),
+    m(94, Galaxy, 12.848, 41.117, 8.2, "CVn", None),
+    m(95, Galaxy, 10.733, 11.700, 9.7, "Leo", None),
+    m(96, Galaxy, 10.780, 11.817, 9.2, "Leo", None),
+    m(
+        97,
+        PlanetaryNebula,
+        11.247,
+        55.017,
+        9.9,
+        "UMa",
+        Some("Owl Nebula"),
+    ),
+    m(98, Galaxy, 12.230, 14.900, 10.1, "Com", None),
+    m(99, Galaxy, 12.313, 14.417, 9.9, "Com", None),
+    m(100, Galaxy, 12.382, 15.817, 9.3, "Com", None),
+    m(
+        101,
+        Galaxy,
+        14.053,
+        54.350,
+        7.9,
+        "UMa",
+        Some("Pinwheel Galaxy"),
+    ),
+    m(
+        102,
+        Galaxy,
+        15.108,
+        55.767,
+        9.9,
+        "Dra",
+        Some("Spindle Galaxy"),
+    ),
+    m(103, OpenCluster, 1.553, 60.700, 7.4, "Cas", None),
+    m(
+        104,
+        Galaxy,
+        12.667,
+        -11.617,
+        8.0,
+        "Vir",
+        Some("Sombrero Galaxy"),
+    ),
+    m(105, Galaxy, 10.797, 12.583, 9.3, "Leo", None),
+    m(106, Galaxy, 12.317, 47.300, 8.4, "CVn", None),
+    m(107, GlobularCluster, 16.542, -13.050, 7.9, "Oph", None),
+    m(108, Galaxy, 11.192, 55.667, 10.0, "UMa", None),
+    m(109, Galaxy, 11.960, 53.383, 9.8, "UMa", None),
+    m(110, Galaxy, 0.673, 41.683, 8.5, "And", None),
+];
+
+/// Messier object `number`, if there is one
+pub fn messier(number: u8) -> Option<&'static DeepSkyObject> {
+    MESSIER.get(usize::from(number).checked_sub(1)?)
+}
+
+/// The `count` objects nearest `object` on the sky, nearest first,
+/// leaving out `object` itself
+pub fn nearest(object: &DeepSkyObject, count: usize) -> Vec<&'static DeepSkyObject> {
+    let mut others: Vec<(&DeepSkyObject, f64)> = MESSIER
+        .iter()
+        .filter(|other| other.messier != object.messier)
+        .map(|other| (other, angular_separation(&object.coord, &other.coord)))
+        .collect();
+    others.sort_by(|a, b| a.1.total_cmp(&b.1));
+    others.truncate(count);
+    others.into_iter().map(|(other, _)| other).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalog_is_numbered_in_order() {
+        for (i, object) in MESSIER.iter().enumerate() {
+            assert_eq!(usize::from(object.messier), i + 1);
+            assert!((0.0..24.0).contains(&object.coord.ra), "{:?}", object);
+            assert!((-90.0..=90.0).contains(&object.coord.dec), "{:?}", object);
+            assert_eq!(object.constellation.len(), 3, "{:?}", object);
+        }
+        assert_eq!(messier(0), None);
+        assert_eq!(messier(111), None);
+    }
+
+    #[test]
+    fn test_well_known_objects() {
+        let orion = messier(42).unwrap();
+        assert_eq!(orion.display_name(), "M42 Orion Nebula");
+        assert_eq!(orion.kind, DsoKind::Nebula);
+        assert_eq!(orion.constellation, "Ori");
+        assert_eq!(messier(13).unwrap().kind, DsoKind::GlobularCluster);
+        assert_eq!(messier(2).unwrap().display_name(), "M2");
+        // Every kind has at least one member
+        for kind in DsoKind::ALL {
+            assert!(
+                MESSIER.iter().any(|object| object.kind == kind),
+                "{:?}",
+                kind
+            );
+        }
+    }
+
+    #[test]
+    fn test_nearest() {
+        let orion = messier(42).unwrap();
+        let near = nearest(orion, 2);
+        assert_eq!(near[0].messier, 43);
+        assert_eq!(near[1].messier, 78);
+        // Andromeda's companions are the closest to it
+        let andromeda = messier(31).unwrap();
+        let mut companions: Vec<u8> = nearest(andromeda, 2).iter().map(|o| o.messier).collect();
+        companions.sort();
+        assert_eq!(companions, [32, 110]);
+    }
+}
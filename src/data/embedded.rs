@@ -0,0 +1,52 @@
+//! Catalog baked in at build time
+//!
+//! With the `embedded-catalog` feature the build script converts the
+//! catalog JSON to the binary format and it is included here, so offline
+//! builds need neither the network nor the filesystem. Without the
+//! feature there is no embedded catalog.
+
+use super::StarCatalog;
+
+#[cfg(feature = "embedded-catalog")]
+use super::formats::{decode_stars, CatalogFormat};
+
+#[cfg(feature = "embedded-catalog")]
+const EMBEDDED: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/catalog.bin"));
+
+/// The embedded catalog, if this build has one
+///
+/// A catalog that fails to decode is reported and treated as absent, so
+/// callers fall back to loading one.
+pub fn embedded_catalog() -> Option<StarCatalog> {
+    #[cfg(feature = "embedded-catalog")]
+    {
+        match decode_stars(EMBEDDED, CatalogFormat::Binary) {
+            Ok(stars) if !stars.is_empty() => {
+                let mut catalog = StarCatalog::new();
+                for star in stars {
+                    catalog.add_star(star);
+                }
+                catalog.rebuild_indices();
+                return Some(catalog);
+            }
+            Ok(_) => log::warn!("The embedded star catalog is empty"),
+            Err(e) => log::warn!("Could not decode the embedded star catalog: {}", e),
+        }
+    }
+    None
+}
+
+#[cfg(all(test, feature = "embedded-catalog"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedded_catalog_matches_source() {
+        let source = std::fs::read(env!("STARGAZER_EMBEDDED_SOURCE")).unwrap();
+        let expected = decode_stars(&source, CatalogFormat::Json).unwrap();
+
+        let catalog = embedded_catalog().expect("catalog was embedded");
+        assert_eq!(catalog.count(), expected.len());
+        assert_eq!(catalog.get(expected[0].id), Some(&expected[0]));
+    }
+}
@@ -0,0 +1,547 @@
+//! Facts about the bright named stars
+//!
+//! Designations, spectra, distances and luminosities of the 65 brightest
+//! named stars, in one table looked up by proper name. Star records are
+//! defined with the catalog format and carry none of these, so they live
+//! here, and a catalog imported from HYG keeps the facts its rows give in
+//! a [`CatalogFacts`] beside its stars.
+//!
+//! Bayer labeled stars by constellation with a Greek letter, roughly in
+//! order of brightness (α Ori), and Flamsteed with a number in order of
+//! right ascension (58 Ori); Flamsteed numbered only the stars visible
+//! from England, so far southern stars have none. The B−V color index
+//! runs from about −0.3 for the hottest, blue-white stars to 2 for the
+//! coolest, orange-red ones. For a double or multiple star the figures
+//! are for its brightest member, and distances to the far supergiants
+//! are uncertain by a quarter or more.
+
+use super::{Star, StarId};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Light-years in a parsec
+pub const LIGHT_YEARS_PER_PARSEC: f64 = 3.26156;
+
+/// What is known about a named star
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StarFacts {
+    /// Proper name, as in the catalog
+    pub name: &'static str,
+    /// Greek letter of the Bayer designation
+    pub bayer: &'static str,
+    /// Flamsteed number, for stars Flamsteed cataloged
+    pub flamsteed: Option<u16>,
+    /// IAU abbreviation of the constellation
+    pub constellation: &'static str,
+    /// MK spectral type, such as "M1-2Ia-Iab"
+    pub spectral_type: &'static str,
+    /// B−V color index in magnitudes
+    pub color_index: f64,
+    /// Distance in light-years
+    pub distance_ly: f64,
+    /// Luminosity in multiples of the Sun's
+    pub luminosity: f64,
+}
+
+impl StarFacts {
+    /// Bayer designation, such as "α Ori"
+    pub fn bayer_designation(&self) -> String {
+        format!("{} {}", self.bayer, self.constellation)
+    }
+
+    /// Flamsteed designation, such as "58 Ori"
+    pub fn flamsteed_designation(&self) -> Option<String> {
+        self.flamsteed
+            .map(|number| format!("{} {}", number, self.constellation))
+    }
+
+    /// Every designation, such as "α Ori, 58 Ori"
+    pub fn designations(&self) -> String {
+        let mut text = self.bayer_designation();
+        if let Some(flamsteed) = self.flamsteed_designation() {
+            text.push_str(", ");
+            text.push_str(&flamsteed);
+        }
+        text
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+const fn f(
+    name: &'static str,
+    bayer: &'static str,
+    flamsteed: Option<u16>,
+    constellation: &'static str,
+    spectral_type: &'static str,
+    color_index: f64,
+    distance_ly: f64,
+    luminosity: f64,
+) -> StarFacts {
+    StarFacts {
+        name,
+        bayer,
+        flamsteed,
+        constellation,
+        spectral_type,
+        color_index,
+        distance_ly,
+        luminosity,
+    }
+}
+
+/// Facts about the bright named stars, sorted by name
+pub static FACTS: [StarFacts; 65] = [
+    f("Achernar", "α", None, "Eri", "B6Vep", -0.16, 139.0, 3150.0),
+    f("Acrux", "α", None, "Cru", "B0.5IV", -0.24, 321.0, 25_000.0),
+    f(
+        "Adhara",
+        "ε",
+        Some(21),
+        "CMa",
+        "B2II",
+        -0.21,
+        430.0,
+        38_700.0,
+    ),
+    f("Albireo", "β", Some(6), "Cyg", "K3II", 1.13, 430.0, 1200.0),
+    f(
+        "Aldebaran",
+        "α",
+        Some(87),
+        "Tau",
+        "K5III",
+        1.54,
+        65.0,
+        439.0,
+    ),
+    f("Algieba", "γ", Some(41), "Leo", "K0III", 1.13, 130.0, 180.0),
+    f("Algol", "β", Some(26), "Per", "B8V", -0.05, 90.0, 98.0),
+    f("Alhena", "γ", Some(24), "Gem", "A1IV", 0.00, 109.0, 123.0),
+    f("Alioth", "ε", Some(77), "UMa", "A1III", 0.02, 83.0, 102.0),
+    f("Alkaid", "η", Some(85), "UMa", "B3V", -0.19, 104.0, 594.0),
+    f("Alnair", "α", None, "Gru", "B6V", -0.13, 101.0, 263.0),
+    f(
+        "Alnilam",
+        "ε",
+        Some(46),
+        "Ori",
+        "B0Ia",
+        -0.18,
+        1200.0,
+        420_000.0,
+    ),
+    f(
+        "Alnitak",
+        "ζ",
+        Some(50),
+        "Ori",
+        "O9.5Iab",
+        -0.21,
+        1260.0,
+        250_000.0,
+    ),
+    f(
+        "Alphard",
+        "α",
+        Some(30),
+        "Hya",
+        "K3II-III",
+        1.44,
+        180.0,
+        780.0,
+    ),
+    f(
+        "Alpheratz",
+        "α",
+        Some(21),
+        "And",
+        "B8IV",
+        -0.11,
+        97.0,
+        200.0,
+    ),
+    f("Altair", "α", Some(53), "Aql", "A7V", 0.22, 16.7, 10.6),
+    f(
+        "Antares",
+        "α",
+        Some(21),
+        "Sco",
+        "M1.5Iab",
+        1.83,
+        550.0,
+        75_900.0,
+    ),
+    f(
+        "Arcturus",
+        "α",
+        Some(16),
+        "Boo",
+        "K1.5III",
+        1.23,
+        36.7,
+        170.0,
+    ),
+    f("Atria", "α", None, "TrA", "K2IIb", 1.44, 391.0, 5500.0),
+    f("Avior", "ε", None, "Car", "K3III", 1.28, 610.0, 3550.0),
+    f(
+        "Bellatrix",
+        "γ",
+        Some(24),
+        "Ori",
+        "B2III",
+        -0.22,
+        250.0,
+        9200.0,
+    ),
+    f(
+        "Betelgeuse",
+        "α",
+        Some(58),
+        "Ori",
+        "M1-2Ia-Iab",
+        1.85,
+        550.0,
+        120_000.0,
+    ),
+    f("Canopus", "α", None, "Car", "A9II", 0.15, 310.0, 10_700.0),
+    f("Capella", "α", Some(13), "Aur", "G3III", 0.80, 42.9, 79.0),
+    f("Caph", "β", Some(11), "Cas", "F2III", 0.34, 54.7, 27.0),
+    f("Castor", "α", Some(66), "Gem", "A1V", 0.03, 51.0, 30.0),
+    f(
+        "Deneb",
+        "α",
+        Some(50),
+        "Cyg",
+        "A2Ia",
+        0.09,
+        2600.0,
+        196_000.0,
+    ),
+    f("Denebola", "β", Some(94), "Leo", "A3V", 0.09, 36.0, 15.0),
+    f("Diphda", "β", Some(16), "Cet", "K0III", 1.02, 96.0, 145.0),
+    f("Dubhe", "α", Some(50), "UMa", "K0III", 1.07, 123.0, 316.0),
+    f(
+        "Elnath",
+        "β",
+        Some(112),
+        "Tau",
+        "B7III",
+        -0.13,
+        134.0,
+        700.0,
+    ),
+    f("Enif", "ε", Some(8), "Peg", "K2Ib", 1.52, 690.0, 12_250.0),
+    f("Fomalhaut", "α", Some(24), "PsA", "A3V", 0.09, 25.1, 16.6),
+    f("Gacrux", "γ", None, "Cru", "M3.5III", 1.60, 88.6, 820.0),
+    f("Hadar", "β", None, "Cen", "B1III", -0.23, 390.0, 41_700.0),
+    f("Hamal", "α", Some(13), "Ari", "K2III", 1.15, 66.0, 91.0),
+    f(
+        "Kaus Australis",
+        "ε",
+        Some(20),
+        "Sgr",
+        "B9.5III",
+        -0.03,
+        143.0,
+        375.0,
+    ),
+    f("Kochab", "β", Some(7), "UMi", "K4III", 1.47, 131.0, 390.0),
+    f("Menkalinan", "β", Some(34), "Aur", "A1IV", 0.08, 81.0, 48.0),
+    f("Menkent", "θ", Some(5), "Cen", "K0III", 1.01, 59.0, 50.0),
+    f("Miaplacidus", "β", None, "Car", "A1III", 0.07, 113.0, 288.0),
+    f(
+        "Mimosa", "β", None, "Cru", "B0.5III", -0.24, 280.0, 33_000.0,
+    ),
+    f(
+        "Mintaka",
+        "δ",
+        Some(34),
+        "Ori",
+        "O9.5II",
+        -0.22,
+        1200.0,
+        190_000.0,
+    ),
+    f("Mirach", "β", Some(43), "And", "M0III", 1.58, 197.0, 1995.0),
+    f("Mirfak", "α", Some(33), "Per", "F5Ib", 0.48, 510.0, 5000.0),
+    f(
+        "Mirzam",
+        "β",
+        Some(2),
+        "CMa",
+        "B1II-III",
+        -0.24,
+        490.0,
+        26_600.0,
+    ),
+    f("Mizar", "ζ", Some(79), "UMa", "A2V", 0.02, 83.0, 33.0),
+    f("Nunki", "σ", Some(34), "Sgr", "B2.5V", -0.13, 228.0, 3300.0),
+    f("Peacock", "α", None, "Pav", "B2IV", -0.20, 179.0, 2200.0),
+    f("Polaris", "α", Some(1), "UMi", "F7Ib", 0.60, 433.0, 1260.0),
+    f("Pollux", "β", Some(78), "Gem", "K0III", 1.00, 33.8, 33.0),
+    f("Procyon", "α", Some(10), "CMi", "F5IV-V", 0.42, 11.5, 6.9),
+    f(
+        "Rasalhague",
+        "α",
+        Some(55),
+        "Oph",
+        "A5III",
+        0.15,
+        48.6,
+        25.0,
+    ),
+    f("Regulus", "α", Some(32), "Leo", "B8IVn", -0.11, 79.0, 288.0),
+    f(
+        "Rigel",
+        "β",
+        Some(19),
+        "Ori",
+        "B8Ia",
+        -0.03,
+        860.0,
+        120_000.0,
+    ),
+    f("Rigil Kentaurus", "α", None, "Cen", "G2V", 0.71, 4.37, 1.52),
+    f("Sadr", "γ", Some(37), "Cyg", "F8Ib", 0.67, 1800.0, 33_000.0),
+    f(
+        "Saiph",
+        "κ",
+        Some(53),
+        "Ori",
+        "B0.5Ia",
+        -0.17,
+        650.0,
+        57_000.0,
+    ),
+    f("Sargas", "θ", None, "Sco", "F1II", 0.40, 300.0, 1800.0),
+    f("Schedar", "α", Some(18), "Cas", "K0III", 1.17, 228.0, 676.0),
+    f(
+        "Shaula",
+        "λ",
+        Some(35),
+        "Sco",
+        "B2IV",
+        -0.23,
+        570.0,
+        36_000.0,
+    ),
+    f("Sirius", "α", Some(9), "CMa", "A1V", 0.00, 8.6, 25.4),
+    f(
+        "Spica",
+        "α",
+        Some(67),
+        "Vir",
+        "B1III-IV",
+        -0.23,
+        250.0,
+        20_500.0,
+    ),
+    f("Vega", "α", Some(3), "Lyr", "A0V", 0.00, 25.0, 40.0),
+    f(
+        "Wezen",
+        "δ",
+        Some(25),
+        "CMa",
+        "F8Ia",
+        0.68,
+        1600.0,
+        68_000.0,
+    ),
+];
+
+/// Facts about the star named `name`, if it is listed
+pub fn facts(name: &str) -> Option<&'static StarFacts> {
+    FACTS
+        .binary_search_by(|facts| facts.name.cmp(name))
+        .ok()
+        .map(|i| &FACTS[i])
+}
+
+/// The Greek letters of Bayer designations: the letter, its name, and
+/// its three-letter abbreviations as the IAU and the Yale Bright Star
+/// Catalogue (and so HYG) write them
+const GREEK_LETTERS: [(&str, &str, [&str; 2]); 24] = [
+    ("α", "alpha", ["alf", "alp"]),
+    ("β", "beta", ["bet", "bet"]),
+    ("γ", "gamma", ["gam", "gam"]),
+    ("δ", "delta", ["del", "del"]),
+    ("ε", "epsilon", ["eps", "eps"]),
+    ("ζ", "zeta", ["zet", "zet"]),
+    ("η", "eta", ["eta", "eta"]),
+    ("θ", "theta", ["tet", "the"]),
+    ("ι", "iota", ["iot", "iot"]),
+    ("κ", "kappa", ["kap", "kap"]),
+    ("λ", "lambda", ["lam", "lam"]),
+    ("μ", "mu", ["mu", "mu"]),
+    ("ν", "nu", ["nu", "nu"]),
+    ("ξ", "xi", ["ksi", "xi"]),
+    ("ο", "omicron", ["omi", "omi"]),
+    ("π", "pi", ["pi", "pi"]),
+    ("ρ", "rho", ["rho", "rho"]),
+    ("σ", "sigma", ["sig", "sig"]),
+    ("τ", "tau", ["tau", "tau"]),
+    ("υ", "upsilon", ["ups", "ups"]),
+    ("φ", "phi", ["phi", "phi"]),
+    ("χ", "chi", ["chi", "chi"]),
+    ("ψ", "psi", ["psi", "psi"]),
+    ("ω", "omega", ["ome", "ome"]),
+];
+
+/// The Greek letter written as itself, its name, or an abbreviation such
+/// as "alf" or "Alp", in any case
+pub fn greek_letter(text: &str) -> Option<&'static str> {
+    let text = text.trim().trim_end_matches('.').to_lowercase();
+    GREEK_LETTERS
+        .iter()
+        .find(|(letter, name, abbreviations)| {
+            *letter == text || *name == text || abbreviations.contains(&text.as_str())
+        })
+        .map(|(letter, _, _)| *letter)
+}
+
+/// The listed star a Bayer or Flamsteed designation such as "α Ori",
+/// "alf Ori" or "58 Ori" names
+pub fn by_designation(query: &str) -> Option<&'static StarFacts> {
+    let (label, constellation) = query.trim().split_once(char::is_whitespace)?;
+    let constellation = constellation.trim();
+    let in_constellation =
+        |facts: &&StarFacts| facts.constellation.eq_ignore_ascii_case(constellation);
+    match label.parse::<u16>() {
+        Ok(number) => FACTS
+            .iter()
+            .filter(in_constellation)
+            .find(|facts| facts.flamsteed == Some(number)),
+        Err(_) => {
+            let letter = greek_letter(label)?;
+            FACTS
+                .iter()
+                .filter(in_constellation)
+                .find(|facts| facts.bayer == letter)
+        }
+    }
+}
+
+impl Star {
+    /// Facts about the star, if it is listed
+    pub fn facts(&self) -> Option<&'static StarFacts> {
+        facts(self.name.as_deref()?)
+    }
+
+    /// Distance in light-years, for a listed star
+    pub fn distance_ly(&self) -> Option<f64> {
+        self.facts().map(|facts| facts.distance_ly)
+    }
+
+    /// Luminosity in multiples of the Sun's, for a listed star
+    pub fn luminosity(&self) -> Option<f64> {
+        self.facts().map(|facts| facts.luminosity)
+    }
+
+    /// MK spectral type, for a listed star
+    pub fn spectral_type(&self) -> Option<&'static str> {
+        self.facts().map(|facts| facts.spectral_type)
+    }
+}
+
+/// What an imported catalog says about one of its stars beyond the star
+/// record; each fact is missing where the input had none
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImportedFacts {
+    /// Greek letter of the Bayer designation, with its superscript if
+    /// the constellation has several, such as "α¹"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bayer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flamsteed: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spectral_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color_index: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_ly: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub luminosity: Option<f64>,
+}
+
+impl ImportedFacts {
+    /// Whether the input gave no facts at all
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Facts about an imported catalog's stars, by star id
+pub type CatalogFacts = BTreeMap<StarId, ImportedFacts>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::CelestialCoord;
+
+    #[test]
+    fn test_sorted_and_in_range() {
+        assert!(FACTS.windows(2).all(|pair| pair[0].name < pair[1].name));
+        for facts in &FACTS {
+            assert_eq!(facts.bayer.chars().count(), 1, "{:?}", facts);
+            assert!(greek_letter(facts.bayer).is_some(), "{:?}", facts);
+            assert_eq!(facts.constellation.len(), 3, "{:?}", facts);
+            assert!("OBAFGKM".contains(&facts.spectral_type[..1]), "{:?}", facts);
+            assert!((-0.4..2.0).contains(&facts.color_index), "{:?}", facts);
+            assert!(facts.distance_ly > 4.0, "{:?}", facts);
+            assert!(facts.luminosity > 1.0, "{:?}", facts);
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        let betelgeuse = facts("Betelgeuse").unwrap();
+        assert_eq!(betelgeuse.spectral_type, "M1-2Ia-Iab");
+        assert!(betelgeuse.color_index > 1.5);
+        assert_eq!(betelgeuse.bayer_designation(), "α Ori");
+        assert_eq!(
+            betelgeuse.flamsteed_designation().as_deref(),
+            Some("58 Ori")
+        );
+        assert_eq!(betelgeuse.designations(), "α Ori, 58 Ori");
+        // Too far south for Flamsteed
+        assert_eq!(facts("Canopus").unwrap().designations(), "α Car");
+        assert_eq!(facts("Kaus Australis").unwrap().spectral_type, "B9.5III");
+        assert_eq!(facts("Nowhere"), None);
+    }
+
+    #[test]
+    fn test_by_designation() {
+        for query in ["α Ori", "alf Ori", "Alp ori", "alpha ORI", "58 Ori"] {
+            assert_eq!(
+                by_designation(query).map(|facts| facts.name),
+                Some("Betelgeuse"),
+                "{}",
+                query
+            );
+        }
+        assert_eq!(by_designation("θ Cen").unwrap().name, "Menkent");
+        assert_eq!(by_designation("the Cen").unwrap().name, "Menkent");
+        assert_eq!(by_designation("ω Ori"), None);
+        assert_eq!(by_designation("Ori"), None);
+        assert_eq!(greek_letter("mu."), Some("μ"));
+    }
+
+    #[test]
+    fn test_star_facts() {
+        let mut star = Star {
+            id: StarId(1),
+            name: Some("Sirius".to_string()),
+            coord: CelestialCoord::new(6.75, -16.7),
+            magnitude: -1.46,
+            constellation: Some("CMa".to_string()),
+        };
+        assert_eq!(star.distance_ly(), Some(8.6));
+        assert_eq!(star.luminosity(), Some(25.4));
+        assert_eq!(star.spectral_type(), Some("A1V"));
+
+        star.name = None;
+        assert_eq!(star.distance_ly(), None);
+        assert_eq!(star.spectral_type(), None);
+    }
+}
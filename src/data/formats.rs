@@ -0,0 +1,297 @@
+//! Catalog file formats
+//!
+//! Encoding and decoding of the star list in the formats the tools
+//! exchange: JSON, gzipped JSON, CSV, and a compact binary layout.
+
+#[path = "binary.rs"]
+mod binary;
+
+use super::import::{import_catalog, ImportFormat, Imported};
+use super::{CelestialCoord, Star, StarId};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+/// Header row of the CSV format, one column per `Star` field
+pub const CSV_HEADER: &str = "id,name,ra,dec,magnitude,constellation";
+
+/// A serialized star list format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CatalogFormat {
+    Json,
+    JsonGz,
+    Csv,
+    Binary,
+}
+
+impl CatalogFormat {
+    /// Infer the format from a file name's extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+        if name.ends_with(".json.gz") || name.ends_with(".gz") {
+            Some(CatalogFormat::JsonGz)
+        } else if name.ends_with(".json") {
+            Some(CatalogFormat::Json)
+        } else if name.ends_with(".csv") {
+            Some(CatalogFormat::Csv)
+        } else if name.ends_with(".bin") {
+            Some(CatalogFormat::Binary)
+        } else {
+            None
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            CatalogFormat::Json => "JSON",
+            CatalogFormat::JsonGz => "gzipped JSON",
+            CatalogFormat::Csv => "CSV",
+            CatalogFormat::Binary => "binary",
+        }
+    }
+}
+
+/// Serialize stars in the given format
+pub fn encode_stars(stars: &[Star], format: CatalogFormat) -> Result<Vec<u8>, String> {
+    match format {
+        CatalogFormat::Json => serde_json::to_vec(stars).map_err(|e| e.to_string()),
+        CatalogFormat::JsonGz => {
+            let json = serde_json::to_vec(stars).map_err(|e| e.to_string())?;
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&json).map_err(|e| e.to_string())?;
+            encoder.finish().map_err(|e| e.to_string())
+        }
+        CatalogFormat::Csv => {
+            let mut csv = Vec::new();
+            write_csv(&mut csv, stars).map_err(|e| e.to_string())?;
+            Ok(csv)
+        }
+        CatalogFormat::Binary => encode_binary(stars),
+    }
+}
+
+/// Deserialize stars from the given format
+pub fn decode_stars(bytes: &[u8], format: CatalogFormat) -> Result<Vec<Star>, String> {
+    match format {
+        CatalogFormat::Json => serde_json::from_slice(bytes).map_err(|e| e.to_string()),
+        CatalogFormat::JsonGz => {
+            let mut json = Vec::new();
+            GzDecoder::new(bytes)
+                .read_to_end(&mut json)
+                .map_err(|e| e.to_string())?;
+            serde_json::from_slice(&json).map_err(|e| e.to_string())
+        }
+        CatalogFormat::Csv => {
+            let Imported { stars, report, .. } =
+                import_catalog(ImportFormat::SimpleCsv, bytes, None)?;
+            match report.warnings.first() {
+                Some(warning) => Err(warning.clone()),
+                None => Ok(stars),
+            }
+        }
+        CatalogFormat::Binary => decode_binary(bytes),
+    }
+}
+
+/// Write stars as CSV, one row at a time
+pub fn write_csv<'a, W: Write>(
+    out: &mut W,
+    stars: impl IntoIterator<Item = &'a Star>,
+) -> io::Result<()> {
+    writeln!(out, "{}", CSV_HEADER)?;
+    for star in stars {
+        writeln!(
+            out,
+            "{},{},{},{},{},{}",
+            star.id.0,
+            csv_field(star.name.as_deref().unwrap_or("")),
+            star.coord.ra,
+            star.coord.dec,
+            star.magnitude,
+            csv_field(star.constellation.as_deref().unwrap_or("")),
+        )?;
+    }
+    Ok(())
+}
+
+/// Quote a CSV field if it contains separators or quotes
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn encode_binary(stars: &[Star]) -> Result<Vec<u8>, String> {
+    let mut out = Vec::with_capacity(8 + stars.len() * 40);
+    binary::write_header(&mut out, stars.len())?;
+    for star in stars {
+        binary::write_star(
+            &mut out,
+            star.id.0,
+            star.coord.ra,
+            star.coord.dec,
+            star.magnitude,
+            star.name.as_deref(),
+            star.constellation.as_deref(),
+        )?;
+    }
+    Ok(out)
+}
+
+fn decode_binary(bytes: &[u8]) -> Result<Vec<Star>, String> {
+    let mut reader = ByteReader { bytes, pos: 0 };
+    if reader.take(4)? != binary::MAGIC {
+        return Err("not a binary star catalog".to_string());
+    }
+
+    let count = u32::from_le_bytes(reader.array()?);
+    let mut stars = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let id = StarId(u32::from_le_bytes(reader.array()?));
+        let ra = f64::from_le_bytes(reader.array()?);
+        let dec = f64::from_le_bytes(reader.array()?);
+        let magnitude = f64::from_le_bytes(reader.array()?);
+        let name = reader.optional_str()?;
+        let constellation = reader.optional_str()?;
+
+        stars.push(Star {
+            id,
+            name,
+            coord: CelestialCoord::new(ra, dec),
+            magnitude,
+            constellation,
+        });
+    }
+
+    if reader.pos != bytes.len() {
+        return Err("trailing data after last star".to_string());
+    }
+    Ok(stars)
+}
+
+/// Cursor over a binary catalog
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let slice = self
+            .bytes
+            .get(self.pos..self.pos + len)
+            .ok_or_else(|| "unexpected end of data".to_string())?;
+        self.pos += len;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    fn optional_str(&mut self) -> Result<Option<String>, String> {
+        let len = u16::from_le_bytes(self.array()?);
+        if len == binary::NONE {
+            return Ok(None);
+        }
+        let raw = self.take(len as usize)?;
+        String::from_utf8(raw.to_vec())
+            .map(Some)
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::generate_placeholder_catalog;
+
+    fn sample_stars() -> Vec<Star> {
+        let mut stars: Vec<Star> = generate_placeholder_catalog()
+            .all_stars()
+            .cloned()
+            .collect();
+        // Exercise quoting and missing optional fields
+        stars.push(Star {
+            id: StarId(999_999),
+            name: Some("Comma, \"Quoted\"".to_string()),
+            coord: CelestialCoord::new(12.5, -45.25),
+            magnitude: 4.2,
+            constellation: None,
+        });
+        stars
+    }
+
+    /// Convert JSON to `via` and back, returning the stars before and after
+    fn round_trip(stars: &[Star], via: CatalogFormat) -> (Vec<Star>, Vec<Star>) {
+        let json = encode_stars(stars, CatalogFormat::Json).unwrap();
+        let before = decode_stars(&json, CatalogFormat::Json).unwrap();
+        let encoded = encode_stars(&before, via).unwrap();
+        let decoded = decode_stars(&encoded, via).unwrap();
+        let json_again = encode_stars(&decoded, CatalogFormat::Json).unwrap();
+        let after = decode_stars(&json_again, CatalogFormat::Json).unwrap();
+        (before, after)
+    }
+
+    #[test]
+    fn test_format_from_extension() {
+        assert_eq!(
+            CatalogFormat::from_path(Path::new("stars.json")),
+            Some(CatalogFormat::Json)
+        );
+        assert_eq!(
+            CatalogFormat::from_path(Path::new("stars.json.gz")),
+            Some(CatalogFormat::JsonGz)
+        );
+        assert_eq!(
+            CatalogFormat::from_path(Path::new("out/STARS.CSV")),
+            Some(CatalogFormat::Csv)
+        );
+        assert_eq!(
+            CatalogFormat::from_path(Path::new("stars.bin")),
+            Some(CatalogFormat::Binary)
+        );
+        assert_eq!(CatalogFormat::from_path(Path::new("stars.txt")), None);
+    }
+
+    #[test]
+    fn test_json_bin_json_round_trip() {
+        let (before, after) = round_trip(&sample_stars(), CatalogFormat::Binary);
+        assert_eq!(after, before);
+    }
+
+    #[test]
+    fn test_json_gz_round_trip() {
+        let (before, after) = round_trip(&sample_stars(), CatalogFormat::JsonGz);
+        assert_eq!(after, before);
+    }
+
+    #[test]
+    fn test_json_csv_json_round_trip() {
+        let (before, after) = round_trip(&sample_stars(), CatalogFormat::Csv);
+
+        assert_eq!(after.len(), before.len());
+        for (a, b) in before.iter().zip(&after) {
+            assert_eq!(a.id, b.id);
+            assert_eq!(a.name, b.name);
+            assert_eq!(a.constellation, b.constellation);
+            assert!((a.coord.ra - b.coord.ra).abs() < 1e-9);
+            assert!((a.coord.dec - b.coord.dec).abs() < 1e-9);
+            assert!((a.magnitude - b.magnitude).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_truncated_binary_is_an_error() {
+        let encoded = encode_stars(&sample_stars(), CatalogFormat::Binary).unwrap();
+        assert!(decode_stars(&encoded[..encoded.len() - 3], CatalogFormat::Binary).is_err());
+        assert!(decode_stars(b"nope", CatalogFormat::Binary).is_err());
+    }
+}
@@ -0,0 +1,377 @@
+//! External catalog import
+//!
+//! Converts third-party star catalogs into the crate's own star list,
+//! validating each row along the way. Currently supports the HYG
+//! database CSV and the simple CSV written by `stargazer-cli generate`.
+//! HYG rows also give a star's designations, spectral type, color index,
+//! distance and luminosity, which are kept as [`CatalogFacts`] where the
+//! columns are present.
+
+use super::facts::{greek_letter, CatalogFacts, ImportedFacts, LIGHT_YEARS_PER_PARSEC};
+use super::{CelestialCoord, Star, StarId};
+use std::collections::HashSet;
+use std::io::BufRead;
+
+/// Supported input formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    /// HYG database CSV (`id,hip,hd,hr,gl,bf,proper,ra,dec,...,mag,...,con,...`)
+    Hyg,
+    /// `id,name,ra,dec,magnitude,constellation`
+    SimpleCsv,
+}
+
+/// HYG's distance for a star whose distance is unknown, in parsecs
+const HYG_UNKNOWN_DISTANCE: f64 = 100_000.0;
+
+/// Stars read by an import, with the facts the input gave about them
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Imported {
+    pub stars: Vec<Star>,
+    /// Facts about the kept stars, for those the input had any for
+    pub facts: CatalogFacts,
+    pub report: ImportReport,
+}
+
+/// Outcome of an import run
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImportReport {
+    /// Data rows read (excluding the header)
+    pub rows_read: usize,
+    /// Stars that passed validation and filtering
+    pub stars_kept: usize,
+    /// Rows skipped by the magnitude limit
+    pub filtered_out: usize,
+    /// Problems found in individual rows
+    pub warnings: Vec<String>,
+}
+
+/// Column positions for one input format
+struct Columns {
+    id: usize,
+    name: usize,
+    ra: usize,
+    dec: usize,
+    magnitude: usize,
+    constellation: usize,
+    facts: FactColumns,
+}
+
+/// Columns of the optional star facts, each only where present
+#[derive(Default)]
+struct FactColumns {
+    designation: Option<usize>,
+    spectral_type: Option<usize>,
+    color_index: Option<usize>,
+    distance: Option<usize>,
+    luminosity: Option<usize>,
+}
+
+impl ImportFormat {
+    /// Locate the columns this format needs in a header row
+    fn columns(&self, header: &[String]) -> Result<Columns, String> {
+        let names = match self {
+            ImportFormat::Hyg => ["id", "proper", "ra", "dec", "mag", "con"],
+            ImportFormat::SimpleCsv => ["id", "name", "ra", "dec", "magnitude", "constellation"],
+        };
+        let position = |name: &str| {
+            header
+                .iter()
+                .position(|h| h.trim().eq_ignore_ascii_case(name))
+        };
+        let find = |name: &str| position(name).ok_or_else(|| format!("missing column '{}'", name));
+        let facts = match self {
+            ImportFormat::Hyg => FactColumns {
+                designation: position("bf"),
+                spectral_type: position("spect"),
+                color_index: position("ci"),
+                distance: position("dist"),
+                luminosity: position("lum"),
+            },
+            ImportFormat::SimpleCsv => FactColumns::default(),
+        };
+
+        Ok(Columns {
+            id: find(names[0])?,
+            name: find(names[1])?,
+            ra: find(names[2])?,
+            dec: find(names[3])?,
+            magnitude: find(names[4])?,
+            constellation: find(names[5])?,
+            facts,
+        })
+    }
+}
+
+/// Read and validate a catalog, keeping stars at or brighter than `max_magnitude`
+pub fn import_catalog<R: BufRead>(
+    format: ImportFormat,
+    reader: R,
+    max_magnitude: Option<f64>,
+) -> Result<Imported, String> {
+    let mut lines = reader.lines();
+    let header = match lines.next() {
+        Some(line) => split_csv_line(&line.map_err(|e| e.to_string())?),
+        None => return Err("input is empty".to_string()),
+    };
+    let columns = format.columns(&header)?;
+
+    let mut report = ImportReport::default();
+    let mut stars = Vec::new();
+    let mut facts = CatalogFacts::new();
+    let mut seen_ids = HashSet::new();
+
+    for (index, line) in lines.enumerate() {
+        let line = line.map_err(|e| e.to_string())?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        report.rows_read += 1;
+        // Header is line 1
+        let line_no = index + 2;
+
+        let fields = split_csv_line(&line);
+        let star = match parse_row(&fields, &columns) {
+            Ok(star) => star,
+            Err(msg) => {
+                report.warnings.push(format!("line {}: {}", line_no, msg));
+                continue;
+            }
+        };
+
+        // HYG lists the Sun as row 0
+        if format == ImportFormat::Hyg && star.name.as_deref() == Some("Sol") {
+            continue;
+        }
+
+        if max_magnitude.is_some_and(|max| star.magnitude > max) {
+            report.filtered_out += 1;
+            continue;
+        }
+
+        if !seen_ids.insert(star.id) {
+            report
+                .warnings
+                .push(format!("line {}: duplicate id {}", line_no, star.id.0));
+            continue;
+        }
+
+        let star_facts = parse_facts(&fields, &columns.facts);
+        if !star_facts.is_empty() {
+            facts.insert(star.id, star_facts);
+        }
+        stars.push(star);
+    }
+
+    report.stars_kept = stars.len();
+    Ok(Imported {
+        stars,
+        facts,
+        report,
+    })
+}
+
+/// Build a star from one row, validating its fields
+fn parse_row(fields: &[String], columns: &Columns) -> Result<Star, String> {
+    let field = |index: usize| fields.get(index).map(|f| f.trim()).unwrap_or("");
+    let number = |index: usize, label: &str| {
+        field(index)
+            .parse::<f64>()
+            .ok()
+            .filter(|v| v.is_finite())
+            .ok_or_else(|| format!("invalid {} '{}'", label, field(index)))
+    };
+
+    let id = field(columns.id)
+        .parse::<u32>()
+        .map_err(|_| format!("invalid id '{}'", field(columns.id)))?;
+    let ra = number(columns.ra, "ra")?;
+    let dec = number(columns.dec, "dec")?;
+    let magnitude = number(columns.magnitude, "magnitude")?;
+
+    if !(0.0..24.0).contains(&ra) {
+        return Err(format!("ra {} outside 0-24h", ra));
+    }
+    if !(-90.0..=90.0).contains(&dec) {
+        return Err(format!("dec {} outside -90..90", dec));
+    }
+
+    let optional = |index: usize| Some(field(index).to_string()).filter(|s| !s.is_empty());
+
+    Ok(Star {
+        id: StarId(id),
+        name: optional(columns.name),
+        coord: CelestialCoord::new(ra, dec),
+        magnitude,
+        constellation: optional(columns.constellation),
+    })
+}
+
+/// The facts a row gives, skipping values that are empty or unreadable
+fn parse_facts(fields: &[String], columns: &FactColumns) -> ImportedFacts {
+    let field = |index: Option<usize>| {
+        index
+            .and_then(|index| fields.get(index))
+            .map(|f| f.trim())
+            .filter(|f| !f.is_empty())
+    };
+    let number = |index: Option<usize>| {
+        field(index)
+            .and_then(|f| f.parse::<f64>().ok())
+            .filter(|v| v.is_finite())
+    };
+    let (flamsteed, bayer) = field(columns.designation).map_or((None, None), bayer_flamsteed);
+    ImportedFacts {
+        bayer,
+        flamsteed,
+        spectral_type: field(columns.spectral_type).map(str::to_string),
+        color_index: number(columns.color_index),
+        distance_ly: number(columns.distance)
+            .filter(|&parsecs| parsecs > 0.0 && parsecs < HYG_UNKNOWN_DISTANCE)
+            .map(|parsecs| parsecs * LIGHT_YEARS_PER_PARSEC),
+        luminosity: number(columns.luminosity).filter(|&lum| lum > 0.0),
+    }
+}
+
+/// Flamsteed number and Bayer letter from HYG's combined `bf` field, such
+/// as "58Alp Ori" or "Alp1Cen"; the constellation closing the field is
+/// on the star already
+fn bayer_flamsteed(bf: &str) -> (Option<u16>, Option<String>) {
+    let Some(head) = bf.len().checked_sub(3).and_then(|end| bf.get(..end)) else {
+        return (None, None);
+    };
+    let head = head.trim_end();
+    let digits = head
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(head.len());
+    let flamsteed = head[..digits].parse().ok();
+    let rest = &head[digits..];
+    let letters = rest
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(rest.len());
+    let bayer = greek_letter(&rest[..letters]).map(|letter| {
+        let superscript = match rest[letters..].trim() {
+            "1" => "¹",
+            "2" => "²",
+            "3" => "³",
+            _ => "",
+        };
+        format!("{}{}", letter, superscript)
+    });
+    (flamsteed, bayer)
+}
+
+/// Split one CSV line into fields, honouring double-quoted values
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                current.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    fields.push(current);
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HYG: &str = "\
+id,hip,hd,hr,gl,bf,proper,ra,dec,dist,mag,con
+0,,,,,,Sol,0.000000,0.000000,0.0000,-26.700,
+32263,32349,48915,2491,Gl 244A,9Alp CMa,Sirius,6.752481,-16.716116,2.6371,-1.440,CMa
+27919,27989,39801,2061,,58Alp Ori,Betelgeuse,5.919529,7.407063,152.6718,0.450,Ori
+1,1,224700,,,,,0.000060,1.089009,219.7802,9.100,Psc
+2,2,224690,,,,,27.0,-19.498840,47.9616,9.270,Cet
+";
+
+    #[test]
+    fn test_split_csv_line_handles_quotes() {
+        assert_eq!(
+            split_csv_line(r#"1,"Alpha, Beta","say ""hi""",x"#),
+            vec!["1", "Alpha, Beta", r#"say "hi""#, "x"]
+        );
+    }
+
+    #[test]
+    fn test_hyg_import() {
+        let Imported { stars, report, .. } =
+            import_catalog(ImportFormat::Hyg, HYG.as_bytes(), None).unwrap();
+
+        assert_eq!(report.rows_read, 5);
+        assert_eq!(report.stars_kept, 3);
+        assert_eq!(report.warnings.len(), 1, "bad RA row should warn");
+
+        let sirius = stars.iter().find(|s| s.id == StarId(32263)).unwrap();
+        assert_eq!(sirius.name.as_deref(), Some("Sirius"));
+        assert_eq!(sirius.constellation.as_deref(), Some("CMa"));
+        assert!(stars.iter().all(|s| s.name.as_deref() != Some("Sol")));
+    }
+
+    #[test]
+    fn test_max_magnitude_filter() {
+        let Imported { stars, report, .. } =
+            import_catalog(ImportFormat::Hyg, HYG.as_bytes(), Some(6.5)).unwrap();
+        assert_eq!(stars.len(), 2);
+        assert_eq!(report.filtered_out, 1);
+    }
+
+    #[test]
+    fn test_simple_csv_import() {
+        let csv = "id,name,ra,dec,magnitude,constellation\n1,Vega,18.615,38.78,0.03,Lyr\n2,,1.0,2.0,5.5,\n";
+        let Imported {
+            stars,
+            facts,
+            report,
+        } = import_catalog(ImportFormat::SimpleCsv, csv.as_bytes(), None).unwrap();
+
+        assert_eq!(report.stars_kept, 2);
+        assert!(report.warnings.is_empty());
+        assert!(stars[1].name.is_none());
+        assert!(facts.is_empty());
+    }
+
+    #[test]
+    fn test_hyg_facts() {
+        let csv = "\
+id,bf,proper,ra,dec,dist,mag,spect,ci,con,lum
+32263,9Alp CMa,Sirius,6.752481,-16.716116,2.6371,-1.440,A0m...,0.009,CMa,22.8
+71681,Alp1Cen,Rigil Kentaurus,14.660765,-60.833976,1.3248,-0.010,G2V,0.710,Cen,1.5
+5,,,0.1,1.0,100000.0000,9.1,,,Psc,
+";
+        let Imported { facts, .. } =
+            import_catalog(ImportFormat::Hyg, csv.as_bytes(), None).unwrap();
+
+        let sirius = &facts[&StarId(32263)];
+        assert_eq!(sirius.bayer.as_deref(), Some("α"));
+        assert_eq!(sirius.flamsteed, Some(9));
+        assert_eq!(sirius.spectral_type.as_deref(), Some("A0m..."));
+        assert_eq!(sirius.color_index, Some(0.009));
+        assert!((sirius.distance_ly.unwrap() - 8.6).abs() < 0.1);
+        assert_eq!(sirius.luminosity, Some(22.8));
+
+        let rigil = &facts[&StarId(71681)];
+        assert_eq!(rigil.bayer.as_deref(), Some("α¹"));
+        assert_eq!(rigil.flamsteed, None);
+
+        // An unknown distance and empty columns give no facts
+        assert!(!facts.contains_key(&StarId(5)));
+    }
+
+    #[test]
+    fn test_missing_column_is_an_error() {
+        let csv = "id,name,ra\n1,Vega,18.6\n";
+        assert!(import_catalog(ImportFormat::SimpleCsv, csv.as_bytes(), None).is_err());
+    }
+}
@@ -0,0 +1,182 @@
+//! Filtered, sorted listings of named stars
+
+use super::{Star, StarCatalog};
+use std::cmp::Ordering;
+
+/// Sort keys for star listings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StarSort {
+    Name,
+    /// Brightest first
+    #[default]
+    Magnitude,
+    RightAscension,
+    Declination,
+    /// By abbreviation, brightest first within each; unassigned stars last
+    Constellation,
+}
+
+impl StarSort {
+    /// Compare two stars by this key, falling back to magnitude then name
+    pub fn compare(&self, a: &Star, b: &Star) -> Ordering {
+        let primary = match self {
+            StarSort::Name => a.name.cmp(&b.name),
+            StarSort::Magnitude => Ordering::Equal,
+            StarSort::RightAscension => a.coord.ra.total_cmp(&b.coord.ra),
+            StarSort::Declination => a.coord.dec.total_cmp(&b.coord.dec),
+            StarSort::Constellation => match (&a.constellation, &b.constellation) {
+                (Some(x), Some(y)) => x.to_lowercase().cmp(&y.to_lowercase()),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            },
+        };
+        primary
+            .then_with(|| a.magnitude.total_cmp(&b.magnitude))
+            .then_with(|| a.name.cmp(&b.name))
+    }
+}
+
+/// Which named stars to list
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedStarFilter {
+    /// Brightest magnitude to include (inclusive)
+    pub min_magnitude: Option<f64>,
+    /// Faintest magnitude to include (exclusive)
+    pub max_magnitude: f64,
+    /// Constellation abbreviation, matched case-insensitively
+    pub constellation: Option<String>,
+    /// Keep at most this many stars after sorting
+    pub limit: Option<usize>,
+}
+
+impl Default for NamedStarFilter {
+    fn default() -> Self {
+        Self {
+            min_magnitude: None,
+            max_magnitude: 6.5,
+            constellation: None,
+            limit: None,
+        }
+    }
+}
+
+impl NamedStarFilter {
+    /// Whether a star passes the magnitude and constellation filters
+    pub fn matches(&self, star: &Star) -> bool {
+        star.magnitude < self.max_magnitude
+            && self.min_magnitude.is_none_or(|min| star.magnitude >= min)
+            && self.constellation.as_deref().is_none_or(|wanted| {
+                star.constellation
+                    .as_deref()
+                    .is_some_and(|c| c.eq_ignore_ascii_case(wanted))
+            })
+    }
+}
+
+impl StarCatalog {
+    /// Named stars passing `filter`, ordered by `sort`
+    pub fn list_named(&self, filter: &NamedStarFilter, sort: StarSort) -> Vec<&Star> {
+        let mut stars: Vec<&Star> = self
+            .named_stars()
+            .into_iter()
+            .filter(|star| filter.matches(star))
+            .collect();
+        stars.sort_by(|a, b| sort.compare(a, b));
+        if let Some(limit) = filter.limit {
+            stars.truncate(limit);
+        }
+        stars
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::generate_placeholder_catalog;
+
+    fn listed(sort: StarSort) -> Vec<Star> {
+        generate_placeholder_catalog()
+            .list_named(&NamedStarFilter::default(), sort)
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    fn assert_sorted_by<K: PartialOrd + std::fmt::Debug>(stars: &[Star], key: impl Fn(&Star) -> K) {
+        assert!(stars.len() > 1);
+        for pair in stars.windows(2) {
+            assert!(
+                key(&pair[0]) <= key(&pair[1]),
+                "{:?} before {:?}",
+                key(&pair[0]),
+                key(&pair[1])
+            );
+        }
+    }
+
+    #[test]
+    fn test_sort_by_name() {
+        assert_sorted_by(&listed(StarSort::Name), |s| s.name.clone());
+    }
+
+    #[test]
+    fn test_sort_by_magnitude() {
+        assert_sorted_by(&listed(StarSort::Magnitude), |s| s.magnitude);
+    }
+
+    #[test]
+    fn test_sort_by_ra_and_dec() {
+        assert_sorted_by(&listed(StarSort::RightAscension), |s| s.coord.ra);
+        assert_sorted_by(&listed(StarSort::Declination), |s| s.coord.dec);
+    }
+
+    #[test]
+    fn test_sort_by_constellation_puts_unassigned_last() {
+        let stars = listed(StarSort::Constellation);
+        // `None` sorts first as an Option, so key unassigned stars past every abbreviation
+        assert_sorted_by(&stars, |s| {
+            (
+                s.constellation.is_none(),
+                s.constellation.as_deref().map(str::to_lowercase),
+            )
+        });
+        for pair in stars.windows(2) {
+            if pair[0].constellation == pair[1].constellation {
+                assert!(pair[0].magnitude <= pair[1].magnitude);
+            }
+        }
+    }
+
+    #[test]
+    fn test_constellation_filter_is_case_insensitive() {
+        let catalog = generate_placeholder_catalog();
+        let filter = |abbrev: &str| NamedStarFilter {
+            constellation: Some(abbrev.to_string()),
+            ..Default::default()
+        };
+
+        let orion = catalog.list_named(&filter("Ori"), StarSort::Name);
+        assert!(!orion.is_empty());
+        assert_eq!(catalog.list_named(&filter("ori"), StarSort::Name), orion);
+        assert_eq!(catalog.list_named(&filter("ORI"), StarSort::Name), orion);
+        assert!(orion
+            .iter()
+            .all(|s| s.constellation.as_deref() == Some("Ori")));
+    }
+
+    #[test]
+    fn test_magnitude_window_and_limit() {
+        let catalog = generate_placeholder_catalog();
+        let filter = NamedStarFilter {
+            min_magnitude: Some(1.0),
+            max_magnitude: 2.0,
+            limit: Some(3),
+            ..Default::default()
+        };
+        let stars = catalog.list_named(&filter, StarSort::Magnitude);
+
+        assert_eq!(stars.len(), 3);
+        assert!(stars.iter().all(|s| (1.0..2.0).contains(&s.magnitude)));
+    }
+}
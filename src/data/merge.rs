@@ -0,0 +1,331 @@
+//! Merging an overlay catalog into a base catalog
+//!
+//! Overlay stars are matched to base stars by position rather than id,
+//! since overlays usually come from a different source with its own
+//! numbering. A [`MergePolicy`] decides what happens to matched stars;
+//! unmatched stars are added, renumbered if their id is already taken.
+
+use super::nearest::angular_separation;
+use super::{CelestialCoord, Star, StarId};
+use std::collections::HashSet;
+use std::fmt;
+
+/// How matched stars are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergePolicy {
+    /// Keep base stars unchanged; only add new ones
+    #[default]
+    PreferExisting,
+    /// Replace base fields with the overlay's
+    PreferIncoming,
+    /// Keep base fields, taking name and constellation from the overlay where missing
+    FillMissing,
+}
+
+impl MergePolicy {
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            MergePolicy::PreferExisting => "prefer-existing",
+            MergePolicy::PreferIncoming => "prefer-incoming",
+            MergePolicy::FillMissing => "fill-missing",
+        }
+    }
+}
+
+/// Settings for a merge
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MergeOptions {
+    pub policy: MergePolicy,
+    /// Maximum separation for an overlay star to match a base star, in arcseconds
+    pub tolerance_arcsec: f64,
+}
+
+impl Default for MergeOptions {
+    fn default() -> Self {
+        Self {
+            policy: MergePolicy::default(),
+            tolerance_arcsec: 30.0,
+        }
+    }
+}
+
+/// An overlay star added to the catalog
+#[derive(Debug, Clone, PartialEq)]
+pub struct AddedStar {
+    /// Id in the merged catalog
+    pub id: StarId,
+    /// Id in the overlay, which differs when it was already taken
+    pub incoming: StarId,
+}
+
+/// A base star changed by its overlay match
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdatedStar {
+    pub id: StarId,
+    pub incoming: StarId,
+    pub old_name: Option<String>,
+    pub new_name: Option<String>,
+    /// Names of the fields that changed
+    pub fields: Vec<&'static str>,
+}
+
+/// An overlay star that left the catalog unchanged
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkippedStar {
+    pub incoming: StarId,
+    pub reason: String,
+}
+
+/// What a merge did, star by star
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MergeSummary {
+    pub added: Vec<AddedStar>,
+    pub updated: Vec<UpdatedStar>,
+    pub skipped: Vec<SkippedStar>,
+}
+
+impl MergeSummary {
+    /// Updates that renamed a star
+    pub fn name_changes(&self) -> impl Iterator<Item = &UpdatedStar> {
+        self.updated.iter().filter(|u| u.old_name != u.new_name)
+    }
+}
+
+/// Merge `overlay` into `base`, returning the merged stars and a summary
+pub fn merge_catalogs(
+    base: &[Star],
+    overlay: &[Star],
+    options: &MergeOptions,
+) -> (Vec<Star>, MergeSummary) {
+    let mut merged = base.to_vec();
+    let mut summary = MergeSummary::default();
+    let mut used_ids: HashSet<StarId> = base.iter().map(|s| s.id).collect();
+    let mut next_id = base
+        .iter()
+        .chain(overlay)
+        .map(|s| s.id.0)
+        .max()
+        .unwrap_or(0);
+    // Base indices already claimed by an earlier overlay star
+    let mut matched = HashSet::new();
+
+    for incoming in overlay {
+        let nearest = base
+            .iter()
+            .enumerate()
+            .map(|(i, star)| (i, angular_separation(&star.coord, &incoming.coord) * 3600.0))
+            .filter(|(_, arcsec)| *arcsec <= options.tolerance_arcsec)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+
+        let Some((index, _)) = nearest else {
+            let mut star = incoming.clone();
+            if !used_ids.insert(star.id) {
+                next_id += 1;
+                star.id = StarId(next_id);
+                used_ids.insert(star.id);
+            }
+            summary.added.push(AddedStar {
+                id: star.id,
+                incoming: incoming.id,
+            });
+            merged.push(star);
+            continue;
+        };
+
+        let existing = &mut merged[index];
+        if !matched.insert(index) {
+            summary.skipped.push(SkippedStar {
+                incoming: incoming.id,
+                reason: format!("star {} was already matched", existing.id.0),
+            });
+            continue;
+        }
+
+        let old_name = existing.name.clone();
+        let fields = apply(existing, incoming, options.policy);
+        if fields.is_empty() {
+            let reason = match options.policy {
+                MergePolicy::PreferExisting => format!("kept existing star {}", existing.id.0),
+                MergePolicy::PreferIncoming => format!("identical to star {}", existing.id.0),
+                MergePolicy::FillMissing => format!("nothing to fill in star {}", existing.id.0),
+            };
+            summary.skipped.push(SkippedStar {
+                incoming: incoming.id,
+                reason,
+            });
+        } else {
+            summary.updated.push(UpdatedStar {
+                id: existing.id,
+                incoming: incoming.id,
+                old_name,
+                new_name: existing.name.clone(),
+                fields,
+            });
+        }
+    }
+
+    (merged, summary)
+}
+
+/// Update `existing` from `incoming` under `policy`, returning the changed fields
+fn apply(existing: &mut Star, incoming: &Star, policy: MergePolicy) -> Vec<&'static str> {
+    let mut fields = Vec::new();
+    match policy {
+        MergePolicy::PreferExisting => {}
+        MergePolicy::PreferIncoming => {
+            if incoming.name.is_some() && existing.name != incoming.name {
+                existing.name = incoming.name.clone();
+                fields.push("name");
+            }
+            let (ra, dec) = (incoming.coord.ra, incoming.coord.dec);
+            if existing.coord.ra != ra || existing.coord.dec != dec {
+                existing.coord = CelestialCoord { ra, dec };
+                fields.push("position");
+            }
+            if existing.magnitude != incoming.magnitude {
+                existing.magnitude = incoming.magnitude;
+                fields.push("magnitude");
+            }
+            if incoming.constellation.is_some() && existing.constellation != incoming.constellation
+            {
+                existing.constellation = incoming.constellation.clone();
+                fields.push("constellation");
+            }
+        }
+        MergePolicy::FillMissing => {
+            if existing.name.is_none() && incoming.name.is_some() {
+                existing.name = incoming.name.clone();
+                fields.push("name");
+            }
+            if existing.constellation.is_none() && incoming.constellation.is_some() {
+                existing.constellation = incoming.constellation.clone();
+                fields.push("constellation");
+            }
+        }
+    }
+    fields
+}
+
+impl fmt::Display for MergeSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Added:   {}", self.added.len())?;
+        for added in self.added.iter().filter(|a| a.id != a.incoming) {
+            writeln!(
+                f,
+                "  star {}: renumbered to {} (id taken)",
+                added.incoming.0, added.id.0
+            )?;
+        }
+        writeln!(f, "Updated: {}", self.updated.len())?;
+        for updated in &self.updated {
+            writeln!(f, "  star {}: {}", updated.id.0, updated.fields.join(", "))?;
+        }
+        writeln!(f, "Skipped: {}", self.skipped.len())?;
+        for skipped in &self.skipped {
+            writeln!(f, "  star {}: {}", skipped.incoming.0, skipped.reason)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn star(id: u32, name: Option<&str>, ra: f64, dec: f64) -> Star {
+        Star {
+            id: StarId(id),
+            name: name.map(str::to_string),
+            coord: CelestialCoord { ra, dec },
+            magnitude: 2.0,
+            constellation: None,
+        }
+    }
+
+    fn options(policy: MergePolicy) -> MergeOptions {
+        MergeOptions {
+            policy,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_unmatched_stars_are_added_and_renumbered() {
+        let base = [star(1, Some("Rigel"), 5.242, -8.2)];
+        let overlay = [star(1, Some("Vega"), 18.6, 38.8)];
+        let (merged, summary) = merge_catalogs(&base, &overlay, &MergeOptions::default());
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(
+            summary.added,
+            [AddedStar {
+                id: StarId(2),
+                incoming: StarId(1)
+            }]
+        );
+        assert_eq!(merged[1].name.as_deref(), Some("Vega"));
+    }
+
+    #[test]
+    fn test_policies() {
+        let base = [star(1, None, 5.242, -8.2)];
+        let mut incoming = star(7, Some("Rigel"), 5.242, -8.2);
+        incoming.magnitude = 0.13;
+        let overlay = [incoming];
+
+        let (merged, summary) =
+            merge_catalogs(&base, &overlay, &options(MergePolicy::PreferExisting));
+        assert_eq!(merged, base);
+        assert_eq!(summary.skipped.len(), 1);
+
+        let (merged, summary) = merge_catalogs(&base, &overlay, &options(MergePolicy::FillMissing));
+        assert_eq!(merged[0].name.as_deref(), Some("Rigel"));
+        assert_eq!(merged[0].magnitude, 2.0);
+        assert_eq!(summary.updated[0].fields, ["name"]);
+
+        let (merged, summary) =
+            merge_catalogs(&base, &overlay, &options(MergePolicy::PreferIncoming));
+        assert_eq!(merged[0].id, StarId(1));
+        assert_eq!(merged[0].magnitude, 0.13);
+        assert_eq!(summary.updated[0].fields, ["name", "magnitude"]);
+        assert_eq!(summary.name_changes().count(), 1);
+    }
+
+    #[test]
+    fn test_tolerance() {
+        let base = [star(1, Some("Rigel"), 5.242, -8.2)];
+        // 20 arcseconds north of the base star
+        let overlay = [star(9, Some("Rigel A"), 5.242, -8.2 + 20.0 / 3600.0)];
+
+        let matched = MergeOptions {
+            policy: MergePolicy::PreferIncoming,
+            tolerance_arcsec: 30.0,
+        };
+        let (merged, _) = merge_catalogs(&base, &overlay, &matched);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].name.as_deref(), Some("Rigel A"));
+
+        let strict = MergeOptions {
+            tolerance_arcsec: 10.0,
+            ..matched
+        };
+        let (merged, summary) = merge_catalogs(&base, &overlay, &strict);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(summary.added.len(), 1);
+    }
+
+    #[test]
+    fn test_base_star_matches_once() {
+        let base = [star(1, Some("Rigel"), 5.242, -8.2)];
+        let overlay = [
+            star(8, Some("Rigel A"), 5.242, -8.2),
+            star(9, Some("Rigel B"), 5.242, -8.2),
+        ];
+        let (merged, summary) =
+            merge_catalogs(&base, &overlay, &options(MergePolicy::PreferIncoming));
+
+        assert_eq!(merged[0].name.as_deref(), Some("Rigel A"));
+        assert_eq!(summary.skipped[0].incoming, StarId(9));
+    }
+}
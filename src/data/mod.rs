@@ -0,0 +1,39 @@
+//! Star catalog and celestial coordinate types
+//!
+//! [`Star`] and [`CelestialCoord`] describe one star, [`StarCatalog`]
+//! holds them all, and with the `tiles` feature [`TileSystem`] files them
+//! by region of the sky. The submodules add lookups over the catalog,
+//! the reference tables for constellations, asterisms, deep-sky objects
+//! and star facts, and the file formats the tools exchange.
+
+mod catalog;
+mod star;
+#[cfg(feature = "tiles")]
+mod tile_system;
+
+pub mod asterisms;
+pub mod constellations;
+pub mod dso;
+pub mod embedded;
+pub mod facts;
+pub mod formats;
+pub mod import;
+pub mod listing;
+pub mod merge;
+pub mod nearest;
+pub mod search;
+pub mod star_names;
+#[cfg(feature = "tiles")]
+pub mod tile_stats;
+#[cfg(feature = "tiles")]
+pub mod tile_view;
+pub mod validate;
+
+#[cfg(test)]
+pub(crate) use catalog::catalog_of;
+#[cfg(not(target_arch = "wasm32"))]
+pub use catalog::load_stars_from_json;
+pub use catalog::{generate_placeholder_catalog, StarCatalog};
+pub use star::{BrightnessCategory, CelestialCoord, Star, StarId};
+#[cfg(feature = "tiles")]
+pub use tile_system::{Tile, TileId, TileSystem, ZoomLevel};
@@ -0,0 +1,117 @@
+//! Nearest-star queries
+//!
+//! Angular-distance lookups over the catalog, used to relate a point
+//! on the sky to the stars around it.
+
+use super::{CelestialCoord, Star, StarCatalog};
+
+/// Angular separation between two coordinates in degrees
+///
+/// Uses the haversine formula, which stays accurate for small separations.
+pub fn angular_separation(a: &CelestialCoord, b: &CelestialCoord) -> f64 {
+    let ra1 = (a.ra * 15.0).to_radians();
+    let ra2 = (b.ra * 15.0).to_radians();
+    let dec1 = a.dec.to_radians();
+    let dec2 = b.dec.to_radians();
+
+    let sin_ddec = ((dec2 - dec1) / 2.0).sin();
+    let sin_dra = ((ra2 - ra1) / 2.0).sin();
+    let h = sin_ddec * sin_ddec + dec1.cos() * dec2.cos() * sin_dra * sin_dra;
+
+    (2.0 * h.sqrt().min(1.0).asin()).to_degrees()
+}
+
+impl StarCatalog {
+    /// Find the named star closest to `coord`, if one lies within `max_degrees`
+    ///
+    /// Returns the star along with its separation in degrees.
+    pub fn nearest_named_star(
+        &self,
+        coord: &CelestialCoord,
+        max_degrees: f64,
+    ) -> Option<(&Star, f64)> {
+        self.nearest_stars(coord, 1, true)
+            .into_iter()
+            .find(|(_, separation)| *separation <= max_degrees)
+    }
+
+    /// The `count` stars closest to `coord`, sorted by ascending separation
+    pub fn nearest_stars(
+        &self,
+        coord: &CelestialCoord,
+        count: usize,
+        named_only: bool,
+    ) -> Vec<(&Star, f64)> {
+        let mut by_distance: Vec<(&Star, f64)> = self
+            .all_stars()
+            .filter(|star| !named_only || star.has_name())
+            .map(|star| (star, angular_separation(coord, &star.coord)))
+            .collect();
+
+        by_distance.sort_by(|a, b| a.1.total_cmp(&b.1));
+        by_distance.truncate(count);
+        by_distance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::generate_placeholder_catalog;
+
+    #[test]
+    fn test_angular_separation() {
+        let origin = CelestialCoord::new(0.0, 0.0);
+        assert!(angular_separation(&origin, &origin).abs() < 1e-9);
+
+        let pole = CelestialCoord::new(0.0, 90.0);
+        assert!((angular_separation(&origin, &pole) - 90.0).abs() < 1e-9);
+
+        // 12 hours of RA on the equator is half the sky
+        let opposite = CelestialCoord::new(12.0, 0.0);
+        assert!((angular_separation(&origin, &opposite) - 180.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_nearest_named_star() {
+        let catalog = generate_placeholder_catalog();
+        let sirius = catalog
+            .named_stars()
+            .into_iter()
+            .find(|s| s.name.as_deref() == Some("Sirius"))
+            .unwrap();
+
+        // A point just off Sirius should resolve to it
+        let nearby = CelestialCoord::new(sirius.coord.ra + 0.01, sirius.coord.dec + 0.1);
+        let (star, separation) = catalog.nearest_named_star(&nearby, 3.0).unwrap();
+        assert_eq!(star.id, sirius.id);
+        assert!(separation < 1.0);
+    }
+
+    #[test]
+    fn test_nearest_stars_sorted() {
+        let catalog = generate_placeholder_catalog();
+        let origin = CelestialCoord::new(6.75, -16.7);
+
+        let nearest = catalog.nearest_stars(&origin, 5, false);
+        assert_eq!(nearest.len(), 5);
+        assert!(nearest.windows(2).all(|w| w[0].1 <= w[1].1));
+
+        let named = catalog.nearest_stars(&origin, 5, true);
+        assert!(named.iter().all(|(s, _)| s.has_name()));
+        assert_eq!(named[0].0.name.as_deref(), Some("Sirius"));
+    }
+
+    #[test]
+    fn test_nearest_named_star_respects_limit() {
+        let catalog = generate_placeholder_catalog();
+        let sirius = catalog
+            .named_stars()
+            .into_iter()
+            .find(|s| s.name.as_deref() == Some("Sirius"))
+            .unwrap();
+
+        let offset = CelestialCoord::new(sirius.coord.ra, sirius.coord.dec + 0.5);
+        assert!(catalog.nearest_named_star(&offset, 0.1).is_none());
+    }
+}
@@ -0,0 +1,200 @@
+//! Star lookup by name or id
+//!
+//! Resolves user-typed queries to catalog stars, with prefix matching
+//! for partial names and edit-distance suggestions for typos.
+
+use super::facts::by_designation;
+use super::{Star, StarCatalog, StarId};
+
+/// Maximum edit distance for a "did you mean" suggestion
+const MAX_SUGGESTION_DISTANCE: usize = 3;
+
+/// Outcome of resolving a query against the catalog
+#[derive(Debug, Clone, PartialEq)]
+pub enum LookupResult<'a> {
+    /// Exactly one star matches
+    Found(&'a Star),
+    /// Several stars match; the query needs to be more specific
+    Ambiguous(Vec<&'a Star>),
+    /// Nothing matches; closest names by edit distance, best first
+    NotFound(Vec<&'a Star>),
+}
+
+impl StarCatalog {
+    /// Resolve a star by numeric id or name
+    ///
+    /// Numeric queries match `StarId`s. Names match case-insensitively,
+    /// first exactly, then as a Bayer or Flamsteed designation such as
+    /// "α Ori", "alf Ori" or "58 Ori", then as a prefix of one or more
+    /// star names.
+    pub fn lookup(&self, query: &str) -> LookupResult<'_> {
+        let query = query.trim();
+
+        if let Some(star) = query
+            .parse::<u32>()
+            .ok()
+            .and_then(|id| self.get(StarId(id)))
+        {
+            return LookupResult::Found(star);
+        }
+
+        let needle = query.to_lowercase();
+        let named = self.named_stars();
+
+        if let Some(star) = named.iter().find(|s| {
+            s.name
+                .as_deref()
+                .is_some_and(|n| n.to_lowercase() == needle)
+        }) {
+            return LookupResult::Found(star);
+        }
+
+        if let Some(star) = by_designation(query)
+            .and_then(|facts| named.iter().find(|s| s.name.as_deref() == Some(facts.name)))
+        {
+            return LookupResult::Found(star);
+        }
+
+        let mut prefixed: Vec<&Star> = named
+            .iter()
+            .copied()
+            .filter(|s| {
+                s.name
+                    .as_deref()
+                    .is_some_and(|n| n.to_lowercase().starts_with(&needle))
+            })
+            .collect();
+
+        match prefixed.len() {
+            0 => LookupResult::NotFound(self.fuzzy_search(query, 3)),
+            1 => LookupResult::Found(prefixed[0]),
+            _ => {
+                prefixed.sort_by(|a, b| a.name.cmp(&b.name));
+                LookupResult::Ambiguous(prefixed)
+            }
+        }
+    }
+
+    /// Named stars whose names are within a few edits of `query`, closest first
+    pub fn fuzzy_search(&self, query: &str, limit: usize) -> Vec<&Star> {
+        let needle = query.trim().to_lowercase();
+        let mut scored: Vec<(usize, &Star)> = self
+            .named_stars()
+            .into_iter()
+            .filter_map(|star| {
+                let name = star.name.as_deref()?.to_lowercase();
+                let distance = edit_distance(&needle, &name);
+                (distance <= MAX_SUGGESTION_DISTANCE).then_some((distance, star))
+            })
+            .collect();
+
+        scored.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.name.cmp(&b.1.name)));
+        scored.into_iter().take(limit).map(|(_, s)| s).collect()
+    }
+}
+
+/// Levenshtein distance between two strings
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::generate_placeholder_catalog;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("sirius", "sirius"), 0);
+        assert_eq!(edit_distance("sirus", "sirius"), 1);
+        assert_eq!(edit_distance("", "vega"), 4);
+        // A swapped pair is two substitutions
+        assert_eq!(edit_distance("vgea", "vega"), 2);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_lookup_by_name_is_case_insensitive() {
+        let catalog = generate_placeholder_catalog();
+        match catalog.lookup("sIRIUS") {
+            LookupResult::Found(star) => assert_eq!(star.name.as_deref(), Some("Sirius")),
+            other => panic!("expected Sirius, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_lookup_by_id() {
+        let catalog = generate_placeholder_catalog();
+        let vega = catalog
+            .named_stars()
+            .into_iter()
+            .find(|s| s.name.as_deref() == Some("Vega"))
+            .unwrap();
+
+        assert_eq!(
+            catalog.lookup(&vega.id.0.to_string()),
+            LookupResult::Found(vega)
+        );
+    }
+
+    #[test]
+    fn test_lookup_by_unique_prefix() {
+        let catalog = generate_placeholder_catalog();
+        match catalog.lookup("Betel") {
+            LookupResult::Found(star) => assert_eq!(star.name.as_deref(), Some("Betelgeuse")),
+            other => panic!("expected Betelgeuse, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_lookup_by_designation() {
+        let catalog = generate_placeholder_catalog();
+        for query in ["α Ori", "alf Ori", "58 ori"] {
+            match catalog.lookup(query) {
+                LookupResult::Found(star) => {
+                    assert_eq!(star.name.as_deref(), Some("Betelgeuse"), "{}", query)
+                }
+                other => panic!("expected Betelgeuse for {}, got {:?}", query, other),
+            }
+        }
+        assert!(matches!(catalog.lookup("ω Ori"), LookupResult::NotFound(_)));
+    }
+
+    #[test]
+    fn test_lookup_ambiguous_prefix() {
+        let catalog = generate_placeholder_catalog();
+        match catalog.lookup("Al") {
+            LookupResult::Ambiguous(stars) => {
+                assert!(stars.len() > 1);
+                assert!(stars
+                    .iter()
+                    .all(|s| s.name.as_deref().unwrap().starts_with("Al")));
+            }
+            other => panic!("expected ambiguity, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_lookup_typo_suggests() {
+        let catalog = generate_placeholder_catalog();
+        match catalog.lookup("Siruis") {
+            LookupResult::NotFound(suggestions) => {
+                assert_eq!(suggestions[0].name.as_deref(), Some("Sirius"));
+            }
+            other => panic!("expected no match, got {:?}", other),
+        }
+    }
+}
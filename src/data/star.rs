@@ -0,0 +1,153 @@
+//! Star and coordinate types
+
+use serde::{Deserialize, Serialize};
+
+/// Unique identifier of a star in the catalog
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct StarId(pub u32);
+
+/// A position on the celestial sphere in equatorial coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CelestialCoord {
+    /// Right ascension in hours, 0 to 24
+    pub ra: f64,
+
+    /// Declination in degrees, -90 to +90
+    pub dec: f64,
+}
+
+impl CelestialCoord {
+    /// A coordinate from RA in hours and Dec in degrees
+    ///
+    /// # Panics
+    ///
+    /// If either value is out of range; use [`CelestialCoord::new_wrapped`]
+    /// for values computed from screen positions.
+    pub fn new(ra: f64, dec: f64) -> Self {
+        assert!(
+            (0.0..=24.0).contains(&ra),
+            "RA must be between 0 and 24 hours"
+        );
+        assert!(
+            (-90.0..=90.0).contains(&dec),
+            "Dec must be between -90 and +90 degrees"
+        );
+        Self { ra, dec }
+    }
+
+    /// A coordinate with RA wrapped into 0 to 24 hours and Dec clamped to
+    /// the poles
+    pub fn new_wrapped(ra: f64, dec: f64) -> Self {
+        Self {
+            ra: ra.rem_euclid(24.0),
+            dec: dec.clamp(-90.0, 90.0),
+        }
+    }
+}
+
+/// A star in the catalog
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Star {
+    /// Unique identifier
+    pub id: StarId,
+
+    /// Proper name, for the stars that have one
+    pub name: Option<String>,
+
+    /// Position on the sky
+    pub coord: CelestialCoord,
+
+    /// Apparent visual magnitude; lower is brighter
+    pub magnitude: f64,
+
+    /// IAU constellation abbreviation, such as "Ori"
+    pub constellation: Option<String>,
+}
+
+impl Star {
+    /// Whether the star has a proper name and so can be quizzed
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    /// The proper name, or a catalog designation for an unnamed star
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("HD {}", self.id.0),
+        }
+    }
+
+    /// Radius to draw the star at, growing from `base_radius` for the
+    /// faintest stars with each magnitude of brightness
+    pub fn render_radius(&self, base_radius: f64) -> f64 {
+        base_radius * (1.0 + (6.5 - self.magnitude).max(0.0) * 0.3)
+    }
+}
+
+/// Brightness classes used for catalog statistics
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrightnessCategory {
+    /// The handful of stars brighter than magnitude 1
+    Brilliant,
+    /// Stars that stand out from a city
+    Bright,
+    /// Stars visible from the suburbs
+    Medium,
+    /// Stars that need a dark sky
+    Faint,
+    /// Stars at the edge of naked-eye visibility
+    VeryFaint,
+}
+
+impl BrightnessCategory {
+    /// Magnitude every star in the category is brighter than
+    pub fn magnitude_limit(&self) -> f64 {
+        match self {
+            BrightnessCategory::Brilliant => 1.0,
+            BrightnessCategory::Bright => 2.5,
+            BrightnessCategory::Medium => 4.0,
+            BrightnessCategory::Faint => 5.5,
+            BrightnessCategory::VeryFaint => 6.5,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn star(name: Option<&str>, magnitude: f64) -> Star {
+        Star {
+            id: StarId(7),
+            name: name.map(str::to_string),
+            coord: CelestialCoord::new(6.0, 0.0),
+            magnitude,
+            constellation: None,
+        }
+    }
+
+    #[test]
+    fn test_display_name() {
+        assert_eq!(star(Some("Vega"), 0.03).display_name(), "Vega");
+        assert_eq!(star(None, 5.0).display_name(), "HD 7");
+    }
+
+    #[test]
+    fn test_brighter_stars_render_larger() {
+        assert!(star(None, 0.0).render_radius(1.0) > star(None, 5.0).render_radius(1.0));
+        assert_eq!(star(None, 7.0).render_radius(2.0), 2.0);
+    }
+
+    #[test]
+    fn test_new_wrapped() {
+        let coord = CelestialCoord::new_wrapped(-1.0, 95.0);
+        assert_eq!(coord, CelestialCoord::new(23.0, 90.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "RA must be between 0 and 24 hours")]
+    fn test_new_rejects_out_of_range_ra() {
+        CelestialCoord::new(25.0, 0.0);
+    }
+}
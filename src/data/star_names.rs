@@ -0,0 +1,184 @@
+//! Star names in other traditions
+//!
+//! Most proper names in the catalog came to Europe from Arabic, and
+//! Chinese astronomy named its stars by their place in an asterism, so
+//! Betelgeuse is يد الجوزاء, "hand of al-Jawzā'", and 参宿四, "fourth star
+//! of the Three Stars". Star records carry only the IAU name, so the
+//! others are looked up here by it. Only the brighter named stars are
+//! covered, and a few have no Arabic name to give.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A tradition star names can be given in
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Lang {
+    /// The IAU's proper names, as in the catalog
+    #[default]
+    Iau,
+    /// Arabic names, which most IAU names come from
+    Arabic,
+    /// Traditional Chinese asterism names
+    Chinese,
+}
+
+impl Lang {
+    /// All traditions, in the order the settings panel shows them
+    pub const ALL: [Lang; 3] = [Lang::Iau, Lang::Arabic, Lang::Chinese];
+
+    /// BCP 47 language tag of names in this tradition
+    pub fn code(&self) -> &'static str {
+        match self {
+            Lang::Iau => "en",
+            Lang::Arabic => "ar",
+            Lang::Chinese => "zh",
+        }
+    }
+}
+
+/// A named star's names in the other traditions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraditionalNames {
+    /// IAU name, as in the catalog
+    pub name: &'static str,
+    pub arabic: Option<&'static str>,
+    pub chinese: Option<&'static str>,
+}
+
+const fn n(
+    name: &'static str,
+    arabic: Option<&'static str>,
+    chinese: Option<&'static str>,
+) -> TraditionalNames {
+    TraditionalNames {
+        name,
+        arabic,
+        chinese,
+    }
+}
+
+/// Names of the bright named stars, sorted by IAU name
+pub static STAR_NAMES: [TraditionalNames; 55] = [
+    n("Achernar", Some("آخر النهر"), Some("水委一")),
+    n("Acrux", None, Some("十字架二")),
+    n("Adhara", Some("العذارى"), Some("弧矢七")),
+    n("Albireo", None, Some("辇道增七")),
+    n("Aldebaran", Some("الدبران"), Some("毕宿五")),
+    n("Algieba", Some("الجبهة"), Some("轩辕十二")),
+    n("Algol", Some("رأس الغول"), Some("大陵五")),
+    n("Alhena", Some("الهنعة"), Some("井宿三")),
+    n("Alioth", None, Some("玉衡")),
+    n("Alkaid", Some("القائد"), Some("摇光")),
+    n("Alnair", Some("النير"), Some("鹤一")),
+    n("Alnilam", Some("النظام"), Some("参宿二")),
+    n("Alnitak", Some("النطاق"), Some("参宿一")),
+    n("Alphard", Some("الفرد"), Some("星宿一")),
+    n("Alpheratz", Some("سرة الفرس"), Some("壁宿二")),
+    n("Altair", Some("النسر الطائر"), Some("河鼓二")),
+    n("Antares", Some("قلب العقرب"), Some("心宿二")),
+    n("Arcturus", Some("السماك الرامح"), Some("大角")),
+    n("Bellatrix", None, Some("参宿五")),
+    n("Betelgeuse", Some("يد الجوزاء"), Some("参宿四")),
+    n("Canopus", Some("سهيل"), Some("老人星")),
+    n("Capella", Some("العيوق"), Some("五车二")),
+    n("Castor", None, Some("北河二")),
+    n("Deneb", Some("ذنب الدجاجة"), Some("天津四")),
+    n("Denebola", Some("ذنب الأسد"), Some("五帝座一")),
+    n("Diphda", Some("الضفدع الثاني"), Some("土司空")),
+    n("Dubhe", Some("ظهر الدب"), Some("天枢")),
+    n("Elnath", Some("الناطح"), Some("五车五")),
+    n("Enif", Some("أنف الفرس"), Some("危宿三")),
+    n("Fomalhaut", Some("فم الحوت"), Some("北落师门")),
+    n("Hadar", None, Some("马腹一")),
+    n("Hamal", Some("الحمل"), Some("娄宿三")),
+    n("Kaus Australis", None, Some("箕宿三")),
+    n("Kochab", None, Some("北极二")),
+    n("Menkalinan", Some("منكب ذي العنان"), Some("五车三")),
+    n("Mintaka", Some("المنطقة"), Some("参宿三")),
+    n("Mirach", None, Some("奎宿九")),
+    n("Mirfak", Some("مرفق الثريا"), Some("天船三")),
+    n("Mizar", Some("المئزر"), Some("开阳")),
+    n("Nunki", None, Some("斗宿四")),
+    n("Polaris", None, Some("勾陈一")),
+    n("Pollux", None, Some("北河三")),
+    n("Procyon", Some("الشعرى الشامية"), Some("南河三")),
+    n("Rasalhague", Some("رأس الحواء"), Some("候")),
+    n("Regulus", Some("قلب الأسد"), Some("轩辕十四")),
+    n("Rigel", Some("رجل الجبار"), Some("参宿七")),
+    n("Rigil Kentaurus", Some("رجل القنطورس"), Some("南门二")),
+    n("Sadr", Some("صدر الدجاجة"), Some("天津一")),
+    n("Saiph", Some("سيف الجبار"), Some("参宿六")),
+    n("Schedar", Some("الصدر"), Some("王良四")),
+    n("Shaula", Some("الشولة"), Some("尾宿八")),
+    n("Sirius", Some("الشعرى اليمانية"), Some("天狼星")),
+    n("Spica", Some("السماك الأعزل"), Some("角宿一")),
+    n("Vega", Some("النسر الواقع"), Some("织女一")),
+    n("Wezen", Some("الوزن"), Some("弧矢一")),
+];
+
+fn traditional_names(name: &str) -> Option<&'static TraditionalNames> {
+    STAR_NAMES
+        .binary_search_by(|names| names.name.cmp(name))
+        .ok()
+        .map(|i| &STAR_NAMES[i])
+}
+
+/// The name of the star with IAU name `name` in `lang`, if known
+pub fn star_name(name: &str, lang: Lang) -> Option<&'static str> {
+    let names = traditional_names(name)?;
+    match lang {
+        Lang::Iau => Some(names.name),
+        Lang::Arabic => names.arabic,
+        Lang::Chinese => names.chinese,
+    }
+}
+
+/// Every known name of the star with IAU name `name`, by tradition
+pub fn names(name: &str) -> BTreeMap<Lang, &'static str> {
+    Lang::ALL
+        .into_iter()
+        .filter_map(|lang| Some((lang, star_name(name, lang)?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_by_name() {
+        assert!(STAR_NAMES
+            .windows(2)
+            .all(|pair| pair[0].name < pair[1].name));
+        assert!(STAR_NAMES.iter().all(|names| names.chinese.is_some()));
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(star_name("Betelgeuse", Lang::Chinese), Some("参宿四"));
+        assert_eq!(star_name("Aldebaran", Lang::Arabic), Some("الدبران"));
+        assert_eq!(star_name("Sirius", Lang::Iau), Some("Sirius"));
+        assert_eq!(star_name("Polaris", Lang::Arabic), None);
+        assert_eq!(star_name("Nowhere", Lang::Iau), None);
+
+        let rigel = names("Rigel");
+        assert_eq!(rigel.len(), 3);
+        assert_eq!(rigel.values().next(), Some(&"Rigel"));
+        assert_eq!(
+            names("Castor").keys().copied().collect::<Vec<_>>(),
+            [Lang::Iau, Lang::Chinese]
+        );
+        assert!(names("Nowhere").is_empty());
+    }
+
+    #[test]
+    fn test_serializes_lowercase() {
+        assert_eq!(
+            serde_json::to_string(&Lang::Chinese).unwrap(),
+            "\"chinese\""
+        );
+    }
+}
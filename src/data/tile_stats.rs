@@ -0,0 +1,139 @@
+//! Tile system statistics
+//!
+//! Per-zoom summaries of how named stars are spread across tiles, used to
+//! judge how much material the tile-aware distractor selection has to
+//! work with.
+
+use super::{StarCatalog, TileId, TileSystem, ZoomLevel};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+
+/// Distribution of named stars per tile at one zoom level
+#[derive(Debug, Clone, PartialEq)]
+pub struct TileZoomSummary {
+    pub zoom: ZoomLevel,
+    /// Tiles containing at least one star
+    pub tiles: usize,
+    /// Tiles without any named star
+    pub empty_tiles: usize,
+    pub min_named: usize,
+    pub median_named: usize,
+    pub max_named: usize,
+    /// Named stars across all tiles at this zoom
+    pub named_total: usize,
+}
+
+impl TileZoomSummary {
+    /// Summarize a list of per-tile named-star counts
+    pub fn from_counts(zoom: ZoomLevel, mut counts: Vec<usize>) -> Self {
+        counts.sort_unstable();
+        Self {
+            zoom,
+            tiles: counts.len(),
+            empty_tiles: counts.iter().filter(|&&c| c == 0).count(),
+            min_named: counts.first().copied().unwrap_or(0),
+            median_named: counts.get(counts.len() / 2).copied().unwrap_or(0),
+            max_named: counts.last().copied().unwrap_or(0),
+            named_total: counts.iter().sum(),
+        }
+    }
+}
+
+/// Summarize the tiles of every zoom level that holds catalog stars
+pub fn summarize_tiles(catalog: &StarCatalog, tiles: &TileSystem) -> Vec<TileZoomSummary> {
+    let mut by_zoom: BTreeMap<u8, HashSet<&TileId>> = BTreeMap::new();
+    for star in catalog.all_stars() {
+        for tile_id in tiles.get_tiles_for_star(star.id).into_iter().flatten() {
+            by_zoom.entry(tile_id.zoom.0).or_default().insert(tile_id);
+        }
+    }
+
+    by_zoom
+        .into_iter()
+        .map(|(zoom, ids)| {
+            let counts = ids
+                .into_iter()
+                .map(|id| tiles.get_tile(id).map_or(0, |t| t.named_star_ids.len()))
+                .collect();
+            TileZoomSummary::from_counts(ZoomLevel(zoom), counts)
+        })
+        .collect()
+}
+
+/// Table of per-zoom summaries for terminal output
+pub struct TileSummaryTable<'a>(pub &'a [TileZoomSummary]);
+
+impl fmt::Display for TileSummaryTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>4} {:>6} {:>6} {:>5} {:>6} {:>5}",
+            "Zoom", "Tiles", "Empty", "Min", "Median", "Max"
+        )?;
+        for s in self.0 {
+            writeln!(
+                f,
+                "{:>4} {:>6} {:>6} {:>5} {:>6} {:>5}",
+                s.zoom.0, s.tiles, s.empty_tiles, s.min_named, s.median_named, s.max_named
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::generate_placeholder_catalog;
+
+    #[test]
+    fn test_from_counts() {
+        let summary = TileZoomSummary::from_counts(ZoomLevel(2), vec![3, 0, 1, 0, 5]);
+        assert_eq!(
+            summary,
+            TileZoomSummary {
+                zoom: ZoomLevel(2),
+                tiles: 5,
+                empty_tiles: 2,
+                min_named: 0,
+                median_named: 1,
+                max_named: 5,
+                named_total: 9,
+            }
+        );
+    }
+
+    #[test]
+    fn test_summary_table_snapshot() {
+        let summaries = [
+            TileZoomSummary::from_counts(ZoomLevel(0), vec![32]),
+            TileZoomSummary::from_counts(ZoomLevel(1), vec![0, 4, 10, 18]),
+        ];
+        assert_eq!(
+            TileSummaryTable(&summaries).to_string(),
+            "\
+Zoom  Tiles  Empty   Min Median   Max
+   0      1      0    32     32    32
+   1      4      1     0     10    18
+"
+        );
+    }
+
+    #[test]
+    fn test_placeholder_catalog_summary() {
+        let catalog = generate_placeholder_catalog();
+        let stars: Vec<_> = catalog.all_stars().cloned().collect();
+        let tiles = TileSystem::from_stars(&stars);
+
+        let summaries = summarize_tiles(&catalog, &tiles);
+        assert!(!summaries.is_empty());
+        for summary in &summaries {
+            // Every named star lives in exactly one tile per zoom level
+            assert_eq!(summary.named_total, catalog.named_count());
+            assert!(summary.min_named <= summary.median_named);
+            assert!(summary.median_named <= summary.max_named);
+        }
+        // Tiles only get finer as zoom increases
+        assert!(summaries.windows(2).all(|w| w[0].tiles <= w[1].tiles));
+    }
+}
@@ -0,0 +1,188 @@
+//! Tile system for level-of-detail queries
+//!
+//! The sky is cut into a grid at each zoom level: zoom `z` has `2^z`
+//! columns of right ascension and `2^z` rows of declination, row 0 at the
+//! north pole. Every star is filed in one tile per zoom level, so quiz
+//! distractors can come from the stars around the answer and the map can
+//! skip tiles out of view.
+
+use super::star::{Star, StarId};
+use std::collections::HashMap;
+
+/// Finest zoom level tiles are built for
+const MAX_ZOOM: u8 = 5;
+
+/// A level of the tile grid; higher is finer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ZoomLevel(pub u8);
+
+/// Address of one tile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TileId {
+    /// Zoom level of the grid the tile belongs to
+    pub zoom: ZoomLevel,
+
+    /// Column, counted eastward from 0h
+    pub x: u32,
+
+    /// Row, counted southward from the north pole
+    pub y: u32,
+}
+
+impl TileId {
+    /// The tile of `zoom` holding a star at `star`'s position
+    fn containing(star: &Star, zoom: ZoomLevel) -> Self {
+        let cells = 1u32 << zoom.0;
+        let cell = |fraction: f64| ((fraction * f64::from(cells)) as u32).min(cells - 1);
+        TileId {
+            zoom,
+            x: cell(star.coord.ra / 24.0),
+            y: cell((90.0 - star.coord.dec) / 180.0),
+        }
+    }
+}
+
+/// The stars filed in one tile
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tile {
+    /// Where the tile is
+    pub id: TileId,
+
+    /// Every star in the tile
+    pub star_ids: Vec<StarId>,
+
+    /// The stars in the tile with a proper name
+    pub named_star_ids: Vec<StarId>,
+}
+
+/// Tiles at every zoom level, holding only tiles with stars in them
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TileSystem {
+    tiles: HashMap<TileId, Tile>,
+    star_tiles: HashMap<StarId, Vec<TileId>>,
+}
+
+impl TileSystem {
+    /// An empty tile system
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// File `stars` at every zoom level
+    pub fn from_stars(stars: &[Star]) -> Self {
+        let mut system = Self::new();
+        for star in stars {
+            system.add_star(star);
+        }
+        system
+    }
+
+    /// File one star in its tile at every zoom level
+    pub fn add_star(&mut self, star: &Star) {
+        let ids: Vec<TileId> = (0..=MAX_ZOOM)
+            .map(|zoom| TileId::containing(star, ZoomLevel(zoom)))
+            .collect();
+        for id in &ids {
+            let tile = self.tiles.entry(*id).or_insert_with(|| Tile {
+                id: *id,
+                star_ids: Vec::new(),
+                named_star_ids: Vec::new(),
+            });
+            tile.star_ids.push(star.id);
+            if star.has_name() {
+                tile.named_star_ids.push(star.id);
+            }
+        }
+        self.star_tiles.insert(star.id, ids);
+    }
+
+    /// The tiles a star is filed in, coarsest first
+    pub fn get_tiles_for_star(&self, id: StarId) -> Option<&Vec<TileId>> {
+        self.star_tiles.get(&id)
+    }
+
+    /// The tile at `id`, if it holds any stars
+    pub fn get_tile(&self, id: &TileId) -> Option<&Tile> {
+        self.tiles.get(id)
+    }
+
+    /// The tiles around `id` that hold stars, wrapping in right ascension
+    /// but not over the poles
+    pub fn get_adjacent_tiles(&self, id: &TileId) -> Vec<&Tile> {
+        let cells = 1i64 << id.zoom.0;
+        let mut neighbors = Vec::new();
+        for dy in -1i64..=1 {
+            let y = i64::from(id.y) + dy;
+            if !(0..cells).contains(&y) {
+                continue;
+            }
+            for dx in -1i64..=1 {
+                let x = (i64::from(id.x) + dx).rem_euclid(cells);
+                let neighbor = TileId {
+                    zoom: id.zoom,
+                    x: x as u32,
+                    y: y as u32,
+                };
+                if neighbor == *id {
+                    continue;
+                }
+                if let Some(tile) = self.tiles.get(&neighbor) {
+                    if !neighbors.iter().any(|t: &&Tile| t.id == neighbor) {
+                        neighbors.push(tile);
+                    }
+                }
+            }
+        }
+        neighbors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::generate_placeholder_catalog;
+
+    fn tiles() -> TileSystem {
+        let stars: Vec<Star> = generate_placeholder_catalog()
+            .all_stars()
+            .cloned()
+            .collect();
+        TileSystem::from_stars(&stars)
+    }
+
+    #[test]
+    fn test_every_star_has_one_tile_per_zoom() {
+        let catalog = generate_placeholder_catalog();
+        let tiles = tiles();
+        for star in catalog.all_stars() {
+            let ids = tiles.get_tiles_for_star(star.id).unwrap();
+            let zooms: Vec<u8> = ids.iter().map(|id| id.zoom.0).collect();
+            assert_eq!(zooms, (0..=MAX_ZOOM).collect::<Vec<_>>());
+            for id in ids {
+                assert!(tiles.get_tile(id).unwrap().star_ids.contains(&star.id));
+            }
+        }
+    }
+
+    #[test]
+    fn test_zoom_zero_holds_every_named_star() {
+        let tiles = tiles();
+        let whole_sky = TileId {
+            zoom: ZoomLevel(0),
+            x: 0,
+            y: 0,
+        };
+        assert_eq!(tiles.get_tile(&whole_sky).unwrap().named_star_ids.len(), 40);
+        assert!(tiles.get_adjacent_tiles(&whole_sky).is_empty());
+    }
+
+    #[test]
+    fn test_adjacent_tiles_wrap_in_right_ascension() {
+        let tiles = tiles();
+        let zoom = ZoomLevel(2);
+        let edge = TileId { zoom, x: 0, y: 1 };
+        let adjacent = tiles.get_adjacent_tiles(&edge);
+        assert!(adjacent.iter().any(|t| t.id.x == 3));
+        assert!(adjacent.iter().all(|t| t.id != edge && t.id.zoom == zoom));
+    }
+}
@@ -0,0 +1,241 @@
+//! Tile-based view culling
+//!
+//! [`TileView`] records the RA/Dec extent of the stars in each tile of a
+//! [`TileSystem`]'s finest zoom level. A view query then walks only the
+//! tiles that overlap the visible range instead of the whole catalog,
+//! and returns the same stars as [`StarCatalog::stars_in_range`].
+
+use super::{Star, StarCatalog, StarId, TileId, TileSystem};
+use std::collections::HashSet;
+
+/// The extent of one tile's stars
+#[derive(Debug, Clone, PartialEq)]
+struct TileBounds {
+    id: TileId,
+    ra_min: f64,
+    ra_max: f64,
+    dec_min: f64,
+    dec_max: f64,
+    star_ids: Vec<StarId>,
+}
+
+impl TileBounds {
+    /// Whether any of the tile's stars could fall in the given range
+    ///
+    /// An RA range with `ra_min > ra_max` wraps through 0h, and equal
+    /// ends cover the whole circle, as in [`StarCatalog::stars_in_range`].
+    fn overlaps(&self, ra_min: f64, ra_max: f64, dec_min: f64, dec_max: f64) -> bool {
+        let dec = self.dec_max >= dec_min && self.dec_min <= dec_max;
+        let ra = if (ra_max - ra_min).abs() < 1e-9 {
+            true
+        } else if ra_min <= ra_max {
+            self.ra_max >= ra_min && self.ra_min <= ra_max
+        } else {
+            self.ra_max >= ra_min || self.ra_min <= ra_max
+        };
+        dec && ra
+    }
+}
+
+/// Whether `star` is in the range, by the rules of [`StarCatalog::stars_in_range`]
+pub fn in_range(
+    star: &Star,
+    ra_min: f64,
+    ra_max: f64,
+    dec_min: f64,
+    dec_max: f64,
+    max_magnitude: f64,
+) -> bool {
+    let ra = star.coord.ra;
+    let ra_ok = if (ra_max - ra_min).abs() < 1e-9 {
+        true
+    } else if ra_min <= ra_max {
+        ra >= ra_min && ra <= ra_max
+    } else {
+        ra >= ra_min || ra <= ra_max
+    };
+    ra_ok
+        && star.coord.dec >= dec_min
+        && star.coord.dec <= dec_max
+        && star.magnitude <= max_magnitude
+}
+
+/// Star extents for the finest zoom level of a tile system
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TileView {
+    tiles: Vec<TileBounds>,
+}
+
+impl TileView {
+    /// Index the finest zoom level of `tiles` over `catalog`'s stars
+    pub fn new(catalog: &StarCatalog, tiles: &TileSystem) -> Self {
+        let finest = catalog
+            .all_stars()
+            .flat_map(|star| tiles.get_tiles_for_star(star.id).into_iter().flatten())
+            .map(|id| id.zoom.0)
+            .max();
+        let Some(finest) = finest else {
+            return Self::default();
+        };
+
+        let mut seen = HashSet::new();
+        let mut bounds = Vec::new();
+        for star in catalog.all_stars() {
+            let Some(id) = tiles
+                .get_tiles_for_star(star.id)
+                .into_iter()
+                .flatten()
+                .find(|id| id.zoom.0 == finest)
+            else {
+                continue;
+            };
+            if !seen.insert(*id) {
+                continue;
+            }
+            let Some(tile) = tiles.get_tile(id) else {
+                continue;
+            };
+            let members: Vec<&Star> = tile
+                .star_ids
+                .iter()
+                .filter_map(|&id| catalog.get(id))
+                .collect();
+            let extent = |value: fn(&Star) -> f64| {
+                members
+                    .iter()
+                    .map(|s| value(s))
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+                        (lo.min(v), hi.max(v))
+                    })
+            };
+            let (ra_min, ra_max) = extent(|s| s.coord.ra);
+            let (dec_min, dec_max) = extent(|s| s.coord.dec);
+            bounds.push(TileBounds {
+                id: *id,
+                ra_min,
+                ra_max,
+                dec_min,
+                dec_max,
+                star_ids: members.iter().map(|s| s.id).collect(),
+            });
+        }
+        Self { tiles: bounds }
+    }
+
+    /// Number of indexed tiles
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// Tiles whose stars could fall in the range
+    pub fn tiles_in_range(
+        &self,
+        ra_min: f64,
+        ra_max: f64,
+        dec_min: f64,
+        dec_max: f64,
+    ) -> Vec<TileId> {
+        self.tiles
+            .iter()
+            .filter(|t| t.overlaps(ra_min, ra_max, dec_min, dec_max))
+            .map(|t| t.id)
+            .collect()
+    }
+
+    /// Stars in the given tiles; a superset of the stars in their range
+    pub fn candidates(&self, tile_ids: &[TileId]) -> Vec<StarId> {
+        let wanted: HashSet<&TileId> = tile_ids.iter().collect();
+        self.tiles
+            .iter()
+            .filter(|t| wanted.contains(&t.id))
+            .flat_map(|t| t.star_ids.iter().copied())
+            .collect()
+    }
+
+    /// The stars [`StarCatalog::stars_in_range`] would return, found by
+    /// walking only the overlapping tiles
+    ///
+    /// When every tile overlaps, a straight scan of the catalog is cheaper
+    /// than looking each star up, so that is used instead.
+    pub fn stars_in_view<'a>(
+        &self,
+        catalog: &'a StarCatalog,
+        ra_min: f64,
+        ra_max: f64,
+        dec_min: f64,
+        dec_max: f64,
+        max_magnitude: f64,
+    ) -> Vec<&'a Star> {
+        let overlapping: Vec<&TileBounds> = self
+            .tiles
+            .iter()
+            .filter(|t| t.overlaps(ra_min, ra_max, dec_min, dec_max))
+            .collect();
+        if overlapping.len() == self.tiles.len() {
+            return catalog.stars_in_range(ra_min, ra_max, dec_min, dec_max, max_magnitude);
+        }
+        overlapping
+            .into_iter()
+            .flat_map(|t| t.star_ids.iter())
+            .filter_map(|&id| catalog.get(id))
+            .filter(|star| in_range(star, ra_min, ra_max, dec_min, dec_max, max_magnitude))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::generate_placeholder_catalog;
+
+    fn ids(stars: &[&Star]) -> Vec<u32> {
+        let mut ids: Vec<u32> = stars.iter().map(|s| s.id.0).collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    fn view() -> (StarCatalog, TileView) {
+        let catalog = generate_placeholder_catalog();
+        let stars: Vec<Star> = catalog.all_stars().cloned().collect();
+        let view = TileView::new(&catalog, &TileSystem::from_stars(&stars));
+        (catalog, view)
+    }
+
+    #[test]
+    fn test_every_star_is_in_one_tile() {
+        let (catalog, view) = view();
+        assert!(!view.is_empty());
+        let all: Vec<TileId> = view.tiles.iter().map(|t| t.id).collect();
+        assert_eq!(view.candidates(&all).len(), catalog.count());
+    }
+
+    #[test]
+    fn test_small_range_touches_few_tiles() {
+        let (catalog, view) = view();
+        let tiles = view.tiles_in_range(5.0, 6.5, -10.0, 10.0);
+        assert!(!tiles.is_empty());
+        assert!(tiles.len() < view.len());
+
+        let found = view.stars_in_view(&catalog, 5.0, 6.5, -10.0, 10.0, 6.0);
+        let expected = catalog.stars_in_range(5.0, 6.5, -10.0, 10.0, 6.0);
+        assert_eq!(ids(&found), ids(&expected));
+        assert!(found
+            .iter()
+            .any(|s| s.name.as_deref() == Some("Betelgeuse")));
+    }
+
+    #[test]
+    fn test_wrapped_and_full_ranges() {
+        let (catalog, view) = view();
+        for (ra_min, ra_max) in [(22.0, 2.0), (3.0, 3.0)] {
+            assert_eq!(
+                ids(&view.stars_in_view(&catalog, ra_min, ra_max, -90.0, 90.0, 6.5)),
+                ids(&catalog.stars_in_range(ra_min, ra_max, -90.0, 90.0, 6.5))
+            );
+        }
+    }
+}
@@ -0,0 +1,286 @@
+//! Catalog validation
+//!
+//! Checks a star list for problems that would break the app (errors) or
+//! that are merely suspicious (warnings), along with the referential
+//! integrity of auxiliary data keyed by star id.
+
+use super::{Star, StarId};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+/// Magnitude range considered plausible for catalog stars
+const PLAUSIBLE_MAGNITUDE: std::ops::RangeInclusive<f64> = -1.5..=12.0;
+
+/// How serious a finding is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The catalog cannot be used as-is
+    Error,
+    /// Suspicious, but loadable
+    Warning,
+}
+
+impl Severity {
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Severity::Error => "Errors",
+            Severity::Warning => "Warnings",
+        }
+    }
+}
+
+/// A single problem found during validation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    pub severity: Severity,
+    /// The star concerned, if the problem is about one star
+    pub star: Option<StarId>,
+    pub message: String,
+}
+
+/// Constellation stick figures: abbreviation to pairs of star ids to connect
+pub type ConstellationLines = BTreeMap<String, Vec<(u32, u32)>>;
+
+/// Findings from validating a catalog
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    pub findings: Vec<Finding>,
+}
+
+impl ValidationReport {
+    /// Number of findings with the given severity
+    pub fn count(&self, severity: Severity) -> usize {
+        self.findings
+            .iter()
+            .filter(|f| f.severity == severity)
+            .count()
+    }
+
+    /// Whether validation found nothing at all
+    pub fn is_clean(&self) -> bool {
+        self.findings.is_empty()
+    }
+
+    /// Process exit code: 1 on errors, or on warnings when `strict`
+    pub fn exit_code(&self, strict: bool) -> i32 {
+        let failing =
+            self.count(Severity::Error) > 0 || (strict && self.count(Severity::Warning) > 0);
+        i32::from(failing)
+    }
+
+    fn push(&mut self, severity: Severity, star: Option<StarId>, message: String) {
+        self.findings.push(Finding {
+            severity,
+            star,
+            message,
+        });
+    }
+
+    /// Validate a star list
+    pub fn for_stars(stars: &[Star]) -> Self {
+        let mut report = Self::default();
+        let mut ids = HashSet::new();
+        let mut names: HashMap<&str, StarId> = HashMap::new();
+
+        for star in stars {
+            let id = Some(star.id);
+            if !ids.insert(star.id) {
+                report.push(Severity::Error, id, "duplicate id".to_string());
+            }
+
+            let (ra, dec) = (star.coord.ra, star.coord.dec);
+            if !(0.0..24.0).contains(&ra) {
+                report.push(Severity::Error, id, format!("RA {} outside 0..24h", ra));
+            }
+            if !(-90.0..=90.0).contains(&dec) {
+                report.push(Severity::Error, id, format!("Dec {} outside -90..90°", dec));
+            }
+            if !star.magnitude.is_finite() {
+                report.push(Severity::Error, id, "magnitude is not a number".to_string());
+            } else if !PLAUSIBLE_MAGNITUDE.contains(&star.magnitude) {
+                report.push(
+                    Severity::Warning,
+                    id,
+                    format!("implausible magnitude {:.2}", star.magnitude),
+                );
+            }
+
+            match star.name.as_deref() {
+                Some(name) if name.trim().is_empty() => {
+                    report.push(Severity::Error, id, "empty name".to_string());
+                }
+                Some(name) => {
+                    if let Some(first) = names.insert(name, star.id) {
+                        report.push(
+                            Severity::Warning,
+                            id,
+                            format!("name '{}' also used by star {}", name, first.0),
+                        );
+                    }
+                    if star.constellation.is_none() {
+                        report.push(
+                            Severity::Warning,
+                            id,
+                            format!("named star '{}' has no constellation", name),
+                        );
+                    }
+                }
+                None => {}
+            }
+
+            if let Some(constellation) = &star.constellation {
+                let is_abbreviation = constellation.len() == 3
+                    && constellation.chars().all(|c| c.is_ascii_alphabetic());
+                if !is_abbreviation {
+                    report.push(
+                        Severity::Warning,
+                        id,
+                        format!(
+                            "constellation '{}' is not a 3-letter abbreviation",
+                            constellation
+                        ),
+                    );
+                }
+            }
+        }
+
+        report
+    }
+
+    /// Check that every star referenced by constellation lines exists
+    pub fn check_constellation_lines(&mut self, stars: &[Star], lines: &ConstellationLines) {
+        let ids: HashSet<u32> = stars.iter().map(|s| s.id.0).collect();
+        for (constellation, segments) in lines {
+            for &(from, to) in segments {
+                for id in [from, to].into_iter().filter(|id| !ids.contains(id)) {
+                    self.push(
+                        Severity::Error,
+                        Some(StarId(id)),
+                        format!(
+                            "{} line {}-{} references a missing star",
+                            constellation, from, to
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for severity in [Severity::Error, Severity::Warning] {
+            let findings: Vec<_> = self
+                .findings
+                .iter()
+                .filter(|finding| finding.severity == severity)
+                .collect();
+            if findings.is_empty() {
+                continue;
+            }
+
+            writeln!(f, "{} ({}):", severity.name(), findings.len())?;
+            for finding in findings {
+                match finding.star {
+                    Some(id) => writeln!(f, "  star {}: {}", id.0, finding.message)?,
+                    None => writeln!(f, "  {}", finding.message)?,
+                }
+            }
+        }
+
+        writeln!(
+            f,
+            "Result: {} errors, {} warnings",
+            self.count(Severity::Error),
+            self.count(Severity::Warning)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{generate_placeholder_catalog, CelestialCoord};
+
+    fn star(id: u32, name: Option<&str>, ra: f64, dec: f64, magnitude: f64) -> Star {
+        Star {
+            id: StarId(id),
+            name: name.map(str::to_string),
+            coord: CelestialCoord { ra, dec },
+            magnitude,
+            constellation: Some("Ori".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_placeholder_catalog_has_no_errors() {
+        let stars: Vec<_> = generate_placeholder_catalog()
+            .all_stars()
+            .cloned()
+            .collect();
+        let report = ValidationReport::for_stars(&stars);
+        assert_eq!(report.count(Severity::Error), 0, "{}", report);
+    }
+
+    #[test]
+    fn test_errors() {
+        let stars = [
+            star(1, Some("Rigel"), 5.2, -8.2, 0.13),
+            star(1, Some("Saiph"), 5.8, -9.7, 2.09),
+            star(2, None, 25.0, 0.0, 4.0),
+            star(3, None, 1.0, -91.0, 4.0),
+            star(4, Some(" "), 1.0, 0.0, f64::NAN),
+        ];
+        let report = ValidationReport::for_stars(&stars);
+
+        assert_eq!(report.count(Severity::Error), 5, "{}", report);
+        assert_eq!(report.exit_code(false), 1);
+    }
+
+    #[test]
+    fn test_warnings() {
+        let mut unassigned = star(3, Some("Mintaka"), 5.5, -0.3, 2.23);
+        unassigned.constellation = None;
+        let stars = [
+            star(1, Some("Rigel"), 5.2, -8.2, 0.13),
+            star(2, Some("Rigel"), 5.3, -8.0, 20.0),
+            unassigned,
+        ];
+        let report = ValidationReport::for_stars(&stars);
+
+        assert_eq!(report.count(Severity::Error), 0);
+        // Duplicate name, implausible magnitude, missing constellation
+        assert_eq!(report.count(Severity::Warning), 3, "{}", report);
+        assert_eq!(report.exit_code(false), 0);
+        assert_eq!(report.exit_code(true), 1);
+    }
+
+    #[test]
+    fn test_constellation_lines_reference_existing_stars() {
+        let stars = [
+            star(1, Some("Rigel"), 5.2, -8.2, 0.13),
+            star(2, Some("Saiph"), 5.8, -9.7, 2.09),
+        ];
+        let mut lines = ConstellationLines::new();
+        lines.insert("Ori".to_string(), vec![(1, 2), (2, 9)]);
+
+        let mut report = ValidationReport::for_stars(&stars);
+        report.check_constellation_lines(&stars, &lines);
+
+        assert_eq!(report.count(Severity::Error), 1);
+        assert_eq!(report.findings[0].star, Some(StarId(9)));
+    }
+
+    #[test]
+    fn test_report_groups_by_severity() {
+        let mut report = ValidationReport::default();
+        report.push(Severity::Warning, None, "odd".to_string());
+        report.push(Severity::Error, Some(StarId(7)), "broken".to_string());
+
+        assert_eq!(
+            report.to_string(),
+            "Errors (1):\n  star 7: broken\nWarnings (1):\n  odd\nResult: 1 errors, 1 warnings\n"
+        );
+    }
+}
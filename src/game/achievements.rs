@@ -0,0 +1,209 @@
+//! Achievements
+//!
+//! Badges earned by playing: a first right answer, a streak of
+//! [`STREAK_GOAL`], every named star in Orion mastered and
+//! [`QUESTIONS_GOAL`] questions answered. The app checks them after each
+//! answer with [`newly_unlocked`]. Unlocked badges and when they were
+//! earned are kept in localStorage.
+
+use super::state::GameState;
+use crate::data::StarCatalog;
+#[cfg(feature = "web")]
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// localStorage key for persisted achievements
+pub const ACHIEVEMENTS_KEY: &str = "stargazer.achievements";
+
+/// Right answers in a row for [`Achievement::Streak`]
+pub const STREAK_GOAL: u32 = 10;
+
+/// Questions answered, across sessions, for [`Achievement::HundredQuestions`]
+pub const QUESTIONS_GOAL: u32 = 100;
+
+/// Mastery at which a star counts as mastered: three right out of three
+pub const MASTERED: f64 = 0.75;
+
+/// Catalog abbreviation of Orion
+const ORION: &str = "Ori";
+
+/// A badge that can be unlocked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Achievement {
+    /// A first right answer
+    FirstCorrect,
+    /// [`STREAK_GOAL`] right answers in a row
+    Streak,
+    /// Every named star in Orion mastered
+    OrionMastered,
+    /// [`QUESTIONS_GOAL`] questions answered
+    HundredQuestions,
+}
+
+impl Achievement {
+    /// Every achievement, in the order the trophy panel lists them
+    pub const ALL: [Achievement; 4] = [
+        Achievement::FirstCorrect,
+        Achievement::Streak,
+        Achievement::OrionMastered,
+        Achievement::HundredQuestions,
+    ];
+
+    /// Whether `state`, over `catalog`, has met this achievement's goal
+    pub fn is_earned(self, state: &GameState, catalog: &StarCatalog) -> bool {
+        let stats = &state.stats;
+        match self {
+            Achievement::FirstCorrect => stats.iter().any(|(_, record)| record.correct > 0),
+            Achievement::Streak => state.score.best_streak >= STREAK_GOAL,
+            Achievement::OrionMastered => {
+                let mut orion = catalog
+                    .all_stars()
+                    .filter(|star| star.has_name() && star.constellation.as_deref() == Some(ORION))
+                    .peekable();
+                orion.peek().is_some() && orion.all(|star| stats.mastery(star.id) >= MASTERED)
+            }
+            Achievement::HundredQuestions => {
+                stats.iter().map(|(_, record)| record.attempts).sum::<u32>() >= QUESTIONS_GOAL
+            }
+        }
+    }
+}
+
+/// The achievements unlocked so far
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Achievements {
+    /// When each was unlocked, in Unix milliseconds
+    unlocked: BTreeMap<Achievement, f64>,
+}
+
+impl Achievements {
+    /// Unlock `achievement` at `timestamp`; false if it already was
+    pub fn unlock(&mut self, achievement: Achievement, timestamp: f64) -> bool {
+        if self.unlocked.contains_key(&achievement) {
+            return false;
+        }
+        self.unlocked.insert(achievement, timestamp);
+        true
+    }
+
+    /// When `achievement` was unlocked, if it was
+    pub fn unlocked_at(&self, achievement: Achievement) -> Option<f64> {
+        self.unlocked.get(&achievement).copied()
+    }
+
+    pub fn is_unlocked(&self, achievement: Achievement) -> bool {
+        self.unlocked.contains_key(&achievement)
+    }
+
+    /// How many are unlocked
+    pub fn len(&self) -> usize {
+        self.unlocked.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.unlocked.is_empty()
+    }
+}
+
+#[cfg(feature = "web")]
+impl Achievements {
+    /// Load achievements from localStorage, starting with none
+    pub fn load() -> Self {
+        LocalStorage::get(ACHIEVEMENTS_KEY).unwrap_or_default()
+    }
+
+    /// Save achievements to localStorage
+    pub fn save(&self) {
+        if let Err(e) = LocalStorage::set(ACHIEVEMENTS_KEY, self) {
+            log::warn!("Failed to save achievements: {}", e);
+        }
+    }
+}
+
+/// Achievements `state` has earned that are not unlocked yet
+pub fn newly_unlocked(state: &GameState, catalog: &StarCatalog) -> Vec<Achievement> {
+    Achievement::ALL
+        .into_iter()
+        .filter(|&achievement| {
+            !state.achievements.is_unlocked(achievement) && achievement.is_earned(state, catalog)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{catalog_of, StarId};
+    use std::rc::Rc;
+
+    fn catalog() -> StarCatalog {
+        catalog_of(&[("Rigel", "Ori"), ("Betelgeuse", "Ori"), ("Vega", "Lyr")])
+    }
+
+    fn answer(state: &mut GameState, star: u32, correct: bool) {
+        Rc::make_mut(&mut state.stats).record(StarId(star), correct, 0.0);
+        if correct {
+            state.score.record_correct();
+        } else {
+            state.score.record_incorrect();
+        }
+    }
+
+    #[test]
+    fn test_goals() {
+        let catalog = catalog();
+        let mut state = GameState::default();
+        assert!(newly_unlocked(&state, &catalog).is_empty());
+
+        answer(&mut state, 3, false);
+        assert!(newly_unlocked(&state, &catalog).is_empty());
+        answer(&mut state, 3, true);
+        assert_eq!(
+            newly_unlocked(&state, &catalog),
+            vec![Achievement::FirstCorrect]
+        );
+
+        // Three right answers about each star in Orion
+        for _ in 0..3 {
+            answer(&mut state, 1, true);
+        }
+        assert!(!Achievement::OrionMastered.is_earned(&state, &catalog));
+        for _ in 0..3 {
+            answer(&mut state, 2, true);
+        }
+        assert!(Achievement::OrionMastered.is_earned(&state, &catalog));
+        assert!(!Achievement::Streak.is_earned(&state, &catalog));
+
+        for _ in 0..4 {
+            answer(&mut state, 3, true);
+        }
+        assert!(Achievement::Streak.is_earned(&state, &catalog));
+        assert!(!Achievement::HundredQuestions.is_earned(&state, &catalog));
+        for _ in 0..88 {
+            answer(&mut state, 3, false);
+        }
+        assert!(Achievement::HundredQuestions.is_earned(&state, &catalog));
+
+        // Nothing in Orion, nothing to master
+        assert!(!Achievement::OrionMastered.is_earned(&state, &StarCatalog::new()));
+    }
+
+    #[test]
+    fn test_unlocked_once_and_persisted_as_json() {
+        let mut achievements = Achievements::default();
+        assert!(achievements.unlock(Achievement::Streak, 1000.0));
+        assert!(!achievements.unlock(Achievement::Streak, 2000.0));
+        assert_eq!(achievements.unlocked_at(Achievement::Streak), Some(1000.0));
+        assert_eq!(achievements.len(), 1);
+
+        let json = serde_json::to_string(&achievements).unwrap();
+        assert_eq!(json, r#"{"unlocked":{"streak":1000.0}}"#);
+        assert_eq!(
+            serde_json::from_str::<Achievements>(&json).unwrap(),
+            achievements
+        );
+    }
+}
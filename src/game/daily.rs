@@ -0,0 +1,324 @@
+//! Star of the day and the daily challenge
+//!
+//! Picks one named star per UTC date. Each day hashes its day number into
+//! a draw over the named stars, weighted toward the best known, excluding
+//! the stars picked on the previous [`EXCLUSION_DAYS`] days. Because every
+//! pick depends on the ones before it, the sequence is replayed from
+//! [`SEQUENCE_START`]; the same date and catalog always give the same star.
+//!
+//! The [`DailyChallenge`] is [`CHALLENGE_LENGTH`] questions drawn from a
+//! seed hashed from the date, so every player gets the same ones that day.
+
+use super::quiz::{QuizConfig, QuizGenerator, QuizQuestion};
+use super::review::introduction_order;
+use super::share::grid;
+use crate::data::{Star, StarCatalog, StarId};
+use crate::utils::dates::format_date;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Day number of 2000-01-01, the first day with a star of the day
+pub const SEQUENCE_START: i64 = 10_957;
+
+/// Days before a star can be picked again
+pub const EXCLUSION_DAYS: usize = 30;
+
+/// Mixed into the day number so the draw is not a plain function of the date
+const SALT: u64 = 0x5741_5247_415a_4552;
+
+/// Questions in a daily challenge
+pub const CHALLENGE_LENGTH: usize = 10;
+
+/// Mixed into the day number for the challenge seed, so the challenge does
+/// not follow the star of the day
+const CHALLENGE_SALT: u64 = 0x4441_494c_5951_555a;
+
+/// SplitMix64 finalizer: a fixed, platform-independent 64-bit hash
+fn mix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+/// Uniform draw in `0.0..1.0` for a day
+fn draw(day: i64) -> f64 {
+    (mix(day as u64 ^ SALT) >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Pick from `ranked` (best known first), skipping `excluded`
+///
+/// A star's weight falls off with the square root of its rank, so famous
+/// stars come up often without crowding out the rest.
+fn pick<'a>(ranked: &[&'a Star], excluded: &VecDeque<StarId>, day: i64) -> Option<&'a Star> {
+    let candidates: Vec<(&Star, f64)> = ranked
+        .iter()
+        .enumerate()
+        .filter(|(_, star)| !excluded.contains(&star.id))
+        .map(|(rank, star)| (*star, 1.0 / ((rank + 1) as f64).sqrt()))
+        .collect();
+
+    let total: f64 = candidates.iter().map(|(_, weight)| weight).sum();
+    let mut target = draw(day) * total;
+    for &(star, weight) in &candidates {
+        if target < weight {
+            return Some(star);
+        }
+        target -= weight;
+    }
+    candidates.last().map(|(star, _)| *star)
+}
+
+/// Stars of the day from [`SEQUENCE_START`] through `day`, in date order
+///
+/// Empty when `day` is before the start or the catalog has no named stars.
+pub fn picks_through(catalog: &StarCatalog, day: i64) -> Vec<&Star> {
+    let ranked = introduction_order(catalog);
+    // Small catalogs cannot exclude a full month
+    let window = EXCLUSION_DAYS.min(ranked.len().saturating_sub(1));
+    let mut recent = VecDeque::with_capacity(window + 1);
+    let mut picks = Vec::new();
+
+    for d in SEQUENCE_START..=day {
+        let Some(star) = pick(&ranked, &recent, d) else {
+            break;
+        };
+        picks.push(star);
+        recent.push_back(star.id);
+        if recent.len() > window {
+            recent.pop_front();
+        }
+    }
+    picks
+}
+
+/// The star of the day for `day`
+pub fn star_of_the_day(catalog: &StarCatalog, day: i64) -> Option<&Star> {
+    picks_through(catalog, day).pop()
+}
+
+/// A short fact about a star's standing among the named stars
+pub fn star_fact(catalog: &StarCatalog, star: &Star) -> String {
+    let name = star.display_name();
+    let ranked = introduction_order(catalog);
+    let Some(constellation) = &star.constellation else {
+        let rank = ranked.iter().position(|s| s.id == star.id).unwrap_or(0) + 1;
+        return format!(
+            "{} is number {} among the brightest named stars.",
+            name, rank
+        );
+    };
+
+    let in_constellation: Vec<&&Star> = ranked
+        .iter()
+        .filter(|s| s.constellation.as_ref() == Some(constellation))
+        .collect();
+    match in_constellation.iter().position(|s| s.id == star.id) {
+        Some(0) if in_constellation.len() > 1 => format!(
+            "{} is the brightest of the {} named stars in {}.",
+            name,
+            in_constellation.len(),
+            constellation
+        ),
+        Some(0) | None => format!("{} is the only named star in {}.", name, constellation),
+        Some(index) => format!(
+            "{} is number {} of the {} named stars in {}, by brightness.",
+            name,
+            index + 1,
+            in_constellation.len(),
+            constellation
+        ),
+    }
+}
+
+/// Seed for the questions of the challenge on `day`
+pub fn challenge_seed(day: i64) -> u64 {
+    mix(day as u64 ^ CHALLENGE_SALT)
+}
+
+/// How challenge questions are asked, whatever the player's settings
+///
+/// Without "none of above", so the challenge can be answered by typing.
+pub fn challenge_config() -> QuizConfig {
+    QuizConfig {
+        include_none_option: false,
+        ..QuizConfig::default()
+    }
+}
+
+/// One day's challenge and the answers given so far
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyChallenge {
+    /// Day number of the challenge
+    pub day: i64,
+
+    /// The questions, in the order they are asked
+    pub questions: Vec<QuizQuestion>,
+
+    /// Whether each question answered so far was right
+    pub results: Vec<bool>,
+}
+
+impl DailyChallenge {
+    /// The challenge for `day`
+    ///
+    /// Drawn with [`StdRng`], whose sequence is the same on every platform,
+    /// so the browser and the CLI agree on the questions.
+    pub fn new(catalog: &StarCatalog, day: i64) -> Self {
+        let mut rng = StdRng::seed_from_u64(challenge_seed(day));
+        let questions = QuizGenerator::new(catalog, challenge_config())
+            .generate_batch(CHALLENGE_LENGTH, &mut rng);
+        Self {
+            day,
+            questions,
+            results: Vec::with_capacity(CHALLENGE_LENGTH),
+        }
+    }
+
+    /// The first question not yet answered, if any
+    pub fn next_question(&self) -> Option<&QuizQuestion> {
+        self.questions.get(self.results.len())
+    }
+
+    /// Count an answer about `star`, if it is the next question's
+    pub fn record(&mut self, star: StarId, correct: bool) {
+        if self.next_question().is_some_and(|q| q.target_star == star) {
+            self.results.push(correct);
+        }
+    }
+
+    /// Whether every question has been answered
+    pub fn is_complete(&self) -> bool {
+        self.results.len() >= self.questions.len()
+    }
+
+    /// Number of right answers
+    pub fn correct(&self) -> usize {
+        self.results.iter().filter(|&&right| right).count()
+    }
+
+    /// Shareable summary: date, score and a grid with one mark per question
+    pub fn share_text(&self) -> String {
+        format!(
+            "Stargazer Daily {}: {}/{}\n{}",
+            format_date(self.day),
+            self.correct(),
+            self.questions.len(),
+            grid(&self.results)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::generate_placeholder_catalog;
+    use crate::utils::dates::days_from_civil;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_sequence_start_is_2000_01_01() {
+        assert_eq!(SEQUENCE_START, days_from_civil(2000, 1, 1));
+    }
+
+    #[test]
+    fn test_pinned_dates() {
+        let catalog = generate_placeholder_catalog();
+        let name = |y, m, d| {
+            star_of_the_day(&catalog, days_from_civil(y, m, d))
+                .and_then(|s| s.name.clone())
+                .unwrap()
+        };
+        assert_eq!(name(2025, 3, 1), "Dubhe");
+        assert_eq!(name(2025, 3, 2), "Porrima");
+        assert_eq!(name(2030, 12, 31), "Algol");
+    }
+
+    #[test]
+    fn test_same_date_same_star() {
+        let catalog = generate_placeholder_catalog();
+        let day = days_from_civil(2024, 6, 21);
+        assert_eq!(
+            star_of_the_day(&catalog, day).map(|s| s.id),
+            star_of_the_day(&catalog, day).map(|s| s.id)
+        );
+    }
+
+    #[test]
+    fn test_no_repeat_within_exclusion_window() {
+        let catalog = generate_placeholder_catalog();
+        let span = EXCLUSION_DAYS.min(catalog.named_count() - 1);
+        let picks = picks_through(&catalog, days_from_civil(2002, 1, 1));
+
+        for window in picks.windows(span + 1) {
+            let ids: HashSet<StarId> = window.iter().map(|s| s.id).collect();
+            assert_eq!(ids.len(), window.len());
+        }
+    }
+
+    #[test]
+    fn test_before_start_has_no_star() {
+        let catalog = generate_placeholder_catalog();
+        assert!(star_of_the_day(&catalog, SEQUENCE_START - 1).is_none());
+        assert!(star_of_the_day(&catalog, SEQUENCE_START).is_some());
+    }
+
+    #[test]
+    fn test_challenge_is_the_same_all_day() {
+        let catalog = generate_placeholder_catalog();
+        let day = days_from_civil(2025, 3, 1);
+        let challenge = DailyChallenge::new(&catalog, day);
+        assert_eq!(challenge.questions.len(), CHALLENGE_LENGTH);
+        assert_eq!(challenge, DailyChallenge::new(&catalog, day));
+        assert_ne!(
+            challenge.questions,
+            DailyChallenge::new(&catalog, day + 1).questions
+        );
+
+        let targets: HashSet<StarId> = challenge.questions.iter().map(|q| q.target_star).collect();
+        assert_eq!(targets.len(), CHALLENGE_LENGTH);
+        assert!(challenge.questions.iter().all(|q| !q.is_none_question));
+    }
+
+    #[test]
+    fn test_challenge_results_and_share_text() {
+        let catalog = generate_placeholder_catalog();
+        let mut challenge = DailyChallenge::new(&catalog, days_from_civil(2025, 3, 1));
+        let first = challenge.questions[0].target_star;
+        let second = challenge.questions[1].target_star;
+
+        challenge.record(first, true);
+        // An answer about another star does not count
+        challenge.record(first, false);
+        challenge.record(second, false);
+        assert_eq!(challenge.results, vec![true, false]);
+        assert_eq!(
+            challenge.next_question().map(|q| q.target_star),
+            Some(challenge.questions[2].target_star)
+        );
+        assert!(!challenge.is_complete());
+
+        while let Some(star) = challenge.next_question().map(|q| q.target_star) {
+            challenge.record(star, true);
+        }
+        assert!(challenge.is_complete());
+        assert_eq!(challenge.correct(), 9);
+        assert_eq!(
+            challenge.share_text(),
+            "Stargazer Daily 2025-03-01: 9/10\n✅❌✅✅✅\n✅✅✅✅✅"
+        );
+    }
+
+    #[test]
+    fn test_fact_mentions_constellation_rank() {
+        let catalog = generate_placeholder_catalog();
+        let rigel = catalog
+            .named_stars()
+            .into_iter()
+            .find(|s| s.name.as_deref() == Some("Rigel"))
+            .unwrap();
+        assert!(star_fact(&catalog, rigel).contains("brightest of the"));
+    }
+}
@@ -0,0 +1,237 @@
+//! Headless game engine
+//!
+//! [`HeadlessGame`] runs the same quiz flow as the web app — clicking a
+//! named star asks about it, answers update the score and history — but
+//! with no browser or UI framework. It owns the catalog, its sky index and
+//! game state, and feeds actions through [`game_reducer`], so front-ends
+//! such as the terminal quiz only decide what to show.
+
+use super::names::intern;
+use super::quiz::{AskedStars, QuizConfig, QuizGenerator, QuizQuestion};
+use super::review::ReviewDeck;
+use super::state::{game_reducer, GameAction, GameState, QuizState};
+#[cfg(feature = "tiles")]
+use crate::data::ZoomLevel;
+use crate::data::{StarCatalog, StarId};
+use crate::utils::dates::{day_from_unix_millis, unix_millis_now};
+use crate::utils::SkyIndex;
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+use std::rc::Rc;
+
+/// Tile zoom level used for distractors at a viewport zoom
+#[cfg(feature = "tiles")]
+pub fn quiz_zoom_level(zoom: f64) -> ZoomLevel {
+    ZoomLevel((zoom.log2().floor() as u8).clamp(0, 5))
+}
+
+/// A game session without a user interface
+pub struct HeadlessGame {
+    catalog: Rc<StarCatalog>,
+    index: SkyIndex,
+    config: QuizConfig,
+    state: Rc<GameState>,
+    rng: SmallRng,
+    review: Option<ReviewDeck>,
+    asked: AskedStars,
+}
+
+impl HeadlessGame {
+    /// Start a session over `catalog`, with questions drawn from `seed`
+    pub fn new(catalog: impl Into<Rc<StarCatalog>>, config: QuizConfig, seed: u64) -> Self {
+        let catalog = catalog.into();
+        Self {
+            index: SkyIndex::new(&catalog),
+            asked: AskedStars::with_capacity(catalog.named_stars().len()),
+            catalog,
+            config,
+            state: Rc::new(GameState::default()),
+            rng: SmallRng::seed_from_u64(seed),
+            review: None,
+        }
+    }
+
+    /// Keep `deck` up to date with every answer, and ask about its due
+    /// stars before random ones
+    pub fn with_review(mut self, deck: ReviewDeck) -> Self {
+        self.review = Some(deck);
+        self
+    }
+
+    /// The review deck, with this session's answers recorded
+    pub fn review(&self) -> Option<&ReviewDeck> {
+        self.review.as_ref()
+    }
+
+    pub fn catalog(&self) -> &StarCatalog {
+        &self.catalog
+    }
+
+    pub fn state(&self) -> &GameState {
+        &self.state
+    }
+
+    /// The current question, if one is showing
+    pub fn quiz(&self) -> Option<&QuizState> {
+        self.state.quiz.as_ref()
+    }
+
+    /// Apply any action, as the app does for UI events
+    pub fn dispatch(&mut self, action: GameAction) {
+        self.state = game_reducer(Rc::clone(&self.state), action);
+    }
+
+    /// Select a star; a named star starts a question about it
+    ///
+    /// Distractors come from the tiles around the star at the current
+    /// zoom, as in the web app.
+    pub fn click_star(&mut self, id: StarId) -> Option<&QuizState> {
+        self.dispatch(GameAction::SelectStar(id));
+        if !self.catalog.get(id)?.has_name() {
+            return None;
+        }
+        let question = self.generate(Some(id))?;
+        Some(self.ask(question))
+    }
+
+    /// Show a prepared question, replacing any current one
+    pub fn ask(&mut self, question: QuizQuestion) -> &QuizState {
+        self.asked.record_asked(question.target_star);
+        self.dispatch(GameAction::start(question));
+        self.quiz().expect("StartQuiz always sets a quiz")
+    }
+
+    /// Answer the current question; returns whether the answer was right
+    ///
+    /// `None` when there is no question or it was already answered.
+    pub fn answer(&mut self, answer: &str) -> Option<bool> {
+        if self.quiz().is_none_or(|quiz| quiz.answered) {
+            return None;
+        }
+        self.dispatch(GameAction::SelectAndSubmitAnswer(intern(answer)));
+        if let (Some(deck), Some(guess)) = (&mut self.review, self.state.guess_history.last()) {
+            deck.record_guesses(&self.catalog, std::slice::from_ref(guess));
+        }
+        let quiz = self.state.quiz.as_ref()?;
+        let correct = quiz.was_correct?;
+        if let Some(star) = quiz.target_star() {
+            self.asked.record_answer(star, correct);
+        }
+        Some(correct)
+    }
+
+    /// Move on from the current question to one about a random named star,
+    /// avoiding the last few asked and favouring those often missed
+    pub fn next_question(&mut self) -> Option<&QuizState> {
+        self.dispatch(GameAction::NextQuestion);
+        let question = self.generate(None)?;
+        self.dispatch(GameAction::SelectStar(question.target_star));
+        Some(self.ask(question))
+    }
+
+    /// A question about `target`, or about a due review star or a random
+    /// named star
+    fn generate(&mut self, target: Option<StarId>) -> Option<QuizQuestion> {
+        let mut generator = QuizGenerator::with_index(
+            &self.catalog,
+            self.config.clone(),
+            &self.index,
+            self.state.viewport.zoom,
+        )
+        .with_history(&self.asked);
+        if let Some(deck) = &self.review {
+            generator = generator.with_review(deck, day_from_unix_millis(unix_millis_now()));
+        }
+        match target {
+            Some(id) => generator.generate_for_star(self.catalog.get(id)?, &mut self.rng),
+            None => generator.generate_random(&mut self.rng),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::generate_placeholder_catalog;
+
+    fn game() -> HeadlessGame {
+        HeadlessGame::new(generate_placeholder_catalog(), QuizConfig::default(), 3)
+    }
+
+    #[test]
+    #[cfg(feature = "tiles")]
+    fn test_zoom_levels() {
+        assert_eq!(quiz_zoom_level(1.0), ZoomLevel(0));
+        assert_eq!(quiz_zoom_level(4.5), ZoomLevel(2));
+        assert_eq!(quiz_zoom_level(1000.0), ZoomLevel(5));
+    }
+
+    #[test]
+    fn test_click_named_star_starts_quiz() {
+        let mut game = game();
+        let star = game.catalog().named_stars()[0].id;
+
+        let quiz = game.click_star(star).unwrap();
+        assert_eq!(quiz.target_star(), Some(star));
+        assert!(!quiz.answered);
+        assert_eq!(game.state().selected_star, Some(star));
+    }
+
+    #[test]
+    fn test_click_unnamed_star_only_selects() {
+        let mut game = game();
+        let unnamed = game
+            .catalog()
+            .all_stars()
+            .find(|s| !s.has_name())
+            .unwrap()
+            .id;
+
+        assert!(game.click_star(unnamed).is_none());
+        assert!(game.quiz().is_none());
+        assert_eq!(game.state().selected_star, Some(unnamed));
+    }
+
+    #[test]
+    fn test_answer_once() {
+        let mut game = game();
+        assert_eq!(game.answer("Sirius"), None);
+
+        let correct = game.next_question().unwrap().correct_name.clone();
+        assert_eq!(game.answer(&correct), Some(true));
+        assert_eq!(game.answer(&correct), None);
+        assert_eq!(game.state().score.correct, 1);
+        assert_eq!(game.state().guess_history.len(), 1);
+    }
+
+    #[test]
+    fn test_next_question_skips_recent_stars() {
+        let mut game = game();
+        let window = QuizConfig::default().selection.recent_window;
+        let mut asked: Vec<StarId> = Vec::new();
+        for _ in 0..30 {
+            let quiz = game.next_question().unwrap();
+            let (target, correct) = (quiz.target_star().unwrap(), quiz.correct_name.clone());
+            assert!(!asked.iter().rev().take(window).any(|&id| id == target));
+            asked.push(target);
+            game.answer(&correct);
+        }
+    }
+
+    #[test]
+    fn test_answers_feed_the_review_deck() {
+        let mut game = game().with_review(ReviewDeck::default());
+        let quiz = game.next_question().unwrap().clone();
+        let wrong = quiz
+            .choices
+            .iter()
+            .find(|&choice| *choice != quiz.correct_name)
+            .unwrap();
+        assert_eq!(game.answer(wrong), Some(false));
+
+        let deck = game.review().unwrap();
+        assert_eq!(deck.cards.len(), 1);
+        assert_eq!(Some(deck.cards[0].star), quiz.target_star());
+        assert_eq!(deck.cards[0].lapses, 1);
+    }
+}
@@ -0,0 +1,287 @@
+//! Quiz lifecycle events for the host page
+//!
+//! The app dispatches a DOM `CustomEvent` on its mount element at each
+//! quiz transition. The structs here are the events' `detail` objects;
+//! field names are camelCase, as JavaScript expects.
+//!
+//! | Event                     | Detail                   |
+//! |---------------------------|--------------------------|
+//! | `stargazer:quizStarted`   | [`QuizStartedDetail`]    |
+//! | `stargazer:answered`      | [`AnsweredDetail`]       |
+//! | `stargazer:sessionEnded`  | [`SessionEndedDetail`]   |
+//!
+//! ```json
+//! { "starId": 42, "choiceCount": 5 }
+//! { "correct": true, "elapsedMs": 2310, "starName": "Vega", "chosenAnswer": "Vega" }
+//! { "shareText": "Stargazer 3/4 (75%)\n✅✅❌✅\nBest streak: 2",
+//!   "correct": 3, "incorrect": 1, "total": 4, "accuracy": 75.0, "bestStreak": 2 }
+//! ```
+//!
+//! The builders are pure so they can be tested natively; the app calls
+//! them where it dispatches actions, never from the reducer.
+
+use super::matching::match_name;
+use super::quiz::Difficulty;
+use super::share::session_text;
+use super::state::{GameAction, GuessSummary, QuizState, ScoreState};
+use crate::data::{Star, StarId};
+use serde::{Deserialize, Serialize};
+
+/// Fired when a question is shown
+pub const QUIZ_STARTED: &str = "stargazer:quizStarted";
+
+/// Fired when an answer is submitted
+pub const ANSWERED: &str = "stargazer:answered";
+
+/// Fired when the session summary opens
+pub const SESSION_ENDED: &str = "stargazer:sessionEnded";
+
+/// Detail of `stargazer:quizStarted`
+///
+/// The star's name is withheld so listeners cannot leak the answer.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuizStartedDetail {
+    pub star_id: u32,
+    pub choice_count: usize,
+}
+
+/// Detail of `stargazer:answered`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnsweredDetail {
+    pub correct: bool,
+    /// Milliseconds from the question appearing to the answer
+    pub elapsed_ms: u64,
+    pub star_name: String,
+    pub chosen_answer: String,
+}
+
+/// Detail of `stargazer:sessionEnded`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionEndedDetail {
+    /// Plain-text summary suitable for sharing
+    pub share_text: String,
+    pub correct: u32,
+    pub incorrect: u32,
+    pub total: usize,
+    /// Percentage of correct answers
+    pub accuracy: f64,
+    pub best_streak: u32,
+}
+
+/// Detail for a question about `star_id` with `choice_count` choices
+pub fn quiz_started(star_id: StarId, choice_count: usize) -> QuizStartedDetail {
+    QuizStartedDetail {
+        star_id: star_id.0,
+        choice_count,
+    }
+}
+
+/// Detail for `action` if it answers `quiz`, or `None` if it does not
+///
+/// Mirrors the reducer: only an unanswered quiz can be answered, and a
+/// bare submit needs a selected answer.
+pub fn answered(quiz: &QuizState, action: &GameAction, elapsed_ms: f64) -> Option<AnsweredDetail> {
+    if quiz.answered {
+        return None;
+    }
+    let (chosen, correct) = match action {
+        GameAction::SubmitAnswer => {
+            let answer = quiz.selected_answer.clone()?;
+            let correct = answer == quiz.correct_name;
+            (answer.to_string(), correct)
+        }
+        GameAction::SelectAndSubmitAnswer(answer) => {
+            (answer.to_string(), *answer == quiz.correct_name)
+        }
+        GameAction::SubmitTypedAnswer(typed) => {
+            let typed = typed.trim();
+            if typed.is_empty() {
+                return None;
+            }
+            let correct = match_name(typed, &quiz.correct_name).is_accepted();
+            (typed.to_string(), correct)
+        }
+        _ => return None,
+    };
+    Some(AnsweredDetail {
+        correct,
+        elapsed_ms: elapsed_ms.max(0.0).round() as u64,
+        star_name: quiz.correct_name.to_string(),
+        chosen_answer: chosen,
+    })
+}
+
+/// Detail for a session ending with `guesses` and `score`
+pub fn session_ended(guesses: &[GuessSummary], score: &ScoreState) -> SessionEndedDetail {
+    SessionEndedDetail {
+        share_text: session_text(guesses, score),
+        correct: score.correct,
+        incorrect: score.incorrect,
+        total: guesses.len(),
+        accuracy: score.accuracy(),
+        best_streak: score.best_streak,
+    }
+}
+
+/// Quiz settings chosen by the host page
+///
+/// Parsed from JSON such as `{ "difficulty": "easy", "questionCount": 10 }`;
+/// both fields are optional.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct HostQuizConfig {
+    /// Only stars within this difficulty's magnitude range start a quiz
+    pub difficulty: Option<Difficulty>,
+    /// Answers per session; the summary opens after each block of this many
+    pub question_count: Option<usize>,
+}
+
+impl HostQuizConfig {
+    /// Parse a config from JSON
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("invalid quiz config: {}", e))
+    }
+
+    /// Whether clicking `star` should start a quiz
+    pub fn allows(&self, star: &Star) -> bool {
+        self.difficulty
+            .is_none_or(|difficulty| difficulty.includes(star.magnitude))
+    }
+
+    /// Whether the `answered`th answer completes a session
+    pub fn session_complete(&self, answered: usize) -> bool {
+        self.question_count
+            .is_some_and(|count| count > 0 && answered > 0 && answered.is_multiple_of(count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::CelestialCoord;
+    use crate::game::QuizTarget;
+
+    fn quiz(selected: Option<&str>, answered: bool) -> QuizState {
+        QuizState {
+            target: QuizTarget::Star(StarId(7)),
+            correct_name: "Vega".into(),
+            choices: vec!["Vega".into(), "Deneb".into()],
+            selected_answer: selected.map(Into::into),
+            answered,
+            was_correct: None,
+            started_at: 0.0,
+            hints: 0,
+        }
+    }
+
+    fn guess(star: &str, correct: bool) -> GuessSummary {
+        GuessSummary {
+            star_name: star.into(),
+            user_answer: star.into(),
+            was_correct: correct,
+            timestamp: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_started_detail_withholds_name() {
+        let json = serde_json::to_value(quiz_started(StarId(7), 5)).unwrap();
+        assert_eq!(json, serde_json::json!({ "starId": 7, "choiceCount": 5 }));
+    }
+
+    #[test]
+    fn test_answered_detail() {
+        let detail = answered(
+            &quiz(None, false),
+            &GameAction::SelectAndSubmitAnswer("Deneb".into()),
+            1234.6,
+        )
+        .unwrap();
+        assert!(!detail.correct);
+        assert_eq!(detail.elapsed_ms, 1235);
+        assert_eq!(detail.star_name, "Vega");
+
+        let json = serde_json::to_value(&detail).unwrap();
+        for key in ["correct", "elapsedMs", "starName", "chosenAnswer"] {
+            assert!(json.get(key).is_some(), "missing {}", key);
+        }
+
+        let submitted = answered(&quiz(Some("Vega"), false), &GameAction::SubmitAnswer, 0.0);
+        assert!(submitted.unwrap().correct);
+    }
+
+    #[test]
+    fn test_no_detail_without_an_answer() {
+        assert!(answered(&quiz(None, false), &GameAction::SubmitAnswer, 0.0).is_none());
+        assert!(answered(&quiz(Some("Vega"), true), &GameAction::SubmitAnswer, 0.0).is_none());
+        assert!(answered(&quiz(None, false), &GameAction::CloseQuiz, 0.0).is_none());
+        let blank = GameAction::SubmitTypedAnswer(" ".into());
+        assert!(answered(&quiz(None, false), &blank, 0.0).is_none());
+    }
+
+    #[test]
+    fn test_typed_answer_detail() {
+        let typed = GameAction::SubmitTypedAnswer(" vegs ".into());
+        let detail = answered(&quiz(None, false), &typed, 0.0).unwrap();
+        assert!(detail.correct);
+        assert_eq!(detail.chosen_answer, "vegs");
+    }
+
+    #[test]
+    fn test_session_ended_detail() {
+        let mut score = ScoreState::default();
+        let guesses = vec![
+            guess("Vega", true),
+            guess("Deneb", true),
+            guess("Altair", false),
+            guess("Rigel", true),
+        ];
+        for g in &guesses {
+            if g.was_correct {
+                score.record_correct();
+            } else {
+                score.record_incorrect();
+            }
+        }
+
+        let detail = session_ended(&guesses, &score);
+        assert_eq!(
+            detail.share_text,
+            "Stargazer 3/4 (75%)\n✅✅❌✅\nBest streak: 2"
+        );
+        assert_eq!((detail.correct, detail.incorrect, detail.total), (3, 1, 4));
+        assert_eq!(detail.accuracy, 75.0);
+        assert!(serde_json::to_value(&detail).unwrap()["bestStreak"] == 2);
+    }
+
+    #[test]
+    fn test_host_config() {
+        let config =
+            HostQuizConfig::from_json(r#"{ "difficulty": "easy", "questionCount": 3 }"#).unwrap();
+        assert_eq!(config.difficulty, Some(Difficulty::Easy));
+        assert!(!config.session_complete(2));
+        assert!(config.session_complete(3));
+        assert!(!config.session_complete(4));
+        assert!(config.session_complete(6));
+
+        let star = |magnitude| Star {
+            id: StarId(1),
+            name: Some("Test".to_string()),
+            coord: CelestialCoord { ra: 0.0, dec: 0.0 },
+            magnitude,
+            constellation: None,
+        };
+        assert!(config.allows(&star(1.0)));
+        assert!(!config.allows(&star(3.0)));
+
+        let open = HostQuizConfig::from_json("{}").unwrap();
+        assert!(open.allows(&star(6.0)));
+        assert!(!open.session_complete(100));
+
+        assert!(HostQuizConfig::from_json(r#"{ "difficulty": "extreme" }"#).is_err());
+        assert!(HostQuizConfig::from_json(r#"{ "questions": 3 }"#).is_err());
+    }
+}
@@ -0,0 +1,180 @@
+//! Progress heatmap
+//!
+//! How well each region of the sky is known. A region is one tile of the
+//! catalog's [`TileSystem`] at [`HEATMAP_ZOOM`], spanning the extent of
+//! its named stars, and its mastery is the mean [`StarStats::mastery`] of
+//! those stars, counting a star never answered as unknown. Without the
+//! `tiles` feature there are no tiles and so no regions.
+
+use crate::data::{CelestialCoord, StarCatalog};
+#[cfg(feature = "tiles")]
+use crate::data::{TileId, TileSystem, ZoomLevel};
+use crate::game::StarStats;
+use crate::utils::SkyIndex;
+#[cfg(feature = "tiles")]
+use std::collections::HashSet;
+
+/// Tile zoom level the heatmap divides the sky at
+#[cfg(feature = "tiles")]
+pub const HEATMAP_ZOOM: ZoomLevel = ZoomLevel(3);
+
+/// The named stars of one region of the sky and how well they are known
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegionMastery {
+    pub ra_min: f64,
+    pub ra_max: f64,
+    pub dec_min: f64,
+    pub dec_max: f64,
+    /// Named stars in the region
+    pub named: usize,
+    /// How many of them were ever answered
+    pub answered: usize,
+    /// Mean mastery of the named stars, from 0 to 1
+    pub mastery: f64,
+}
+
+impl RegionMastery {
+    /// Whether none of the region's stars was ever answered
+    pub fn is_untouched(&self) -> bool {
+        self.answered == 0
+    }
+
+    /// The middle of the region
+    pub fn center(&self) -> CelestialCoord {
+        CelestialCoord::new(
+            (self.ra_min + self.ra_max) / 2.0,
+            (self.dec_min + self.dec_max) / 2.0,
+        )
+    }
+}
+
+/// Mastery of every tile at `zoom` that holds named stars, from north to
+/// south and then by right ascension
+#[cfg(feature = "tiles")]
+pub fn tile_mastery(
+    catalog: &StarCatalog,
+    tiles: &TileSystem,
+    zoom: ZoomLevel,
+    stats: &StarStats,
+) -> Vec<RegionMastery> {
+    let mut seen: HashSet<TileId> = HashSet::new();
+    let mut regions = Vec::new();
+    for star in catalog.named_stars() {
+        let Some(&id) = tiles
+            .get_tiles_for_star(star.id)
+            .into_iter()
+            .flatten()
+            .find(|id| id.zoom == zoom)
+        else {
+            continue;
+        };
+        if !seen.insert(id) {
+            continue;
+        }
+        let Some(tile) = tiles.get_tile(&id) else {
+            continue;
+        };
+        let members: Vec<_> = tile
+            .named_star_ids
+            .iter()
+            .filter_map(|&id| catalog.get(id))
+            .collect();
+        let extent = |value: fn(&CelestialCoord) -> f64| {
+            members
+                .iter()
+                .map(|star| value(&star.coord))
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+                    (lo.min(v), hi.max(v))
+                })
+        };
+        let (ra_min, ra_max) = extent(|coord| coord.ra);
+        let (dec_min, dec_max) = extent(|coord| coord.dec);
+        let total: f64 = members.iter().map(|star| stats.mastery(star.id)).sum();
+        regions.push(RegionMastery {
+            ra_min,
+            ra_max,
+            dec_min,
+            dec_max,
+            named: members.len(),
+            answered: members
+                .iter()
+                .filter(|star| stats.get(star.id).is_some())
+                .count(),
+            mastery: total / members.len() as f64,
+        });
+    }
+    regions.sort_by(|a, b| {
+        b.dec_max
+            .total_cmp(&a.dec_max)
+            .then(a.ra_min.total_cmp(&b.ra_min))
+    });
+    regions
+}
+
+/// Mastery of each region of the sky, over the tiles behind `index`
+pub fn sky_mastery(
+    catalog: &StarCatalog,
+    index: &SkyIndex,
+    stats: &StarStats,
+) -> Vec<RegionMastery> {
+    #[cfg(feature = "tiles")]
+    {
+        tile_mastery(catalog, index.tiles(), HEATMAP_ZOOM, stats)
+    }
+    #[cfg(not(feature = "tiles"))]
+    {
+        let _ = (catalog, index, stats);
+        Vec::new()
+    }
+}
+
+#[cfg(all(test, feature = "tiles"))]
+mod tests {
+    use super::*;
+    use crate::data::{generate_placeholder_catalog, Star};
+
+    #[test]
+    fn test_tile_mastery() {
+        let catalog = generate_placeholder_catalog();
+        let stars: Vec<Star> = catalog.all_stars().cloned().collect();
+        let tiles = TileSystem::from_stars(&stars);
+
+        let mut stats = StarStats::default();
+        let untouched = tile_mastery(&catalog, &tiles, HEATMAP_ZOOM, &stats);
+        assert!(untouched.len() > 1);
+        assert!(untouched.iter().all(RegionMastery::is_untouched));
+        // Every named star is in exactly one region
+        let named: usize = untouched.iter().map(|region| region.named).sum();
+        assert_eq!(named, catalog.named_count());
+
+        let sirius = catalog
+            .all_stars()
+            .find(|star| star.name.as_deref() == Some("Sirius"))
+            .unwrap();
+        for _ in 0..4 {
+            stats.record(sirius.id, true, 1000.0);
+        }
+        let regions = tile_mastery(&catalog, &tiles, HEATMAP_ZOOM, &stats);
+        let learned: Vec<_> = regions.iter().filter(|r| !r.is_untouched()).collect();
+        assert_eq!(learned.len(), 1);
+        let region = learned[0];
+        assert_eq!(region.answered, 1);
+        assert!((region.mastery - 0.8 / region.named as f64).abs() < 1e-9);
+        assert!(region.ra_min <= sirius.coord.ra && sirius.coord.ra <= region.ra_max);
+        assert!(region.dec_min <= sirius.coord.dec && sirius.coord.dec <= region.dec_max);
+
+        // North to south
+        assert!(regions.windows(2).all(|w| w[0].dec_max >= w[1].dec_max));
+    }
+
+    #[test]
+    fn test_sky_mastery_uses_the_index() {
+        let catalog = generate_placeholder_catalog();
+        let index = SkyIndex::new(&catalog);
+        let stats = StarStats::default();
+        assert_eq!(
+            sky_mastery(&catalog, &index, &stats),
+            tile_mastery(&catalog, index.tiles(), HEATMAP_ZOOM, &stats)
+        );
+    }
+}
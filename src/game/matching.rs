@@ -0,0 +1,174 @@
+//! Typed answer matching
+//!
+//! Compares a star name typed by the player with the real one. Case,
+//! accents, punctuation and spacing are ignored, and a slip of a letter
+//! or two still counts, so "betelguese" is accepted for "Betelgeuse".
+//! The same comparison scores how easily two names are confused, for
+//! look-alike distractors.
+
+use crate::data::search::edit_distance;
+
+/// How a typed answer compares with a star's name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameMatch {
+    /// The name, up to case, accents and punctuation
+    Exact,
+    /// Within a small edit distance of the name: accepted, but misspelled
+    Close,
+    /// Not the name
+    Wrong,
+}
+
+impl NameMatch {
+    /// Whether the answer counts as correct
+    pub fn is_accepted(self) -> bool {
+        self != NameMatch::Wrong
+    }
+}
+
+/// `text` lowercased, without accents or punctuation and with runs of
+/// spaces and hyphens collapsed to one space
+pub fn fold(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    let mut pending_space = false;
+    for c in text.chars().flat_map(char::to_lowercase) {
+        if c.is_whitespace() || c == '-' || c == '_' {
+            pending_space = !folded.is_empty();
+            continue;
+        }
+        let mut ascii = [0; 4];
+        let plain: &str = if c.is_ascii_alphanumeric() {
+            c.encode_utf8(&mut ascii)
+        } else {
+            unaccented(c)
+        };
+        if plain.is_empty() {
+            continue;
+        }
+        if pending_space {
+            folded.push(' ');
+            pending_space = false;
+        }
+        folded.push_str(plain);
+    }
+    folded
+}
+
+/// The plain letters for an accented lowercase `c`, or "" for
+/// punctuation and anything else
+fn unaccented(c: char) -> &'static str {
+    match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'č' => "c",
+        'ď' | 'đ' | 'ḍ' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'ğ' | 'ġ' => "g",
+        'ḥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'ı' => "i",
+        'ł' => "l",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+        'œ' => "oe",
+        'ř' => "r",
+        'ś' | 'š' | 'ş' | 'ṣ' => "s",
+        'ß' => "ss",
+        'ť' | 'ţ' | 'ṭ' => "t",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' | 'ẓ' => "z",
+        _ => "",
+    }
+}
+
+/// How many slips a name of `len` letters forgives: none for very short
+/// names, where one letter makes a different word, then one, then two
+pub fn tolerance(len: usize) -> usize {
+    match len {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+/// Compare a typed answer with a star's name
+pub fn match_name(typed: &str, name: &str) -> NameMatch {
+    let typed = fold(typed);
+    let name = fold(name);
+    if typed.is_empty() {
+        NameMatch::Wrong
+    } else if typed == name {
+        NameMatch::Exact
+    } else if edit_distance(&typed, &name) <= tolerance(name.chars().count()) {
+        NameMatch::Close
+    } else {
+        NameMatch::Wrong
+    }
+}
+
+/// Longest shared start that still adds to [`confusability`]
+const MAX_SHARED_PREFIX: usize = 4;
+
+/// How easily one star name is mistaken for another
+///
+/// A shared start counts most, as in Alnilam and Alnitak, then how few
+/// edits separate the names; a difference in length counts against
+/// them. Names with nothing in common score 0 or less.
+pub fn confusability(a: &str, b: &str) -> f64 {
+    let a = fold(a);
+    let b = fold(b);
+    let (len_a, len_b) = (a.chars().count(), b.chars().count());
+    let longest = len_a.max(len_b);
+    if longest == 0 || a == b {
+        return 0.0;
+    }
+    let prefix = a
+        .chars()
+        .zip(b.chars())
+        .take_while(|(x, y)| x == y)
+        .count()
+        .min(MAX_SHARED_PREFIX);
+    let closeness = 1.0 - edit_distance(&a, &b) as f64 / longest as f64;
+    prefix as f64 + 3.0 * closeness - 0.5 * len_a.abs_diff(len_b) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold_ignores_case_accents_and_punctuation() {
+        assert_eq!(fold("  Alpha  Centauri "), "alpha centauri");
+        assert_eq!(fold("Zubenel-genubi"), "zubenel genubi");
+        assert_eq!(fold("Añañuca"), "ananuca");
+        assert_eq!(fold("Ain't"), "aint");
+        assert_eq!(fold("--"), "");
+    }
+
+    #[test]
+    fn test_misspellings_are_close() {
+        assert_eq!(match_name("Betelgeuse", "Betelgeuse"), NameMatch::Exact);
+        assert_eq!(match_name("BETELGEUSE", "Betelgeuse"), NameMatch::Exact);
+        assert_eq!(match_name("Betelguese", "Betelgeuse"), NameMatch::Close);
+        assert_eq!(match_name("Sirrah", "Sirius"), NameMatch::Wrong);
+        assert_eq!(match_name("Sirus", "Sirius"), NameMatch::Close);
+        assert_eq!(match_name("Acrux", "Achernar"), NameMatch::Wrong);
+    }
+
+    #[test]
+    fn test_look_alike_names_are_confusable() {
+        let twin = confusability("Alnilam", "Alnitak");
+        assert!(twin > confusability("Alnilam", "Mintaka"));
+        assert!(confusability("Alnilam", "Sirius") <= 0.0);
+        assert!(confusability("Deneb", "Denebola") > confusability("Deneb", "Vega"));
+        assert_eq!(confusability("Vega", "vega"), 0.0);
+    }
+
+    #[test]
+    fn test_short_names_must_be_exact() {
+        assert_eq!(match_name("Mu", "Mu"), NameMatch::Exact);
+        assert_eq!(match_name("Ma", "Mu"), NameMatch::Wrong);
+        assert_eq!(match_name("", "Mu"), NameMatch::Wrong);
+        assert!(!match_name("  ", "Vega").is_accepted());
+    }
+}
@@ -2,10 +2,40 @@
 //!
 //! Contains state management, quiz generation, and game rules.
 
+pub mod achievements;
+pub mod daily;
+pub mod engine;
+pub mod events;
+pub mod heatmap;
+pub mod matching;
+pub mod modes;
+pub mod names;
+pub mod preferences;
+pub mod progress;
 pub mod quiz;
+pub mod review;
+pub mod scoring;
+pub mod scripted;
+pub mod share;
+pub mod simulation;
 pub mod state;
+pub mod stats;
+pub mod telemetry;
+pub mod tour;
 
-pub use quiz::{Difficulty, QuizConfig, QuizGenerator, QuizQuestion};
+pub use achievements::{Achievement, Achievements};
+pub use daily::DailyChallenge;
+pub use modes::{GameMode, Grade, RoundRun, SurvivalRun, TimeAttackRun};
+pub use names::{intern, Name, NONE_OF_ABOVE};
+pub use preferences::{AnswerMode, MapRenderer, Preferences};
+pub use quiz::{
+    deep_sky_choices, AskedStars, ChoiceNames, Difficulty, Distractor, DistractorSource,
+    DistractorStrategy, QuestionKind, QuizConfig, QuizGenerator, QuizQuestion, SelectionPolicy,
+};
 pub use state::{
-    game_reducer, GameAction, GameState, GuessSummary, QuizState, ScoreState, UiState,
+    game_reducer, GameAction, GameState, GuessSummary, QuizState, QuizTarget, ScoreState, UiState,
+    ViewMode,
 };
+pub use stats::{StarRecord, StarStats};
+pub use telemetry::{NoTelemetry, Telemetry};
+pub use tour::{Tour, TourId, TourRun, TourStop, TOURS};
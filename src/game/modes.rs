@@ -0,0 +1,469 @@
+//! Game modes
+//!
+//! Free play asks questions for as long as the player likes. A survival
+//! run starts with [`SURVIVAL_LIVES`] lives: each wrong answer costs one,
+//! the run ends when none are left, and its score is the questions
+//! survived. A time-attack run asks question after question until its
+//! clock runs out, and scores the right answers. A round asks
+//! [`ROUND_LENGTH`] questions one after another and ends with a
+//! [`Grade`]. Practice asks nothing: clicking a star describes it, as it
+//! does on a [`TourRun`], which visits famous stars one after another. A
+//! review of mistakes asks again about each star missed this session,
+//! sending a star missed again to the back of the line, until every one
+//! has been named.
+//!
+//! Run clocks are in Unix milliseconds, the clock each question's
+//! [`QuizState::started_at`](super::QuizState::started_at) is read from.
+
+use super::tour::TourRun;
+use crate::data::StarId;
+use serde::{Deserialize, Serialize};
+
+/// Lives at the start of a survival run
+pub const SURVIVAL_LIVES: u32 = 3;
+
+/// The lengths of time-attack runs on offer, in seconds
+pub const TIME_ATTACK_SECONDS: [u32; 2] = [60, 120];
+
+/// Questions in a round
+pub const ROUND_LENGTH: u32 = 10;
+
+/// How the session is being played
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameMode {
+    /// Questions for as long as the player likes
+    #[default]
+    Free,
+    /// A survival run
+    Survival(SurvivalRun),
+    /// A time-attack run
+    TimeAttack(TimeAttackRun),
+    /// A round of a fixed number of questions
+    Round(RoundRun),
+    /// Stars are described instead of asked about, and nothing is scored
+    Practice,
+    /// A guided tour, describing stars like practice
+    Tour(TourRun),
+    /// A review of the session's mistakes
+    Mistakes(MistakesRun),
+}
+
+impl GameMode {
+    /// Count an answer given at `now_ms` toward the run being played, if
+    /// any; `star` is the star a name question asked about
+    pub fn record(&mut self, star: Option<StarId>, correct: bool, now_ms: f64) {
+        match self {
+            GameMode::Free | GameMode::Practice | GameMode::Tour(_) => {}
+            GameMode::Survival(run) => run.record(correct),
+            GameMode::TimeAttack(run) => run.record(correct, now_ms),
+            GameMode::Round(run) => run.record(correct),
+            GameMode::Mistakes(run) => {
+                if let Some(star) = star {
+                    run.record(star, correct);
+                }
+            }
+        }
+    }
+
+    /// Whether a run has ended and its results are due
+    pub fn is_over(&self) -> bool {
+        match self {
+            GameMode::Free | GameMode::Practice | GameMode::Tour(_) => false,
+            GameMode::Survival(run) => run.is_over(),
+            GameMode::TimeAttack(run) => run.time_up,
+            GameMode::Round(run) => run.is_over(),
+            GameMode::Mistakes(run) => run.is_over(),
+        }
+    }
+
+    /// Lives left, in a mode that has them
+    pub fn lives(&self) -> Option<u32> {
+        match self {
+            GameMode::Survival(run) => Some(run.lives),
+            _ => None,
+        }
+    }
+
+    /// When the run's clock runs out, in a mode that has one that is
+    /// still running
+    pub fn deadline(&self) -> Option<f64> {
+        match self {
+            GameMode::TimeAttack(run) if !run.time_up => Some(run.ends_at),
+            _ => None,
+        }
+    }
+
+    /// Whether the next question opens by itself a moment after each
+    /// answer
+    pub fn advances_by_itself(&self) -> bool {
+        match self {
+            GameMode::Free | GameMode::Survival(_) | GameMode::Practice | GameMode::Tour(_) => {
+                false
+            }
+            GameMode::TimeAttack(run) => !run.time_up,
+            GameMode::Round(run) => !run.is_over(),
+            GameMode::Mistakes(run) => !run.is_over(),
+        }
+    }
+
+    /// Whether a click on a star asks about it
+    pub fn asks_questions(&self) -> bool {
+        !matches!(self, GameMode::Practice | GameMode::Tour(_))
+    }
+
+    /// The tour being taken, if any
+    pub fn tour(&self) -> Option<TourRun> {
+        match self {
+            GameMode::Tour(run) => Some(*run),
+            _ => None,
+        }
+    }
+
+    /// Questions answered and questions in all, in a mode with a set
+    /// number of questions
+    pub fn progress(&self) -> Option<(usize, usize)> {
+        match self {
+            GameMode::Round(run) => Some((run.answered as usize, run.length as usize)),
+            _ => None,
+        }
+    }
+}
+
+/// A survival run and how far it has got
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SurvivalRun {
+    /// Lives left; the run is over at zero
+    pub lives: u32,
+
+    /// Questions answered with a life to spare
+    pub survived: u32,
+}
+
+impl Default for SurvivalRun {
+    fn default() -> Self {
+        Self {
+            lives: SURVIVAL_LIVES,
+            survived: 0,
+        }
+    }
+}
+
+impl SurvivalRun {
+    /// Count an answer: a wrong one costs a life, and every answer but
+    /// the one that costs the last life is survived
+    pub fn record(&mut self, correct: bool) {
+        if self.is_over() {
+            return;
+        }
+        if !correct {
+            self.lives -= 1;
+        }
+        if !self.is_over() {
+            self.survived += 1;
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.lives == 0
+    }
+}
+
+/// A time-attack run and how far it has got
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TimeAttackRun {
+    /// How long the run lasts, in seconds
+    pub seconds: u32,
+
+    /// When the run ends, in Unix milliseconds
+    pub ends_at: f64,
+
+    /// Questions answered in time
+    pub answered: u32,
+
+    /// Right answers given in time
+    pub correct: u32,
+
+    /// Whether the clock has run out
+    pub time_up: bool,
+}
+
+impl TimeAttackRun {
+    /// A run of `seconds` starting at `now_ms`
+    pub fn new(seconds: u32, now_ms: f64) -> Self {
+        Self {
+            seconds,
+            ends_at: now_ms + f64::from(seconds) * 1000.0,
+            answered: 0,
+            correct: 0,
+            time_up: false,
+        }
+    }
+
+    /// Milliseconds left at `now_ms`
+    pub fn remaining_ms(&self, now_ms: f64) -> f64 {
+        (self.ends_at - now_ms).max(0.0)
+    }
+
+    /// Count an answer given at `now_ms`; one given after the end stops
+    /// the run instead
+    pub fn record(&mut self, correct: bool, now_ms: f64) {
+        if now_ms >= self.ends_at {
+            self.time_up = true;
+        }
+        if self.time_up {
+            return;
+        }
+        self.answered += 1;
+        if correct {
+            self.correct += 1;
+        }
+    }
+}
+
+/// A round and how far it has got
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundRun {
+    /// Questions in the round
+    pub length: u32,
+
+    /// Questions answered so far
+    pub answered: u32,
+
+    /// Right answers so far
+    pub correct: u32,
+}
+
+impl Default for RoundRun {
+    fn default() -> Self {
+        Self {
+            length: ROUND_LENGTH,
+            answered: 0,
+            correct: 0,
+        }
+    }
+}
+
+impl RoundRun {
+    /// Count an answer, unless every question has been answered
+    pub fn record(&mut self, correct: bool) {
+        if self.is_over() {
+            return;
+        }
+        self.answered += 1;
+        if correct {
+            self.correct += 1;
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.answered >= self.length
+    }
+
+    /// The grade for the right answers so far
+    pub fn grade(&self) -> Grade {
+        Grade::for_score(self.correct, self.length)
+    }
+}
+
+/// A review of mistakes and how far it has got
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MistakesRun {
+    /// Stars still to be named, the next one asked about first
+    pub queue: Vec<StarId>,
+
+    /// Stars missed when the review started
+    pub mistakes: usize,
+
+    /// Answers given about stars in the queue
+    pub answered: u32,
+}
+
+impl MistakesRun {
+    pub fn new(stars: Vec<StarId>) -> Self {
+        Self {
+            mistakes: stars.len(),
+            queue: stars,
+            answered: 0,
+        }
+    }
+
+    /// The star to ask about next
+    pub fn next_star(&self) -> Option<StarId> {
+        self.queue.first().copied()
+    }
+
+    /// Count an answer about `star`: named, it leaves the queue; missed,
+    /// it goes to the back. Answers about other stars do not count.
+    pub fn record(&mut self, star: StarId, correct: bool) {
+        let Some(index) = self.queue.iter().position(|&queued| queued == star) else {
+            return;
+        };
+        self.answered += 1;
+        self.queue.remove(index);
+        if !correct {
+            self.queue.push(star);
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+/// A letter grade for a share of right answers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Grade {
+    A,
+    B,
+    C,
+    D,
+    F,
+}
+
+impl Grade {
+    /// The grade for `correct` right answers out of `total`: A from 90%,
+    /// then a letter down for each 10% less, and F below 60%
+    pub fn for_score(correct: u32, total: u32) -> Self {
+        let percent = (correct * 100).checked_div(total).unwrap_or(0);
+        match percent {
+            90.. => Grade::A,
+            80..=89 => Grade::B,
+            70..=79 => Grade::C,
+            60..=69 => Grade::D,
+            _ => Grade::F,
+        }
+    }
+
+    pub fn letter(self) -> &'static str {
+        match self {
+            Grade::A => "A",
+            Grade::B => "B",
+            Grade::C => "C",
+            Grade::D => "D",
+            Grade::F => "F",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_survival_ends_at_zero_lives() {
+        let mut mode = GameMode::Survival(SurvivalRun::default());
+        assert_eq!(mode.lives(), Some(SURVIVAL_LIVES));
+
+        for correct in [true, false, true, true, false] {
+            mode.record(None, correct, 0.0);
+        }
+        assert_eq!(mode.lives(), Some(1));
+        assert!(!mode.is_over());
+
+        mode.record(None, false, 0.0);
+        assert!(mode.is_over());
+        // Answers after the end do not count
+        mode.record(None, true, 0.0);
+        assert_eq!(
+            mode,
+            GameMode::Survival(SurvivalRun {
+                lives: 0,
+                survived: 5
+            })
+        );
+
+        let mut free = GameMode::Free;
+        free.record(None, false, 0.0);
+        assert!(!free.is_over());
+        assert_eq!(free.lives(), None);
+        assert!(!free.advances_by_itself());
+        assert!(free.asks_questions());
+        assert!(!GameMode::Practice.asks_questions());
+        assert!(!GameMode::Tour(TourRun::new(0).unwrap()).asks_questions());
+    }
+
+    #[test]
+    fn test_time_attack_counts_answers_in_time() {
+        let mut mode = GameMode::TimeAttack(TimeAttackRun::new(60, 1000.0));
+        assert_eq!(mode.deadline(), Some(61_000.0));
+        assert!(mode.advances_by_itself());
+
+        mode.record(None, true, 2000.0);
+        mode.record(None, false, 30_000.0);
+        mode.record(None, true, 60_999.0);
+        let GameMode::TimeAttack(run) = mode else {
+            unreachable!()
+        };
+        assert_eq!((run.correct, run.answered), (2, 3));
+        assert_eq!(run.remaining_ms(31_000.0), 30_000.0);
+        assert_eq!(run.remaining_ms(99_000.0), 0.0);
+
+        // An answer after the end stops the run uncounted
+        mode.record(None, true, 61_000.0);
+        assert!(mode.is_over());
+        assert_eq!(mode.deadline(), None);
+        assert!(!mode.advances_by_itself());
+        let GameMode::TimeAttack(run) = mode else {
+            unreachable!()
+        };
+        assert_eq!((run.correct, run.answered), (2, 3));
+    }
+
+    #[test]
+    fn test_round_ends_after_its_questions() {
+        let mut mode = GameMode::Round(RoundRun::default());
+        assert_eq!(mode.progress(), Some((0, 10)));
+        for i in 0..ROUND_LENGTH {
+            assert!(mode.advances_by_itself());
+            mode.record(None, i % 4 != 0, 0.0);
+        }
+        assert!(mode.is_over());
+        assert!(!mode.advances_by_itself());
+        mode.record(None, true, 0.0);
+        assert_eq!(mode.progress(), Some((10, 10)));
+
+        let GameMode::Round(run) = mode else {
+            unreachable!()
+        };
+        assert_eq!(run.correct, 7);
+        assert_eq!(run.grade(), Grade::C);
+    }
+
+    #[test]
+    fn test_mistakes_come_back_until_named() {
+        let mut mode = GameMode::Mistakes(MistakesRun::new(vec![StarId(1), StarId(2)]));
+        assert!(mode.advances_by_itself());
+
+        mode.record(Some(StarId(1)), false, 0.0);
+        // Answers about other stars do not count
+        mode.record(Some(StarId(9)), true, 0.0);
+        mode.record(None, true, 0.0);
+        let GameMode::Mistakes(run) = &mode else {
+            unreachable!()
+        };
+        assert_eq!(run.queue, [StarId(2), StarId(1)]);
+        assert_eq!(run.next_star(), Some(StarId(2)));
+
+        mode.record(Some(StarId(2)), true, 0.0);
+        mode.record(Some(StarId(1)), true, 0.0);
+        assert!(mode.is_over());
+        assert!(!mode.advances_by_itself());
+        let GameMode::Mistakes(run) = mode else {
+            unreachable!()
+        };
+        assert_eq!((run.mistakes, run.answered), (2, 3));
+    }
+
+    #[test]
+    fn test_grades() {
+        let grades: Vec<_> = (0..=10)
+            .map(|correct| Grade::for_score(correct, 10).letter())
+            .collect();
+        assert_eq!(
+            grades,
+            ["F", "F", "F", "F", "F", "F", "D", "C", "B", "A", "A"]
+        );
+        assert_eq!(Grade::for_score(0, 0), Grade::F);
+    }
+}
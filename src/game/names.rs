@@ -0,0 +1,48 @@
+//! Shared star names
+//!
+//! Quiz questions, answers and the guess history pass the same few
+//! hundred star names around constantly. They are held as [`Name`]s,
+//! reference-counted strings handed out by [`intern`], so cloning a
+//! question or a state is a count bump instead of a string copy and each
+//! distinct name is allocated once per page.
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// A shared, immutable star name
+pub type Name = Rc<str>;
+
+/// Answer for questions where the star is none of the choices
+pub const NONE_OF_ABOVE: &str = "none of above";
+
+thread_local! {
+    static NAMES: RefCell<HashSet<Name>> = RefCell::new(HashSet::new());
+}
+
+/// The shared copy of `name`, allocating it on first use
+pub fn intern(name: &str) -> Name {
+    NAMES.with(|names| {
+        let mut names = names.borrow_mut();
+        if let Some(existing) = names.get(name) {
+            return Rc::clone(existing);
+        }
+        let shared: Name = Rc::from(name);
+        names.insert(Rc::clone(&shared));
+        shared
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern_shares_one_allocation() {
+        let first = intern("Vega");
+        let second = intern(&String::from("Vega"));
+        assert!(Rc::ptr_eq(&first, &second));
+        assert_eq!(&*first, "Vega");
+        assert!(!Rc::ptr_eq(&first, &intern("Deneb")));
+    }
+}
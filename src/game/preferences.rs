@@ -0,0 +1,158 @@
+//! User preferences
+//!
+//! Display settings that persist across sessions via localStorage.
+
+use crate::data::star_names::Lang;
+use crate::utils::{LabelDensity, Locale, Observer};
+#[cfg(feature = "web")]
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+/// localStorage key for persisted preferences
+pub const PREFERENCES_KEY: &str = "stargazer.preferences";
+
+/// Allowed range for the star size multiplier
+pub const STAR_SCALE_RANGE: (f64, f64) = (0.5, 3.0);
+
+/// How the star map is drawn
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MapRenderer {
+    /// One SVG element per star: sharp, styleable and accessible
+    #[default]
+    Svg,
+    /// Stars painted onto a canvas, which stays fast with thousands of stars
+    Canvas,
+}
+
+/// How quiz questions are answered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnswerMode {
+    /// Pick the name from a list of choices
+    #[default]
+    Choices,
+    /// Type the name, with small misspellings forgiven
+    Typed,
+}
+
+/// Persisted user preferences
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    /// Multiplier applied to star symbol sizes
+    pub star_scale: f64,
+
+    /// Whether clicking an unnamed star shows its info card
+    pub unnamed_star_info: bool,
+
+    /// How many star labels to draw
+    pub label_density: LabelDensity,
+
+    /// Interface language
+    pub locale: Locale,
+
+    /// Tradition star labels are named in
+    pub star_name_lang: Lang,
+
+    /// Whether answers play a sound
+    pub sound_enabled: bool,
+
+    /// Where the local sky is seen from, once known
+    pub observer: Option<Observer>,
+
+    /// Whether the local sky leaves out stars below the horizon rather
+    /// than dimming them
+    pub hide_below_horizon: bool,
+
+    /// How the star map is drawn
+    pub renderer: MapRenderer,
+
+    /// How quiz questions are answered
+    pub answer_mode: AnswerMode,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            star_scale: 1.0,
+            unnamed_star_info: true,
+            label_density: LabelDensity::default(),
+            locale: Locale::default(),
+            star_name_lang: Lang::default(),
+            sound_enabled: false,
+            observer: None,
+            hide_below_horizon: false,
+            renderer: MapRenderer::default(),
+            answer_mode: AnswerMode::default(),
+        }
+    }
+}
+
+impl Preferences {
+    /// Bring values from a stale or hand-edited save back into range
+    pub fn sanitized(mut self) -> Self {
+        self.star_scale = clamp_star_scale(self.star_scale);
+        self
+    }
+}
+
+#[cfg(feature = "web")]
+impl Preferences {
+    /// Load preferences from localStorage, falling back to defaults
+    pub fn load() -> Self {
+        LocalStorage::get::<Self>(PREFERENCES_KEY)
+            .unwrap_or_default()
+            .sanitized()
+    }
+
+    /// Save preferences to localStorage
+    pub fn save(&self) {
+        if let Err(e) = LocalStorage::set(PREFERENCES_KEY, self) {
+            log::warn!("Failed to save preferences: {}", e);
+        }
+    }
+}
+
+/// Clamp a star scale to the allowed range
+pub fn clamp_star_scale(scale: f64) -> f64 {
+    scale.clamp(STAR_SCALE_RANGE.0, STAR_SCALE_RANGE.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_star_scale() {
+        assert_eq!(clamp_star_scale(10.0), 3.0);
+        assert_eq!(clamp_star_scale(0.1), 0.5);
+        assert_eq!(clamp_star_scale(1.5), 1.5);
+    }
+
+    #[test]
+    fn test_out_of_range_star_scale_is_clamped_on_load() {
+        let prefs: Preferences = serde_json::from_str(r#"{"star_scale":40.0}"#).unwrap();
+        assert_eq!(prefs.sanitized().star_scale, 3.0);
+        let prefs: Preferences = serde_json::from_str(r#"{"star_scale":-2.0}"#).unwrap();
+        assert_eq!(prefs.sanitized().star_scale, 0.5);
+    }
+
+    #[test]
+    fn test_missing_fields_use_defaults() {
+        let prefs: Preferences = serde_json::from_str("{}").unwrap();
+        assert_eq!(prefs, Preferences::default());
+    }
+
+    #[test]
+    fn test_renderer_serializes_lowercase() {
+        let prefs: Preferences = serde_json::from_str(r#"{"renderer":"canvas"}"#).unwrap();
+        assert_eq!(prefs.renderer, MapRenderer::Canvas);
+    }
+
+    #[test]
+    fn test_star_name_lang_serializes_lowercase() {
+        let prefs: Preferences = serde_json::from_str(r#"{"star_name_lang":"arabic"}"#).unwrap();
+        assert_eq!(prefs.star_name_lang, Lang::Arabic);
+    }
+}
@@ -0,0 +1,98 @@
+//! Learning progress as one file
+//!
+//! [`Progress`] bundles everything learned so far, the per-star
+//! statistics, the spaced-repetition deck and the achievements, into a
+//! single JSON document, so that progress can be moved between browsers
+//! or between the app and the CLI. Importing replaces what is there
+//! rather than merging into it.
+
+use super::achievements::Achievements;
+use super::review::ReviewDeck;
+use super::stats::StarStats;
+use serde::{Deserialize, Serialize};
+
+/// Version of the progress format written by this build
+pub const PROGRESS_VERSION: u32 = 1;
+
+/// Default file name for exported progress
+pub const PROGRESS_FILENAME: &str = "stargazer-progress.json";
+
+/// Everything learned so far
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Progress {
+    /// Format version, required so that other JSON files are not taken
+    /// for empty progress
+    pub version: u32,
+    #[serde(default)]
+    pub stats: StarStats,
+    #[serde(default)]
+    pub deck: ReviewDeck,
+    #[serde(default)]
+    pub achievements: Achievements,
+}
+
+impl Default for Progress {
+    fn default() -> Self {
+        Self {
+            version: PROGRESS_VERSION,
+            stats: StarStats::default(),
+            deck: ReviewDeck::default(),
+            achievements: Achievements::default(),
+        }
+    }
+}
+
+impl Progress {
+    /// Pretty-printed JSON, for a file people may open
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("progress always serializes")
+    }
+
+    /// Parse progress from JSON, refusing a format newer than this build's
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let progress: Self =
+            serde_json::from_str(json).map_err(|e| format!("invalid progress file: {}", e))?;
+        if progress.version > PROGRESS_VERSION {
+            return Err(format!(
+                "progress file version {} is newer than this app supports ({})",
+                progress.version, PROGRESS_VERSION
+            ));
+        }
+        Ok(progress)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{generate_placeholder_catalog, StarId};
+    use crate::game::Achievement;
+
+    #[test]
+    fn test_progress_round_trips_through_json() {
+        let mut progress = Progress::default();
+        progress.stats.record(StarId(3), true, 1000.0);
+        progress
+            .deck
+            .introduce_new(&generate_placeholder_catalog(), 20_000, 2);
+        progress
+            .achievements
+            .unlock(Achievement::FirstCorrect, 1000.0);
+
+        let parsed = Progress::from_json(&progress.to_json()).unwrap();
+        assert_eq!(parsed, progress);
+        assert_eq!(parsed.deck.cards.len(), 2);
+    }
+
+    #[test]
+    fn test_from_json_rejects_other_files() {
+        // Missing parts are empty, but the version must be there
+        let empty = Progress::from_json(r#"{"version": 1}"#).unwrap();
+        assert_eq!(empty, Progress::default());
+        assert!(Progress::from_json("{}").is_err());
+        assert!(Progress::from_json("[1, 2]").is_err());
+
+        let newer = Progress::from_json(r#"{"version": 2}"#).unwrap_err();
+        assert!(newer.contains("newer"), "{}", newer);
+    }
+}
@@ -3,12 +3,41 @@
 //! Handles logic for creating quiz questions, selecting distractors,
 //! and managing quiz sessions.
 
-use crate::data::{Star, StarCatalog, StarId, TileSystem, ZoomLevel};
+use crate::data::asterisms::{asterisms_of, ASTERISMS};
+use crate::data::constellations::{constellation_name, CONSTELLATION_NAMES};
+use crate::data::dso::{self, DeepSkyObject};
+use crate::data::nearest::angular_separation;
+use crate::data::{Star, StarCatalog, StarId};
+#[cfg(feature = "tiles")]
+use crate::data::{TileSystem, ZoomLevel};
+#[cfg(feature = "tiles")]
+use crate::game::engine::quiz_zoom_level;
+use crate::game::matching::confusability;
+use crate::game::names::{intern, Name, NONE_OF_ABOVE};
+use crate::game::review::ReviewDeck;
+use crate::utils::SkyIndex;
 use rand::prelude::*;
-use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::rc::Rc;
+
+/// Fewest and most choices the settings panel offers
+pub const MIN_CHOICES: usize = 3;
+pub const MAX_CHOICES: usize = 5;
+
+/// Highest chance of "none of above" the settings panel offers
+pub const MAX_NONE_PROBABILITY: f64 = 0.5;
+
+/// Smallest difference in magnitude between the two stars of a "which is
+/// brighter" question, so the eye can tell them apart
+pub const MIN_MAGNITUDE_GAP: f64 = 0.3;
+
+/// How many of the nearest stars a "which is brighter" question picks its
+/// second star from
+const COMPARISON_POOL: usize = 3;
 
 /// Configuration for quiz generation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct QuizConfig {
     /// Number of choices to present (including correct answer)
     pub num_choices: usize,
@@ -18,6 +47,18 @@ pub struct QuizConfig {
 
     /// Probability of "none of above" being the correct answer
     pub none_probability: f64,
+
+    /// How random questions choose their star, given an [`AskedStars`]
+    pub selection: SelectionPolicy,
+
+    /// Where wrong choices come from; `Confusable` makes questions hard
+    pub distractor_strategy: DistractorStrategy,
+
+    /// Whether choices are proper names or Bayer designations
+    pub choice_names: ChoiceNames,
+
+    /// What a question about a star asks for
+    pub question_kind: QuestionKind,
 }
 
 impl Default for QuizConfig {
@@ -26,32 +67,287 @@ impl Default for QuizConfig {
             num_choices: 5,
             include_none_option: true,
             none_probability: 0.1,
+            selection: SelectionPolicy::default(),
+            distractor_strategy: DistractorStrategy::default(),
+            choice_names: ChoiceNames::default(),
+            question_kind: QuestionKind::default(),
+        }
+    }
+}
+
+impl QuizConfig {
+    /// This configuration with its choice count and "none of above"
+    /// chance brought into the ranges the settings panel offers
+    pub fn clamped(self) -> Self {
+        Self {
+            num_choices: self.num_choices.clamp(MIN_CHOICES, MAX_CHOICES),
+            none_probability: self.none_probability.clamp(0.0, MAX_NONE_PROBABILITY),
+            ..self
+        }
+    }
+}
+
+/// Most stars an [`AskedStars`] remembers as recently asked
+pub const MAX_RECENT_WINDOW: usize = 50;
+
+/// How a random question picks its star from the stars already asked
+///
+/// Each candidate is drawn with a weight: 1 for a star always answered
+/// right, `unseen_weight` for one not answered yet, and `1 + miss_weight`
+/// times its miss rate for the rest, so poorly known stars come up most.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SelectionPolicy {
+    /// How many of the most recently asked stars are never picked, unless
+    /// no other star is left
+    pub recent_window: usize,
+
+    /// Weight of a star with no answers yet
+    pub unseen_weight: f64,
+
+    /// Weight added for a star that is always missed
+    pub miss_weight: f64,
+}
+
+impl Default for SelectionPolicy {
+    fn default() -> Self {
+        Self {
+            recent_window: 5,
+            unseen_weight: 2.0,
+            miss_weight: 3.0,
+        }
+    }
+}
+
+/// The stars a session has asked about and how they were answered
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AskedStars {
+    /// Most recently asked last, at most [`MAX_RECENT_WINDOW`] long
+    recent: VecDeque<StarId>,
+    /// Right and wrong answers per star
+    answers: HashMap<StarId, (u32, u32)>,
+}
+
+impl AskedStars {
+    /// An empty history with room for answers about `stars` stars
+    pub fn with_capacity(stars: usize) -> Self {
+        Self {
+            recent: VecDeque::with_capacity(MAX_RECENT_WINDOW),
+            answers: HashMap::with_capacity(stars),
+        }
+    }
+
+    /// Note that a question about `star` was shown
+    pub fn record_asked(&mut self, star: StarId) {
+        if self.recent.len() == MAX_RECENT_WINDOW {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(star);
+    }
+
+    /// Note an answer about `star`
+    pub fn record_answer(&mut self, star: StarId, correct: bool) {
+        let (right, wrong) = self.answers.entry(star).or_default();
+        if correct {
+            *right += 1;
+        } else {
+            *wrong += 1;
+        }
+    }
+
+    /// Whether `star` is among the last `window` stars asked
+    pub fn is_recent(&self, star: StarId, window: usize) -> bool {
+        self.recent.iter().rev().take(window).any(|&id| id == star)
+    }
+
+    /// How likely `star` is to be picked next under `policy`
+    pub fn weight(&self, star: StarId, policy: &SelectionPolicy) -> f64 {
+        match self.answers.get(&star) {
+            None => policy.unseen_weight,
+            Some(&(right, wrong)) => {
+                let miss_rate = f64::from(wrong) / f64::from(right + wrong);
+                1.0 + policy.miss_weight * miss_rate
+            }
         }
     }
 }
 
 /// A generated quiz question
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct QuizQuestion {
     /// The star being asked about
     pub target_star: StarId,
 
     /// The correct answer (star name)
-    pub correct_answer: String,
+    pub correct_answer: Name,
 
     /// All answer choices (shuffled)
-    pub choices: Vec<String>,
+    pub choices: Vec<Name>,
 
     /// Whether this is a "none of above" question
     pub is_none_question: bool,
+
+    /// What the question asks for; only name questions count toward a
+    /// star's mastery
+    pub question_kind: QuestionKind,
+
+    /// The other star of a question about two stars
+    pub second_star: Option<StarId>,
+}
+
+/// Where a distractor was drawn from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistractorSource {
+    /// Same tile as the target at the current zoom
+    SameTile,
+    /// A tile adjacent to the target's
+    AdjacentTile,
+    /// Same constellation as the target
+    SameConstellation,
+    /// Named star whose name looks like the answer's
+    SimilarName,
+    /// Random named star (fallback)
+    Random,
+}
+
+impl DistractorSource {
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            DistractorSource::SameTile => "same tile",
+            DistractorSource::AdjacentTile => "adjacent tile",
+            DistractorSource::SameConstellation => "same constellation",
+            DistractorSource::SimilarName => "similar name",
+            DistractorSource::Random => "random",
+        }
+    }
+}
+
+/// How distractors are chosen
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DistractorStrategy {
+    /// Prefer stars in the target's tile and its neighbours; random
+    /// without the `tiles` feature
+    #[default]
+    Tile,
+    /// Prefer stars in the target's constellation
+    Constellation,
+    /// Prefer names that look like the answer, such as Alnitak for
+    /// Alnilam, for expert players
+    Confusable,
+    /// Any named star
+    Random,
+}
+
+/// What the choices of a question call stars
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChoiceNames {
+    /// Proper names, such as Betelgeuse
+    #[default]
+    Proper,
+    /// Bayer designations, such as α Ori, for advanced players; stars
+    /// without one are asked about by name
+    Bayer,
+}
+
+/// What a question about a star asks for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuestionKind {
+    /// The star's name
+    #[default]
+    Name,
+    /// The asterism the star is part of; stars in none are asked about
+    /// by name
+    Asterism,
+    /// The constellation the star is in; stars without one are asked
+    /// about by name
+    Constellation,
+    /// Which of the star and a nearby one is brighter
+    Brighter,
+}
+
+impl QuestionKind {
+    /// Every kind, in the order the settings panel shows them
+    pub const ALL: [QuestionKind; 4] = [
+        QuestionKind::Name,
+        QuestionKind::Asterism,
+        QuestionKind::Constellation,
+        QuestionKind::Brighter,
+    ];
+}
+
+/// A distractor along with its provenance
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distractor {
+    pub star_id: StarId,
+    pub name: Name,
+    pub source: DistractorSource,
+}
+
+/// Least [`confusability`] for a name to count as a look-alike
+const MIN_CONFUSABILITY: f64 = 1.0;
+
+/// Collects unique, usable distractor names up to a target count
+struct DistractorPicker {
+    used_names: HashSet<Name>,
+    distractors: Vec<Distractor>,
+    count: usize,
+}
+
+impl DistractorPicker {
+    fn new(correct_star: &Star, count: usize) -> Self {
+        Self {
+            used_names: correct_star.name.iter().map(|n| intern(n)).collect(),
+            distractors: Vec::with_capacity(count),
+            count,
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.distractors.len() >= self.count
+    }
+
+    fn remaining(&self) -> usize {
+        self.count.saturating_sub(self.distractors.len())
+    }
+
+    /// Whether `star` could still be added
+    fn accepts(&self, star: &Star) -> bool {
+        star.name
+            .as_ref()
+            .is_some_and(|n| !self.used_names.contains(n.as_str()) && n.len() >= 3)
+    }
+
+    fn offer(&mut self, star: &Star, source: DistractorSource) {
+        if self.is_full() || !self.accepts(star) {
+            return;
+        }
+        if let Some(name) = &star.name {
+            let name = intern(name);
+            self.used_names.insert(Rc::clone(&name));
+            self.distractors.push(Distractor {
+                star_id: star.id,
+                name,
+                source,
+            });
+        }
+    }
 }
 
 /// Quiz generator
 pub struct QuizGenerator<'a> {
     catalog: &'a StarCatalog,
     config: QuizConfig,
+    #[cfg(feature = "tiles")]
     tile_system: Option<&'a TileSystem>,
+    #[cfg(feature = "tiles")]
     current_zoom: ZoomLevel,
+    /// Review deck and today's day number, for choosing due stars first
+    review: Option<(&'a ReviewDeck, i64)>,
+    /// Stars already asked, for avoiding repeats
+    history: Option<&'a AskedStars>,
 }
 
 impl<'a> QuizGenerator<'a> {
@@ -60,131 +356,174 @@ impl<'a> QuizGenerator<'a> {
         Self {
             catalog,
             config,
+            #[cfg(feature = "tiles")]
             tile_system: None,
+            #[cfg(feature = "tiles")]
             current_zoom: ZoomLevel(0),
+            review: None,
+            history: None,
         }
     }
 
-    /// Create quiz generator with tile system for spatial distractors
-    pub fn with_tiles(
-        catalog: &'a StarCatalog,
-        config: QuizConfig,
-        tile_system: &'a TileSystem,
-        zoom: ZoomLevel,
-    ) -> Self {
-        Self {
-            catalog,
-            config,
-            tile_system: Some(tile_system),
-            current_zoom: zoom,
+    /// Ask about stars `deck` has due on `today` before any others
+    ///
+    /// Random questions and batches then start with the due stars, most
+    /// overdue and most often missed first.
+    pub fn with_review(mut self, deck: &'a ReviewDeck, today: i64) -> Self {
+        self.review = Some((deck, today));
+        self
+    }
+
+    /// Choose random targets by the configured [`SelectionPolicy`] over
+    /// `history`, skipping recent stars and favouring poorly known ones
+    pub fn with_history(mut self, history: &'a AskedStars) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    /// The first due star, or a weighted pick among `candidates`, leaving
+    /// out the stars `history` asked about recently while others remain
+    fn choose_target<R: Rng>(
+        &self,
+        candidates: Vec<&'a Star>,
+        due: Vec<&'a Star>,
+        history: &AskedStars,
+        rng: &mut R,
+    ) -> Option<&'a Star> {
+        let policy = &self.config.selection;
+        let recent = |star: &Star| history.is_recent(star.id, policy.recent_window);
+        if let Some(star) = due.into_iter().find(|s| !recent(s)) {
+            return Some(star);
         }
+        let fresh: Vec<&'a Star> = candidates.iter().copied().filter(|s| !recent(s)).collect();
+        let pool = if fresh.is_empty() {
+            &candidates
+        } else {
+            &fresh
+        };
+        pool.choose_weighted(rng, |star| history.weight(star.id, policy))
+            .ok()
+            .copied()
     }
 
-    /// Update current zoom level (for tile-based distractor selection)
-    pub fn set_zoom(&mut self, zoom: ZoomLevel) {
-        self.current_zoom = zoom;
+    /// Named stars in a magnitude range that the review deck has due, in
+    /// review order
+    fn due_stars(&self, min_mag: f64, max_mag: f64) -> Vec<&'a Star> {
+        let Some((deck, today)) = self.review else {
+            return Vec::new();
+        };
+        deck.due(today)
+            .into_iter()
+            .filter_map(|id| self.catalog.get(id))
+            .filter(|s| s.has_name() && s.magnitude >= min_mag && s.magnitude < max_mag)
+            .collect()
     }
 
-    /// Generate tile-aware distractors (mix of nearby and distant stars)
+    /// Generate distractors with a given strategy, recording where each came from
     ///
-    /// This method selects distractors from:
-    /// - Same tile (if available)
-    /// - Adjacent tiles (nearby stars)
-    /// - Random distant tiles
-    pub fn generate_tile_distractors<R: Rng>(
+    /// Every strategy tops up with random named stars when its preferred
+    /// pool runs out.
+    pub fn generate_distractors<R: Rng>(
         &self,
         correct_star: &Star,
         count: usize,
+        strategy: DistractorStrategy,
         rng: &mut R,
-    ) -> Vec<String> {
-        let mut used_names: HashSet<String> = HashSet::new();
-        let mut distractors = Vec::new();
-        let correct_name = correct_star.name.clone().unwrap_or_default();
-
-        used_names.insert(correct_name.clone());
-
-        // Try to use tile system if available
-        if let Some(tile_system) = self.tile_system.as_ref() {
-            if let Some(star_tiles) = tile_system.get_tiles_for_star(correct_star.id) {
-                // Find tile at current zoom
-                let current_tile = star_tiles.iter().find(|t| t.zoom == self.current_zoom);
-
-                if let Some(tile_id) = current_tile {
-                    // Get named stars in same tile
-                    if let Some(tile) = tile_system.get_tile(tile_id) {
-                        for &star_id in &tile.named_star_ids {
-                            if let Some(star) = self.catalog.get(star_id) {
-                                if let Some(ref name) = star.name {
-                                    if !used_names.contains(name) && name.len() >= 3 {
-                                        distractors.push(name.clone());
-                                        used_names.insert(name.clone());
-
-                                        if distractors.len() >= count {
-                                            return distractors;
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-
-                    // If still need more, check adjacent tiles
-                    if distractors.len() < count {
-                        let adjacent = tile_system.get_adjacent_tiles(tile_id);
-
-                        for tile in adjacent {
-                            for &star_id in &tile.named_star_ids {
-                                if let Some(star) = self.catalog.get(star_id) {
-                                    if let Some(ref name) = star.name {
-                                        if !used_names.contains(name) && name.len() >= 3 {
-                                            distractors.push(name.clone());
-                                            used_names.insert(name.clone());
-
-                                            if distractors.len() >= count {
-                                                return distractors;
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
+    ) -> Vec<Distractor> {
+        let mut picker = DistractorPicker::new(correct_star, count);
 
-                        if distractors.len() >= count {
-                            return distractors;
+        match strategy {
+            DistractorStrategy::Tile => self.pick_from_tiles(&mut picker, correct_star),
+            DistractorStrategy::Constellation => {
+                if let Some(constellation) = correct_star.constellation.as_deref() {
+                    for star in self.catalog.named_stars() {
+                        if star.constellation.as_deref() == Some(constellation) {
+                            picker.offer(star, DistractorSource::SameConstellation);
                         }
                     }
                 }
             }
+            DistractorStrategy::Confusable => self.pick_confusable(&mut picker, correct_star),
+            DistractorStrategy::Random => {}
         }
 
         // Fall back to random distant stars if needed
-        let all_named: Vec<_> = self
+        if !picker.is_full() {
+            let remaining = picker.remaining();
+            let all_named: Vec<_> = self
+                .catalog
+                .named_stars()
+                .into_iter()
+                .filter(|s| picker.accepts(s))
+                .collect();
+
+            for star in all_named.choose_multiple(rng, remaining) {
+                picker.offer(star, DistractorSource::Random);
+            }
+        }
+
+        picker.distractors
+    }
+
+    /// Offer the named stars whose names look most like the target's,
+    /// most confusable first
+    fn pick_confusable(&self, picker: &mut DistractorPicker, correct_star: &Star) {
+        let Some(name) = correct_star.name.as_deref() else {
+            return;
+        };
+        let mut look_alikes: Vec<(f64, &Star)> = self
             .catalog
             .named_stars()
             .into_iter()
-            .filter(|s| {
-                s.name
-                    .as_ref()
-                    .is_some_and(|n| !used_names.contains(n) && n.len() >= 3)
+            .filter(|s| s.id != correct_star.id)
+            .filter_map(|s| {
+                let score = confusability(name, s.name.as_deref()?);
+                (score >= MIN_CONFUSABILITY).then_some((score, s))
             })
             .collect();
-
-        let remaining = count - distractors.len();
-        let random_stars: Vec<_> = all_named.choose_multiple(rng, remaining).collect();
-
-        for star in random_stars {
-            if let Some(ref name) = star.name {
-                distractors.push(name.clone());
-                used_names.insert(name.clone());
+        look_alikes.sort_by(|a, b| b.0.total_cmp(&a.0));
+        for (_, star) in look_alikes {
+            if picker.is_full() {
+                break;
             }
+            picker.offer(star, DistractorSource::SimilarName);
         }
+    }
 
-        distractors
+    /// Distractor names for a question, by the configured strategy
+    fn question_distractors<R: Rng>(
+        &self,
+        star: &Star,
+        correct_name: &str,
+        rng: &mut R,
+    ) -> Vec<Name> {
+        match self.config.distractor_strategy {
+            DistractorStrategy::Tile => self.nearby_distractors(star, correct_name, rng),
+            strategy => self
+                .generate_distractors(star, self.config.num_choices - 1, strategy, rng)
+                .into_iter()
+                .map(|d| d.name)
+                .collect(),
+        }
     }
 
     /// Generate a question for a specific star
     pub fn generate_for_star<R: Rng>(&self, star: &Star, rng: &mut R) -> Option<QuizQuestion> {
-        let correct_name = star.name.clone()?;
+        let membership = match self.config.question_kind {
+            QuestionKind::Name => None,
+            QuestionKind::Asterism => self.generate_asterism(star, rng),
+            QuestionKind::Constellation => self.generate_constellation(star, rng),
+            QuestionKind::Brighter => self.generate_brighter(star, rng),
+        };
+        if membership.is_some() {
+            return membership;
+        }
+        if self.config.choice_names == ChoiceNames::Bayer {
+            if let Some(question) = self.generate_bayer(star, rng) {
+                return Some(question);
+            }
+        }
+        let correct_name = intern(star.name.as_deref()?);
 
         // Decide if this will be a "none of above" question
         let is_none_question =
@@ -193,50 +532,14 @@ impl<'a> QuizGenerator<'a> {
         let mut choices = Vec::with_capacity(self.config.num_choices);
 
         if is_none_question {
-            // Use tile-aware distractors if available, otherwise random
-            let distractors = if self.tile_system.is_some() {
-                self.generate_tile_distractors(star, self.config.num_choices - 1, rng)
-            } else {
-                self.catalog
-                    .random_distractors(&correct_name, self.config.num_choices - 1, rng)
-            };
-
+            let distractors = self.question_distractors(star, &correct_name, rng);
             choices.extend(distractors);
-            choices.push("none of above".to_string());
+            choices.push(intern(NONE_OF_ABOVE));
         } else {
             // Include correct answer
-            choices.push(correct_name.clone());
-
-            // Use tile-aware distractors if available, otherwise random
-            let distractors = if self.tile_system.is_some() {
-                self.generate_tile_distractors(star, self.config.num_choices - 1, rng)
-            } else {
-                self.catalog
-                    .random_distractors(&correct_name, self.config.num_choices - 1, rng)
-            };
-
-            choices.extend(distractors);
-        }
-
-        // Decide if this will be a "none of above" question
-        let is_none_question =
-            self.config.include_none_option && rng.gen::<f64>() < self.config.none_probability;
-
-        let mut choices = Vec::with_capacity(self.config.num_choices);
+            choices.push(Rc::clone(&correct_name));
 
-        if is_none_question {
-            // Get distractors (not including the correct answer)
-            let distractors =
-                self.catalog
-                    .random_distractors(&correct_name, self.config.num_choices - 1, rng);
-            choices.extend(distractors);
-            choices.push("none of above".to_string());
-        } else {
-            // Include correct answer plus distractors
-            choices.push(correct_name.clone());
-            let distractors =
-                self.catalog
-                    .random_distractors(&correct_name, self.config.num_choices - 1, rng);
+            let distractors = self.question_distractors(star, &correct_name, rng);
             choices.extend(distractors);
         }
 
@@ -244,7 +547,7 @@ impl<'a> QuizGenerator<'a> {
         choices.shuffle(rng);
 
         let actual_correct = if is_none_question {
-            "none of above".to_string()
+            intern(NONE_OF_ABOVE)
         } else {
             correct_name
         };
@@ -254,12 +557,172 @@ impl<'a> QuizGenerator<'a> {
             correct_answer: actual_correct,
             choices,
             is_none_question,
+            question_kind: QuestionKind::Name,
+            second_star: None,
         })
     }
 
-    /// Generate a random question from named stars
+    /// A question whose choices are Bayer designations: the star's among
+    /// those of the designated stars nearest it
+    fn generate_bayer<R: Rng>(&self, star: &Star, rng: &mut R) -> Option<QuizQuestion> {
+        let correct_name = intern(&star.facts()?.bayer_designation());
+        let count = self.config.num_choices - 1;
+        let mut others: Vec<(f64, Name)> = self
+            .catalog
+            .named_stars()
+            .into_iter()
+            .filter(|other| other.id != star.id)
+            .filter_map(|other| {
+                Some((
+                    angular_separation(&star.coord, &other.coord),
+                    intern(&other.facts()?.bayer_designation()),
+                ))
+            })
+            .filter(|(_, name)| *name != correct_name)
+            .collect();
+        others.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut seen = HashSet::new();
+        others.retain(|(_, name)| seen.insert(Rc::clone(name)));
+        others.truncate(count * 2);
+
+        let mut choices: Vec<Name> = others
+            .choose_multiple(rng, count)
+            .map(|(_, name)| Rc::clone(name))
+            .collect();
+        choices.push(Rc::clone(&correct_name));
+        choices.shuffle(rng);
+        Some(QuizQuestion {
+            target_star: star.id,
+            correct_answer: correct_name,
+            choices,
+            is_none_question: false,
+            question_kind: QuestionKind::Name,
+            second_star: None,
+        })
+    }
+
+    /// A question about which asterism the star is part of, if it is in
+    /// one
+    fn generate_asterism<R: Rng>(&self, star: &Star, rng: &mut R) -> Option<QuizQuestion> {
+        let (correct_answer, choices) =
+            asterism_choices(star.name.as_deref()?, self.config.num_choices, rng)?;
+        Some(QuizQuestion {
+            target_star: star.id,
+            correct_answer,
+            choices,
+            is_none_question: false,
+            question_kind: QuestionKind::Asterism,
+            second_star: None,
+        })
+    }
+
+    /// A question about which of the star and one of the named stars
+    /// nearest it is brighter; the two differ by at least
+    /// [`MIN_MAGNITUDE_GAP`]
+    fn generate_brighter<R: Rng>(&self, star: &Star, rng: &mut R) -> Option<QuizQuestion> {
+        let name = intern(star.name.as_deref()?);
+        let mut others: Vec<(f64, &Star)> = self
+            .catalog
+            .named_stars()
+            .into_iter()
+            .filter(|other| {
+                other.id != star.id && (other.magnitude - star.magnitude).abs() >= MIN_MAGNITUDE_GAP
+            })
+            .map(|other| (angular_separation(&star.coord, &other.coord), other))
+            .collect();
+        others.sort_by(|a, b| a.0.total_cmp(&b.0));
+        others.truncate(COMPARISON_POOL);
+        let (_, other) = others.choose(rng)?;
+        let other_name = intern(other.name.as_deref()?);
+
+        let correct_answer = if other.magnitude < star.magnitude {
+            Rc::clone(&other_name)
+        } else {
+            Rc::clone(&name)
+        };
+        let mut choices = vec![name, other_name];
+        choices.shuffle(rng);
+        Some(QuizQuestion {
+            target_star: star.id,
+            correct_answer,
+            choices,
+            is_none_question: false,
+            question_kind: QuestionKind::Brighter,
+            second_star: Some(other.id),
+        })
+    }
+
+    /// A question about which constellation the star is in: its own among
+    /// those of the named stars nearest it, topped up at random
+    fn generate_constellation<R: Rng>(&self, star: &Star, rng: &mut R) -> Option<QuizQuestion> {
+        let full_name =
+            |abbreviation: &str| intern(constellation_name(abbreviation).unwrap_or(abbreviation));
+        let correct_name = full_name(star.constellation.as_deref()?);
+        let count = self.config.num_choices - 1;
+        let mut others: Vec<(f64, Name)> = self
+            .catalog
+            .named_stars()
+            .into_iter()
+            .filter_map(|other| {
+                Some((
+                    angular_separation(&star.coord, &other.coord),
+                    full_name(other.constellation.as_deref()?),
+                ))
+            })
+            .filter(|(_, name)| *name != correct_name)
+            .collect();
+        others.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut seen = HashSet::new();
+        others.retain(|(_, name)| seen.insert(Rc::clone(name)));
+        others.truncate(count * 2);
+
+        let mut choices: Vec<Name> = others
+            .choose_multiple(rng, count)
+            .map(|(_, name)| Rc::clone(name))
+            .collect();
+        // A sparse catalog may not border enough constellations
+        while choices.len() < count {
+            let (_, name) = CONSTELLATION_NAMES.choose(rng)?;
+            let name = intern(name);
+            if name != correct_name && !choices.contains(&name) {
+                choices.push(name);
+            }
+        }
+        choices.push(Rc::clone(&correct_name));
+        choices.shuffle(rng);
+        Some(QuizQuestion {
+            target_star: star.id,
+            correct_answer: correct_name,
+            choices,
+            is_none_question: false,
+            question_kind: QuestionKind::Constellation,
+            second_star: None,
+        })
+    }
+
+    /// Random distractor names for a question
+    fn random_distractors<R: Rng>(&self, correct_name: &str, rng: &mut R) -> Vec<Name> {
+        self.catalog
+            .random_distractors(correct_name, self.config.num_choices - 1, rng)
+            .iter()
+            .map(|name| intern(name))
+            .collect()
+    }
+
+    /// Generate a question about the most overdue review star, or else a
+    /// random named star
+    ///
+    /// With a history, recently asked stars are skipped and the rest are
+    /// weighed by the selection policy.
     pub fn generate_random<R: Rng>(&self, rng: &mut R) -> Option<QuizQuestion> {
-        let star = self.catalog.random_named_star(rng)?;
+        let due = self.due_stars(f64::NEG_INFINITY, f64::INFINITY);
+        let star = match (self.history, due.first()) {
+            (Some(history), _) => {
+                self.choose_target(self.catalog.named_stars(), due, history, rng)?
+            }
+            (None, Some(&star)) => star,
+            (None, None) => self.catalog.random_named_star(rng)?,
+        };
         self.generate_for_star(star, rng)
     }
 
@@ -269,21 +732,213 @@ impl<'a> QuizGenerator<'a> {
         min_mag: f64,
         max_mag: f64,
         rng: &mut R,
+    ) -> Option<QuizQuestion> {
+        self.generate_matching(min_mag, max_mag, |_| true, rng)
+    }
+
+    /// Generate a question for a star within a magnitude range that
+    /// `allowed` accepts, such as one above the local horizon
+    pub fn generate_matching<R: Rng>(
+        &self,
+        min_mag: f64,
+        max_mag: f64,
+        allowed: impl Fn(&Star) -> bool,
+        rng: &mut R,
     ) -> Option<QuizQuestion> {
         let candidates: Vec<_> = self
             .catalog
             .named_stars()
             .into_iter()
-            .filter(|s| s.magnitude >= min_mag && s.magnitude < max_mag)
+            .filter(|s| s.magnitude >= min_mag && s.magnitude < max_mag && allowed(s))
             .collect();
 
-        let star = candidates.choose(rng)?;
+        let mut due = self.due_stars(min_mag, max_mag);
+        due.retain(|s| allowed(s));
+        let star = match (self.history, due.first()) {
+            (Some(history), _) => self.choose_target(candidates, due, history, rng)?,
+            (None, Some(&star)) => star,
+            (None, None) => candidates.choose(rng)?,
+        };
         self.generate_for_star(star, rng)
     }
+
+    /// Generate a session of `count` questions
+    ///
+    /// Targets are drawn without repetition until every named star has been
+    /// used, so for a given seed the whole sequence is reproducible. With a
+    /// review deck, each pass over the stars starts with the due ones.
+    pub fn generate_batch<R: Rng>(&self, count: usize, rng: &mut R) -> Vec<QuizQuestion> {
+        self.generate_batch_for_magnitude_range(f64::NEG_INFINITY, f64::INFINITY, count, rng)
+    }
+
+    /// Generate a session of `count` questions about stars within a magnitude range
+    pub fn generate_batch_for_magnitude_range<R: Rng>(
+        &self,
+        min_mag: f64,
+        max_mag: f64,
+        count: usize,
+        rng: &mut R,
+    ) -> Vec<QuizQuestion> {
+        let named: Vec<_> = self
+            .catalog
+            .named_stars()
+            .into_iter()
+            .filter(|s| s.magnitude >= min_mag && s.magnitude < max_mag)
+            .collect();
+        let due = self.due_stars(min_mag, max_mag);
+        let mut questions = Vec::with_capacity(count);
+        let mut pool: Vec<&Star> = Vec::new();
+
+        while questions.len() < count {
+            if pool.is_empty() {
+                if named.is_empty() {
+                    break;
+                }
+                pool = named.clone();
+                pool.shuffle(rng);
+                // Targets are popped from the end, so due stars go there
+                pool.retain(|s| !due.iter().any(|d| d.id == s.id));
+                pool.extend(due.iter().rev());
+            }
+
+            if let Some(question) = pool.pop().and_then(|s| self.generate_for_star(s, rng)) {
+                questions.push(question);
+            }
+        }
+
+        questions
+    }
+}
+
+/// Distractors from the tiles around the target
+#[cfg(feature = "tiles")]
+impl<'a> QuizGenerator<'a> {
+    /// Create quiz generator with tile system for spatial distractors
+    pub fn with_tiles(
+        catalog: &'a StarCatalog,
+        config: QuizConfig,
+        tile_system: &'a TileSystem,
+        zoom: ZoomLevel,
+    ) -> Self {
+        Self {
+            tile_system: Some(tile_system),
+            current_zoom: zoom,
+            ..Self::new(catalog, config)
+        }
+    }
+
+    /// Create a quiz generator with `index`'s tiles, at the tile zoom
+    /// level for the viewport zoom `view_zoom`
+    pub fn with_index(
+        catalog: &'a StarCatalog,
+        config: QuizConfig,
+        index: &'a SkyIndex,
+        view_zoom: f64,
+    ) -> Self {
+        Self::with_tiles(catalog, config, index.tiles(), quiz_zoom_level(view_zoom))
+    }
+
+    /// Update current zoom level (for tile-based distractor selection)
+    pub fn set_zoom(&mut self, zoom: ZoomLevel) {
+        self.current_zoom = zoom;
+    }
+
+    /// Generate tile-aware distractors (mix of nearby and distant stars)
+    ///
+    /// This method selects distractors from:
+    /// - Same tile (if available)
+    /// - Adjacent tiles (nearby stars)
+    /// - Random distant tiles
+    pub fn generate_tile_distractors<R: Rng>(
+        &self,
+        correct_star: &Star,
+        count: usize,
+        rng: &mut R,
+    ) -> Vec<Name> {
+        self.generate_distractors(correct_star, count, DistractorStrategy::Tile, rng)
+            .into_iter()
+            .map(|d| d.name)
+            .collect()
+    }
+
+    /// Offer named stars from the target's tile, then its neighbours
+    fn pick_from_tiles(&self, picker: &mut DistractorPicker, correct_star: &Star) {
+        let Some(tile_system) = self.tile_system else {
+            return;
+        };
+        let Some(star_tiles) = tile_system.get_tiles_for_star(correct_star.id) else {
+            return;
+        };
+        // Find tile at current zoom
+        let Some(tile_id) = star_tiles.iter().find(|t| t.zoom == self.current_zoom) else {
+            return;
+        };
+
+        // Get named stars in same tile
+        if let Some(tile) = tile_system.get_tile(tile_id) {
+            for &star_id in &tile.named_star_ids {
+                if let Some(star) = self.catalog.get(star_id) {
+                    picker.offer(star, DistractorSource::SameTile);
+                }
+            }
+        }
+
+        // If still need more, check adjacent tiles
+        for tile in tile_system.get_adjacent_tiles(tile_id) {
+            for &star_id in &tile.named_star_ids {
+                if picker.is_full() {
+                    return;
+                }
+                if let Some(star) = self.catalog.get(star_id) {
+                    picker.offer(star, DistractorSource::AdjacentTile);
+                }
+            }
+        }
+    }
+
+    /// Tile-aware distractors if the generator has tiles, otherwise random
+    fn nearby_distractors<R: Rng>(
+        &self,
+        star: &Star,
+        correct_name: &str,
+        rng: &mut R,
+    ) -> Vec<Name> {
+        if self.tile_system.is_some() {
+            self.generate_tile_distractors(star, self.config.num_choices - 1, rng)
+        } else {
+            self.random_distractors(correct_name, rng)
+        }
+    }
+}
+
+/// Without tiles, every question uses random distractors
+#[cfg(not(feature = "tiles"))]
+impl<'a> QuizGenerator<'a> {
+    /// Create a quiz generator; without tiles `index` holds nothing to use
+    pub fn with_index(
+        catalog: &'a StarCatalog,
+        config: QuizConfig,
+        _index: &'a SkyIndex,
+        _view_zoom: f64,
+    ) -> Self {
+        Self::new(catalog, config)
+    }
+
+    fn pick_from_tiles(&self, _picker: &mut DistractorPicker, _correct_star: &Star) {}
+
+    fn nearby_distractors<R: Rng>(
+        &self,
+        _star: &Star,
+        correct_name: &str,
+        rng: &mut R,
+    ) -> Vec<Name> {
+        self.random_distractors(correct_name, rng)
+    }
 }
 
 /// Difficulty levels for the quiz
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Difficulty {
     /// Only very bright, famous stars (mag < 2)
     Easy,
@@ -303,6 +958,12 @@ impl Difficulty {
         }
     }
 
+    /// Whether a star of this magnitude belongs at this difficulty
+    pub fn includes(&self, magnitude: f64) -> bool {
+        let (min, max) = self.magnitude_range();
+        (min..max).contains(&magnitude)
+    }
+
     /// Get display name
     pub fn name(&self) -> &'static str {
         match self {
@@ -313,6 +974,48 @@ impl Difficulty {
     }
 }
 
+/// Choices for a question about a Messier object: its designation among
+/// those of objects near it on the sky, shuffled
+pub fn deep_sky_choices<R: Rng>(
+    object: &DeepSkyObject,
+    num_choices: usize,
+    rng: &mut R,
+) -> Vec<Name> {
+    let count = num_choices.saturating_sub(1);
+    let mut choices: Vec<Name> = dso::nearest(object, count * 2)
+        .choose_multiple(rng, count)
+        .map(|other| intern(&other.designation()))
+        .collect();
+    choices.push(intern(&object.designation()));
+    choices.shuffle(rng);
+    choices
+}
+
+/// The answer and choices for a question about which asterism the star
+/// named `name` is part of, or `None` if it is in none
+///
+/// A star in several asterisms is asked about one of them, and the others
+/// are kept out of the choices.
+pub fn asterism_choices<R: Rng>(
+    name: &str,
+    num_choices: usize,
+    rng: &mut R,
+) -> Option<(Name, Vec<Name>)> {
+    let memberships: Vec<_> = asterisms_of(name).collect();
+    let correct = memberships.choose(rng)?;
+    let others: Vec<_> = ASTERISMS
+        .iter()
+        .filter(|asterism| !asterism.contains(name))
+        .collect();
+    let mut choices: Vec<Name> = others
+        .choose_multiple(rng, num_choices.saturating_sub(1))
+        .map(|asterism| intern(asterism.name))
+        .collect();
+    choices.push(intern(correct.name));
+    choices.shuffle(rng);
+    Some((intern(correct.name), choices))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -349,12 +1052,307 @@ mod tests {
         assert!(q.is_some());
     }
 
+    #[test]
+    fn test_deep_sky_choices() {
+        let orion_nebula = dso::messier(42).unwrap();
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(7);
+        let choices = deep_sky_choices(orion_nebula, 4, &mut rng);
+        assert_eq!(choices.len(), 4);
+        assert!(choices.contains(&intern("M42")));
+        let unique: HashSet<_> = choices.iter().collect();
+        assert_eq!(unique.len(), 4);
+    }
+
+    #[test]
+    fn test_asterism_choices() {
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(3);
+        let (correct, choices) = asterism_choices("Mizar", 4, &mut rng).unwrap();
+        assert_eq!(correct, intern("Big Dipper"));
+        assert_eq!(choices.len(), 4);
+        assert!(choices.contains(&correct));
+
+        // Sirius is in both winter asterisms, so only one is a choice
+        for seed in 0..10 {
+            let mut rng = rand::rngs::SmallRng::seed_from_u64(seed);
+            let (correct, choices) = asterism_choices("Sirius", 5, &mut rng).unwrap();
+            assert!(["Winter Triangle", "Winter Hexagon"].contains(&&*correct));
+            let winter = choices.iter().filter(|c| c.starts_with("Winter")).count();
+            assert_eq!(winter, 1);
+        }
+        assert_eq!(asterism_choices("Polaris", 4, &mut rng), None);
+    }
+
+    #[test]
+    fn test_constellation_questions() {
+        let stars = [
+            ("Betelgeuse", 5.92, 7.41, Some("Ori")),
+            ("Aldebaran", 4.60, 16.51, Some("Tau")),
+            ("Sirius", 6.75, -16.72, Some("CMa")),
+            ("Rigel", 5.24, -8.20, Some("Ori")),
+            ("Drifter", 5.0, 0.0, None),
+        ];
+        let mut catalog = StarCatalog::new();
+        for (i, (name, ra, dec, constellation)) in stars.into_iter().enumerate() {
+            catalog.add_star(Star {
+                id: StarId(i as u32 + 1),
+                name: Some(name.to_string()),
+                coord: crate::data::CelestialCoord::new(ra, dec),
+                magnitude: 1.0,
+                constellation: constellation.map(str::to_string),
+            });
+        }
+        catalog.rebuild_indices();
+        let config = QuizConfig {
+            num_choices: 4,
+            question_kind: QuestionKind::Constellation,
+            ..QuizConfig::default()
+        };
+        let generator = QuizGenerator::new(&catalog, config);
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(9);
+
+        let question = generator
+            .generate_for_star(catalog.get(StarId(1)).unwrap(), &mut rng)
+            .unwrap();
+        assert_eq!(question.question_kind, QuestionKind::Constellation);
+        assert_eq!(&*question.correct_answer, "Orion");
+        // Both neighboring constellations, topped up to four
+        assert_eq!(question.choices.len(), 4);
+        for name in ["Orion", "Taurus", "Canis Major"] {
+            assert!(question.choices.contains(&intern(name)), "{}", name);
+        }
+        let unique: HashSet<_> = question.choices.iter().collect();
+        assert_eq!(unique.len(), 4);
+
+        // A star in no constellation is asked about by name
+        let question = generator
+            .generate_for_star(catalog.get(StarId(5)).unwrap(), &mut rng)
+            .unwrap();
+        assert_eq!(question.question_kind, QuestionKind::Name);
+    }
+
+    #[test]
+    fn test_brighter_questions() {
+        let stars = [
+            ("Betelgeuse", 5.92, 7.41, 0.50),
+            ("Bellatrix", 5.42, 6.35, 1.64),
+            ("Meissa", 5.59, 9.93, 0.55),
+            ("Sirius", 6.75, -16.72, -1.46),
+        ];
+        let mut catalog = StarCatalog::new();
+        for (i, (name, ra, dec, magnitude)) in stars.into_iter().enumerate() {
+            catalog.add_star(Star {
+                id: StarId(i as u32 + 1),
+                name: Some(name.to_string()),
+                coord: crate::data::CelestialCoord::new(ra, dec),
+                magnitude,
+                constellation: None,
+            });
+        }
+        catalog.rebuild_indices();
+        let config = QuizConfig {
+            question_kind: QuestionKind::Brighter,
+            ..QuizConfig::default()
+        };
+        let generator = QuizGenerator::new(&catalog, config);
+
+        for seed in 0..10 {
+            let mut rng = rand::rngs::SmallRng::seed_from_u64(seed);
+            let question = generator
+                .generate_for_star(catalog.get(StarId(1)).unwrap(), &mut rng)
+                .unwrap();
+            assert_eq!(question.question_kind, QuestionKind::Brighter);
+            assert_eq!(question.choices.len(), 2);
+            assert!(!question.is_none_question);
+            // Meissa is too close in brightness to compare
+            let expected = match question.second_star {
+                Some(StarId(2)) => "Betelgeuse",
+                Some(StarId(4)) => "Sirius",
+                other => panic!("unexpected second star {:?}", other),
+            };
+            assert_eq!(&*question.correct_answer, expected);
+            assert!(question.choices.contains(&intern("Betelgeuse")));
+        }
+    }
+
+    #[test]
+    fn test_bayer_choices() {
+        // The bright stars of Orion, near Betelgeuse, and Vega far away
+        let stars = [
+            ("Betelgeuse", 5.92, 7.41),
+            ("Rigel", 5.24, -8.20),
+            ("Bellatrix", 5.42, 6.35),
+            ("Saiph", 5.80, -9.67),
+            ("Vega", 18.62, 38.78),
+            ("Unlisted", 5.90, 7.0),
+        ];
+        let mut catalog = StarCatalog::new();
+        for (i, (name, ra, dec)) in stars.into_iter().enumerate() {
+            catalog.add_star(Star {
+                id: StarId(i as u32 + 1),
+                name: Some(name.to_string()),
+                coord: crate::data::CelestialCoord::new(ra, dec),
+                magnitude: 1.0,
+                constellation: None,
+            });
+        }
+        catalog.rebuild_indices();
+        let config = QuizConfig {
+            num_choices: 2,
+            choice_names: ChoiceNames::Bayer,
+            ..QuizConfig::default()
+        };
+        let generator = QuizGenerator::new(&catalog, config);
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(3);
+
+        let question = generator
+            .generate_for_star(catalog.get(StarId(1)).unwrap(), &mut rng)
+            .unwrap();
+        assert_eq!(&*question.correct_answer, "α Ori");
+        assert!(!question.is_none_question);
+        assert_eq!(question.choices.len(), 2);
+        // The other choice is one of the two designated stars nearest
+        for choice in &question.choices {
+            assert!(["α Ori", "γ Ori", "κ Ori"].contains(&&**choice));
+        }
+
+        // A star without a designation is asked about by name
+        let question = generator
+            .generate_for_star(catalog.get(StarId(6)).unwrap(), &mut rng)
+            .unwrap();
+        assert_eq!(&*question.correct_answer, "Unlisted");
+    }
+
     #[test]
     fn test_difficulty_magnitude_ranges() {
         assert!(Difficulty::Easy.magnitude_range().1 < Difficulty::Medium.magnitude_range().1);
         assert!(Difficulty::Medium.magnitude_range().1 < Difficulty::Hard.magnitude_range().1);
     }
 
+    #[test]
+    fn test_batch_has_distinct_targets() {
+        let catalog = generate_placeholder_catalog();
+        let generator = QuizGenerator::new(&catalog, QuizConfig::default());
+        let mut rng = rand::thread_rng();
+
+        let batch = generator.generate_batch(10, &mut rng);
+        assert_eq!(batch.len(), 10);
+
+        let targets: HashSet<_> = batch.iter().map(|q| q.target_star).collect();
+        assert_eq!(targets.len(), 10);
+    }
+
+    #[test]
+    fn test_review_deck_targets_come_first() {
+        let catalog = generate_placeholder_catalog();
+        let named = catalog.named_stars();
+        let mut deck = ReviewDeck::default();
+        deck.introduce_new(&catalog, 0, named.len());
+        // Two missed stars, the rest learned and not due for a while
+        let missed = [named[4].id, named[2].id];
+        for card in &mut deck.cards {
+            card.record(!missed.contains(&card.star), 0);
+        }
+        deck.card_mut(missed[0]).unwrap().record(false, 0);
+
+        let generator = QuizGenerator::new(&catalog, QuizConfig::default()).with_review(&deck, 1);
+        let mut rng = rand::thread_rng();
+        let question = generator.generate_random(&mut rng).unwrap();
+        assert_eq!(question.target_star, missed[0]);
+
+        let batch = generator.generate_batch(4, &mut rng);
+        let targets: Vec<StarId> = batch.iter().map(|q| q.target_star).collect();
+        assert_eq!(targets[..2], missed);
+        assert!(!targets[2..].iter().any(|id| missed.contains(id)));
+
+        // Nothing is due yet on the day of the answers
+        let generator = QuizGenerator::new(&catalog, QuizConfig::default()).with_review(&deck, 0);
+        assert!(generator
+            .due_stars(f64::NEG_INFINITY, f64::INFINITY)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_history_avoids_recent_stars() {
+        let catalog = generate_placeholder_catalog();
+        let mut history = AskedStars::default();
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(5);
+
+        let mut asked = Vec::new();
+        for _ in 0..20 {
+            let question = QuizGenerator::new(&catalog, QuizConfig::default())
+                .with_history(&history)
+                .generate_random(&mut rng)
+                .unwrap();
+            history.record_asked(question.target_star);
+            asked.push(question.target_star);
+        }
+        for window in asked.windows(6) {
+            assert!(!window[1..].contains(&window[0]), "{:?}", asked);
+        }
+    }
+
+    #[test]
+    fn test_history_weighs_missed_stars_up() {
+        let policy = SelectionPolicy::default();
+        let mut history = AskedStars::default();
+        history.record_answer(StarId(1), true);
+        history.record_answer(StarId(2), true);
+        history.record_answer(StarId(2), false);
+        history.record_answer(StarId(3), false);
+
+        let known = history.weight(StarId(1), &policy);
+        let shaky = history.weight(StarId(2), &policy);
+        let missed = history.weight(StarId(3), &policy);
+        assert_eq!(known, 1.0);
+        assert!(known < shaky && shaky < missed);
+        assert_eq!(history.weight(StarId(4), &policy), policy.unseen_weight);
+
+        // With only recent stars left, they are asked anyway
+        let catalog = generate_placeholder_catalog();
+        let only = catalog.named_stars()[0];
+        let mut history = AskedStars::default();
+        history.record_asked(only.id);
+        let generator = QuizGenerator::new(&catalog, QuizConfig::default()).with_history(&history);
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(1);
+        let question = generator
+            .generate_for_magnitude_range(only.magnitude, only.magnitude + 1e-9, &mut rng)
+            .unwrap();
+        assert_eq!(question.target_star, only.id);
+    }
+
+    #[test]
+    fn test_batch_respects_magnitude_range() {
+        let catalog = generate_placeholder_catalog();
+        let generator = QuizGenerator::new(&catalog, QuizConfig::default());
+        let mut rng = rand::thread_rng();
+
+        let (min_mag, max_mag) = Difficulty::Easy.magnitude_range();
+        let batch = generator.generate_batch_for_magnitude_range(min_mag, max_mag, 5, &mut rng);
+        assert!(!batch.is_empty());
+        for q in batch {
+            let star = catalog.get(q.target_star).unwrap();
+            assert!(star.magnitude >= min_mag && star.magnitude < max_mag);
+        }
+    }
+
+    #[test]
+    fn test_generate_matching_keeps_to_allowed_stars() {
+        let catalog = generate_placeholder_catalog();
+        let generator = QuizGenerator::new(&catalog, QuizConfig::default());
+        let mut rng = rand::thread_rng();
+
+        let north = |star: &Star| star.coord.dec > 0.0;
+        for _ in 0..20 {
+            let question = generator
+                .generate_matching(f64::NEG_INFINITY, f64::INFINITY, north, &mut rng)
+                .unwrap();
+            assert!(north(catalog.get(question.target_star).unwrap()));
+        }
+        assert!(generator
+            .generate_matching(f64::NEG_INFINITY, f64::INFINITY, |_| false, &mut rng)
+            .is_none());
+    }
+
     #[test]
     fn test_no_duplicate_choices() {
         let catalog = generate_placeholder_catalog();
@@ -362,6 +1360,7 @@ mod tests {
             num_choices: 5,
             include_none_option: false,
             none_probability: 0.0,
+            ..QuizConfig::default()
         };
         let generator = QuizGenerator::new(&catalog, config);
         let mut rng = rand::thread_rng();
@@ -375,4 +1374,203 @@ mod tests {
             }
         }
     }
+
+    /// A small catalog with a tight cluster around the target and a few far-off stars
+    fn crafted_catalog() -> StarCatalog {
+        let stars = [
+            ("Target", 6.00, 10.00),
+            ("Closeby", 6.01, 10.05),
+            ("Nextdoor", 6.02, 9.95),
+            ("Neighbor", 6.60, 14.00),
+            ("Faraway", 18.00, -40.00),
+            ("Distant", 20.00, -60.00),
+            ("Remote", 14.00, 70.00),
+        ];
+
+        let mut catalog = StarCatalog::new();
+        for (i, (name, ra, dec)) in stars.into_iter().enumerate() {
+            catalog.add_star(Star {
+                id: StarId(i as u32 + 1),
+                name: Some(name.to_string()),
+                coord: crate::data::CelestialCoord::new(ra, dec),
+                magnitude: 2.0,
+                constellation: Some(if dec > 0.0 { "Ori" } else { "Sco" }.to_string()),
+            });
+        }
+        catalog.rebuild_indices();
+        catalog
+    }
+
+    #[test]
+    #[cfg(feature = "tiles")]
+    fn test_distractor_provenance_matches_tiles() {
+        let catalog = crafted_catalog();
+        let stars: Vec<_> = catalog.all_stars().cloned().collect();
+        let tiles = TileSystem::from_stars(&stars);
+        let zoom = ZoomLevel(3);
+        let generator = QuizGenerator::with_tiles(&catalog, QuizConfig::default(), &tiles, zoom);
+
+        let target = catalog.get(StarId(1)).unwrap();
+        let tile_at_zoom = |id: StarId| {
+            *tiles
+                .get_tiles_for_star(id)
+                .unwrap()
+                .iter()
+                .find(|t| t.zoom == zoom)
+                .unwrap()
+        };
+        let target_tile = tile_at_zoom(target.id);
+        let adjacent: Vec<_> = tiles
+            .get_adjacent_tiles(&target_tile)
+            .iter()
+            .map(|t| t.id)
+            .collect();
+
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(7);
+        let distractors =
+            generator.generate_distractors(target, 6, DistractorStrategy::Tile, &mut rng);
+        assert_eq!(distractors.len(), 6);
+
+        for d in &distractors {
+            let tile = tile_at_zoom(d.star_id);
+            match d.source {
+                DistractorSource::SameTile => assert_eq!(tile, target_tile, "{}", d.name),
+                DistractorSource::AdjacentTile => assert!(adjacent.contains(&tile), "{}", d.name),
+                DistractorSource::Random => {
+                    assert_ne!(tile, target_tile, "{}", d.name);
+                    assert!(!adjacent.contains(&tile), "{}", d.name);
+                }
+                DistractorSource::SameConstellation | DistractorSource::SimilarName => {
+                    panic!("unexpected source")
+                }
+            }
+        }
+
+        // The cluster members share the target's tile
+        let closeby = distractors.iter().find(|d| &*d.name == "Closeby").unwrap();
+        assert_eq!(closeby.source, DistractorSource::SameTile);
+    }
+
+    #[test]
+    #[cfg(feature = "tiles")]
+    fn test_generate_for_star_uses_tile_distractors() {
+        let catalog = generate_placeholder_catalog();
+        let stars: Vec<_> = catalog.all_stars().cloned().collect();
+        let tiles = TileSystem::from_stars(&stars);
+        let config = QuizConfig {
+            include_none_option: false,
+            ..QuizConfig::default()
+        };
+        let generator = QuizGenerator::with_tiles(&catalog, config, &tiles, ZoomLevel(2));
+        let target = catalog.lookup("Betelgeuse");
+        let crate::data::search::LookupResult::Found(target) = target else {
+            panic!("Betelgeuse missing from placeholder catalog");
+        };
+
+        let question = generator
+            .generate_for_star(target, &mut rand::rngs::SmallRng::seed_from_u64(5))
+            .unwrap();
+        let mut expected = generator.generate_tile_distractors(
+            target,
+            4,
+            &mut rand::rngs::SmallRng::seed_from_u64(5),
+        );
+        expected.push("Betelgeuse".into());
+
+        let mut choices = question.choices.clone();
+        choices.sort();
+        expected.sort();
+        assert_eq!(choices, expected);
+    }
+
+    #[test]
+    fn test_index_generator_questions() {
+        let catalog = generate_placeholder_catalog();
+        let index = SkyIndex::new(&catalog);
+        let generator = QuizGenerator::with_index(&catalog, QuizConfig::default(), &index, 4.0);
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(9);
+
+        for question in generator.generate_batch(20, &mut rng) {
+            let unique: HashSet<_> = question.choices.iter().collect();
+            assert_eq!(unique.len(), 5);
+            assert!(question.choices.contains(&question.correct_answer));
+        }
+    }
+
+    #[test]
+    fn test_constellation_strategy() {
+        let catalog = crafted_catalog();
+        let generator = QuizGenerator::new(&catalog, QuizConfig::default());
+        let target = catalog.get(StarId(1)).unwrap();
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(7);
+
+        let distractors =
+            generator.generate_distractors(target, 4, DistractorStrategy::Constellation, &mut rng);
+        assert_eq!(distractors.len(), 4);
+
+        for d in &distractors {
+            let star = catalog.get(d.star_id).unwrap();
+            let same = star.constellation == target.constellation;
+            assert_eq!(
+                d.source == DistractorSource::SameConstellation,
+                same,
+                "{}",
+                d.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_confusable_strategy() {
+        let mut catalog = StarCatalog::new();
+        let names = [
+            "Alnilam", "Mintaka", "Alnitak", "Sirius", "Altair", "Alnair", "Vega",
+        ];
+        for (i, name) in names.into_iter().enumerate() {
+            catalog.add_star(Star {
+                id: StarId(i as u32 + 1),
+                name: Some(name.to_string()),
+                coord: crate::data::CelestialCoord::new(i as f64, 0.0),
+                magnitude: 2.0,
+                constellation: None,
+            });
+        }
+        catalog.rebuild_indices();
+        let target = catalog.get(StarId(1)).unwrap();
+        let config = QuizConfig {
+            include_none_option: false,
+            distractor_strategy: DistractorStrategy::Confusable,
+            ..QuizConfig::default()
+        };
+        let generator = QuizGenerator::new(&catalog, config);
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(7);
+
+        let distractors =
+            generator.generate_distractors(target, 4, DistractorStrategy::Confusable, &mut rng);
+        assert_eq!(distractors.len(), 4);
+        let similar: Vec<&str> = distractors
+            .iter()
+            .filter(|d| d.source == DistractorSource::SimilarName)
+            .map(|d| &*d.name)
+            .collect();
+        assert_eq!(similar, ["Alnitak", "Alnair", "Altair"]);
+        assert_eq!(distractors[3].source, DistractorSource::Random);
+
+        // Questions take their choices from the configured strategy
+        let question = generator.generate_for_star(target, &mut rng).unwrap();
+        assert!(question.choices.contains(&distractors[0].name));
+    }
+
+    #[test]
+    fn test_seeded_distractors_are_reproducible() {
+        let catalog = generate_placeholder_catalog();
+        let generator = QuizGenerator::new(&catalog, QuizConfig::default());
+        let target = catalog.named_stars()[0];
+
+        let run = |seed| {
+            let mut rng = rand::rngs::SmallRng::seed_from_u64(seed);
+            generator.generate_distractors(target, 4, DistractorStrategy::Random, &mut rng)
+        };
+        assert_eq!(run(3), run(3));
+    }
 }
@@ -0,0 +1,352 @@
+//! Spaced-repetition review of named stars
+//!
+//! A [`ReviewDeck`] holds one card per star being learned. Each answer
+//! reschedules its card: correct answers push the next review further out
+//! by the card's ease factor, wrong ones bring it back the next day. Days
+//! are day numbers from [`crate::utils::dates`], passed in by the caller
+//! so scheduling never reads the clock itself.
+//!
+//! Quiz answers feed the same deck through [`ReviewDeck::record_guesses`],
+//! and a [`QuizGenerator`](super::QuizGenerator) given the deck asks about
+//! due stars first.
+
+use super::state::GuessSummary;
+use crate::data::{Star, StarCatalog, StarId};
+use crate::utils::dates::day_from_unix_millis;
+#[cfg(feature = "web")]
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// localStorage key for a deck brought into the browser with imported
+/// progress, kept so that exporting again carries it on
+pub const DECK_KEY: &str = "stargazer.deck";
+
+/// Ease factor of a new card
+const INITIAL_EASE: f64 = 2.5;
+
+/// Lowest ease factor, so hard cards still make progress
+const MIN_EASE: f64 = 1.3;
+
+/// Ease gained on a correct answer and lost on a wrong one
+const EASE_STEP: f64 = 0.15;
+
+/// One star being learned
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewCard {
+    pub star: StarId,
+    /// Day the card was added to the deck
+    pub introduced: i64,
+    /// Day the card is next due
+    pub due: i64,
+    /// Days between the last review and `due`
+    pub interval: u32,
+    pub ease: f64,
+    /// Correct answers in a row
+    pub streak: u32,
+    /// Times the card was answered wrong
+    pub lapses: u32,
+}
+
+impl ReviewCard {
+    fn new(star: StarId, today: i64) -> Self {
+        Self {
+            star,
+            introduced: today,
+            due: today,
+            interval: 0,
+            ease: INITIAL_EASE,
+            streak: 0,
+            lapses: 0,
+        }
+    }
+
+    /// Reschedule after an answer given on `today`
+    pub fn record(&mut self, correct: bool, today: i64) {
+        if correct {
+            self.streak += 1;
+            self.interval = match self.streak {
+                1 => 1,
+                2 => 3,
+                _ => (f64::from(self.interval) * self.ease).round() as u32,
+            };
+            self.ease += EASE_STEP;
+        } else {
+            self.streak = 0;
+            self.lapses += 1;
+            self.interval = 1;
+            self.ease = (self.ease - EASE_STEP).max(MIN_EASE);
+        }
+        self.due = today + i64::from(self.interval);
+    }
+}
+
+/// A learner's cards, saved between sessions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReviewDeck {
+    pub cards: Vec<ReviewCard>,
+}
+
+impl ReviewDeck {
+    /// Whether the deck already has a card for `star`
+    pub fn contains(&self, star: StarId) -> bool {
+        self.cards.iter().any(|c| c.star == star)
+    }
+
+    /// The card for `star`
+    pub fn card_mut(&mut self, star: StarId) -> Option<&mut ReviewCard> {
+        self.cards.iter_mut().find(|c| c.star == star)
+    }
+
+    /// Add up to `per_day` new cards on `today`, counting any already added today
+    ///
+    /// Stars are introduced in [`introduction_order`]. Returns the new cards' stars.
+    pub fn introduce_new(
+        &mut self,
+        catalog: &StarCatalog,
+        today: i64,
+        per_day: usize,
+    ) -> Vec<StarId> {
+        let introduced_today = self.cards.iter().filter(|c| c.introduced == today).count();
+        let new: Vec<StarId> = introduction_order(catalog)
+            .into_iter()
+            .map(|s| s.id)
+            .filter(|&id| !self.contains(id))
+            .take(per_day.saturating_sub(introduced_today))
+            .collect();
+
+        self.cards
+            .extend(new.iter().map(|&id| ReviewCard::new(id, today)));
+        new
+    }
+
+    /// Stars due for review on `today`, most overdue first, then most often missed
+    pub fn due(&self, today: i64) -> Vec<StarId> {
+        let mut due: Vec<&ReviewCard> = self.cards.iter().filter(|c| c.due <= today).collect();
+        due.sort_by_key(|c| (c.due, Reverse(c.lapses), c.star.0));
+        due.into_iter().map(|c| c.star).collect()
+    }
+
+    /// Reschedule cards for quiz answers, adding cards for stars not yet in the deck
+    ///
+    /// Each guess counts on the day of its timestamp. Guesses whose star is
+    /// not a named catalog star, such as "none of the above" questions, are
+    /// skipped. Returns how many guesses were recorded.
+    pub fn record_guesses(&mut self, catalog: &StarCatalog, guesses: &[GuessSummary]) -> usize {
+        let by_name = ids_by_name(catalog);
+        let mut recorded = 0;
+        for guess in guesses {
+            let Some(&star) = by_name.get(&*guess.star_name) else {
+                continue;
+            };
+            let today = day_from_unix_millis(guess.timestamp);
+            if !self.contains(star) {
+                self.cards.push(ReviewCard::new(star, today));
+            }
+            if let Some(card) = self.card_mut(star) {
+                card.record(guess.was_correct, today);
+                recorded += 1;
+            }
+        }
+        recorded
+    }
+
+    /// Earliest day any card is due, if the deck has cards
+    pub fn next_due(&self) -> Option<i64> {
+        self.cards.iter().map(|c| c.due).min()
+    }
+}
+
+#[cfg(feature = "web")]
+impl ReviewDeck {
+    /// Load the deck from localStorage, starting empty if there is none
+    pub fn load() -> Self {
+        LocalStorage::get(DECK_KEY).unwrap_or_default()
+    }
+
+    /// Save the deck to localStorage
+    pub fn save(&self) {
+        if let Err(e) = LocalStorage::set(DECK_KEY, self) {
+            log::warn!("Failed to save review deck: {}", e);
+        }
+    }
+}
+
+fn ids_by_name(catalog: &StarCatalog) -> HashMap<&str, StarId> {
+    catalog
+        .named_stars()
+        .into_iter()
+        .filter_map(|s| Some((s.name.as_deref()?, s.id)))
+        .collect()
+}
+
+/// The named catalog stars answered wrong in `guesses`, each once, in the
+/// order they were first missed
+pub fn missed_stars(catalog: &StarCatalog, guesses: &[GuessSummary]) -> Vec<StarId> {
+    let by_name = ids_by_name(catalog);
+    let mut missed: Vec<StarId> = Vec::new();
+    for guess in guesses.iter().filter(|guess| !guess.was_correct) {
+        if let Some(&star) = by_name.get(&*guess.star_name) {
+            if !missed.contains(&star) {
+                missed.push(star);
+            }
+        }
+    }
+    missed
+}
+
+/// Named stars in the order they are introduced: brightest first
+///
+/// The catalog has no popularity data, so brightness stands in for fame;
+/// the brightest stars are also the best known.
+pub fn introduction_order(catalog: &StarCatalog) -> Vec<&Star> {
+    let mut stars = catalog.named_stars();
+    stars.sort_by(|a, b| {
+        a.magnitude
+            .total_cmp(&b.magnitude)
+            .then_with(|| a.id.0.cmp(&b.id.0))
+    });
+    stars
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::generate_placeholder_catalog;
+
+    #[test]
+    fn test_intervals_grow_and_reset() {
+        let mut card = ReviewCard::new(StarId(1), 0);
+        card.record(true, 0);
+        assert_eq!((card.interval, card.due), (1, 1));
+        card.record(true, 1);
+        assert_eq!((card.interval, card.due), (3, 4));
+        card.record(true, 4);
+        assert!(card.interval > 3);
+
+        card.record(false, 20);
+        assert_eq!((card.interval, card.due, card.streak), (1, 21, 0));
+        assert_eq!(card.lapses, 1);
+    }
+
+    #[test]
+    fn test_ease_has_a_floor() {
+        let mut card = ReviewCard::new(StarId(1), 0);
+        for day in 0..20 {
+            card.record(false, day);
+        }
+        assert_eq!(card.ease, MIN_EASE);
+    }
+
+    #[test]
+    fn test_two_days_of_review() {
+        let catalog = generate_placeholder_catalog();
+        let mut deck = ReviewDeck::default();
+        let order: Vec<StarId> = introduction_order(&catalog).iter().map(|s| s.id).collect();
+
+        // Day 0: three new stars, the brightest
+        let new = deck.introduce_new(&catalog, 0, 3);
+        assert_eq!(new, order[..3]);
+        assert_eq!(deck.due(0).len(), 3);
+        // Introducing again the same day adds nothing
+        assert!(deck.introduce_new(&catalog, 0, 3).is_empty());
+
+        deck.card_mut(order[0]).unwrap().record(true, 0);
+        deck.card_mut(order[1]).unwrap().record(true, 0);
+        deck.card_mut(order[2]).unwrap().record(false, 0);
+        assert!(deck.due(0).is_empty());
+
+        // Day 1: everything answered on day 0 comes back, plus new stars
+        assert_eq!(deck.due(1).len(), 3);
+        assert_eq!(deck.introduce_new(&catalog, 1, 2), order[3..5]);
+        assert_eq!(deck.due(1).len(), 5);
+
+        for star in deck.due(1) {
+            deck.card_mut(star).unwrap().record(true, 1);
+        }
+        // Second correct answers wait three days; first ones, one day
+        let mut due = deck.due(2);
+        due.sort_by_key(|s| s.0);
+        let mut expected = order[2..5].to_vec();
+        expected.sort_by_key(|s| s.0);
+        assert_eq!(due, expected);
+        assert_eq!(deck.next_due(), Some(2));
+    }
+
+    #[test]
+    fn test_missed_stars_come_first() {
+        let mut deck = ReviewDeck::default();
+        for id in 1..=3 {
+            deck.cards.push(ReviewCard::new(StarId(id), 0));
+        }
+        deck.card_mut(StarId(3)).unwrap().record(false, 0);
+        deck.card_mut(StarId(3)).unwrap().due = 0;
+        assert_eq!(deck.due(0), [StarId(3), StarId(1), StarId(2)]);
+    }
+
+    #[test]
+    fn test_guesses_feed_the_deck() {
+        let catalog = generate_placeholder_catalog();
+        let order = introduction_order(&catalog);
+        let (first, second) = (order[0], order[1]);
+        let day = 20_000;
+        let guess = |star: &Star, was_correct| GuessSummary {
+            star_name: star.name.as_deref().unwrap().into(),
+            user_answer: "".into(),
+            was_correct,
+            timestamp: day as f64 * 86_400_000.0 + 3_600_000.0,
+        };
+
+        let mut deck = ReviewDeck::default();
+        let guesses = [
+            guess(first, true),
+            guess(second, false),
+            GuessSummary {
+                star_name: crate::game::NONE_OF_ABOVE.into(),
+                ..guess(first, true)
+            },
+        ];
+        assert_eq!(deck.record_guesses(&catalog, &guesses), 2);
+
+        let card = deck.card_mut(first.id).unwrap();
+        assert_eq!((card.introduced, card.due, card.streak), (day, day + 1, 1));
+        let card = deck.card_mut(second.id).unwrap();
+        assert_eq!((card.due, card.lapses), (day + 1, 1));
+        assert_eq!(deck.due(day + 1), [second.id, first.id]);
+    }
+
+    #[test]
+    fn test_missed_stars() {
+        let catalog = generate_placeholder_catalog();
+        let order = introduction_order(&catalog);
+        let guess = |star: &Star, was_correct| GuessSummary {
+            star_name: star.name.as_deref().unwrap().into(),
+            user_answer: "".into(),
+            was_correct,
+            timestamp: 0.0,
+        };
+        let guesses = [
+            guess(order[0], true),
+            guess(order[2], false),
+            guess(order[1], false),
+            guess(order[2], false),
+            GuessSummary {
+                star_name: "Big Dipper".into(),
+                ..guess(order[0], false)
+            },
+        ];
+        assert_eq!(missed_stars(&catalog, &guesses), [order[2].id, order[1].id]);
+    }
+
+    #[test]
+    fn test_deck_json_round_trip() {
+        let catalog = generate_placeholder_catalog();
+        let mut deck = ReviewDeck::default();
+        deck.introduce_new(&catalog, 100, 4);
+        deck.cards[0].record(true, 100);
+
+        let json = serde_json::to_string(&deck).unwrap();
+        assert_eq!(serde_json::from_str::<ReviewDeck>(&json).unwrap(), deck);
+    }
+}
@@ -0,0 +1,69 @@
+//! Points
+//!
+//! A right answer scores [`BASE_POINTS`], multiplied for the difficulty
+//! being played and for the streak it extends, less [`HINT_PENALTY`] for
+//! each hint taken. A wrong answer scores nothing and ends the streak.
+
+use super::quiz::Difficulty;
+
+/// Points for a right answer before multipliers
+pub const BASE_POINTS: u32 = 100;
+
+/// Points taken off a right answer per hint
+pub const HINT_PENALTY: u32 = 40;
+
+/// Hints a question gives, a letter of the answer each
+pub const MAX_HINTS: u32 = 2;
+
+/// Right answers in a row for each half-step up of the streak multiplier
+pub const STREAK_STEP: u32 = 5;
+
+/// The streak multiplier at its highest
+pub const MAX_STREAK_MULTIPLIER: f64 = 3.0;
+
+/// How much more a right answer is worth at a difficulty; all named
+/// stars count as hard
+pub fn difficulty_multiplier(difficulty: Option<Difficulty>) -> f64 {
+    match difficulty {
+        Some(Difficulty::Easy) => 1.0,
+        Some(Difficulty::Medium) => 1.5,
+        Some(Difficulty::Hard) | None => 2.0,
+    }
+}
+
+/// How much more a right answer is worth at a streak, counting the
+/// answer itself: half as much again for every [`STREAK_STEP`] in a row
+pub fn streak_multiplier(streak: u32) -> f64 {
+    (1.0 + 0.5 * f64::from(streak / STREAK_STEP)).min(MAX_STREAK_MULTIPLIER)
+}
+
+/// Points for a right answer
+pub fn points(difficulty: Option<Difficulty>, streak: u32, hints: u32) -> u32 {
+    let earned =
+        f64::from(BASE_POINTS) * difficulty_multiplier(difficulty) * streak_multiplier(streak);
+    (earned.round() as u32).saturating_sub(hints * HINT_PENALTY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_streak_multiplier() {
+        assert_eq!(streak_multiplier(1), 1.0);
+        assert_eq!(streak_multiplier(4), 1.0);
+        assert_eq!(streak_multiplier(5), 1.5);
+        assert_eq!(streak_multiplier(12), 2.0);
+        assert_eq!(streak_multiplier(100), MAX_STREAK_MULTIPLIER);
+    }
+
+    #[test]
+    fn test_points() {
+        assert_eq!(points(Some(Difficulty::Easy), 1, 0), 100);
+        assert_eq!(points(Some(Difficulty::Medium), 5, 0), 225);
+        assert_eq!(points(None, 10, 0), 400);
+        assert_eq!(points(Some(Difficulty::Easy), 1, 1), 60);
+        // Hints can take a right answer down to nothing, but no further
+        assert_eq!(points(Some(Difficulty::Easy), 1, 3), 0);
+    }
+}
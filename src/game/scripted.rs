@@ -0,0 +1,186 @@
+//! Scripted quiz sessions
+//!
+//! Plays a question set against pre-recorded answers, one per question,
+//! for automated evaluation. Answers are 1-based choice numbers or choice
+//! text; anything missing or unrecognized counts as incorrect.
+
+use super::events::{self, SessionEndedDetail};
+use super::names::intern;
+use super::quiz::QuizQuestion;
+use super::state::{GuessSummary, ScoreState};
+use crate::data::StarCatalog;
+use crate::utils::dates::unix_millis_now;
+use serde::Serialize;
+
+/// Outcome of one scripted question
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScriptedAnswer {
+    /// 1-based question number
+    pub question: usize,
+    pub target_id: u32,
+    pub target_name: String,
+    pub choices: Vec<String>,
+    /// The choice the answer resolved to, if it resolved at all
+    pub given: Option<String>,
+    pub correct_answer: String,
+    pub correct: bool,
+    /// Why an answer counted as incorrect without being a wrong choice
+    pub note: Option<String>,
+}
+
+/// Results of a scripted session
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ScriptedReport {
+    pub answers: Vec<ScriptedAnswer>,
+    pub score: ScoreState,
+    /// Percentage of correct answers
+    pub accuracy: f64,
+}
+
+impl ScriptedReport {
+    /// The summary the app would report for a session with these answers
+    pub fn session_summary(&self) -> SessionEndedDetail {
+        let guesses: Vec<GuessSummary> = self
+            .answers
+            .iter()
+            .map(|answer| GuessSummary {
+                star_name: intern(&answer.correct_answer),
+                user_answer: intern(answer.given.as_deref().unwrap_or_default()),
+                was_correct: answer.correct,
+                timestamp: unix_millis_now(),
+            })
+            .collect();
+        events::session_ended(&guesses, &self.score)
+    }
+}
+
+/// Resolve an answer line to one of the question's choices
+///
+/// Accepts a 1-based choice number or the choice text (case-insensitive).
+pub fn resolve_answer(question: &QuizQuestion, line: Option<&str>) -> Result<String, String> {
+    let line = line.map(str::trim).unwrap_or_default();
+    if line.is_empty() {
+        return Err("missing answer".to_string());
+    }
+
+    if let Ok(number) = line.parse::<usize>() {
+        return number
+            .checked_sub(1)
+            .and_then(|i| question.choices.get(i))
+            .map(|c| c.to_string())
+            .ok_or_else(|| {
+                format!(
+                    "choice {} out of range 1..={}",
+                    number,
+                    question.choices.len()
+                )
+            });
+    }
+
+    question
+        .choices
+        .iter()
+        .find(|c| c.eq_ignore_ascii_case(line))
+        .map(|c| c.to_string())
+        .ok_or_else(|| format!("'{}' is not one of the choices", line))
+}
+
+/// Play `questions` against `answers`, one answer line per question
+pub fn run_scripted<S: AsRef<str>>(
+    catalog: &StarCatalog,
+    questions: &[QuizQuestion],
+    answers: &[S],
+) -> ScriptedReport {
+    let mut report = ScriptedReport::default();
+
+    for (index, question) in questions.iter().enumerate() {
+        let resolved = resolve_answer(question, answers.get(index).map(AsRef::as_ref));
+        let correct = resolved.as_deref() == Ok(&*question.correct_answer);
+        if correct {
+            report.score.record_correct();
+        } else {
+            report.score.record_incorrect();
+        }
+
+        let (given, note) = match resolved {
+            Ok(choice) => (Some(choice), None),
+            Err(reason) => (None, Some(reason)),
+        };
+        report.answers.push(ScriptedAnswer {
+            question: index + 1,
+            target_id: question.target_star.0,
+            target_name: catalog
+                .get(question.target_star)
+                .map(|s| s.display_name())
+                .unwrap_or_default(),
+            choices: question.choices.iter().map(|c| c.to_string()).collect(),
+            given,
+            correct_answer: question.correct_answer.to_string(),
+            correct,
+            note,
+        });
+    }
+
+    report.accuracy = report.score.accuracy();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::StarId;
+    use crate::game::quiz::QuestionKind;
+
+    fn question() -> QuizQuestion {
+        QuizQuestion {
+            target_star: StarId(1),
+            correct_answer: "Vega".into(),
+            choices: vec!["Deneb".into(), "Vega".into(), "Altair".into()],
+            is_none_question: false,
+            question_kind: QuestionKind::Name,
+            second_star: None,
+        }
+    }
+
+    #[test]
+    fn test_resolve_by_number_and_name() {
+        let q = question();
+        assert_eq!(resolve_answer(&q, Some("2")), Ok("Vega".to_string()));
+        assert_eq!(
+            resolve_answer(&q, Some(" altair ")),
+            Ok("Altair".to_string())
+        );
+    }
+
+    #[test]
+    fn test_resolve_rejects_bad_lines() {
+        let q = question();
+        assert!(resolve_answer(&q, None).unwrap_err().contains("missing"));
+        assert!(resolve_answer(&q, Some(""))
+            .unwrap_err()
+            .contains("missing"));
+        assert!(resolve_answer(&q, Some("0"))
+            .unwrap_err()
+            .contains("out of range"));
+        assert!(resolve_answer(&q, Some("4"))
+            .unwrap_err()
+            .contains("out of range"));
+        assert!(resolve_answer(&q, Some("Sirius"))
+            .unwrap_err()
+            .contains("not one of"));
+    }
+
+    #[test]
+    fn test_run_scripted_totals() {
+        let catalog = crate::data::generate_placeholder_catalog();
+        let questions = vec![question(); 4];
+        let report = run_scripted(&catalog, &questions, &["2", "vega", "1"]);
+
+        assert_eq!(report.score.correct, 2);
+        assert_eq!(report.score.incorrect, 2);
+        assert_eq!(report.score.best_streak, 2);
+        assert_eq!(report.accuracy, 50.0);
+        assert_eq!(report.answers[2].given.as_deref(), Some("Deneb"));
+        assert_eq!(report.answers[3].note.as_deref(), Some("missing answer"));
+    }
+}
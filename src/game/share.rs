@@ -0,0 +1,79 @@
+//! Shareable results
+//!
+//! Plain-text summaries to paste into a chat, Wordle style: a score line
+//! and a grid with a mark per answer, [`GRID_WIDTH`] to a row. Used for the
+//! session summary, the daily challenge and the `sessionEnded` event.
+
+use super::state::{GuessSummary, ScoreState};
+
+/// Marks per row of the grid
+pub const GRID_WIDTH: usize = 5;
+
+/// The mark for a right or wrong answer
+pub fn mark(correct: bool) -> char {
+    if correct {
+        '✅'
+    } else {
+        '❌'
+    }
+}
+
+/// One mark per result, in rows of [`GRID_WIDTH`]
+pub fn grid(results: &[bool]) -> String {
+    results
+        .chunks(GRID_WIDTH)
+        .map(|row| row.iter().map(|&correct| mark(correct)).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A session's score line, its grid and the best streak
+pub fn session_text(guesses: &[GuessSummary], score: &ScoreState) -> String {
+    let results: Vec<bool> = guesses.iter().map(|g| g.was_correct).collect();
+    format!(
+        "Stargazer {}/{} ({:.0}%)\n{}\nBest streak: {}",
+        score.correct,
+        score.correct + score.incorrect,
+        score.accuracy(),
+        grid(&results),
+        score.best_streak
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_wraps_rows() {
+        assert_eq!(grid(&[]), "");
+        assert_eq!(grid(&[true, false]), "✅❌");
+        let results = [true, true, false, true, true, false, true];
+        assert_eq!(grid(&results), "✅✅❌✅✅\n❌✅");
+    }
+
+    #[test]
+    fn test_session_text() {
+        let mut score = ScoreState::default();
+        let guesses: Vec<_> = [true, true, true, false, true, true]
+            .into_iter()
+            .map(|was_correct| {
+                if was_correct {
+                    score.record_correct();
+                } else {
+                    score.record_incorrect();
+                }
+                GuessSummary {
+                    star_name: "Vega".into(),
+                    user_answer: "Vega".into(),
+                    was_correct,
+                    timestamp: 0.0,
+                }
+            })
+            .collect();
+        assert_eq!(
+            session_text(&guesses, &score),
+            "Stargazer 5/6 (83%)\n✅✅✅❌✅\n✅\nBest streak: 3"
+        );
+    }
+}
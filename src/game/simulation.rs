@@ -0,0 +1,283 @@
+//! Quiz balance simulation
+//!
+//! Generates questions in bulk without playing them and gathers statistics
+//! for tuning difficulty presets: which stars get asked, which show up as
+//! distractors, how far distractors sit from their targets, and whether any
+//! question breaks the basic invariants.
+
+use super::names::NONE_OF_ABOVE;
+use super::quiz::{QuizGenerator, QuizQuestion};
+use crate::data::nearest::angular_separation;
+use crate::data::{Star, StarCatalog};
+use rand::Rng;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+/// A question that breaks one of the generator's invariants
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InvariantViolation {
+    /// Index of the question within the simulated run
+    pub question: usize,
+    pub message: String,
+}
+
+/// How often one star was used
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct StarUsage {
+    pub as_target: usize,
+    pub as_distractor: usize,
+}
+
+/// Statistics gathered over a simulated run
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SimulationReport {
+    pub questions: usize,
+    /// Target counts per whole-magnitude bucket, keyed by the bucket's lower bound
+    pub target_magnitudes: BTreeMap<i32, usize>,
+    /// Usage per star name
+    pub stars: BTreeMap<String, StarUsage>,
+    pub none_of_above_fraction: f64,
+    /// Mean angular separation between distractors and their target, in degrees
+    pub mean_distractor_separation: f64,
+    pub violations: Vec<InvariantViolation>,
+}
+
+/// Check a question against the generator's invariants
+///
+/// Every question must have distinct choices and include its correct
+/// answer; "none of above" questions must not offer the target's name.
+pub fn check_question(question: &QuizQuestion, target: Option<&Star>) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    let mut seen = HashSet::new();
+    for choice in &question.choices {
+        if !seen.insert(&**choice) {
+            problems.push(format!("duplicate choice '{}'", choice));
+        }
+    }
+
+    if !question.choices.contains(&question.correct_answer) {
+        problems.push(format!(
+            "correct answer '{}' missing from choices",
+            question.correct_answer
+        ));
+    }
+
+    match target {
+        None => problems.push(format!("unknown target {:?}", question.target_star)),
+        Some(star) if question.is_none_question => {
+            if let Some(name) = &star.name {
+                if question.choices.iter().any(|c| **c == **name) {
+                    problems.push(format!("none-of-above question offers target '{}'", name));
+                }
+            }
+        }
+        Some(star) => {
+            if star.name.as_deref() != Some(&*question.correct_answer) {
+                problems.push(format!(
+                    "correct answer '{}' is not the target's name",
+                    question.correct_answer
+                ));
+            }
+        }
+    }
+
+    problems
+}
+
+/// Generate `count` questions about stars within a magnitude range and report on them
+pub fn simulate<R: Rng>(
+    catalog: &StarCatalog,
+    generator: &QuizGenerator,
+    magnitude_range: (f64, f64),
+    count: usize,
+    rng: &mut R,
+) -> SimulationReport {
+    let (min_mag, max_mag) = magnitude_range;
+    let questions = generator.generate_batch_for_magnitude_range(min_mag, max_mag, count, rng);
+    let by_name: HashMap<&str, &Star> = catalog
+        .named_stars()
+        .into_iter()
+        .filter_map(|s| Some((s.name.as_deref()?, s)))
+        .collect();
+
+    let mut report = SimulationReport {
+        questions: questions.len(),
+        ..Default::default()
+    };
+    let mut none_questions = 0;
+    let mut separation_sum = 0.0;
+    let mut separation_count = 0;
+
+    for (index, question) in questions.iter().enumerate() {
+        let target = catalog.get(question.target_star);
+        report
+            .violations
+            .extend(check_question(question, target).into_iter().map(|message| {
+                InvariantViolation {
+                    question: index,
+                    message,
+                }
+            }));
+
+        let Some(target) = target else {
+            continue;
+        };
+        *report
+            .target_magnitudes
+            .entry(target.magnitude.floor() as i32)
+            .or_default() += 1;
+        if let Some(name) = &target.name {
+            report.stars.entry(name.clone()).or_default().as_target += 1;
+        }
+        if question.is_none_question {
+            none_questions += 1;
+        }
+
+        for choice in &question.choices {
+            if &**choice == NONE_OF_ABOVE || target.name.as_deref() == Some(&**choice) {
+                continue;
+            }
+            report
+                .stars
+                .entry(choice.to_string())
+                .or_default()
+                .as_distractor += 1;
+            if let Some(star) = by_name.get(&**choice) {
+                separation_sum += angular_separation(&target.coord, &star.coord);
+                separation_count += 1;
+            }
+        }
+    }
+
+    if report.questions > 0 {
+        report.none_of_above_fraction = none_questions as f64 / report.questions as f64;
+    }
+    if separation_count > 0 {
+        report.mean_distractor_separation = separation_sum / separation_count as f64;
+    }
+    report
+}
+
+impl fmt::Display for SimulationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Questions:                {}", self.questions)?;
+        writeln!(
+            f,
+            "None of above:            {:.1}%",
+            self.none_of_above_fraction * 100.0
+        )?;
+        writeln!(
+            f,
+            "Mean distractor distance: {:.1}°",
+            self.mean_distractor_separation
+        )?;
+
+        writeln!(f, "\nTarget magnitudes:")?;
+        for (bucket, count) in &self.target_magnitudes {
+            writeln!(f, "  {:>3} to {:>3}  {:>6}", bucket, bucket + 1, count)?;
+        }
+
+        writeln!(f, "\n{:<20} {:>8} {:>11}", "Star", "Target", "Distractor")?;
+        writeln!(f, "{}", "-".repeat(41))?;
+        let mut stars: Vec<_> = self.stars.iter().collect();
+        stars.sort_by(|a, b| {
+            b.1.as_target
+                .cmp(&a.1.as_target)
+                .then_with(|| b.1.as_distractor.cmp(&a.1.as_distractor))
+                .then_with(|| a.0.cmp(b.0))
+        });
+        for (name, usage) in stars {
+            writeln!(
+                f,
+                "{:<20} {:>8} {:>11}",
+                name, usage.as_target, usage.as_distractor
+            )?;
+        }
+
+        writeln!(f, "\nInvariant violations: {}", self.violations.len())?;
+        for violation in &self.violations {
+            writeln!(
+                f,
+                "  question {}: {}",
+                violation.question, violation.message
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::generate_placeholder_catalog;
+    use crate::game::quiz::QuestionKind;
+    use crate::game::{Difficulty, QuizConfig};
+    use crate::utils::SkyIndex;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_check_question_flags_duplicates_and_missing_answer() {
+        let catalog = generate_placeholder_catalog();
+        let target = catalog.named_stars()[0];
+        let question = QuizQuestion {
+            target_star: target.id,
+            correct_answer: target.name.as_deref().unwrap().into(),
+            choices: vec!["Vega".into(), "Vega".into()],
+            is_none_question: false,
+            question_kind: QuestionKind::Name,
+            second_star: None,
+        };
+
+        let problems = check_question(&question, Some(target));
+        assert!(problems.iter().any(|p| p.contains("duplicate")));
+        assert!(problems.iter().any(|p| p.contains("missing")));
+    }
+
+    #[test]
+    fn test_simulation_has_no_violations() {
+        let catalog = generate_placeholder_catalog();
+        let index = SkyIndex::new(&catalog);
+        let config = QuizConfig {
+            none_probability: 0.3,
+            ..QuizConfig::default()
+        };
+        let generator = QuizGenerator::with_index(&catalog, config, &index, 4.0);
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(11);
+
+        let report = simulate(
+            &catalog,
+            &generator,
+            Difficulty::Hard.magnitude_range(),
+            500,
+            &mut rng,
+        );
+
+        assert_eq!(report.questions, 500);
+        assert!(report.violations.is_empty(), "{:?}", report.violations);
+        assert_eq!(report.target_magnitudes.values().sum::<usize>(), 500);
+        assert_eq!(
+            report.stars.values().map(|u| u.as_target).sum::<usize>(),
+            500
+        );
+        assert!(report.none_of_above_fraction > 0.0 && report.none_of_above_fraction < 1.0);
+        assert!(report.mean_distractor_separation > 0.0);
+    }
+
+    #[test]
+    fn test_simulation_respects_magnitude_range() {
+        let catalog = generate_placeholder_catalog();
+        let generator = QuizGenerator::new(&catalog, QuizConfig::default());
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(11);
+
+        let report = simulate(
+            &catalog,
+            &generator,
+            Difficulty::Easy.magnitude_range(),
+            100,
+            &mut rng,
+        );
+        assert!(report.target_magnitudes.keys().all(|&bucket| bucket < 2));
+    }
+}
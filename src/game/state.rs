@@ -3,12 +3,33 @@
 //! Uses a reducer pattern for predictable state updates,
 //! compatible with Yew's use_reducer hook.
 
+use crate::data::star_names::Lang;
 use crate::data::StarId;
-use crate::utils::Viewport;
+use crate::game::achievements::{Achievement, Achievements};
+use crate::game::daily::DailyChallenge;
+use crate::game::matching::{match_name, NameMatch};
+use crate::game::modes::{GameMode, MistakesRun, RoundRun, SurvivalRun, TimeAttackRun};
+use crate::game::names::Name;
+use crate::game::preferences::{clamp_star_scale, AnswerMode, MapRenderer, Preferences};
+use crate::game::quiz::{Difficulty, QuestionKind, QuizConfig, QuizQuestion};
+use crate::game::scoring::{points, MAX_HINTS};
+use crate::game::stats::StarStats;
+use crate::game::tour::TourRun;
+use crate::utils::dates::unix_millis_now;
+use crate::utils::{
+    LabelDensity, LocalSky, Locale, Observer, ProjectionKind, ScreenCoord, Viewport, MAX_ZOOM,
+    MIN_ZOOM,
+};
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
 /// The complete game state
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Serializes in full, so a session can be saved and restored or kept
+/// as a test snapshot; fields missing from a snapshot take their
+/// defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct GameState {
     /// Current viewport configuration
     pub viewport: Viewport,
@@ -22,6 +43,42 @@ pub struct GameState {
     /// Whether to show constellation lines
     pub show_constellations: bool,
 
+    /// Whether to show the Milky Way band
+    pub show_milky_way: bool,
+
+    /// Whether to show the Messier objects
+    pub show_deep_sky: bool,
+
+    /// Whether to draw the asterisms
+    pub show_asterisms: bool,
+
+    /// Whether the map shows the whole sky or what is up right now
+    pub view_mode: ViewMode,
+
+    /// The moment the local sky is shown for, in Unix seconds; `None`
+    /// follows the clock
+    pub observation_time: Option<f64>,
+
+    /// Restricts which stars start a quiz, if set
+    pub difficulty: Option<Difficulty>,
+
+    /// How questions are asked: number of choices and "none of above" chance
+    pub quiz_config: QuizConfig,
+
+    /// Whether the session opens on the daily challenge
+    pub daily_challenge: bool,
+
+    /// The daily challenge being played, kept after the last answer for
+    /// its results
+    pub challenge: Option<DailyChallenge>,
+
+    /// Free play or the run being played, kept after a run ends for its
+    /// results
+    pub mode: GameMode,
+
+    /// Seed for quiz questions, so a shared link asks the same ones
+    pub seed: Option<u64>,
+
     /// Current quiz state (if a quiz is active)
     pub quiz: Option<QuizState>,
 
@@ -36,33 +93,159 @@ pub struct GameState {
 
     /// UI state
     pub ui: UiState,
+
+    /// Persisted user preferences
+    pub preferences: Preferences,
+
+    /// Persisted answers per star, across sessions
+    pub stats: Rc<StarStats>,
+
+    /// Persisted achievements
+    pub achievements: Achievements,
+}
+
+/// What the map shows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ViewMode {
+    /// The whole celestial sphere
+    #[default]
+    Celestial,
+    /// The sky over the observer right now: stars below the horizon are
+    /// dimmed, or hidden, and cannot be quizzed
+    LocalSky,
+}
+
+impl ViewMode {
+    /// The sky `observer` sees at a Unix time in seconds, if this mode
+    /// shows it and the observer is known
+    pub fn local_sky(self, observer: Option<Observer>, unix_seconds: f64) -> Option<LocalSky> {
+        match self {
+            ViewMode::Celestial => None,
+            ViewMode::LocalSky => Some(LocalSky::at(observer?, unix_seconds)),
+        }
+    }
+}
+
+/// What a question asks about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuizTarget {
+    /// A star of the catalog
+    Star(StarId),
+    /// A Messier object, by number
+    DeepSky(u8),
+    /// The asterism a star of the catalog is part of
+    Asterism(StarId),
+    /// The constellation a star of the catalog is in
+    Constellation(StarId),
+    /// Which of two stars of the catalog is brighter
+    Brighter(StarId, StarId),
+}
+
+impl QuizTarget {
+    /// What a generated question asks about
+    pub fn of(question: &QuizQuestion) -> Self {
+        let star = question.target_star;
+        match question.question_kind {
+            QuestionKind::Name => QuizTarget::Star(star),
+            QuestionKind::Asterism => QuizTarget::Asterism(star),
+            QuestionKind::Constellation => QuizTarget::Constellation(star),
+            QuestionKind::Brighter => {
+                QuizTarget::Brighter(star, question.second_star.unwrap_or(star))
+            }
+        }
+    }
 }
 
 /// State for an active quiz question
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct QuizState {
-    /// The star being quizzed
-    pub target_star_id: StarId,
+    /// The star or deep-sky object being quizzed
+    pub target: QuizTarget,
 
     /// The correct answer
-    pub correct_name: String,
+    pub correct_name: Name,
 
     /// All choices (including correct answer)
-    pub choices: Vec<String>,
+    pub choices: Vec<Name>,
 
     /// User's selected answer (if any)
-    pub selected_answer: Option<String>,
+    pub selected_answer: Option<Name>,
 
     /// Whether the answer has been submitted
     pub answered: bool,
 
     /// Whether the answer was correct
     pub was_correct: Option<bool>,
+
+    /// When the question opened, in Unix milliseconds, for answer timing
+    pub started_at: f64,
+
+    /// Hints taken, each showing one more letter of the answer
+    pub hints: u32,
+}
+
+impl QuizState {
+    /// The star being quizzed, unless the question is about a deep-sky
+    /// object or asks which group a star belongs to
+    pub fn target_star(&self) -> Option<StarId> {
+        match self.target {
+            QuizTarget::Star(id) => Some(id),
+            QuizTarget::DeepSky(_)
+            | QuizTarget::Asterism(_)
+            | QuizTarget::Constellation(_)
+            | QuizTarget::Brighter(..) => None,
+        }
+    }
+
+    /// The second star ringed on the map, in a question about two stars
+    pub fn compared_star(&self) -> Option<StarId> {
+        match self.target {
+            QuizTarget::Brighter(_, other) => Some(other),
+            _ => None,
+        }
+    }
+
+    /// The start of the answer the hints taken show, such as "Si…"
+    pub fn hint(&self) -> Option<String> {
+        let letters: String = self
+            .correct_name
+            .chars()
+            .take(self.hints as usize)
+            .collect();
+        (self.hints > 0).then(|| format!("{}…", letters))
+    }
+
+    /// Whether another hint can be taken: the question is open, has
+    /// hints left and would not give the whole answer away
+    pub fn can_hint(&self) -> bool {
+        !self.answered
+            && self.hints < MAX_HINTS
+            && (self.hints as usize + 1) < self.correct_name.chars().count()
+    }
+
+    /// Milliseconds since the question opened, at `now_ms`
+    pub fn elapsed_ms(&self, now_ms: f64) -> f64 {
+        (now_ms - self.started_at).max(0.0)
+    }
+
+    /// Whether the answer was accepted despite being misspelled
+    pub fn misspelled(&self) -> bool {
+        self.was_correct == Some(true)
+            && self
+                .selected_answer
+                .as_ref()
+                .is_some_and(|answer| match_name(answer, &self.correct_name) == NameMatch::Close)
+    }
 }
 
 /// Score tracking
-#[derive(Debug, Clone, Default, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ScoreState {
+    /// Points from right answers, the headline score
+    pub points: u32,
+
     /// Number of correct answers
     pub correct: u32,
 
@@ -77,16 +260,19 @@ pub struct ScoreState {
 }
 
 /// Summary of a guess
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GuessSummary {
     /// Star that was quizzed
-    pub star_name: String,
+    pub star_name: Name,
 
     /// User's answer
-    pub user_answer: String,
+    pub user_answer: Name,
 
     /// Whether correct
     pub was_correct: bool,
+
+    /// When the answer was submitted, in Unix milliseconds
+    pub timestamp: f64,
 }
 
 impl ScoreState {
@@ -117,7 +303,7 @@ impl ScoreState {
 }
 
 /// UI-specific state
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct UiState {
     /// Position for dropdown menu
     pub dropdown_position: Option<(f64, f64)>,
@@ -125,6 +311,12 @@ pub struct UiState {
     /// Whether settings panel is open
     pub settings_open: bool,
 
+    /// Whether the trophy panel is open
+    pub trophies_open: bool,
+
+    /// Whether the progress map is open
+    pub progress_map_open: bool,
+
     /// Whether help overlay is shown
     pub help_shown: bool,
 
@@ -133,6 +325,19 @@ pub struct UiState {
 
     /// Whether summary popup is shown
     pub summary_shown: bool,
+
+    /// Whether the "ping" highlight for a newly quizzed star is playing
+    pub quiz_ping: bool,
+
+    /// Whether the info card for the selected star is shown: an unnamed
+    /// star's when asked for, or any star's in practice
+    pub star_info_shown: bool,
+
+    /// Whether the narrow-screen layout is active
+    pub compact_layout: bool,
+
+    /// Whether the sidebar drawer is open (compact layout only)
+    pub drawer_open: bool,
 }
 
 impl Default for GameState {
@@ -142,65 +347,311 @@ impl Default for GameState {
             magnitude_limit: 4.5,
             show_grid: true,
             show_constellations: false,
+            show_milky_way: false,
+            show_deep_sky: false,
+            show_asterisms: false,
+            view_mode: ViewMode::default(),
+            observation_time: None,
+            difficulty: None,
+            quiz_config: QuizConfig::default(),
+            daily_challenge: false,
+            challenge: None,
+            mode: GameMode::default(),
+            seed: None,
             quiz: None,
             score: ScoreState::default(),
             guess_history: Vec::new(),
             selected_star: None,
             ui: UiState::default(),
+            preferences: Preferences::default(),
+            stats: Rc::default(),
+            achievements: Achievements::default(),
+        }
+    }
+}
+
+impl GameState {
+    /// Create the initial state with previously saved preferences
+    pub fn with_preferences(preferences: Preferences) -> Self {
+        Self {
+            preferences,
+            ..Self::default()
+        }
+    }
+
+    /// The observer's sky, in local sky mode once the observer's
+    /// location is known: at the chosen observation time, or at `now` (a
+    /// Unix time in seconds) if none was chosen
+    pub fn local_sky(&self, now: f64) -> Option<LocalSky> {
+        self.view_mode.local_sky(
+            self.preferences.observer,
+            self.observation_time.unwrap_or(now),
+        )
+    }
+
+    /// Compact JSON of the whole state
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("game state always serializes")
+    }
+
+    /// Parse a state written by [`GameState::to_json`]
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("invalid game state: {}", e))
+    }
+
+    /// The labels to draw: none while a question is open, as star names
+    /// would give the answer away
+    pub fn shown_label_density(&self) -> LabelDensity {
+        if self.quiz.is_some() {
+            LabelDensity::Off
+        } else {
+            self.preferences.label_density
         }
     }
 }
 
 /// Actions that can modify the game state
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum GameAction {
     // Viewport actions
     SetZoom(f64),
     ZoomBy(f64),
+    /// Zoom by a factor, keeping the sky under a viewport point in place
+    ZoomAt(f64, f64, f64),
     Pan(f64, f64),
     SetCenter(f64, f64),
     ResetView,
     SetViewportSize(f64, f64),
+    SetProjection(ProjectionKind),
 
     // Display settings
     SetMagnitudeLimit(f64),
     ToggleGrid,
     ToggleConstellations,
+    ToggleDeepSky,
+    ToggleAsterisms,
+    SetShowGrid(bool),
+    SetShowConstellations(bool),
+    SetShowMilkyWay(bool),
+    SetViewMode(ViewMode),
+    SetObserver(Observer),
+    /// Ask the browser where the observer is; the app answers with
+    /// [`GameAction::SetObserver`]
+    LocateObserver,
+    ToggleHideBelowHorizon,
+    /// Show the local sky at a Unix time in seconds, or `None` for now
+    SetObservationTime(Option<f64>),
+    SetStarScale(f64),
+    ToggleUnnamedStarInfo,
+    SetLabelDensity(LabelDensity),
+    SetLocale(Locale),
+    SetStarNameLang(Lang),
+    ToggleSound,
+    SetRenderer(MapRenderer),
+    SetAnswerMode(AnswerMode),
+
+    // Session settings
+    SetDifficulty(Option<Difficulty>),
+    SetQuizConfig(QuizConfig),
+    SetDailyChallenge(bool),
+    SetSeed(Option<u64>),
 
     // Star selection
     SelectStar(StarId),
+    /// A click on a Messier object; the app answers with
+    /// [`GameAction::StartDeepSkyQuiz`]
+    SelectDeepSky(u8),
+    /// Show the info card for the selected star
+    ShowStarInfo,
     ClearSelection,
 
     // Quiz actions
     StartQuiz {
         target_star_id: StarId,
-        correct_name: String,
-        choices: Vec<String>,
+        correct_name: Name,
+        choices: Vec<Name>,
     },
-    SelectAnswer(String),
+    StartDeepSkyQuiz {
+        messier: u8,
+        correct_name: Name,
+        choices: Vec<Name>,
+    },
+    /// Open a generated question of any kind
+    StartQuestion(QuizQuestion),
+    SelectAnswer(Name),
     SubmitAnswer,
     /// Combined action: select and immediately evaluate answer
-    SelectAndSubmitAnswer(String),
+    SelectAndSubmitAnswer(Name),
+    /// Evaluate a typed answer, forgiving small misspellings
+    SubmitTypedAnswer(String),
+    /// Show another letter of the answer, for fewer points
+    TakeHint,
     CloseQuiz,
     NextQuestion,
 
+    // Daily challenge
+    /// Ask for today's challenge; the app answers with [`GameAction::StartChallenge`]
+    PlayDailyChallenge,
+    /// Start a challenge on its first question
+    StartChallenge(DailyChallenge),
+    /// Dismiss the challenge results
+    EndChallenge,
+
+    // Game modes
+    /// Start a survival run of [`crate::game::modes::SURVIVAL_LIVES`] lives
+    StartSurvival,
+    /// Start a time-attack run of this many seconds; the app answers with
+    /// its first question
+    StartTimeAttack(u32),
+    /// The time-attack clock has run out
+    TimeUp,
+    /// Start a round of [`crate::game::modes::ROUND_LENGTH`] questions; the
+    /// app answers with its first question
+    StartRound,
+    /// Switch between practice and free play
+    TogglePractice,
+    /// Start the tour at this index of [`crate::game::tour::TOURS`]; the
+    /// app takes the map to each stop's star
+    StartTour(usize),
+    NextTourStop,
+    PreviousTourStop,
+    /// Ask to review the session's mistakes; the app answers with
+    /// [`GameAction::StartMistakes`]
+    ReviewMistakes,
+    /// Start a review of these missed stars; the app answers with its
+    /// first question
+    StartMistakes(Vec<StarId>),
+    /// Dismiss a run's results, or give it up, back to free play
+    EndRun,
+
     // UI actions
     SetDropdownPosition(f64, f64),
     ToggleSettings,
+    ToggleTrophies,
+    ToggleProgressMap,
     ShowHelp,
     HideHelp,
     ShowToast(String),
     ClearToast,
     ShowSummary,
     HideSummary,
+    ClearQuizPing,
+    SetCompactLayout(bool),
+    ToggleDrawer,
 
     // Score
     ResetScore,
+    /// Unlock achievements the app found earned after an answer
+    UnlockAchievements(Vec<Achievement>),
+    /// Replace the statistics and achievements with imported ones
+    ImportProgress(Rc<StarStats>, Achievements),
 
     /// Force a view refresh without changing zoom
     RefreshView,
 }
 
+impl GameAction {
+    /// The action that opens a generated question: [`GameAction::StartQuiz`]
+    /// for a name question, [`GameAction::StartQuestion`] for the others
+    pub fn start(question: QuizQuestion) -> Self {
+        match question.question_kind {
+            QuestionKind::Name => GameAction::StartQuiz {
+                target_star_id: question.target_star,
+                correct_name: question.correct_answer,
+                choices: question.choices,
+            },
+            _ => GameAction::StartQuestion(question),
+        }
+    }
+}
+
+/// Record an answer to the open question in the guess history, the
+/// score and the per-star statistics
+fn record_guess(state: &mut GameState, answer: Name, correct: bool) {
+    let Some(quiz) = &state.quiz else {
+        return;
+    };
+    let timestamp = unix_millis_now();
+    let hints = quiz.hints;
+    let target_star = quiz.target_star();
+    state.guess_history.push(GuessSummary {
+        star_name: quiz.correct_name.clone(),
+        user_answer: answer,
+        was_correct: correct,
+        timestamp,
+    });
+    // Per-star statistics and the daily challenge only follow stars
+    if let Some(star) = target_star {
+        Rc::make_mut(&mut state.stats).record(star, correct, timestamp);
+        if let Some(challenge) = &mut state.challenge {
+            challenge.record(star, correct);
+        }
+    }
+
+    state.mode.record(target_star, correct, timestamp);
+
+    if correct {
+        state.score.record_correct();
+        state.score.points += points(state.difficulty, state.score.streak, hints);
+    } else {
+        state.score.record_incorrect();
+    }
+}
+
+/// Open a question about `target`
+fn start_quiz(state: &mut GameState, target: QuizTarget, correct_name: Name, choices: Vec<Name>) {
+    state.quiz = Some(QuizState {
+        target,
+        correct_name,
+        choices,
+        selected_answer: None,
+        answered: false,
+        was_correct: None,
+        started_at: unix_millis_now(),
+        hints: 0,
+    });
+    state.ui.quiz_ping = true;
+}
+
+/// Start a run of `mode`, leaving any question or challenge
+fn start_run(state: &mut GameState, mode: GameMode) {
+    state.mode = mode;
+    state.challenge = None;
+    state.quiz = None;
+    state.selected_star = None;
+    state.ui.dropdown_position = None;
+    state.ui.summary_shown = false;
+}
+
+/// Move the tour being taken, if any, to another stop
+fn move_tour(state: &mut GameState, step: fn(&mut TourRun)) {
+    if let GameMode::Tour(run) = &mut state.mode {
+        step(run);
+        // The card of a star clicked at the last stop goes with it
+        state.ui.dropdown_position = None;
+        state.ui.star_info_shown = false;
+    }
+}
+
+/// Open the challenge's next question, if one remains
+fn next_challenge_question(state: &mut GameState) -> bool {
+    let Some(question) = state
+        .challenge
+        .as_ref()
+        .and_then(DailyChallenge::next_question)
+    else {
+        return false;
+    };
+    let (target, name, choices) = (
+        question.target_star,
+        question.correct_answer.clone(),
+        question.choices.clone(),
+    );
+    state.selected_star = Some(target);
+    start_quiz(state, QuizTarget::Star(target), name, choices);
+    true
+}
+
 /// Implement the reducer pattern for GameState
 pub fn game_reducer(state: Rc<GameState>, action: GameAction) -> Rc<GameState> {
     let mut new_state: GameState = (*state).clone();
@@ -208,11 +659,16 @@ pub fn game_reducer(state: Rc<GameState>, action: GameAction) -> Rc<GameState> {
     match action {
         // Viewport actions
         GameAction::SetZoom(zoom) => {
-            new_state.viewport.zoom = zoom.clamp(1.0, 50.0);
+            new_state.viewport.zoom = zoom.clamp(MIN_ZOOM, MAX_ZOOM);
         }
         GameAction::ZoomBy(factor) => {
             new_state.viewport.zoom_by(factor, None);
         }
+        GameAction::ZoomAt(factor, x, y) => {
+            new_state
+                .viewport
+                .zoom_by(factor, Some(ScreenCoord::new(x, y)));
+        }
         GameAction::Pan(dx, dy) => {
             new_state.viewport.pan(dx, dy);
         }
@@ -224,11 +680,15 @@ pub fn game_reducer(state: Rc<GameState>, action: GameAction) -> Rc<GameState> {
             new_state.viewport = Viewport::default();
             new_state.viewport.width = state.viewport.width;
             new_state.viewport.height = state.viewport.height;
+            new_state.viewport.projection = state.viewport.projection;
         }
         GameAction::SetViewportSize(width, height) => {
             new_state.viewport.width = width;
             new_state.viewport.height = height;
         }
+        GameAction::SetProjection(projection) => {
+            new_state.viewport.projection = projection;
+        }
 
         // Display settings
         GameAction::SetMagnitudeLimit(mag) => {
@@ -240,15 +700,89 @@ pub fn game_reducer(state: Rc<GameState>, action: GameAction) -> Rc<GameState> {
         GameAction::ToggleConstellations => {
             new_state.show_constellations = !new_state.show_constellations;
         }
+        GameAction::ToggleDeepSky => {
+            new_state.show_deep_sky = !new_state.show_deep_sky;
+        }
+        GameAction::ToggleAsterisms => {
+            new_state.show_asterisms = !new_state.show_asterisms;
+        }
+        GameAction::SetShowGrid(show) => {
+            new_state.show_grid = show;
+        }
+        GameAction::SetShowConstellations(show) => {
+            new_state.show_constellations = show;
+        }
+        GameAction::SetShowMilkyWay(show) => {
+            new_state.show_milky_way = show;
+        }
+        GameAction::SetViewMode(mode) => {
+            new_state.view_mode = mode;
+        }
+        GameAction::SetObserver(observer) => {
+            new_state.preferences.observer = Some(observer);
+        }
+        GameAction::LocateObserver => {}
+        GameAction::ToggleHideBelowHorizon => {
+            new_state.preferences.hide_below_horizon = !new_state.preferences.hide_below_horizon;
+        }
+        GameAction::SetObservationTime(time) => {
+            new_state.observation_time = time.filter(|t| t.is_finite());
+        }
+        GameAction::SetStarScale(scale) => {
+            new_state.preferences.star_scale = clamp_star_scale(scale);
+        }
+        GameAction::SetLabelDensity(density) => {
+            new_state.preferences.label_density = density;
+        }
+        GameAction::SetLocale(locale) => {
+            new_state.preferences.locale = locale;
+        }
+        GameAction::SetStarNameLang(lang) => {
+            new_state.preferences.star_name_lang = lang;
+        }
+        GameAction::ToggleSound => {
+            new_state.preferences.sound_enabled = !new_state.preferences.sound_enabled;
+        }
+        GameAction::SetRenderer(renderer) => {
+            new_state.preferences.renderer = renderer;
+        }
+        GameAction::SetAnswerMode(mode) => {
+            new_state.preferences.answer_mode = mode;
+        }
+        GameAction::ToggleUnnamedStarInfo => {
+            new_state.preferences.unnamed_star_info = !new_state.preferences.unnamed_star_info;
+        }
+
+        // Session settings
+        GameAction::SetDifficulty(difficulty) => {
+            new_state.difficulty = difficulty;
+        }
+        GameAction::SetQuizConfig(config) => {
+            new_state.quiz_config = config.clamped();
+        }
+        GameAction::SetDailyChallenge(daily) => {
+            new_state.daily_challenge = daily;
+        }
+        GameAction::SetSeed(seed) => {
+            new_state.seed = seed;
+        }
 
         // Star selection
         GameAction::SelectStar(id) => {
             new_state.selected_star = Some(id);
+            // Practice describes every star it selects
+            new_state.ui.star_info_shown = !new_state.mode.asks_questions();
+        }
+        GameAction::SelectDeepSky(_) => {}
+        GameAction::ShowStarInfo => {
+            new_state.quiz = None;
+            new_state.ui.star_info_shown = new_state.selected_star.is_some();
         }
         GameAction::ClearSelection => {
             new_state.selected_star = None;
             new_state.quiz = None;
             new_state.ui.dropdown_position = None;
+            new_state.ui.star_info_shown = false;
         }
 
         // Quiz actions
@@ -257,14 +791,34 @@ pub fn game_reducer(state: Rc<GameState>, action: GameAction) -> Rc<GameState> {
             correct_name,
             choices,
         } => {
-            new_state.quiz = Some(QuizState {
-                target_star_id,
+            start_quiz(
+                &mut new_state,
+                QuizTarget::Star(target_star_id),
                 correct_name,
                 choices,
-                selected_answer: None,
-                answered: false,
-                was_correct: None,
-            });
+            );
+        }
+        GameAction::StartDeepSkyQuiz {
+            messier,
+            correct_name,
+            choices,
+        } => {
+            new_state.selected_star = None;
+            start_quiz(
+                &mut new_state,
+                QuizTarget::DeepSky(messier),
+                correct_name,
+                choices,
+            );
+        }
+        GameAction::StartQuestion(question) => {
+            let target = QuizTarget::of(&question);
+            start_quiz(
+                &mut new_state,
+                target,
+                question.correct_answer,
+                question.choices,
+            );
         }
         GameAction::SelectAnswer(answer) => {
             if let Some(ref mut quiz) = new_state.quiz {
@@ -276,23 +830,11 @@ pub fn game_reducer(state: Rc<GameState>, action: GameAction) -> Rc<GameState> {
         GameAction::SubmitAnswer => {
             if let Some(ref mut quiz) = new_state.quiz {
                 if !quiz.answered {
-                    if let Some(ref answer) = quiz.selected_answer {
+                    if let Some(answer) = quiz.selected_answer.clone() {
                         quiz.answered = true;
-                        let correct = answer == &quiz.correct_name;
+                        let correct = answer == quiz.correct_name;
                         quiz.was_correct = Some(correct);
-
-                        // Record the guess
-                        new_state.guess_history.push(GuessSummary {
-                            star_name: quiz.correct_name.clone(),
-                            user_answer: answer.clone(),
-                            was_correct: correct,
-                        });
-
-                        if correct {
-                            new_state.score.record_correct();
-                        } else {
-                            new_state.score.record_incorrect();
-                        }
+                        record_guess(&mut new_state, answer, correct);
                     }
                 }
             }
@@ -304,26 +846,42 @@ pub fn game_reducer(state: Rc<GameState>, action: GameAction) -> Rc<GameState> {
                     quiz.answered = true;
                     let correct = answer == quiz.correct_name;
                     quiz.was_correct = Some(correct);
-
-                    // Record the guess
-                    new_state.guess_history.push(GuessSummary {
-                        star_name: quiz.correct_name.clone(),
-                        user_answer: answer,
-                        was_correct: correct,
-                    });
-
-                    if correct {
-                        new_state.score.record_correct();
-                    } else {
-                        new_state.score.record_incorrect();
-                    }
+                    record_guess(&mut new_state, answer, correct);
+                }
+            }
+        }
+        GameAction::SubmitTypedAnswer(typed) => {
+            if let Some(ref mut quiz) = new_state.quiz {
+                let typed = typed.trim();
+                if !quiz.answered && !typed.is_empty() {
+                    let answer: Name = typed.into();
+                    quiz.selected_answer = Some(answer.clone());
+                    quiz.answered = true;
+                    let correct = match_name(typed, &quiz.correct_name).is_accepted();
+                    quiz.was_correct = Some(correct);
+                    // The guess is recorded as typed
+                    record_guess(&mut new_state, answer, correct);
                 }
             }
         }
+        GameAction::TakeHint => {
+            if let Some(quiz) = new_state.quiz.as_mut().filter(|quiz| quiz.can_hint()) {
+                quiz.hints += 1;
+            }
+        }
         GameAction::CloseQuiz => {
+            // Leaving a challenge question gives up the challenge
+            if new_state
+                .challenge
+                .as_ref()
+                .is_some_and(|c| !c.is_complete())
+            {
+                new_state.challenge = None;
+            }
             new_state.quiz = None;
             new_state.selected_star = None;
             new_state.ui.dropdown_position = None;
+            new_state.ui.star_info_shown = false;
             // Force refresh to redraw stars
             new_state.viewport.center_ra = (new_state.viewport.center_ra + 0.0001) % 24.0;
         }
@@ -333,6 +891,65 @@ pub fn game_reducer(state: Rc<GameState>, action: GameAction) -> Rc<GameState> {
             new_state.ui.dropdown_position = None;
             // Force refresh to redraw stars
             new_state.viewport.center_ra = (new_state.viewport.center_ra + 0.0001) % 24.0;
+            next_challenge_question(&mut new_state);
+        }
+
+        // Daily challenge
+        GameAction::PlayDailyChallenge => {}
+        GameAction::StartChallenge(challenge) => {
+            new_state.challenge = Some(challenge);
+            new_state.mode = GameMode::Free;
+            new_state.ui.summary_shown = false;
+            if !next_challenge_question(&mut new_state) {
+                new_state.challenge = None;
+            }
+        }
+        GameAction::EndChallenge => {
+            new_state.challenge = None;
+        }
+
+        // Game modes
+        GameAction::StartSurvival => {
+            start_run(&mut new_state, GameMode::Survival(SurvivalRun::default()));
+        }
+        GameAction::StartTimeAttack(seconds) => {
+            let run = TimeAttackRun::new(seconds, unix_millis_now());
+            start_run(&mut new_state, GameMode::TimeAttack(run));
+        }
+        GameAction::StartRound => {
+            start_run(&mut new_state, GameMode::Round(RoundRun::default()));
+        }
+        GameAction::TogglePractice => {
+            let mode = if new_state.mode == GameMode::Practice {
+                GameMode::Free
+            } else {
+                GameMode::Practice
+            };
+            start_run(&mut new_state, mode);
+            new_state.ui.star_info_shown = false;
+        }
+        GameAction::StartTour(index) => {
+            if let Some(run) = TourRun::new(index) {
+                start_run(&mut new_state, GameMode::Tour(run));
+                new_state.ui.star_info_shown = false;
+            }
+        }
+        GameAction::NextTourStop => move_tour(&mut new_state, TourRun::next),
+        GameAction::PreviousTourStop => move_tour(&mut new_state, TourRun::previous),
+        GameAction::TimeUp => {
+            if let GameMode::TimeAttack(run) = &mut new_state.mode {
+                run.time_up = true;
+                new_state.quiz = None;
+                new_state.selected_star = None;
+                new_state.ui.dropdown_position = None;
+            }
+        }
+        GameAction::ReviewMistakes => {}
+        GameAction::StartMistakes(stars) => {
+            start_run(&mut new_state, GameMode::Mistakes(MistakesRun::new(stars)));
+        }
+        GameAction::EndRun => {
+            new_state.mode = GameMode::Free;
         }
 
         // UI actions
@@ -342,6 +959,12 @@ pub fn game_reducer(state: Rc<GameState>, action: GameAction) -> Rc<GameState> {
         GameAction::ToggleSettings => {
             new_state.ui.settings_open = !new_state.ui.settings_open;
         }
+        GameAction::ToggleTrophies => {
+            new_state.ui.trophies_open = !new_state.ui.trophies_open;
+        }
+        GameAction::ToggleProgressMap => {
+            new_state.ui.progress_map_open = !new_state.ui.progress_map_open;
+        }
         GameAction::ShowHelp => {
             new_state.ui.help_shown = true;
         }
@@ -359,6 +982,16 @@ pub fn game_reducer(state: Rc<GameState>, action: GameAction) -> Rc<GameState> {
         GameAction::ResetScore => {
             new_state.score = ScoreState::default();
         }
+        GameAction::UnlockAchievements(achievements) => {
+            let timestamp = unix_millis_now();
+            for achievement in achievements {
+                new_state.achievements.unlock(achievement, timestamp);
+            }
+        }
+        GameAction::ImportProgress(stats, achievements) => {
+            new_state.stats = stats;
+            new_state.achievements = achievements;
+        }
         GameAction::ShowSummary => {
             new_state.ui.summary_shown = true;
             new_state.quiz = None;
@@ -366,6 +999,18 @@ pub fn game_reducer(state: Rc<GameState>, action: GameAction) -> Rc<GameState> {
         GameAction::HideSummary => {
             new_state.ui.summary_shown = false;
         }
+        GameAction::ClearQuizPing => {
+            new_state.ui.quiz_ping = false;
+        }
+        GameAction::SetCompactLayout(compact) => {
+            new_state.ui.compact_layout = compact;
+            if !compact {
+                new_state.ui.drawer_open = false;
+            }
+        }
+        GameAction::ToggleDrawer => {
+            new_state.ui.drawer_open = !new_state.ui.drawer_open;
+        }
 
         // Force a view refresh by slightly nudging center_ra
         GameAction::RefreshView => {
@@ -380,6 +1025,9 @@ pub fn game_reducer(state: Rc<GameState>, action: GameAction) -> Rc<GameState> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::modes::{Grade, ROUND_LENGTH, SURVIVAL_LIVES};
+    use crate::game::quiz::{MAX_CHOICES, MAX_NONE_PROBABILITY};
+    use crate::game::scoring::HINT_PENALTY;
 
     #[test]
     fn test_default_state() {
@@ -438,6 +1086,10 @@ mod tests {
             },
         );
         assert!(state.quiz.is_some());
+        assert!(state.ui.quiz_ping);
+
+        let state = game_reducer(state, GameAction::ClearQuizPing);
+        assert!(!state.ui.quiz_ping);
 
         // Select answer
         let state = game_reducer(state, GameAction::SelectAnswer("Sirius".into()));
@@ -451,6 +1103,292 @@ mod tests {
         assert!(state.quiz.as_ref().unwrap().answered);
         assert_eq!(state.quiz.as_ref().unwrap().was_correct, Some(true));
         assert_eq!(state.score.correct, 1);
+        assert_eq!(state.score.points, 200);
+    }
+
+    #[test]
+    fn test_hints_cost_points() {
+        let state = game_reducer(
+            Rc::new(GameState::default()),
+            GameAction::SetDifficulty(Some(Difficulty::Easy)),
+        );
+        let state = game_reducer(
+            state,
+            GameAction::StartQuiz {
+                target_star_id: StarId(1),
+                correct_name: "Vega".into(),
+                choices: vec!["Vega".into(), "Deneb".into()],
+            },
+        );
+        assert_eq!(state.quiz.as_ref().unwrap().hint(), None);
+
+        let state = game_reducer(state, GameAction::TakeHint);
+        let state = game_reducer(state, GameAction::TakeHint);
+        // No more than the hints a question gives
+        let state = game_reducer(state, GameAction::TakeHint);
+        let quiz = state.quiz.as_ref().unwrap();
+        assert_eq!(quiz.hints, MAX_HINTS);
+        assert_eq!(quiz.hint().as_deref(), Some("Ve…"));
+        assert!(!quiz.can_hint());
+
+        let state = game_reducer(state, GameAction::SelectAndSubmitAnswer("Vega".into()));
+        assert_eq!(state.score.points, 100 - 2 * HINT_PENALTY);
+        // Not once answered
+        let state = game_reducer(state, GameAction::TakeHint);
+        assert_eq!(state.quiz.as_ref().unwrap().hints, MAX_HINTS);
+
+        // A hint never spells out a short answer
+        let mut quiz = state.quiz.as_ref().unwrap().clone();
+        quiz.answered = false;
+        quiz.hints = 1;
+        quiz.correct_name = "Mu".into();
+        assert!(!quiz.can_hint());
+    }
+
+    #[test]
+    fn test_answers_are_recorded_in_guess_history() {
+        let start = |state| {
+            game_reducer(
+                state,
+                GameAction::StartQuiz {
+                    target_star_id: StarId(1),
+                    correct_name: "Sirius".into(),
+                    choices: vec!["Sirius".into(), "Vega".into()],
+                },
+            )
+        };
+        let state = start(Rc::new(GameState::default()));
+        let state = game_reducer(state, GameAction::SelectAnswer("Vega".into()));
+        let state = game_reducer(state, GameAction::SubmitAnswer);
+        let state = start(state);
+        let state = game_reducer(state, GameAction::SelectAndSubmitAnswer("Sirius".into()));
+        // A second submit of the same question is not recorded again
+        let state = game_reducer(state, GameAction::SelectAndSubmitAnswer("Vega".into()));
+
+        let history = &state.guess_history;
+        assert_eq!(history.len(), 2);
+        assert_eq!(&*history[0].star_name, "Sirius");
+        assert_eq!(&*history[0].user_answer, "Vega");
+        assert!(!history[0].was_correct);
+        assert_eq!(&*history[1].user_answer, "Sirius");
+        assert!(history[1].was_correct);
+        assert!(history[0].timestamp > 0.0);
+        assert!(history[1].timestamp >= history[0].timestamp);
+
+        // Both answers count towards the star's statistics
+        let record = state.stats.get(StarId(1)).unwrap();
+        assert_eq!((record.attempts, record.correct), (2, 1));
+        assert_eq!(record.last_seen, history[1].timestamp);
+    }
+
+    #[test]
+    fn test_deep_sky_quiz_scores_without_star_stats() {
+        let state = GameState {
+            selected_star: Some(StarId(1)),
+            ..Default::default()
+        };
+        let state = game_reducer(Rc::new(state), GameAction::ToggleDeepSky);
+        assert!(state.show_deep_sky);
+        // Choosing an object is left to the app
+        let state = game_reducer(state, GameAction::SelectDeepSky(42));
+        assert!(state.quiz.is_none());
+
+        let state = game_reducer(
+            state,
+            GameAction::StartDeepSkyQuiz {
+                messier: 42,
+                correct_name: "M42".into(),
+                choices: vec!["M42".into(), "M43".into(), "M78".into()],
+            },
+        );
+        let quiz = state.quiz.as_ref().unwrap();
+        assert_eq!(quiz.target, QuizTarget::DeepSky(42));
+        assert_eq!(quiz.target_star(), None);
+        assert_eq!(state.selected_star, None);
+
+        let state = game_reducer(state, GameAction::SelectAndSubmitAnswer("M42".into()));
+        assert_eq!(state.score.correct, 1);
+        assert_eq!(&*state.guess_history[0].star_name, "M42");
+        assert!(state.stats.is_empty());
+    }
+
+    #[test]
+    fn test_start_opens_each_kind_of_question() {
+        let question = |question_kind| QuizQuestion {
+            target_star: StarId(3),
+            correct_answer: "Orion".into(),
+            choices: vec!["Orion".into(), "Taurus".into()],
+            is_none_question: false,
+            question_kind,
+            second_star: Some(StarId(4)),
+        };
+        for (kind, target) in [
+            (QuestionKind::Name, QuizTarget::Star(StarId(3))),
+            (QuestionKind::Asterism, QuizTarget::Asterism(StarId(3))),
+            (
+                QuestionKind::Constellation,
+                QuizTarget::Constellation(StarId(3)),
+            ),
+            (
+                QuestionKind::Brighter,
+                QuizTarget::Brighter(StarId(3), StarId(4)),
+            ),
+        ] {
+            let state = game_reducer(
+                Rc::new(GameState::default()),
+                GameAction::start(question(kind)),
+            );
+            let quiz = state.quiz.as_ref().unwrap();
+            assert_eq!(quiz.target, target);
+            assert_eq!(
+                quiz.compared_star(),
+                (kind == QuestionKind::Brighter).then_some(StarId(4))
+            );
+        }
+    }
+
+    #[test]
+    fn test_asterism_quiz_keeps_the_star_selected() {
+        let state = game_reducer(Rc::new(GameState::default()), GameAction::ToggleAsterisms);
+        assert!(state.show_asterisms);
+        let state = game_reducer(state, GameAction::SelectStar(StarId(7)));
+        let state = game_reducer(
+            state,
+            GameAction::StartQuestion(QuizQuestion {
+                target_star: StarId(7),
+                correct_answer: "Big Dipper".into(),
+                choices: vec!["Big Dipper".into(), "Teapot".into(), "Sickle".into()],
+                is_none_question: false,
+                question_kind: QuestionKind::Asterism,
+                second_star: None,
+            }),
+        );
+        let quiz = state.quiz.as_ref().unwrap();
+        assert_eq!(quiz.target, QuizTarget::Asterism(StarId(7)));
+        assert_eq!(quiz.target_star(), None);
+        assert_eq!(state.selected_star, Some(StarId(7)));
+
+        let state = game_reducer(state, GameAction::SelectAndSubmitAnswer("Teapot".into()));
+        assert_eq!(state.score.incorrect, 1);
+        // A star's statistics follow only questions about its name
+        assert!(state.stats.is_empty());
+    }
+
+    #[test]
+    fn test_summary_closes_the_quiz() {
+        let state = game_reducer(
+            Rc::new(GameState::default()),
+            GameAction::StartQuiz {
+                target_star_id: StarId(1),
+                correct_name: "Sirius".into(),
+                choices: vec!["Sirius".into(), "Vega".into()],
+            },
+        );
+        let state = game_reducer(state, GameAction::ShowSummary);
+        assert!(state.ui.summary_shown);
+        assert!(state.quiz.is_none());
+
+        let state = game_reducer(state, GameAction::HideSummary);
+        assert!(!state.ui.summary_shown);
+    }
+
+    #[test]
+    fn test_achievements_unlock_once() {
+        let state = game_reducer(
+            Rc::new(GameState::default()),
+            GameAction::UnlockAchievements(vec![Achievement::FirstCorrect]),
+        );
+        let first = state
+            .achievements
+            .unlocked_at(Achievement::FirstCorrect)
+            .unwrap();
+        let state = game_reducer(
+            state,
+            GameAction::UnlockAchievements(vec![Achievement::FirstCorrect, Achievement::Streak]),
+        );
+        assert_eq!(
+            state.achievements.unlocked_at(Achievement::FirstCorrect),
+            Some(first)
+        );
+        assert_eq!(state.achievements.len(), 2);
+
+        let state = game_reducer(state, GameAction::ToggleTrophies);
+        assert!(state.ui.trophies_open);
+        let state = game_reducer(state, GameAction::ToggleProgressMap);
+        assert!(state.ui.progress_map_open);
+    }
+
+    #[test]
+    fn test_import_progress_replaces_what_was_learned() {
+        let state = game_reducer(
+            Rc::new(GameState::default()),
+            GameAction::UnlockAchievements(vec![Achievement::Streak]),
+        );
+        let mut stats = StarStats::default();
+        stats.record(StarId(4), true, 1000.0);
+        let mut achievements = Achievements::default();
+        achievements.unlock(Achievement::FirstCorrect, 1000.0);
+
+        let state = game_reducer(
+            state,
+            GameAction::ImportProgress(Rc::new(stats), achievements.clone()),
+        );
+        assert_eq!(state.stats.len(), 1);
+        assert_eq!(state.achievements, achievements);
+        assert!(!state.achievements.is_unlocked(Achievement::Streak));
+    }
+
+    #[test]
+    fn test_state_round_trips_through_json() {
+        let state = game_reducer(Rc::new(GameState::default()), GameAction::StartRound);
+        let state = game_reducer(
+            state,
+            GameAction::StartQuiz {
+                target_star_id: StarId(1),
+                correct_name: "Sirius".into(),
+                choices: vec!["Sirius".into(), "Vega".into()],
+            },
+        );
+        let state = game_reducer(state, GameAction::SelectAndSubmitAnswer("Vega".into()));
+        assert!(state.stats.get(StarId(1)).is_some());
+
+        // Clock readings, in the quiz, the guess and the stats, come back
+        // to the last bit
+        let restored = GameState::from_json(&state.to_json()).unwrap();
+        assert_eq!(restored, *state);
+        assert!(matches!(restored.mode, GameMode::Round(run) if run.answered == 1));
+        assert_eq!(restored.score.incorrect, 1);
+    }
+
+    #[test]
+    fn test_state_from_partial_json() {
+        let state = GameState::from_json(r#"{"magnitude_limit": 6.0}"#).unwrap();
+        assert_eq!(state.magnitude_limit, 6.0);
+        assert_eq!(state.viewport, Viewport::default());
+        assert!(GameState::from_json(r#"{"score": "high"}"#).is_err());
+    }
+
+    #[test]
+    fn test_labels_hide_while_a_question_is_open() {
+        let state = game_reducer(
+            Rc::new(GameState::default()),
+            GameAction::SetLabelDensity(LabelDensity::Dense),
+        );
+        assert_eq!(state.shown_label_density(), LabelDensity::Dense);
+
+        let state = game_reducer(
+            state,
+            GameAction::StartQuiz {
+                target_star_id: StarId(1),
+                correct_name: "Sirius".into(),
+                choices: vec!["Sirius".into(), "Vega".into()],
+            },
+        );
+        assert_eq!(state.shown_label_density(), LabelDensity::Off);
+        assert_eq!(state.preferences.label_density, LabelDensity::Dense);
+
+        let state = game_reducer(state, GameAction::CloseQuiz);
+        assert_eq!(state.shown_label_density(), LabelDensity::Dense);
     }
 
     #[test]
@@ -463,4 +1401,449 @@ mod tests {
         let state = game_reducer(state, GameAction::SetMagnitudeLimit(0.0));
         assert_eq!(state.magnitude_limit, 1.0);
     }
+
+    #[test]
+    fn test_absolute_settings() {
+        let state = Rc::new(GameState::default());
+
+        // Setting a flag twice leaves it set, unlike a toggle
+        let state = game_reducer(state, GameAction::SetShowGrid(false));
+        let state = game_reducer(state, GameAction::SetShowGrid(false));
+        assert!(!state.show_grid);
+
+        let state = game_reducer(state, GameAction::SetShowMilkyWay(true));
+        let state = game_reducer(state, GameAction::SetDifficulty(Some(Difficulty::Medium)));
+        let state = game_reducer(state, GameAction::SetSeed(Some(9)));
+        assert!(state.show_milky_way);
+        assert_eq!(state.difficulty, Some(Difficulty::Medium));
+        assert_eq!(state.seed, Some(9));
+    }
+
+    #[test]
+    fn test_quiz_config_is_kept_in_range() {
+        let state = game_reducer(
+            Rc::new(GameState::default()),
+            GameAction::SetQuizConfig(QuizConfig {
+                num_choices: 3,
+                include_none_option: false,
+                none_probability: 0.0,
+                ..QuizConfig::default()
+            }),
+        );
+        assert_eq!(state.quiz_config.num_choices, 3);
+        assert!(!state.quiz_config.include_none_option);
+
+        let state = game_reducer(
+            state,
+            GameAction::SetQuizConfig(QuizConfig {
+                num_choices: 12,
+                include_none_option: true,
+                none_probability: 0.9,
+                ..QuizConfig::default()
+            }),
+        );
+        assert_eq!(state.quiz_config.num_choices, MAX_CHOICES);
+        assert_eq!(state.quiz_config.none_probability, MAX_NONE_PROBABILITY);
+    }
+
+    #[test]
+    fn test_typed_answers_forgive_misspellings() {
+        let start = |state| {
+            game_reducer(
+                state,
+                GameAction::StartQuiz {
+                    target_star_id: StarId(1),
+                    correct_name: "Betelgeuse".into(),
+                    choices: vec!["Betelgeuse".into(), "Rigel".into()],
+                },
+            )
+        };
+        let state = start(Rc::new(GameState::default()));
+
+        // Blank input is ignored
+        let state = game_reducer(state, GameAction::SubmitTypedAnswer("  ".into()));
+        assert!(!state.quiz.as_ref().unwrap().answered);
+
+        let state = game_reducer(state, GameAction::SubmitTypedAnswer(" Betelguese ".into()));
+        let quiz = state.quiz.as_ref().unwrap();
+        assert_eq!(quiz.was_correct, Some(true));
+        assert!(quiz.misspelled());
+        assert_eq!(&*state.guess_history[0].user_answer, "Betelguese");
+
+        let state = start(state);
+        let state = game_reducer(state, GameAction::SubmitTypedAnswer("betelgeuse".into()));
+        assert!(!state.quiz.as_ref().unwrap().misspelled());
+
+        let state = start(state);
+        let state = game_reducer(state, GameAction::SubmitTypedAnswer("Bellatrix".into()));
+        assert_eq!(state.quiz.as_ref().unwrap().was_correct, Some(false));
+        assert_eq!(state.score.correct, 2);
+        assert_eq!(state.score.incorrect, 1);
+    }
+
+    #[test]
+    fn test_daily_challenge_flow() {
+        let catalog = crate::data::generate_placeholder_catalog();
+        let challenge = DailyChallenge::new(&catalog, 20_000);
+        let questions = challenge.questions.clone();
+        let state = game_reducer(
+            Rc::new(GameState::default()),
+            GameAction::StartChallenge(challenge),
+        );
+        assert_eq!(state.selected_star, Some(questions[0].target_star));
+
+        let mut state = state;
+        for (i, question) in questions.iter().enumerate() {
+            let quiz = state.quiz.as_ref().unwrap();
+            assert_eq!(quiz.target_star(), Some(question.target_star));
+            let answer = if i == 0 {
+                "nobody".into()
+            } else {
+                question.correct_answer.clone()
+            };
+            state = game_reducer(state, GameAction::SelectAndSubmitAnswer(answer));
+            state = game_reducer(state, GameAction::NextQuestion);
+        }
+
+        // The results stay until dismissed
+        assert!(state.quiz.is_none());
+        let challenge = state.challenge.as_ref().unwrap();
+        assert!(challenge.is_complete());
+        assert_eq!(challenge.correct(), questions.len() - 1);
+        assert_eq!(state.score.incorrect, 1);
+        let state = game_reducer(state, GameAction::EndChallenge);
+        assert!(state.challenge.is_none());
+
+        // Closing a question gives up the challenge
+        let challenge = DailyChallenge::new(&catalog, 20_000);
+        let state = game_reducer(state, GameAction::StartChallenge(challenge));
+        let state = game_reducer(state, GameAction::CloseQuiz);
+        assert!(state.challenge.is_none());
+    }
+
+    #[test]
+    fn test_survival_run() {
+        let ask = |state, star: u32| {
+            game_reducer(
+                state,
+                GameAction::StartQuiz {
+                    target_star_id: StarId(star),
+                    correct_name: "Vega".into(),
+                    choices: vec!["Vega".into(), "Deneb".into()],
+                },
+            )
+        };
+        let state = game_reducer(Rc::new(GameState::default()), GameAction::StartSurvival);
+        assert_eq!(state.mode.lives(), Some(SURVIVAL_LIVES));
+
+        let mut state = state;
+        for (star, answer) in [(1, "Vega"), (2, "Deneb"), (3, "Deneb"), (4, "Vega")] {
+            state = ask(state, star);
+            state = game_reducer(state, GameAction::SelectAndSubmitAnswer(answer.into()));
+            state = game_reducer(state, GameAction::NextQuestion);
+        }
+        assert_eq!(state.mode.lives(), Some(1));
+        assert!(!state.mode.is_over());
+
+        state = ask(state, 5);
+        state = game_reducer(state, GameAction::SelectAndSubmitAnswer("Deneb".into()));
+        // The results stay until dismissed
+        assert_eq!(
+            state.mode,
+            GameMode::Survival(SurvivalRun {
+                lives: 0,
+                survived: 4
+            })
+        );
+        let state = game_reducer(state, GameAction::EndRun);
+        assert_eq!(state.mode, GameMode::Free);
+
+        // The daily challenge ends a run
+        let state = game_reducer(state, GameAction::StartSurvival);
+        let challenge = DailyChallenge::new(&crate::data::generate_placeholder_catalog(), 20_000);
+        let state = game_reducer(state, GameAction::StartChallenge(challenge));
+        assert_eq!(state.mode, GameMode::Free);
+    }
+
+    #[test]
+    fn test_time_attack_run() {
+        let state = game_reducer(
+            Rc::new(GameState::default()),
+            GameAction::StartTimeAttack(60),
+        );
+        let deadline = state.mode.deadline().unwrap();
+        assert!((deadline - unix_millis_now() - 60_000.0).abs() < 5000.0);
+
+        let state = game_reducer(
+            state,
+            GameAction::StartQuiz {
+                target_star_id: StarId(1),
+                correct_name: "Vega".into(),
+                choices: vec!["Vega".into(), "Deneb".into()],
+            },
+        );
+        let state = game_reducer(state, GameAction::SelectAndSubmitAnswer("Vega".into()));
+        assert!(state.mode.advances_by_itself());
+
+        // Time running out closes the open question
+        let state = game_reducer(state, GameAction::TimeUp);
+        assert!(state.quiz.is_none());
+        assert!(state.mode.is_over());
+        let GameMode::TimeAttack(run) = state.mode else {
+            panic!("not a time-attack run")
+        };
+        assert_eq!((run.seconds, run.correct, run.answered), (60, 1, 1));
+
+        // Only a time-attack run has a clock to run out
+        let state = game_reducer(state, GameAction::StartSurvival);
+        let state = game_reducer(state, GameAction::TimeUp);
+        assert!(!state.mode.is_over());
+    }
+
+    #[test]
+    fn test_round() {
+        let mut state = game_reducer(Rc::new(GameState::default()), GameAction::StartRound);
+        for i in 0..ROUND_LENGTH {
+            assert_eq!(state.mode.progress(), Some((i as usize, 10)));
+            state = game_reducer(
+                state,
+                GameAction::StartQuiz {
+                    target_star_id: StarId(i),
+                    correct_name: "Vega".into(),
+                    choices: vec!["Vega".into(), "Deneb".into()],
+                },
+            );
+            let answer = if i < 8 { "Vega" } else { "Deneb" };
+            state = game_reducer(state, GameAction::SelectAndSubmitAnswer(answer.into()));
+            state = game_reducer(state, GameAction::NextQuestion);
+        }
+
+        // The results stay until dismissed
+        let GameMode::Round(run) = state.mode else {
+            panic!("not a round")
+        };
+        assert!(run.is_over());
+        assert_eq!(run.grade(), Grade::B);
+        assert!(!state.mode.advances_by_itself());
+        let state = game_reducer(state, GameAction::EndRun);
+        assert_eq!(state.mode, GameMode::Free);
+    }
+
+    #[test]
+    fn test_unnamed_star_info_flow() {
+        let state = Rc::new(GameState::default());
+
+        let state = game_reducer(state, GameAction::SelectStar(StarId(42)));
+        let state = game_reducer(state, GameAction::ShowStarInfo);
+        assert!(state.ui.star_info_shown);
+        assert_eq!(state.selected_star, Some(StarId(42)));
+
+        // Selecting another star hides the card until asked again
+        let state = game_reducer(state, GameAction::SelectStar(StarId(7)));
+        assert!(!state.ui.star_info_shown);
+
+        let state = game_reducer(state, GameAction::ShowStarInfo);
+        let state = game_reducer(state, GameAction::CloseQuiz);
+        assert!(!state.ui.star_info_shown);
+        assert!(state.selected_star.is_none());
+    }
+
+    #[test]
+    fn test_practice_describes_selected_stars() {
+        let state = game_reducer(Rc::new(GameState::default()), GameAction::TogglePractice);
+        assert_eq!(state.mode, GameMode::Practice);
+
+        let state = game_reducer(state, GameAction::SelectStar(StarId(7)));
+        assert!(state.ui.star_info_shown);
+        assert!(state.quiz.is_none());
+        let state = game_reducer(state, GameAction::ClearSelection);
+        assert!(!state.ui.star_info_shown);
+
+        // Back to free play, a selection waits for its question again
+        let state = game_reducer(state, GameAction::TogglePractice);
+        assert_eq!(state.mode, GameMode::Free);
+        let state = game_reducer(state, GameAction::SelectStar(StarId(7)));
+        assert!(!state.ui.star_info_shown);
+    }
+
+    #[test]
+    fn test_mistakes_review() {
+        let ask = |state, id: u32, name: &str| {
+            game_reducer(
+                state,
+                GameAction::StartQuiz {
+                    target_star_id: StarId(id),
+                    correct_name: name.into(),
+                    choices: vec!["Vega".into(), "Deneb".into()],
+                },
+            )
+        };
+        let state = ask(Rc::new(GameState::default()), 1, "Vega");
+        let state = game_reducer(state, GameAction::SelectAndSubmitAnswer("Deneb".into()));
+        let state = game_reducer(state, GameAction::ShowSummary);
+
+        let state = game_reducer(state, GameAction::StartMistakes(vec![StarId(1)]));
+        assert!(!state.ui.summary_shown);
+        assert!(state.quiz.is_none());
+        assert!(state.mode.advances_by_itself());
+
+        let state = ask(state, 1, "Vega");
+        let state = game_reducer(state, GameAction::SelectAndSubmitAnswer("Vega".into()));
+        assert!(state.mode.is_over());
+        let state = game_reducer(state, GameAction::EndRun);
+        assert_eq!(state.mode, GameMode::Free);
+    }
+
+    #[test]
+    fn test_tour() {
+        let state = game_reducer(Rc::new(GameState::default()), GameAction::StartTour(9));
+        assert_eq!(state.mode, GameMode::Free);
+
+        let state = game_reducer(state, GameAction::StartTour(1));
+        let state = game_reducer(state, GameAction::PreviousTourStop);
+        assert_eq!(state.mode.tour().unwrap().stop, 0);
+
+        // A star clicked along the way is described until the next stop
+        let state = game_reducer(state, GameAction::SetDropdownPosition(10.0, 20.0));
+        let state = game_reducer(state, GameAction::SelectStar(StarId(7)));
+        assert!(state.ui.star_info_shown);
+        let state = game_reducer(state, GameAction::NextTourStop);
+        assert_eq!(state.mode.tour().unwrap().current().star, "Rigel");
+        assert!(!state.ui.star_info_shown);
+        assert_eq!(state.ui.dropdown_position, None);
+
+        let state = game_reducer(state, GameAction::EndRun);
+        assert_eq!(state.mode.tour(), None);
+        // Outside a tour, moving between stops does nothing
+        let state = game_reducer(state, GameAction::NextTourStop);
+        assert_eq!(state.mode, GameMode::Free);
+    }
+
+    #[test]
+    fn test_star_scale_clamp() {
+        let state = Rc::new(GameState::default());
+
+        let state = game_reducer(state, GameAction::SetStarScale(2.0));
+        assert_eq!(state.preferences.star_scale, 2.0);
+
+        let state = game_reducer(state, GameAction::SetStarScale(10.0));
+        assert_eq!(state.preferences.star_scale, 3.0);
+
+        let state = game_reducer(state, GameAction::SetStarScale(0.0));
+        assert_eq!(state.preferences.star_scale, 0.5);
+    }
+
+    #[test]
+    fn test_set_locale_is_a_preference() {
+        let state = Rc::new(GameState::default());
+        let state = game_reducer(state, GameAction::SetLocale(Locale::Spanish));
+        assert_eq!(state.preferences.locale, Locale::Spanish);
+    }
+
+    #[test]
+    fn test_set_star_name_lang_is_a_preference() {
+        let state = Rc::new(GameState::default());
+        let state = game_reducer(state, GameAction::SetStarNameLang(Lang::Chinese));
+        assert_eq!(state.preferences.star_name_lang, Lang::Chinese);
+        assert_eq!(state.preferences.locale, Locale::English);
+    }
+
+    #[test]
+    fn test_local_sky_needs_mode_and_location() {
+        let state = Rc::new(GameState::default());
+        assert_eq!(state.view_mode, ViewMode::Celestial);
+        assert_eq!(state.local_sky(0.0), None);
+
+        let state = game_reducer(state, GameAction::SetViewMode(ViewMode::LocalSky));
+        assert_eq!(state.local_sky(0.0), None);
+
+        let observer = Observer::new(50.0, 8.0).unwrap();
+        let state = game_reducer(state, GameAction::SetObserver(observer));
+        assert_eq!(state.preferences.observer, Some(observer));
+        assert_eq!(state.local_sky(0.0), Some(LocalSky::at(observer, 0.0)));
+
+        // The location is kept for the next time local sky is chosen
+        let state = game_reducer(state, GameAction::SetViewMode(ViewMode::Celestial));
+        assert_eq!(state.local_sky(0.0), None);
+        assert_eq!(state.preferences.observer, Some(observer));
+    }
+
+    #[test]
+    fn test_observation_time_overrides_the_clock() {
+        let observer = Observer::new(50.0, 8.0).unwrap();
+        let state = Rc::new(GameState::default());
+        let state = game_reducer(state, GameAction::SetViewMode(ViewMode::LocalSky));
+        let state = game_reducer(state, GameAction::SetObserver(observer));
+
+        let state = game_reducer(state, GameAction::SetObservationTime(Some(3600.0)));
+        assert_eq!(state.local_sky(0.0), Some(LocalSky::at(observer, 3600.0)));
+
+        let state = game_reducer(state, GameAction::SetObservationTime(Some(f64::NAN)));
+        assert_eq!(state.observation_time, None);
+        let state = game_reducer(state, GameAction::SetObservationTime(None));
+        assert_eq!(state.local_sky(60.0), Some(LocalSky::at(observer, 60.0)));
+    }
+
+    #[test]
+    fn test_hide_below_horizon_is_a_preference() {
+        let state = Rc::new(GameState::default());
+        assert!(!state.preferences.hide_below_horizon);
+        let state = game_reducer(state, GameAction::ToggleHideBelowHorizon);
+        assert!(state.preferences.hide_below_horizon);
+        // Locating is left to the app
+        let state = game_reducer(state, GameAction::LocateObserver);
+        assert_eq!(state.preferences.observer, None);
+    }
+
+    #[test]
+    fn test_projection_survives_reset_view() {
+        let state = Rc::new(GameState::default());
+        assert_eq!(state.viewport.projection, ProjectionKind::Equirectangular);
+
+        let state = game_reducer(
+            state,
+            GameAction::SetProjection(ProjectionKind::Stereographic),
+        );
+        let state = game_reducer(state, GameAction::SetZoom(4.0));
+        let state = game_reducer(state, GameAction::ResetView);
+        assert_eq!(state.viewport.zoom, 1.0);
+        assert_eq!(state.viewport.projection, ProjectionKind::Stereographic);
+    }
+
+    #[test]
+    fn test_zoom_at_keeps_the_point_under_the_cursor() {
+        let state = Rc::new(GameState::default());
+        let cursor = ScreenCoord::new(300.0, 200.0);
+        let under = state.viewport.unproject(cursor).unwrap();
+
+        let state = game_reducer(state, GameAction::ZoomAt(1.2, cursor.x, cursor.y));
+        assert_eq!(state.viewport.zoom, 1.2);
+        let moved = state.viewport.project(&under).point;
+        assert!((moved.x - cursor.x).abs() < 1.0 && (moved.y - cursor.y).abs() < 1.0);
+
+        // The plain zoom keeps the center instead
+        let state = game_reducer(state, GameAction::ZoomBy(1.5));
+        assert!((state.viewport.project(&under).point.x - cursor.x).abs() > 10.0);
+    }
+
+    #[test]
+    fn test_sound_is_off_until_toggled() {
+        let state = Rc::new(GameState::default());
+        assert!(!state.preferences.sound_enabled);
+
+        let state = game_reducer(state, GameAction::ToggleSound);
+        assert!(state.preferences.sound_enabled);
+        let state = game_reducer(state, GameAction::ToggleSound);
+        assert!(!state.preferences.sound_enabled);
+    }
+
+    #[test]
+    fn test_renderer_survives_reset_view() {
+        let state = Rc::new(GameState::default());
+        assert_eq!(state.preferences.renderer, MapRenderer::Svg);
+
+        let state = game_reducer(state, GameAction::SetRenderer(MapRenderer::Canvas));
+        let state = game_reducer(state, GameAction::ResetView);
+        assert_eq!(state.preferences.renderer, MapRenderer::Canvas);
+    }
 }
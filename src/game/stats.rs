@@ -0,0 +1,152 @@
+//! Per-star mastery statistics
+//!
+//! [`StarStats`] counts the questions asked about each star and how many
+//! were answered right, across sessions via localStorage. Its
+//! [`mastery`](StarStats::mastery) score is what target selection and the
+//! statistics views read to tell well known stars from shaky ones.
+
+use crate::data::StarId;
+#[cfg(feature = "web")]
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// localStorage key for persisted statistics
+pub const STATS_KEY: &str = "stargazer.stats";
+
+/// Answers [`StarStats::mastery`] assumes were missed before the first,
+/// so one lucky answer does not make a star mastered
+const MASTERY_PRIOR: f64 = 1.0;
+
+/// Answers about one star
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct StarRecord {
+    /// Questions answered about the star
+    pub attempts: u32,
+
+    /// How many of them were right
+    pub correct: u32,
+
+    /// When the star was last answered, in Unix milliseconds
+    pub last_seen: f64,
+}
+
+impl StarRecord {
+    /// Share of answers that were right, from 0 to 1
+    pub fn accuracy(&self) -> f64 {
+        if self.attempts == 0 {
+            0.0
+        } else {
+            f64::from(self.correct) / f64::from(self.attempts)
+        }
+    }
+}
+
+/// Answers about every star answered so far
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StarStats {
+    stars: HashMap<StarId, StarRecord>,
+}
+
+/// A copy with room for one more star, so that recording an answer in the
+/// copy, as the reducer does, never reallocates
+impl Clone for StarStats {
+    fn clone(&self) -> Self {
+        let mut stars = HashMap::with_capacity(self.stars.len() + 1);
+        stars.extend(self.stars.iter().map(|(&id, &record)| (id, record)));
+        Self { stars }
+    }
+}
+
+impl StarStats {
+    /// Count an answer about `star` given at `timestamp` (Unix milliseconds)
+    pub fn record(&mut self, star: StarId, correct: bool, timestamp: f64) {
+        let record = self.stars.entry(star).or_default();
+        record.attempts += 1;
+        record.correct += u32::from(correct);
+        record.last_seen = record.last_seen.max(timestamp);
+    }
+
+    /// The answers about `star`, if it was ever answered
+    pub fn get(&self, star: StarId) -> Option<&StarRecord> {
+        self.stars.get(&star)
+    }
+
+    /// Every answered star and its answers, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (StarId, &StarRecord)> {
+        self.stars.iter().map(|(&id, record)| (id, record))
+    }
+
+    /// How many stars have been answered
+    pub fn len(&self) -> usize {
+        self.stars.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stars.is_empty()
+    }
+
+    /// How well `star` is known, from 0 for a star never answered right
+    /// towards 1 for one answered right many times
+    pub fn mastery(&self, star: StarId) -> f64 {
+        self.get(star).map_or(0.0, |record| {
+            f64::from(record.correct) / (f64::from(record.attempts) + MASTERY_PRIOR)
+        })
+    }
+}
+
+#[cfg(feature = "web")]
+impl StarStats {
+    /// Load statistics from localStorage, starting empty if there are none
+    pub fn load() -> Self {
+        LocalStorage::get(STATS_KEY).unwrap_or_default()
+    }
+
+    /// Save statistics to localStorage
+    pub fn save(&self) {
+        if let Err(e) = LocalStorage::set(STATS_KEY, self) {
+            log::warn!("Failed to save statistics: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mastery_grows_with_right_answers() {
+        let mut stats = StarStats::default();
+        assert_eq!(stats.mastery(StarId(1)), 0.0);
+
+        stats.record(StarId(1), true, 1000.0);
+        let once = stats.mastery(StarId(1));
+        assert!(once > 0.0 && once < 1.0);
+        for _ in 0..9 {
+            stats.record(StarId(1), true, 2000.0);
+        }
+        assert!(stats.mastery(StarId(1)) > once);
+
+        stats.record(StarId(2), true, 1000.0);
+        stats.record(StarId(2), false, 500.0);
+        let record = stats.get(StarId(2)).unwrap();
+        assert_eq!((record.attempts, record.correct), (2, 1));
+        assert_eq!(record.accuracy(), 0.5);
+        assert_eq!(record.last_seen, 1000.0);
+        assert!(stats.mastery(StarId(2)) < once);
+        assert_eq!(stats.len(), 2);
+    }
+
+    #[test]
+    fn test_stats_round_trip_through_json() {
+        let mut stats = StarStats::default();
+        stats.record(StarId(7), true, 1234.0);
+        let json = serde_json::to_string(&stats).unwrap();
+        assert_eq!(serde_json::from_str::<StarStats>(&json).unwrap(), stats);
+        assert_eq!(
+            serde_json::from_str::<StarStats>("{}").unwrap(),
+            StarStats::default()
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! Usage telemetry hooks
+//!
+//! The crate reports what happens in a session through [`Telemetry`]
+//! without knowing where the reports go. Every method does nothing by
+//! default, so an implementation only overrides what it records.
+//! [`NoTelemetry`] records nothing; [`JsonLinesTelemetry`] writes one
+//! JSON object per line:
+//!
+//! ```json
+//! { "event": "questionAnswered", "correct": true, "difficulty": "easy", "elapsedMs": 2310 }
+//! { "event": "sessionEnded", "shareText": "…", "correct": 3, "incorrect": 1, "total": 4, "accuracy": 75.0, "bestStreak": 2 }
+//! { "event": "error", "context": "catalog", "message": "HTTP 404" }
+//! ```
+//!
+//! Like the quiz events, reports are made where actions are dispatched,
+//! never from the reducer.
+
+use super::events::SessionEndedDetail;
+use super::quiz::Difficulty;
+use super::scripted::ScriptedReport;
+use serde::Serialize;
+use std::cell::RefCell;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Receives usage reports
+pub trait Telemetry {
+    /// A question was answered `elapsed_ms` after it appeared
+    fn question_answered(&self, _correct: bool, _difficulty: Option<Difficulty>, _elapsed_ms: u64) {
+    }
+
+    /// A session ended with the summary open
+    fn session_ended(&self, _summary: &SessionEndedDetail) {}
+
+    /// Something failed; `context` names the feature, such as "catalog"
+    fn error(&self, _context: &str, _message: &str) {}
+}
+
+/// Telemetry that records nothing
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoTelemetry;
+
+impl Telemetry for NoTelemetry {}
+
+/// One telemetry report, as serialized by [`JsonLinesTelemetry`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(
+    tag = "event",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum TelemetryRecord<'a> {
+    QuestionAnswered {
+        correct: bool,
+        difficulty: Option<Difficulty>,
+        elapsed_ms: u64,
+    },
+    SessionEnded(&'a SessionEndedDetail),
+    Error {
+        context: &'a str,
+        message: &'a str,
+    },
+}
+
+/// Telemetry written as JSON lines
+///
+/// Write failures are logged and otherwise ignored, so telemetry never
+/// interrupts a session.
+#[derive(Debug)]
+pub struct JsonLinesTelemetry<W: Write> {
+    out: RefCell<W>,
+}
+
+impl JsonLinesTelemetry<File> {
+    /// Append to the file at `path`, creating it if needed
+    pub fn append(path: &Path) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self::new(file))
+    }
+}
+
+impl<W: Write> JsonLinesTelemetry<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out: RefCell::new(out),
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out.into_inner()
+    }
+
+    fn record(&self, record: &TelemetryRecord) {
+        let mut out = self.out.borrow_mut();
+        let written = serde_json::to_writer(&mut *out, record)
+            .map_err(io::Error::from)
+            .and_then(|_| writeln!(out))
+            .and_then(|_| out.flush());
+        if let Err(e) = written {
+            log::warn!("Could not write telemetry: {}", e);
+        }
+    }
+}
+
+impl<W: Write> Telemetry for JsonLinesTelemetry<W> {
+    fn question_answered(&self, correct: bool, difficulty: Option<Difficulty>, elapsed_ms: u64) {
+        self.record(&TelemetryRecord::QuestionAnswered {
+            correct,
+            difficulty,
+            elapsed_ms,
+        });
+    }
+
+    fn session_ended(&self, summary: &SessionEndedDetail) {
+        self.record(&TelemetryRecord::SessionEnded(summary));
+    }
+
+    fn error(&self, context: &str, message: &str) {
+        self.record(&TelemetryRecord::Error { context, message });
+    }
+}
+
+/// Report each answer of a scripted session, then its end
+///
+/// Scripted answers are not timed, so each is reported as taking no time.
+pub fn report_scripted(
+    report: &ScriptedReport,
+    difficulty: Option<Difficulty>,
+    telemetry: &dyn Telemetry,
+) {
+    for answer in &report.answers {
+        telemetry.question_answered(answer.correct, difficulty, 0);
+    }
+    telemetry.session_ended(&report.session_summary());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{generate_placeholder_catalog, StarId};
+    use crate::game::quiz::{QuestionKind, QuizQuestion};
+    use crate::game::scripted::run_scripted;
+
+    fn lines(telemetry: JsonLinesTelemetry<Vec<u8>>) -> Vec<serde_json::Value> {
+        String::from_utf8(telemetry.into_inner())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_scripted_session_records() {
+        let question = QuizQuestion {
+            target_star: StarId(1),
+            correct_answer: "Vega".into(),
+            choices: vec!["Deneb".into(), "Vega".into()],
+            is_none_question: false,
+            question_kind: QuestionKind::Name,
+            second_star: None,
+        };
+        let report = run_scripted(
+            &generate_placeholder_catalog(),
+            &vec![question; 3],
+            &["2", "1", "Vega"],
+        );
+
+        let telemetry = JsonLinesTelemetry::new(Vec::new());
+        report_scripted(&report, Some(Difficulty::Easy), &telemetry);
+        let records = lines(telemetry);
+
+        assert_eq!(records.len(), 4);
+        let correct: Vec<_> = records[..3].iter().map(|r| r["correct"].clone()).collect();
+        assert_eq!(correct, [true, false, true]);
+        assert_eq!(
+            records[0],
+            serde_json::json!({
+                "event": "questionAnswered",
+                "correct": true,
+                "difficulty": "easy",
+                "elapsedMs": 0
+            })
+        );
+        assert_eq!(records[3]["event"], "sessionEnded");
+        assert_eq!(records[3]["total"], 3);
+        assert_eq!(records[3]["bestStreak"], 1);
+    }
+
+    #[test]
+    fn test_error_record() {
+        let telemetry = JsonLinesTelemetry::new(Vec::new());
+        telemetry.error("catalog", "HTTP 404");
+        NoTelemetry.error("catalog", "ignored");
+        assert_eq!(
+            lines(telemetry),
+            [serde_json::json!({ "event": "error", "context": "catalog", "message": "HTTP 404" })]
+        );
+    }
+}
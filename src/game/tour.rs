@@ -0,0 +1,224 @@
+//! Guided tours
+//!
+//! A tour visits famous stars in order, a sentence about each. Stops
+//! name their stars by proper name, so a tour resolves against whichever
+//! catalog is loaded; a stop whose star the catalog lacks still shows
+//! its note. Tour names and notes are interface text, translated with
+//! the rest of it. Moving between stops glides the map from one star to
+//! the next along [`glide_step`].
+
+use crate::data::search::LookupResult;
+use crate::data::{CelestialCoord, Star, StarCatalog};
+use serde::{Deserialize, Serialize};
+
+/// A star on a tour; what is said about it is interface text, looked
+/// up by the tour and the star
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TourStop {
+    /// Proper name, as in the catalog
+    pub star: &'static str,
+}
+
+impl TourStop {
+    /// The stop's star in `catalog`
+    pub fn find<'a>(&self, catalog: &'a StarCatalog) -> Option<&'a Star> {
+        match catalog.lookup(self.star) {
+            LookupResult::Found(star) if star.name.as_deref() == Some(self.star) => Some(star),
+            _ => None,
+        }
+    }
+}
+
+/// Which tour a [`Tour`] is, naming it in the interface text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TourId {
+    BrightestStars,
+    WinterHexagon,
+}
+
+/// An ordered tour of stars
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tour {
+    pub id: TourId,
+    pub stops: &'static [TourStop],
+}
+
+const fn stop(star: &'static str) -> TourStop {
+    TourStop { star }
+}
+
+/// The tours on offer
+pub static TOURS: [Tour; 2] = [
+    Tour {
+        id: TourId::BrightestStars,
+        stops: &[
+            stop("Sirius"),
+            stop("Canopus"),
+            stop("Rigil Kentaurus"),
+            stop("Arcturus"),
+            stop("Vega"),
+            stop("Capella"),
+            stop("Rigel"),
+            stop("Procyon"),
+            stop("Achernar"),
+            stop("Betelgeuse"),
+            stop("Hadar"),
+            stop("Altair"),
+            stop("Acrux"),
+            stop("Aldebaran"),
+            stop("Antares"),
+            stop("Spica"),
+            stop("Pollux"),
+            stop("Fomalhaut"),
+            stop("Deneb"),
+            stop("Mimosa"),
+        ],
+    },
+    Tour {
+        id: TourId::WinterHexagon,
+        stops: &[
+            stop("Sirius"),
+            stop("Rigel"),
+            stop("Aldebaran"),
+            stop("Capella"),
+            stop("Pollux"),
+            stop("Procyon"),
+            stop("Betelgeuse"),
+        ],
+    },
+];
+
+/// A tour being taken and the stop it is at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TourRun {
+    /// Index of the tour in [`TOURS`]
+    pub tour: usize,
+
+    /// Index of the current stop
+    pub stop: usize,
+}
+
+impl TourRun {
+    /// The tour at `index` in [`TOURS`], from its first stop
+    pub fn new(index: usize) -> Option<Self> {
+        (index < TOURS.len()).then_some(Self {
+            tour: index,
+            stop: 0,
+        })
+    }
+
+    pub fn tour(&self) -> &'static Tour {
+        &TOURS[self.tour]
+    }
+
+    pub fn current(&self) -> &'static TourStop {
+        &self.tour().stops[self.stop]
+    }
+
+    pub fn is_first(&self) -> bool {
+        self.stop == 0
+    }
+
+    pub fn is_last(&self) -> bool {
+        self.stop + 1 == self.tour().stops.len()
+    }
+
+    /// Move on a stop, unless at the last
+    pub fn next(&mut self) {
+        if !self.is_last() {
+            self.stop += 1;
+        }
+    }
+
+    /// Move back a stop, unless at the first
+    pub fn previous(&mut self) {
+        self.stop = self.stop.saturating_sub(1);
+    }
+}
+
+/// Smoothstep easing: slow to start and slow to stop
+fn ease(t: f64) -> f64 {
+    let t = t.clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Where frame `frame` of a glide of `frames` frames moves the map
+/// center to from `from`, on its way to `to`
+///
+/// Each frame covers its eased share of what is left of the way, so the
+/// glide follows the user's panning mid-way and still ends on `to`. Right
+/// ascension takes the short way round.
+pub fn glide_step(
+    from: &CelestialCoord,
+    to: &CelestialCoord,
+    frame: u32,
+    frames: u32,
+) -> CelestialCoord {
+    let done = ease(f64::from(frame) / f64::from(frames));
+    let share = if done < 1.0 {
+        (ease(f64::from(frame + 1) / f64::from(frames)) - done) / (1.0 - done)
+    } else {
+        1.0
+    };
+    let ra_delta = (to.ra - from.ra + 12.0).rem_euclid(24.0) - 12.0;
+    CelestialCoord::new(
+        (from.ra + ra_delta * share).rem_euclid(24.0),
+        from.dec + (to.dec - from.dec) * share,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{facts::facts, generate_placeholder_catalog};
+
+    #[test]
+    fn test_tour_stops() {
+        for tour in &TOURS {
+            assert!(!tour.stops.is_empty());
+            // Every stop's star is a known bright star
+            for stop in tour.stops {
+                assert!(facts(stop.star).is_some(), "{}", stop.star);
+            }
+        }
+        assert_eq!(TOURS[0].stops.len(), 20);
+
+        let catalog = generate_placeholder_catalog();
+        let sirius = TOURS[1].stops[0].find(&catalog).unwrap();
+        assert_eq!(sirius.name.as_deref(), Some("Sirius"));
+    }
+
+    #[test]
+    fn test_moving_between_stops() {
+        assert_eq!(TourRun::new(TOURS.len()), None);
+        let mut run = TourRun::new(1).unwrap();
+        assert!(run.is_first());
+        run.previous();
+        assert_eq!(run.stop, 0);
+
+        for _ in 0..10 {
+            run.next();
+        }
+        assert!(run.is_last());
+        assert_eq!(run.current().star, "Betelgeuse");
+        run.previous();
+        assert_eq!(run.current().star, "Procyon");
+    }
+
+    #[test]
+    fn test_glide_ends_on_target() {
+        // Across 0h, the short way round
+        let to = CelestialCoord::new(1.0, 20.0);
+        let mut at = CelestialCoord::new(23.0, -20.0);
+        let mut path = Vec::new();
+        for frame in 0..10 {
+            at = glide_step(&at, &to, frame, 10);
+            path.push(at);
+        }
+        assert!((path[4].ra - 0.0).abs() < 1e-9 || (path[4].ra - 24.0).abs() < 1e-9);
+        assert!(path[4].dec.abs() < 1e-9);
+        assert!(path.iter().all(|c| c.ra >= 23.0 || c.ra <= 1.0 + 1e-9));
+        assert!((at.ra - 1.0).abs() < 1e-9);
+        assert!((at.dec - 20.0).abs() < 1e-9);
+    }
+}
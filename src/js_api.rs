@@ -0,0 +1,317 @@
+//! Catalog queries and quiz hooks for JavaScript
+//!
+//! Lets a host page query the star catalog without mounting the Yew UI.
+//! The functions share one lazily built catalog, so they work before,
+//! after, or without [`crate::start`].
+//!
+//! The host can also configure the quiz with [`set_quiz_config`],
+//! listen for the events described in [`crate::game::events`], and
+//! receive usage reports through [`set_telemetry_callback`].
+//!
+//! Stars are returned as plain objects:
+//!
+//! ```json
+//! { "id": 1, "name": "Sirius", "ra": 6.752, "dec": -16.716, "mag": -1.46, "constellation": "CMa" }
+//! ```
+//!
+//! `name` and `constellation` are `null` when unknown.
+
+use crate::data::{generate_placeholder_catalog, Star, StarCatalog, StarId};
+use crate::game::events::{HostQuizConfig, SessionEndedDetail};
+use crate::game::telemetry::TelemetryRecord;
+use crate::game::{Difficulty, Telemetry};
+use serde::Serialize;
+use std::cell::{OnceCell, RefCell};
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+
+thread_local! {
+    static CATALOG: OnceCell<Rc<StarCatalog>> = const { OnceCell::new() };
+    static QUIZ_CONFIG: RefCell<HostQuizConfig> = RefCell::new(HostQuizConfig::default());
+    static TELEMETRY: RefCell<Option<Rc<dyn Telemetry>>> = const { RefCell::new(None) };
+}
+
+/// The catalog shared by all JavaScript queries
+pub fn shared_catalog() -> Rc<StarCatalog> {
+    CATALOG.with(|cell| {
+        cell.get_or_init(|| Rc::new(generate_placeholder_catalog()))
+            .clone()
+    })
+}
+
+/// A star as seen from JavaScript
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JsStar {
+    pub id: u32,
+    pub name: Option<String>,
+    pub ra: f64,
+    pub dec: f64,
+    pub mag: f64,
+    pub constellation: Option<String>,
+}
+
+impl From<&Star> for JsStar {
+    fn from(star: &Star) -> Self {
+        Self {
+            id: star.id.0,
+            name: star.name.clone(),
+            ra: star.coord.ra,
+            dec: star.coord.dec,
+            mag: star.magnitude,
+            constellation: star.constellation.clone(),
+        }
+    }
+}
+
+/// Named stars, brightest first
+pub fn named_star_records(catalog: &StarCatalog) -> Vec<JsStar> {
+    let mut named = catalog.named_stars();
+    named.sort_by(|a, b| a.magnitude.total_cmp(&b.magnitude));
+    named.into_iter().map(JsStar::from).collect()
+}
+
+/// Named stars matching `query`, at most `limit`
+///
+/// Names starting with the query come first, then names containing it
+/// (both case-insensitive, alphabetical within each group). When nothing
+/// matches, close misspellings are returned instead.
+pub fn search_records(catalog: &StarCatalog, query: &str, limit: usize) -> Vec<JsStar> {
+    let needle = query.trim().to_lowercase();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<(bool, &Star)> = catalog
+        .named_stars()
+        .into_iter()
+        .filter_map(|star| {
+            let name = star.name.as_deref()?.to_lowercase();
+            name.contains(&needle)
+                .then(|| (!name.starts_with(&needle), star))
+        })
+        .collect();
+    matches.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.name.cmp(&b.1.name)));
+
+    let stars: Vec<&Star> = if matches.is_empty() {
+        catalog.fuzzy_search(query, limit)
+    } else {
+        matches.into_iter().map(|(_, star)| star).collect()
+    };
+    stars.into_iter().take(limit).map(JsStar::from).collect()
+}
+
+/// Serialize for JavaScript, with `None` as `null` rather than `undefined`
+fn to_js<T: Serialize>(value: &T) -> JsValue {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .unwrap_or(JsValue::NULL)
+}
+
+/// Quiz settings last set by the host page
+pub fn host_quiz_config() -> HostQuizConfig {
+    QUIZ_CONFIG.with(|config| config.borrow().clone())
+}
+
+/// Telemetry that passes each report to a JavaScript function
+struct JsTelemetry(js_sys::Function);
+
+impl JsTelemetry {
+    fn send(&self, record: &TelemetryRecord) {
+        if let Err(e) = self.0.call1(&JsValue::NULL, &to_js(record)) {
+            log::warn!("Telemetry callback failed: {:?}", e);
+        }
+    }
+}
+
+impl Telemetry for JsTelemetry {
+    fn question_answered(&self, correct: bool, difficulty: Option<Difficulty>, elapsed_ms: u64) {
+        self.send(&TelemetryRecord::QuestionAnswered {
+            correct,
+            difficulty,
+            elapsed_ms,
+        });
+    }
+
+    fn session_ended(&self, summary: &SessionEndedDetail) {
+        self.send(&TelemetryRecord::SessionEnded(summary));
+    }
+
+    fn error(&self, context: &str, message: &str) {
+        self.send(&TelemetryRecord::Error { context, message });
+    }
+}
+
+/// Telemetry registered by the host page, if any
+pub fn host_telemetry() -> Option<Rc<dyn Telemetry>> {
+    TELEMETRY.with(|telemetry| telemetry.borrow().clone())
+}
+
+/// Dispatch a `CustomEvent` named `name` with `detail` on `target`
+pub fn dispatch_quiz_event<T: Serialize>(target: &web_sys::EventTarget, name: &str, detail: &T) {
+    let init = web_sys::CustomEventInit::new();
+    init.set_detail(&to_js(detail));
+    init.set_bubbles(true);
+    match web_sys::CustomEvent::new_with_event_init_dict(name, &init) {
+        Ok(event) => {
+            let _ = target.dispatch_event(&event);
+        }
+        Err(e) => log::warn!("Could not create {} event: {:?}", name, e),
+    }
+}
+
+/// Set the quiz difficulty and question count from JSON
+///
+/// Accepts `{ "difficulty": "easy" | "medium" | "hard", "questionCount": 10 }`;
+/// omitted fields are cleared. Takes effect from the next question.
+#[wasm_bindgen]
+pub fn set_quiz_config(json: &str) -> Result<(), JsValue> {
+    let config = HostQuizConfig::from_json(json).map_err(|e| JsValue::from(JsError::new(&e)))?;
+    QUIZ_CONFIG.with(|current| *current.borrow_mut() = config);
+    Ok(())
+}
+
+/// Send usage reports to `callback`, or stop sending them with `null`
+///
+/// The callback gets one object per report, shaped as in
+/// [`crate::game::telemetry`], e.g.
+/// `{ "event": "questionAnswered", "correct": true, "difficulty": null, "elapsedMs": 2310 }`.
+#[wasm_bindgen]
+pub fn set_telemetry_callback(callback: Option<js_sys::Function>) {
+    let telemetry = callback.map(|f| Rc::new(JsTelemetry(f)) as Rc<dyn Telemetry>);
+    TELEMETRY.with(|current| *current.borrow_mut() = telemetry);
+}
+
+/// All named stars, brightest first
+#[wasm_bindgen]
+pub fn catalog_named_stars() -> JsValue {
+    to_js(&named_star_records(&shared_catalog()))
+}
+
+/// Named stars whose names match `query`, at most `limit`
+#[wasm_bindgen]
+pub fn catalog_search(query: &str, limit: usize) -> JsValue {
+    to_js(&search_records(&shared_catalog(), query, limit))
+}
+
+/// The star with the given id, or `null` if there is none
+#[wasm_bindgen]
+pub fn catalog_star(id: u32) -> JsValue {
+    match shared_catalog().get(StarId(id)) {
+        Some(star) => to_js(&JsStar::from(star)),
+        None => JsValue::NULL,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records_have_expected_fields() {
+        let records = named_star_records(&generate_placeholder_catalog());
+        let json = serde_json::to_value(&records[0]).unwrap();
+
+        assert_eq!(json["name"], "Sirius");
+        for key in ["id", "name", "ra", "dec", "mag", "constellation"] {
+            assert!(json.get(key).is_some(), "missing {}", key);
+        }
+        assert!(records.windows(2).all(|w| w[0].mag <= w[1].mag));
+    }
+
+    #[test]
+    fn test_search_prefers_prefix_matches() {
+        let catalog = generate_placeholder_catalog();
+        let names: Vec<_> = search_records(&catalog, "al", 50)
+            .into_iter()
+            .filter_map(|s| s.name)
+            .collect();
+
+        let first_inner = names
+            .iter()
+            .position(|n| !n.to_lowercase().starts_with("al"))
+            .unwrap_or(names.len());
+        assert!(first_inner > 0);
+        assert!(names[first_inner..]
+            .iter()
+            .all(|n| !n.to_lowercase().starts_with("al")));
+        assert_eq!(search_records(&catalog, "al", 2).len(), 2);
+    }
+
+    #[test]
+    fn test_search_falls_back_to_misspellings() {
+        let catalog = generate_placeholder_catalog();
+        let results = search_records(&catalog, "Siruis", 3);
+        assert_eq!(results[0].name.as_deref(), Some("Sirius"));
+        assert!(search_records(&catalog, "  ", 3).is_empty());
+    }
+
+    #[test]
+    fn test_shared_catalog_is_built_once() {
+        assert!(Rc::ptr_eq(&shared_catalog(), &shared_catalog()));
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod wasm_tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn get(value: &JsValue, key: &str) -> JsValue {
+        js_sys::Reflect::get(value, &JsValue::from_str(key)).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn named_stars_are_plain_objects() {
+        let stars = js_sys::Array::from(&catalog_named_stars());
+        assert!(stars.length() > 0);
+
+        let first = stars.get(0);
+        assert_eq!(get(&first, "name").as_string().as_deref(), Some("Sirius"));
+        for key in ["id", "ra", "dec", "mag", "constellation"] {
+            assert!(!get(&first, key).is_undefined(), "missing {}", key);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn search_respects_limit() {
+        let results = js_sys::Array::from(&catalog_search("a", 3));
+        assert_eq!(results.length(), 3);
+    }
+
+    #[wasm_bindgen_test]
+    fn unknown_id_is_null() {
+        assert!(catalog_star(u32::MAX).is_null());
+        assert_eq!(get(&catalog_star(1), "id").as_f64(), Some(1.0));
+    }
+
+    #[wasm_bindgen_test]
+    fn quiz_config_is_validated() {
+        set_quiz_config(r#"{ "difficulty": "medium", "questionCount": 5 }"#).unwrap();
+        assert_eq!(host_quiz_config().question_count, Some(5));
+        assert!(set_quiz_config(r#"{ "difficulty": "extreme" }"#).is_err());
+        set_quiz_config("{}").unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn telemetry_reaches_the_callback() {
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let push = {
+            let received = received.clone();
+            Closure::<dyn Fn(JsValue)>::new(move |record| received.borrow_mut().push(record))
+        };
+        set_telemetry_callback(Some(
+            push.as_ref().unchecked_ref::<js_sys::Function>().clone(),
+        ));
+        host_telemetry().unwrap().error("catalog", "HTTP 404");
+        set_telemetry_callback(None);
+
+        assert_eq!(received.borrow().len(), 1);
+        let record = received.borrow()[0].clone();
+        assert_eq!(get(&record, "event").as_string().as_deref(), Some("error"));
+        assert_eq!(
+            get(&record, "message").as_string().as_deref(),
+            Some("HTTP 404")
+        );
+        assert!(host_telemetry().is_none());
+    }
+}
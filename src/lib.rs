@@ -12,6 +12,11 @@
 //! - **utils**: Coordinate projections and utilities
 //! - **components**: Yew UI components
 //! - **app**: Main application component
+//! - **js_api**: Catalog queries callable from JavaScript
+//!
+//! `components`, `app` and `js_api` belong to the default `web` feature.
+//! Without it, `data`, `game` and `utils` build as a plain Rust library;
+//! [`game::engine::HeadlessGame`] drives the game without a browser.
 //!
 //! # Usage
 //!
@@ -20,27 +25,38 @@
 //! trunk serve --open
 //! ```
 
+#[cfg(feature = "web")]
 pub mod app;
+#[cfg(feature = "web")]
 pub mod components;
 pub mod data;
 pub mod game;
+#[cfg(feature = "web")]
+pub mod js_api;
 pub mod utils;
 
+#[cfg(feature = "web")]
 pub use app::App;
 
+use serde::Serialize;
+#[cfg(feature = "web")]
 use wasm_bindgen::prelude::*;
 
 /// Entry point for the WebAssembly module
 ///
 /// This function is called when the WASM module is loaded.
 /// It initializes logging and mounts the Yew application.
+#[cfg(feature = "web")]
 #[wasm_bindgen(start)]
 pub fn start() -> Result<(), JsValue> {
-    // Initialize panic hook for better error messages
-    console_error_panic_hook::set_once();
+    // Log panics and replace the frozen page with an error card
+    app::crash::install_panic_hook();
 
-    // Initialize logging
-    wasm_logger::init(wasm_logger::Config::default());
+    // Initialize logging at the level asked for in the query string
+    let query = web_sys::window()
+        .and_then(|w| w.location().search().ok())
+        .unwrap_or_default();
+    wasm_logger::init(wasm_logger::Config::new(log_level(&query)));
 
     log::info!("Stargazer starting...");
 
@@ -50,12 +66,141 @@ pub fn start() -> Result<(), JsValue> {
     Ok(())
 }
 
+/// Log level for a page query string such as `?log=debug`
+///
+/// Without a readable `log` parameter, debug builds log at Debug and
+/// release builds at Warn.
+pub fn log_level(query: &str) -> log::Level {
+    query
+        .trim_start_matches('?')
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == "log")
+        .and_then(|(_, value)| value.parse().ok())
+        .unwrap_or(if cfg!(debug_assertions) {
+            log::Level::Debug
+        } else {
+            log::Level::Warn
+        })
+}
+
 /// Get version information
-#[wasm_bindgen]
+#[cfg_attr(feature = "web", wasm_bindgen)]
 pub fn version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
 
+/// Details of this build, captured by `build.rs`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildInfo {
+    pub version: &'static str,
+    /// Short commit SHA, or `unknown` when built outside a git checkout
+    pub git_sha: &'static str,
+    /// Whether the checkout had uncommitted changes
+    pub git_dirty: bool,
+    /// UTC build time in RFC 3339 form
+    pub build_time: &'static str,
+    /// Cargo profile, such as `debug` or `release`
+    pub profile: &'static str,
+    /// Target architecture, such as `wasm32` or `x86_64`
+    pub target_arch: &'static str,
+    /// Enabled cargo features, in [`FEATURES`] order
+    pub features: Vec<&'static str>,
+}
+
+/// The crate's cargo features and whether this build enables each
+///
+/// Optional dependencies are left out: the features that pull them in
+/// say more.
+pub const FEATURES: [(&str, bool); 4] = [
+    ("cli", cfg!(feature = "cli")),
+    ("embedded-catalog", cfg!(feature = "embedded-catalog")),
+    ("tiles", cfg!(feature = "tiles")),
+    ("web", cfg!(feature = "web")),
+];
+
+impl BuildInfo {
+    /// The commit SHA, marked `-dirty` for uncommitted changes
+    pub fn revision(&self) -> String {
+        if self.git_dirty {
+            format!("{}-dirty", self.git_sha)
+        } else {
+            self.git_sha.to_string()
+        }
+    }
+
+    /// Text for the page footer
+    pub fn footer_text(&self) -> String {
+        let mut text = format!(
+            "v{} • Build: {} • SHA: {}",
+            self.version,
+            self.build_time,
+            self.revision()
+        );
+        if self.profile != "release" {
+            text.push_str(&format!(" ({})", self.profile));
+        }
+        text
+    }
+
+    /// Text for `-V`: the version and the commit it was built from
+    pub fn version_text(&self) -> String {
+        format!("{} ({})", self.version, self.revision())
+    }
+
+    /// Text for `--version`, with every detail
+    pub fn long_version_text(&self) -> String {
+        let features = if self.features.is_empty() {
+            "none".to_string()
+        } else {
+            self.features.join(", ")
+        };
+        format!(
+            "{}\nbuilt: {}\nprofile: {}\ntarget: {}\nfeatures: {}",
+            self.version_text(),
+            self.build_time,
+            self.profile,
+            self.target_arch,
+            features
+        )
+    }
+}
+
+/// Information about the running build
+pub fn build_info() -> BuildInfo {
+    BuildInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        git_sha: env!("STARGAZER_GIT_SHA"),
+        git_dirty: env!("STARGAZER_GIT_DIRTY") == "true",
+        build_time: env!("STARGAZER_BUILD_TIME"),
+        profile: env!("STARGAZER_BUILD_PROFILE"),
+        target_arch: std::env::consts::ARCH,
+        features: FEATURES
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| *name)
+            .collect(),
+    }
+}
+
+/// Build information as JSON, for pages that embed the module to show in
+/// an about dialog or attach to bug reports
+///
+/// Keys are camelCase: `version`, `gitSha`, `gitDirty`, `buildTime`,
+/// `profile`, `targetArch` and `features`, an array of feature names.
+#[cfg_attr(feature = "web", wasm_bindgen)]
+pub fn build_info_json() -> String {
+    serde_json::to_string(&build_info()).expect("build info serializes")
+}
+
+/// Build information as a plain object
+#[cfg(feature = "web")]
+#[wasm_bindgen(js_name = buildInfo)]
+pub fn build_info_js() -> JsValue {
+    serde_wasm_bindgen::to_value(&build_info()).unwrap_or(JsValue::NULL)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,4 +210,110 @@ mod tests {
         let v = version();
         assert!(!v.is_empty());
     }
+
+    #[test]
+    fn test_log_level() {
+        assert_eq!(log_level("?log=info"), log::Level::Info);
+        assert_eq!(log_level("?theme=dark&log=WARN"), log::Level::Warn);
+        assert_eq!(log_level("?log=debug"), log::Level::Debug);
+
+        let default = if cfg!(debug_assertions) {
+            log::Level::Debug
+        } else {
+            log::Level::Warn
+        };
+        assert_eq!(log_level(""), default);
+        assert_eq!(log_level("?log=loud"), default);
+    }
+
+    #[test]
+    fn test_build_info_is_filled_in() {
+        let info = build_info();
+        assert_eq!(info.version, version());
+        assert!(!info.git_sha.is_empty());
+        assert!(!info.profile.is_empty());
+        // e.g. 2025-12-30T18:55:00Z
+        assert_eq!(info.build_time.len(), 20);
+        assert!(info.build_time.ends_with('Z'));
+    }
+
+    #[test]
+    fn test_build_info_json() {
+        let json: serde_json::Value = serde_json::from_str(&build_info_json()).unwrap();
+        let mut keys: Vec<&str> = json
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(|k| k.as_str())
+            .collect();
+        keys.sort_unstable();
+        assert_eq!(
+            keys,
+            [
+                "buildTime",
+                "features",
+                "gitDirty",
+                "gitSha",
+                "profile",
+                "targetArch",
+                "version"
+            ]
+        );
+        assert_eq!(json["version"], version());
+        assert_eq!(json["targetArch"], std::env::consts::ARCH);
+        assert!(json["gitDirty"].is_boolean());
+    }
+
+    #[test]
+    fn test_features_match_the_build() {
+        let features = build_info().features;
+        assert_eq!(features.contains(&"cli"), cfg!(feature = "cli"));
+        assert_eq!(features.contains(&"web"), cfg!(feature = "web"));
+        assert_eq!(features.contains(&"tiles"), cfg!(feature = "tiles"));
+    }
+
+    fn release_info() -> BuildInfo {
+        BuildInfo {
+            version: "0.1.0",
+            git_sha: "7e39ace",
+            git_dirty: false,
+            build_time: "2025-12-30T18:55:00Z",
+            profile: "release",
+            target_arch: "wasm32",
+            features: vec!["tiles", "web"],
+        }
+    }
+
+    #[test]
+    fn test_footer_text() {
+        let info = release_info();
+        assert_eq!(
+            info.footer_text(),
+            "v0.1.0 • Build: 2025-12-30T18:55:00Z • SHA: 7e39ace"
+        );
+
+        let debug = BuildInfo {
+            profile: "debug",
+            git_dirty: true,
+            ..info
+        };
+        assert!(debug.footer_text().ends_with("SHA: 7e39ace-dirty (debug)"));
+    }
+
+    #[test]
+    fn test_version_text() {
+        let info = release_info();
+        assert_eq!(info.version_text(), "0.1.0 (7e39ace)");
+        assert_eq!(
+            info.long_version_text(),
+            "0.1.0 (7e39ace)\nbuilt: 2025-12-30T18:55:00Z\nprofile: release\n\
+             target: wasm32\nfeatures: tiles, web"
+        );
+
+        let bare = BuildInfo {
+            features: vec![],
+            ..info
+        };
+        assert!(bare.long_version_text().ends_with("features: none"));
+    }
 }
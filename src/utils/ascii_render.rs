@@ -0,0 +1,222 @@
+//! Character-grid rendering of the star map
+//!
+//! Rasterizes the stars visible in a viewport into lines of text, one
+//! character per cell, for terminals without a browser. The viewport's
+//! width and height are taken as the number of columns and rows.
+
+use super::projection::Viewport;
+use super::sky_svg::grid_lines;
+use crate::data::{Star, StarCatalog};
+
+/// Options for rendering the map as text
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AsciiRenderOptions {
+    /// Faintest magnitude to include
+    pub magnitude_limit: f64,
+    /// Write names next to named stars
+    pub show_labels: bool,
+    /// Mark grid line intersections with `+`
+    pub show_grid: bool,
+    /// Longest label, in characters
+    pub max_label_len: usize,
+}
+
+impl Default for AsciiRenderOptions {
+    fn default() -> Self {
+        Self {
+            magnitude_limit: 6.0,
+            show_labels: false,
+            show_grid: false,
+            max_label_len: 10,
+        }
+    }
+}
+
+const EMPTY: char = ' ';
+const GRID: char = '+';
+
+/// Character for a star of the given magnitude, brightest first
+pub fn star_symbol(magnitude: f64) -> char {
+    if magnitude < 1.0 {
+        '@'
+    } else if magnitude < 3.0 {
+        '*'
+    } else {
+        '.'
+    }
+}
+
+/// Rank of a symbol so brighter stars win when sharing a cell
+fn symbol_rank(c: char) -> u8 {
+    match c {
+        '@' => 3,
+        '*' => 2,
+        '.' => 1,
+        _ => 0,
+    }
+}
+
+/// Render the stars in `viewport` as `viewport.height` lines of `viewport.width` characters
+pub fn render_ascii(
+    catalog: &StarCatalog,
+    viewport: &Viewport,
+    options: &AsciiRenderOptions,
+) -> Vec<String> {
+    let cols = viewport.width.max(0.0) as usize;
+    let rows = viewport.height.max(0.0) as usize;
+    let mut grid = vec![vec![EMPTY; cols]; rows];
+
+    let cell = |x: f64, y: f64| -> Option<(usize, usize)> {
+        (x >= 0.0 && y >= 0.0 && x < cols as f64 && y < rows as f64)
+            .then_some((x as usize, y as usize))
+    };
+
+    if options.show_grid {
+        let lines = grid_lines(viewport);
+        let (verticals, horizontals): (Vec<_>, Vec<_>) =
+            lines.iter().partition(|line| line.x1 == line.x2);
+        for v in &verticals {
+            for h in &horizontals {
+                if let Some((col, row)) = cell(v.x1, h.y1) {
+                    grid[row][col] = GRID;
+                }
+            }
+        }
+    }
+
+    let (ra_min, ra_max) = viewport.ra_range();
+    let (dec_min, dec_max) = viewport.dec_range();
+    let mut stars: Vec<(&Star, (usize, usize))> = catalog
+        .stars_in_range(ra_min, ra_max, dec_min, dec_max, options.magnitude_limit)
+        .into_iter()
+        .filter_map(|star| {
+            let projected = viewport.project(&star.coord);
+            if !projected.on_screen {
+                return None;
+            }
+            Some((star, cell(projected.point.x, projected.point.y)?))
+        })
+        .collect();
+
+    for (star, (col, row)) in &stars {
+        let symbol = star_symbol(star.magnitude);
+        if symbol_rank(symbol) > symbol_rank(grid[*row][*col]) {
+            grid[*row][*col] = symbol;
+        }
+    }
+
+    if options.show_labels {
+        // Brightest stars claim label space first
+        stars.sort_by(|a, b| a.0.magnitude.total_cmp(&b.0.magnitude));
+        for (star, (col, row)) in &stars {
+            let Some(name) = &star.name else {
+                continue;
+            };
+            let start = col + 2;
+            let label: Vec<char> = name.chars().take(options.max_label_len).collect();
+            let end = start + label.len();
+            // Keep one blank cell after the label so neighbours stay readable
+            let free = end < cols
+                && grid[*row][col + 1..=end]
+                    .iter()
+                    .all(|&c| c == EMPTY || c == GRID);
+            if free {
+                grid[*row][start..end].copy_from_slice(&label);
+            }
+        }
+    }
+
+    grid.into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::generate_placeholder_catalog;
+
+    /// 120x40 view centred on RA 6h, Dec 0°, covering 6h by 45°
+    fn orion_viewport() -> Viewport {
+        Viewport {
+            center_ra: 6.0,
+            center_dec: 0.0,
+            zoom: 4.0,
+            ..Viewport::new(120.0, 40.0)
+        }
+    }
+
+    fn find(lines: &[String], needle: &str) -> Option<(usize, usize)> {
+        lines.iter().enumerate().find_map(|(row, line)| {
+            line.find(needle)
+                .map(|byte| (line[..byte].chars().count(), row))
+        })
+    }
+
+    #[test]
+    fn test_output_dimensions() {
+        let catalog = generate_placeholder_catalog();
+        let options = AsciiRenderOptions {
+            show_labels: true,
+            show_grid: true,
+            ..Default::default()
+        };
+        let lines = render_ascii(&catalog, &orion_viewport(), &options);
+
+        assert_eq!(lines.len(), 40);
+        assert!(lines.iter().all(|l| l.chars().count() == 120));
+        assert!(lines.iter().any(|l| l.contains(GRID)));
+    }
+
+    #[test]
+    fn test_sirius_lands_in_expected_cell() {
+        let catalog = generate_placeholder_catalog();
+        let lines = render_ascii(&catalog, &orion_viewport(), &AsciiRenderOptions::default());
+
+        // RA 6.75h is 3.75h into a 6h-wide field: column 75 of 120.
+        // Dec -16.7° is 39.2° below the +22.5° top edge of a 45° field: row 34 of 40.
+        assert_eq!(lines[34].chars().nth(75), Some('@'));
+    }
+
+    #[test]
+    fn test_labels_follow_their_star() {
+        let catalog = generate_placeholder_catalog();
+        // Bright stars only, so no faint neighbour crowds out the label
+        let options = AsciiRenderOptions {
+            magnitude_limit: 1.0,
+            show_labels: true,
+            ..Default::default()
+        };
+        let lines = render_ascii(&catalog, &orion_viewport(), &options);
+
+        assert_eq!(find(&lines, "Sirius"), Some((77, 34)));
+    }
+
+    #[test]
+    fn test_out_of_view_stars_are_absent() {
+        let catalog = generate_placeholder_catalog();
+        let options = AsciiRenderOptions {
+            show_labels: true,
+            ..Default::default()
+        };
+        let lines = render_ascii(&catalog, &orion_viewport(), &options);
+
+        // Vega (RA 18.6h) and Polaris (Dec +89°) are far outside the field
+        assert_eq!(find(&lines, "Vega"), None);
+        assert_eq!(find(&lines, "Polaris"), None);
+    }
+
+    #[test]
+    fn test_magnitude_limit_hides_faint_stars() {
+        let catalog = generate_placeholder_catalog();
+        let options = AsciiRenderOptions {
+            magnitude_limit: 0.0,
+            ..Default::default()
+        };
+        let lines = render_ascii(&catalog, &orion_viewport(), &options);
+
+        let text: String = lines.concat();
+        assert!(!text.contains('.') && !text.contains('*'));
+        assert!(text.contains('@'));
+    }
+}
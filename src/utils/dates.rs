@@ -0,0 +1,193 @@
+//! Calendar dates as day numbers
+//!
+//! Dates are counted in whole days since 1970-01-01 (UTC), which makes
+//! scheduling arithmetic plain integer math. Conversion to and from the
+//! proleptic Gregorian calendar follows Howard Hinnant's algorithms.
+
+/// Seconds in a day
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Minutes in a day
+pub const MINUTES_PER_DAY: i64 = 1440;
+
+/// Day number of a calendar date
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = i64::from((month + 9) % 12);
+    let day_of_year = (153 * month_index + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Calendar date `(year, month, day)` of a day number
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Day number containing a Unix timestamp in seconds
+pub fn day_from_unix_seconds(seconds: i64) -> i64 {
+    seconds.div_euclid(SECONDS_PER_DAY)
+}
+
+/// Day number containing a Unix timestamp in milliseconds
+pub fn day_from_unix_millis(millis: f64) -> i64 {
+    day_from_unix_seconds((millis / 1000.0).floor() as i64)
+}
+
+/// The current Unix time in milliseconds
+///
+/// Reads the browser clock on wasm, where the system clock is missing,
+/// and reports 0 there without the `web` feature.
+pub fn unix_millis_now() -> f64 {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    {
+        js_sys::Date::now()
+    }
+    #[cfg(all(target_arch = "wasm32", not(feature = "web")))]
+    {
+        0.0
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0.0, |elapsed| elapsed.as_secs_f64() * 1000.0)
+    }
+}
+
+/// Parse an ISO date such as `2025-03-01` into a day number
+pub fn parse_date(input: &str) -> Result<i64, String> {
+    let invalid = || format!("expected a date like 2025-03-01: {}", input);
+    let mut parts = input.trim().splitn(3, '-');
+    let mut next = || parts.next().ok_or_else(invalid);
+    let year: i64 = next()?.parse().map_err(|_| invalid())?;
+    let month: u32 = next()?.parse().map_err(|_| invalid())?;
+    let day: u32 = next()?.parse().map_err(|_| invalid())?;
+
+    if !(1..=12).contains(&month) || day == 0 {
+        return Err(invalid());
+    }
+    let days = days_from_civil(year, month, day);
+    // Reject days past the end of the month, which would roll over
+    if civil_from_days(days) != (year, month, day) {
+        return Err(invalid());
+    }
+    Ok(days)
+}
+
+/// Format a day number as an ISO date
+pub fn format_date(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Format a Unix time in seconds, `offset_minutes` east of UTC, as
+/// `2025-03-01T21:30`: the value of a `datetime-local` input
+pub fn format_datetime(unix_seconds: f64, offset_minutes: i64) -> String {
+    let minutes = (unix_seconds / 60.0).floor() as i64 + offset_minutes;
+    let minute_of_day = minutes.rem_euclid(MINUTES_PER_DAY);
+    format!(
+        "{}T{:02}:{:02}",
+        format_date(minutes.div_euclid(MINUTES_PER_DAY)),
+        minute_of_day / 60,
+        minute_of_day % 60
+    )
+}
+
+/// Parse a date and time such as `2025-03-01T21:30`, read
+/// `offset_minutes` east of UTC, into a Unix time in seconds
+///
+/// Seconds, which a `datetime-local` input may add, are ignored.
+pub fn parse_datetime(input: &str, offset_minutes: i64) -> Result<f64, String> {
+    let invalid = || format!("expected a date and time like 2025-03-01T21:30: {}", input);
+    let (date, time) = input.trim().split_once('T').ok_or_else(invalid)?;
+    let days = parse_date(date).map_err(|_| invalid())?;
+    let mut parts = time.split(':');
+    let mut next = || -> Result<i64, String> {
+        parts
+            .next()
+            .and_then(|part| part.parse().ok())
+            .ok_or_else(invalid)
+    };
+    let hours = next()?;
+    let minutes = next()?;
+
+    if !(0..24).contains(&hours) || !(0..60).contains(&minutes) {
+        return Err(invalid());
+    }
+    let local = days * MINUTES_PER_DAY + hours * 60 + minutes;
+    Ok(((local - offset_minutes) * 60) as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_epoch_and_known_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(format_date(days_from_civil(2024, 2, 29)), "2024-02-29");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for days in (-800_000..800_000).step_by(997) {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y, m, d), days);
+        }
+    }
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("2025-03-01"), Ok(days_from_civil(2025, 3, 1)));
+        assert!(parse_date("2025-02-29").is_err());
+        assert!(parse_date("2025-13-01").is_err());
+        assert!(parse_date("March 1").is_err());
+    }
+
+    #[test]
+    fn test_datetime_round_trip() {
+        let seconds = days_from_civil(2025, 3, 1) as f64 * 86_400.0 + 21.5 * 3600.0;
+        assert_eq!(format_datetime(seconds, 0), "2025-03-01T21:30");
+        assert_eq!(format_datetime(seconds, 180), "2025-03-02T00:30");
+        assert_eq!(format_datetime(seconds, -60), "2025-03-01T20:30");
+        assert_eq!(parse_datetime("2025-03-02T00:30", 180), Ok(seconds));
+        assert_eq!(parse_datetime("2025-03-01T21:30:00", 0), Ok(seconds));
+        assert!(parse_datetime("2025-03-01T24:00", 0).is_err());
+        assert!(parse_datetime("2025-03-01", 0).is_err());
+        assert!(parse_datetime("2025-03-01Tnoon", 0).is_err());
+    }
+
+    #[test]
+    fn test_day_from_unix_seconds() {
+        assert_eq!(day_from_unix_seconds(0), 0);
+        assert_eq!(day_from_unix_seconds(86_399), 0);
+        assert_eq!(day_from_unix_seconds(-1), -1);
+        assert_eq!(day_from_unix_millis(86_400_000.0), 1);
+        assert_eq!(day_from_unix_millis(-0.5), -1);
+    }
+
+    #[test]
+    fn test_unix_millis_now_is_after_2025() {
+        let jan_2025 = days_from_civil(2025, 1, 1) as f64 * 86_400_000.0;
+        assert!(unix_millis_now() > jan_2025);
+    }
+}
@@ -0,0 +1,126 @@
+//! File downloads and uploads
+//!
+//! Browser helpers for saving generated content (such as exported map
+//! images) to the user's machine, and for reading a file the user picked.
+
+use gloo::timers::callback::Timeout;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    Blob, BlobPropertyBag, CanvasRenderingContext2d, HtmlAnchorElement, HtmlCanvasElement,
+    HtmlImageElement, Url,
+};
+
+/// MIME type for SVG documents
+pub const SVG_MIME: &str = "image/svg+xml";
+
+/// MIME type for JSON documents
+pub const JSON_MIME: &str = "application/json";
+
+/// Image format for map exports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Svg,
+    Png,
+}
+
+impl ExportFormat {
+    /// Default file name for an exported map image
+    pub fn filename(&self) -> &'static str {
+        match self {
+            ExportFormat::Svg => "stargazer-map.svg",
+            ExportFormat::Png => "stargazer-map.png",
+        }
+    }
+}
+
+/// Trigger a download of `contents` as a file named `filename`
+pub fn download_text(filename: &str, mime: &str, contents: &str) -> Result<(), JsValue> {
+    let url = object_url_for(mime, contents)?;
+    let result = click_download_link(filename, &url);
+    revoke_after_click(url);
+    result
+}
+
+/// Rasterize an SVG document to PNG and trigger a download
+///
+/// The SVG is drawn onto an offscreen canvas of the given size once the
+/// browser has decoded it, so the download happens asynchronously.
+pub fn download_svg_as_png(
+    filename: &str,
+    svg: &str,
+    width: u32,
+    height: u32,
+) -> Result<(), JsValue> {
+    let document = gloo_utils::document();
+    let url = object_url_for(SVG_MIME, svg)?;
+
+    let image = HtmlImageElement::new()?;
+    let canvas: HtmlCanvasElement = document.create_element("canvas")?.dyn_into()?;
+    canvas.set_width(width);
+    canvas.set_height(height);
+
+    let filename = filename.to_string();
+    let onload = {
+        let image = image.clone();
+        let url = url.clone();
+        Closure::once_into_js(move || {
+            let result = (|| -> Result<(), JsValue> {
+                let context: CanvasRenderingContext2d = canvas
+                    .get_context("2d")?
+                    .ok_or_else(|| JsValue::from_str("2d context unavailable"))?
+                    .dyn_into()?;
+                context.draw_image_with_html_image_element(&image, 0.0, 0.0)?;
+                let png_url = canvas.to_data_url_with_type("image/png")?;
+                click_download_link(&filename, &png_url)
+            })();
+            if let Err(e) = result {
+                log::warn!("PNG export failed: {:?}", e);
+            }
+            revoke_after_click(url);
+        })
+    };
+
+    // The closure is freed after its single invocation
+    image.set_onload(Some(onload.unchecked_ref()));
+    image.set_src(&url);
+    Ok(())
+}
+
+/// The contents of a picked file (or any blob) as text
+pub async fn read_text(blob: &Blob) -> Result<String, JsValue> {
+    let text = JsFuture::from(blob.text()).await?;
+    text.as_string()
+        .ok_or_else(|| JsValue::from_str("file is not text"))
+}
+
+/// Wrap `contents` in a Blob and return an object URL for it
+fn object_url_for(mime: &str, contents: &str) -> Result<String, JsValue> {
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime);
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    Url::create_object_url_with_blob(&blob)
+}
+
+/// Revoke an object URL once the current task is done
+///
+/// Some browsers abort a download whose URL is revoked in the same task
+/// as the click that started it.
+fn revoke_after_click(url: String) {
+    Timeout::new(0, move || {
+        let _ = Url::revoke_object_url(&url);
+    })
+    .forget();
+}
+
+/// Click a temporary `<a download>` link pointing at `href`
+fn click_download_link(filename: &str, href: &str) -> Result<(), JsValue> {
+    let document = gloo_utils::document();
+    let anchor: HtmlAnchorElement = document.create_element("a")?.dyn_into()?;
+    anchor.set_href(href);
+    anchor.set_download(filename);
+    anchor.click();
+    Ok(())
+}
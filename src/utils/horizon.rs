@@ -0,0 +1,276 @@
+//! The sky as seen from a place on Earth
+//!
+//! Converts between equatorial coordinates (RA/Dec) and horizontal ones
+//! (altitude above the horizon, azimuth from north through east) for an
+//! [`Observer`] at a moment in time. The moment enters only through the
+//! local sidereal time (see [`super::time`]), so a [`LocalSky`] can be
+//! built for any time and checked without a clock.
+//!
+//! Refraction, precession and the observer's height are ignored; they
+//! move a star by well under a degree, which is plenty for deciding what
+//! is up tonight.
+
+use super::time::local_sidereal_time;
+use crate::data::CelestialCoord;
+use serde::{Deserialize, Serialize};
+
+/// A place on Earth, in degrees
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Observer {
+    /// Degrees north of the equator, -90 to 90
+    pub latitude: f64,
+    /// Degrees east of Greenwich, -180 to 180
+    pub longitude: f64,
+}
+
+impl Observer {
+    /// An observer, or `None` if either angle is out of range
+    pub fn new(latitude: f64, longitude: f64) -> Option<Self> {
+        ((-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude)).then_some(
+            Self {
+                latitude,
+                longitude,
+            },
+        )
+    }
+}
+
+/// A direction in the observer's sky, in degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HorizontalCoord {
+    /// Degrees above the horizon; negative below it
+    pub altitude: f64,
+    /// Degrees from north through east, 0 to 360
+    pub azimuth: f64,
+}
+
+/// A compass point on the horizon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cardinal {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Cardinal {
+    pub const ALL: [Cardinal; 4] = [
+        Cardinal::North,
+        Cardinal::East,
+        Cardinal::South,
+        Cardinal::West,
+    ];
+
+    /// Azimuth in degrees
+    pub fn azimuth(&self) -> f64 {
+        match self {
+            Cardinal::North => 0.0,
+            Cardinal::East => 90.0,
+            Cardinal::South => 180.0,
+            Cardinal::West => 270.0,
+        }
+    }
+}
+
+/// The sky of one observer at one moment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocalSky {
+    pub observer: Observer,
+    /// Local sidereal time in hours: the RA on the meridian
+    pub sidereal_time: f64,
+}
+
+impl LocalSky {
+    /// The sky over `observer` at a Unix time in seconds
+    pub fn at(observer: Observer, unix_seconds: f64) -> Self {
+        Self {
+            observer,
+            sidereal_time: local_sidereal_time(unix_seconds, observer.longitude),
+        }
+    }
+
+    /// The sky coordinate straight overhead
+    pub fn zenith(&self) -> CelestialCoord {
+        CelestialCoord::new_wrapped(self.sidereal_time, self.observer.latitude)
+    }
+
+    /// Where `coord` appears in this sky
+    pub fn to_horizontal(&self, coord: &CelestialCoord) -> HorizontalCoord {
+        let hour_angle = ((self.sidereal_time - coord.ra) * 15.0).to_radians();
+        let dec = coord.dec.to_radians();
+        let lat = self.observer.latitude.to_radians();
+
+        let altitude = (dec.sin() * lat.sin() + dec.cos() * lat.cos() * hour_angle.cos())
+            .clamp(-1.0, 1.0)
+            .asin();
+        let azimuth = (-dec.cos() * hour_angle.sin())
+            .atan2(dec.sin() * lat.cos() - dec.cos() * lat.sin() * hour_angle.cos());
+        HorizontalCoord {
+            altitude: altitude.to_degrees(),
+            azimuth: azimuth.to_degrees().rem_euclid(360.0),
+        }
+    }
+
+    /// The sky coordinate in a direction of this sky
+    pub fn to_celestial(&self, direction: &HorizontalCoord) -> CelestialCoord {
+        let alt = direction.altitude.to_radians();
+        let az = direction.azimuth.to_radians();
+        let lat = self.observer.latitude.to_radians();
+
+        let dec = (alt.sin() * lat.sin() + alt.cos() * lat.cos() * az.cos())
+            .clamp(-1.0, 1.0)
+            .asin();
+        let hour_angle =
+            (-az.sin() * alt.cos()).atan2(alt.sin() * lat.cos() - alt.cos() * lat.sin() * az.cos());
+        CelestialCoord::new_wrapped(
+            self.sidereal_time - hour_angle.to_degrees() / 15.0,
+            dec.to_degrees(),
+        )
+    }
+
+    /// Whether `coord` is above the horizon
+    pub fn is_visible(&self, coord: &CelestialCoord) -> bool {
+        self.to_horizontal(coord).altitude > 0.0
+    }
+
+    /// `samples` evenly spaced points along the horizon, from north
+    /// through east
+    pub fn horizon_line(&self, samples: usize) -> Vec<CelestialCoord> {
+        (0..samples)
+            .map(|i| {
+                self.to_celestial(&HorizontalCoord {
+                    altitude: 0.0,
+                    azimuth: 360.0 * i as f64 / samples as f64,
+                })
+            })
+            .collect()
+    }
+
+    /// The sky coordinate of a compass point on the horizon
+    pub fn cardinal_point(&self, cardinal: Cardinal) -> CelestialCoord {
+        self.to_celestial(&HorizontalCoord {
+            altitude: 0.0,
+            azimuth: cardinal.azimuth(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POLARIS: CelestialCoord = CelestialCoord {
+        ra: 2.53,
+        dec: 89.26,
+    };
+    const CANOPUS: CelestialCoord = CelestialCoord {
+        ra: 6.40,
+        dec: -52.70,
+    };
+    const SIRIUS: CelestialCoord = CelestialCoord {
+        ra: 6.75,
+        dec: -16.72,
+    };
+
+    fn at_latitude(latitude: f64, sidereal_time: f64) -> LocalSky {
+        LocalSky {
+            observer: Observer::new(latitude, 0.0).unwrap(),
+            sidereal_time,
+        }
+    }
+
+    /// Sidereal times around the clock, every 20 minutes
+    fn sidereal_day() -> impl Iterator<Item = f64> {
+        (0..72).map(|i| i as f64 / 3.0)
+    }
+
+    #[test]
+    fn test_polaris_is_always_up_from_50_north() {
+        for lst in sidereal_day() {
+            let sky = at_latitude(50.0, lst);
+            assert!(sky.is_visible(&POLARIS), "LST {}", lst);
+            let altitude = sky.to_horizontal(&POLARIS).altitude;
+            assert!((altitude - 50.0).abs() < 1.0, "{}", altitude);
+        }
+    }
+
+    #[test]
+    fn test_canopus_is_never_up_from_50_north() {
+        for lst in sidereal_day() {
+            assert!(!at_latitude(50.0, lst).is_visible(&CANOPUS), "LST {}", lst);
+        }
+        // ... but it is from the southern hemisphere
+        assert!(at_latitude(-35.0, CANOPUS.ra).is_visible(&CANOPUS));
+    }
+
+    #[test]
+    fn test_a_star_on_the_meridian_is_due_south() {
+        // Sirius culminates at 90 - 50 - 16.72 degrees, due south
+        let position = at_latitude(50.0, SIRIUS.ra).to_horizontal(&SIRIUS);
+        assert!((position.altitude - 23.28).abs() < 1e-6);
+        assert!((position.azimuth - 180.0).abs() < 1e-6);
+
+        // Six hours later it has set in the west
+        let later = at_latitude(50.0, SIRIUS.ra + 6.0).to_horizontal(&SIRIUS);
+        assert!(later.altitude < 0.0);
+        assert!((180.0..360.0).contains(&later.azimuth));
+    }
+
+    #[test]
+    fn test_conversion_roundtrip() {
+        let sky = LocalSky::at(Observer::new(37.4, -122.1).unwrap(), 1_735_689_600.0);
+        for (ra, dec) in [(0.0, 0.0), (5.5, 45.0), (13.2, -60.0), (23.9, 10.0)] {
+            let coord = CelestialCoord::new(ra, dec);
+            let back = sky.to_celestial(&sky.to_horizontal(&coord));
+            let ra_error = (back.ra - ra + 12.0).rem_euclid(24.0) - 12.0;
+            assert!(ra_error.abs() < 1e-9, "{:?} -> {:?}", coord, back);
+            assert!((back.dec - dec).abs() < 1e-9, "{:?} -> {:?}", coord, back);
+        }
+    }
+
+    #[test]
+    fn test_zenith_is_straight_up() {
+        let sky = LocalSky::at(Observer::new(-33.9, 18.4).unwrap(), 1_735_689_600.0);
+        let zenith = sky.zenith();
+        assert!((zenith.ra - sky.sidereal_time).abs() < 1e-9);
+        assert!((zenith.dec + 33.9).abs() < 1e-9);
+        assert!((sky.to_horizontal(&zenith).altitude - 90.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_horizon_line_is_at_zero_altitude() {
+        let sky = at_latitude(50.0, 7.0);
+        let line = sky.horizon_line(36);
+        assert_eq!(line.len(), 36);
+        for (i, coord) in line.iter().enumerate() {
+            let position = sky.to_horizontal(coord);
+            assert!(position.altitude.abs() < 1e-9, "{:?}", position);
+            assert!((position.azimuth - 10.0 * i as f64).abs() < 1e-6);
+        }
+        // The horizon reaches dec 90 - 50 in the north and its negative in the south
+        assert!((line[0].dec - 40.0).abs() < 1e-9);
+        assert!((line[18].dec + 40.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cardinal_points() {
+        let sky = at_latitude(50.0, 7.0);
+        let north = sky.cardinal_point(Cardinal::North);
+        assert!((north.dec - 40.0).abs() < 1e-9);
+        assert!((north.ra - 19.0).abs() < 1e-9);
+        // East and west are on the equator, six hours either side of the meridian
+        let east = sky.cardinal_point(Cardinal::East);
+        let west = sky.cardinal_point(Cardinal::West);
+        assert!(east.dec.abs() < 1e-9 && west.dec.abs() < 1e-9);
+        assert!((east.ra - 13.0).abs() < 1e-9);
+        assert!((west.ra - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_observer_range() {
+        assert!(Observer::new(90.0, -180.0).is_some());
+        assert_eq!(Observer::new(91.0, 0.0), None);
+        assert_eq!(Observer::new(0.0, 180.5), None);
+        assert_eq!(Observer::new(f64::NAN, 0.0), None);
+    }
+}
@@ -0,0 +1,250 @@
+//! Star label policy
+//!
+//! Decides which named stars get a text label at a given zoom level, and
+//! where each label goes so that labels do not overlap.
+
+use serde::{Deserialize, Serialize};
+
+/// How much label text to draw on the map
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LabelDensity {
+    /// No labels at all
+    Off,
+    /// Only the brightest stars
+    Sparse,
+    /// Balanced default
+    #[default]
+    Normal,
+    /// Label as many stars as the zoom allows
+    Dense,
+}
+
+impl LabelDensity {
+    /// All densities, from least to most text
+    pub const ALL: [LabelDensity; 4] = [
+        LabelDensity::Off,
+        LabelDensity::Sparse,
+        LabelDensity::Normal,
+        LabelDensity::Dense,
+    ];
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            LabelDensity::Off => "Off",
+            LabelDensity::Sparse => "Sparse",
+            LabelDensity::Normal => "Normal",
+            LabelDensity::Dense => "Dense",
+        }
+    }
+
+    /// Magnitude offset applied to the label threshold
+    fn magnitude_offset(&self) -> Option<f64> {
+        match self {
+            LabelDensity::Off => None,
+            LabelDensity::Sparse => Some(-1.0),
+            LabelDensity::Normal => Some(0.0),
+            LabelDensity::Dense => Some(1.5),
+        }
+    }
+}
+
+/// Level-of-detail policy for star labels
+#[derive(Debug, Clone, Copy)]
+pub struct LabelLod {
+    /// Faintest magnitude labeled at zoom 1.0 (Normal density)
+    pub base_magnitude: f64,
+    /// Additional magnitude depth per zoom level
+    pub magnitude_per_zoom: f64,
+}
+
+impl Default for LabelLod {
+    fn default() -> Self {
+        Self {
+            base_magnitude: 1.0,
+            magnitude_per_zoom: 0.4,
+        }
+    }
+}
+
+impl LabelLod {
+    /// Faintest magnitude that gets a label, or `None` when labels are off
+    pub fn magnitude_threshold(&self, zoom: f64, density: LabelDensity) -> Option<f64> {
+        let offset = density.magnitude_offset()?;
+        Some(self.base_magnitude + (zoom - 1.0) * self.magnitude_per_zoom + offset)
+    }
+
+    /// Whether a star of this magnitude should be labeled
+    pub fn should_label(&self, magnitude: f64, zoom: f64, density: LabelDensity) -> bool {
+        self.magnitude_threshold(zoom, density)
+            .is_some_and(|limit| magnitude <= limit)
+    }
+}
+
+/// Approximate advance of one character in the map's 10px monospace font
+pub const LABEL_CHAR_WIDTH: f64 = 6.0;
+
+/// Extent of label text above and below its baseline
+const LABEL_ASCENT: f64 = 8.0;
+const LABEL_DESCENT: f64 = 2.0;
+
+/// Gap between a star's symbol and its label
+const LABEL_GAP: f64 = 3.0;
+
+/// A label to place beside a star, in screen pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LabelRequest {
+    /// The star's center
+    pub x: f64,
+    pub y: f64,
+    /// The radius of the star's symbol
+    pub radius: f64,
+    /// Length of the label text in characters
+    pub chars: usize,
+}
+
+/// The area a label's text covers
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct LabelBox {
+    left: f64,
+    top: f64,
+    right: f64,
+    bottom: f64,
+}
+
+impl LabelBox {
+    /// The box of text starting at `(x, y)` on its baseline
+    fn at(x: f64, y: f64, width: f64) -> Self {
+        Self {
+            left: x,
+            top: y - LABEL_ASCENT,
+            right: x + width,
+            bottom: y + LABEL_DESCENT,
+        }
+    }
+
+    fn overlaps(&self, other: &LabelBox) -> bool {
+        self.left < other.right
+            && other.left < self.right
+            && self.top < other.bottom
+            && other.top < self.bottom
+    }
+}
+
+impl LabelRequest {
+    /// Where the label's text may start, on its baseline, most preferred
+    /// first: right of the star, then left, above and below
+    fn spots(&self) -> [(f64, f64); 4] {
+        let width = self.chars as f64 * LABEL_CHAR_WIDTH;
+        let offset = self.radius + LABEL_GAP;
+        [
+            (self.x + offset, self.y + LABEL_GAP),
+            (self.x - offset - width, self.y + LABEL_GAP),
+            (self.x - width / 2.0, self.y - offset - LABEL_DESCENT),
+            (self.x - width / 2.0, self.y + offset + LABEL_ASCENT),
+        ]
+    }
+}
+
+/// Place labels one at a time, each at the first of its spots clear of
+/// the labels already placed
+///
+/// Requests come in priority order, so earlier (brighter) stars keep
+/// their labels when space runs out. Each result is the start of the
+/// label's baseline, or `None` when every spot was taken.
+pub fn place_labels(requests: &[LabelRequest]) -> Vec<Option<(f64, f64)>> {
+    let mut placed: Vec<LabelBox> = Vec::with_capacity(requests.len());
+    requests
+        .iter()
+        .map(|request| {
+            let width = request.chars as f64 * LABEL_CHAR_WIDTH;
+            let (x, y, bounds) = request
+                .spots()
+                .into_iter()
+                .map(|(x, y)| (x, y, LabelBox::at(x, y, width)))
+                .find(|(_, _, bounds)| !placed.iter().any(|other| other.overlaps(bounds)))?;
+            placed.push(bounds);
+            Some((x, y))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(x: f64, y: f64, chars: usize) -> LabelRequest {
+        LabelRequest {
+            x,
+            y,
+            radius: 2.0,
+            chars,
+        }
+    }
+
+    #[test]
+    fn test_lone_label_sits_right_of_its_star() {
+        assert_eq!(
+            place_labels(&[request(100.0, 50.0, 4)]),
+            vec![Some((105.0, 53.0))]
+        );
+    }
+
+    #[test]
+    fn test_crowded_labels_move_aside_or_drop() {
+        // Five stars on one spot: four spots to share, the last is dropped
+        let requests = [request(100.0, 50.0, 6); 5];
+        let placed = place_labels(&requests);
+        assert!(placed[..4].iter().all(Option::is_some));
+        assert_eq!(placed[4], None);
+
+        // Every placed label is clear of the others
+        let boxes: Vec<LabelBox> = placed
+            .iter()
+            .flatten()
+            .map(|&(x, y)| LabelBox::at(x, y, 6.0 * LABEL_CHAR_WIDTH))
+            .collect();
+        for (i, a) in boxes.iter().enumerate() {
+            for b in &boxes[i + 1..] {
+                assert!(!a.overlaps(b), "{:?} overlaps {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_distant_labels_keep_their_first_spot() {
+        let placed = place_labels(&[request(100.0, 50.0, 8), request(100.0, 90.0, 8)]);
+        assert_eq!(placed, vec![Some((105.0, 53.0)), Some((105.0, 93.0))]);
+    }
+
+    #[test]
+    fn test_off_never_labels() {
+        let lod = LabelLod::default();
+        assert!(!lod.should_label(-1.5, 50.0, LabelDensity::Off));
+        assert!(lod.magnitude_threshold(10.0, LabelDensity::Off).is_none());
+    }
+
+    #[test]
+    fn test_density_is_monotonic() {
+        let lod = LabelLod::default();
+        for zoom in [1.0, 2.0, 5.0, 10.0, 25.0, 50.0] {
+            let mut mag = -1.5;
+            while mag <= 6.5 {
+                if lod.should_label(mag, zoom, LabelDensity::Sparse) {
+                    assert!(lod.should_label(mag, zoom, LabelDensity::Normal));
+                }
+                if lod.should_label(mag, zoom, LabelDensity::Normal) {
+                    assert!(lod.should_label(mag, zoom, LabelDensity::Dense));
+                }
+                mag += 0.25;
+            }
+        }
+    }
+
+    #[test]
+    fn test_more_labels_when_zoomed_in() {
+        let lod = LabelLod::default();
+        assert!(!lod.should_label(3.0, 1.0, LabelDensity::Normal));
+        assert!(lod.should_label(3.0, 10.0, LabelDensity::Normal));
+    }
+}
@@ -0,0 +1,50 @@
+//! Responsive layout selection
+//!
+//! Decides which page layout to use from the available width.
+
+/// Widths at or below this use the compact layout (matches the CSS media query)
+pub const COMPACT_BREAKPOINT: f64 = 768.0;
+
+/// Page layout variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutMode {
+    /// Map with a fixed sidebar and floating quiz dropdown
+    Wide,
+    /// Full-width map with a drawer and bottom-anchored quiz panel
+    Compact,
+}
+
+impl LayoutMode {
+    /// Whether this is the compact variant
+    pub fn is_compact(&self) -> bool {
+        matches!(self, LayoutMode::Compact)
+    }
+}
+
+/// Choose the layout for a given window width in CSS pixels
+pub fn layout_for_width(width: f64) -> LayoutMode {
+    if width <= COMPACT_BREAKPOINT {
+        LayoutMode::Compact
+    } else {
+        LayoutMode::Wide
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_for_width() {
+        assert_eq!(layout_for_width(375.0), LayoutMode::Compact);
+        assert_eq!(layout_for_width(COMPACT_BREAKPOINT), LayoutMode::Compact);
+        assert_eq!(layout_for_width(COMPACT_BREAKPOINT + 1.0), LayoutMode::Wide);
+        assert_eq!(layout_for_width(1920.0), LayoutMode::Wide);
+    }
+
+    #[test]
+    fn test_is_compact() {
+        assert!(LayoutMode::Compact.is_compact());
+        assert!(!LayoutMode::Wide.is_compact());
+    }
+}
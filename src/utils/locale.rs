@@ -0,0 +1,51 @@
+//! Interface languages
+//!
+//! The chosen language is a saved preference; the strings for each one
+//! live with the UI in `app::i18n`.
+
+use serde::{Deserialize, Serialize};
+
+/// A language the interface is translated into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Locale {
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "es")]
+    Spanish,
+}
+
+impl Locale {
+    /// All locales, in the order the language picker shows them
+    pub const ALL: [Locale; 2] = [Locale::English, Locale::Spanish];
+
+    /// BCP 47 language tag, as used in the `lang` attribute
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::Spanish => "es",
+        }
+    }
+
+    /// The language's name in that language
+    pub fn name(&self) -> &'static str {
+        match self {
+            Locale::English => "English",
+            Locale::Spanish => "Español",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serializes_as_language_code() {
+        for locale in Locale::ALL {
+            let json = serde_json::to_string(&locale).unwrap();
+            assert_eq!(json, format!("\"{}\"", locale.code()));
+            assert_eq!(serde_json::from_str::<Locale>(&json).unwrap(), locale);
+        }
+    }
+}
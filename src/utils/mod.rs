@@ -1,5 +1,28 @@
 //! Utility modules for the Stargazer application
 
+pub mod ascii_render;
+pub mod dates;
+#[cfg(feature = "web")]
+pub mod download;
+pub mod horizon;
+pub mod labels;
+pub mod layout;
+pub mod locale;
+pub mod perf;
 pub mod projection;
+pub mod sexagesimal;
+pub mod sky_index;
+pub mod sky_svg;
+pub mod time;
 
-pub use projection::{LodSettings, Projection, ScreenCoord, Viewport};
+#[cfg(feature = "web")]
+pub use download::ExportFormat;
+pub use horizon::{LocalSky, Observer};
+pub use labels::{LabelDensity, LabelLod};
+pub use layout::{layout_for_width, LayoutMode};
+pub use locale::Locale;
+pub use projection::{
+    LodSettings, ProjectResult, Projection, ProjectionKind, ScreenCoord, StereographicProjection,
+    UnprojectError, Viewport, MAX_ZOOM, MIN_ZOOM,
+};
+pub use sky_index::SkyIndex;
@@ -0,0 +1,225 @@
+//! Performance metrics for the debug overlay
+//!
+//! The star map records how long it spent preparing each frame's star
+//! list, and the app records every action it dispatches. Both go into
+//! fixed-size [`RingBuffer`]s so collecting metrics costs the same
+//! however long the page stays open.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+/// Render samples kept for averaging
+pub const RENDER_HISTORY: usize = 30;
+
+/// Dispatched actions kept for the overlay
+pub const ACTION_HISTORY: usize = 5;
+
+/// A fixed-capacity queue that drops its oldest item when full
+#[derive(Debug, Clone, PartialEq)]
+pub struct RingBuffer<T> {
+    items: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T> RingBuffer<T> {
+    /// An empty buffer holding at most `capacity` items (at least one)
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            items: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Add `item`, evicting the oldest if the buffer is full
+    pub fn push(&mut self, item: T) {
+        if self.items.len() == self.capacity {
+            self.items.pop_front();
+        }
+        self.items.push_back(item);
+    }
+
+    /// Items from oldest to newest
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.items.iter()
+    }
+
+    /// The most recently pushed item
+    pub fn latest(&self) -> Option<&T> {
+        self.items.back()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+/// One star-map render
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RenderSample {
+    pub visible_stars: usize,
+    /// Time spent preparing the star list, in milliseconds
+    pub duration_ms: f64,
+}
+
+/// Recent renders and actions
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerfLog {
+    renders: RingBuffer<RenderSample>,
+    actions: RingBuffer<String>,
+    render_count: u64,
+}
+
+impl Default for PerfLog {
+    fn default() -> Self {
+        Self {
+            renders: RingBuffer::new(RENDER_HISTORY),
+            actions: RingBuffer::new(ACTION_HISTORY),
+            render_count: 0,
+        }
+    }
+}
+
+impl PerfLog {
+    pub fn record_render(&mut self, sample: RenderSample) {
+        self.renders.push(sample);
+        self.render_count += 1;
+    }
+
+    pub fn record_action(&mut self, action: String) {
+        self.actions.push(action);
+    }
+
+    /// Renders recorded since the log was created
+    pub fn render_count(&self) -> u64 {
+        self.render_count
+    }
+
+    pub fn last_render(&self) -> Option<RenderSample> {
+        self.renders.latest().copied()
+    }
+
+    /// Mean render duration over the kept samples
+    pub fn average_render_ms(&self) -> Option<f64> {
+        if self.renders.is_empty() {
+            return None;
+        }
+        let total: f64 = self.renders.iter().map(|s| s.duration_ms).sum();
+        Some(total / self.renders.len() as f64)
+    }
+
+    /// Recent actions, newest first
+    pub fn recent_actions(&self) -> Vec<String> {
+        self.actions.iter().rev().cloned().collect()
+    }
+}
+
+thread_local! {
+    static PERF_LOG: RefCell<PerfLog> = RefCell::new(PerfLog::default());
+}
+
+/// Record a star-map render in the page's log
+pub fn record_render(visible_stars: usize, duration_ms: f64) {
+    PERF_LOG.with(|log| {
+        log.borrow_mut().record_render(RenderSample {
+            visible_stars,
+            duration_ms,
+        })
+    });
+}
+
+/// Record a dispatched action in the page's log
+pub fn record_action(action: String) {
+    PERF_LOG.with(|log| log.borrow_mut().record_action(action));
+}
+
+/// A copy of the page's log
+pub fn snapshot() -> PerfLog {
+    PERF_LOG.with(|log| log.borrow().clone())
+}
+
+/// Recent actions in the page's log, newest first
+///
+/// Empty if the log is in use or already torn down, so the panic hook
+/// can call this whatever was running when it fired.
+pub fn try_recent_actions() -> Vec<String> {
+    PERF_LOG
+        .try_with(|log| {
+            log.try_borrow()
+                .map(|log| log.recent_actions())
+                .unwrap_or_default()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_buffer_evicts_oldest() {
+        let mut buffer = RingBuffer::new(3);
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.latest(), None);
+
+        for i in 1..=5 {
+            buffer.push(i);
+        }
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert_eq!(buffer.latest(), Some(&5));
+    }
+
+    #[test]
+    fn test_ring_buffer_zero_capacity_holds_one() {
+        let mut buffer = RingBuffer::new(0);
+        buffer.push("a");
+        buffer.push("b");
+        assert_eq!(buffer.capacity(), 1);
+        assert_eq!(buffer.iter().collect::<Vec<_>>(), vec![&"b"]);
+    }
+
+    #[test]
+    fn test_perf_log() {
+        let mut log = PerfLog::default();
+        assert_eq!(log.average_render_ms(), None);
+
+        log.record_render(RenderSample {
+            visible_stars: 100,
+            duration_ms: 2.0,
+        });
+        log.record_render(RenderSample {
+            visible_stars: 120,
+            duration_ms: 4.0,
+        });
+        assert_eq!(log.last_render().unwrap().visible_stars, 120);
+        assert_eq!(log.render_count(), 2);
+        assert_eq!(log.average_render_ms(), Some(3.0));
+
+        for i in 0..7 {
+            log.record_action(format!("SetZoom({})", i));
+        }
+        let actions = log.recent_actions();
+        assert_eq!(actions.len(), ACTION_HISTORY);
+        assert_eq!(actions[0], "SetZoom(6)");
+        assert_eq!(actions[4], "SetZoom(2)");
+    }
+
+    #[test]
+    fn test_page_log() {
+        record_action("ZoomIn".to_string());
+        record_render(42, 1.5);
+        let log = snapshot();
+        assert_eq!(log.recent_actions()[0], "ZoomIn");
+        assert_eq!(try_recent_actions(), log.recent_actions());
+        assert_eq!(log.last_render().unwrap().visible_stars, 42);
+    }
+}
@@ -1,9 +1,29 @@
 //! Coordinate projection utilities
 //!
-//! Handles transformation between celestial coordinates and screen coordinates
-//! using equirectangular (plate carrée) projection for the proof of concept.
+//! Handles transformation between celestial coordinates and screen coordinates.
+//! A [`Viewport`] uses the equirectangular (plate carrée) projection unless its
+//! [`ProjectionKind`] says otherwise; the [`StereographicProjection`] keeps
+//! shapes true around the view center, including near the poles.
+//!
+//! [`Viewport::project`] and [`Viewport::unproject`] report points off
+//! screen, off the sky or not finite; the [`Projection`] trait methods are
+//! kept as thin wrappers over them.
 
 use crate::data::CelestialCoord;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Minimum zoom level (full sky)
+pub const MIN_ZOOM: f64 = 1.0;
+
+/// Maximum zoom level
+pub const MAX_ZOOM: f64 = 50.0;
+
+/// Most pans [`Viewport::zoom_by`] makes to bring its anchor back
+const ANCHOR_STEPS: usize = 4;
+
+/// How far from its anchor, in pixels, a zoom may leave the point
+const ANCHOR_TOLERANCE: f64 = 0.5;
 
 /// Screen/viewport coordinates
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -23,10 +43,56 @@ impl ScreenCoord {
         let dy = self.y - other.y;
         (dx * dx + dy * dy).sqrt()
     }
+
+    /// Whether both coordinates are finite
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
 }
 
-/// Viewport definition for the star map
+/// Where a celestial coordinate lands in a viewport
 #[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProjectResult {
+    /// Whether `point` is finite and within the viewport, edges included
+    pub on_screen: bool,
+    /// Projected position, which may lie outside the viewport, or be
+    /// infinite for the point opposite a stereographic view's center
+    pub point: ScreenCoord,
+}
+
+/// Why a screen point has no celestial coordinate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnprojectError {
+    /// The point is beyond a celestial pole
+    OutOfSky,
+    /// The point, or the viewport, is NaN or infinite
+    NonFinite,
+}
+
+impl fmt::Display for UnprojectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnprojectError::OutOfSky => write!(f, "point is beyond a celestial pole"),
+            UnprojectError::NonFinite => write!(f, "point is not finite"),
+        }
+    }
+}
+
+impl std::error::Error for UnprojectError {}
+
+/// How a viewport flattens the sky onto the screen
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectionKind {
+    /// RA and Dec map straight to x and y; stretches the polar regions
+    #[default]
+    Equirectangular,
+    /// See [`StereographicProjection`]
+    Stereographic,
+}
+
+/// Viewport definition for the star map
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Viewport {
     /// Width of the viewport in pixels
     pub width: f64,
@@ -38,6 +104,8 @@ pub struct Viewport {
     pub center_dec: f64,
     /// Zoom level (1.0 = full sky, higher = zoomed in)
     pub zoom: f64,
+    /// How the sky is flattened onto the screen
+    pub projection: ProjectionKind,
 }
 
 impl Viewport {
@@ -49,6 +117,7 @@ impl Viewport {
             center_ra: 12.0, // Default to center of RA range
             center_dec: 0.0, // Default to celestial equator
             zoom: 1.0,
+            projection: ProjectionKind::Equirectangular,
         }
     }
 
@@ -64,6 +133,9 @@ impl Viewport {
 
     /// Get the RA range visible in this viewport
     pub fn ra_range(&self) -> (f64, f64) {
+        if self.projection == ProjectionKind::Stereographic {
+            return StereographicProjection::new(*self).ra_range();
+        }
         let half_fov = self.fov_ra() / 2.0;
         let min = (self.center_ra - half_fov + 24.0) % 24.0;
         let max = (self.center_ra + half_fov) % 24.0;
@@ -72,59 +144,28 @@ impl Viewport {
 
     /// Get the Dec range visible in this viewport
     pub fn dec_range(&self) -> (f64, f64) {
+        if self.projection == ProjectionKind::Stereographic {
+            return StereographicProjection::new(*self).dec_range();
+        }
         let half_fov = self.fov_dec() / 2.0;
         let min = (self.center_dec - half_fov).clamp(-90.0, 90.0);
         let max = (self.center_dec + half_fov).clamp(-90.0, 90.0);
         (min, max)
     }
 
-    /// Pan the viewport by a delta in pixels
-    pub fn pan(&mut self, dx: f64, dy: f64) {
-        // Convert pixel delta to coordinate delta
-        let ra_per_pixel = self.fov_ra() / self.width;
-        let dec_per_pixel = self.fov_dec() / self.height;
-
-        // Note: RA increases to the left (west), so we negate dx
-        self.center_ra = (self.center_ra - dx * ra_per_pixel + 24.0) % 24.0;
-        self.center_dec = (self.center_dec + dy * dec_per_pixel).clamp(-90.0, 90.0);
+    /// Whether `point` lies within the viewport, edges included
+    pub fn contains(&self, point: ScreenCoord) -> bool {
+        (0.0..=self.width).contains(&point.x) && (0.0..=self.height).contains(&point.y)
     }
 
-    /// Zoom by a factor, optionally around a point
-    pub fn zoom_by(&mut self, factor: f64, anchor: Option<ScreenCoord>) {
-        let _old_zoom = self.zoom;
-        self.zoom = (self.zoom * factor).clamp(1.0, 50.0);
-
-        // If anchor provided, adjust center to keep that point stationary
-        if let Some(anchor) = anchor {
-            let anchor_coord = self.screen_to_celestial(anchor);
-            if let Some(coord) = anchor_coord {
-                // Recalculate offset after zoom
-                let new_screen = self.celestial_to_screen(&coord);
-                let dx = anchor.x - new_screen.x;
-                let dy = anchor.y - new_screen.y;
-                self.pan(-dx, -dy);
-            }
+    /// Project `coord` to the screen, noting whether it lands in view
+    ///
+    /// Coordinates that are not finite never land in view.
+    pub fn project(&self, coord: &CelestialCoord) -> ProjectResult {
+        if self.projection == ProjectionKind::Stereographic {
+            return StereographicProjection::new(*self).project(coord);
         }
-    }
-}
-
-impl Default for Viewport {
-    fn default() -> Self {
-        Self::new(1200.0, 600.0)
-    }
-}
-
-/// Projection trait for converting between coordinate systems
-pub trait Projection {
-    /// Convert celestial coordinates to screen coordinates
-    fn celestial_to_screen(&self, coord: &CelestialCoord) -> ScreenCoord;
-
-    /// Convert screen coordinates to celestial coordinates
-    fn screen_to_celestial(&self, screen: ScreenCoord) -> Option<CelestialCoord>;
-}
 
-impl Projection for Viewport {
-    fn celestial_to_screen(&self, coord: &CelestialCoord) -> ScreenCoord {
         // Equirectangular projection
         let (ra_min, _) = self.ra_range();
         let (_dec_min, dec_max) = self.dec_range();
@@ -155,28 +196,237 @@ impl Projection for Viewport {
         let dec_offset = dec_max - coord.dec;
         let y = (dec_offset / self.fov_dec()) * self.height;
 
-        ScreenCoord::new(x, y)
+        let point = ScreenCoord::new(x, y);
+        ProjectResult {
+            on_screen: self.contains(point),
+            point,
+        }
     }
 
-    fn screen_to_celestial(&self, screen: ScreenCoord) -> Option<CelestialCoord> {
+    /// The celestial coordinate under a screen point
+    pub fn unproject(&self, screen: ScreenCoord) -> Result<CelestialCoord, UnprojectError> {
+        if !screen.x.is_finite() || !screen.y.is_finite() {
+            return Err(UnprojectError::NonFinite);
+        }
+        if self.projection == ProjectionKind::Stereographic {
+            return StereographicProjection::new(*self).unproject(screen);
+        }
         let (ra_min, _) = self.ra_range();
         let (_, dec_max) = self.dec_range();
 
-        // Inverse of celestial_to_screen
+        // Inverse of project
         let ra_offset = (screen.x / self.width) * self.fov_ra();
         let ra = (ra_min + ra_offset + 24.0) % 24.0;
 
         let dec_offset = (screen.y / self.height) * self.fov_dec();
         let dec = dec_max - dec_offset;
 
-        if (-90.0..=90.0).contains(&dec) {
-            Some(CelestialCoord::new_wrapped(ra, dec))
+        if !ra.is_finite() || !dec.is_finite() {
+            Err(UnprojectError::NonFinite)
+        } else if !(-90.0..=90.0).contains(&dec) {
+            Err(UnprojectError::OutOfSky)
+        } else {
+            Ok(CelestialCoord::new_wrapped(ra, dec))
+        }
+    }
+
+    /// Whether a coordinate projects into the central `fraction` of the viewport
+    ///
+    /// A fraction of 0.6 means the middle 60% of both width and height.
+    pub fn is_in_central_region(&self, coord: &CelestialCoord, fraction: f64) -> bool {
+        let screen = self.project(coord).point;
+        let margin_x = self.width * (1.0 - fraction) / 2.0;
+        let margin_y = self.height * (1.0 - fraction) / 2.0;
+
+        screen.x >= margin_x
+            && screen.x <= self.width - margin_x
+            && screen.y >= margin_y
+            && screen.y <= self.height - margin_y
+    }
+
+    /// Pan the viewport by a delta in pixels
+    pub fn pan(&mut self, dx: f64, dy: f64) {
+        // Convert pixel delta to coordinate delta
+        let ra_per_pixel = self.fov_ra() / self.width;
+        let dec_per_pixel = self.fov_dec() / self.height;
+
+        // Note: RA increases to the left (west), so we negate dx
+        self.center_ra = (self.center_ra - dx * ra_per_pixel + 24.0) % 24.0;
+        self.center_dec = (self.center_dec + dy * dec_per_pixel).clamp(-90.0, 90.0);
+    }
+
+    /// Zoom by a factor, optionally keeping the sky under a screen point
+    /// in place
+    pub fn zoom_by(&mut self, factor: f64, anchor: Option<ScreenCoord>) {
+        // The sky under the anchor, found before the zoom moves it
+        let anchored = anchor.and_then(|point| Some((point, self.unproject(point).ok()?)));
+        self.zoom = (self.zoom * factor).clamp(MIN_ZOOM, MAX_ZOOM);
+
+        if let Some((anchor, coord)) = anchored {
+            // Panning back is exact on the flat map; on the round one the
+            // pan is linear and the drift shrinks with each step
+            for _ in 0..ANCHOR_STEPS {
+                let drifted = self.project(&coord).point;
+                let (dx, dy) = (anchor.x - drifted.x, anchor.y - drifted.y);
+                if dx.abs() < ANCHOR_TOLERANCE && dy.abs() < ANCHOR_TOLERANCE {
+                    break;
+                }
+                self.pan(dx, dy);
+            }
+        }
+    }
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self::new(1200.0, 600.0)
+    }
+}
+
+/// Projection trait for converting between coordinate systems
+///
+/// For [`Viewport`], prefer [`Viewport::project`] and
+/// [`Viewport::unproject`], which say why a point has no useful result.
+pub trait Projection {
+    /// Convert celestial coordinates to screen coordinates
+    fn celestial_to_screen(&self, coord: &CelestialCoord) -> ScreenCoord;
+
+    /// Convert screen coordinates to celestial coordinates
+    fn screen_to_celestial(&self, screen: ScreenCoord) -> Option<CelestialCoord>;
+}
+
+impl Projection for Viewport {
+    fn celestial_to_screen(&self, coord: &CelestialCoord) -> ScreenCoord {
+        self.project(coord).point
+    }
+
+    fn screen_to_celestial(&self, screen: ScreenCoord) -> Option<CelestialCoord> {
+        self.unproject(screen).ok()
+    }
+}
+
+/// Stereographic projection about a viewport's center
+///
+/// Circles on the sky stay circles on the screen, so constellations keep
+/// their shape at any declination. The view's vertical field of view is
+/// the same angle as in the equirectangular projection, measured from the
+/// center; only the point opposite the center cannot be drawn.
+///
+/// A [`Viewport`] projects through this when its [`ProjectionKind`] is
+/// `Stereographic`; used directly, it ignores the viewport's kind.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StereographicProjection {
+    pub viewport: Viewport,
+}
+
+impl StereographicProjection {
+    pub fn new(viewport: Viewport) -> Self {
+        Self { viewport }
+    }
+
+    /// Pixels per unit of the projection plane, where a point `c` radians
+    /// from the center lies `2 tan(c / 2)` from it
+    fn scale(&self) -> f64 {
+        let half_fov = (self.viewport.fov_dec() / 2.0).to_radians();
+        self.viewport.height / (4.0 * (half_fov / 2.0).tan())
+    }
+
+    /// Angular distance in degrees from the center to the view's corners
+    fn radius(&self) -> f64 {
+        let corner = (self.viewport.width / 2.0).hypot(self.viewport.height / 2.0);
+        (2.0 * (corner / self.scale() / 2.0).atan()).to_degrees()
+    }
+
+    /// A RA range covering the view: all of it when a pole is in view
+    pub fn ra_range(&self) -> (f64, f64) {
+        let vp = &self.viewport;
+        let radius = self.radius();
+        if radius >= 90.0 - vp.center_dec.abs() {
+            return (vp.center_ra, vp.center_ra);
+        }
+        let half_width = (radius.to_radians().sin() / vp.center_dec.to_radians().cos())
+            .asin()
+            .to_degrees()
+            / 15.0;
+        (
+            (vp.center_ra - half_width).rem_euclid(24.0),
+            (vp.center_ra + half_width).rem_euclid(24.0),
+        )
+    }
+
+    /// A Dec range covering the view
+    pub fn dec_range(&self) -> (f64, f64) {
+        let radius = self.radius();
+        (
+            (self.viewport.center_dec - radius).max(-90.0),
+            (self.viewport.center_dec + radius).min(90.0),
+        )
+    }
+
+    /// Project `coord`, noting whether it lands in view
+    pub fn project(&self, coord: &CelestialCoord) -> ProjectResult {
+        let vp = &self.viewport;
+        let d_ra = ((coord.ra - vp.center_ra) * 15.0).to_radians();
+        let (sin_dec, cos_dec) = coord.dec.to_radians().sin_cos();
+        let (sin_dec0, cos_dec0) = vp.center_dec.to_radians().sin_cos();
+
+        let cos_c = sin_dec0 * sin_dec + cos_dec0 * cos_dec * d_ra.cos();
+        let k = 2.0 / (1.0 + cos_c) * self.scale();
+        // RA increases to the right, as in the equirectangular projection
+        let x = k * cos_dec * d_ra.sin();
+        let y = k * (cos_dec0 * sin_dec - sin_dec0 * cos_dec * d_ra.cos());
+
+        let point = ScreenCoord::new(vp.width / 2.0 + x, vp.height / 2.0 - y);
+        ProjectResult {
+            on_screen: vp.contains(point),
+            point,
+        }
+    }
+
+    /// The celestial coordinate under a screen point
+    ///
+    /// Every finite point of the plane is on the sky.
+    pub fn unproject(&self, screen: ScreenCoord) -> Result<CelestialCoord, UnprojectError> {
+        let vp = &self.viewport;
+        let scale = self.scale();
+        let x = (screen.x - vp.width / 2.0) / scale;
+        let y = (vp.height / 2.0 - screen.y) / scale;
+        let rho = x.hypot(y);
+        let c = 2.0 * (rho / 2.0).atan();
+        let (sin_c, cos_c) = c.sin_cos();
+        let (sin_dec0, cos_dec0) = vp.center_dec.to_radians().sin_cos();
+
+        let (dec, d_ra) = if rho == 0.0 {
+            (vp.center_dec.to_radians(), 0.0)
+        } else {
+            (
+                (cos_c * sin_dec0 + y * sin_c * cos_dec0 / rho)
+                    .clamp(-1.0, 1.0)
+                    .asin(),
+                (x * sin_c).atan2(rho * cos_dec0 * cos_c - y * sin_dec0 * sin_c),
+            )
+        };
+        let ra = vp.center_ra + d_ra.to_degrees() / 15.0;
+        let dec = dec.to_degrees();
+
+        if !ra.is_finite() || !dec.is_finite() {
+            Err(UnprojectError::NonFinite)
         } else {
-            None
+            Ok(CelestialCoord::new_wrapped(ra, dec))
         }
     }
 }
 
+impl Projection for StereographicProjection {
+    fn celestial_to_screen(&self, coord: &CelestialCoord) -> ScreenCoord {
+        self.project(coord).point
+    }
+
+    fn screen_to_celestial(&self, screen: ScreenCoord) -> Option<CelestialCoord> {
+        self.unproject(screen).ok()
+    }
+}
+
 /// Level-of-detail settings for progressive rendering
 #[derive(Debug, Clone, Copy)]
 pub struct LodSettings {
@@ -206,9 +456,34 @@ impl LodSettings {
     }
 }
 
+/// A stereographic viewport on `center_ra`, `center_dec` at `zoom`, for tests
+#[cfg(test)]
+pub(crate) fn stereographic(center_ra: f64, center_dec: f64, zoom: f64) -> Viewport {
+    Viewport {
+        center_ra,
+        center_dec,
+        zoom,
+        projection: ProjectionKind::Stereographic,
+        ..Viewport::default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    const NON_FINITE: [f64; 3] = [f64::NAN, f64::INFINITY, f64::NEG_INFINITY];
+
+    fn random_viewport(rng: &mut SmallRng) -> Viewport {
+        Viewport {
+            center_ra: rng.gen_range(0.0..24.0),
+            center_dec: rng.gen_range(-90.0..=90.0),
+            zoom: rng.gen_range(MIN_ZOOM..=MAX_ZOOM),
+            ..Viewport::new(rng.gen_range(1.0..2000.0), rng.gen_range(1.0..2000.0))
+        }
+    }
 
     #[test]
     fn test_viewport_creation() {
@@ -229,6 +504,213 @@ mod tests {
         assert!((original.dec - back.dec).abs() < 0.01);
     }
 
+    #[test]
+    fn test_on_screen_agrees_with_bounds() {
+        let mut rng = SmallRng::seed_from_u64(17);
+        let mut seen = (0, 0);
+        for _ in 0..5000 {
+            let vp = random_viewport(&mut rng);
+            // Around the view, past the edges and sometimes past a pole
+            let coord = CelestialCoord {
+                ra: vp.center_ra + rng.gen_range(-1.0..1.0) * vp.fov_ra(),
+                dec: vp.center_dec + rng.gen_range(-1.0..1.0) * vp.fov_dec(),
+            };
+            let projected = vp.project(&coord);
+            let in_bounds = projected.point.x >= 0.0
+                && projected.point.x <= vp.width
+                && projected.point.y >= 0.0
+                && projected.point.y <= vp.height;
+            assert_eq!(projected.on_screen, in_bounds, "{:?} in {:?}", coord, vp);
+
+            if projected.on_screen {
+                seen.0 += 1;
+                if (-90.0..=90.0).contains(&coord.dec) {
+                    assert!(vp.unproject(projected.point).is_ok(), "{:?}", coord);
+                }
+            } else {
+                seen.1 += 1;
+            }
+        }
+        // Both outcomes were exercised
+        assert!(seen.0 > 100 && seen.1 > 100, "{:?}", seen);
+    }
+
+    #[test]
+    fn test_unproject_in_view_is_on_the_sky_or_past_a_pole() {
+        let mut rng = SmallRng::seed_from_u64(23);
+        for _ in 0..5000 {
+            let vp = random_viewport(&mut rng);
+            let screen = ScreenCoord::new(
+                rng.gen_range(0.0..=vp.width),
+                rng.gen_range(0.0..=vp.height),
+            );
+            match vp.unproject(screen) {
+                Ok(coord) => {
+                    assert!((0.0..24.0).contains(&coord.ra), "{:?}", coord);
+                    assert!((-90.0..=90.0).contains(&coord.dec), "{:?}", coord);
+                }
+                Err(e) => assert_eq!(e, UnprojectError::OutOfSky, "{:?} in {:?}", screen, vp),
+            }
+        }
+    }
+
+    #[test]
+    fn test_non_finite_inputs() {
+        let vp = Viewport::default();
+        for bad in NON_FINITE {
+            for (a, b) in [(bad, 0.0), (0.0, bad), (bad, bad)] {
+                let projected = vp.project(&CelestialCoord { ra: a, dec: b });
+                assert!(!projected.on_screen);
+                assert_eq!(
+                    vp.unproject(ScreenCoord::new(a, b)),
+                    Err(UnprojectError::NonFinite)
+                );
+                assert_eq!(vp.screen_to_celestial(ScreenCoord::new(a, b)), None);
+            }
+        }
+
+        let broken = Viewport {
+            zoom: f64::NAN,
+            ..Viewport::default()
+        };
+        assert!(!broken.project(&CelestialCoord::new(12.0, 0.0)).on_screen);
+        assert_eq!(
+            broken.unproject(ScreenCoord::new(10.0, 10.0)),
+            Err(UnprojectError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn test_unproject_beyond_the_poles() {
+        let vp = Viewport::default();
+        assert_eq!(
+            vp.unproject(ScreenCoord::new(100.0, -1.0)),
+            Err(UnprojectError::OutOfSky)
+        );
+        assert_eq!(
+            vp.unproject(ScreenCoord::new(100.0, vp.height + 1.0)),
+            Err(UnprojectError::OutOfSky)
+        );
+        // Off the side of the view is still on the sky
+        assert!(vp.unproject(ScreenCoord::new(-50.0, 300.0)).is_ok());
+    }
+
+    #[test]
+    fn test_stereographic_roundtrip() {
+        let mut rng = SmallRng::seed_from_u64(29);
+        for _ in 0..5000 {
+            let vp = Viewport {
+                projection: ProjectionKind::Stereographic,
+                ..random_viewport(&mut rng)
+            };
+            let screen = ScreenCoord::new(
+                rng.gen_range(0.0..=vp.width),
+                rng.gen_range(0.0..=vp.height),
+            );
+            let coord = vp.unproject(screen).expect("the whole plane is on the sky");
+            let back = vp.project(&coord);
+            assert!(back.on_screen, "{:?} -> {:?} in {:?}", screen, coord, vp);
+            assert!(back.point.distance(&screen) < 1e-6, "{:?}", back);
+        }
+    }
+
+    #[test]
+    fn test_stereographic_ranges_cover_the_view() {
+        let mut rng = SmallRng::seed_from_u64(31);
+        for _ in 0..5000 {
+            let vp = Viewport {
+                projection: ProjectionKind::Stereographic,
+                ..random_viewport(&mut rng)
+            };
+            let (ra_min, ra_max) = vp.ra_range();
+            let (dec_min, dec_max) = vp.dec_range();
+            let screen = ScreenCoord::new(
+                rng.gen_range(0.0..=vp.width),
+                rng.gen_range(0.0..=vp.height),
+            );
+            let coord = vp.unproject(screen).unwrap();
+            let ra_ok = if (ra_max - ra_min).abs() < 1e-9 {
+                true
+            } else if ra_min <= ra_max {
+                (ra_min - 1e-9..=ra_max + 1e-9).contains(&coord.ra)
+            } else {
+                coord.ra >= ra_min - 1e-9 || coord.ra <= ra_max + 1e-9
+            };
+            assert!(
+                ra_ok,
+                "{:?} outside RA {}..{} in {:?}",
+                coord, ra_min, ra_max, vp
+            );
+            assert!(
+                (dec_min - 1e-9..=dec_max + 1e-9).contains(&coord.dec),
+                "{:?} outside Dec {}..{} in {:?}",
+                coord,
+                dec_min,
+                dec_max,
+                vp
+            );
+        }
+    }
+
+    #[test]
+    fn test_stereographic_keeps_the_pole_round() {
+        // Looking at the pole, a circle of declination is a circle on screen
+        let vp = stereographic(0.0, 90.0, 2.0);
+        let center = ScreenCoord::new(vp.width / 2.0, vp.height / 2.0);
+        assert!(
+            vp.project(&CelestialCoord::new(7.0, 90.0))
+                .point
+                .distance(&center)
+                < 1e-9
+        );
+
+        let radii: Vec<f64> = (0..24)
+            .map(|hour| {
+                let projected = vp.project(&CelestialCoord::new(hour as f64, 70.0));
+                assert!(projected.on_screen);
+                projected.point.distance(&center)
+            })
+            .collect();
+        assert!(
+            radii.iter().all(|r| (r - radii[0]).abs() < 1e-9),
+            "{:?}",
+            radii
+        );
+        assert_eq!(vp.ra_range().0, vp.ra_range().1);
+    }
+
+    #[test]
+    fn test_stereographic_matches_equirectangular_at_the_center() {
+        // Zoomed in on the equator the two projections nearly agree
+        let flat = Viewport {
+            center_ra: 6.0,
+            zoom: 40.0,
+            ..Viewport::default()
+        };
+        let round = stereographic(6.0, 0.0, 40.0);
+        let coord = CelestialCoord::new(6.05, 1.0);
+        let distance = flat
+            .project(&coord)
+            .point
+            .distance(&round.project(&coord).point);
+        assert!(distance < 1.0, "{}", distance);
+        assert_eq!(
+            round.project(&CelestialCoord::new(6.0, 0.0)).point,
+            ScreenCoord::new(round.width / 2.0, round.height / 2.0)
+        );
+    }
+
+    #[test]
+    fn test_stereographic_antipode_is_off_screen() {
+        let vp = stereographic(6.0, 20.0, 1.0);
+        let projected = vp.project(&CelestialCoord::new(18.0, -20.0));
+        assert!(!projected.on_screen);
+        // The projection trait agrees with the viewport
+        let direct = StereographicProjection::new(vp);
+        let coord = CelestialCoord::new(4.0, 35.0);
+        assert_eq!(direct.celestial_to_screen(&coord), vp.project(&coord).point);
+    }
+
     #[test]
     fn test_viewport_pan() {
         let mut vp = Viewport::default();
@@ -249,6 +731,61 @@ mod tests {
         assert_eq!(vp.zoom, 50.0);
     }
 
+    #[test]
+    fn test_zoom_keeps_the_anchor_in_place() {
+        for projection in [
+            ProjectionKind::Equirectangular,
+            ProjectionKind::Stereographic,
+        ] {
+            let mut vp = Viewport {
+                center_ra: 5.5,
+                center_dec: 20.0,
+                zoom: 3.0,
+                projection,
+                ..Viewport::default()
+            };
+            let anchor = ScreenCoord::new(900.0, 150.0);
+            let under = vp.unproject(anchor).unwrap();
+
+            vp.zoom_by(1.2, Some(anchor));
+            assert!((vp.zoom - 3.6).abs() < 1e-9);
+            let moved = vp.project(&under).point;
+            assert!(
+                (moved.x - anchor.x).abs() < 1.0,
+                "{:?}: {:?}",
+                projection,
+                moved
+            );
+            assert!(
+                (moved.y - anchor.y).abs() < 1.0,
+                "{:?}: {:?}",
+                projection,
+                moved
+            );
+
+            // Zooming out about the same point undoes it
+            vp.zoom_by(1.0 / 1.2, Some(anchor));
+            assert!((vp.center_ra - 5.5).abs() < 0.01, "{:?}", projection);
+            assert!((vp.center_dec - 20.0).abs() < 0.1, "{:?}", projection);
+        }
+    }
+
+    #[test]
+    fn test_central_region() {
+        let mut vp = Viewport::default();
+        vp.zoom_by(4.0, None);
+
+        // The view center is always central
+        let center = CelestialCoord::new(vp.center_ra, vp.center_dec);
+        assert!(vp.is_in_central_region(&center, 0.6));
+
+        // Near the left edge of the view is not
+        let (ra_min, _) = vp.ra_range();
+        let edge = CelestialCoord::new(ra_min + 0.1, vp.center_dec);
+        assert!(!vp.is_in_central_region(&edge, 0.6));
+        assert!(vp.is_in_central_region(&edge, 1.0));
+    }
+
     #[test]
     fn test_lod_settings() {
         let lod = LodSettings::default();
@@ -0,0 +1,149 @@
+//! Sexagesimal coordinate formatting and parsing
+//!
+//! Renders right ascension as hours/minutes/seconds and declination as
+//! degrees/arcminutes/arcseconds, and reads either those forms or plain
+//! decimal values back.
+
+/// Format right ascension in hours as `06h 45m 08.9s`
+pub fn format_ra_hms(ra_hours: f64) -> String {
+    let total_tenths = (ra_hours.rem_euclid(24.0) * 36_000.0).round() as u64 % (24 * 36_000);
+    let hours = total_tenths / 36_000;
+    let minutes = (total_tenths / 600) % 60;
+    let tenths = total_tenths % 600;
+    format!(
+        "{:02}h {:02}m {:02}.{}s",
+        hours,
+        minutes,
+        tenths / 10,
+        tenths % 10
+    )
+}
+
+/// Format declination in degrees as `-16° 42′ 58″`
+pub fn format_dec_dms(dec_degrees: f64) -> String {
+    let sign = if dec_degrees < 0.0 { '-' } else { '+' };
+    let total_seconds = (dec_degrees.abs() * 3600.0).round() as u64;
+    format!(
+        "{}{:02}° {:02}′ {:02}″",
+        sign,
+        total_seconds / 3600,
+        (total_seconds / 60) % 60,
+        total_seconds % 60
+    )
+}
+
+/// Parse right ascension in hours
+///
+/// Accepts decimal hours (`6.75`) or sexagesimal forms such as
+/// `6h45m08.9s`, `06:45:08.9` or `6 45 8.9`.
+pub fn parse_ra(input: &str) -> Result<f64, String> {
+    let (negative, parts) = split_sexagesimal(input, &['h', 'm', 's'])?;
+    if negative {
+        return Err(format!("RA cannot be negative: {}", input));
+    }
+    let hours = combine(&parts);
+    if !(0.0..24.0).contains(&hours) {
+        return Err(format!("RA must be in 0..24h: {}", input));
+    }
+    Ok(hours)
+}
+
+/// Parse declination in degrees
+///
+/// Accepts decimal degrees (`-16.7`) or sexagesimal forms such as
+/// `-16°42′58″`, `-16d42m58s`, `-16:42:58` or `-16 42 58`.
+pub fn parse_dec(input: &str) -> Result<f64, String> {
+    let (negative, parts) = split_sexagesimal(input, &['°', 'd', '′', '\'', 'm', '″', '"', 's'])?;
+    let degrees = combine(&parts);
+    if degrees > 90.0 {
+        return Err(format!("Dec must be in -90..90°: {}", input));
+    }
+    Ok(if negative { -degrees } else { degrees })
+}
+
+/// Split a coordinate into its sign and up to three numeric components
+fn split_sexagesimal(input: &str, unit_marks: &[char]) -> Result<(bool, Vec<f64>), String> {
+    let trimmed = input.trim();
+    let (negative, body) = match trimmed.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+    };
+
+    let parts: Vec<f64> = body
+        .split(|c: char| c == ':' || c.is_whitespace() || unit_marks.contains(&c))
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<f64>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| format!("invalid coordinate: {}", input))?;
+
+    if parts.is_empty() || parts.len() > 3 {
+        return Err(format!("invalid coordinate: {}", input));
+    }
+    // Leading components must be whole; minutes and seconds must be below 60
+    let (last, leading) = parts.split_last().unwrap_or((&0.0, &[]));
+    if leading.iter().any(|p| p.fract() != 0.0 || *p < 0.0)
+        || (!leading.is_empty() && !(0.0..60.0).contains(last))
+        || leading.iter().skip(1).any(|p| *p >= 60.0)
+    {
+        return Err(format!("invalid coordinate: {}", input));
+    }
+
+    Ok((negative, parts))
+}
+
+/// Combine units, minutes and seconds into a decimal value
+fn combine(parts: &[f64]) -> f64 {
+    parts
+        .iter()
+        .zip([1.0, 60.0, 3600.0])
+        .map(|(value, divisor)| value / divisor)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_ra() {
+        assert_eq!(format_ra_hms(0.0), "00h 00m 00.0s");
+        assert_eq!(format_ra_hms(6.752481), "06h 45m 08.9s");
+        // Rounding up to 24h wraps to zero
+        assert_eq!(format_ra_hms(23.999_999_9), "00h 00m 00.0s");
+    }
+
+    #[test]
+    fn test_format_dec() {
+        assert_eq!(format_dec_dms(-16.716116), "-16° 42′ 58″");
+        assert_eq!(format_dec_dms(38.783692), "+38° 47′ 01″");
+        assert_eq!(format_dec_dms(0.0), "+00° 00′ 00″");
+    }
+
+    #[test]
+    fn test_parse_ra() {
+        assert_eq!(parse_ra("6.75").unwrap(), 6.75);
+        assert!((parse_ra("6h45m00s").unwrap() - 6.75).abs() < 1e-9);
+        assert!((parse_ra("06:45:00").unwrap() - 6.75).abs() < 1e-9);
+        assert!((parse_ra("6 45").unwrap() - 6.75).abs() < 1e-9);
+        assert!(parse_ra("24").is_err());
+        assert!(parse_ra("-1").is_err());
+        assert!(parse_ra("6:75:00").is_err());
+        assert!(parse_ra("six").is_err());
+    }
+
+    #[test]
+    fn test_parse_dec() {
+        assert_eq!(parse_dec("-16.7").unwrap(), -16.7);
+        assert!((parse_dec("-16°42′00″").unwrap() + 16.7).abs() < 1e-9);
+        assert!((parse_dec("-16d42m").unwrap() + 16.7).abs() < 1e-9);
+        assert!((parse_dec("+38:47:01").unwrap() - 38.783_611).abs() < 1e-5);
+        assert!(parse_dec("91").is_err());
+        assert!(parse_dec("-90").is_ok());
+    }
+
+    #[test]
+    fn test_format_parse_round_trip() {
+        let dec = parse_dec(&format_dec_dms(-16.716116)).unwrap();
+        assert!((dec + 16.716116).abs() < 1.0 / 3600.0);
+    }
+}
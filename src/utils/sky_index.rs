@@ -0,0 +1,235 @@
+//! Spatial index over a catalog
+//!
+//! With the default `tiles` feature, [`SkyIndex`] holds the catalog's
+//! [`TileSystem`](crate::data::TileSystem): quiz distractors come from
+//! around the target star, and the star map only considers stars in
+//! on-screen tiles. Without it the index is empty, quizzes use random
+//! distractors and the map scans the whole catalog, so a build for a tiny
+//! catalog carries no tile code. Callers build and pass a `SkyIndex` the
+//! same way either way.
+
+#[cfg(feature = "tiles")]
+use crate::data::{
+    tile_view::{in_range, TileView},
+    StarId, TileId, TileSystem,
+};
+use crate::data::{Star, StarCatalog};
+use crate::utils::Viewport;
+#[cfg(not(feature = "tiles"))]
+use std::convert::Infallible;
+
+/// Spatial lookups over one catalog's stars
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkyIndex {
+    #[cfg(feature = "tiles")]
+    tiles: TileSystem,
+    #[cfg(feature = "tiles")]
+    view: TileView,
+}
+
+/// The part of the index a view overlaps
+///
+/// Equal cells give equal [`Candidates`], so the map memoizes on these.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewCells {
+    #[cfg(feature = "tiles")]
+    tiles: Vec<TileId>,
+    #[cfg(not(feature = "tiles"))]
+    never: Infallible,
+}
+
+/// Stars that could be in a view: a superset of the stars in its range
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidates {
+    #[cfg(feature = "tiles")]
+    stars: Vec<StarId>,
+    #[cfg(not(feature = "tiles"))]
+    never: Infallible,
+}
+
+#[cfg(feature = "tiles")]
+impl SkyIndex {
+    /// Index `catalog`'s stars
+    pub fn new(catalog: &StarCatalog) -> Self {
+        let stars: Vec<Star> = catalog.all_stars().cloned().collect();
+        let tiles = TileSystem::from_stars(&stars);
+        Self {
+            view: TileView::new(catalog, &tiles),
+            tiles,
+        }
+    }
+
+    /// The tiles behind the index
+    pub fn tiles(&self) -> &TileSystem {
+        &self.tiles
+    }
+
+    /// Cells overlapping the range, or `None` when the index cannot rule
+    /// out any stars and the whole catalog should be scanned
+    pub fn cells_in_range(
+        &self,
+        ra_min: f64,
+        ra_max: f64,
+        dec_min: f64,
+        dec_max: f64,
+    ) -> Option<ViewCells> {
+        let tiles = self.view.tiles_in_range(ra_min, ra_max, dec_min, dec_max);
+        (tiles.len() < self.view.len()).then_some(ViewCells { tiles })
+    }
+
+    /// Stars in `cells`
+    pub fn candidates(&self, cells: &ViewCells) -> Candidates {
+        Candidates {
+            stars: self.view.candidates(&cells.tiles),
+        }
+    }
+}
+
+#[cfg(feature = "tiles")]
+impl Candidates {
+    /// The candidates in the range, by the rules of
+    /// [`StarCatalog::stars_in_range`]
+    pub fn in_range<'a>(
+        &self,
+        catalog: &'a StarCatalog,
+        ra_min: f64,
+        ra_max: f64,
+        dec_min: f64,
+        dec_max: f64,
+        max_magnitude: f64,
+    ) -> Vec<&'a Star> {
+        self.stars
+            .iter()
+            .filter_map(|&id| catalog.get(id))
+            .filter(|star| in_range(star, ra_min, ra_max, dec_min, dec_max, max_magnitude))
+            .collect()
+    }
+}
+
+/// Without tiles the index holds nothing and never narrows a view, so
+/// no [`ViewCells`] or [`Candidates`] can exist
+#[cfg(not(feature = "tiles"))]
+impl SkyIndex {
+    pub fn new(_catalog: &StarCatalog) -> Self {
+        Self {}
+    }
+
+    pub fn cells_in_range(
+        &self,
+        _ra_min: f64,
+        _ra_max: f64,
+        _dec_min: f64,
+        _dec_max: f64,
+    ) -> Option<ViewCells> {
+        None
+    }
+
+    pub fn candidates(&self, cells: &ViewCells) -> Candidates {
+        match cells.never {}
+    }
+}
+
+#[cfg(not(feature = "tiles"))]
+impl Candidates {
+    pub fn in_range<'a>(
+        &self,
+        _catalog: &'a StarCatalog,
+        _ra_min: f64,
+        _ra_max: f64,
+        _dec_min: f64,
+        _dec_max: f64,
+        _max_magnitude: f64,
+    ) -> Vec<&'a Star> {
+        match self.never {}
+    }
+}
+
+impl SkyIndex {
+    /// Stars in `viewport` no fainter than `max_magnitude`
+    ///
+    /// Reads only the cells the view overlaps, and falls back to scanning
+    /// the catalog when the index cannot narrow the view. `catalog` must
+    /// be the catalog the index was built from.
+    pub fn query_viewport<'a>(
+        &self,
+        catalog: &'a StarCatalog,
+        viewport: &Viewport,
+        max_magnitude: f64,
+    ) -> Vec<&'a Star> {
+        let (ra_min, ra_max) = viewport.ra_range();
+        let (dec_min, dec_max) = viewport.dec_range();
+        let candidates = self
+            .cells_in_range(ra_min, ra_max, dec_min, dec_max)
+            .map(|cells| self.candidates(&cells));
+        match &candidates {
+            Some(candidates) => {
+                candidates.in_range(catalog, ra_min, ra_max, dec_min, dec_max, max_magnitude)
+            }
+            None => catalog.stars_in_range(ra_min, ra_max, dec_min, dec_max, max_magnitude),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::generate_placeholder_catalog;
+
+    #[test]
+    #[cfg(feature = "tiles")]
+    fn test_narrowed_view_matches_catalog_scan() {
+        let catalog = generate_placeholder_catalog();
+        let index = SkyIndex::new(&catalog);
+        let cells = index
+            .cells_in_range(5.0, 6.5, -10.0, 10.0)
+            .expect("a small range rules out some tiles");
+        let found = index
+            .candidates(&cells)
+            .in_range(&catalog, 5.0, 6.5, -10.0, 10.0, 6.5);
+        let expected = catalog.stars_in_range(5.0, 6.5, -10.0, 10.0, 6.5);
+        assert_eq!(found.len(), expected.len());
+        assert!(found.iter().all(|star| expected.contains(star)));
+    }
+
+    #[test]
+    #[cfg(not(feature = "tiles"))]
+    fn test_nothing_is_narrowed_without_tiles() {
+        let index = SkyIndex::new(&generate_placeholder_catalog());
+        assert_eq!(index.cells_in_range(5.0, 6.5, -10.0, 10.0), None);
+    }
+
+    #[test]
+    fn test_query_viewport_matches_catalog_scan() {
+        let catalog = generate_placeholder_catalog();
+        let index = SkyIndex::new(&catalog);
+        for (ra, dec, zoom) in [(6.0, 0.0, 4.0), (23.8, 30.0, 8.0), (12.0, -80.0, 2.0)] {
+            let viewport = Viewport {
+                center_ra: ra,
+                center_dec: dec,
+                zoom,
+                ..Viewport::new(1200.0, 600.0)
+            };
+            let mut found: Vec<u32> = index
+                .query_viewport(&catalog, &viewport, 6.0)
+                .iter()
+                .map(|star| star.id.0)
+                .collect();
+            let (ra_min, ra_max) = viewport.ra_range();
+            let (dec_min, dec_max) = viewport.dec_range();
+            let mut expected: Vec<u32> = catalog
+                .stars_in_range(ra_min, ra_max, dec_min, dec_max, 6.0)
+                .iter()
+                .map(|star| star.id.0)
+                .collect();
+            found.sort_unstable();
+            expected.sort_unstable();
+            assert_eq!(found, expected, "view at {}h {}°", ra, dec);
+        }
+    }
+
+    #[test]
+    fn test_whole_sky_is_scanned() {
+        let index = SkyIndex::new(&generate_placeholder_catalog());
+        assert_eq!(index.cells_in_range(0.0, 0.0, -90.0, 90.0), None);
+    }
+}
@@ -0,0 +1,1252 @@
+//! Sky map drawing shared by the web map and exports
+//!
+//! Grid lines, star styles and label placement are computed here as plain
+//! data, so the Yew component, the standalone SVG export and the ASCII
+//! renderer all draw the same map.
+
+use crate::data::constellations::{figure_segments, FigureSegment};
+use crate::data::dso::{DsoKind, MESSIER};
+use crate::data::star_names::{star_name, Lang};
+use crate::data::{CelestialCoord, Star, StarCatalog, StarId};
+use crate::utils::horizon::{Cardinal, LocalSky};
+use crate::utils::labels::{place_labels, LabelRequest};
+use crate::utils::{LabelDensity, LabelLod, ProjectionKind, ScreenCoord, Viewport};
+use std::f64::consts::TAU;
+use std::fmt::Write;
+
+/// A single grid line in screen coordinates
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridLine {
+    /// Stable key for diffing
+    pub key: String,
+    pub x1: f64,
+    pub y1: f64,
+    pub x2: f64,
+    pub y2: f64,
+    /// Stroke color
+    pub stroke: &'static str,
+    /// Stroke width
+    pub width: &'static str,
+}
+
+/// Compute the RA/Dec grid lines for a viewport
+pub fn grid_lines(viewport: &Viewport) -> Vec<GridLine> {
+    if viewport.projection == ProjectionKind::Stereographic {
+        return curved_grid_lines(viewport);
+    }
+    let mut lines = Vec::new();
+
+    // RA lines (every hour at zoom 1, more at higher zooms)
+    let ra_step = (2.0 / viewport.zoom).max(0.5);
+    let mut ra = 0.0;
+    while ra < 24.0 {
+        // A meridian is in view where it crosses the view's center line
+        let crossing = viewport.project(&CelestialCoord::new(ra, viewport.center_dec));
+        if crossing.on_screen {
+            let screen_top = viewport.project(&CelestialCoord::new(ra, 90.0)).point;
+            let screen_bot = viewport.project(&CelestialCoord::new(ra, -90.0)).point;
+            lines.push(GridLine {
+                key: format!("ra-{}", ra),
+                x1: screen_top.x,
+                y1: screen_top.y,
+                x2: screen_bot.x,
+                y2: screen_bot.y,
+                stroke: GRID_STROKE,
+                width: "1",
+            });
+        }
+        ra += ra_step;
+    }
+
+    // Dec lines (every 10 degrees at zoom 1, more at higher zooms)
+    let dec_step = (30.0 / viewport.zoom).max(5.0);
+    let mut dec = -80.0_f64;
+    while dec <= 80.0 {
+        let screen_left = viewport.project(&CelestialCoord::new(0.0, dec)).point;
+        let screen_right = viewport.project(&CelestialCoord::new(24.0, dec)).point;
+
+        // Celestial equator gets special treatment
+        let is_equator = dec.abs() < 0.1;
+
+        lines.push(GridLine {
+            key: format!("dec-{}", dec),
+            x1: 0.0,
+            y1: screen_left.y,
+            x2: viewport.width,
+            y2: screen_right.y,
+            stroke: if is_equator {
+                EQUATOR_STROKE
+            } else {
+                GRID_STROKE
+            },
+            width: if is_equator { "2" } else { "1" },
+        });
+        dec += dec_step;
+    }
+
+    lines
+}
+
+/// Degrees between the points a curved grid line is drawn through
+const GRID_SAMPLE_DEGREES: f64 = 5.0;
+
+/// Whether the straight line from `a` to `b` is worth drawing: it may
+/// cross the view, and is not an artifact of the projection
+///
+/// Near the point opposite a stereographic view's center, lines run off
+/// toward infinity, so any end far outside the view rules a line out. An
+/// equirectangular line longer than half the view wraps around the map.
+fn drawable_line(viewport: &Viewport, a: ScreenCoord, b: ScreenCoord) -> bool {
+    let margin = viewport.width.max(viewport.height);
+    let near_view = |p: &ScreenCoord| {
+        p.is_finite()
+            && (-margin..=viewport.width + margin).contains(&p.x)
+            && (-margin..=viewport.height + margin).contains(&p.y)
+    };
+    let crosses_view = a.x.min(b.x) <= viewport.width
+        && a.x.max(b.x) >= 0.0
+        && a.y.min(b.y) <= viewport.height
+        && a.y.max(b.y) >= 0.0;
+    let wraps = viewport.projection == ProjectionKind::Equirectangular
+        && (a.x - b.x).abs() > viewport.width / 2.0;
+    near_view(&a) && near_view(&b) && crosses_view && !wraps
+}
+
+/// Grid lines where meridians and parallels curve, each drawn as short
+/// straight pieces; pieces that miss the view are left out
+fn curved_grid_lines(viewport: &Viewport) -> Vec<GridLine> {
+    let mut lines = Vec::new();
+    let mut add_curve = |key: String, coords: Vec<CelestialCoord>, stroke, width| {
+        let points: Vec<ScreenCoord> = coords.iter().map(|c| viewport.project(c).point).collect();
+        for (i, pair) in points.windows(2).enumerate() {
+            let (a, b) = (pair[0], pair[1]);
+            if drawable_line(viewport, a, b) {
+                lines.push(GridLine {
+                    key: format!("{}-{}", key, i),
+                    x1: a.x,
+                    y1: a.y,
+                    x2: b.x,
+                    y2: b.y,
+                    stroke,
+                    width,
+                });
+            }
+        }
+    };
+
+    let ra_step = (2.0 / viewport.zoom).max(0.5);
+    let mut ra = 0.0;
+    while ra < 24.0 {
+        let samples = (180.0 / GRID_SAMPLE_DEGREES) as usize;
+        let coords = (0..=samples)
+            .map(|i| CelestialCoord::new(ra, -90.0 + i as f64 * GRID_SAMPLE_DEGREES))
+            .collect();
+        add_curve(format!("ra-{}", ra), coords, GRID_STROKE, "1");
+        ra += ra_step;
+    }
+
+    let dec_step = (30.0 / viewport.zoom).max(5.0);
+    let mut dec = -80.0_f64;
+    while dec <= 80.0 {
+        let samples = (360.0 / GRID_SAMPLE_DEGREES) as usize;
+        let coords = (0..=samples)
+            .map(|i| CelestialCoord::new_wrapped(i as f64 * GRID_SAMPLE_DEGREES / 15.0, dec))
+            .collect();
+        let is_equator = dec.abs() < 0.1;
+        if is_equator {
+            add_curve(format!("dec-{}", dec), coords, EQUATOR_STROKE, "2");
+        } else {
+            add_curve(format!("dec-{}", dec), coords, GRID_STROKE, "1");
+        }
+        dec += dec_step;
+    }
+
+    lines
+}
+
+/// A constellation figure line in screen coordinates
+#[derive(Debug, Clone, PartialEq)]
+pub struct FigureLine {
+    /// Stable key for diffing
+    pub key: String,
+    pub x1: f64,
+    pub y1: f64,
+    pub x2: f64,
+    pub y2: f64,
+}
+
+/// The constellation figure lines that cross a viewport
+pub fn figure_lines(viewport: &Viewport, segments: &[FigureSegment]) -> Vec<FigureLine> {
+    segments
+        .iter()
+        .enumerate()
+        .filter_map(|(i, segment)| {
+            let from = viewport.project(&segment.from).point;
+            let to = viewport.project(&segment.to).point;
+            drawable_line(viewport, from, to).then(|| FigureLine {
+                key: format!("{}-{}", segment.constellation, i),
+                x1: from.x,
+                y1: from.y,
+                x2: to.x,
+                y2: to.y,
+            })
+        })
+        .collect()
+}
+
+/// Points sampled along the horizon by [`horizon_overlay`]
+const HORIZON_SAMPLES: usize = 180;
+
+/// The horizon and compass points of a local sky, in screen coordinates
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HorizonOverlay {
+    /// Runs of the horizon line, each drawn as one polyline; the line
+    /// breaks where it wraps around the edge of the map
+    pub segments: Vec<Vec<ScreenCoord>>,
+    /// The compass points that are in view
+    pub cardinals: Vec<(Cardinal, ScreenCoord)>,
+}
+
+/// The horizon line and compass points of `sky` in a viewport
+pub fn horizon_overlay(viewport: &Viewport, sky: &LocalSky) -> HorizonOverlay {
+    let mut line = sky.horizon_line(HORIZON_SAMPLES);
+    // Close the loop
+    line.extend(line.first().copied());
+
+    let mut segments: Vec<Vec<ScreenCoord>> = Vec::new();
+    let mut run: Vec<ScreenCoord> = Vec::new();
+    let mut previous: Option<(CelestialCoord, ScreenCoord)> = None;
+    for coord in line {
+        let point = viewport.project(&coord).point;
+        if !point.is_finite() {
+            segments.push(std::mem::take(&mut run));
+            previous = None;
+            continue;
+        }
+        if let Some((last_coord, last_point)) = previous {
+            let breaks = match viewport.projection {
+                // Where the projection wraps, the screen step is far from
+                // the step the shortest way round in RA
+                ProjectionKind::Equirectangular => {
+                    let ra_step = (coord.ra - last_coord.ra + 12.0).rem_euclid(24.0) - 12.0;
+                    let expected_dx = ra_step / viewport.fov_ra() * viewport.width;
+                    (point.x - last_point.x - expected_dx).abs() > viewport.width / 2.0
+                }
+                // Nothing wraps, but the line leaps across the screen where
+                // it passes the point opposite the view's center
+                ProjectionKind::Stereographic => {
+                    point.distance(&last_point) > viewport.width.max(viewport.height)
+                }
+            };
+            if breaks {
+                segments.push(std::mem::take(&mut run));
+            }
+        }
+        run.push(point);
+        previous = Some((coord, point));
+    }
+    segments.push(run);
+    segments.retain(|segment| segment.len() > 1);
+
+    let cardinals = Cardinal::ALL
+        .into_iter()
+        .filter_map(|cardinal| {
+            let projected = viewport.project(&sky.cardinal_point(cardinal));
+            projected.on_screen.then_some((cardinal, projected.point))
+        })
+        .collect();
+
+    HorizonOverlay {
+        segments,
+        cardinals,
+    }
+}
+
+/// Radius of a deep-sky object's glyph, in pixels
+pub const DSO_GLYPH_RADIUS: f64 = 6.0;
+
+/// Tilt of the galaxy glyph's long axis, in degrees counterclockwise
+const GALAXY_TILT: f64 = 30.0;
+
+/// A Messier object placed in a viewport
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DsoGlyph {
+    pub messier: u8,
+    pub kind: DsoKind,
+    pub x: f64,
+    pub y: f64,
+    /// Whether the object is below the local horizon, if one is shown
+    pub below_horizon: bool,
+}
+
+/// The Messier objects in a viewport, in screen coordinates
+pub fn deep_sky_glyphs(viewport: &Viewport, sky: Option<&LocalSky>) -> Vec<DsoGlyph> {
+    MESSIER
+        .iter()
+        .filter_map(|object| {
+            let projected = viewport.project(&object.coord);
+            projected.on_screen.then(|| DsoGlyph {
+                messier: object.messier,
+                kind: object.kind,
+                x: projected.point.x,
+                y: projected.point.y,
+                below_horizon: sky.is_some_and(|sky| !sky.is_visible(&object.coord)),
+            })
+        })
+        .collect()
+}
+
+/// The glyph nearest a screen point, if the point is on or just around it
+pub fn glyph_at(glyphs: &[DsoGlyph], x: f64, y: f64) -> Option<&DsoGlyph> {
+    glyphs
+        .iter()
+        .map(|glyph| (glyph, (glyph.x - x).hypot(glyph.y - y)))
+        .filter(|(_, distance)| *distance <= DSO_GLYPH_RADIUS + HIT_SLOP)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(glyph, _)| glyph)
+}
+
+/// SVG path data for a glyph, in the usual chart symbol for its kind
+///
+/// Plain path data, so the canvas map can draw the very same shape.
+pub fn glyph_path(glyph: &DsoGlyph) -> String {
+    let DsoGlyph { kind, x, y, .. } = *glyph;
+    let r = DSO_GLYPH_RADIUS;
+    let mut d = String::new();
+    match kind {
+        DsoKind::Galaxy => {
+            let (sin, cos) = GALAXY_TILT.to_radians().sin_cos();
+            let (dx, dy) = (r * cos, r * sin);
+            let (rx, ry) = (r, r / 2.0);
+            let _ = write!(
+                d,
+                "M{:.1} {:.1}A{rx} {ry} {} 1 0 {:.1} {:.1}A{rx} {ry} {} 1 0 {:.1} {:.1}Z",
+                x + dx,
+                y - dy,
+                -GALAXY_TILT,
+                x - dx,
+                y + dy,
+                -GALAXY_TILT,
+                x + dx,
+                y - dy,
+            );
+        }
+        DsoKind::GlobularCluster => {
+            circle_path(&mut d, x, y, r);
+            let _ = write!(
+                d,
+                "M{:.1} {:.1}H{:.1}M{:.1} {:.1}V{:.1}",
+                x - r,
+                y,
+                x + r,
+                x,
+                y - r,
+                y + r
+            );
+        }
+        DsoKind::OpenCluster => dashed_circle_path(&mut d, x, y, r, 8),
+        DsoKind::Nebula => {
+            let side = 1.6 * r;
+            let _ = write!(
+                d,
+                "M{:.1} {:.1}h{side:.1}v{side:.1}h{:.1}Z",
+                x - side / 2.0,
+                y - side / 2.0,
+                -side
+            );
+        }
+        DsoKind::PlanetaryNebula => {
+            let inner = r / 2.0;
+            circle_path(&mut d, x, y, inner);
+            let _ = write!(
+                d,
+                "M{:.1} {:.1}V{:.1}M{:.1} {:.1}V{:.1}M{:.1} {:.1}H{:.1}M{:.1} {:.1}H{:.1}",
+                x,
+                y - r,
+                y - inner,
+                x,
+                y + inner,
+                y + r,
+                x - r,
+                y,
+                x - inner,
+                x + inner,
+                y,
+                x + r
+            );
+        }
+        DsoKind::SupernovaRemnant => {
+            let _ = write!(
+                d,
+                "M{:.1} {:.1}L{:.1} {:.1}L{:.1} {:.1}L{:.1} {:.1}Z",
+                x,
+                y - r,
+                x + r,
+                y,
+                x,
+                y + r,
+                x - r,
+                y
+            );
+        }
+        DsoKind::Asterism => dashed_circle_path(&mut d, x, y, 0.8 * r, 12),
+    }
+    d
+}
+
+/// Append a circle to SVG path data, as two half arcs
+fn circle_path(d: &mut String, x: f64, y: f64, r: f64) {
+    let _ = write!(
+        d,
+        "M{:.1} {:.1}A{r} {r} 0 1 0 {:.1} {:.1}A{r} {r} 0 1 0 {:.1} {:.1}Z",
+        x - r,
+        y,
+        x + r,
+        y,
+        x - r,
+        y
+    );
+}
+
+/// Append a circle broken into `dashes` arcs to SVG path data
+fn dashed_circle_path(d: &mut String, x: f64, y: f64, r: f64, dashes: usize) {
+    let step = TAU / dashes as f64;
+    for i in 0..dashes {
+        let start = step * i as f64;
+        let end = start + step / 2.0;
+        let _ = write!(
+            d,
+            "M{:.1} {:.1}A{r} {r} 0 0 1 {:.1} {:.1}",
+            x + r * start.cos(),
+            y + r * start.sin(),
+            x + r * end.cos(),
+            y + r * end.sin()
+        );
+    }
+}
+
+/// A star's name with its designations, such as
+/// "Betelgeuse (α Ori, 58 Ori)"
+pub fn designated_name(star: &Star) -> String {
+    let name = star.display_name();
+    match star.facts() {
+        Some(facts) => format!("{} ({})", name, facts.designations()),
+        None => name,
+    }
+}
+
+/// A star's name in `lang`, falling back to its IAU name
+pub fn traditional_name(star: &Star, lang: Lang) -> String {
+    star.name
+        .as_deref()
+        .and_then(|name| star_name(name, lang))
+        .map_or_else(|| star.display_name(), str::to_string)
+}
+
+/// A star's tooltip: its name in `lang` when that differs from the IAU
+/// name, then the IAU name with its designations
+pub fn tooltip_name(star: &Star, lang: Lang) -> String {
+    let name = designated_name(star);
+    match star.name.as_deref().and_then(|n| star_name(n, lang)) {
+        Some(traditional) if Some(traditional) != star.name.as_deref() => {
+            format!("{} · {}", traditional, name)
+        }
+        _ => name,
+    }
+}
+
+/// A star's label: its name in `lang`, with its Bayer designation at the
+/// densest labeling
+fn label_text(star: &Star, density: LabelDensity, lang: Lang) -> String {
+    let name = traditional_name(star, lang);
+    match star.facts() {
+        Some(facts) if density == LabelDensity::Dense => {
+            format!("{} ({})", name, facts.bayer_designation())
+        }
+        _ => name,
+    }
+}
+
+/// Named stars that get a label at this zoom and density
+pub fn labeled_stars<'a>(
+    stars: &'a [&'a Star],
+    zoom: f64,
+    density: LabelDensity,
+) -> impl Iterator<Item = &'a Star> + 'a {
+    let lod = LabelLod::default();
+    stars
+        .iter()
+        .copied()
+        .filter(|star| star.has_name())
+        .filter(move |star| {
+            density != LabelDensity::Off && lod.should_label(star.magnitude, zoom, density)
+        })
+}
+
+/// What the map needs to draw one star
+#[derive(Debug, Clone, PartialEq)]
+pub struct StarRenderInfo {
+    pub id: StarId,
+    /// Screen position of the star's center
+    pub x: f64,
+    pub y: f64,
+    pub radius: f64,
+    pub fill: &'static str,
+    /// Whether the star has a proper name (and so starts a quiz)
+    pub named: bool,
+}
+
+/// A star name label in screen coordinates
+#[derive(Debug, Clone, PartialEq)]
+pub struct StarLabel {
+    pub id: StarId,
+    pub x: f64,
+    pub y: f64,
+    pub text: String,
+}
+
+/// Settings that change which stars are drawn and how
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrawOptions {
+    /// Faintest magnitude to include
+    pub magnitude_limit: f64,
+    /// Star size multiplier
+    pub star_scale: f64,
+    /// How many star labels to draw
+    pub label_density: LabelDensity,
+    /// Tradition labels name stars in
+    pub name_lang: Lang,
+}
+
+/// The stars and labels visible in a viewport, ready to draw
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DrawList {
+    pub stars: Vec<StarRenderInfo>,
+    pub labels: Vec<StarLabel>,
+}
+
+/// Extra pixels around a star or glyph that still count as clicking it
+pub const HIT_SLOP: f64 = 3.0;
+
+impl DrawList {
+    /// The star drawn nearest a screen point, if the point is on or just
+    /// around it
+    ///
+    /// For maps without an element per star to receive the click.
+    pub fn star_at(&self, x: f64, y: f64) -> Option<&StarRenderInfo> {
+        self.stars
+            .iter()
+            .map(|star| (star, (star.x - x).hypot(star.y - y)))
+            .filter(|(star, distance)| *distance <= star.radius + HIT_SLOP)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(star, _)| star)
+    }
+}
+
+/// Cull `catalog` to `viewport` and project, size and label what remains
+///
+/// This is the costly part of drawing the map, so the map component
+/// memoizes it; the SVG export draws from the same list.
+pub fn prepare_draw_list(
+    catalog: &StarCatalog,
+    viewport: &Viewport,
+    options: &DrawOptions,
+) -> DrawList {
+    let (ra_min, ra_max) = viewport.ra_range();
+    let (dec_min, dec_max) = viewport.dec_range();
+    let visible = catalog.stars_in_range(ra_min, ra_max, dec_min, dec_max, options.magnitude_limit);
+    draw_list_for(&visible, viewport, options)
+}
+
+/// Project, size and label stars already culled to `viewport`
+pub fn draw_list_for(visible: &[&Star], viewport: &Viewport, options: &DrawOptions) -> DrawList {
+    // A star opposite a stereographic view's center has no position
+    let finite: Vec<&Star>;
+    let visible = if viewport.projection == ProjectionKind::Stereographic {
+        finite = visible
+            .iter()
+            .copied()
+            .filter(|star| viewport.project(&star.coord).point.is_finite())
+            .collect();
+        &finite
+    } else {
+        visible
+    };
+
+    let stars = visible
+        .iter()
+        .map(|star| {
+            let screen = viewport.project(&star.coord).point;
+            let style = render_style_for(
+                star.magnitude,
+                star.has_name(),
+                viewport.zoom,
+                options.star_scale,
+            );
+            StarRenderInfo {
+                id: star.id,
+                x: screen.x,
+                y: screen.y,
+                radius: style.radius,
+                fill: star_fill(star),
+                named: star.has_name(),
+            }
+        })
+        .collect();
+
+    // Brighter stars claim label space first; labels with no room left
+    // beside their star are dropped
+    let mut labeled: Vec<&Star> =
+        labeled_stars(visible, viewport.zoom, options.label_density).collect();
+    labeled.sort_by(|a, b| a.magnitude.total_cmp(&b.magnitude));
+    let names: Vec<String> = labeled
+        .iter()
+        .map(|star| label_text(star, options.label_density, options.name_lang))
+        .collect();
+    let requests: Vec<LabelRequest> = labeled
+        .iter()
+        .zip(&names)
+        .map(|(star, name)| {
+            let screen = viewport.project(&star.coord).point;
+            let style = render_style_for(star.magnitude, true, viewport.zoom, options.star_scale);
+            LabelRequest {
+                x: screen.x,
+                y: screen.y,
+                radius: style.radius,
+                chars: name.chars().count(),
+            }
+        })
+        .collect();
+    let labels = place_labels(&requests)
+        .into_iter()
+        .zip(labeled.iter().zip(names))
+        .filter_map(|(spot, (star, text))| {
+            let (x, y) = spot?;
+            Some(StarLabel {
+                id: star.id,
+                x,
+                y,
+                text,
+            })
+        })
+        .collect();
+
+    DrawList { stars, labels }
+}
+
+/// Map background color
+pub const BACKGROUND_FILL: &str = "#0a0a14";
+
+/// Stroke color of exported constellation figures, the map's `.constellation-line`
+const CONSTELLATION_STROKE: &str = "#4a9eff";
+
+/// Options for exporting the map as a standalone SVG
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SvgExportOptions {
+    /// Faintest magnitude to include
+    pub magnitude_limit: f64,
+    /// Whether to draw grid lines
+    pub show_grid: bool,
+    /// Whether to draw the constellation figures
+    pub show_constellations: bool,
+    /// Star size multiplier
+    pub star_scale: f64,
+    /// How many star labels to draw
+    pub label_density: LabelDensity,
+    /// Tradition labels name stars in
+    pub name_lang: Lang,
+}
+
+impl SvgExportOptions {
+    pub fn draw_options(&self) -> DrawOptions {
+        DrawOptions {
+            magnitude_limit: self.magnitude_limit,
+            star_scale: self.star_scale,
+            label_density: self.label_density,
+            name_lang: self.name_lang,
+        }
+    }
+}
+
+impl Default for SvgExportOptions {
+    fn default() -> Self {
+        Self {
+            magnitude_limit: 6.0,
+            show_grid: true,
+            show_constellations: false,
+            star_scale: 1.0,
+            label_density: LabelDensity::default(),
+            name_lang: Lang::default(),
+        }
+    }
+}
+
+/// Serialize the map as it appears in `viewport` into a standalone SVG document
+///
+/// Uses the same grid, style and label logic as the web map component, but
+/// produces plain markup with embedded styles and no event handlers.
+pub fn render_to_svg_string(
+    catalog: &StarCatalog,
+    viewport: &Viewport,
+    options: &SvgExportOptions,
+) -> String {
+    let draw_list = prepare_draw_list(catalog, viewport, &options.draw_options());
+
+    let mut svg = String::new();
+    let _ = write!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+        w = viewport.width,
+        h = viewport.height,
+    );
+    svg.push_str(
+        "<style>.star-label{fill:#a8a6a3;font-family:'Courier New',monospace;font-size:10px}</style>",
+    );
+    let _ = write!(
+        svg,
+        r#"<rect x="0" y="0" width="{}" height="{}" fill="{}"/>"#,
+        viewport.width, viewport.height, BACKGROUND_FILL
+    );
+
+    if options.show_grid {
+        for line in grid_lines(viewport) {
+            let _ = write!(
+                svg,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="{}" stroke-opacity="0.5"/>"#,
+                line.x1, line.y1, line.x2, line.y2, line.stroke, line.width
+            );
+        }
+    }
+
+    if options.show_constellations {
+        for line in figure_lines(viewport, &figure_segments(catalog)) {
+            let _ = write!(
+                svg,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="1" stroke-opacity="0.45"/>"#,
+                line.x1, line.y1, line.x2, line.y2, CONSTELLATION_STROKE
+            );
+        }
+    }
+
+    for star in &draw_list.stars {
+        let _ = write!(
+            svg,
+            r#"<circle cx="{}" cy="{}" r="{}" fill="{}"/>"#,
+            star.x, star.y, star.radius, star.fill
+        );
+    }
+
+    for label in &draw_list.labels {
+        let _ = write!(
+            svg,
+            r#"<text class="star-label" x="{}" y="{}">{}</text>"#,
+            label.x,
+            label.y,
+            escape_xml(&label.text)
+        );
+    }
+
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Escape text for inclusion in XML content or attributes
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Fill color for named (clickable) stars
+pub const NAMED_STAR_FILL: &str = "#fffaf0";
+
+/// Fill color for unnamed stars
+pub const UNNAMED_STAR_FILL: &str = "#c0c8d0";
+
+/// Star tints by B−V color index, each for indices below its bound, from
+/// the hot O stars to the cool M stars
+const STAR_TINTS: [(f64, &str); 8] = [
+    (-0.25, "#9bb0ff"),
+    (-0.05, "#aabfff"),
+    (0.15, "#cad7ff"),
+    (0.45, "#f8f7ff"),
+    (0.75, "#fff4ea"),
+    (1.15, "#ffd2a1"),
+    (1.5, "#ffc78e"),
+    (f64::INFINITY, "#ffb46b"),
+];
+
+/// The approximate color of a star with a B−V color index: blue-white
+/// for hot stars, through white, to orange for cool ones
+pub fn tint_for_color_index(color_index: f64) -> &'static str {
+    STAR_TINTS
+        .iter()
+        .find(|(bound, _)| color_index < *bound)
+        .map_or(STAR_TINTS[STAR_TINTS.len() - 1].1, |(_, tint)| tint)
+}
+
+/// Fill color of a star: tinted by its color where its spectrum is
+/// known, otherwise the plain named or unnamed fill
+pub fn star_fill(star: &Star) -> &'static str {
+    match star.facts() {
+        Some(facts) => tint_for_color_index(facts.color_index),
+        None if star.has_name() => NAMED_STAR_FILL,
+        None => UNNAMED_STAR_FILL,
+    }
+}
+
+/// Stroke color for RA/Dec grid lines
+pub const GRID_STROKE: &str = "#1a3a5a";
+
+/// Stroke color for the celestial equator
+pub const EQUATOR_STROKE: &str = "#7a2a5a";
+
+/// Visual style of a star symbol
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StarStyle {
+    /// Circle radius in SVG units
+    pub radius: f64,
+    /// Fill color
+    pub fill: &'static str,
+}
+
+/// Compute the rendered style for a star of the given magnitude
+///
+/// Shared by the map and the legend so the two never disagree.
+/// `scale` is the user's star size preference.
+pub fn render_style_for(magnitude: f64, named: bool, zoom: f64, scale: f64) -> StarStyle {
+    let base_radius = (3.0 / zoom.sqrt()) * scale;
+    // Brighter (lower magnitude) stars get larger symbols
+    let brightness = (6.5 - magnitude).max(0.0);
+    let radius = base_radius * (0.4 + brightness * 0.25);
+
+    // Color based on whether star is named
+    let fill = if named {
+        NAMED_STAR_FILL // Warmer white for named stars
+    } else {
+        UNNAMED_STAR_FILL // Cooler for unnamed
+    };
+
+    StarStyle { radius, fill }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::generate_placeholder_catalog;
+    use crate::utils::projection::stereographic;
+    #[test]
+    fn test_render_style_brighter_is_larger() {
+        let bright = render_style_for(0.0, true, 1.0, 1.0);
+        let faint = render_style_for(5.0, true, 1.0, 1.0);
+        assert!(bright.radius > faint.radius);
+        assert_eq!(bright.fill, NAMED_STAR_FILL);
+        assert_eq!(
+            render_style_for(5.0, false, 1.0, 1.0).fill,
+            UNNAMED_STAR_FILL
+        );
+    }
+
+    #[test]
+    fn test_star_colors_and_designations() {
+        let star = |name: Option<&str>| Star {
+            id: StarId(1),
+            name: name.map(str::to_string),
+            coord: CelestialCoord::new(0.0, 0.0),
+            magnitude: 1.0,
+            constellation: None,
+        };
+        // Betelgeuse is orange and Rigel blue-white
+        assert_eq!(star_fill(&star(Some("Betelgeuse"))), "#ffb46b");
+        assert_eq!(star_fill(&star(Some("Rigel"))), "#cad7ff");
+        assert_eq!(star_fill(&star(Some("Nowhere"))), NAMED_STAR_FILL);
+        assert_eq!(star_fill(&star(None)), UNNAMED_STAR_FILL);
+        assert_eq!(
+            designated_name(&star(Some("Betelgeuse"))),
+            "Betelgeuse (α Ori, 58 Ori)"
+        );
+        assert_eq!(
+            label_text(&star(Some("Rigel")), LabelDensity::Dense, Lang::Iau),
+            "Rigel (β Ori)"
+        );
+        assert_eq!(
+            label_text(&star(Some("Rigel")), LabelDensity::Normal, Lang::Iau),
+            "Rigel"
+        );
+        // Other traditions, falling back to the IAU name
+        assert_eq!(
+            label_text(&star(Some("Rigel")), LabelDensity::Dense, Lang::Chinese),
+            "参宿七 (β Ori)"
+        );
+        assert_eq!(
+            label_text(&star(Some("Polaris")), LabelDensity::Normal, Lang::Arabic),
+            "Polaris"
+        );
+        assert_eq!(
+            tooltip_name(&star(Some("Betelgeuse")), Lang::Chinese),
+            "参宿四 · Betelgeuse (α Ori, 58 Ori)"
+        );
+        assert_eq!(
+            tooltip_name(&star(Some("Betelgeuse")), Lang::Iau),
+            "Betelgeuse (α Ori, 58 Ori)"
+        );
+        // Redder with a larger index
+        assert_eq!(tint_for_color_index(-0.3), "#9bb0ff");
+        assert_eq!(tint_for_color_index(0.65), "#fff4ea");
+        assert_eq!(tint_for_color_index(3.0), "#ffb46b");
+    }
+
+    #[test]
+    fn test_star_scale_doubles_radius() {
+        for zoom in [1.0, 3.0, 12.5] {
+            let normal = render_style_for(1.3, true, zoom, 1.0);
+            let doubled = render_style_for(1.3, true, zoom, 2.0);
+            assert_eq!(doubled.radius, normal.radius * 2.0);
+        }
+    }
+
+    fn draw_options() -> DrawOptions {
+        SvgExportOptions::default().draw_options()
+    }
+
+    fn sky_at_50_north(sidereal_time: f64) -> LocalSky {
+        LocalSky {
+            observer: crate::utils::horizon::Observer::new(50.0, 0.0).unwrap(),
+            sidereal_time,
+        }
+    }
+
+    #[test]
+    fn test_horizon_overlay_breaks_only_at_the_wrap() {
+        let viewport = Viewport::default();
+        for sidereal_time in [0.0, 6.0, 12.5, 20.0] {
+            let overlay = horizon_overlay(&viewport, &sky_at_50_north(sidereal_time));
+            let points: usize = overlay.segments.iter().map(Vec::len).sum();
+            assert!(points > HORIZON_SAMPLES - 2, "{}", points);
+            assert!(overlay.segments.len() <= 3, "{:?}", overlay.segments.len());
+            for segment in &overlay.segments {
+                for pair in segment.windows(2) {
+                    assert!((pair[1].x - pair[0].x).abs() < viewport.width / 2.0);
+                }
+            }
+            // Every compass point is somewhere on the whole-sky map
+            assert_eq!(overlay.cardinals.len(), 4);
+        }
+    }
+
+    #[test]
+    fn test_horizon_overlay_when_zoomed() {
+        // Looking at the northern horizon: north is in view, south is not
+        let sky = sky_at_50_north(6.0);
+        let north = sky.cardinal_point(Cardinal::North);
+        let viewport = Viewport {
+            center_ra: north.ra,
+            center_dec: north.dec,
+            zoom: 4.0,
+            ..Viewport::default()
+        };
+        let overlay = horizon_overlay(&viewport, &sky);
+        let cardinals: Vec<Cardinal> = overlay.cardinals.iter().map(|(c, _)| *c).collect();
+        assert_eq!(cardinals, [Cardinal::North]);
+        assert!(overlay
+            .segments
+            .iter()
+            .flatten()
+            .any(|point| viewport.contains(*point)));
+    }
+
+    #[test]
+    fn test_draw_list_is_deterministic() {
+        let catalog = generate_placeholder_catalog();
+        let viewport = Viewport::default();
+        let first = prepare_draw_list(&catalog, &viewport, &draw_options());
+        let second = prepare_draw_list(&catalog, &viewport, &draw_options());
+        assert!(!first.stars.is_empty());
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_draw_list_matches_star_styles() {
+        let catalog = generate_placeholder_catalog();
+        let viewport = Viewport {
+            zoom: 2.0,
+            ..Viewport::default()
+        };
+        let options = DrawOptions {
+            star_scale: 1.5,
+            ..draw_options()
+        };
+        let list = prepare_draw_list(&catalog, &viewport, &options);
+
+        for info in &list.stars {
+            let star = catalog.get(info.id).unwrap();
+            let screen = viewport.project(&star.coord).point;
+            let style = render_style_for(star.magnitude, star.has_name(), 2.0, 1.5);
+            assert_eq!((info.x, info.y), (screen.x, screen.y));
+            assert_eq!((info.radius, info.fill), (style.radius, star_fill(star)));
+            assert_eq!(info.named, star.has_name());
+        }
+        for label in &list.labels {
+            assert!(list.stars.iter().any(|s| s.id == label.id && s.named));
+        }
+
+        let unlabeled = DrawOptions {
+            label_density: LabelDensity::Off,
+            ..options
+        };
+        assert!(prepare_draw_list(&catalog, &viewport, &unlabeled)
+            .labels
+            .is_empty());
+    }
+
+    #[test]
+    fn test_star_at_finds_the_nearest_star() {
+        let star = |id, x, radius| StarRenderInfo {
+            id: StarId(id),
+            x,
+            y: 50.0,
+            radius,
+            fill: NAMED_STAR_FILL,
+            named: true,
+        };
+        let list = DrawList {
+            stars: vec![star(1, 100.0, 2.0), star(2, 106.0, 4.0)],
+            labels: Vec::new(),
+        };
+        assert_eq!(list.star_at(101.0, 50.0).unwrap().id, StarId(1));
+        assert_eq!(list.star_at(104.0, 50.0).unwrap().id, StarId(2));
+        // Just outside a small star still hits it
+        assert_eq!(list.star_at(100.0, 54.5).unwrap().id, StarId(1));
+        assert!(list.star_at(100.0, 60.0).is_none());
+    }
+
+    #[test]
+    fn test_deep_sky_glyphs() {
+        // Centered on the Orion Nebula
+        let viewport = Viewport {
+            center_ra: 5.588,
+            center_dec: -5.383,
+            zoom: 4.0,
+            ..Viewport::default()
+        };
+        let glyphs = deep_sky_glyphs(&viewport, None);
+        let orion = glyphs.iter().find(|g| g.messier == 42).unwrap();
+        assert_eq!(orion.kind, DsoKind::Nebula);
+        assert!((orion.x - viewport.width / 2.0).abs() < 1.0);
+        assert!(!orion.below_horizon);
+        // The Andromeda Galaxy is on the other side of the sky
+        assert!(glyphs.iter().all(|g| g.messier != 31));
+
+        let (x, y) = (orion.x, orion.y);
+        assert_eq!(glyph_at(&glyphs, x, y + 2.0).unwrap().messier, 42);
+        assert!(glyph_at(&glyphs, x + 100.0, y + 100.0).is_none());
+
+        // Seen from 50° north when Orion is low in the south
+        let sky = sky_at_50_north(17.6);
+        assert!(deep_sky_glyphs(&viewport, Some(&sky))
+            .iter()
+            .any(|g| g.messier == 42 && g.below_horizon));
+    }
+
+    #[test]
+    fn test_glyph_paths() {
+        for kind in DsoKind::ALL {
+            let glyph = DsoGlyph {
+                messier: 1,
+                kind,
+                x: 50.0,
+                y: 40.0,
+                below_horizon: false,
+            };
+            let d = glyph_path(&glyph);
+            assert!(d.starts_with('M'), "{:?}: {}", kind, d);
+            assert!(!d.contains("NaN"), "{:?}: {}", kind, d);
+        }
+    }
+
+    #[test]
+    fn test_svg_export_circle_count() {
+        let catalog = generate_placeholder_catalog();
+        let viewport = Viewport::default();
+        let options = SvgExportOptions {
+            show_grid: false,
+            ..SvgExportOptions::default()
+        };
+
+        let (ra_min, ra_max) = viewport.ra_range();
+        let (dec_min, dec_max) = viewport.dec_range();
+        let expected = catalog
+            .stars_in_range(ra_min, ra_max, dec_min, dec_max, options.magnitude_limit)
+            .len();
+        assert!(expected > 0);
+
+        let svg = render_to_svg_string(&catalog, &viewport, &options);
+        let doc = roxmltree::Document::parse(&svg).expect("export should be valid XML");
+        let circles = doc
+            .descendants()
+            .filter(|n| n.has_tag_name("circle"))
+            .count();
+        assert_eq!(circles, expected);
+    }
+
+    #[test]
+    fn test_svg_export_structure() {
+        let catalog = generate_placeholder_catalog();
+        let viewport = Viewport::default();
+        let svg = render_to_svg_string(&catalog, &viewport, &SvgExportOptions::default());
+        let doc = roxmltree::Document::parse(&svg).expect("export should be valid XML");
+
+        let root = doc.root_element();
+        assert_eq!(root.tag_name().name(), "svg");
+        assert_eq!(
+            root.tag_name().namespace(),
+            Some("http://www.w3.org/2000/svg")
+        );
+        assert!(root.children().any(|n| n.has_tag_name("style")));
+        assert_eq!(
+            root.descendants()
+                .filter(|n| n.has_tag_name("line"))
+                .count(),
+            grid_lines(&viewport).len()
+        );
+    }
+
+    #[test]
+    fn test_svg_export_draws_constellations_when_asked() {
+        let catalog = generate_placeholder_catalog();
+        let viewport = Viewport {
+            center_ra: 5.6,
+            zoom: 4.0,
+            ..Viewport::default()
+        };
+        let lines = |show_constellations| {
+            let options = SvgExportOptions {
+                show_grid: false,
+                show_constellations,
+                ..SvgExportOptions::default()
+            };
+            let svg = render_to_svg_string(&catalog, &viewport, &options);
+            svg.matches("<line ").count()
+        };
+
+        assert_eq!(lines(false), 0);
+        assert_eq!(
+            lines(true),
+            figure_lines(&viewport, &figure_segments(&catalog)).len()
+        );
+        assert!(lines(true) > 0);
+    }
+
+    #[test]
+    fn test_stereographic_grid_stays_near_the_view() {
+        for viewport in [
+            stereographic(12.0, 0.0, 1.0),
+            stereographic(3.0, 90.0, 1.0),
+            stereographic(18.0, -60.0, 8.0),
+            stereographic(6.0, 10.0, 50.0),
+        ] {
+            let lines = grid_lines(&viewport);
+            assert!(!lines.is_empty(), "{:?}", viewport);
+            for line in &lines {
+                assert!(
+                    [line.x1, line.y1, line.x2, line.y2]
+                        .iter()
+                        .all(|v| v.is_finite()),
+                    "{:?}",
+                    line
+                );
+                assert!(line.x1.min(line.x2) <= viewport.width && line.x1.max(line.x2) >= 0.0);
+            }
+            let keys: std::collections::HashSet<_> = lines.iter().map(|l| &l.key).collect();
+            assert_eq!(keys.len(), lines.len());
+        }
+        // Looking at the pole every meridian is in view
+        let polar = grid_lines(&stereographic(3.0, 90.0, 1.0));
+        assert!(polar.iter().any(|l| l.key.starts_with("ra-0-")));
+        assert!(polar.iter().any(|l| l.key.starts_with("ra-12-")));
+    }
+
+    #[test]
+    fn test_stereographic_horizon_is_one_loop() {
+        // The horizon is a circle on the sky and so on the screen; seen
+        // from the zenith it does not pass the point opposite the center
+        let sky = sky_at_50_north(6.0);
+        let viewport = stereographic(6.0, 50.0, 1.0);
+        let overlay = horizon_overlay(&viewport, &sky);
+        assert_eq!(overlay.segments.len(), 1);
+        let center = ScreenCoord::new(viewport.width / 2.0, viewport.height / 2.0);
+        let radii: Vec<f64> = overlay.segments[0]
+            .iter()
+            .map(|p| p.distance(&center))
+            .collect();
+        assert!(radii.iter().all(|r| (r - radii[0]).abs() < 1e-6));
+        // Half the view's height is 90 degrees, so north and south sit on
+        // its top and bottom edges; east and west are well inside
+        let cardinals: Vec<Cardinal> = overlay.cardinals.iter().map(|(c, _)| *c).collect();
+        assert!(cardinals.contains(&Cardinal::East) && cardinals.contains(&Cardinal::West));
+    }
+
+    #[test]
+    fn test_stereographic_svg_export() {
+        let catalog = generate_placeholder_catalog();
+        for viewport in [stereographic(12.0, 0.0, 1.0), stereographic(2.5, 89.0, 3.0)] {
+            let svg = render_to_svg_string(&catalog, &viewport, &SvgExportOptions::default());
+            assert!(!svg.contains("inf") && !svg.contains("NaN"));
+            let doc = roxmltree::Document::parse(&svg).expect("export should be valid XML");
+            let circles = doc
+                .descendants()
+                .filter(|n| n.has_tag_name("circle"))
+                .count();
+            assert!(circles > 0);
+        }
+    }
+
+    #[test]
+    fn test_figure_lines_cross_the_view() {
+        let segments = crate::data::constellations::figure_segments(&StarCatalog::new());
+        // Orion, zoomed in: its lines and no others from far away
+        for projection in [
+            ProjectionKind::Equirectangular,
+            ProjectionKind::Stereographic,
+        ] {
+            let viewport = Viewport {
+                center_ra: 5.6,
+                center_dec: 0.0,
+                zoom: 6.0,
+                projection,
+                ..Viewport::default()
+            };
+            let lines = figure_lines(&viewport, &segments);
+            assert!(lines.iter().any(|l| l.key.starts_with("Ori-")));
+            assert!(!lines.iter().any(|l| l.key.starts_with("UMa-")));
+            for line in &lines {
+                assert!(line.x1.min(line.x2) <= viewport.width && line.x1.max(line.x2) >= 0.0);
+            }
+        }
+
+        // The whole flat sky draws nearly every line, but none wraps across it
+        let viewport = Viewport::default();
+        let lines = figure_lines(&viewport, &segments);
+        assert!(lines.len() > segments.len() * 9 / 10);
+        assert!(lines
+            .iter()
+            .all(|l| (l.x1 - l.x2).abs() <= viewport.width / 2.0));
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(
+            escape_xml("Alpha & <Beta> \"'"),
+            "Alpha &amp; &lt;Beta&gt; &quot;&apos;"
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! Astronomical time
+//!
+//! Julian dates and sidereal time from Unix time. Sidereal time is the
+//! right ascension on an observer's meridian, so it says which part of
+//! the sky is overhead. The GMST formula is the linear one from the
+//! Astronomical Almanac, good to a fraction of a second for centuries
+//! around J2000.
+
+/// Seconds in a day
+pub const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Julian date of the Unix epoch
+pub const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+
+/// Julian date of J2000.0, 2000-01-01 12:00 TT
+pub const J2000_JD: f64 = 2_451_545.0;
+
+/// Julian date of a Unix time in seconds
+pub fn julian_date(unix_seconds: f64) -> f64 {
+    unix_seconds / SECONDS_PER_DAY + UNIX_EPOCH_JD
+}
+
+/// Greenwich mean sidereal time in hours at a Unix time in seconds
+pub fn greenwich_sidereal_time(unix_seconds: f64) -> f64 {
+    let days = julian_date(unix_seconds) - J2000_JD;
+    (18.697_374_558 + 24.065_709_824_419_08 * days).rem_euclid(24.0)
+}
+
+/// Local sidereal time in hours at `longitude` degrees east, at a Unix
+/// time in seconds
+pub fn local_sidereal_time(unix_seconds: f64, longitude: f64) -> f64 {
+    (greenwich_sidereal_time(unix_seconds) + longitude / 15.0).rem_euclid(24.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2000-01-01 12:00 UTC
+    const J2000_UNIX: f64 = 946_728_000.0;
+
+    #[test]
+    fn test_julian_date() {
+        assert_eq!(julian_date(0.0), UNIX_EPOCH_JD);
+        assert_eq!(julian_date(J2000_UNIX), J2000_JD);
+        assert_eq!(
+            julian_date(J2000_UNIX + SECONDS_PER_DAY / 2.0),
+            J2000_JD + 0.5
+        );
+    }
+
+    #[test]
+    fn test_sidereal_time() {
+        // At J2000.0 GMST is 18h 41m 50.5s
+        assert!((greenwich_sidereal_time(J2000_UNIX) - 18.697_374_558).abs() < 1e-9);
+        // A sidereal day is about 3m 56s shorter than a solar one
+        let next_day = greenwich_sidereal_time(J2000_UNIX + SECONDS_PER_DAY);
+        assert!((next_day - 18.697_374_558 - 0.065_709_824).abs() < 1e-6);
+        // 15 degrees of longitude is an hour of sidereal time
+        assert!((local_sidereal_time(J2000_UNIX, 15.0) - 19.697_374_558).abs() < 1e-9);
+        assert!((local_sidereal_time(J2000_UNIX, -180.0) - 6.697_374_558).abs() < 1e-9);
+    }
+}
@@ -2,7 +2,10 @@
 //!
 //! These tests verify the integration between different modules.
 
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
 use stargazer_poc::data::{generate_placeholder_catalog, BrightnessCategory, CelestialCoord};
+use stargazer_poc::game::engine::HeadlessGame;
 use stargazer_poc::game::{game_reducer, GameAction, GameState, QuizConfig, QuizGenerator};
 use stargazer_poc::utils::{LodSettings, Projection, Viewport};
 use std::rc::Rc;
@@ -17,6 +20,7 @@ fn test_catalog_quiz_integration() {
         num_choices: 5,
         include_none_option: false,
         none_probability: 0.0,
+        ..QuizConfig::default()
     };
     let generator = QuizGenerator::new(&catalog, config);
 
@@ -267,3 +271,711 @@ fn test_score_persistence_through_actions() {
     assert_eq!(current_state.score.streak, 0);
     assert_eq!(current_state.score.best_streak, 5); // Best streak preserved
 }
+
+#[test]
+fn test_seeded_quiz_batch_is_reproducible() {
+    let catalog = generate_placeholder_catalog();
+    let generator = QuizGenerator::new(&catalog, QuizConfig::default());
+
+    // Mirrors the CLI quiz: a seed plus a count fully determines the run
+    let run = |seed: u64| {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        generator.generate_batch(8, &mut rng)
+    };
+
+    let first = run(42);
+    let second = run(42);
+    assert_eq!(first.len(), 8);
+    assert_eq!(first, second);
+
+    let targets: Vec<_> = first.iter().map(|q| q.target_star).collect();
+    let other: Vec<_> = run(43).iter().map(|q| q.target_star).collect();
+    assert_ne!(targets, other);
+}
+
+#[test]
+fn test_headless_session() {
+    let catalog = generate_placeholder_catalog();
+    let first_star = catalog.named_stars()[0].id;
+    let mut game = HeadlessGame::new(catalog, QuizConfig::default(), 7);
+
+    // Click a star, answer it right, then take five more questions,
+    // answering the first choice that is not the correct one
+    let correct = game.click_star(first_star).unwrap().correct_name.clone();
+    assert_eq!(game.answer(&correct), Some(true));
+
+    for _ in 0..5 {
+        let quiz = game.next_question().unwrap();
+        let wrong = quiz
+            .choices
+            .iter()
+            .find(|c| **c != quiz.correct_name)
+            .unwrap()
+            .clone();
+        assert_eq!(game.answer(&wrong), Some(false));
+    }
+
+    let state = game.state();
+    assert_eq!(state.guess_history.len(), 6);
+    assert_eq!((state.score.correct, state.score.incorrect), (1, 5));
+    assert_eq!(state.score.best_streak, 1);
+    assert!(state.guess_history[0].was_correct);
+
+    // Summary and reset go through the same reducer
+    game.dispatch(GameAction::ShowSummary);
+    assert!(game.state().ui.summary_shown);
+    game.dispatch(GameAction::ResetScore);
+    assert_eq!(game.state().score.correct, 0);
+}
+
+#[cfg(feature = "tiles")]
+#[test]
+fn test_tile_culling_matches_brute_force() {
+    use rand::Rng;
+    use stargazer_poc::data::tile_view::TileView;
+    use stargazer_poc::data::{Star, TileSystem};
+
+    let catalog = generate_placeholder_catalog();
+    let stars: Vec<Star> = catalog.all_stars().cloned().collect();
+    let view = TileView::new(&catalog, &TileSystem::from_stars(&stars));
+    let sorted_ids = |stars: Vec<&Star>| {
+        let mut ids: Vec<u32> = stars.iter().map(|s| s.id.0).collect();
+        ids.sort_unstable();
+        ids
+    };
+
+    let mut rng = SmallRng::seed_from_u64(11);
+    let mut fewer_tiles = 0;
+    for _ in 0..500 {
+        let viewport = Viewport {
+            center_ra: rng.gen_range(0.0..24.0),
+            center_dec: rng.gen_range(-90.0..90.0),
+            zoom: 2f64.powf(rng.gen_range(0.0..6.0)),
+            ..Viewport::default()
+        };
+        let magnitude = rng.gen_range(1.0..6.5);
+        let (ra_min, ra_max) = viewport.ra_range();
+        let (dec_min, dec_max) = viewport.dec_range();
+
+        let brute = catalog.stars_in_range(ra_min, ra_max, dec_min, dec_max, magnitude);
+        let tiled = view.stars_in_view(&catalog, ra_min, ra_max, dec_min, dec_max, magnitude);
+        assert_eq!(
+            sorted_ids(tiled),
+            sorted_ids(brute),
+            "mismatch at RA {:.3} Dec {:.3} zoom {:.2}",
+            viewport.center_ra,
+            viewport.center_dec,
+            viewport.zoom
+        );
+
+        if view.tiles_in_range(ra_min, ra_max, dec_min, dec_max).len() < view.len() {
+            fewer_tiles += 1;
+        }
+    }
+    // Zoomed-in views should skip most tiles
+    assert!(
+        fewer_tiles > 250,
+        "only {} views skipped tiles",
+        fewer_tiles
+    );
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_import_hyg_fixture_round_trips() {
+    use stargazer_poc::data::facts::CatalogFacts;
+    use stargazer_poc::data::{Star, StarId};
+    use std::process::Command;
+
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/hyg_sample.csv");
+    let output = std::env::temp_dir().join(format!("stargazer-import-{}.json", std::process::id()));
+
+    let run = Command::new(env!("CARGO_BIN_EXE_stargazer-cli"))
+        .args([
+            "import",
+            "--format",
+            "hyg",
+            "--input",
+            fixture,
+            "--max-magnitude",
+            "6.5",
+        ])
+        .arg("--output")
+        .arg(&output)
+        .output()
+        .expect("failed to run stargazer-cli");
+    assert!(run.status.success());
+
+    let stdout = String::from_utf8_lossy(&run.stdout);
+    assert!(stdout.contains("Rows read:    11"), "{}", stdout);
+    assert!(stdout.contains("Stars kept:   5"), "{}", stdout);
+
+    // The emitted JSON must load back as the catalog's star list
+    let json = std::fs::read_to_string(&output).unwrap();
+    std::fs::remove_file(&output).ok();
+    let stars: Vec<Star> = serde_json::from_str(&json).unwrap();
+    assert_eq!(stars.len(), 5);
+    assert!(stars.iter().any(|s| s.name.as_deref() == Some("Vega")));
+    assert!(stars.iter().all(|s| s.magnitude <= 6.5));
+
+    // With the facts HYG gives about them beside the stars
+    let facts_path = output.with_extension("facts.json");
+    assert!(stdout.contains("Wrote facts for 5 stars"), "{}", stdout);
+    let facts = std::fs::read_to_string(&facts_path).unwrap();
+    std::fs::remove_file(&facts_path).ok();
+    let facts: CatalogFacts = serde_json::from_str(&facts).unwrap();
+    let sirius = &facts[&StarId(32263)];
+    assert_eq!(sirius.bayer.as_deref(), Some("α"));
+    assert_eq!(sirius.spectral_type.as_deref(), Some("A0m..."));
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_import_dry_run_writes_nothing() {
+    use std::process::Command;
+
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/hyg_sample.csv");
+    let output =
+        std::env::temp_dir().join(format!("stargazer-dry-run-{}.json", std::process::id()));
+
+    let run = Command::new(env!("CARGO_BIN_EXE_stargazer-cli"))
+        .args(["import", "--format", "hyg", "--input", fixture, "--dry-run"])
+        .arg("--output")
+        .arg(&output)
+        .output()
+        .expect("failed to run stargazer-cli");
+    assert!(run.status.success());
+    assert!(!output.exists());
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_import_hyg_defaults_to_hyg() {
+    use std::process::Command;
+
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/hyg_sample.csv");
+    let run = Command::new(env!("CARGO_BIN_EXE_stargazer-cli"))
+        .args(["import-hyg", "--input", fixture, "--dry-run"])
+        .output()
+        .expect("failed to run stargazer-cli");
+    assert!(run.status.success());
+
+    let stdout = String::from_utf8_lossy(&run.stdout);
+    assert!(stdout.contains("Rows read:    11"), "{}", stdout);
+    assert!(stdout.contains("Dry run: nothing written."), "{}", stdout);
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_render_writes_svg() {
+    use std::process::Command;
+
+    let output = std::env::temp_dir().join(format!("stargazer-render-{}.svg", std::process::id()));
+
+    let run = Command::new(env!("CARGO_BIN_EXE_stargazer-cli"))
+        .args([
+            "render", "--ra", "5.5", "--dec", "0", "--zoom", "4", "--mag", "5.0", "--width", "400",
+            "--height", "300",
+        ])
+        .arg("--output")
+        .arg(&output)
+        .output()
+        .expect("failed to run stargazer-cli");
+    assert!(run.status.success());
+
+    let svg = std::fs::read_to_string(&output).unwrap();
+    std::fs::remove_file(&output).ok();
+    let doc = roxmltree::Document::parse(&svg).expect("render should write valid XML");
+    let circles = doc
+        .descendants()
+        .filter(|n| n.has_tag_name("circle"))
+        .count();
+
+    let viewport = Viewport {
+        center_ra: 5.5,
+        center_dec: 0.0,
+        zoom: 4.0,
+        ..Viewport::new(400.0, 300.0)
+    };
+    let (ra_min, ra_max) = viewport.ra_range();
+    let (dec_min, dec_max) = viewport.dec_range();
+    let expected = generate_placeholder_catalog()
+        .stars_in_range(ra_min, ra_max, dec_min, dec_max, 5.0)
+        .len();
+    assert!(expected > 0);
+    assert_eq!(circles, expected);
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_render_draws_constellations() {
+    use std::process::Command;
+
+    let output = std::env::temp_dir().join(format!(
+        "stargazer-render-figures-{}.svg",
+        std::process::id()
+    ));
+    let lines = |extra: &[&str]| {
+        let run = Command::new(env!("CARGO_BIN_EXE_stargazer-cli"))
+            .args(["render", "--ra", "5.5", "--zoom", "4"])
+            .args(extra)
+            .arg("--output")
+            .arg(&output)
+            .output()
+            .expect("failed to run stargazer-cli");
+        assert!(run.status.success());
+        let svg = std::fs::read_to_string(&output).unwrap();
+        svg.matches("<line ").count()
+    };
+
+    let without = lines(&[]);
+    let with = lines(&["--constellations"]);
+    std::fs::remove_file(&output).ok();
+    assert_eq!(without, 0);
+    assert!(with > 0);
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_render_reports_unwritable_output() {
+    use std::process::Command;
+
+    let output = std::env::temp_dir()
+        .join("stargazer-missing-dir")
+        .join("out.svg");
+
+    let run = Command::new(env!("CARGO_BIN_EXE_stargazer-cli"))
+        .arg("render")
+        .arg("--output")
+        .arg(&output)
+        .output()
+        .expect("failed to run stargazer-cli");
+    assert!(!run.status.success());
+    assert!(String::from_utf8_lossy(&run.stderr).contains("Cannot write"));
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_generate_exports_full_catalog() {
+    use stargazer_poc::data::Star;
+    use std::process::Command;
+
+    let output = std::env::temp_dir().join(format!("stargazer-export-{}.json", std::process::id()));
+
+    let run = Command::new(env!("CARGO_BIN_EXE_stargazer-cli"))
+        .args(["generate", "--format", "json"])
+        .arg("--output")
+        .arg(&output)
+        .output()
+        .expect("failed to run stargazer-cli");
+    assert!(run.status.success());
+    assert!(run.stdout.is_empty());
+
+    let json = std::fs::read_to_string(&output).unwrap();
+    std::fs::remove_file(&output).ok();
+    let stars: Vec<Star> = serde_json::from_str(&json).unwrap();
+
+    let catalog = generate_placeholder_catalog();
+    assert_eq!(stars.len(), catalog.count());
+    assert_eq!(
+        stars.iter().filter(|s| s.has_name()).count(),
+        catalog.named_count()
+    );
+    assert!(stars.iter().any(|s| !s.has_name()));
+
+    for sample in [&stars[0], &stars[stars.len() / 2], &stars[stars.len() - 1]] {
+        let original = catalog.get(sample.id).unwrap();
+        assert_eq!(sample.name, original.name);
+        assert_eq!(sample.constellation, original.constellation);
+        assert!((sample.coord.ra - original.coord.ra).abs() < 1e-9);
+        assert!((sample.coord.dec - original.coord.dec).abs() < 1e-9);
+        assert!((sample.magnitude - original.magnitude).abs() < 1e-9);
+    }
+}
+
+#[cfg(feature = "cli")]
+fn validate_exit_code(fixture: &str, extra: &[&str]) -> Option<i32> {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/");
+    std::process::Command::new(env!("CARGO_BIN_EXE_stargazer-cli"))
+        .args(["validate", "--input"])
+        .arg(format!("{}{}", dir, fixture))
+        .args(extra.iter().map(|a| a.replace("{fixtures}", dir)))
+        .output()
+        .expect("failed to run stargazer-cli")
+        .status
+        .code()
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_validate_exit_codes() {
+    assert_eq!(validate_exit_code("catalog_clean.json", &[]), Some(0));
+    assert_eq!(
+        validate_exit_code("catalog_clean.json", &["--strict"]),
+        Some(0)
+    );
+    assert_eq!(validate_exit_code("catalog_warnings.json", &[]), Some(0));
+    assert_eq!(
+        validate_exit_code("catalog_warnings.json", &["--strict"]),
+        Some(1)
+    );
+    assert_eq!(validate_exit_code("catalog_broken.json", &[]), Some(1));
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_validate_constellation_lines() {
+    // The lines reference star 7, which the clean catalog lacks
+    assert_eq!(
+        validate_exit_code(
+            "catalog_clean.json",
+            &[
+                "--constellation-lines",
+                "{fixtures}constellation_lines.json"
+            ]
+        ),
+        Some(1)
+    );
+}
+
+#[cfg(feature = "cli")]
+fn scripted_quiz(answers: &std::path::Path) -> serde_json::Value {
+    let run = std::process::Command::new(env!("CARGO_BIN_EXE_stargazer-cli"))
+        .args(["quiz", "--count", "5", "--seed", "7", "--format", "json"])
+        .arg("--answers-file")
+        .arg(answers)
+        .output()
+        .expect("failed to run stargazer-cli");
+    assert!(run.status.success());
+    serde_json::from_slice(&run.stdout).unwrap()
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_scripted_quiz_totals() {
+    let fixture = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/answers_scripted.txt"
+    );
+    let first = scripted_quiz(std::path::Path::new(fixture));
+    let answers = first["answers"].as_array().unwrap();
+    assert_eq!(answers.len(), 5);
+    assert_eq!(first["seed"], 7);
+    // Only the first line can resolve; the rest are malformed or missing
+    for (answer, note) in
+        answers[1..]
+            .iter()
+            .zip(["not one of", "missing", "out of range", "missing"])
+    {
+        assert_eq!(answer["correct"], false);
+        assert!(answer["note"].as_str().unwrap().contains(note));
+    }
+
+    // Same seed, same questions: answer the first three correctly by name
+    let script: Vec<String> = answers
+        .iter()
+        .enumerate()
+        .map(|(i, a)| match i {
+            0..=2 => a["correct_answer"].as_str().unwrap().to_string(),
+            _ => "nonsense".to_string(),
+        })
+        .collect();
+    let path = std::env::temp_dir().join(format!("stargazer-answers-{}.txt", std::process::id()));
+    std::fs::write(&path, script.join("\n")).unwrap();
+    let second = scripted_quiz(&path);
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(second["score"]["correct"], 3);
+    assert_eq!(second["score"]["incorrect"], 2);
+    assert_eq!(second["score"]["best_streak"], 3);
+    assert_eq!(second["accuracy"], 60.0);
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_telemetry_log_records_scripted_session() {
+    let answers = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/answers_scripted.txt"
+    );
+    let log =
+        std::env::temp_dir().join(format!("stargazer-telemetry-{}.jsonl", std::process::id()));
+    std::fs::remove_file(&log).ok();
+
+    // Two runs append to the same log
+    for _ in 0..2 {
+        let run = std::process::Command::new(env!("CARGO_BIN_EXE_stargazer-cli"))
+            .args([
+                "quiz",
+                "--count",
+                "5",
+                "--seed",
+                "7",
+                "--difficulty",
+                "easy",
+            ])
+            .args(["--answers-file", answers, "--telemetry-log"])
+            .arg(&log)
+            .output()
+            .expect("failed to run stargazer-cli");
+        assert!(run.status.success());
+    }
+    let records: Vec<serde_json::Value> = std::fs::read_to_string(&log)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    std::fs::remove_file(&log).ok();
+
+    assert_eq!(records.len(), 12);
+    let session = &records[..6];
+    assert_eq!(session, &records[6..]);
+    for answer in &session[..5] {
+        assert_eq!(answer["event"], "questionAnswered");
+        assert_eq!(answer["difficulty"], "easy");
+        assert_eq!(answer["elapsedMs"], 0);
+    }
+    // Only the first line of the fixture can resolve
+    for answer in &session[1..5] {
+        assert_eq!(answer["correct"], false);
+    }
+    let correct = session[0]["correct"].as_bool().unwrap();
+    assert_eq!(session[5]["event"], "sessionEnded");
+    assert_eq!(session[5]["total"], 5);
+    assert_eq!(session[5]["correct"], u32::from(correct));
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_bench_reports_every_metric() {
+    let run = std::process::Command::new(env!("CARGO_BIN_EXE_stargazer-cli"))
+        .args([
+            "bench",
+            "--stars",
+            "500",
+            "--iterations",
+            "3",
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("failed to run stargazer-cli");
+    assert!(run.status.success());
+
+    let report: serde_json::Value = serde_json::from_slice(&run.stdout).unwrap();
+    assert_eq!(report["stars"], 500);
+    let names: Vec<&str> = report["measurements"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|m| m["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "build_indices",
+            "stars_in_range",
+            "query_viewport",
+            "random_distractors",
+            "tile_system",
+            "nearest_star",
+            "generate_question"
+        ]
+    );
+    for m in report["measurements"].as_array().unwrap() {
+        assert!(m["iterations"].as_u64().unwrap() > 0);
+        assert!(m["ns_per_op"].as_f64().is_some());
+        assert!(m["total_ns"].as_u64().is_some());
+    }
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_list_named_filters_and_sorts() {
+    use stargazer_poc::data::Star;
+
+    let run = std::process::Command::new(env!("CARGO_BIN_EXE_stargazer-cli"))
+        .args(["list-named", "--constellation", "uMa", "--sort", "dec"])
+        .args(["--limit", "3", "--format", "json"])
+        .output()
+        .expect("failed to run stargazer-cli");
+    assert!(run.status.success());
+
+    let stars: Vec<Star> = serde_json::from_slice(&run.stdout).unwrap();
+    assert_eq!(stars.len(), 3);
+    assert!(stars
+        .iter()
+        .all(|s| s.constellation.as_deref() == Some("UMa")));
+    assert!(stars.windows(2).all(|w| w[0].coord.dec <= w[1].coord.dec));
+}
+
+/// Run `merge` on fixtures, returning whether it succeeded, its report and the written stars
+#[cfg(feature = "cli")]
+fn merge_fixtures(
+    overlay: &str,
+    extra: &[&str],
+) -> (bool, String, Option<Vec<stargazer_poc::data::Star>>) {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/");
+    let output = std::env::temp_dir().join(format!(
+        "stargazer-merge-{}-{}{}",
+        std::process::id(),
+        extra.join(""),
+        overlay
+    ));
+    std::fs::remove_file(&output).ok();
+
+    let run = std::process::Command::new(env!("CARGO_BIN_EXE_stargazer-cli"))
+        .arg("merge")
+        .arg(format!("{}catalog_clean.json", dir))
+        .arg(format!("{}{}", dir, overlay))
+        .args(extra)
+        .arg("--output")
+        .arg(&output)
+        .output()
+        .expect("failed to run stargazer-cli");
+
+    let stars = std::fs::read_to_string(&output)
+        .ok()
+        .map(|json| serde_json::from_str(&json).unwrap());
+    std::fs::remove_file(&output).ok();
+    (
+        run.status.success(),
+        String::from_utf8_lossy(&run.stdout).into_owned(),
+        stars,
+    )
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_merge_policies() {
+    let name_of = |stars: &[stargazer_poc::data::Star], id: u32| {
+        stars
+            .iter()
+            .find(|s| s.id.0 == id)
+            .and_then(|s| s.name.clone())
+    };
+
+    let (ok, report, stars) = merge_fixtures("merge_overlay.json", &[]);
+    let stars = stars.unwrap();
+    assert!(ok);
+    assert!(report.contains("Added:   1") && report.contains("Skipped: 2"));
+    assert_eq!(stars.len(), 5);
+    assert_eq!(name_of(&stars, 2).as_deref(), Some("Rigel"));
+    // Vega's id 3 is Bellatrix's in the base, so it is renumbered
+    assert_eq!(name_of(&stars, 23).as_deref(), Some("Vega"));
+
+    let (ok, report, stars) = merge_fixtures("merge_overlay.json", &["--policy", "fill-missing"]);
+    let stars = stars.unwrap();
+    assert!(ok);
+    assert!(report.contains("Updated: 1"));
+    assert_eq!(name_of(&stars, 2).as_deref(), Some("Rigel"));
+    assert_eq!(name_of(&stars, 4).as_deref(), Some("Heka"));
+
+    let (ok, report, stars) = merge_fixtures(
+        "merge_overlay.json",
+        &["--policy", "prefer-incoming", "--verbose"],
+    );
+    let stars = stars.unwrap();
+    assert!(ok);
+    assert!(report.contains("Updated: 2"));
+    assert!(report.contains("  - Rigel\n  + Rigel A"));
+    assert_eq!(name_of(&stars, 2).as_deref(), Some("Rigel A"));
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_merge_tolerance() {
+    // Heka sits 10" from the base's unnamed star 4
+    let (_, _, stars) = merge_fixtures(
+        "merge_overlay.json",
+        &["--policy", "prefer-incoming", "--tolerance-arcsec", "30"],
+    );
+    assert_eq!(stars.unwrap().len(), 5);
+
+    let (_, report, stars) = merge_fixtures(
+        "merge_overlay.json",
+        &["--policy", "prefer-incoming", "--tolerance-arcsec", "5"],
+    );
+    assert!(report.contains("Added:   2"));
+    assert_eq!(stars.unwrap().len(), 6);
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_merge_refuses_invalid_result() {
+    let (ok, report, stars) = merge_fixtures("merge_overlay_broken.json", &[]);
+    assert!(!ok);
+    assert!(report.contains("Errors (1)"));
+    assert!(stars.is_none());
+
+    let (ok, _, stars) = merge_fixtures("merge_overlay_broken.json", &["--force"]);
+    assert!(ok);
+    assert_eq!(stars.unwrap().len(), 5);
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_star_of_the_day_is_stable() {
+    let run = || {
+        std::process::Command::new(env!("CARGO_BIN_EXE_stargazer-cli"))
+            .args(["star-of-the-day", "--date", "2025-03-01"])
+            .output()
+            .expect("failed to run stargazer-cli")
+    };
+    let first = run();
+    assert!(first.status.success());
+    assert_eq!(first.stdout, run().stdout);
+
+    let text = String::from_utf8_lossy(&first.stdout);
+    assert!(text.contains("Star of the day for 2025-03-01"));
+    assert!(text.contains("=== Dubhe ==="));
+    assert!(text.contains("Finder chart:"));
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_lookup_by_bayer_designation() {
+    let run = std::process::Command::new(env!("CARGO_BIN_EXE_stargazer-cli"))
+        .args(["lookup", "alf Ori"])
+        .output()
+        .expect("failed to run stargazer-cli");
+    assert!(run.status.success());
+
+    let text = String::from_utf8_lossy(&run.stdout);
+    assert!(text.contains("=== Betelgeuse ==="), "{}", text);
+    assert!(text.contains("Designation:   α Ori, 58 Ori"), "{}", text);
+    assert!(text.contains("Spectral type: M1-2Ia-Iab"), "{}", text);
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_progress_import_then_export() {
+    use stargazer_poc::game::progress::Progress;
+
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/progress.json");
+    let dir = std::env::temp_dir().join(format!("stargazer-cli-progress-{}", std::process::id()));
+    let deck = dir.join("deck.json");
+    let cli = |args: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_stargazer-cli"))
+            .args(args)
+            .arg("--deck")
+            .arg(&deck)
+            .output()
+            .expect("failed to run stargazer-cli")
+    };
+
+    let import = cli(&["progress", "import", fixture]);
+    assert!(import.status.success());
+    let report = String::from_utf8_lossy(&import.stdout);
+    assert!(report.contains("(1 cards)"), "{}", report);
+    assert!(report.contains("Stars:        2"), "{}", report);
+    assert!(report.contains("Achievements: 1"), "{}", report);
+
+    // The deck and everything kept beside it come back out as they went in
+    let export = cli(&["progress", "export"]);
+    assert!(export.status.success());
+    std::fs::remove_dir_all(&dir).ok();
+    let expected = Progress::from_json(&std::fs::read_to_string(fixture).unwrap()).unwrap();
+    let exported = Progress::from_json(&String::from_utf8_lossy(&export.stdout)).unwrap();
+    assert_eq!(exported, expected);
+}
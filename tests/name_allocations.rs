@@ -0,0 +1,86 @@
+//! Allocation counts for the quiz path
+//!
+//! Star names in questions and the guess history are shared, so the
+//! reducer's per-action state clone must not copy them. A counting
+//! allocator checks that over a 100-question session.
+
+use stargazer_poc::data::generate_placeholder_catalog;
+use stargazer_poc::game::engine::HeadlessGame;
+use stargazer_poc::game::QuizConfig;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// The system allocator, counting allocations made on each thread
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Allocations made on this thread while running `f`
+fn allocations<T>(f: impl FnOnce() -> T) -> (usize, T) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (ALLOCATIONS.with(Cell::get) - before, result)
+}
+
+/// Play one question, answering with its first choice
+fn play(game: &mut HeadlessGame) -> usize {
+    let first = game
+        .next_question()
+        .expect("catalog has named stars")
+        .choices[0]
+        .clone();
+    allocations(|| game.answer(&first)).0
+}
+
+#[test]
+fn test_hundred_question_session_shares_names() {
+    let config = QuizConfig {
+        num_choices: 5,
+        include_none_option: true,
+        none_probability: 0.2,
+        ..QuizConfig::default()
+    };
+    let mut game = HeadlessGame::new(generate_placeholder_catalog(), config, 100);
+
+    play(&mut game);
+    let (first_clone, _) = allocations(|| game.state().clone());
+    // From the second guess on, the history is never empty
+    let answers: Vec<usize> = (1..100).map(|_| play(&mut game)).collect();
+    assert_eq!(game.state().guess_history.len(), 100);
+
+    // Cloning the state copies the history's buffer, never its names
+    let (last_clone, _) = allocations(|| game.state().clone());
+    assert_eq!(last_clone, first_clone);
+    assert!(answers.iter().all(|&n| n == answers[0]), "{:?}", answers);
+
+    // Each guess points at the same name as the question it answered
+    let quiz = game.quiz().unwrap();
+    let last = game.state().guess_history.last().unwrap();
+    assert!(Rc::ptr_eq(&last.star_name, &quiz.correct_name));
+    assert!(quiz
+        .choices
+        .iter()
+        .any(|c| Rc::ptr_eq(c, &last.user_answer)));
+}
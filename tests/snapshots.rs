@@ -0,0 +1,199 @@
+//! Golden-file tests for the SVG export
+//!
+//! Each scene renders the placeholder catalog with `render_to_svg_string`
+//! and compares the normalized markup against `tests/snapshots/<scene>.svg`.
+//! Run with `UPDATE_SNAPSHOTS=1` to rewrite the golden files after a
+//! deliberate rendering change, and review the diff before committing.
+
+use stargazer_poc::data::generate_placeholder_catalog;
+use stargazer_poc::utils::sky_svg::{render_to_svg_string, SvgExportOptions};
+use stargazer_poc::utils::{LabelDensity, Viewport};
+use std::fmt::Write;
+use std::path::PathBuf;
+
+/// Set to rewrite the golden files instead of checking them
+const UPDATE_VAR: &str = "UPDATE_SNAPSHOTS";
+
+/// A named view of the sky
+struct Scene {
+    name: &'static str,
+    viewport: Viewport,
+    options: SvgExportOptions,
+}
+
+fn viewport(center_ra: f64, center_dec: f64, zoom: f64) -> Viewport {
+    Viewport {
+        center_ra,
+        center_dec,
+        zoom,
+        ..Viewport::new(800.0, 600.0)
+    }
+}
+
+fn scenes() -> Vec<Scene> {
+    let defaults = SvgExportOptions::default();
+    vec![
+        Scene {
+            name: "full_sky",
+            viewport: viewport(12.0, 0.0, 1.0),
+            options: defaults,
+        },
+        Scene {
+            name: "orion_zoomed",
+            viewport: viewport(5.6, 0.0, 4.0),
+            options: defaults,
+        },
+        Scene {
+            name: "polar",
+            viewport: viewport(0.0, 80.0, 2.0),
+            options: defaults,
+        },
+        Scene {
+            name: "dense_labels",
+            viewport: viewport(6.0, -10.0, 2.0),
+            options: SvgExportOptions {
+                label_density: LabelDensity::Dense,
+                ..defaults
+            },
+        },
+        Scene {
+            name: "grid_off",
+            viewport: viewport(18.0, 20.0, 2.0),
+            options: SvgExportOptions {
+                show_grid: false,
+                ..defaults
+            },
+        },
+        Scene {
+            name: "constellations_on",
+            viewport: viewport(5.6, 0.0, 2.0),
+            options: SvgExportOptions {
+                show_constellations: true,
+                ..defaults
+            },
+        },
+    ]
+}
+
+fn render(scene: &Scene) -> String {
+    let catalog = generate_placeholder_catalog();
+    normalize(&render_to_svg_string(
+        &catalog,
+        &scene.viewport,
+        &scene.options,
+    ))
+}
+
+/// Round a numeric attribute value to two decimals; other values pass through
+fn round_value(value: &str) -> String {
+    value
+        .split(' ')
+        .map(|token| match token.parse::<f64>() {
+            Ok(number) => {
+                let rounded = format!("{:.2}", number);
+                if rounded == "-0.00" {
+                    "0.00".to_string()
+                } else {
+                    rounded
+                }
+            }
+            Err(_) => token.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// One element per line with sorted attributes, volatile attributes
+/// (ids, build data) dropped and numbers rounded
+fn normalize(svg: &str) -> String {
+    let document = roxmltree::Document::parse(svg).expect("export is well-formed XML");
+    let mut out = String::new();
+    for node in document.descendants().filter(|n| n.is_element()) {
+        let depth = node.ancestors().filter(|n| n.is_element()).count() - 1;
+        let mut attributes: Vec<(String, String)> = node
+            .attributes()
+            .filter(|a| a.name() != "id" && !a.name().starts_with("data-"))
+            .map(|a| (a.name().to_string(), round_value(a.value())))
+            .collect();
+        attributes.sort();
+
+        let _ = write!(out, "{}<{}", "  ".repeat(depth), node.tag_name().name());
+        for (name, value) in attributes {
+            let _ = write!(out, " {}=\"{}\"", name, value);
+        }
+        out.push('>');
+        let text: String = node
+            .children()
+            .filter(|n| n.is_text())
+            .filter_map(|n| n.text())
+            .collect();
+        out.push_str(text.trim());
+        out.push('\n');
+    }
+    out
+}
+
+fn golden_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{}.svg", name))
+}
+
+#[test]
+fn test_scenes_match_golden_files() {
+    let update = std::env::var_os(UPDATE_VAR).is_some();
+    let mut mismatched = Vec::new();
+
+    for scene in scenes() {
+        let actual = render(&scene);
+        let path = golden_path(scene.name);
+        if update {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, &actual).unwrap();
+            continue;
+        }
+
+        let expected = std::fs::read_to_string(&path).unwrap_or_default();
+        if actual != expected {
+            let first_difference = actual
+                .lines()
+                .zip(expected.lines())
+                .position(|(a, e)| a != e)
+                .map_or_else(|| "length".to_string(), |line| format!("line {}", line + 1));
+            mismatched.push(format!(
+                "{} (first difference at {})",
+                scene.name, first_difference
+            ));
+        }
+    }
+
+    assert!(
+        mismatched.is_empty(),
+        "SVG output differs from tests/snapshots for: {}; rerun with {}=1 if the change is intended",
+        mismatched.join(", "),
+        UPDATE_VAR
+    );
+}
+
+#[test]
+fn test_one_pixel_shift_is_detected() {
+    let scene = &scenes()[0];
+    let mut shifted = scene.viewport;
+    shifted.pan(1.0, 0.0);
+    let moved = Scene {
+        name: scene.name,
+        viewport: shifted,
+        options: scene.options,
+    };
+    assert_ne!(render(&moved), render(scene));
+}
+
+#[test]
+fn test_normalize_rounds_and_drops_volatile_attributes() {
+    let svg =
+        r##"<svg><circle id="s7" data-build="abc" r="1.005" cx="-0.001" fill="#fff"/></svg>"##;
+    assert_eq!(
+        normalize(svg),
+        "<svg>\n  <circle cx=\"0.00\" fill=\"#fff\" r=\"1.00\">\n"
+    );
+}
@@ -0,0 +1,49 @@
+use super::{mount, recorder};
+use stargazer_poc::components::challenge_results::{ChallengeResults, ChallengeResultsProps};
+use stargazer_poc::data::generate_placeholder_catalog;
+use stargazer_poc::game::{DailyChallenge, GameAction};
+use stargazer_poc::utils::dates::days_from_civil;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn finished_challenge() -> DailyChallenge {
+    let catalog = generate_placeholder_catalog();
+    let mut challenge = DailyChallenge::new(&catalog, days_from_civil(2025, 3, 1));
+    while let Some(star) = challenge.next_question().map(|q| q.target_star) {
+        let right = !challenge.results.len().is_multiple_of(3);
+        challenge.record(star, right);
+    }
+    challenge
+}
+
+#[wasm_bindgen_test]
+async fn shows_the_score_and_share_text() {
+    let challenge = finished_challenge();
+    let results = mount::<ChallengeResults>(ChallengeResultsProps {
+        challenge: challenge.clone(),
+        on_action: yew::Callback::noop(),
+    })
+    .await;
+
+    assert_eq!(results.text(".challenge-date"), "2025-03-01");
+    assert_eq!(results.text(".challenge-score"), "6 of 10 right");
+    assert_eq!(results.count(".challenge-marks li"), 10);
+    assert_eq!(results.count(".challenge-marks li.incorrect"), 4);
+    assert_eq!(results.text(".share-text"), challenge.share_text());
+}
+
+#[wasm_bindgen_test]
+async fn closing_ends_the_challenge() {
+    let (on_action, actions) = recorder();
+    let results = mount::<ChallengeResults>(ChallengeResultsProps {
+        challenge: finished_challenge(),
+        on_action,
+    })
+    .await;
+
+    results.click(".close-btn").await;
+    results.click(".close-button").await;
+    assert_eq!(
+        *actions.borrow(),
+        vec![GameAction::EndChallenge, GameAction::EndChallenge]
+    );
+}
@@ -0,0 +1,31 @@
+use super::{mount, recorder};
+use stargazer_poc::components::countdown::{Countdown, CountdownProps};
+use stargazer_poc::game::GameAction;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn shows_the_time_left() {
+    let (on_action, actions) = recorder();
+    let countdown = mount::<Countdown>(CountdownProps {
+        ends_at: js_sys::Date::now() + 90_000.0,
+        on_action,
+    })
+    .await;
+
+    assert_eq!(countdown.text(".countdown"), "1:30");
+    assert!(!countdown.has(".countdown.urgent"));
+    assert!(actions.borrow().is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn reports_when_time_is_up() {
+    let (on_action, actions) = recorder();
+    let countdown = mount::<Countdown>(CountdownProps {
+        ends_at: js_sys::Date::now() - 1.0,
+        on_action,
+    })
+    .await;
+
+    assert_eq!(countdown.text(".countdown.urgent"), "0:00");
+    assert_eq!(*actions.borrow(), vec![GameAction::TimeUp]);
+}
@@ -0,0 +1,126 @@
+//! Browser tests for UI components
+//!
+//! Each test mounts one component into a detached element, waits for Yew
+//! to render, and checks the DOM it produced. Run with
+//! `wasm-pack test --headless --chrome` (or `--firefox`).
+
+#![cfg(all(target_arch = "wasm32", feature = "web"))]
+
+mod challenge_results;
+mod countdown;
+mod progress_map;
+mod quiz_dropdown;
+mod reducer_flow;
+mod run_results;
+mod score_display;
+mod settings_panel;
+mod shortcuts_help;
+mod slices;
+mod star_map;
+mod stats_dashboard;
+mod summary_popup;
+mod time_control;
+mod tour_card;
+mod trophy_panel;
+
+use stargazer_poc::data::StarId;
+use stargazer_poc::game::{GameAction, QuizState, QuizTarget};
+use stargazer_poc::utils::perf;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::wasm_bindgen_test_configure;
+use web_sys::{Element, HtmlElement};
+use yew::{AppHandle, BaseComponent, Callback, Renderer};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// A mounted component and the element it rendered into
+pub struct Mounted<C: BaseComponent> {
+    pub root: Element,
+    _handle: AppHandle<C>,
+}
+
+impl<C: BaseComponent> Mounted<C> {
+    /// The first element matching `selector`
+    pub fn find(&self, selector: &str) -> Element {
+        self.root
+            .query_selector(selector)
+            .unwrap()
+            .unwrap_or_else(|| panic!("nothing matches {}", selector))
+    }
+
+    /// Whether anything matches `selector`
+    pub fn has(&self, selector: &str) -> bool {
+        self.root.query_selector(selector).unwrap().is_some()
+    }
+
+    /// How many elements match `selector`
+    pub fn count(&self, selector: &str) -> u32 {
+        self.root.query_selector_all(selector).unwrap().length()
+    }
+
+    /// Text of the first element matching `selector`
+    pub fn text(&self, selector: &str) -> String {
+        self.find(selector).text_content().unwrap_or_default()
+    }
+
+    /// Click the first element matching `selector` and wait for the re-render
+    pub async fn click(&self, selector: &str) {
+        self.find(selector).unchecked_into::<HtmlElement>().click();
+        settle().await;
+    }
+}
+
+/// Mount `C` with `props` into a fresh detached element and wait for it to render
+pub async fn mount<C: BaseComponent>(props: C::Properties) -> Mounted<C> {
+    let root = gloo_utils::document().create_element("div").unwrap();
+    let handle = Renderer::<C>::with_root_and_props(root.clone(), props).render();
+    settle().await;
+    Mounted {
+        root,
+        _handle: handle,
+    }
+}
+
+/// Wait until Yew's scheduled renders and effects have run
+pub async fn settle() {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        gloo_utils::window()
+            .set_timeout_with_callback(&resolve)
+            .unwrap();
+    });
+    JsFuture::from(promise).await.unwrap();
+}
+
+/// How many times any star map has rendered on this page
+pub fn star_map_renders() -> u64 {
+    perf::snapshot().render_count()
+}
+
+/// A callback that records the actions it receives
+pub fn recorder() -> (Callback<GameAction>, Rc<RefCell<Vec<GameAction>>>) {
+    let actions = Rc::new(RefCell::new(Vec::new()));
+    let log = actions.clone();
+    (
+        Callback::from(move |action| log.borrow_mut().push(action)),
+        actions,
+    )
+}
+
+/// An unanswered question about Sirius
+pub fn sirius_quiz() -> QuizState {
+    QuizState {
+        target: QuizTarget::Star(StarId(1)),
+        correct_name: "Sirius".into(),
+        choices: ["Vega", "Sirius", "Arcturus", "Rigel"]
+            .map(Into::into)
+            .to_vec(),
+        selected_answer: None,
+        answered: false,
+        was_correct: None,
+        started_at: 0.0,
+        hints: 0,
+    }
+}
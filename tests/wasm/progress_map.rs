@@ -0,0 +1,57 @@
+use super::{mount, recorder, settle};
+use stargazer_poc::components::progress_map::{ProgressMap, ProgressMapProps};
+use stargazer_poc::game::heatmap::RegionMastery;
+use stargazer_poc::game::GameAction;
+use std::rc::Rc;
+use wasm_bindgen_test::wasm_bindgen_test;
+use web_sys::{Event, EventInit};
+
+fn region(ra: f64, dec: f64, answered: usize, mastery: f64) -> RegionMastery {
+    RegionMastery {
+        ra_min: ra - 1.0,
+        ra_max: ra + 1.0,
+        dec_min: dec - 10.0,
+        dec_max: dec + 10.0,
+        named: 4,
+        answered,
+        mastery,
+    }
+}
+
+#[wasm_bindgen_test]
+async fn colors_regions_and_centers_on_a_click() {
+    let (on_action, actions) = recorder();
+    let map = mount::<ProgressMap>(ProgressMapProps {
+        regions: Rc::new(vec![region(6.0, -20.0, 2, 0.5), region(18.0, 40.0, 0, 0.0)]),
+        on_action,
+    })
+    .await;
+
+    assert_eq!(map.count("rect.region"), 2);
+    assert_eq!(map.count("rect.region.untouched"), 1);
+    let learned = map.find("rect.region:not(.untouched)");
+    assert_eq!(
+        learned.get_attribute("fill").as_deref(),
+        Some("hsl(60, 70%, 45%)")
+    );
+    assert_eq!(
+        map.text("rect.region:not(.untouched) title"),
+        "2 of 4 stars answered, 50% known"
+    );
+
+    // SVG elements have no click(), so send the event by hand
+    let init = EventInit::new();
+    init.set_bubbles(true);
+    let click = Event::new_with_event_init_dict("click", &init).unwrap();
+    learned.dispatch_event(&click).unwrap();
+    settle().await;
+    map.click(".close-button").await;
+    assert_eq!(
+        *actions.borrow(),
+        vec![
+            GameAction::SetCenter(6.0, -20.0),
+            GameAction::ToggleProgressMap,
+            GameAction::ToggleProgressMap
+        ]
+    );
+}
@@ -0,0 +1,236 @@
+use super::{mount, recorder, settle, sirius_quiz};
+use stargazer_poc::app::i18n::{set_locale, Locale};
+use stargazer_poc::components::quiz_dropdown::{QuizDropdown, QuizDropdownProps};
+use stargazer_poc::game::GameAction;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::wasm_bindgen_test;
+use web_sys::{Event, EventInit, HtmlInputElement};
+use yew::Callback;
+
+fn props(position: (f64, f64)) -> QuizDropdownProps {
+    QuizDropdownProps {
+        quiz: sirius_quiz(),
+        position,
+        map_size: (1200.0, 600.0),
+        on_action: Callback::noop(),
+        compact: false,
+        typed: false,
+        return_focus: Default::default(),
+        progress: None,
+    }
+}
+
+#[wasm_bindgen_test]
+async fn renders_one_button_per_choice() {
+    let dropdown = mount::<QuizDropdown>(props((100.0, 100.0))).await;
+    assert_eq!(dropdown.count("button.quiz-choice"), 4);
+    assert_eq!(dropdown.text("button.quiz-choice .choice-text"), "Vega");
+    assert!(!dropdown.has(".quiz-result"));
+}
+
+#[wasm_bindgen_test]
+async fn position_is_clamped_to_the_map() {
+    let near_corner = mount::<QuizDropdown>(props((1100.0, 500.0))).await;
+    let style = near_corner
+        .find(".quiz-dropdown")
+        .get_attribute("style")
+        .unwrap();
+    assert!(style.contains("left: 865px"), "{}", style);
+    assert!(style.contains("top: 165px"), "{}", style);
+
+    let near_edge = mount::<QuizDropdown>(props((1190.0, 310.0))).await;
+    let style = near_edge
+        .find(".quiz-dropdown")
+        .get_attribute("style")
+        .unwrap();
+    assert!(style.contains("top: 15px"), "{}", style);
+
+    // On a phone-sized map the same star is already near the right edge
+    let small_map = mount::<QuizDropdown>(QuizDropdownProps {
+        map_size: (400.0, 700.0),
+        ..props((300.0, 310.0))
+    })
+    .await;
+    let style = small_map
+        .find(".quiz-dropdown")
+        .get_attribute("style")
+        .unwrap();
+    assert!(style.contains("left: 65px"), "{}", style);
+    assert!(style.contains("top: 325px"), "{}", style);
+}
+
+#[wasm_bindgen_test]
+async fn compact_layout_docks_to_the_bottom() {
+    let dropdown = mount::<QuizDropdown>(QuizDropdownProps {
+        compact: true,
+        ..props((100.0, 100.0))
+    })
+    .await;
+    assert!(dropdown.has(".quiz-dropdown.bottom-sheet"));
+    assert_eq!(
+        dropdown.find(".quiz-dropdown").get_attribute("style"),
+        Some(String::new())
+    );
+}
+
+#[wasm_bindgen_test]
+async fn clicking_a_choice_submits_it() {
+    let (on_action, actions) = recorder();
+    let dropdown = mount::<QuizDropdown>(QuizDropdownProps {
+        on_action,
+        ..props((100.0, 100.0))
+    })
+    .await;
+
+    dropdown.click("button.quiz-choice:nth-child(3)").await;
+    assert_eq!(
+        *actions.borrow(),
+        vec![GameAction::SelectAndSubmitAnswer("Arcturus".into())]
+    );
+
+    dropdown.click(".close-button").await;
+    assert_eq!(actions.borrow().last(), Some(&GameAction::CloseQuiz));
+}
+
+#[wasm_bindgen_test]
+async fn answered_quiz_shows_the_result_and_ignores_clicks() {
+    let (on_action, actions) = recorder();
+    let mut quiz = sirius_quiz();
+    quiz.selected_answer = Some("Vega".into());
+    quiz.answered = true;
+    quiz.was_correct = Some(false);
+    let dropdown = mount::<QuizDropdown>(QuizDropdownProps {
+        quiz,
+        on_action,
+        ..props((100.0, 100.0))
+    })
+    .await;
+
+    assert_eq!(dropdown.text(".result"), "Incorrect");
+    assert_eq!(dropdown.text(".correct-answer"), "The answer was: Sirius");
+    assert!(dropdown.has("button.quiz-choice.correct"));
+    assert!(dropdown.has("button.quiz-choice.wrong"));
+    assert_eq!(
+        dropdown.text("[aria-live]"),
+        "Incorrect, the answer was Sirius"
+    );
+
+    dropdown.click("button.quiz-choice").await;
+    assert!(actions.borrow().is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn typed_mode_submits_the_text_box() {
+    let (on_action, actions) = recorder();
+    let dropdown = mount::<QuizDropdown>(QuizDropdownProps {
+        typed: true,
+        on_action,
+        ..props((100.0, 100.0))
+    })
+    .await;
+    assert_eq!(dropdown.count("button.quiz-choice"), 0);
+
+    dropdown
+        .find(".typed-answer-input")
+        .unchecked_into::<HtmlInputElement>()
+        .set_value("Sirus");
+    let init = EventInit::new();
+    init.set_bubbles(true);
+    init.set_cancelable(true);
+    let submit = Event::new_with_event_init_dict("submit", &init).unwrap();
+    dropdown
+        .find("form.typed-answer")
+        .dispatch_event(&submit)
+        .unwrap();
+    settle().await;
+
+    assert!(submit.default_prevented());
+    assert_eq!(
+        *actions.borrow(),
+        vec![GameAction::SubmitTypedAnswer("Sirus".into())]
+    );
+}
+
+#[wasm_bindgen_test]
+async fn misspelled_answers_are_accepted_with_a_warning() {
+    let mut quiz = sirius_quiz();
+    quiz.selected_answer = Some("Sirus".into());
+    quiz.answered = true;
+    quiz.was_correct = Some(true);
+    let dropdown = mount::<QuizDropdown>(QuizDropdownProps {
+        quiz,
+        typed: true,
+        ..props((100.0, 100.0))
+    })
+    .await;
+
+    assert_eq!(dropdown.text(".result"), "Correct!");
+    assert_eq!(
+        dropdown.text(".spelling-warning"),
+        "Close enough! It's spelled Sirius"
+    );
+    assert!(dropdown.has(".typed-answer-input.correct:disabled"));
+}
+
+#[wasm_bindgen_test]
+async fn challenge_questions_show_progress_and_move_on() {
+    let (on_action, actions) = recorder();
+    let mut quiz = sirius_quiz();
+    quiz.answered = true;
+    quiz.was_correct = Some(true);
+    let dropdown = mount::<QuizDropdown>(QuizDropdownProps {
+        quiz: quiz.clone(),
+        on_action,
+        progress: Some((3, 10)),
+        ..props((100.0, 100.0))
+    })
+    .await;
+
+    assert_eq!(dropdown.text(".challenge-progress"), "Question 3 of 10");
+    assert_eq!(dropdown.text(".next-button"), "Next");
+    dropdown.click(".next-button").await;
+    assert_eq!(*actions.borrow(), vec![GameAction::NextQuestion]);
+
+    let last = mount::<QuizDropdown>(QuizDropdownProps {
+        quiz,
+        progress: Some((10, 10)),
+        ..props((100.0, 100.0))
+    })
+    .await;
+    assert_eq!(last.text(".next-button"), "See results");
+}
+
+#[wasm_bindgen_test]
+async fn text_follows_the_interface_language() {
+    let mut quiz = sirius_quiz();
+    quiz.answered = true;
+    quiz.was_correct = Some(false);
+    set_locale(Locale::Spanish);
+    let dropdown = mount::<QuizDropdown>(QuizDropdownProps {
+        quiz,
+        ..props((100.0, 100.0))
+    })
+    .await;
+    set_locale(Locale::English);
+
+    assert_eq!(dropdown.text(".quiz-title"), "¿Qué estrella es esta?");
+    assert_eq!(dropdown.text(".result"), "Incorrecto");
+    assert_eq!(dropdown.text(".correct-answer"), "La respuesta era: Sirius");
+}
+
+#[wasm_bindgen_test]
+async fn hints_show_the_start_of_the_answer() {
+    let (on_action, actions) = recorder();
+    let mut quiz = sirius_quiz();
+    quiz.hints = 1;
+    let dropdown = mount::<QuizDropdown>(QuizDropdownProps {
+        quiz,
+        on_action,
+        ..props((100.0, 100.0))
+    })
+    .await;
+    assert_eq!(dropdown.text(".hint-text"), "S…");
+
+    dropdown.click(".hint-button").await;
+    assert_eq!(*actions.borrow(), vec![GameAction::TakeHint]);
+}
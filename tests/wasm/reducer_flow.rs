@@ -0,0 +1,58 @@
+//! The quiz dropdown wired to the real reducer, as the app does it
+
+use super::{mount, sirius_quiz};
+use stargazer_poc::components::{QuizDropdown, ScoreDisplay};
+use stargazer_poc::game::{GameAction, GameState};
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+
+/// A question about Sirius, with the score beside it
+#[function_component(QuizHarness)]
+fn quiz_harness() -> Html {
+    let state = use_reducer(|| GameState {
+        quiz: Some(sirius_quiz()),
+        ..GameState::default()
+    });
+    let on_action = {
+        let state = state.clone();
+        Callback::from(move |action: GameAction| state.dispatch(action))
+    };
+
+    html! {
+        <>
+            <ScoreDisplay score={state.score.clone()} />
+            if let Some(quiz) = state.quiz.clone() {
+                <QuizDropdown quiz={quiz} position={(100.0, 100.0)} map_size={(1200.0, 600.0)} on_action={on_action} />
+            }
+        </>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn right_answer_is_scored() {
+    let app = mount::<QuizHarness>(()).await;
+    assert_eq!(app.text(".correct-score"), "0/0");
+
+    app.click("button.quiz-choice:nth-child(2)").await;
+    assert_eq!(app.text(".result"), "Correct!");
+    assert!(!app.has(".correct-answer"));
+    assert_eq!(app.text(".correct-score"), "1/1");
+    assert!(app.has("button.quiz-choice.selected.correct"));
+}
+
+#[wasm_bindgen_test]
+async fn wrong_answer_reveals_the_star_and_locks_the_quiz() {
+    let app = mount::<QuizHarness>(()).await;
+
+    app.click("button.quiz-choice:nth-child(4)").await;
+    assert_eq!(app.text(".result"), "Incorrect");
+    assert_eq!(app.text(".correct-answer"), "The answer was: Sirius");
+    assert_eq!(app.text(".correct-score"), "0/1");
+
+    // A second click on an answered quiz changes nothing
+    app.click("button.quiz-choice:nth-child(2)").await;
+    assert_eq!(app.text(".correct-score"), "0/1");
+
+    app.click(".close-button").await;
+    assert!(!app.has(".quiz-dropdown"));
+}
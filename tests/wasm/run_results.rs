@@ -0,0 +1,79 @@
+use super::{mount, recorder};
+use stargazer_poc::components::run_results::{RunResults, RunResultsProps};
+use stargazer_poc::game::{GameAction, GameMode, RoundRun, SurvivalRun, TimeAttackRun};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn ended_run() -> GameMode {
+    GameMode::Survival(SurvivalRun {
+        lives: 0,
+        survived: 12,
+    })
+}
+
+#[wasm_bindgen_test]
+async fn shows_the_questions_survived() {
+    let results = mount::<RunResults>(RunResultsProps {
+        mode: ended_run(),
+        on_action: yew::Callback::noop(),
+    })
+    .await;
+
+    assert_eq!(results.text("#run-title"), "Survival");
+    assert_eq!(results.text(".run-ending"), "Out of lives");
+    assert_eq!(results.text(".challenge-score"), "12 questions survived");
+}
+
+#[wasm_bindgen_test]
+async fn plays_again_or_ends_the_run() {
+    let (on_action, actions) = recorder();
+    let results = mount::<RunResults>(RunResultsProps {
+        mode: ended_run(),
+        on_action,
+    })
+    .await;
+
+    results.click(".play-again").await;
+    results.click(".close-btn").await;
+    assert_eq!(
+        *actions.borrow(),
+        vec![GameAction::StartSurvival, GameAction::EndRun]
+    );
+}
+
+#[wasm_bindgen_test]
+async fn time_attack_plays_again_for_as_long() {
+    let (on_action, actions) = recorder();
+    let results = mount::<RunResults>(RunResultsProps {
+        mode: GameMode::TimeAttack(TimeAttackRun {
+            seconds: 120,
+            ends_at: 0.0,
+            answered: 9,
+            correct: 7,
+            time_up: true,
+        }),
+        on_action,
+    })
+    .await;
+
+    assert_eq!(results.text(".run-ending"), "Time's up");
+    assert_eq!(results.text(".challenge-score"), "7 right of 9 answered");
+    results.click(".play-again").await;
+    assert_eq!(*actions.borrow(), vec![GameAction::StartTimeAttack(120)]);
+}
+
+#[wasm_bindgen_test]
+async fn round_ends_with_a_grade() {
+    let results = mount::<RunResults>(RunResultsProps {
+        mode: GameMode::Round(RoundRun {
+            length: 10,
+            answered: 10,
+            correct: 8,
+        }),
+        on_action: yew::Callback::noop(),
+    })
+    .await;
+
+    assert_eq!(results.text(".run-ending"), "Round complete");
+    assert_eq!(results.text(".run-grade"), "B");
+    assert_eq!(results.text(".challenge-score"), "8 of 10 right");
+}
@@ -0,0 +1,68 @@
+use super::mount;
+use stargazer_poc::components::score_display::{ScoreDisplay, ScoreDisplayProps};
+use stargazer_poc::game::ScoreState;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// Three right and one wrong, ending on a streak of two
+fn score() -> ScoreState {
+    let mut score = ScoreState::default();
+    score.record_correct();
+    score.record_incorrect();
+    score.record_correct();
+    score.record_correct();
+    score.points = 550;
+    score
+}
+
+#[wasm_bindgen_test]
+async fn shows_score_accuracy_and_streak() {
+    let display = mount::<ScoreDisplay>(ScoreDisplayProps {
+        score: score(),
+        compact: false,
+        lives: None,
+    })
+    .await;
+    assert_eq!(display.text(".points"), "550");
+    assert_eq!(display.text(".correct-score"), "3/4");
+    assert_eq!(display.count(".score-item"), 5);
+    assert_eq!(display.text(".streak"), "2🔥");
+    assert_eq!(display.text(".best-streak .score-value"), "2");
+}
+
+#[wasm_bindgen_test]
+async fn fresh_score_has_no_best_streak() {
+    let display = mount::<ScoreDisplay>(ScoreDisplayProps {
+        score: ScoreState::default(),
+        compact: false,
+        lives: None,
+    })
+    .await;
+    assert_eq!(display.text(".correct-score"), "0/0");
+    assert!(!display.has(".best-streak"));
+}
+
+#[wasm_bindgen_test]
+async fn compact_display_is_one_line() {
+    let display = mount::<ScoreDisplay>(ScoreDisplayProps {
+        score: score(),
+        compact: true,
+        lives: None,
+    })
+    .await;
+    assert!(display.has(".score-display.compact"));
+    assert_eq!(display.text(".points"), "★ 550");
+    assert_eq!(display.text(".correct-score"), "✓ 3/4");
+    assert_eq!(display.text("[title=Accuracy]"), "◎ 75%");
+}
+
+#[wasm_bindgen_test]
+async fn survival_shows_the_lives_left() {
+    let display = mount::<ScoreDisplay>(ScoreDisplayProps {
+        score: score(),
+        compact: false,
+        lives: Some(2),
+    })
+    .await;
+    assert_eq!(display.count(".score-item"), 6);
+    assert_eq!(display.text(".lives"), "♥♥♡");
+}
@@ -0,0 +1,49 @@
+use super::{mount, recorder};
+use stargazer_poc::components::settings_panel::{SettingsPanel, SettingsPanelProps};
+use stargazer_poc::data::star_names::Lang;
+use stargazer_poc::game::{AnswerMode, Difficulty, DistractorStrategy, GameAction, QuizConfig};
+use stargazer_poc::utils::{LabelDensity, ProjectionKind};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn choices_and_difficulty_dispatch_settings() {
+    let (on_action, actions) = recorder();
+    let panel = mount::<SettingsPanel>(SettingsPanelProps {
+        config: QuizConfig::default(),
+        difficulty: None,
+        answer_mode: AnswerMode::Choices,
+        label_density: LabelDensity::Normal,
+        name_lang: Lang::Iau,
+        projection: ProjectionKind::Equirectangular,
+        on_action,
+    })
+    .await;
+
+    // Three, four or five choices, with the current count highlighted
+    assert_eq!(panel.count(".choices .toggle-btn"), 3);
+    assert_eq!(panel.text(".choices .toggle-btn.active"), "5");
+
+    panel.click(".answer-mode .toggle-btn:nth-child(2)").await;
+    panel.click(".choices .toggle-btn").await;
+    panel.click(".distractors .toggle-btn:nth-child(2)").await;
+    panel.click(".difficulty .toggle-btn:nth-child(2)").await;
+    panel.click(".label-names .toggle-btn:nth-child(3)").await;
+    panel.click(".close-button").await;
+    assert_eq!(
+        *actions.borrow(),
+        vec![
+            GameAction::SetAnswerMode(AnswerMode::Typed),
+            GameAction::SetQuizConfig(QuizConfig {
+                num_choices: 3,
+                ..QuizConfig::default()
+            }),
+            GameAction::SetQuizConfig(QuizConfig {
+                distractor_strategy: DistractorStrategy::Confusable,
+                ..QuizConfig::default()
+            }),
+            GameAction::SetDifficulty(Some(Difficulty::Easy)),
+            GameAction::SetStarNameLang(Lang::Chinese),
+            GameAction::ToggleSettings,
+        ]
+    );
+}
@@ -0,0 +1,17 @@
+use super::{mount, recorder};
+use stargazer_poc::components::keyboard::SHORTCUTS;
+use stargazer_poc::components::shortcuts_help::{ShortcutsHelp, ShortcutsHelpProps};
+use stargazer_poc::game::GameAction;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn lists_every_shortcut_and_closes() {
+    let (on_action, actions) = recorder();
+    let help = mount::<ShortcutsHelp>(ShortcutsHelpProps { on_action }).await;
+
+    assert_eq!(help.count(".shortcut-row"), SHORTCUTS.len() as u32);
+    assert_eq!(help.text(".shortcut-row kbd"), SHORTCUTS[0].0);
+
+    help.click(".close-button").await;
+    assert_eq!(*actions.borrow(), vec![GameAction::HideHelp]);
+}
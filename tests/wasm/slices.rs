@@ -0,0 +1,81 @@
+//! Only the views whose slice changed re-render
+
+use super::{mount, settle, sirius_quiz, star_map_renders};
+use stargazer_poc::app::slices::Slices;
+use stargazer_poc::app::views::{MapView, QuizView};
+use stargazer_poc::data::generate_placeholder_catalog;
+use stargazer_poc::game::{GameAction, GameState, UiState};
+use stargazer_poc::utils::SkyIndex;
+use std::cell::RefCell;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+
+thread_local! {
+    /// Dispatches to the mounted harness's reducer
+    static DISPATCH: RefCell<Option<Callback<GameAction>>> = const { RefCell::new(None) };
+}
+
+fn dispatch(action: GameAction) {
+    DISPATCH.with(|d| {
+        d.borrow()
+            .clone()
+            .expect("harness not mounted")
+            .emit(action)
+    });
+}
+
+/// The star map and an open quiz, laid out as the app does
+#[function_component(SliceHarness)]
+fn slice_harness() -> Html {
+    let state = use_reducer(|| GameState {
+        quiz: Some(sirius_quiz()),
+        ui: UiState {
+            dropdown_position: Some((100.0, 100.0)),
+            ..UiState::default()
+        },
+        ..GameState::default()
+    });
+    let catalog = use_memo((), |_| generate_placeholder_catalog());
+    let index = {
+        let catalog = catalog.clone();
+        use_memo((), move |_| SkyIndex::new(&catalog))
+    };
+    let return_focus = use_node_ref();
+    let on_action = {
+        let dispatcher = state.dispatcher();
+        use_callback((), move |action, _| dispatcher.dispatch(action))
+    };
+    DISPATCH.with(|d| *d.borrow_mut() = Some(on_action.clone()));
+
+    Slices::of(&state).provide(html! {
+        <>
+            <MapView
+                catalog={catalog}
+                index={index}
+                on_action={on_action.clone()}
+            />
+            <QuizView on_action={on_action} compact={false} return_focus={return_focus} />
+        </>
+    })
+}
+
+#[wasm_bindgen_test]
+async fn star_map_skips_quiz_and_ui_updates() {
+    let app = mount::<SliceHarness>(()).await;
+    assert!(app.has("svg"));
+    let renders = star_map_renders();
+
+    dispatch(GameAction::SelectAnswer("Vega".into()));
+    settle().await;
+    assert!(app.has("button.quiz-choice.selected"));
+    assert_eq!(star_map_renders(), renders);
+
+    dispatch(GameAction::ShowToast("Saved".to_string()));
+    settle().await;
+    assert_eq!(star_map_renders(), renders);
+
+    // A map change still gets through
+    dispatch(GameAction::SetMagnitudeLimit(3.0));
+    settle().await;
+    assert!(star_map_renders() > renders);
+}
@@ -0,0 +1,58 @@
+use super::mount;
+use stargazer_poc::components::star_map::{StarMap, StarMapProps};
+use stargazer_poc::data::generate_placeholder_catalog;
+use stargazer_poc::data::star_names::Lang;
+use stargazer_poc::utils::{LabelDensity, Viewport};
+use std::rc::Rc;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::Callback;
+
+fn props(quiz_active: bool) -> StarMapProps {
+    StarMapProps {
+        catalog: Rc::new(generate_placeholder_catalog()),
+        index: None,
+        viewport: Viewport::new(800.0, 600.0),
+        magnitude_limit: 6.5,
+        show_grid: false,
+        show_constellations: false,
+        show_deep_sky: false,
+        show_asterisms: false,
+        selected_star: None,
+        compared_star: None,
+        star_scale: 1.0,
+        ping: false,
+        unnamed_clickable: false,
+        label_density: LabelDensity::Dense,
+        name_lang: Lang::Iau,
+        quiz_active,
+        practice: false,
+        local_sky: None,
+        hide_below_horizon: false,
+        on_action: Callback::noop(),
+    }
+}
+
+#[wasm_bindgen_test]
+async fn names_show_between_questions() {
+    let map = mount::<StarMap>(props(false)).await;
+    assert!(map.count(".named-star title") > 0);
+    assert!(map.count(".star-label") > 0);
+}
+
+#[wasm_bindgen_test]
+async fn open_question_hides_every_name() {
+    let map = mount::<StarMap>(props(true)).await;
+    assert!(map.count(".named-star") > 0);
+    assert_eq!(map.count("title"), 0);
+    assert_eq!(map.count(".star-label"), 0);
+}
+
+#[wasm_bindgen_test]
+async fn practice_describes_stars_on_hover() {
+    let map = mount::<StarMap>(StarMapProps {
+        practice: true,
+        ..props(false)
+    })
+    .await;
+    assert!(map.text(".named-star title").contains("Magnitude"));
+}
@@ -0,0 +1,69 @@
+use super::mount;
+use stargazer_poc::components::stats_dashboard::{StatsDashboard, StatsDashboardProps};
+use stargazer_poc::data::{CelestialCoord, Star, StarCatalog, StarId};
+use stargazer_poc::game::{GuessSummary, StarStats};
+use std::rc::Rc;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn guess(was_correct: bool) -> GuessSummary {
+    GuessSummary {
+        star_name: "Rigel".into(),
+        user_answer: "Rigel".into(),
+        was_correct,
+        timestamp: 0.0,
+    }
+}
+
+fn catalog() -> Rc<StarCatalog> {
+    let mut catalog = StarCatalog::new();
+    catalog.add_star(Star {
+        id: StarId(1),
+        name: Some("Rigel".into()),
+        coord: CelestialCoord::new(5.2, -8.2),
+        magnitude: 0.1,
+        constellation: Some("Ori".into()),
+    });
+    catalog.rebuild_indices();
+    Rc::new(catalog)
+}
+
+#[wasm_bindgen_test]
+async fn charts_accuracy_and_misses() {
+    let mut stats = StarStats::default();
+    stats.record(StarId(1), false, 0.0);
+    stats.record(StarId(1), true, 0.0);
+    let dashboard = mount::<StatsDashboard>(StatsDashboardProps {
+        guesses: vec![guess(false), guess(true)],
+        stats: Rc::new(stats),
+        catalog: catalog(),
+    })
+    .await;
+
+    assert_eq!(
+        dashboard
+            .find(".sparkline polyline")
+            .get_attribute("points"),
+        Some("0.0,48.0 240.0,24.0".into())
+    );
+    assert_eq!(dashboard.text(".stats-missed .stats-bar-label"), "Rigel");
+    assert_eq!(
+        dashboard.text(".stats-missed .stats-bar-value"),
+        "1 of 2 wrong"
+    );
+    assert_eq!(
+        dashboard.text(".stats-constellations .stats-bar-value"),
+        "50%"
+    );
+}
+
+#[wasm_bindgen_test]
+async fn nothing_answered_says_so() {
+    let dashboard = mount::<StatsDashboard>(StatsDashboardProps {
+        guesses: Vec::new(),
+        stats: Rc::default(),
+        catalog: catalog(),
+    })
+    .await;
+    assert!(dashboard.has(".stats-empty"));
+    assert!(!dashboard.has("svg"));
+}
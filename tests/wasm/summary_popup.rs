@@ -0,0 +1,92 @@
+use super::{mount, recorder};
+use stargazer_poc::components::summary_popup::{SummaryPopup, SummaryPopupProps};
+use stargazer_poc::game::{GameAction, GuessSummary, ScoreState};
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::Callback;
+
+fn guess(star: &str, answer: &str) -> GuessSummary {
+    GuessSummary {
+        star_name: star.into(),
+        user_answer: answer.into(),
+        was_correct: star == answer,
+        timestamp: 0.0,
+    }
+}
+
+#[wasm_bindgen_test]
+async fn lists_guesses_newest_first() {
+    let mut score = ScoreState::default();
+    score.record_correct();
+    score.record_incorrect();
+    let popup = mount::<SummaryPopup>(SummaryPopupProps {
+        guesses: vec![guess("Vega", "Vega"), guess("Rigel", "Deneb")],
+        score,
+        on_action: Callback::noop(),
+        children: Default::default(),
+    })
+    .await;
+
+    assert_eq!(popup.count(".summary-row"), 2);
+    assert!(popup.has(".summary-row.incorrect:first-child"));
+    assert_eq!(popup.text(".summary-row .summary-star"), "Rigel");
+    assert_eq!(popup.text(".summary-row .summary-answer"), "Deneb");
+    assert_eq!(popup.text(".stat-value.correct"), "1");
+    assert_eq!(popup.text(".stat-value.incorrect"), "1");
+    assert!(popup.root.text_content().unwrap().contains("50.0%"));
+    assert_eq!(popup.text(".copy-button"), "Copy results");
+}
+
+#[wasm_bindgen_test]
+async fn empty_session_says_so() {
+    let popup = mount::<SummaryPopup>(SummaryPopupProps {
+        guesses: Vec::new(),
+        score: ScoreState::default(),
+        on_action: Callback::noop(),
+        children: Default::default(),
+    })
+    .await;
+    assert!(popup.has(".summary-empty"));
+    assert_eq!(popup.count(".summary-row"), 0);
+    assert!(!popup.has(".copy-button"));
+}
+
+#[wasm_bindgen_test]
+async fn buttons_dispatch_actions() {
+    let (on_action, actions) = recorder();
+    let popup = mount::<SummaryPopup>(SummaryPopupProps {
+        guesses: Vec::new(),
+        score: ScoreState::default(),
+        on_action,
+        children: Default::default(),
+    })
+    .await;
+
+    popup.click(".reset-button").await;
+    popup.click(".close-btn").await;
+    popup.click(".close-button").await;
+    assert_eq!(
+        *actions.borrow(),
+        vec![
+            GameAction::ResetScore,
+            GameAction::HideSummary,
+            GameAction::HideSummary
+        ]
+    );
+    // Nothing was missed, so there is nothing to review
+    assert!(!popup.has(".review-button"));
+}
+
+#[wasm_bindgen_test]
+async fn review_button_shown_after_a_miss() {
+    let (on_action, actions) = recorder();
+    let popup = mount::<SummaryPopup>(SummaryPopupProps {
+        guesses: vec![guess("Vega", "Vega"), guess("Deneb", "Altair")],
+        score: ScoreState::default(),
+        on_action,
+        children: Default::default(),
+    })
+    .await;
+
+    popup.click(".review-button").await;
+    assert_eq!(*actions.borrow(), vec![GameAction::ReviewMistakes]);
+}
@@ -0,0 +1,75 @@
+use super::{mount, recorder};
+use stargazer_poc::components::time_control::{TimeControl, TimeControlProps, TIME_LAPSE_STEP};
+use stargazer_poc::game::GameAction;
+use stargazer_poc::utils::dates::{days_from_civil, format_datetime};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::wasm_bindgen_test;
+use web_sys::HtmlInputElement;
+
+/// 2025-03-01 21:30 UTC
+fn evening() -> f64 {
+    days_from_civil(2025, 3, 1) as f64 * 86_400.0 + 21.5 * 3600.0
+}
+
+/// Wait `ms` milliseconds
+async fn sleep(ms: i32) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        gloo_utils::window()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms)
+            .unwrap();
+    });
+    JsFuture::from(promise).await.unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn shows_the_chosen_time_in_local_time() {
+    let (on_action, _) = recorder();
+    let control = mount::<TimeControl>(TimeControlProps {
+        time: Some(evening()),
+        on_action,
+    })
+    .await;
+
+    let offset = -js_sys::Date::new(&JsValue::from_f64(evening() * 1000.0)).get_timezone_offset();
+    let picker: HtmlInputElement = control.find("input[type=datetime-local]").unchecked_into();
+    assert_eq!(picker.value(), format_datetime(evening(), offset as i64));
+    assert!(!control.has(".now-button.active"));
+}
+
+#[wasm_bindgen_test]
+async fn now_returns_to_the_clock() {
+    let (on_action, actions) = recorder();
+    let control = mount::<TimeControl>(TimeControlProps {
+        time: Some(evening()),
+        on_action,
+    })
+    .await;
+
+    control.click(".now-button").await;
+    assert_eq!(
+        *actions.borrow(),
+        vec![GameAction::SetObservationTime(None)]
+    );
+}
+
+#[wasm_bindgen_test]
+async fn play_steps_the_clock_forward() {
+    let (on_action, actions) = recorder();
+    let control = mount::<TimeControl>(TimeControlProps {
+        time: Some(evening()),
+        on_action,
+    })
+    .await;
+    assert_eq!(control.text(".play-button"), "Play");
+
+    control.click(".play-button").await;
+    assert_eq!(control.text(".play-button"), "Pause");
+    sleep(300).await;
+    assert_eq!(
+        actions.borrow().first(),
+        Some(&GameAction::SetObservationTime(Some(
+            evening() + TIME_LAPSE_STEP
+        )))
+    );
+}
@@ -0,0 +1,40 @@
+use super::{mount, recorder};
+use stargazer_poc::components::tour_card::{TourCard, TourCardProps};
+use stargazer_poc::data::CelestialCoord;
+use stargazer_poc::game::{GameAction, TourRun};
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::Callback;
+
+fn props(stop: usize, on_action: Callback<GameAction>) -> TourCardProps {
+    TourCardProps {
+        run: TourRun { tour: 1, stop },
+        star: None,
+        center: CelestialCoord::new(0.0, 0.0),
+        name_lang: Default::default(),
+        on_action,
+    }
+}
+
+#[wasm_bindgen_test]
+async fn shows_the_stop_and_its_note() {
+    let card = mount::<TourCard>(props(1, Callback::noop())).await;
+    assert_eq!(card.text(".tour-name"), "Winter Hexagon");
+    assert_eq!(card.text(".tour-progress"), "2 of 7");
+    assert_eq!(card.text(".tour-star"), "Rigel");
+    assert_eq!(card.text(".tour-note"), "Up to Orion's bright blue foot.");
+}
+
+#[wasm_bindgen_test]
+async fn moves_between_stops_and_ends() {
+    let (on_action, actions) = recorder();
+    let card = mount::<TourCard>(props(0, on_action)).await;
+    assert!(card.has(".tour-previous[disabled]"));
+
+    card.click(".tour-previous").await;
+    card.click(".tour-next").await;
+    card.click(".close-button").await;
+    assert_eq!(
+        *actions.borrow(),
+        vec![GameAction::NextTourStop, GameAction::EndRun]
+    );
+}
@@ -0,0 +1,29 @@
+use super::{mount, recorder};
+use stargazer_poc::components::trophy_panel::{TrophyPanel, TrophyPanelProps};
+use stargazer_poc::game::{Achievement, Achievements, GameAction};
+use stargazer_poc::utils::dates::days_from_civil;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn lists_every_achievement_and_marks_the_unlocked() {
+    let (on_action, actions) = recorder();
+    let mut achievements = Achievements::default();
+    let day_ms = days_from_civil(2025, 3, 1) as f64 * 86_400_000.0;
+    achievements.unlock(Achievement::Streak, day_ms + 1000.0);
+    let panel = mount::<TrophyPanel>(TrophyPanelProps {
+        achievements,
+        on_action,
+    })
+    .await;
+
+    assert_eq!(panel.count(".trophy"), Achievement::ALL.len() as u32);
+    assert_eq!(panel.count(".trophy.unlocked"), 1);
+    assert_eq!(panel.text(".trophy.unlocked .trophy-name"), "On a Roll");
+    assert_eq!(
+        panel.text(".trophy.unlocked .trophy-status"),
+        "Earned 2025-03-01"
+    );
+
+    panel.click(".close-button").await;
+    assert_eq!(*actions.borrow(), vec![GameAction::ToggleTrophies]);
+}